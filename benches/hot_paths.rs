@@ -0,0 +1,103 @@
+//! Benchmarks of the engine's logic hot paths: deck shuffling and drawing, and status ticking.
+//!
+//! ```text
+//! cargo bench --bench hot_paths
+//! ```
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use deckbuilder_eng::card::{Card, CardType, Deck};
+use deckbuilder_eng::status::{Status, StatusContainer};
+
+fn cards(count: usize) -> Vec<Card> {
+    (0..count)
+        .map(|i| {
+            let card_type = match i % 3 {
+                0 => CardType::Attack,
+                1 => CardType::Skill,
+                _ => CardType::Power,
+            };
+            Card::new(i as u32, format!("Card {}", i), "Deal 6 damage", (i % 4) as u32, card_type)
+        })
+        .collect()
+}
+
+fn deck(c: &mut Criterion) {
+    let pile = cards(1000);
+    c.bench_function("deck/shuffle_1k", |b| {
+        b.iter_batched(
+            || Deck {
+                draw_pile: Vec::new(),
+                discard_pile: pile.clone(),
+            },
+            |mut deck| {
+                deck.shuffle();
+                deck
+            },
+            BatchSize::SmallInput,
+        )
+    });
+    c.bench_function("deck/draw_1k", |b| {
+        b.iter_batched(
+            || Deck::new(pile.clone()),
+            |mut deck| black_box(deck.draw_multiple(1000)),
+            BatchSize::SmallInput,
+        )
+    });
+    c.bench_function("deck/draw_discard_cycle_1k", |b| {
+        b.iter_batched(
+            || Deck::new(pile.clone()),
+            |mut deck| {
+                // Draw and discard the whole deck twice, reshuffling in between.
+                for _ in 0..2000 {
+                    if let Some(card) = deck.draw() {
+                        deck.discard(card);
+                    }
+                }
+                deck
+            },
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+fn statuses(count: usize) -> StatusContainer {
+    let mut statuses = StatusContainer::new();
+    for i in 0..count {
+        let status = if i % 2 == 0 {
+            Status::buff(format!("Buff {}", i), 99)
+        } else {
+            Status::debuff(format!("Debuff {}", i), 99).turns((i % 10 + 1) as u32)
+        };
+        statuses.apply(status);
+    }
+    statuses
+}
+
+fn status(c: &mut Criterion) {
+    let many = statuses(200);
+    c.bench_function("status/tick_200", |b| {
+        b.iter_batched(
+            || many.clone(),
+            |mut statuses| {
+                statuses.tick();
+                statuses
+            },
+            BatchSize::SmallInput,
+        )
+    });
+    c.bench_function("status/apply_stacks_200", |b| {
+        b.iter_batched(
+            || many.clone(),
+            |mut statuses| {
+                for i in 0..200 {
+                    statuses.apply(Status::buff(format!("Buff {}", i * 2 % 200), 1));
+                }
+                statuses
+            },
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+criterion_group!(benches, deck, status);
+criterion_main!(benches);
@@ -0,0 +1,57 @@
+//! Benchmarks of the UI hot paths: laying out a large card grid and decoding a GIF.
+//!
+//! ```text
+//! cargo bench --bench ui
+//! ```
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use deckbuilder_eng::card::{Card, CardType};
+use deckbuilder_eng::ui_card::{ui_card_grid, CardGridState};
+use deckbuilder_eng::ui_image::load_gif_frames_from_bytes;
+use egui::{CentralPanel, Context, Pos2, RawInput, Rect, Vec2};
+
+static GIF: &[u8] = include_bytes!("../assets/example.gif");
+
+fn raw_input() -> RawInput {
+    RawInput {
+        screen_rect: Some(Rect::from_min_size(Pos2::ZERO, Vec2::new(1280.0, 720.0))),
+        ..Default::default()
+    }
+}
+
+fn card_grid(c: &mut Criterion) {
+    let cards: Vec<Card> = (0..1000)
+        .map(|i| Card::new(i, format!("Card {}", i), "Deal 6 damage. Apply 1 Vulnerable.", i % 4, CardType::Attack))
+        .collect();
+    let ctx = Context::default();
+    let mut state = CardGridState::default();
+    c.bench_function("ui/card_grid_1k", |b| {
+        b.iter(|| {
+            black_box(ctx.run(raw_input(), |ctx| {
+                CentralPanel::default().show(ctx, |ui| ui_card_grid(ui, &cards, &mut state));
+            }))
+        })
+    });
+    state.search = "card 1".to_owned();
+    c.bench_function("ui/card_grid_1k_filtered", |b| {
+        b.iter(|| {
+            black_box(ctx.run(raw_input(), |ctx| {
+                CentralPanel::default().show(ctx, |ui| ui_card_grid(ui, &cards, &mut state));
+            }))
+        })
+    });
+}
+
+fn gif_decode(c: &mut Criterion) {
+    let ctx = Context::default();
+    let mut group = c.benchmark_group("ui");
+    // Decoding the example GIF takes long; fewer samples keep the run short.
+    group.sample_size(10);
+    group.bench_function("gif_decode", |b| {
+        b.iter(|| load_gif_frames_from_bytes(&ctx, "example", black_box(GIF), None).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, card_grid, gif_decode);
+criterion_main!(benches);
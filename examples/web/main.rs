@@ -0,0 +1,126 @@
+//! A deckbuilder screen that runs in the browser.
+//!
+//! Native run (packs `assets/` into `examples/web/assets.pak` and shows the same screen):
+//!
+//! ```text
+//! cargo run --example web
+//! ```
+//!
+//! Web build (needs the `wasm32-unknown-unknown` target and `wasm-bindgen-cli`):
+//!
+//! ```text
+//! cargo build --example web --release --target wasm32-unknown-unknown
+//! wasm-bindgen --target web --no-typescript --out-dir examples/web/pkg \
+//!     target/wasm32-unknown-unknown/release/examples/web.wasm
+//! ```
+//!
+//! Then serve `examples/web` with any static file server and open `index.html`.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use deckbuilder_eng::card::{Card, CardType};
+use deckbuilder_eng::texture::TextureManager;
+use deckbuilder_eng::ui::ui_heading;
+use deckbuilder_eng::ui_card::{ui_hand, HandLayoutConfig};
+use deckbuilder_eng::ui_image::ui_image;
+use deckbuilder_eng::vfs::PakSource;
+use egui::{CentralPanel, Context, Vec2};
+
+/// The asset pack, once it has been fetched (or read from disk natively).
+type PendingPak = Rc<RefCell<Option<deckbuilder_eng::Result<PakSource>>>>;
+
+struct WebApp {
+    textures: TextureManager,
+    pak: PendingPak,
+    ready: bool,
+    status: String,
+    hand: Vec<Card>,
+}
+
+impl WebApp {
+    fn new(ctx: &Context, pak: PendingPak) -> Self {
+        Self {
+            textures: TextureManager::new(ctx),
+            pak,
+            ready: false,
+            status: "Loading assets.pak...".to_owned(),
+            hand: vec![
+                Card::new(1, "Strike", "Deal 6 damage", 1, CardType::Attack),
+                Card::new(2, "Defend", "Gain 5 block", 1, CardType::Skill),
+                Card::new(3, "Bash", "Deal 8 damage", 2, CardType::Attack),
+            ],
+        }
+    }
+}
+
+impl eframe::App for WebApp {
+    fn update(&mut self, ctx: &Context, _frame: &mut eframe::Frame) {
+        if let Some(pak) = self.pak.borrow_mut().take() {
+            match pak {
+                Ok(pak) => {
+                    self.status = format!("{} files in assets.pak", pak.file_count());
+                    self.textures.set_source(Arc::new(pak));
+                    self.ready = true;
+                }
+                Err(err) => self.status = err.to_string(),
+            }
+        }
+
+        CentralPanel::default().show(ctx, |ui| {
+            ui_heading(ui, "Deckbuilder on the web");
+            ui.label(&self.status);
+            if self.ready
+                && let Ok((frames, _)) = self.textures.load_gif("example.gif", 1.0)
+                && let Some(frame) = frames.first()
+            {
+                ui_image(ui, frame, Some(Vec2::splat(96.0)));
+            }
+            let response = ui_hand(ui, &self.hand, &HandLayoutConfig::default());
+            if let Some(i) = response.clicked {
+                self.status = format!("Played {}", self.hand[i].name);
+            }
+        });
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn main() {
+    wasm_bindgen_futures::spawn_local(async {
+        eframe::WebRunner::new()
+            .start(
+                "deckbuilder_canvas",
+                eframe::WebOptions::default(),
+                Box::new(|cc| {
+                    let pak = PendingPak::default();
+                    let fetched = Rc::clone(&pak);
+                    let ctx = cc.egui_ctx.clone();
+                    wasm_bindgen_futures::spawn_local(async move {
+                        *fetched.borrow_mut() = Some(deckbuilder_eng::web::fetch_pak("assets.pak").await);
+                        ctx.request_repaint();
+                    });
+                    Box::new(WebApp::new(&cc.egui_ctx, pak))
+                }),
+            )
+            .await
+            .expect("failed to start eframe");
+    });
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() -> eframe::Result<()> {
+    use deckbuilder_eng::vfs::PakBuilder;
+
+    let path = "examples/web/assets.pak";
+    let packed = PakBuilder::new().add_dir("assets").and_then(|pak| pak.write(path));
+    let pak = match packed {
+        Ok(()) => PakSource::open(path).map_err(|err| deckbuilder_eng::Error::io(path, err)),
+        Err(err) => Err(deckbuilder_eng::Error::io("assets", err)),
+    };
+    eframe::run_native(
+        "Deckbuilder Engine - Web Example",
+        eframe::NativeOptions::default(),
+        Box::new(|cc| Box::new(WebApp::new(&cc.egui_ctx, Rc::new(RefCell::new(Some(pak)))))),
+    )
+}
@@ -0,0 +1,555 @@
+//! Computer opponents for deckbuilder games.
+//!
+//! Provides [`AiPlayer`], which plays a hand through the same [`PlayerAction`]s a human sends:
+//! it estimates each card's effect ([`CardEffect`]), predicts its damage with
+//! [`predict_damage`], picks targets and ends its turn. How it chooses is an [`AiStrategy`];
+//! [`Difficulty`] selects one of the built-in ones. Use it for single-player versus modes or
+//! to play games automatically in tests and balancing runs.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::ai::{AiPlayer, AiState, Difficulty};
+//! use deckbuilder_eng::card::{Card, CardType, GameContext};
+//! use deckbuilder_eng::net::PlayerAction;
+//!
+//! let hand = vec![
+//!     Card::new(1, "Strike", "Deal 6 damage.", 1, CardType::Attack),
+//!     Card::new(2, "Defend", "Gain 5 block.", 1, CardType::Skill),
+//!     Card::new(3, "Bash", "Deal 8 damage.", 2, CardType::Attack),
+//! ];
+//! let mut battle = GameContext::new(40, 14);
+//! battle.energy = 3;
+//!
+//! let mut ai = AiPlayer::new(Difficulty::Hard);
+//! let actions = ai.plan_turn(&AiState::from_battle(&hand, &battle));
+//! // 6 + 8 damage is lethal, so it attacks with everything.
+//! assert_eq!(actions.len(), 3);
+//! assert_eq!(actions.last(), Some(&PlayerAction::EndTurn));
+//! ```
+//!
+//! # Details
+//!
+//! - Targets are relative to the AI: attacks target [`Target::Enemy`], its opponent; other
+//!   cards have no target.
+//! - By default a card's effect is read from its description ("Deal 6 damage", "Deal 3 damage
+//!   2 times", "Gain 5 block"); set [`AiPlayer::effects`] for cards whose text doesn't say.
+//! - Block counts as useful up to the damage the AI expects to take
+//!   ([`AiState::incoming_damage`]), after "Dexterity" and "Frail", and is worth more while
+//!   that damage would be lethal.
+//! - [`Difficulty::Easy`] plays random affordable cards, [`Difficulty::Normal`] the most
+//!   valuable card each time, and [`Difficulty::Hard`] the best combination of cards for the
+//!   whole turn, going for lethal damage first and then for surviving the next hit.
+//! - See each struct and function's documentation for more.
+
+use crate::card::{Card, CardId, CardType, GameContext};
+use crate::events::Target;
+use crate::net::PlayerAction;
+use crate::status::{predict_damage, StatusContainer};
+
+/// What a card does, as far as the AI is concerned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CardEffect {
+    /// Damage per hit before statuses.
+    pub damage: i32,
+    /// Number of hits (at least 1 when the card deals damage).
+    pub hits: u32,
+    /// Block gained before statuses.
+    pub block: i32,
+}
+
+impl CardEffect {
+    /// Reads the effect from card text: "Deal N damage" (optionally "N times") and
+    /// "Gain N block". Markup like `<b>6</b>` or `[red]6[/red]` is ignored.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ai::CardEffect;
+    /// let effect = CardEffect::parse("Deal <b>4</b> damage 2 times. Gain 3 Block.");
+    /// assert_eq!(effect, CardEffect { damage: 4, hits: 2, block: 3 });
+    /// ```
+    pub fn parse(description: &str) -> Self {
+        let mut plain = String::with_capacity(description.len());
+        let mut skip_until = None;
+        for c in description.chars() {
+            match (skip_until, c) {
+                (Some(end), c) if c == end => skip_until = None,
+                (Some(_), _) => {}
+                (None, '<') => skip_until = Some('>'),
+                (None, '[') => skip_until = Some(']'),
+                (None, c) => plain.push(c),
+            }
+        }
+        let words: Vec<String> = plain
+            .split_whitespace()
+            .map(|word| word.trim_matches(|c: char| !c.is_alphanumeric()).to_lowercase())
+            .collect();
+        let number = |i: usize| words.get(i).and_then(|word| word.parse::<i32>().ok());
+        let word = |i: usize| words.get(i).map_or("", String::as_str);
+        let mut effect = CardEffect::default();
+        for i in 0..words.len() {
+            match word(i) {
+                "deal" if word(i + 2) == "damage" => effect.damage += number(i + 1).unwrap_or(0),
+                "gain" if word(i + 2) == "block" => effect.block += number(i + 1).unwrap_or(0),
+                "times" => effect.hits = number(i.wrapping_sub(1)).map_or(effect.hits, |n| n.max(1) as u32),
+                _ => {}
+            }
+        }
+        if effect.damage > 0 {
+            effect.hits = effect.hits.max(1);
+        }
+        effect
+    }
+}
+
+/// What the AI knows at the start of its turn.
+#[derive(Debug, Clone, Copy)]
+pub struct AiState<'a> {
+    pub hand: &'a [Card],
+    pub energy: u32,
+    pub health: i32,
+    /// Block the AI already has.
+    pub block: i32,
+    pub opponent_health: i32,
+    /// Damage the AI expects to take before its next turn, e.g. from the opponent's intent.
+    pub incoming_damage: i32,
+    pub statuses: Option<&'a StatusContainer>,
+    pub opponent_statuses: Option<&'a StatusContainer>,
+}
+
+impl<'a> AiState<'a> {
+    /// Creates the state of an AI holding `hand` with `energy` and `health`, against an
+    /// opponent with `opponent_health`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ai::AiState;
+    /// let state = AiState::new(&[], 3, 40, 30);
+    /// assert_eq!(state.incoming_damage, 0);
+    /// ```
+    pub fn new(hand: &'a [Card], energy: u32, health: i32, opponent_health: i32) -> Self {
+        Self {
+            hand,
+            energy,
+            health,
+            block: 0,
+            opponent_health,
+            incoming_damage: 0,
+            statuses: None,
+            opponent_statuses: None,
+        }
+    }
+
+    /// Creates the state of an AI playing the player side of `battle`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ai::AiState;
+    /// # use deckbuilder_eng::card::GameContext;
+    /// let battle = GameContext::new(40, 30);
+    /// let state = AiState::from_battle(&[], &battle);
+    /// assert_eq!(state.opponent_health, 30);
+    /// ```
+    pub fn from_battle(hand: &'a [Card], battle: &GameContext) -> Self {
+        Self::new(hand, battle.energy, battle.player_health, battle.enemy_health)
+    }
+
+    /// Sets the block the AI already has.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ai::AiState;
+    /// let state = AiState::new(&[], 3, 40, 30).block(5);
+    /// ```
+    pub fn block(mut self, block: i32) -> Self {
+        self.block = block;
+        self
+    }
+
+    /// Sets the damage the AI expects to take, so it knows how much block is worth.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ai::AiState;
+    /// let state = AiState::new(&[], 3, 40, 30).incoming_damage(12);
+    /// ```
+    pub fn incoming_damage(mut self, damage: i32) -> Self {
+        self.incoming_damage = damage;
+        self
+    }
+
+    /// Sets the statuses of the AI and of its opponent, used to predict damage and block.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ai::AiState;
+    /// # use deckbuilder_eng::status::{Status, StatusContainer};
+    /// let mut own = StatusContainer::new();
+    /// own.apply(Status::buff("Strength", 2));
+    /// let opponent = StatusContainer::new();
+    /// let state = AiState::new(&[], 3, 40, 30).statuses(&own, &opponent);
+    /// ```
+    pub fn statuses(mut self, own: &'a StatusContainer, opponent: &'a StatusContainer) -> Self {
+        self.statuses = Some(own);
+        self.opponent_statuses = Some(opponent);
+        self
+    }
+}
+
+/// A card the AI can afford to play, with its predicted effect.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CardOption {
+    /// Index of the card in the hand.
+    pub index: usize,
+    pub card: CardId,
+    pub card_type: CardType,
+    pub cost: u32,
+    /// Total damage after statuses, over all hits.
+    pub damage: i32,
+    /// Block gained after statuses.
+    pub block: i32,
+    pub target: Option<Target>,
+}
+
+impl CardOption {
+    /// Returns how much the card is worth now: its damage, its block up to the damage still
+    /// unblocked (twice that while the unblocked damage would be lethal), and a flat bonus for
+    /// powers.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ai::{CardOption, TurnView};
+    /// # use deckbuilder_eng::card::CardType;
+    /// let defend = CardOption { index: 0, card: 2, card_type: CardType::Skill, cost: 1, damage: 0, block: 5, target: None };
+    /// let turn = TurnView { energy: 3, health: 40, block: 0, opponent_health: 30, incoming_damage: 3 };
+    /// assert_eq!(defend.value(&turn), 3);
+    /// ```
+    pub fn value(&self, turn: &TurnView) -> i32 {
+        let unblocked = (turn.incoming_damage - turn.block).max(0);
+        let block = self.block.min(unblocked);
+        let block = if unblocked >= turn.health { block * 2 } else { block };
+        let power = if self.card_type == CardType::Power { 4 } else { 0 };
+        self.damage.min(turn.opponent_health.max(0)) + block + power
+    }
+}
+
+/// The AI's turn so far, as predicted from the cards it played.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TurnView {
+    pub energy: u32,
+    pub health: i32,
+    pub block: i32,
+    pub opponent_health: i32,
+    pub incoming_damage: i32,
+}
+
+/// How an AI picks its cards.
+pub trait AiStrategy {
+    /// Returns the index in `options` of the card to play next, or `None` to end the turn.
+    /// `options` holds the affordable cards and is never empty.
+    fn choose(&mut self, options: &[CardOption], turn: &TurnView) -> Option<usize>;
+}
+
+/// Plays random affordable cards ([`Difficulty::Easy`]).
+#[derive(Debug, Clone)]
+pub struct RandomStrategy {
+    state: u64,
+}
+
+impl RandomStrategy {
+    /// Creates a strategy whose choices are determined by `seed`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ai::{AiPlayer, RandomStrategy};
+    /// let ai = AiPlayer::with_strategy(RandomStrategy::new(7));
+    /// ```
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed.max(1) }
+    }
+
+    fn next(&mut self) -> u64 {
+        // xorshift64
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+}
+
+impl AiStrategy for RandomStrategy {
+    fn choose(&mut self, options: &[CardOption], _turn: &TurnView) -> Option<usize> {
+        Some((self.next() % options.len() as u64) as usize)
+    }
+}
+
+/// Plays the most valuable card each time, preferring cheaper cards on ties
+/// ([`Difficulty::Normal`]).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GreedyStrategy;
+
+impl AiStrategy for GreedyStrategy {
+    fn choose(&mut self, options: &[CardOption], turn: &TurnView) -> Option<usize> {
+        options
+            .iter()
+            .enumerate()
+            .filter(|(_, option)| option.value(turn) > 0)
+            .max_by_key(|(_, option)| (option.value(turn), std::cmp::Reverse(option.cost)))
+            .map(|(i, _)| i)
+    }
+}
+
+/// Plays the combination of affordable cards worth the most over the whole turn, going for
+/// lethal damage first and then for enough block to survive ([`Difficulty::Hard`]). Hands of
+/// more than 12 affordable cards fall back to [`GreedyStrategy`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LookaheadStrategy;
+
+impl AiStrategy for LookaheadStrategy {
+    fn choose(&mut self, options: &[CardOption], turn: &TurnView) -> Option<usize> {
+        if options.len() > 12 {
+            return GreedyStrategy.choose(options, turn);
+        }
+        let unblocked = (turn.incoming_damage - turn.block).max(0);
+        let mut best = (0, 0);
+        for set in 1u32..1 << options.len() {
+            let chosen = || options.iter().enumerate().filter(move |(i, _)| set & (1 << i) != 0).map(|(_, o)| o);
+            if chosen().map(|option| option.cost).sum::<u32>() > turn.energy {
+                continue;
+            }
+            let damage: i32 = chosen().map(|option| option.damage).sum();
+            let block: i32 = chosen().map(|option| option.block).sum();
+            let powers = chosen().filter(|option| option.card_type == CardType::Power).count() as i32;
+            let lethal = if damage >= turn.opponent_health { 1000 } else { 0 };
+            let survives = if unblocked >= turn.health && unblocked - block < turn.health { 500 } else { 0 };
+            let value = lethal + survives + damage.min(turn.opponent_health.max(0)) + block.min(unblocked) + powers * 4;
+            if value > best.0 {
+                best = (value, set);
+            }
+        }
+        // Powers first, so they apply to the rest of the turn.
+        (0..options.len())
+            .filter(|i| best.1 & (1 << i) != 0)
+            .max_by_key(|&i| (options[i].card_type == CardType::Power, options[i].value(turn)))
+    }
+}
+
+/// Built-in strategies by difficulty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Difficulty {
+    Easy,
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    /// Returns the strategy of the difficulty.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ai::Difficulty;
+    /// let strategy = Difficulty::Normal.strategy();
+    /// ```
+    pub fn strategy(self) -> Box<dyn AiStrategy> {
+        match self {
+            Difficulty::Easy => Box::new(RandomStrategy::new(0x9e37_79b9_7f4a_7c15)),
+            Difficulty::Normal => Box::new(GreedyStrategy),
+            Difficulty::Hard => Box::new(LookaheadStrategy),
+        }
+    }
+}
+
+type EffectFn = Box<dyn Fn(&Card) -> CardEffect>;
+
+/// A computer player choosing [`PlayerAction`]s like a human would send.
+pub struct AiPlayer {
+    strategy: Box<dyn AiStrategy>,
+    effects: EffectFn,
+}
+
+impl AiPlayer {
+    /// Creates an AI of `difficulty`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ai::{AiPlayer, Difficulty};
+    /// let ai = AiPlayer::new(Difficulty::Normal);
+    /// ```
+    pub fn new(difficulty: Difficulty) -> Self {
+        Self {
+            strategy: difficulty.strategy(),
+            effects: Box::new(|card| CardEffect::parse(&card.description)),
+        }
+    }
+
+    /// Creates an AI choosing with `strategy`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ai::{AiPlayer, AiStrategy, CardOption, TurnView};
+    /// /// Plays the first affordable card, for reproducible tests.
+    /// struct FirstCard;
+    /// impl AiStrategy for FirstCard {
+    ///     fn choose(&mut self, _options: &[CardOption], _turn: &TurnView) -> Option<usize> {
+    ///         Some(0)
+    ///     }
+    /// }
+    /// let ai = AiPlayer::with_strategy(FirstCard);
+    /// ```
+    pub fn with_strategy(strategy: impl AiStrategy + 'static) -> Self {
+        Self {
+            strategy: Box::new(strategy),
+            ..Self::new(Difficulty::Normal)
+        }
+    }
+
+    /// Sets how card effects are estimated, instead of reading card descriptions.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ai::{AiPlayer, CardEffect, Difficulty};
+    /// let ai = AiPlayer::new(Difficulty::Hard).effects(|card| match card.name.as_str() {
+    ///     "Whirlwind" => CardEffect { damage: 5, hits: 3, block: 0 },
+    ///     _ => CardEffect::parse(&card.description),
+    /// });
+    /// ```
+    pub fn effects(mut self, effects: impl Fn(&Card) -> CardEffect + 'static) -> Self {
+        self.effects = Box::new(effects);
+        self
+    }
+
+    /// Returns the cards of the hand the AI can afford, with their predicted effects.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ai::{AiPlayer, AiState, Difficulty};
+    /// # use deckbuilder_eng::card::{Card, CardType};
+    /// # use deckbuilder_eng::status::{Status, StatusContainer};
+    /// let hand = [Card::new(1, "Strike", "Deal 6 damage.", 1, CardType::Attack)];
+    /// let own = StatusContainer::new();
+    /// let mut opponent = StatusContainer::new();
+    /// opponent.apply(Status::debuff("Vulnerable", 1).turns(2));
+    ///
+    /// let ai = AiPlayer::new(Difficulty::Normal);
+    /// let options = ai.options(&AiState::new(&hand, 3, 40, 30).statuses(&own, &opponent));
+    /// assert_eq!(options[0].damage, 9);
+    /// ```
+    pub fn options(&self, state: &AiState) -> Vec<CardOption> {
+        let empty = StatusContainer::new();
+        let own = state.statuses.unwrap_or(&empty);
+        let opponent = state.opponent_statuses.unwrap_or(&empty);
+        state
+            .hand
+            .iter()
+            .enumerate()
+            .filter(|(_, card)| card.cost <= state.energy)
+            .map(|(index, card)| {
+                let effect = (self.effects)(card);
+                let damage = if effect.damage > 0 {
+                    predict_damage(effect.damage, own, opponent) * effect.hits.max(1) as i32
+                } else {
+                    0
+                };
+                let mut block = 0;
+                if effect.block > 0 {
+                    let mut amount = (effect.block + own.stacks("Dexterity")) as f32;
+                    if own.get("Frail").is_some() {
+                        amount *= 0.75;
+                    }
+                    block = (amount.floor() as i32).max(0);
+                }
+                CardOption {
+                    index,
+                    card: card.id,
+                    card_type: card.card_type.clone(),
+                    cost: card.cost,
+                    damage,
+                    block,
+                    target: (card.card_type == CardType::Attack).then_some(Target::Enemy),
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the AI's next action: a card to play or [`PlayerAction::EndTurn`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ai::{AiPlayer, AiState, Difficulty};
+    /// # use deckbuilder_eng::net::PlayerAction;
+    /// let mut ai = AiPlayer::new(Difficulty::Normal);
+    /// assert_eq!(ai.next_action(&AiState::new(&[], 3, 40, 30)), PlayerAction::EndTurn);
+    /// ```
+    pub fn next_action(&mut self, state: &AiState) -> PlayerAction {
+        let options = self.options(state);
+        let turn = TurnView {
+            energy: state.energy,
+            health: state.health,
+            block: state.block,
+            opponent_health: state.opponent_health,
+            incoming_damage: state.incoming_damage,
+        };
+        self.choose(&options, &turn)
+            .map_or(PlayerAction::EndTurn, |option| PlayerAction::PlayCard {
+                card: option.card,
+                target: option.target,
+            })
+    }
+
+    /// Plans a whole turn: the cards to play in order, predicting their effects as they are
+    /// played, followed by [`PlayerAction::EndTurn`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ai::{AiPlayer, AiState, Difficulty};
+    /// # use deckbuilder_eng::card::{Card, CardType};
+    /// # use deckbuilder_eng::net::PlayerAction;
+    /// let hand = vec![
+    ///     Card::new(1, "Strike", "Deal 6 damage.", 1, CardType::Attack),
+    ///     Card::new(2, "Defend", "Gain 5 block.", 1, CardType::Skill),
+    /// ];
+    /// let mut ai = AiPlayer::new(Difficulty::Normal);
+    /// // With one energy and a big hit coming, it blocks.
+    /// let actions = ai.plan_turn(&AiState::new(&hand, 1, 10, 30).incoming_damage(12));
+    /// assert_eq!(actions[0], PlayerAction::PlayCard { card: 2, target: None });
+    /// ```
+    pub fn plan_turn(&mut self, state: &AiState) -> Vec<PlayerAction> {
+        let mut hand = state.hand.to_vec();
+        let mut turn = TurnView {
+            energy: state.energy,
+            health: state.health,
+            block: state.block,
+            opponent_health: state.opponent_health,
+            incoming_damage: state.incoming_damage,
+        };
+        let mut actions = Vec::new();
+        loop {
+            let options = self.options(&AiState {
+                hand: &hand,
+                energy: turn.energy,
+                ..*state
+            });
+            let Some(option) = self.choose(&options, &turn) else {
+                break;
+            };
+            turn.energy -= option.cost;
+            turn.opponent_health -= option.damage;
+            turn.block += option.block;
+            hand.remove(option.index);
+            actions.push(PlayerAction::PlayCard {
+                card: option.card,
+                target: option.target,
+            });
+        }
+        actions.push(PlayerAction::EndTurn);
+        actions
+    }
+
+    fn choose(&mut self, options: &[CardOption], turn: &TurnView) -> Option<CardOption> {
+        if options.is_empty() {
+            return None;
+        }
+        let index = self.strategy.choose(options, turn)?;
+        options.get(index).cloned()
+    }
+}
@@ -0,0 +1,793 @@
+//! Asset management for deckbuilder games.
+//!
+//! Provides an [`AssetManager`] that owns the [`TextureManager`], works with an optional
+//! [`AudioManager`], and loads assets listed in an [`AssetManifest`] in named bundles (one per
+//! scene), reporting progress into a [`LoadProgress`] and looking assets up by name.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::assets::{AssetManager, AssetManifest};
+//! use deckbuilder_eng::audio::AudioManager;
+//! use deckbuilder_eng::ui_image::ui_image;
+//! use egui::{CentralPanel, Context};
+//!
+//! let manifest = AssetManifest::parse(
+//!     "[combat]\n\
+//!      texture strike = assets/cards/strike.png\n\
+//!      gif slime = assets/example.gif\n\
+//!      sound click = assets/sfx/click.wav\n\
+//!      music battle = assets/music/background.ogg\n",
+//! )
+//! .unwrap();
+//!
+//! let ctx = Context::default();
+//! let mut assets = AssetManager::new(&ctx, manifest);
+//! if let Ok(audio) = AudioManager::new() {
+//!     assets.set_audio(audio);
+//! }
+//!
+//! // Missing files are reported, everything else is loaded.
+//! if let Err(err) = assets.load_bundle("combat") {
+//!     eprintln!("{err}");
+//! }
+//!
+//! CentralPanel::default().show(&ctx, |ui| {
+//!     if let Some(strike) = assets.texture("strike") {
+//!         ui_image(ui, &strike, None);
+//!     }
+//! });
+//!
+//! // Leaving combat frees its textures and sounds.
+//! assets.unload_bundle("combat");
+//! ```
+//!
+//! # Details
+//!
+//! - Manifest sections are bundles; an asset listed in several bundles stays loaded until the
+//!   last of them is unloaded.
+//! - [`AssetManager::load_bundle_step`] loads one asset per call, for a loading screen step.
+//! - Files are read from disk, or from an asset pack or mod directories with
+//!   [`AssetManager::set_source`].
+//! - Sounds and music are skipped without an [`AudioManager`], so games still run without an
+//!   audio device, or when the crate is built without the `kira` feature.
+//! - See each struct and function's documentation for more.
+
+use std::collections::{HashMap, HashSet};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use egui::{Context, TextureHandle};
+use thiserror::Error;
+
+#[cfg(feature = "kira")]
+use crate::audio::AudioManager;
+use crate::texture::TextureManager;
+use crate::ui_loading::LoadProgress;
+use crate::vfs::AssetSource;
+
+/// Errors from parsing a manifest or loading a bundle.
+#[derive(Debug, Error)]
+pub enum AssetError {
+    /// The manifest file couldn't be read.
+    #[error("failed to read asset manifest: {0}")]
+    Io(#[from] std::io::Error),
+    /// Malformed manifest line.
+    #[error("asset manifest line {line}: {message}")]
+    Parse { line: usize, message: String },
+    /// The manifest has no bundle of this name.
+    #[error("unknown asset bundle `{0}`")]
+    UnknownBundle(String),
+    /// Some assets of a bundle couldn't be loaded; the rest of the bundle was.
+    #[error("failed to load {} asset(s) of bundle `{bundle}`", failed.len())]
+    Load { bundle: String, failed: Vec<PathBuf> },
+}
+
+/// Kind of an asset in a manifest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AssetKind {
+    /// A still image, loaded as a texture.
+    Texture,
+    /// A GIF (or animated WebP/APNG), loaded as frames.
+    Gif,
+    /// A sound effect on the SFX track.
+    Sound,
+    /// Looping music on the music track.
+    Music,
+}
+
+impl AssetKind {
+    /// Parses the manifest name of a kind (`texture`, `gif`, `sound` or `music`).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::assets::AssetKind;
+    /// assert_eq!(AssetKind::from_name("gif"), Some(AssetKind::Gif));
+    /// assert_eq!(AssetKind::from_name("font"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "texture" => Some(AssetKind::Texture),
+            "gif" => Some(AssetKind::Gif),
+            "sound" => Some(AssetKind::Sound),
+            "music" => Some(AssetKind::Music),
+            _ => None,
+        }
+    }
+}
+
+/// One asset of a bundle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssetEntry {
+    /// What the file is loaded as.
+    pub kind: AssetKind,
+    /// Name the asset is looked up by.
+    pub name: String,
+    /// File to load.
+    pub path: PathBuf,
+}
+
+/// Named bundles of assets, usually parsed from a manifest file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AssetManifest {
+    bundles: Vec<(String, Vec<AssetEntry>)>,
+}
+
+impl AssetManifest {
+    /// Creates an empty manifest.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::assets::AssetManifest;
+    /// assert!(AssetManifest::new().bundle_names().is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses a manifest. A `[name]` header starts a bundle, followed by `kind name = path`
+    /// lines, where kind is one of `texture`, `gif`, `sound` or `music`. Lines starting with
+    /// `#` are comments. Repeating a header adds to the existing bundle.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::assets::{AssetKind, AssetManifest};
+    /// let manifest = AssetManifest::parse(
+    ///     "# Shared by every scene\n\
+    ///      [ui]\n\
+    ///      texture button = assets/ui/button.png\n\
+    ///      [combat]\n\
+    ///      sound hit = assets/sfx/hit.wav\n",
+    /// )
+    /// .unwrap();
+    /// assert_eq!(manifest.bundle_names(), ["ui", "combat"]);
+    /// assert_eq!(manifest.bundle("combat").unwrap()[0].kind, AssetKind::Sound);
+    /// ```
+    pub fn parse(source: &str) -> Result<Self, AssetError> {
+        let mut manifest = AssetManifest::new();
+        let mut bundle: Option<String> = None;
+        for (index, line) in source.lines().enumerate() {
+            let error = |message: &str| AssetError::Parse {
+                line: index + 1,
+                message: message.to_owned(),
+            };
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(name) = line.strip_prefix('[').and_then(|rest| rest.strip_suffix(']')) {
+                let name = name.trim();
+                if name.is_empty() {
+                    return Err(error("empty bundle name"));
+                }
+                if manifest.bundle(name).is_none() {
+                    manifest.bundles.push((name.to_owned(), Vec::new()));
+                }
+                bundle = Some(name.to_owned());
+                continue;
+            }
+            let Some(bundle) = &bundle else {
+                return Err(error("asset outside of a bundle"));
+            };
+            let Some((key, path)) = line.split_once('=') else {
+                return Err(error("expected `kind name = path`"));
+            };
+            let Some((kind, name)) = key.trim().split_once(char::is_whitespace) else {
+                return Err(error("expected `kind name = path`"));
+            };
+            let kind = AssetKind::from_name(kind).ok_or_else(|| error("unknown asset kind"))?;
+            let (name, path) = (name.trim(), path.trim());
+            if path.is_empty() {
+                return Err(error("missing path"));
+            }
+            manifest.add(bundle, kind, name, path);
+        }
+        Ok(manifest)
+    }
+
+    /// Reads and parses the manifest file at `path`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::assets::AssetManifest;
+    /// assert!(AssetManifest::load("missing.manifest").is_err());
+    /// ```
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, AssetError> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    /// Reads and parses the manifest at `path` in `source`, e.g. an asset pack.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::assets::AssetManifest;
+    /// # use deckbuilder_eng::vfs::{PakBuilder, PakSource};
+    /// let pak = PakBuilder::new().add("assets.manifest", b"[menu]\n".to_vec()).to_bytes();
+    /// let pak = PakSource::from_bytes(pak).unwrap();
+    /// let manifest = AssetManifest::load_from(&pak, "assets.manifest").unwrap();
+    /// assert_eq!(manifest.bundle_names(), ["menu"]);
+    /// ```
+    pub fn load_from(source: &dyn AssetSource, path: impl AsRef<Path>) -> Result<Self, AssetError> {
+        Self::parse(&source.read_to_string(path.as_ref())?)
+    }
+
+    /// Adds an asset to `bundle`, creating the bundle if needed.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::assets::{AssetKind, AssetManifest};
+    /// let mut manifest = AssetManifest::new();
+    /// manifest.add("map", AssetKind::Texture, "elite", "assets/map/elite.png");
+    /// assert_eq!(manifest.bundle("map").unwrap().len(), 1);
+    /// ```
+    pub fn add(&mut self, bundle: &str, kind: AssetKind, name: &str, path: impl Into<PathBuf>) {
+        let entry = AssetEntry {
+            kind,
+            name: name.to_owned(),
+            path: path.into(),
+        };
+        match self.bundles.iter_mut().find(|(name, _)| name == bundle) {
+            Some((_, entries)) => entries.push(entry),
+            None => self.bundles.push((bundle.to_owned(), vec![entry])),
+        }
+    }
+
+    /// Returns the assets of `bundle`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::assets::AssetManifest;
+    /// assert!(AssetManifest::new().bundle("combat").is_none());
+    /// ```
+    pub fn bundle(&self, name: &str) -> Option<&[AssetEntry]> {
+        self.bundles.iter().find(|(bundle, _)| bundle == name).map(|(_, entries)| entries.as_slice())
+    }
+
+    /// Returns the bundle names in manifest order.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::assets::{AssetKind, AssetManifest};
+    /// let mut manifest = AssetManifest::new();
+    /// manifest.add("shop", AssetKind::Music, "shop", "assets/music/shop.ogg");
+    /// assert_eq!(manifest.bundle_names(), ["shop"]);
+    /// ```
+    pub fn bundle_names(&self) -> Vec<&str> {
+        self.bundles.iter().map(|(name, _)| name.as_str()).collect()
+    }
+}
+
+struct BundleLoad {
+    entries: Vec<AssetEntry>,
+    next: usize,
+    failed: Vec<PathBuf>,
+}
+
+/// Loads manifest bundles into a [`TextureManager`] and an optional [`AudioManager`], and
+/// looks assets up by name.
+pub struct AssetManager {
+    textures: TextureManager,
+    #[cfg(feature = "kira")]
+    audio: Option<AudioManager>,
+    source: Option<Arc<dyn AssetSource>>,
+    manifest: AssetManifest,
+    progress: LoadProgress,
+    loading: HashMap<String, BundleLoad>,
+    loaded: Vec<String>,
+    names: HashMap<(AssetKind, String), PathBuf>,
+}
+
+impl AssetManager {
+    /// Creates an asset manager with its own texture cache and no audio.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::assets::{AssetManager, AssetManifest};
+    /// let assets = AssetManager::new(&egui::Context::default(), AssetManifest::new());
+    /// assert!(assets.loaded_bundles().is_empty());
+    /// ```
+    pub fn new(ctx: &Context, manifest: AssetManifest) -> Self {
+        Self::with_textures(TextureManager::new(ctx), manifest)
+    }
+
+    /// Creates an asset manager that uses an existing texture cache.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::assets::{AssetManager, AssetManifest};
+    /// # use deckbuilder_eng::texture::TextureManager;
+    /// let mut textures = TextureManager::new(&egui::Context::default());
+    /// textures.set_budget(Some(64 * 1024 * 1024));
+    /// let assets = AssetManager::with_textures(textures, AssetManifest::new());
+    /// assert!(assets.textures().budget().is_some());
+    /// ```
+    pub fn with_textures(textures: TextureManager, manifest: AssetManifest) -> Self {
+        Self {
+            textures,
+            #[cfg(feature = "kira")]
+            audio: None,
+            source: None,
+            manifest,
+            progress: LoadProgress::new(),
+            loading: HashMap::new(),
+            loaded: Vec::new(),
+            names: HashMap::new(),
+        }
+    }
+
+    /// Sets the audio manager sounds and music are loaded into.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::assets::{AssetManager, AssetManifest};
+    /// # use deckbuilder_eng::audio::AudioManager;
+    /// let mut assets = AssetManager::new(&egui::Context::default(), AssetManifest::new());
+    /// if let Ok(audio) = AudioManager::new() {
+    ///     assets.set_audio(audio);
+    /// }
+    /// ```
+    #[cfg(feature = "kira")]
+    pub fn set_audio(&mut self, audio: AudioManager) {
+        self.audio = Some(audio);
+    }
+
+    /// Returns the audio manager, if one is set.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::assets::{AssetManager, AssetManifest};
+    /// let assets = AssetManager::new(&egui::Context::default(), AssetManifest::new());
+    /// assert!(assets.audio().is_none());
+    /// ```
+    #[cfg(feature = "kira")]
+    pub fn audio(&self) -> Option<&AudioManager> {
+        self.audio.as_ref()
+    }
+
+    /// Returns the audio manager mutably, e.g. to change volumes.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::assets::{AssetManager, AssetManifest};
+    /// # fn demo(assets: &mut AssetManager) {
+    /// if let Some(audio) = assets.audio_mut() {
+    ///     audio.set_music_volume(0.5).ok();
+    /// }
+    /// # }
+    /// ```
+    #[cfg(feature = "kira")]
+    pub fn audio_mut(&mut self) -> Option<&mut AudioManager> {
+        self.audio.as_mut()
+    }
+
+    /// Sets where textures, sounds and data files are read from, e.g. a
+    /// [`Vfs`](crate::vfs::Vfs) over the shipped asset pack and mod directories. Without a
+    /// source, paths are read from disk.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::sync::Arc;
+    /// # use deckbuilder_eng::assets::{AssetManager, AssetManifest};
+    /// # use deckbuilder_eng::vfs::{DirSource, PakBuilder, PakSource, Vfs};
+    /// let mut vfs = Vfs::new();
+    /// vfs.mount(PakSource::from_bytes(PakBuilder::new().add_dir("assets").unwrap().to_bytes()).unwrap());
+    /// vfs.mount(DirSource::new("mods"));
+    ///
+    /// let manifest = AssetManifest::parse("[combat]\ngif slime = example.gif").unwrap();
+    /// let mut assets = AssetManager::new(&egui::Context::default(), manifest);
+    /// assets.set_source(Arc::new(vfs));
+    /// assets.load_bundle("combat").unwrap();
+    /// assert!(assets.gif("slime", 1.0).is_some());
+    /// ```
+    pub fn set_source(&mut self, source: Arc<dyn AssetSource>) {
+        self.textures.set_source(source.clone());
+        self.source = Some(source);
+    }
+
+    /// Reads a data file (card database, localization, ...) from the source, or from disk
+    /// without one.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::assets::{AssetManager, AssetManifest};
+    /// let assets = AssetManager::new(&egui::Context::default(), AssetManifest::new());
+    /// assert!(assets.read("assets/sfx/click.wav").is_ok());
+    /// ```
+    pub fn read(&self, path: impl AsRef<Path>) -> io::Result<Vec<u8>> {
+        match &self.source {
+            Some(source) => source.read(path.as_ref()),
+            None => std::fs::read(path),
+        }
+    }
+
+    /// Reads a UTF-8 data file like [`AssetManager::read`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::assets::{AssetManager, AssetManifest};
+    /// let assets = AssetManager::new(&egui::Context::default(), AssetManifest::new());
+    /// assert!(assets.read_to_string("missing/cards.json").is_err());
+    /// ```
+    pub fn read_to_string(&self, path: impl AsRef<Path>) -> io::Result<String> {
+        String::from_utf8(self.read(path)?).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    /// Returns the texture cache.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::assets::{AssetManager, AssetManifest};
+    /// let assets = AssetManager::new(&egui::Context::default(), AssetManifest::new());
+    /// assert!(assets.textures().is_empty());
+    /// ```
+    pub fn textures(&self) -> &TextureManager {
+        &self.textures
+    }
+
+    /// Returns the texture cache mutably, e.g. to load textures outside of any bundle.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::assets::{AssetManager, AssetManifest};
+    /// let mut assets = AssetManager::new(&egui::Context::default(), AssetManifest::new());
+    /// assert!(assets.textures_mut().load("missing.png").is_err());
+    /// ```
+    pub fn textures_mut(&mut self) -> &mut TextureManager {
+        &mut self.textures
+    }
+
+    /// Returns the manifest.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::assets::{AssetManager, AssetManifest};
+    /// let assets = AssetManager::new(&egui::Context::default(), AssetManifest::new());
+    /// assert!(assets.manifest().bundle_names().is_empty());
+    /// ```
+    pub fn manifest(&self) -> &AssetManifest {
+        &self.manifest
+    }
+
+    /// Replaces the manifest. Loaded bundles stay loaded.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::assets::{AssetManager, AssetManifest};
+    /// let mut assets = AssetManager::new(&egui::Context::default(), AssetManifest::new());
+    /// assets.set_manifest(AssetManifest::parse("[menu]\nmusic theme = assets/music/background.ogg").unwrap());
+    /// assert_eq!(assets.manifest().bundle_names(), ["menu"]);
+    /// ```
+    pub fn set_manifest(&mut self, manifest: AssetManifest) {
+        self.manifest = manifest;
+    }
+
+    /// Returns the progress handle bundles report into, one task per bundle weighted by its
+    /// number of assets. Clone it into a loading screen.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::assets::{AssetManager, AssetManifest};
+    /// # use deckbuilder_eng::ui_loading::LoadingScreen;
+    /// # use deckbuilder_eng::ui_screen::{Screen, ScreenAction};
+    /// # struct Combat;
+    /// # impl Screen for Combat {
+    /// #     fn update(&mut self, _ui: &mut egui::Ui) -> ScreenAction { ScreenAction::None }
+    /// # }
+    /// let assets = AssetManager::new(&egui::Context::default(), AssetManifest::new());
+    /// let loading = LoadingScreen::new(assets.progress().clone(), || Box::new(Combat));
+    /// ```
+    pub fn progress(&self) -> &LoadProgress {
+        &self.progress
+    }
+
+    /// Loads every asset of `bundle`. Assets that fail to load are skipped and returned in
+    /// [`AssetError::Load`]; the bundle counts as loaded either way.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::assets::{AssetError, AssetManager, AssetManifest};
+    /// let manifest = AssetManifest::parse("[combat]\ntexture strike = missing.png").unwrap();
+    /// let mut assets = AssetManager::new(&egui::Context::default(), manifest);
+    /// assert!(matches!(assets.load_bundle("combat"), Err(AssetError::Load { .. })));
+    /// assert!(assets.is_loaded("combat"));
+    /// assert!(matches!(assets.load_bundle("shop"), Err(AssetError::UnknownBundle(_))));
+    /// ```
+    pub fn load_bundle(&mut self, bundle: &str) -> Result<(), AssetError> {
+        while self.load_bundle_step(bundle)? < 1.0 {}
+        match self.loading.remove(bundle) {
+            Some(load) if !load.failed.is_empty() => Err(AssetError::Load {
+                bundle: bundle.to_owned(),
+                failed: load.failed,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Loads the next asset of `bundle` and returns the fraction of the bundle done. Returns
+    /// `1.0` once the bundle is loaded; failed assets are listed by [`AssetManager::failed`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::assets::{AssetManager, AssetManifest};
+    /// let manifest = AssetManifest::parse(
+    ///     "[combat]\ntexture a = missing_a.png\ntexture b = missing_b.png",
+    /// )
+    /// .unwrap();
+    /// let mut assets = AssetManager::new(&egui::Context::default(), manifest);
+    /// assert_eq!(assets.load_bundle_step("combat").unwrap(), 0.5);
+    /// assert_eq!(assets.load_bundle_step("combat").unwrap(), 1.0);
+    /// assert_eq!(assets.failed("combat").len(), 2);
+    /// ```
+    pub fn load_bundle_step(&mut self, bundle: &str) -> Result<f32, AssetError> {
+        let _span = tracing::info_span!("load_bundle", bundle).entered();
+        if self.is_loaded(bundle) && !self.loading.contains_key(bundle) {
+            return Ok(1.0);
+        }
+        if !self.loading.contains_key(bundle) {
+            let entries = self
+                .manifest
+                .bundle(bundle)
+                .ok_or_else(|| AssetError::UnknownBundle(bundle.to_owned()))?
+                .to_vec();
+            tracing::debug!(assets = entries.len(), "loading bundle");
+            self.progress.register(bundle, entries.len().max(1) as f32);
+            self.loading.insert(bundle.to_owned(), BundleLoad { entries, next: 0, failed: Vec::new() });
+        }
+        let load = self.loading.get_mut(bundle).expect("bundle load was just inserted");
+        if let Some(entry) = load.entries.get(load.next).cloned() {
+            load.next += 1;
+            match self.load_entry(&entry) {
+                Some(true) => {
+                    self.names.insert((entry.kind, entry.name), entry.path);
+                }
+                Some(false) => self.loading.get_mut(bundle).expect("bundle is loading").failed.push(entry.path),
+                None => {}
+            }
+        }
+        let load = &self.loading[bundle];
+        let fraction = if load.entries.is_empty() {
+            1.0
+        } else {
+            load.next as f32 / load.entries.len() as f32
+        };
+        self.progress.set(bundle, fraction);
+        if fraction >= 1.0 && !self.loaded.iter().any(|name| name == bundle) {
+            tracing::info!(failed = load.failed.len(), "bundle loaded");
+            self.loaded.push(bundle.to_owned());
+        }
+        Ok(fraction)
+    }
+
+    /// Returns the paths of assets of `bundle` that failed to load during
+    /// [`AssetManager::load_bundle_step`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::assets::{AssetManager, AssetManifest};
+    /// let assets = AssetManager::new(&egui::Context::default(), AssetManifest::new());
+    /// assert!(assets.failed("combat").is_empty());
+    /// ```
+    pub fn failed(&self, bundle: &str) -> &[PathBuf] {
+        self.loading.get(bundle).map(|load| load.failed.as_slice()).unwrap_or(&[])
+    }
+
+    /// Unloads `bundle`: textures, GIFs and sounds not used by another loaded bundle are freed
+    /// and their names removed. Returns `false` if the bundle wasn't loaded or loading.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::assets::{AssetManager, AssetManifest};
+    /// let manifest = AssetManifest::parse("[combat]\ngif slime = assets/example.gif").unwrap();
+    /// let mut assets = AssetManager::new(&egui::Context::default(), manifest);
+    /// assets.load_bundle("combat").unwrap();
+    /// assert!(assets.unload_bundle("combat"));
+    /// assert!(!assets.is_loaded("combat"));
+    /// assert!(assets.gif("slime", 1.0).is_none());
+    /// ```
+    pub fn unload_bundle(&mut self, bundle: &str) -> bool {
+        let loading = self.loading.remove(bundle).is_some();
+        let loaded = self.loaded.len();
+        self.loaded.retain(|name| name != bundle);
+        if !loading && self.loaded.len() == loaded {
+            return false;
+        }
+        let Some(entries) = self.manifest.bundle(bundle) else {
+            return true;
+        };
+        let kept: Vec<&AssetEntry> = self
+            .loaded
+            .iter()
+            .chain(self.loading.keys())
+            .filter_map(|name| self.manifest.bundle(name))
+            .flatten()
+            .collect();
+        let kept_names: HashSet<(AssetKind, &str)> = kept.iter().map(|e| (e.kind, e.name.as_str())).collect();
+        let kept_paths: HashSet<&Path> = kept.iter().map(|e| e.path.as_path()).collect();
+        for entry in entries {
+            if kept_names.contains(&(entry.kind, entry.name.as_str())) {
+                continue;
+            }
+            self.names.remove(&(entry.kind, entry.name.clone()));
+            match entry.kind {
+                AssetKind::Texture | AssetKind::Gif if !kept_paths.contains(entry.path.as_path()) => {
+                    self.textures.evict(&entry.path);
+                }
+                #[cfg(feature = "kira")]
+                AssetKind::Sound | AssetKind::Music => {
+                    if let Some(audio) = &mut self.audio {
+                        audio.unload_sound(&entry.name);
+                    }
+                }
+                _ => {}
+            }
+        }
+        true
+    }
+
+    /// Returns `true` once every asset of `bundle` has been attempted.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::assets::{AssetManager, AssetManifest};
+    /// let assets = AssetManager::new(&egui::Context::default(), AssetManifest::new());
+    /// assert!(!assets.is_loaded("combat"));
+    /// ```
+    pub fn is_loaded(&self, bundle: &str) -> bool {
+        self.loaded.iter().any(|name| name == bundle)
+    }
+
+    /// Returns the loaded bundles in load order.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::assets::{AssetManager, AssetManifest};
+    /// let mut assets = AssetManager::new(&egui::Context::default(), AssetManifest::parse("[empty]").unwrap());
+    /// assets.load_bundle("empty").unwrap();
+    /// assert_eq!(assets.loaded_bundles(), ["empty"]);
+    /// ```
+    pub fn loaded_bundles(&self) -> &[String] {
+        &self.loaded
+    }
+
+    /// Returns the path of the loaded asset of `kind` called `name`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::assets::{AssetKind, AssetManager, AssetManifest};
+    /// let manifest = AssetManifest::parse("[map]\ngif boss = assets/example.gif").unwrap();
+    /// let mut assets = AssetManager::new(&egui::Context::default(), manifest);
+    /// assets.load_bundle("map").unwrap();
+    /// assert_eq!(assets.path(AssetKind::Gif, "boss"), Some(std::path::Path::new("assets/example.gif")));
+    /// ```
+    pub fn path(&self, kind: AssetKind, name: &str) -> Option<&Path> {
+        self.names.get(&(kind, name.to_owned())).map(PathBuf::as_path)
+    }
+
+    /// Returns the texture called `name` from a loaded bundle.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::assets::{AssetManager, AssetManifest};
+    /// let assets = AssetManager::new(&egui::Context::default(), AssetManifest::new());
+    /// assert!(assets.texture("strike").is_none());
+    /// ```
+    pub fn texture(&self, name: &str) -> Option<TextureHandle> {
+        self.textures.get(self.path(AssetKind::Texture, name)?)
+    }
+
+    /// Returns the frames and delays of the GIF called `name` from a loaded bundle, with
+    /// delays adjusted for `speed` like [`TextureManager::load_gif`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::assets::{AssetManager, AssetManifest};
+    /// let manifest = AssetManifest::parse("[combat]\ngif slime = assets/example.gif").unwrap();
+    /// let mut assets = AssetManager::new(&egui::Context::default(), manifest);
+    /// assets.load_bundle("combat").unwrap();
+    /// let (frames, delays) = assets.gif("slime", 1.0).unwrap();
+    /// assert_eq!(frames.len(), delays.len());
+    /// ```
+    pub fn gif(&mut self, name: &str, speed: f32) -> Option<(Vec<TextureHandle>, Vec<Duration>)> {
+        let path = self.path(AssetKind::Gif, name)?.to_path_buf();
+        self.textures
+            .load_gif(&path, speed)
+            .inspect_err(|err| tracing::warn!(%err, "failed to load GIF `{}`", name))
+            .ok()
+    }
+
+    /// Returns `true` if a sound or music track called `name` is loaded.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::assets::{AssetManager, AssetManifest};
+    /// let assets = AssetManager::new(&egui::Context::default(), AssetManifest::new());
+    /// assert!(!assets.has_sound("click"));
+    /// ```
+    pub fn has_sound(&self, name: &str) -> bool {
+        self.path(AssetKind::Sound, name).is_some() || self.path(AssetKind::Music, name).is_some()
+    }
+
+    /// Plays the sound effect called `name` at `volume` (0.0 to 1.0).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::assets::{AssetManager, AssetManifest};
+    /// let mut assets = AssetManager::new(&egui::Context::default(), AssetManifest::new());
+    /// // Fails without an audio manager or a loaded sound of that name.
+    /// assert!(assets.play_sound("click", 1.0).is_err());
+    /// ```
+    #[cfg(feature = "kira")]
+    pub fn play_sound(&mut self, name: &str, volume: f32) -> Result<(), crate::Error> {
+        self.audio.as_mut().ok_or_else(|| crate::Error::audio(name, "no audio manager set"))?.play_sound(name, volume)
+    }
+
+    /// Plays the music track called `name` at `volume` (0.0 to 1.0), replacing the current one.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::assets::{AssetManager, AssetManifest};
+    /// let mut assets = AssetManager::new(&egui::Context::default(), AssetManifest::new());
+    /// assert!(assets.play_music("battle", 0.5).is_err());
+    /// ```
+    #[cfg(feature = "kira")]
+    pub fn play_music(&mut self, name: &str, volume: f32) -> Result<(), crate::Error> {
+        self.audio.as_mut().ok_or_else(|| crate::Error::audio(name, "no audio manager set"))?.play_music(name, volume)
+    }
+
+    /// Loads one entry; returns `None` if it was skipped, otherwise whether its file loaded.
+    fn load_entry(&mut self, entry: &AssetEntry) -> Option<bool> {
+        let _span = tracing::debug_span!("load_asset", kind = ?entry.kind, name = %entry.name).entered();
+        let result = match entry.kind {
+            AssetKind::Texture => self.textures.load(&entry.path).map(drop),
+            AssetKind::Gif => self.textures.load_gif(&entry.path, 1.0).map(drop),
+            AssetKind::Sound | AssetKind::Music => self.load_sound_entry(entry)?,
+        };
+        if let Err(err) = &result {
+            tracing::warn!(%err, "asset failed to load");
+        }
+        Some(result.is_ok())
+    }
+
+    /// Loads a sound or music entry; skipped without an audio manager.
+    #[cfg(feature = "kira")]
+    fn load_sound_entry(&mut self, entry: &AssetEntry) -> Option<Result<(), crate::Error>> {
+        let audio = self.audio.as_mut()?;
+        let music = entry.kind == AssetKind::Music;
+        let track = if music { audio.music_track().id() } else { audio.sfx_track().id() };
+        Some(match self.source.as_deref() {
+            Some(source) => source
+                .read(&entry.path)
+                .map_err(|err| crate::Error::io(&entry.path, err))
+                .and_then(|bytes| audio.load_sound_from_bytes(bytes, &entry.name, track, music)),
+            None => audio.load_sound(&entry.path.to_string_lossy(), &entry.name, track, music),
+        })
+    }
+
+    /// Sounds and music are always skipped without the `kira` feature.
+    #[cfg(not(feature = "kira"))]
+    fn load_sound_entry(&mut self, _entry: &AssetEntry) -> Option<Result<(), crate::Error>> {
+        None
+    }
+}
@@ -1,6 +1,7 @@
 //! Audio management for deckbuilder_eng.
 //!
-//! Provides the [`AudioManager`] type for loading, playing, and controlling background music and sound effects using the `kira` audio engine.
+//! Provides the [`AudioManager`] type for loading, playing, and controlling background music and sound effects using the `kira` audio engine,
+//! and [`AudioCues`] to play sounds in reaction to game events.
 //!
 //! # Example
 //!
@@ -31,23 +32,37 @@
 //!
 //! - Music and SFX are handled on separate tracks.
 //! - Supports volume, panning, pitch, fade, crossfade, and more.
+//! - Fallible calls return [`crate::Error::Audio`] with the sound's name, or `music`/`sfx` for
+//!   track-wide calls.
+//! - [`AudioCues`] maps [`EventKind`]s to loaded sounds; call [`AudioCues::play`] from an
+//!   [`EventBus`](crate::events::EventBus) listener.
+//! - Loads run in `load_sound` tracing spans, and playback is logged at `debug`/`trace` level
+//!   (see [`crate::logging`]).
+//! - In the browser, files can't be read: load sounds with
+//!   [`AudioManager::load_sound_from_bytes`] (e.g. from a fetched asset pack), and create the
+//!   manager after the first click or key press, since browsers keep audio suspended until then.
 //! - See each method's documentation for advanced usage and error handling.
 
 #![warn(missing_docs)]
 
 use std::collections::HashMap;
-use std::error::Error;
 use std::time::Duration;
 
 use kira::{
     manager::{AudioManager as KiraManager, AudioManagerSettings},
-    sound::static_sound::{StaticSoundData, StaticSoundHandle, StaticSoundSettings},
+    sound::{
+        static_sound::{StaticSoundData, StaticSoundHandle, StaticSoundSettings},
+        PlaybackState,
+    },
     track::TrackHandle,
     track::TrackId,
     tween::{Tween, Easing},
     OutputDestination,
 };
 
+use crate::error::{audio_error, Error, Result};
+use crate::events::{EventKind, GameEvent};
+
 /// Manages audio playback for music and sound effects.
 ///
 /// # Example
@@ -61,6 +76,8 @@ pub struct AudioManager {
     sfx_track: TrackHandle,
     sounds: HashMap<String, StaticSoundData>,
     current_music: Option<StaticSoundHandle>,
+    sfx_handles: Vec<StaticSoundHandle>,
+    playback_rate: f64,
 }
 
 impl AudioManager {
@@ -71,22 +88,24 @@ impl AudioManager {
     /// use deckbuilder_eng::audio::AudioManager;
     /// let mut audio = AudioManager::new().unwrap();
     /// ```
-    pub fn new() -> Result<Self, Box<dyn Error>> {
-        let mut kira_manager = KiraManager::new(AudioManagerSettings::default())?;
-        let music_track = kira_manager.add_sub_track(kira::track::TrackBuilder::new())?;
-        let sfx_track = kira_manager.add_sub_track(kira::track::TrackBuilder::new())?;
+    pub fn new() -> Result<Self> {
+        let mut kira_manager = KiraManager::new(AudioManagerSettings::default()).map_err(audio_error("output"))?;
+        let music_track = kira_manager.add_sub_track(kira::track::TrackBuilder::new()).map_err(audio_error("output"))?;
+        let sfx_track = kira_manager.add_sub_track(kira::track::TrackBuilder::new()).map_err(audio_error("output"))?;
         Ok(Self {
             kira_manager,
             music_track,
             sfx_track,
             sounds: HashMap::new(),
             current_music: None,
+            sfx_handles: Vec::new(),
+            playback_rate: 1.0,
         })
     }
 
     /// Loads a static sound from `path`, registers it under `name`
     /// and assigns it to the given `track_id`. If `loop_sound` is true,
-    /// the sound will loop indefinitely.
+    /// the sound will loop indefinitely. Always fails on `wasm32`, which has no filesystem.
     ///
     /// # Example
     /// ```
@@ -101,13 +120,43 @@ impl AudioManager {
         name: &str,
         track_id: TrackId,
         loop_sound: bool,
-    ) -> Result<(), Box<dyn Error>> {
-        let mut settings = StaticSoundSettings::default();
-        settings.output_destination = OutputDestination::Track(track_id);
-        if loop_sound {
-            settings.loop_region = Some(kira::sound::Region::default());
+    ) -> Result<()> {
+        let _span = tracing::debug_span!("load_sound", name, path).entered();
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let settings = sound_settings(track_id, loop_sound);
+            let sound_data = StaticSoundData::from_file(path, settings).map_err(audio_error(name))?;
+            self.sounds.insert(name.to_string(), sound_data);
+            Ok(())
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            let _ = (path, track_id, loop_sound);
+            Err(Error::audio(name, "files can't be read in the browser; use load_sound_from_bytes"))
         }
-        let sound_data = StaticSoundData::from_file(path, settings)?;
+    }
+
+    /// Like [`AudioManager::load_sound`], but decodes the sound from `bytes` (an encoded WAV,
+    /// OGG, MP3 or FLAC file), e.g. one read from an asset pack.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::audio::AudioManager;
+    /// # let mut audio = AudioManager::new().unwrap();
+    /// let bytes = std::fs::read("assets/sfx/click.wav").unwrap();
+    /// let sfx_id = audio.sfx_track().id();
+    /// audio.load_sound_from_bytes(bytes, "click", sfx_id, false).unwrap();
+    /// ```
+    pub fn load_sound_from_bytes(
+        &mut self,
+        bytes: Vec<u8>,
+        name: &str,
+        track_id: TrackId,
+        loop_sound: bool,
+    ) -> Result<()> {
+        let _span = tracing::debug_span!("load_sound", name, bytes = bytes.len()).entered();
+        let settings = sound_settings(track_id, loop_sound);
+        let sound_data = StaticSoundData::from_cursor(std::io::Cursor::new(bytes), settings).map_err(audio_error(name))?;
         self.sounds.insert(name.to_string(), sound_data);
         Ok(())
     }
@@ -123,13 +172,18 @@ impl AudioManager {
     /// # audio.load_sound("assets/click.wav", "click", sfx_id, false).unwrap();
     /// audio.play_sound("click", 1.0).unwrap();
     /// ```
-    pub fn play_sound(&mut self, name: &str, volume: f32) -> Result<(), Box<dyn Error>> {
+    pub fn play_sound(&mut self, name: &str, volume: f32) -> Result<()> {
+        tracing::trace!(name, volume, "play sound");
         if let Some(sound_data) = self.sounds.get(name) {
-            let mut handle = self.kira_manager.play(sound_data.clone())?;
-            handle.set_volume(volume as f64, Tween::default())?;
+            let rate = self.playback_rate;
+            let mut handle = self
+                .kira_manager
+                .play(sound_data.with_modified_settings(|s| s.playback_rate(rate))).map_err(audio_error(name))?;
+            handle.set_volume(volume as f64, Tween::default()).map_err(audio_error(name))?;
+            self.track_sfx(handle);
             Ok(())
         } else {
-            Err(format!("Ses '{}' bulunamadı", name).into())
+            Err(Error::audio(name, "sound is not loaded"))
         }
     }
 
@@ -144,17 +198,21 @@ impl AudioManager {
     /// # audio.load_sound("assets/music.ogg", "bgm", music_id, true).unwrap();
     /// audio.play_music("bgm", 0.5).unwrap();
     /// ```
-    pub fn play_music(&mut self, name: &str, volume: f32) -> Result<(), Box<dyn Error>> {
+    pub fn play_music(&mut self, name: &str, volume: f32) -> Result<()> {
+        tracing::debug!(name, volume, "play music");
         if let Some(sound_data) = self.sounds.get(name) {
             if let Some(mut current) = self.current_music.take() {
-                current.stop(Tween::default())?;
+                current.stop(Tween::default()).map_err(audio_error("music"))?;
             }
-            let mut handle = self.kira_manager.play(sound_data.clone())?;
-            handle.set_volume(volume as f64, Tween::default())?;
+            let rate = self.playback_rate;
+            let mut handle = self
+                .kira_manager
+                .play(sound_data.with_modified_settings(|s| s.playback_rate(rate))).map_err(audio_error(name))?;
+            handle.set_volume(volume as f64, Tween::default()).map_err(audio_error(name))?;
             self.current_music = Some(handle);
             Ok(())
         } else {
-            Err(format!("Müzik '{}' bulunamadı", name).into())
+            Err(Error::audio(name, "music is not loaded"))
         }
     }
 
@@ -166,9 +224,9 @@ impl AudioManager {
     /// # let mut audio = AudioManager::new().unwrap();
     /// audio.stop_music().unwrap();
     /// ```
-    pub fn stop_music(&mut self) -> Result<(), Box<dyn Error>> {
+    pub fn stop_music(&mut self) -> Result<()> {
         if let Some(mut handle) = self.current_music.take() {
-            handle.stop(Tween::default())?;
+            handle.stop(Tween::default()).map_err(audio_error("music"))?;
         }
         Ok(())
     }
@@ -181,8 +239,8 @@ impl AudioManager {
     /// # let mut audio = AudioManager::new().unwrap();
     /// audio.set_music_volume(0.7).unwrap();
     /// ```
-    pub fn set_music_volume(&mut self, volume: f32) -> Result<(), Box<dyn Error>> {
-        self.music_track.set_volume(volume as f64, Tween::default())?;
+    pub fn set_music_volume(&mut self, volume: f32) -> Result<()> {
+        self.music_track.set_volume(volume as f64, Tween::default()).map_err(audio_error("music"))?;
         Ok(())
     }
 
@@ -194,8 +252,8 @@ impl AudioManager {
     /// # let mut audio = AudioManager::new().unwrap();
     /// audio.set_sfx_volume(0.3).unwrap();
     /// ```
-    pub fn set_sfx_volume(&mut self, volume: f32) -> Result<(), Box<dyn Error>> {
-        self.sfx_track.set_volume(volume as f64, Tween::default())?;
+    pub fn set_sfx_volume(&mut self, volume: f32) -> Result<()> {
+        self.sfx_track.set_volume(volume as f64, Tween::default()).map_err(audio_error("sfx"))?;
         Ok(())
     }
 
@@ -232,7 +290,7 @@ impl AudioManager {
     /// # let mut audio = AudioManager::new().unwrap();
     /// audio.set_music_pitch(1.2).unwrap();
     /// ```
-    pub fn set_music_pitch(&mut self, _speed: f32) -> Result<(), Box<dyn Error>> {
+    pub fn set_music_pitch(&mut self, _speed: f32) -> Result<()> {
         // pitch control not supported in this build
         Ok(())
     }
@@ -245,9 +303,9 @@ impl AudioManager {
     /// # let mut audio = AudioManager::new().unwrap();
     /// audio.pause_music().unwrap();
     /// ```
-    pub fn pause_music(&mut self) -> Result<(), Box<dyn Error>> {
+    pub fn pause_music(&mut self) -> Result<()> {
         if let Some(handle) = &mut self.current_music {
-            handle.pause(Tween::default())?;
+            handle.pause(Tween::default()).map_err(audio_error("music"))?;
         }
         Ok(())
     }
@@ -260,9 +318,9 @@ impl AudioManager {
     /// # let mut audio = AudioManager::new().unwrap();
     /// audio.resume_music().unwrap();
     /// ```
-    pub fn resume_music(&mut self) -> Result<(), Box<dyn Error>> {
+    pub fn resume_music(&mut self) -> Result<()> {
         if let Some(handle) = &mut self.current_music {
-            handle.resume(Tween::default())?;
+            handle.resume(Tween::default()).map_err(audio_error("music"))?;
         }
         Ok(())
     }
@@ -275,9 +333,9 @@ impl AudioManager {
     /// # let mut audio = AudioManager::new().unwrap();
     /// audio.fade_out_music(std::time::Duration::from_secs(2)).unwrap();
     /// ```
-    pub fn fade_out_music(&mut self, duration: Duration) -> Result<(), Box<dyn Error>> {
+    pub fn fade_out_music(&mut self, duration: Duration) -> Result<()> {
         if let Some(handle) = &mut self.current_music {
-            handle.stop(Tween { duration, easing: Easing::Linear, ..Default::default() })?;
+            handle.stop(Tween { duration, easing: Easing::Linear, ..Default::default() }).map_err(audio_error("music"))?;
         }
         Ok(())
     }
@@ -290,9 +348,9 @@ impl AudioManager {
     /// # let mut audio = AudioManager::new().unwrap();
     /// audio.set_music_pan(-0.5).unwrap();
     /// ```
-    pub fn set_music_pan(&mut self, pan: f32) -> Result<(), Box<dyn Error>> {
+    pub fn set_music_pan(&mut self, pan: f32) -> Result<()> {
         if let Some(handle) = &mut self.current_music {
-            handle.set_panning(pan as f64, Tween::default())?;
+            handle.set_panning(pan as f64, Tween::default()).map_err(audio_error("music"))?;
         }
         Ok(())
     }
@@ -349,10 +407,13 @@ impl AudioManager {
         name: &str,
         target_volume: f32,
         duration: Duration,
-    ) -> Result<(), Box<dyn Error>> {
+    ) -> Result<()> {
         if let Some(data) = self.sounds.get(name) {
-            let mut handle = self.kira_manager.play(data.clone())?;
-            handle.set_volume(0.0, Tween::default())?;
+            let rate = self.playback_rate;
+            let mut handle = self
+                .kira_manager
+                .play(data.with_modified_settings(|s| s.playback_rate(rate))).map_err(audio_error(name))?;
+            handle.set_volume(0.0, Tween::default()).map_err(audio_error(name))?;
             handle.set_volume(
                 target_volume as f64,
                 Tween {
@@ -360,10 +421,11 @@ impl AudioManager {
                     easing: Easing::Linear,
                     ..Default::default()
                 },
-            )?;
+            ).map_err(audio_error(name))?;
+            self.track_sfx(handle);
             Ok(())
         } else {
-            Err(format!("Ses '{}' bulunamadı", name).into())
+            Err(Error::audio(name, "sound is not loaded"))
         }
     }
 
@@ -382,14 +444,18 @@ impl AudioManager {
         name: &str,
         volume: f32,
         pan: f32,
-    ) -> Result<(), Box<dyn Error>> {
+    ) -> Result<()> {
         if let Some(data) = self.sounds.get(name) {
-            let mut handle = self.kira_manager.play(data.clone())?;
-            handle.set_volume(volume as f64, Tween::default())?;
-            handle.set_panning(pan as f64, Tween::default())?;
+            let rate = self.playback_rate;
+            let mut handle = self
+                .kira_manager
+                .play(data.with_modified_settings(|s| s.playback_rate(rate))).map_err(audio_error(name))?;
+            handle.set_volume(volume as f64, Tween::default()).map_err(audio_error(name))?;
+            handle.set_panning(pan as f64, Tween::default()).map_err(audio_error(name))?;
+            self.track_sfx(handle);
             Ok(())
         } else {
-            Err(format!("Ses '{}' bulunamadı", name).into())
+            Err(Error::audio(name, "sound is not loaded"))
         }
     }
 
@@ -410,19 +476,22 @@ impl AudioManager {
         name: &str,
         target_volume: f32,
         duration: Duration,
-    ) -> Result<(), Box<dyn Error>> {
+    ) -> Result<()> {
         // eskiyi fade-out
         if let Some(mut old) = self.current_music.take() {
             old.stop(Tween {
                 duration,
                 easing: Easing::Linear,
                 ..Default::default()
-            })?;
+            }).map_err(audio_error("music"))?;
         }
         // yeniyi fade-in ile başlat
         if let Some(data) = self.sounds.get(name) {
-            let mut handle = self.kira_manager.play(data.clone())?;
-            handle.set_volume(0.0, Tween::default())?;
+            let rate = self.playback_rate;
+            let mut handle = self
+                .kira_manager
+                .play(data.with_modified_settings(|s| s.playback_rate(rate))).map_err(audio_error(name))?;
+            handle.set_volume(0.0, Tween::default()).map_err(audio_error(name))?;
             handle.set_volume(
                 target_volume as f64,
                 Tween {
@@ -430,11 +499,11 @@ impl AudioManager {
                     easing: Easing::Linear,
                     ..Default::default()
                 },
-            )?;
+            ).map_err(audio_error(name))?;
             self.current_music = Some(handle);
             Ok(())
         } else {
-            Err(format!("Müzik '{}' bulunamadı", name).into())
+            Err(Error::audio(name, "music is not loaded"))
         }
     }
 
@@ -451,7 +520,7 @@ impl AudioManager {
         &mut self,
         target_volume: f32,
         duration: Duration,
-    ) -> Result<(), Box<dyn Error>> {
+    ) -> Result<()> {
         self.music_track.set_volume(
             target_volume as f64,
             Tween {
@@ -459,7 +528,7 @@ impl AudioManager {
                 easing: Easing::Linear,
                 ..Default::default()
             },
-        )?;
+        ).map_err(audio_error("music"))?;
         Ok(())
     }
 
@@ -471,7 +540,7 @@ impl AudioManager {
     /// # let mut audio = AudioManager::new().unwrap();
     /// audio.fade_out_sfx(std::time::Duration::from_secs(1)).unwrap();
     /// ```
-    pub fn fade_out_sfx(&mut self, duration: Duration) -> Result<(), Box<dyn Error>> {
+    pub fn fade_out_sfx(&mut self, duration: Duration) -> Result<()> {
         self.sfx_track.set_volume(
             0.0,
             Tween {
@@ -479,7 +548,141 @@ impl AudioManager {
                 easing: Easing::Linear,
                 ..Default::default()
             },
-        )?;
+        ).map_err(audio_error("sfx"))?;
+        Ok(())
+    }
+
+    /// Changes the playback rate of all music and sound effects at once,
+    /// tweening currently playing sounds to `rate` with `tween`.
+    /// Sounds started afterwards also use this rate until it is changed again.
+    /// `1.0` is normal speed, lower values slow down and lower the pitch.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::audio::AudioManager;
+    /// # use kira::tween::Tween;
+    /// # let mut audio = AudioManager::new().unwrap();
+    /// // slow-motion "big hit"
+    /// audio.set_global_playback_rate(0.5, Tween::default()).unwrap();
+    /// // smoothly back to normal
+    /// audio
+    ///     .set_global_playback_rate(
+    ///         1.0,
+    ///         Tween { duration: std::time::Duration::from_millis(500), ..Default::default() },
+    ///     )
+    ///     .unwrap();
+    /// ```
+    pub fn set_global_playback_rate(&mut self, rate: f32, tween: Tween) -> Result<()> {
+        self.playback_rate = rate as f64;
+        if let Some(handle) = &mut self.current_music {
+            handle.set_playback_rate(self.playback_rate, tween).map_err(audio_error("music"))?;
+        }
+        self.sfx_handles.retain(|h| h.state() != PlaybackState::Stopped);
+        for handle in &mut self.sfx_handles {
+            handle.set_playback_rate(self.playback_rate, tween).map_err(audio_error("sfx"))?;
+        }
         Ok(())
     }
-}
\ No newline at end of file
+
+    /// Returns the current global playback rate (`1.0` = normal speed).
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::audio::AudioManager;
+    /// # let audio = AudioManager::new().unwrap();
+    /// let rate = audio.global_playback_rate();
+    /// ```
+    pub fn global_playback_rate(&self) -> f32 {
+        self.playback_rate as f32
+    }
+
+    /// Keeps the handle of a started sound effect so later global changes reach it.
+    fn track_sfx(&mut self, handle: StaticSoundHandle) {
+        self.sfx_handles.retain(|h| h.state() != PlaybackState::Stopped);
+        self.sfx_handles.push(handle);
+    }
+}
+
+/// Settings that send a sound to `track_id`, looping it over its whole length if `loop_sound`.
+fn sound_settings(track_id: TrackId, loop_sound: bool) -> StaticSoundSettings {
+    let mut settings = StaticSoundSettings::default();
+    settings.output_destination = OutputDestination::Track(track_id);
+    if loop_sound {
+        settings.loop_region = Some(kira::sound::Region::default());
+    }
+    settings
+}
+
+/// Sounds played in reaction to [`GameEvent`]s, one per event kind.
+///
+/// # Example
+/// ```rust
+/// use deckbuilder_eng::audio::AudioCues;
+/// use deckbuilder_eng::events::EventKind;
+///
+/// let cues = AudioCues::new()
+///     .cue(EventKind::Damage, "hit", 0.8)
+///     .cue(EventKind::Heal, "heal", 0.6);
+/// assert_eq!(cues.sound_for(EventKind::Damage), Some(("hit", 0.8)));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AudioCues {
+    cues: HashMap<EventKind, (String, f32)>,
+}
+
+impl AudioCues {
+    /// Creates an empty set of cues.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::audio::AudioCues;
+    /// let cues = AudioCues::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Plays the loaded sound `name` at `volume` for events of `kind`, replacing any earlier cue.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::audio::AudioCues;
+    /// # use deckbuilder_eng::events::EventKind;
+    /// let cues = AudioCues::new().cue(EventKind::TurnStarted, "turn_bell", 1.0);
+    /// ```
+    pub fn cue(mut self, kind: EventKind, name: impl Into<String>, volume: f32) -> Self {
+        self.cues.insert(kind, (name.into(), volume));
+        self
+    }
+
+    /// Returns the sound and volume played for events of `kind`.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::audio::AudioCues;
+    /// # use deckbuilder_eng::events::EventKind;
+    /// assert!(AudioCues::new().sound_for(EventKind::Damage).is_none());
+    /// ```
+    pub fn sound_for(&self, kind: EventKind) -> Option<(&str, f32)> {
+        self.cues.get(&kind).map(|(name, volume)| (name.as_str(), *volume))
+    }
+
+    /// Plays the cue of `event` as a sound effect. Returns false if its kind has no cue.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::audio::{AudioCues, AudioManager};
+    /// # use deckbuilder_eng::events::{GameEvent, Target};
+    /// # fn demo(audio: &mut AudioManager) {
+    /// let cues = AudioCues::new().cue(deckbuilder_eng::events::EventKind::Damage, "hit", 0.8);
+    /// let played = cues.play(audio, &GameEvent::Damage { target: Target::Enemy, amount: 6 }).unwrap();
+    /// assert!(played);
+    /// # }
+    /// ```
+    pub fn play(&self, audio: &mut AudioManager, event: &GameEvent) -> Result<bool> {
+        match self.sound_for(event.kind()) {
+            Some((name, volume)) => audio.play_sound(name, volume).map(|()| true),
+            None => Ok(false),
+        }
+    }
+}
@@ -24,12 +24,18 @@
 //!
 //! # Details
 //!
-//! - `Card` is the basic card data structure.
+//! - `Card` is the basic card data structure; `Rarity` classifies cards for rewards and filters.
+//! - `Keyword` pairs a rules keyword with its explanation (for tooltips).
+//! - `Relic` is a passive item owned for the rest of the run.
 //! - `Deck` manages draw/discard piles and card operations.
-//! - `GameContext` tracks player/enemy health, energy, and turn.
+//! - `GameContext` tracks player/enemy health, energy, and turn, and records a
+//!   [`GameEvent`] for each change for the event bus.
 //! - `Playable` trait allows custom card effects.
 //! - See each struct and function's documentation for more.
 
+use crate::events::{GameEvent, Target};
+use crate::stats::{self, Counter};
+
 /// Unique identifier for each card.
 pub type CardId = u32;
 
@@ -41,6 +47,15 @@ pub enum CardType {
     Power,
 }
 
+/// Card rarity, used for rewards and filtering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub enum Rarity {
+    #[default]
+    Common,
+    Uncommon,
+    Rare,
+}
+
 /// Basic card data.
 #[derive(Debug, Clone)]
 pub struct Card {
@@ -49,6 +64,7 @@ pub struct Card {
     pub description: String,
     pub cost: u32,
     pub card_type: CardType,
+    pub rarity: Rarity,
 }
 
 impl Card {
@@ -79,10 +95,84 @@ impl Card {
             description: description.into(),
             cost,
             card_type,
+            rarity: Rarity::Common,
+        }
+    }
+
+    /// Returns the card with the given rarity. Cards are `Common` by default.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card::{Card, CardType, Rarity};
+    /// let card = Card::new(7, "Bludgeon", "Deal 32 damage", 3, CardType::Attack).with_rarity(Rarity::Rare);
+    /// assert_eq!(card.rarity, Rarity::Rare);
+    /// ```
+    pub fn with_rarity(mut self, rarity: Rarity) -> Self {
+        self.rarity = rarity;
+        self
+    }
+}
+
+/// A game keyword (e.g. "Exhaust", "Vulnerable") with its rules explanation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Keyword {
+    pub name: String,
+    pub description: String,
+}
+
+impl Keyword {
+    /// Creates a new `Keyword`.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card::Keyword;
+    /// let exhaust = Keyword::new("Exhaust", "Removed from your deck until end of combat.");
+    /// ```
+    pub fn new(name: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: description.into(),
         }
     }
 }
 
+/// A relic: a passive item the player keeps for the rest of the run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Relic {
+    pub name: String,
+    pub description: String,
+    pub rarity: Rarity,
+}
+
+impl Relic {
+    /// Creates a new common `Relic`.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card::Relic;
+    /// let relic = Relic::new("Anchor", "Start each combat with 10 block.");
+    /// ```
+    pub fn new(name: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: description.into(),
+            rarity: Rarity::Common,
+        }
+    }
+
+    /// Sets the relic's rarity.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card::{Rarity, Relic};
+    /// let relic = Relic::new("Ice Cream", "Energy is conserved between turns.").with_rarity(Rarity::Rare);
+    /// ```
+    pub fn with_rarity(mut self, rarity: Rarity) -> Self {
+        self.rarity = rarity;
+        self
+    }
+}
+
 /// Deck holding draw and discard piles.
 pub struct Deck {
     pub draw_pile: Vec<Card>,
@@ -114,6 +204,7 @@ impl Deck {
     /// deck.shuffle();
     /// ```
     pub fn shuffle(&mut self) {
+        stats::add(Counter::Shuffles, 1);
         self.draw_pile.append(&mut self.discard_pile);
         self.draw_pile.reverse();
     }
@@ -127,12 +218,17 @@ impl Deck {
     /// let card = deck.draw();
     /// ```
     pub fn draw(&mut self) -> Option<Card> {
-        if let Some(card) = self.draw_pile.pop() {
-            Some(card)
-        } else {
-            self.shuffle();
-            self.draw_pile.pop()
+        let card = match self.draw_pile.pop() {
+            Some(card) => Some(card),
+            None => {
+                self.shuffle();
+                self.draw_pile.pop()
+            }
+        };
+        if card.is_some() {
+            stats::add(Counter::CardsDrawn, 1);
         }
+        card
     }
 
     /// Discards a card by moving it into the discard pile.
@@ -213,7 +309,7 @@ impl Deck {
     where
         F: Fn(&Card) -> bool,
     {
-        if let Some(pos) = self.draw_pile.iter().position(|c| predicate(c)) {
+        if let Some(pos) = self.draw_pile.iter().position(predicate) {
             Some(self.draw_pile.remove(pos))
         } else {
             None
@@ -235,11 +331,18 @@ impl Deck {
 }
 
 /// Game context holding player/enemy health, energy, and turn.
+///
+/// Damage, heals, energy, turns and defeats are recorded as [`GameEvent`]s until
+/// [`GameContext::take_events`] drains them. The engine does that every frame for its battle;
+/// other callers must drain them too, or turn recording off with
+/// [`GameContext::record_events`], e.g. in a headless simulator, so they don't pile up.
 pub struct GameContext {
     pub player_health: i32,
     pub enemy_health: i32,
     pub energy: u32,
     pub turn: u32, // current turn number
+    events: Vec<GameEvent>,
+    record_events: bool,
 }
 
 impl GameContext {
@@ -256,9 +359,27 @@ impl GameContext {
             enemy_health,
             energy: 0,
             turn: 1,
+            events: Vec::new(),
+            record_events: true,
         }
     }
 
+    /// Sets whether events are recorded (the default) for [`GameContext::take_events`].
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card::GameContext;
+    /// // A balance simulation that never reads the events
+    /// let mut ctx = GameContext::new(30, 30).record_events(false);
+    /// ctx.deal_damage(5);
+    /// assert!(ctx.events().is_empty());
+    /// ```
+    pub fn record_events(mut self, record: bool) -> Self {
+        self.record_events = record;
+        self.events.clear();
+        self
+    }
+
     /// Deals damage to the enemy, reducing their health by `amount`.
     ///
     /// # Example
@@ -268,7 +389,12 @@ impl GameContext {
     /// ctx.deal_damage(5);
     /// ```
     pub fn deal_damage(&mut self, amount: i32) {
+        let alive = self.enemy_health > 0;
         self.enemy_health -= amount;
+        self.emit(GameEvent::Damage { target: Target::Enemy, amount });
+        if alive && self.enemy_health <= 0 {
+            self.emit(GameEvent::Defeated { target: Target::Enemy });
+        }
     }
     /// Heals the player, increasing their health by `amount`.
     ///
@@ -280,6 +406,7 @@ impl GameContext {
     /// ```
     pub fn heal(&mut self, amount: i32) {
         self.player_health += amount;
+        self.emit(GameEvent::Heal { target: Target::Player, amount });
     }
 
     /// Attempts to spend `amount` energy; returns `true` if successful.
@@ -293,6 +420,7 @@ impl GameContext {
     pub fn spend_energy(&mut self, amount: u32) -> bool {
         if self.energy >= amount {
             self.energy -= amount;
+            self.emit(GameEvent::EnergySpent { amount });
             true
         } else {
             false
@@ -322,6 +450,53 @@ impl GameContext {
     pub fn new_turn(&mut self, max_energy: u32) {
         self.turn += 1;
         self.energy = max_energy;
+        self.emit(GameEvent::TurnStarted { turn: self.turn });
+    }
+
+    /// Records an event, e.g. from a card effect, for the next [`GameContext::take_events`]. Does
+    /// nothing if recording is turned off.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card::GameContext;
+    /// # use deckbuilder_eng::events::{GameEvent, Target};
+    /// # let mut ctx = GameContext::new(30, 30);
+    /// ctx.emit(GameEvent::Block { target: Target::Player, amount: 5 });
+    /// assert_eq!(ctx.events().len(), 1);
+    /// ```
+    pub fn emit(&mut self, event: GameEvent) {
+        if self.record_events {
+            self.events.push(event);
+        }
+    }
+
+    /// Returns the events recorded since the last [`GameContext::take_events`].
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card::GameContext;
+    /// # use deckbuilder_eng::events::{GameEvent, Target};
+    /// # let mut ctx = GameContext::new(30, 30);
+    /// ctx.heal(3);
+    /// assert_eq!(ctx.events(), [GameEvent::Heal { target: Target::Player, amount: 3 }]);
+    /// ```
+    pub fn events(&self) -> &[GameEvent] {
+        &self.events
+    }
+
+    /// Removes and returns the recorded events, e.g. to pass them to an
+    /// [`EventBus`](crate::events::EventBus).
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card::GameContext;
+    /// # let mut ctx = GameContext::new(30, 30);
+    /// ctx.new_turn(3);
+    /// assert_eq!(ctx.take_events().len(), 1);
+    /// assert!(ctx.events().is_empty());
+    /// ```
+    pub fn take_events(&mut self) -> Vec<GameEvent> {
+        std::mem::take(&mut self.events)
     }
 }
 
@@ -0,0 +1,672 @@
+//! Card definitions for deckbuilder games.
+//!
+//! Provides [`CardDatabase`], a game's cards with their effects and keywords, read from and
+//! written to JSON or RON files, and validation that catches content mistakes (duplicate ids,
+//! empty names, unknown keywords, ...) before they reach a run.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::card::{Card, CardType, Keyword};
+//! use deckbuilder_eng::card_db::*;
+//!
+//! let db = CardDatabase::new()
+//!     .with_keyword(Keyword::new("Exhaust", "Removed from your deck until end of combat."))
+//!     .with_card(CardDef::new(Card::new(1, "Strike", "Deal 6 damage.", 1, CardType::Attack)).with_effect("damage", 6))
+//!     .with_card(CardDef::new(Card::new(2, "Bash", "Deal 8 damage.", 2, CardType::Attack)).with_keyword("Exhaust"));
+//! assert!(db.validate().is_empty());
+//!
+//! // Both formats read back the same cards
+//! let json = CardDatabase::parse(&db.to_json()).unwrap();
+//! let ron = CardDatabase::parse(&db.to_ron()).unwrap();
+//! assert_eq!(json.get(1).unwrap().effect("damage"), Some(6));
+//! assert_eq!(ron.get(2).unwrap().keywords, ["Exhaust"]);
+//! ```
+//!
+//! # Details
+//!
+//! - Files hold a `keywords` list and a `cards` list; a card has `id`, `name`, `description`,
+//!   `cost`, `card_type`, `rarity`, `effects` (`kind` and `amount`) and `keywords`. Only `id`,
+//!   `name` and `card_type` are required.
+//! - [`CardDatabase::parse`] accepts JSON and RON alike; [`CardDatabase::save`] picks the
+//!   format from the file extension (`.json` or `.ron`).
+//! - Effects are named amounts (`damage`, `block`, `draw`, ...) the game interprets; the
+//!   database doesn't restrict their kinds.
+//! - Keywords of cards must be in the database's keyword list, unless the list is empty.
+//! - See each struct and function's documentation for more.
+
+use std::collections::HashSet;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::Path;
+
+use crate::card::{Card, CardId, CardType, Keyword, Rarity};
+use crate::json::{self, Value};
+
+/// Error returned when a card database can't be read or written.
+#[derive(Debug, thiserror::Error)]
+pub enum CardDbError {
+    /// The file couldn't be read or written.
+    #[error("card database io: {0}")]
+    Io(#[from] io::Error),
+    /// Malformed JSON or RON.
+    #[error("card database line {line}: {message}")]
+    Parse { line: usize, message: String },
+    /// Well-formed file whose contents aren't cards, e.g. a missing field.
+    #[error("card database: {0}")]
+    Schema(String),
+    /// The file extension is neither `.json` nor `.ron`.
+    #[error("unsupported card database format `{0}`")]
+    Format(String),
+}
+
+/// File format of a card database.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardFormat {
+    Json,
+    Ron,
+}
+
+impl CardFormat {
+    /// Returns the format matching the extension of `path`.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card_db::CardFormat;
+    /// assert_eq!(CardFormat::from_path("data/cards.ron"), Some(CardFormat::Ron));
+    /// assert_eq!(CardFormat::from_path("data/cards.txt"), None);
+    /// ```
+    pub fn from_path(path: impl AsRef<Path>) -> Option<Self> {
+        let extension = path.as_ref().extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "json" => Some(CardFormat::Json),
+            "ron" => Some(CardFormat::Ron),
+            _ => None,
+        }
+    }
+}
+
+/// A named amount a card applies when played, e.g. `damage` 6.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EffectDef {
+    pub kind: String,
+    pub amount: i32,
+}
+
+impl EffectDef {
+    /// Creates a new `EffectDef`.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card_db::EffectDef;
+    /// let block = EffectDef::new("block", 5);
+    /// ```
+    pub fn new(kind: impl Into<String>, amount: i32) -> Self {
+        Self { kind: kind.into(), amount }
+    }
+}
+
+/// A card with its effects and keywords, as stored in a [`CardDatabase`].
+#[derive(Debug, Clone)]
+pub struct CardDef {
+    pub card: Card,
+    pub effects: Vec<EffectDef>,
+    pub keywords: Vec<String>,
+}
+
+impl CardDef {
+    /// Creates a definition of `card` without effects or keywords.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card::{Card, CardType};
+    /// # use deckbuilder_eng::card_db::CardDef;
+    /// let def = CardDef::new(Card::new(1, "Strike", "Deal 6 damage.", 1, CardType::Attack));
+    /// ```
+    pub fn new(card: Card) -> Self {
+        Self {
+            card,
+            effects: Vec::new(),
+            keywords: Vec::new(),
+        }
+    }
+
+    /// Adds an effect.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card::{Card, CardType};
+    /// # use deckbuilder_eng::card_db::CardDef;
+    /// let def = CardDef::new(Card::new(3, "Defend", "Gain 5 block.", 1, CardType::Skill)).with_effect("block", 5);
+    /// ```
+    pub fn with_effect(mut self, kind: impl Into<String>, amount: i32) -> Self {
+        self.effects.push(EffectDef::new(kind, amount));
+        self
+    }
+
+    /// Adds a keyword.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card::{Card, CardType};
+    /// # use deckbuilder_eng::card_db::CardDef;
+    /// let def = CardDef::new(Card::new(4, "Offering", "Gain 2 energy.", 0, CardType::Skill)).with_keyword("Exhaust");
+    /// ```
+    pub fn with_keyword(mut self, name: impl Into<String>) -> Self {
+        self.keywords.push(name.into());
+        self
+    }
+
+    /// Returns the total amount of the effects of `kind`, or `None` if the card has none.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card::{Card, CardType};
+    /// # use deckbuilder_eng::card_db::CardDef;
+    /// let def = CardDef::new(Card::new(5, "Twin Strike", "Deal 5 damage twice.", 1, CardType::Attack))
+    ///     .with_effect("damage", 5)
+    ///     .with_effect("damage", 5);
+    /// assert_eq!(def.effect("damage"), Some(10));
+    /// assert_eq!(def.effect("block"), None);
+    /// ```
+    pub fn effect(&self, kind: &str) -> Option<i32> {
+        self.effects
+            .iter()
+            .filter(|effect| effect.kind == kind)
+            .map(|effect| effect.amount)
+            .reduce(|a, b| a + b)
+    }
+}
+
+/// A problem found by [`CardDatabase::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationIssue {
+    /// Index of the card in [`CardDatabase::cards`].
+    pub card: usize,
+    /// Field with the problem: `id`, `name`, `description`, `effects` or `keywords`.
+    pub field: &'static str,
+    pub message: String,
+}
+
+/// A game's cards and keywords.
+#[derive(Debug, Clone, Default)]
+pub struct CardDatabase {
+    pub cards: Vec<CardDef>,
+    pub keywords: Vec<Keyword>,
+}
+
+impl CardDatabase {
+    /// Creates an empty database.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card_db::CardDatabase;
+    /// let db = CardDatabase::new();
+    /// assert!(db.cards.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a card.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card::{Card, CardType};
+    /// # use deckbuilder_eng::card_db::{CardDatabase, CardDef};
+    /// let db = CardDatabase::new().with_card(CardDef::new(Card::new(1, "Strike", "Deal 6 damage.", 1, CardType::Attack)));
+    /// ```
+    pub fn with_card(mut self, def: CardDef) -> Self {
+        self.cards.push(def);
+        self
+    }
+
+    /// Adds a keyword cards may use.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card::Keyword;
+    /// # use deckbuilder_eng::card_db::CardDatabase;
+    /// let db = CardDatabase::new().with_keyword(Keyword::new("Retain", "Not discarded at end of turn."));
+    /// ```
+    pub fn with_keyword(mut self, keyword: Keyword) -> Self {
+        self.keywords.push(keyword);
+        self
+    }
+
+    /// Returns the card with `id`.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card::{Card, CardType};
+    /// # use deckbuilder_eng::card_db::{CardDatabase, CardDef};
+    /// let db = CardDatabase::new().with_card(CardDef::new(Card::new(1, "Strike", "Deal 6 damage.", 1, CardType::Attack)));
+    /// assert_eq!(db.get(1).unwrap().card.name, "Strike");
+    /// ```
+    pub fn get(&self, id: CardId) -> Option<&CardDef> {
+        self.cards.iter().find(|def| def.card.id == id)
+    }
+
+    /// Returns the card with `id` for editing.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card::{Card, CardType};
+    /// # use deckbuilder_eng::card_db::{CardDatabase, CardDef};
+    /// let mut db = CardDatabase::new().with_card(CardDef::new(Card::new(1, "Strike", "Deal 6 damage.", 1, CardType::Attack)));
+    /// db.get_mut(1).unwrap().card.cost = 0;
+    /// ```
+    pub fn get_mut(&mut self, id: CardId) -> Option<&mut CardDef> {
+        self.cards.iter_mut().find(|def| def.card.id == id)
+    }
+
+    /// Returns the keyword named `name`.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card::Keyword;
+    /// # use deckbuilder_eng::card_db::CardDatabase;
+    /// let db = CardDatabase::new().with_keyword(Keyword::new("Exhaust", "Removed until end of combat."));
+    /// assert!(db.keyword("Exhaust").is_some());
+    /// ```
+    pub fn keyword(&self, name: &str) -> Option<&Keyword> {
+        self.keywords.iter().find(|keyword| keyword.name == name)
+    }
+
+    /// Returns an id no card uses yet, for new cards: one past the highest id, or the lowest
+    /// unused id if the highest is `CardId::MAX`.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card::{Card, CardType};
+    /// # use deckbuilder_eng::card_db::{CardDatabase, CardDef};
+    /// let db = CardDatabase::new().with_card(CardDef::new(Card::new(7, "Strike", "Deal 6 damage.", 1, CardType::Attack)));
+    /// assert_eq!(db.next_id(), 8);
+    ///
+    /// let db = db
+    ///     .with_card(CardDef::new(Card::new(1, "Defend", "Gain 5 block.", 1, CardType::Skill)))
+    ///     .with_card(CardDef::new(Card::new(u32::MAX, "Bash", "Deal 8 damage.", 2, CardType::Attack)));
+    /// assert_eq!(db.next_id(), 2);
+    /// ```
+    pub fn next_id(&self) -> CardId {
+        let highest = self.cards.iter().map(|def| def.card.id).max().unwrap_or(0);
+        highest.checked_add(1).unwrap_or_else(|| {
+            let used: HashSet<CardId> = self.cards.iter().map(|def| def.card.id).collect();
+            // A database can't hold a card for every id, so there is always a gap
+            (1..CardId::MAX).find(|id| !used.contains(id)).unwrap_or(0)
+        })
+    }
+
+    /// Returns copies of the cards with `ids`, in order, e.g. a starting deck. Unknown ids are
+    /// skipped.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card::{Card, CardType, Deck};
+    /// # use deckbuilder_eng::card_db::{CardDatabase, CardDef};
+    /// let db = CardDatabase::new().with_card(CardDef::new(Card::new(1, "Strike", "Deal 6 damage.", 1, CardType::Attack)));
+    /// let deck = Deck::new(db.deck_cards(&[1, 1, 1]));
+    /// assert_eq!(deck.draw_pile.len(), 3);
+    /// ```
+    pub fn deck_cards(&self, ids: &[CardId]) -> Vec<Card> {
+        ids.iter().filter_map(|&id| self.get(id)).map(|def| def.card.clone()).collect()
+    }
+
+    /// Checks all cards and returns the problems found, in card order.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card::{Card, CardType};
+    /// # use deckbuilder_eng::card_db::{CardDatabase, CardDef};
+    /// let db = CardDatabase::new()
+    ///     .with_card(CardDef::new(Card::new(1, "Strike", "Deal 6 damage.", 1, CardType::Attack)))
+    ///     .with_card(CardDef::new(Card::new(1, "", "Gain 5 block.", 1, CardType::Skill)));
+    /// let issues = db.validate();
+    /// assert_eq!(issues.len(), 3); // the duplicate id on both cards, and the empty name
+    /// assert_eq!(issues[2].field, "name");
+    /// ```
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+        for (index, def) in self.cards.iter().enumerate() {
+            let mut issue = |field, message: String| {
+                issues.push(ValidationIssue {
+                    card: index,
+                    field,
+                    message,
+                })
+            };
+            let card = &def.card;
+            if let Some(other) = self
+                .cards
+                .iter()
+                .enumerate()
+                .find(|(i, other)| *i != index && other.card.id == card.id)
+            {
+                issue("id", format!("id {} is also used by `{}`", card.id, other.1.card.name));
+            }
+            if card.name.trim().is_empty() {
+                issue("name", "name is empty".to_owned());
+            }
+            if card.description.trim().is_empty() {
+                issue("description", "description is empty".to_owned());
+            }
+            for effect in &def.effects {
+                if effect.kind.trim().is_empty() {
+                    issue("effects", "effect has no kind".to_owned());
+                } else if effect.amount < 0 {
+                    issue("effects", format!("`{}` amount {} is negative", effect.kind, effect.amount));
+                }
+            }
+            for (i, keyword) in def.keywords.iter().enumerate() {
+                if keyword.trim().is_empty() {
+                    issue("keywords", "keyword is empty".to_owned());
+                } else if def.keywords[..i].contains(keyword) {
+                    issue("keywords", format!("keyword `{}` is listed twice", keyword));
+                } else if !self.keywords.is_empty() && self.keyword(keyword).is_none() {
+                    issue("keywords", format!("unknown keyword `{}`", keyword));
+                }
+            }
+        }
+        issues
+    }
+
+    /// Parses a database from JSON or RON source.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card_db::CardDatabase;
+    /// let db = CardDatabase::parse(r#"{ "cards": [{ "id": 1, "name": "Strike \ud83d\udd25", "card_type": "Attack" }] }"#).unwrap();
+    /// assert_eq!(db.get(1).unwrap().card.name, "Strike 🔥");
+    /// let db = CardDatabase::parse("(cards: [(id: 1, name: \"Strike\", card_type: Attack, cost: 1)])").unwrap();
+    /// assert_eq!(db.get(1).unwrap().card.cost, 1);
+    ///
+    /// // Nesting is limited, so hostile files fail instead of overflowing the stack
+    /// assert!(CardDatabase::parse(&"[".repeat(100_000)).is_err());
+    /// ```
+    pub fn parse(source: &str) -> Result<Self, CardDbError> {
+        let value = json::parse(source).map_err(|err| CardDbError::Parse {
+            line: err.line,
+            message: err.message,
+        })?;
+        database_from_value(value)
+    }
+
+    /// Reads a JSON or RON database file.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use deckbuilder_eng::card_db::CardDatabase;
+    /// let db = CardDatabase::load("assets/cards.ron").unwrap();
+    /// ```
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, CardDbError> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    /// Writes the database to `path`, as JSON or RON depending on its extension. The file is
+    /// written to a temporary file first that then replaces it, so a failed save leaves the
+    /// previous file intact.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use deckbuilder_eng::card_db::CardDatabase;
+    /// let db = CardDatabase::load("assets/cards.json").unwrap();
+    /// db.save("assets/cards.ron").unwrap();
+    /// ```
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), CardDbError> {
+        let path = path.as_ref();
+        let format = CardFormat::from_path(path).ok_or_else(|| {
+            CardDbError::Format(path.extension().map(|e| e.to_string_lossy().into_owned()).unwrap_or_default())
+        })?;
+        let mut temp = path.as_os_str().to_owned();
+        temp.push(".tmp");
+        let mut file = File::create(&temp)?;
+        file.write_all(self.encode(format).as_bytes())?;
+        file.sync_all()?;
+        drop(file);
+        fs::rename(&temp, path)?;
+        Ok(())
+    }
+
+    /// Returns the database in `format`.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card_db::{CardDatabase, CardFormat};
+    /// let text = CardDatabase::new().encode(CardFormat::Ron);
+    /// assert!(text.contains("cards: ["));
+    /// ```
+    pub fn encode(&self, format: CardFormat) -> String {
+        let keywords = self.keywords.iter().map(|keyword| {
+            vec![
+                ("name", quote(&keyword.name, format)),
+                ("description", quote(&keyword.description, format)),
+            ]
+        });
+        let cards = self.cards.iter().map(|def| {
+            let card = &def.card;
+            let effects: Vec<String> = def
+                .effects
+                .iter()
+                .map(|effect| {
+                    inline_record(
+                        &[("kind", quote(&effect.kind, format)), ("amount", effect.amount.to_string())],
+                        format,
+                    )
+                })
+                .collect();
+            let keywords: Vec<String> = def.keywords.iter().map(|keyword| quote(keyword, format)).collect();
+            vec![
+                ("id", card.id.to_string()),
+                ("name", quote(&card.name, format)),
+                ("description", quote(&card.description, format)),
+                ("cost", card.cost.to_string()),
+                ("card_type", variant(&format!("{:?}", card.card_type), format)),
+                ("rarity", variant(&format!("{:?}", card.rarity), format)),
+                ("effects", format!("[{}]", effects.join(", "))),
+                ("keywords", format!("[{}]", keywords.join(", "))),
+            ]
+        });
+        let keywords: Vec<String> = keywords.map(|fields| inline_record(&fields, format)).collect();
+        let cards: Vec<String> = cards.map(|fields| block_record(&fields, format, 2)).collect();
+        let lists = [("keywords", keywords), ("cards", cards)].map(|(key, items)| {
+            let items = items
+                .iter()
+                .map(|item| format!("{}{}", indent(2, format), item))
+                .collect::<Vec<_>>()
+                .join(",\n");
+            let body = if items.is_empty() {
+                "[]".to_owned()
+            } else if format == CardFormat::Ron {
+                format!("[\n{},\n{}]", items, indent(1, format))
+            } else {
+                format!("[\n{}\n{}]", items, indent(1, format))
+            };
+            (key, body)
+        });
+        block_record(&lists, format, 0)
+    }
+
+    /// Returns the database as JSON.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card_db::CardDatabase;
+    /// assert!(CardDatabase::new().to_json().starts_with('{'));
+    /// ```
+    pub fn to_json(&self) -> String {
+        self.encode(CardFormat::Json)
+    }
+
+    /// Returns the database as RON.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card_db::CardDatabase;
+    /// assert!(CardDatabase::new().to_ron().starts_with('('));
+    /// ```
+    pub fn to_ron(&self) -> String {
+        self.encode(CardFormat::Ron)
+    }
+}
+
+fn indent(level: usize, format: CardFormat) -> String {
+    let width = if format == CardFormat::Ron { 4 } else { 2 };
+    " ".repeat(level * width)
+}
+
+fn field_name(name: &str, format: CardFormat) -> String {
+    match format {
+        CardFormat::Json => format!("\"{}\"", name),
+        CardFormat::Ron => name.to_owned(),
+    }
+}
+
+fn variant(name: &str, format: CardFormat) -> String {
+    match format {
+        CardFormat::Json => format!("\"{}\"", name),
+        CardFormat::Ron => name.to_owned(),
+    }
+}
+
+fn inline_record(fields: &[(&str, String)], format: CardFormat) -> String {
+    let body = fields
+        .iter()
+        .map(|(name, value)| format!("{}: {}", field_name(name, format), value))
+        .collect::<Vec<_>>()
+        .join(", ");
+    match format {
+        CardFormat::Json => format!("{{ {} }}", body),
+        CardFormat::Ron => format!("({})", body),
+    }
+}
+
+fn block_record(fields: &[(&str, String)], format: CardFormat, level: usize) -> String {
+    let body = fields
+        .iter()
+        .map(|(name, value)| format!("{}{}: {}", indent(level + 1, format), field_name(name, format), value))
+        .collect::<Vec<_>>()
+        .join(",\n");
+    match format {
+        CardFormat::Json => format!("{{\n{}\n{}}}", body, indent(level, format)),
+        CardFormat::Ron => format!("(\n{},\n{})", body, indent(level, format)),
+    }
+}
+
+fn quote(text: &str, format: CardFormat) -> String {
+    match format {
+        CardFormat::Json => json::quote(text),
+        CardFormat::Ron => json::quote_ron(text),
+    }
+}
+
+fn field<'v>(fields: &'v [(String, Value)], name: &str) -> Option<&'v Value> {
+    fields.iter().find(|(key, _)| key == name).map(|(_, value)| value)
+}
+
+fn as_str<'v>(value: &'v Value, what: &str) -> Result<&'v str, CardDbError> {
+    match value {
+        Value::Str(s) | Value::Ident(s) => Ok(s),
+        _ => Err(CardDbError::Schema(format!("{} must be a string", what))),
+    }
+}
+
+fn as_int(value: &Value, what: &str) -> Result<i64, CardDbError> {
+    match value {
+        Value::Int(n) => Ok(*n),
+        _ => Err(CardDbError::Schema(format!("{} must be an integer", what))),
+    }
+}
+
+fn as_list<'v>(value: &'v Value, what: &str) -> Result<&'v [Value], CardDbError> {
+    match value {
+        Value::List(items) => Ok(items),
+        _ => Err(CardDbError::Schema(format!("{} must be a list", what))),
+    }
+}
+
+fn as_map<'v>(value: &'v Value, what: &str) -> Result<&'v [(String, Value)], CardDbError> {
+    match value {
+        Value::Map(fields) => Ok(fields),
+        _ => Err(CardDbError::Schema(format!("{} must be a record", what))),
+    }
+}
+
+fn string_field(fields: &[(String, Value)], name: &str, what: &str) -> Result<Option<String>, CardDbError> {
+    field(fields, name)
+        .map(|value| as_str(value, &format!("{} `{}`", what, name)).map(str::to_owned))
+        .transpose()
+}
+
+fn database_from_value(value: Value) -> Result<CardDatabase, CardDbError> {
+    let mut db = CardDatabase::new();
+    let fields = as_map(&value, "the database")?;
+    if let Some(keywords) = field(fields, "keywords") {
+        for (i, keyword) in as_list(keywords, "`keywords`")?.iter().enumerate() {
+            let what = format!("keyword {}", i + 1);
+            let fields = as_map(keyword, &what)?;
+            let name = string_field(fields, "name", &what)?
+                .ok_or_else(|| CardDbError::Schema(format!("{} has no `name`", what)))?;
+            let description = string_field(fields, "description", &what)?.unwrap_or_default();
+            db.keywords.push(Keyword::new(name, description));
+        }
+    }
+    if let Some(cards) = field(fields, "cards") {
+        for (i, card) in as_list(cards, "`cards`")?.iter().enumerate() {
+            db.cards.push(card_from_value(card, &format!("card {}", i + 1))?);
+        }
+    }
+    Ok(db)
+}
+
+fn card_from_value(value: &Value, what: &str) -> Result<CardDef, CardDbError> {
+    let fields = as_map(value, what)?;
+    let required = |name: &str| {
+        field(fields, name).ok_or_else(|| CardDbError::Schema(format!("{} has no `{}`", what, name)))
+    };
+    let id = as_int(required("id")?, &format!("{} `id`", what))?;
+    let id = CardId::try_from(id).map_err(|_| CardDbError::Schema(format!("{} has invalid id {}", what, id)))?;
+    let what = format!("card {}", id);
+    let name = as_str(required("name")?, &format!("{} `name`", what))?;
+    let card_type = match as_str(required("card_type")?, &format!("{} `card_type`", what))? {
+        t if t.eq_ignore_ascii_case("attack") => CardType::Attack,
+        t if t.eq_ignore_ascii_case("skill") => CardType::Skill,
+        t if t.eq_ignore_ascii_case("power") => CardType::Power,
+        t => return Err(CardDbError::Schema(format!("{} has unknown card type `{}`", what, t))),
+    };
+    let rarity = match string_field(fields, "rarity", &what)?.as_deref() {
+        None => Rarity::Common,
+        Some(r) if r.eq_ignore_ascii_case("common") => Rarity::Common,
+        Some(r) if r.eq_ignore_ascii_case("uncommon") => Rarity::Uncommon,
+        Some(r) if r.eq_ignore_ascii_case("rare") => Rarity::Rare,
+        Some(r) => return Err(CardDbError::Schema(format!("{} has unknown rarity `{}`", what, r))),
+    };
+    let cost = match field(fields, "cost") {
+        Some(cost) => {
+            let cost = as_int(cost, &format!("{} `cost`", what))?;
+            u32::try_from(cost).map_err(|_| CardDbError::Schema(format!("{} has invalid cost {}", what, cost)))?
+        }
+        None => 0,
+    };
+    let description = string_field(fields, "description", &what)?.unwrap_or_default();
+    let mut def = CardDef::new(Card::new(id, name, description, cost, card_type).with_rarity(rarity));
+    if let Some(effects) = field(fields, "effects") {
+        for effect in as_list(effects, &format!("{} `effects`", what))? {
+            let fields = as_map(effect, &format!("{} effect", what))?;
+            let kind = string_field(fields, "kind", &what)?
+                .ok_or_else(|| CardDbError::Schema(format!("{} has an effect without `kind`", what)))?;
+            let amount = match field(fields, "amount") {
+                Some(amount) => as_int(amount, &format!("{} `{}` amount", what, kind))?,
+                None => 0,
+            };
+            let amount = i32::try_from(amount)
+                .map_err(|_| CardDbError::Schema(format!("{} `{}` amount {} is out of range", what, kind, amount)))?;
+            def.effects.push(EffectDef::new(kind, amount));
+        }
+    }
+    if let Some(keywords) = field(fields, "keywords") {
+        for keyword in as_list(keywords, &format!("{} `keywords`", what))? {
+            def.keywords.push(as_str(keyword, &format!("{} keyword", what))?.to_owned());
+        }
+    }
+    Ok(def)
+}
@@ -0,0 +1,246 @@
+//! Combat log types for deckbuilder games.
+//!
+//! Provides a structured [`CombatLog`] recording what happened in a battle, turn by turn, as
+//! [`LogEntry`]s of a [`LogKind`] (damage, block, cards played, ...).
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::combat_log::*;
+//!
+//! let mut log = CombatLog::new();
+//! log.push(LogKind::Card, "Played Strike");
+//! log.push(LogKind::Damage, "Jaw Worm takes [red]6[/red] damage");
+//! log.next_turn();
+//! log.push(LogKind::Block, "Gained 5 block");
+//!
+//! assert_eq!(log.turn(), 2);
+//! assert_eq!(log.entries_for_turn(1).count(), 2);
+//! ```
+//!
+//! # Details
+//!
+//! - Entry text may use the rich text markup of [`crate::ui_text`].
+//! - With a capacity set, the oldest entries are dropped first.
+//! - See each struct and function's documentation for more.
+
+use std::collections::VecDeque;
+
+/// Category of a combat log entry, used for coloring and filtering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LogKind {
+    Damage,
+    Block,
+    Heal,
+    Status,
+    Card,
+    Enemy,
+    System,
+}
+
+impl LogKind {
+    /// All kinds, in display order.
+    pub const ALL: [LogKind; 7] = [
+        LogKind::Damage,
+        LogKind::Block,
+        LogKind::Heal,
+        LogKind::Status,
+        LogKind::Card,
+        LogKind::Enemy,
+        LogKind::System,
+    ];
+
+    /// Returns a short display name.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::combat_log::LogKind;
+    /// assert_eq!(LogKind::Damage.label(), "Damage");
+    /// ```
+    pub fn label(self) -> &'static str {
+        match self {
+            LogKind::Damage => "Damage",
+            LogKind::Block => "Block",
+            LogKind::Heal => "Heal",
+            LogKind::Status => "Status",
+            LogKind::Card => "Cards",
+            LogKind::Enemy => "Enemy",
+            LogKind::System => "System",
+        }
+    }
+}
+
+/// One line of a [`CombatLog`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogEntry {
+    pub turn: u32,
+    pub kind: LogKind,
+    pub text: String,
+}
+
+/// Entries of a battle, grouped by turn number.
+#[derive(Debug, Clone)]
+pub struct CombatLog {
+    entries: VecDeque<LogEntry>,
+    turn: u32,
+    capacity: Option<usize>,
+}
+
+impl Default for CombatLog {
+    fn default() -> Self {
+        Self {
+            entries: VecDeque::new(),
+            turn: 1,
+            capacity: None,
+        }
+    }
+}
+
+impl CombatLog {
+    /// Creates an empty, unbounded log starting at turn 1.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::combat_log::CombatLog;
+    /// let log = CombatLog::new();
+    /// assert!(log.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keeps at most `capacity` entries.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::combat_log::{CombatLog, LogKind};
+    /// let mut log = CombatLog::new().with_capacity(1);
+    /// log.push(LogKind::Card, "Played Strike");
+    /// log.push(LogKind::Card, "Played Defend");
+    /// assert_eq!(log.len(), 1);
+    /// ```
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = Some(capacity);
+        self
+    }
+
+    /// Adds an entry to the current turn.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::combat_log::{CombatLog, LogKind};
+    /// let mut log = CombatLog::new();
+    /// log.push(LogKind::Heal, "Healed 3");
+    /// assert_eq!(log.len(), 1);
+    /// ```
+    pub fn push(&mut self, kind: LogKind, text: impl Into<String>) {
+        self.entries.push_back(LogEntry {
+            turn: self.turn,
+            kind,
+            text: text.into(),
+        });
+        if let Some(capacity) = self.capacity {
+            while self.entries.len() > capacity {
+                self.entries.pop_front();
+            }
+        }
+    }
+
+    /// Starts the next turn.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::combat_log::CombatLog;
+    /// let mut log = CombatLog::new();
+    /// log.next_turn();
+    /// assert_eq!(log.turn(), 2);
+    /// ```
+    pub fn next_turn(&mut self) {
+        self.turn += 1;
+    }
+
+    /// Sets the current turn, e.g. from [`crate::card::GameContext::turn`].
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::combat_log::CombatLog;
+    /// let mut log = CombatLog::new();
+    /// log.set_turn(4);
+    /// assert_eq!(log.turn(), 4);
+    /// ```
+    pub fn set_turn(&mut self, turn: u32) {
+        self.turn = turn;
+    }
+
+    /// Returns the current turn.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::combat_log::CombatLog;
+    /// assert_eq!(CombatLog::new().turn(), 1);
+    /// ```
+    pub fn turn(&self) -> u32 {
+        self.turn
+    }
+
+    /// Returns an iterator over all entries, oldest first.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::combat_log::{CombatLog, LogKind};
+    /// let mut log = CombatLog::new();
+    /// log.push(LogKind::System, "Battle start");
+    /// assert_eq!(log.entries().next().unwrap().text, "Battle start");
+    /// ```
+    pub fn entries(&self) -> impl Iterator<Item = &LogEntry> {
+        self.entries.iter()
+    }
+
+    /// Returns an iterator over the entries of `turn`.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::combat_log::CombatLog;
+    /// assert_eq!(CombatLog::new().entries_for_turn(1).count(), 0);
+    /// ```
+    pub fn entries_for_turn(&self, turn: u32) -> impl Iterator<Item = &LogEntry> {
+        self.entries.iter().filter(move |e| e.turn == turn)
+    }
+
+    /// Returns the number of entries.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::combat_log::CombatLog;
+    /// assert_eq!(CombatLog::new().len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the log has no entries.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::combat_log::CombatLog;
+    /// assert!(CombatLog::new().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes all entries and restarts at turn 1.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::combat_log::{CombatLog, LogKind};
+    /// let mut log = CombatLog::new();
+    /// log.push(LogKind::System, "Battle start");
+    /// log.clear();
+    /// assert!(log.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.turn = 1;
+    }
+}
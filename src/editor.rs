@@ -0,0 +1,555 @@
+//! Card editor screen for deckbuilder content.
+//!
+//! Provides [`CardEditor`], an egui screen that browses a [`CardDatabase`], edits card stats,
+//! effects and keywords with live validation, previews each card through a [`CardRenderer`]
+//! exactly as the game draws it, and writes the changes back to the JSON or RON file.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use deckbuilder_eng::editor::CardEditor;
+//! use deckbuilder_eng::ui_card_render::{CardRenderer, CardTheme};
+//! use egui::{CentralPanel, Context};
+//!
+//! let mut editor = CardEditor::open("assets/cards.ron")
+//!     .unwrap()
+//!     .with_renderer(CardRenderer::new(CardTheme::load("assets/card_theme.txt").unwrap()));
+//!
+//! fn frame(ctx: &Context, editor: &mut CardEditor) {
+//!     CentralPanel::default().show(ctx, |ui| editor.ui(ui));
+//! }
+//! ```
+//!
+//! # Details
+//!
+//! - Needs the `editor` feature.
+//! - The editor is also a [`Screen`], so it can be pushed onto the game's `ScreenStack` as a
+//!   developer menu entry.
+//! - The database is validated after every edit; cards with problems are marked in the list
+//!   and saving is refused until they are fixed.
+//! - Saving writes the format of the file's extension, so a JSON database can be converted by
+//!   saving it under a `.ron` name with [`CardEditor::save_as`].
+//! - See each struct and function's documentation for more.
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+use egui::{CentralPanel, Color32, ComboBox, DragValue, Grid, RichText, ScrollArea, SidePanel, TextEdit, Ui, Vec2};
+
+use crate::card::{Card, CardType, Rarity};
+use crate::card_db::{CardDatabase, CardDbError, CardDef, EffectDef, ValidationIssue};
+use crate::ui_card_render::{CardRenderer, CardTheme};
+use crate::ui_screen::{Screen, ScreenAction};
+
+const ERROR_COLOR: Color32 = Color32::from_rgb(230, 90, 80);
+
+/// An egui screen for editing the cards of a [`CardDatabase`].
+pub struct CardEditor {
+    database: CardDatabase,
+    path: Option<PathBuf>,
+    renderer: CardRenderer,
+    images_loaded: bool,
+    preview_size: Vec2,
+    selected: Option<usize>,
+    filter: String,
+    new_keyword: String,
+    issues: Vec<ValidationIssue>,
+    dirty: bool,
+    status: Option<(String, bool)>,
+}
+
+impl CardEditor {
+    /// Creates an editor for `database`, previewing cards with the default card theme.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card_db::CardDatabase;
+    /// # use deckbuilder_eng::editor::CardEditor;
+    /// let editor = CardEditor::new(CardDatabase::new());
+    /// assert!(!editor.is_dirty());
+    /// ```
+    pub fn new(database: CardDatabase) -> Self {
+        let issues = database.validate();
+        Self {
+            selected: if database.cards.is_empty() { None } else { Some(0) },
+            database,
+            path: None,
+            renderer: CardRenderer::new(CardTheme::default()),
+            images_loaded: false,
+            preview_size: Vec2::new(210.0, 300.0),
+            filter: String::new(),
+            new_keyword: String::new(),
+            issues,
+            dirty: false,
+            status: None,
+        }
+    }
+
+    /// Loads the database at `path` and creates an editor that saves back to it.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use deckbuilder_eng::editor::CardEditor;
+    /// let editor = CardEditor::open("assets/cards.json").unwrap();
+    /// ```
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, CardDbError> {
+        let database = CardDatabase::load(path.as_ref())?;
+        Ok(Self::new(database).with_path(path.as_ref()))
+    }
+
+    /// Sets the file [`CardEditor::save`] writes to.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card_db::CardDatabase;
+    /// # use deckbuilder_eng::editor::CardEditor;
+    /// let editor = CardEditor::new(CardDatabase::new()).with_path("assets/cards.ron");
+    /// assert!(editor.path().is_some());
+    /// ```
+    pub fn with_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Previews cards with `renderer`, e.g. one using the game's card theme. Its layer images
+    /// are loaded the first time the editor is shown.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card_db::CardDatabase;
+    /// # use deckbuilder_eng::editor::CardEditor;
+    /// # use deckbuilder_eng::ui_card_render::{CardRenderer, CardTheme};
+    /// let editor = CardEditor::new(CardDatabase::new()).with_renderer(CardRenderer::new(CardTheme::default()));
+    /// ```
+    pub fn with_renderer(mut self, renderer: CardRenderer) -> Self {
+        self.renderer = renderer;
+        self.images_loaded = false;
+        self
+    }
+
+    /// Sets the size of the card preview. Defaults to 210×300 points.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card_db::CardDatabase;
+    /// # use deckbuilder_eng::editor::CardEditor;
+    /// let editor = CardEditor::new(CardDatabase::new()).preview_size(egui::vec2(140.0, 200.0));
+    /// ```
+    pub fn preview_size(mut self, size: Vec2) -> Self {
+        self.preview_size = size;
+        self
+    }
+
+    /// Returns the database being edited.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card_db::CardDatabase;
+    /// # use deckbuilder_eng::editor::CardEditor;
+    /// let editor = CardEditor::new(CardDatabase::new());
+    /// assert!(editor.database().cards.is_empty());
+    /// ```
+    pub fn database(&self) -> &CardDatabase {
+        &self.database
+    }
+
+    /// Returns the edited database, e.g. to use it in the running game.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card_db::CardDatabase;
+    /// # use deckbuilder_eng::editor::CardEditor;
+    /// let db = CardEditor::new(CardDatabase::new()).into_database();
+    /// ```
+    pub fn into_database(self) -> CardDatabase {
+        self.database
+    }
+
+    /// Returns the file the editor saves to.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card_db::CardDatabase;
+    /// # use deckbuilder_eng::editor::CardEditor;
+    /// assert!(CardEditor::new(CardDatabase::new()).path().is_none());
+    /// ```
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Returns the index of the selected card.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card::{Card, CardType};
+    /// # use deckbuilder_eng::card_db::{CardDatabase, CardDef};
+    /// # use deckbuilder_eng::editor::CardEditor;
+    /// let db = CardDatabase::new().with_card(CardDef::new(Card::new(1, "Strike", "Deal 6 damage.", 1, CardType::Attack)));
+    /// assert_eq!(CardEditor::new(db).selected(), Some(0));
+    /// ```
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Selects the card at `index`, or nothing.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card::{Card, CardType};
+    /// # use deckbuilder_eng::card_db::{CardDatabase, CardDef};
+    /// # use deckbuilder_eng::editor::CardEditor;
+    /// let db = CardDatabase::new().with_card(CardDef::new(Card::new(1, "Strike", "Deal 6 damage.", 1, CardType::Attack)));
+    /// let mut editor = CardEditor::new(db);
+    /// editor.select(None);
+    /// assert_eq!(editor.selected(), None);
+    /// ```
+    pub fn select(&mut self, index: Option<usize>) {
+        self.selected = index.filter(|&i| i < self.database.cards.len());
+    }
+
+    /// Returns `true` if there are changes that haven't been saved.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card_db::CardDatabase;
+    /// # use deckbuilder_eng::editor::CardEditor;
+    /// let mut editor = CardEditor::new(CardDatabase::new());
+    /// editor.add_card();
+    /// assert!(editor.is_dirty());
+    /// ```
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// Returns the validation problems of the database as last edited.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card_db::CardDatabase;
+    /// # use deckbuilder_eng::editor::CardEditor;
+    /// let mut editor = CardEditor::new(CardDatabase::new());
+    /// editor.add_card();
+    /// assert!(editor.issues().iter().any(|issue| issue.field == "description"));
+    /// ```
+    pub fn issues(&self) -> &[ValidationIssue] {
+        &self.issues
+    }
+
+    /// Adds a new Attack card with an unused id, selects it and returns its index.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card_db::CardDatabase;
+    /// # use deckbuilder_eng::editor::CardEditor;
+    /// let mut editor = CardEditor::new(CardDatabase::new());
+    /// let index = editor.add_card();
+    /// assert_eq!(editor.database().cards[index].card.id, 1);
+    /// ```
+    pub fn add_card(&mut self) -> usize {
+        let card = Card::new(self.database.next_id(), "New Card", "", 1, CardType::Attack);
+        self.database.cards.push(CardDef::new(card));
+        let index = self.database.cards.len() - 1;
+        self.selected = Some(index);
+        self.changed();
+        index
+    }
+
+    /// Removes the card at `index` and returns it.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card_db::CardDatabase;
+    /// # use deckbuilder_eng::editor::CardEditor;
+    /// let mut editor = CardEditor::new(CardDatabase::new());
+    /// let index = editor.add_card();
+    /// assert!(editor.remove_card(index).is_some());
+    /// assert_eq!(editor.selected(), None);
+    /// ```
+    pub fn remove_card(&mut self, index: usize) -> Option<CardDef> {
+        if index >= self.database.cards.len() {
+            return None;
+        }
+        let def = self.database.cards.remove(index);
+        self.selected = match self.selected {
+            Some(selected) if selected > index => Some(selected - 1),
+            Some(selected) if selected == index => {
+                let last = self.database.cards.len().checked_sub(1);
+                last.map(|last| index.min(last))
+            }
+            selected => selected,
+        };
+        self.changed();
+        Some(def)
+    }
+
+    /// Saves the database to its file. Refused while the database has validation problems.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use deckbuilder_eng::editor::CardEditor;
+    /// let mut editor = CardEditor::open("assets/cards.ron").unwrap();
+    /// editor.save().unwrap();
+    /// ```
+    pub fn save(&mut self) -> Result<(), CardDbError> {
+        let Some(path) = self.path.clone() else {
+            return Err(CardDbError::Io(io::Error::new(io::ErrorKind::NotFound, "no file to save to")));
+        };
+        self.save_as(path)
+    }
+
+    /// Saves the database to `path`, as JSON or RON depending on its extension, and saves to
+    /// that file from then on.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use deckbuilder_eng::editor::CardEditor;
+    /// let mut editor = CardEditor::open("assets/cards.json").unwrap();
+    /// editor.save_as("assets/cards.ron").unwrap();
+    /// ```
+    pub fn save_as(&mut self, path: impl Into<PathBuf>) -> Result<(), CardDbError> {
+        let path = path.into();
+        if !self.issues.is_empty() {
+            return Err(CardDbError::Schema(format!(
+                "{} validation problem(s), first: {}",
+                self.issues.len(),
+                self.issues[0].message
+            )));
+        }
+        self.database.save(&path)?;
+        tracing::info!("saved {} cards to {}", self.database.cards.len(), path.display());
+        self.path = Some(path);
+        self.dirty = false;
+        Ok(())
+    }
+
+    fn changed(&mut self) {
+        self.issues = self.database.validate();
+        self.dirty = true;
+    }
+
+    /// Shows the editor: the card list on the left, the selected card's fields in the middle
+    /// and its preview on the right, under a toolbar with New, Delete and Save.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card::{Card, CardType};
+    /// # use deckbuilder_eng::card_db::{CardDatabase, CardDef};
+    /// # use deckbuilder_eng::editor::CardEditor;
+    /// # use egui::{CentralPanel, Context};
+    /// let db = CardDatabase::new().with_card(CardDef::new(Card::new(1, "Strike", "Deal 6 damage.", 1, CardType::Attack)));
+    /// let mut editor = CardEditor::new(db);
+    /// let ctx = Context::default();
+    /// let _ = ctx.run(Default::default(), |ctx| {
+    ///     CentralPanel::default().show(ctx, |ui| editor.ui(ui));
+    /// });
+    /// ```
+    pub fn ui(&mut self, ui: &mut Ui) {
+        if !self.images_loaded {
+            self.renderer.load_images(ui.ctx());
+            self.images_loaded = true;
+        }
+        self.toolbar(ui);
+        ui.separator();
+        SidePanel::left("card_editor_list")
+            .resizable(true)
+            .default_width(200.0)
+            .show_inside(ui, |ui| self.card_list(ui));
+        SidePanel::right("card_editor_preview")
+            .resizable(false)
+            .exact_width(self.preview_size.x + 16.0)
+            .show_inside(ui, |ui| self.preview(ui));
+        CentralPanel::default().show_inside(ui, |ui| {
+            ScrollArea::vertical().show(ui, |ui| self.card_form(ui));
+        });
+    }
+
+    fn toolbar(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            if ui.button("New card").clicked() {
+                self.add_card();
+            }
+            if ui.add_enabled(self.selected.is_some(), egui::Button::new("Delete")).clicked()
+                && let Some(index) = self.selected
+            {
+                self.remove_card(index);
+            }
+            let can_save = self.path.is_some() && self.dirty && self.issues.is_empty();
+            if ui.add_enabled(can_save, egui::Button::new("Save")).clicked() {
+                self.status = Some(match self.save() {
+                    Ok(()) => ("Saved".to_owned(), false),
+                    Err(err) => (err.to_string(), true),
+                });
+            }
+            if let Some(path) = &self.path {
+                let marker = if self.dirty { " *" } else { "" };
+                ui.label(format!("{}{}", path.display(), marker));
+            }
+            if !self.issues.is_empty() {
+                ui.colored_label(ERROR_COLOR, format!("{} problem(s)", self.issues.len()));
+            }
+            if let Some((message, error)) = &self.status {
+                let color = if *error { ERROR_COLOR } else { ui.visuals().weak_text_color() };
+                ui.colored_label(color, message);
+            }
+        });
+    }
+
+    fn card_list(&mut self, ui: &mut Ui) {
+        ui.add(TextEdit::singleline(&mut self.filter).hint_text("Search"));
+        let filter = self.filter.to_lowercase();
+        ScrollArea::vertical().show(ui, |ui| {
+            for (index, def) in self.database.cards.iter().enumerate() {
+                let card = &def.card;
+                if !filter.is_empty()
+                    && !card.name.to_lowercase().contains(&filter)
+                    && !card.id.to_string().starts_with(&filter)
+                {
+                    continue;
+                }
+                let mut text = RichText::new(format!("#{} {}", card.id, card.name));
+                if self.issues.iter().any(|issue| issue.card == index) {
+                    text = text.color(ERROR_COLOR);
+                }
+                if ui.selectable_label(self.selected == Some(index), text).clicked() {
+                    self.selected = Some(index);
+                }
+            }
+        });
+    }
+
+    fn card_form(&mut self, ui: &mut Ui) {
+        let Some(index) = self.selected else {
+            ui.label("Select a card to edit it.");
+            return;
+        };
+        let CardDatabase { cards, keywords } = &mut self.database;
+        let def = &mut cards[index];
+        let mut changed = false;
+        Grid::new("card_editor_fields").num_columns(2).spacing([12.0, 6.0]).show(ui, |ui| {
+            ui.label("Id");
+            changed |= ui.add(DragValue::new(&mut def.card.id)).changed();
+            ui.end_row();
+
+            ui.label("Name");
+            changed |= ui.text_edit_singleline(&mut def.card.name).changed();
+            ui.end_row();
+
+            ui.label("Description");
+            changed |= ui.add(TextEdit::multiline(&mut def.card.description).desired_rows(3)).changed();
+            ui.end_row();
+
+            ui.label("Cost");
+            changed |= ui.add(DragValue::new(&mut def.card.cost).clamp_range(0..=99)).changed();
+            ui.end_row();
+
+            ui.label("Type");
+            ComboBox::from_id_source("card_editor_type")
+                .selected_text(format!("{:?}", def.card.card_type))
+                .show_ui(ui, |ui| {
+                    for card_type in [CardType::Attack, CardType::Skill, CardType::Power] {
+                        let label = format!("{:?}", card_type);
+                        changed |= ui.selectable_value(&mut def.card.card_type, card_type, label).changed();
+                    }
+                });
+            ui.end_row();
+
+            ui.label("Rarity");
+            ComboBox::from_id_source("card_editor_rarity")
+                .selected_text(format!("{:?}", def.card.rarity))
+                .show_ui(ui, |ui| {
+                    for rarity in [Rarity::Common, Rarity::Uncommon, Rarity::Rare] {
+                        changed |= ui.selectable_value(&mut def.card.rarity, rarity, format!("{:?}", rarity)).changed();
+                    }
+                });
+            ui.end_row();
+        });
+
+        ui.separator();
+        ui.strong("Effects");
+        let mut remove = None;
+        for (i, effect) in def.effects.iter_mut().enumerate() {
+            ui.horizontal(|ui| {
+                changed |= ui.add(TextEdit::singleline(&mut effect.kind).desired_width(120.0).hint_text("kind")).changed();
+                changed |= ui.add(DragValue::new(&mut effect.amount)).changed();
+                if ui.small_button("✖").on_hover_text("Remove effect").clicked() {
+                    remove = Some(i);
+                }
+            });
+        }
+        if let Some(i) = remove {
+            def.effects.remove(i);
+            changed = true;
+        }
+        if ui.button("Add effect").clicked() {
+            def.effects.push(EffectDef::new("damage", 0));
+            changed = true;
+        }
+
+        ui.separator();
+        ui.strong("Keywords");
+        let mut remove = None;
+        ui.horizontal_wrapped(|ui| {
+            for (i, keyword) in def.keywords.iter().enumerate() {
+                if ui.small_button(format!("{} ✖", keyword)).on_hover_text("Remove keyword").clicked() {
+                    remove = Some(i);
+                }
+            }
+        });
+        if let Some(i) = remove {
+            def.keywords.remove(i);
+            changed = true;
+        }
+        ui.horizontal(|ui| {
+            if keywords.is_empty() {
+                ui.add(TextEdit::singleline(&mut self.new_keyword).desired_width(120.0).hint_text("keyword"));
+            } else {
+                let text = if self.new_keyword.is_empty() { "Choose…" } else { self.new_keyword.as_str() };
+                ComboBox::from_id_source("card_editor_keyword").selected_text(text.to_owned()).show_ui(ui, |ui| {
+                    for keyword in keywords.iter().filter(|keyword| !def.keywords.contains(&keyword.name)) {
+                        ui.selectable_value(&mut self.new_keyword, keyword.name.clone(), &keyword.name)
+                            .on_hover_text(&keyword.description);
+                    }
+                });
+            }
+            let name = self.new_keyword.trim();
+            if ui.add_enabled(!name.is_empty(), egui::Button::new("Add keyword")).clicked() {
+                def.keywords.push(name.to_owned());
+                self.new_keyword.clear();
+                changed = true;
+            }
+        });
+
+        if changed {
+            self.changed();
+        }
+        let issues: Vec<_> = self.issues.iter().filter(|issue| issue.card == index).collect();
+        if !issues.is_empty() {
+            ui.separator();
+            for issue in issues {
+                ui.colored_label(ERROR_COLOR, format!("{}: {}", issue.field, issue.message));
+            }
+        }
+    }
+
+    fn preview(&mut self, ui: &mut Ui) {
+        let Some(def) = self.selected.and_then(|index| self.database.cards.get(index)) else {
+            return;
+        };
+        ui.add_space(8.0);
+        self.renderer.ui(ui, &def.card, None, self.preview_size);
+        for name in &def.keywords {
+            if let Some(keyword) = self.database.keyword(name) {
+                ui.add_space(4.0);
+                ui.strong(&keyword.name);
+                ui.label(&keyword.description);
+            }
+        }
+    }
+}
+
+impl Screen for CardEditor {
+    fn update(&mut self, ui: &mut Ui) -> ScreenAction {
+        self.ui(ui);
+        ScreenAction::None
+    }
+}
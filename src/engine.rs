@@ -0,0 +1,839 @@
+//! Game runner for egui-based deckbuilders.
+//!
+//! Provides [`DeckbuilderEngine`], an `eframe::App` that owns the [`ScreenStack`], the
+//! [`AssetManager`] (with its audio manager), the persisted [`Settings`] and their keybindings,
+//! the [`GameClock`], the [`EventBus`] and the [`RunState`] of the current run, with
+//! [`EngineHook`]s to extend each frame and [`EnginePlugin`]s for self-contained features.
+//! Configure it with an [`EngineConfig`] and start it with [`DeckbuilderEngine::run`].
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use deckbuilder_eng::engine::{DeckbuilderEngine, EngineConfig, EngineContext, EngineHook, SharedState};
+//! use deckbuilder_eng::input::Action;
+//! use deckbuilder_eng::ui_screen::{Screen, ScreenAction};
+//! use egui::Ui;
+//!
+//! struct MainMenu {
+//!     state: SharedState,
+//! }
+//!
+//! impl Screen for MainMenu {
+//!     fn update(&mut self, ui: &mut Ui) -> ScreenAction {
+//!         ui.heading("Deckbuilder");
+//!         ui.label(format!("Gold: {}", self.state.borrow().run.gold));
+//!         ScreenAction::None
+//!     }
+//! }
+//!
+//! struct Cheats;
+//!
+//! impl EngineHook for Cheats {
+//!     fn on_action(&mut self, action: Action, engine: &mut EngineContext) {
+//!         if action == Action::OpenMap {
+//!             engine.state.run.gold += 100;
+//!         }
+//!     }
+//! }
+//!
+//! fn main() -> eframe::Result<()> {
+//!     let config = EngineConfig::new("My Deckbuilder")
+//!         .app_id("my_deckbuilder")
+//!         .manifest("assets/manifest.txt")
+//!         .preload("ui")
+//!         .start_screen(|state| Box::new(MainMenu { state }))
+//!         .hook(Cheats);
+//!     DeckbuilderEngine::run(config)
+//! }
+//! ```
+//!
+//! # Details
+//!
+//! - Screens reach the game through the [`SharedState`] handle passed to `start_screen`; clone it
+//!   into the screens they push. Don't hold a borrow across `ScreenAction`s.
+//! - Preloaded bundles load one asset per frame behind a [`LoadingScreen`] before the start screen
+//!   is shown.
+//! - Each frame: input actions are polled with the current keybindings, the game clock advances
+//!   and is published to the context, hooks run `fixed_update` once per due tick, then
+//!   `before_frame` and `on_action`, the screens are drawn, the events recorded by the current
+//!   battle are dispatched on [`GameState::events`], then hooks run `after_frame`. Plugins run
+//!   alongside, as described in the [`plugin`](crate::plugin) module. The CPU time of each
+//!   frame is recorded in [`engine_stats`](crate::stats::engine_stats).
+//! - Event listeners run while the game state is borrowed: capture what they change (a
+//!   `FloatingTextManager`, a counter) in their own `Rc<RefCell<_>>`, not the [`SharedState`].
+//! - [`GameState::set_time_scale`] slows down (or speeds up) the clock, the published
+//!   [`game_dt`](crate::game_time::game_dt) and the audio together; pause with
+//!   `state.clock_mut().pause()`.
+//! - Settings are loaded from the platform config directory of the app id and saved on change
+//!   (unless [`EngineConfig::persist_settings`] is off);
+//!   [`GameState::set_settings`] also applies them to the window and the audio.
+//! - Settings or manifest files that fail to load fall back to defaults; the errors are kept in
+//!   [`GameState::startup_errors`].
+//! - See each struct and function's documentation for more.
+
+use std::cell::RefCell;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use egui::{Context, Vec2};
+#[cfg(feature = "kira")]
+use kira::tween::Tween;
+use web_time::Instant;
+
+#[cfg(feature = "kira")]
+use crate::audio::AudioManager;
+use crate::assets::{AssetManager, AssetManifest};
+use crate::card::{Deck, GameContext, Relic};
+use crate::combat_log::CombatLog;
+use crate::events::EventBus;
+use crate::game_time::GameClock;
+use crate::input::{Action, InputMap};
+use crate::map::RunMap;
+use crate::plugin::{EnginePlugin, PluginRegistry};
+use crate::stats;
+use crate::settings::{GameSettings, Settings, SettingsChange, SettingsError};
+use crate::ui_loading::LoadingScreen;
+use crate::ui_screen::{Screen, ScreenStack, Transition};
+
+/// Shared handle to the [`GameState`], for screens to capture.
+pub type SharedState = Rc<RefCell<GameState>>;
+
+/// State of the current run.
+pub struct RunState {
+    pub deck: Deck,
+    pub relics: Vec<Relic>,
+    pub gold: u32,
+    pub map: RunMap,
+    /// The fight in progress, if any.
+    pub battle: Option<GameContext>,
+    pub log: CombatLog,
+}
+
+impl Default for RunState {
+    fn default() -> Self {
+        Self {
+            deck: Deck::new(Vec::new()),
+            relics: Vec::new(),
+            gold: 0,
+            map: RunMap::new(),
+            battle: None,
+            log: CombatLog::new(),
+        }
+    }
+}
+
+/// Everything the engine owns besides its screens.
+pub struct GameState {
+    /// Textures, sounds and music, loaded by manifest bundle.
+    pub assets: AssetManager,
+    /// The current run; replace it to start a new one.
+    pub run: RunState,
+    /// Combat and run events, dispatched once per frame.
+    pub events: EventBus,
+    settings: Settings,
+    clock: GameClock,
+    actions: Vec<Action>,
+    startup_errors: Vec<crate::Error>,
+}
+
+impl GameState {
+    /// Returns the current settings.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::engine::{DeckbuilderEngine, EngineConfig};
+    /// let engine = DeckbuilderEngine::new(&egui::Context::default(), EngineConfig::new("Demo").audio(false));
+    /// assert_eq!(engine.state().borrow().settings().ui_scale, 1.0);
+    /// ```
+    pub fn settings(&self) -> &GameSettings {
+        self.settings.get()
+    }
+
+    /// Returns the keybindings of the current settings.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::engine::{DeckbuilderEngine, EngineConfig};
+    /// # use deckbuilder_eng::input::Action;
+    /// let engine = DeckbuilderEngine::new(&egui::Context::default(), EngineConfig::new("Demo").audio(false));
+    /// assert!(engine.state().borrow().input().key_for(Action::EndTurn).is_some());
+    /// ```
+    pub fn input(&self) -> &InputMap {
+        &self.settings.get().keybindings
+    }
+
+    /// Replaces the settings, saves them and applies them to the window (through the settings
+    /// subscription) and to the audio manager.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::engine::{DeckbuilderEngine, EngineConfig};
+    /// let config = EngineConfig::new("Demo").audio(false).persist_settings(false);
+    /// let engine = DeckbuilderEngine::new(&egui::Context::default(), config);
+    /// let mut state = engine.state().borrow_mut();
+    /// let mut draft = state.settings().clone();
+    /// draft.music_volume = 0.3;
+    /// assert!(state.set_settings(draft).unwrap().audio);
+    /// ```
+    pub fn set_settings(&mut self, settings: GameSettings) -> Result<SettingsChange, SettingsError> {
+        let change = self.settings.set(settings);
+        #[cfg(feature = "kira")]
+        if change.as_ref().is_ok_and(|change| change.audio) {
+            self.apply_audio();
+        }
+        change
+    }
+
+    /// Changes the settings in place; see [`GameState::set_settings`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::engine::{DeckbuilderEngine, EngineConfig};
+    /// let config = EngineConfig::new("Demo").audio(false).persist_settings(false);
+    /// let engine = DeckbuilderEngine::new(&egui::Context::default(), config);
+    /// let change = engine.state().borrow_mut().update_settings(|s| s.language = "tr".to_owned()).unwrap();
+    /// assert!(change.language);
+    /// ```
+    pub fn update_settings(&mut self, edit: impl FnOnce(&mut GameSettings)) -> Result<SettingsChange, SettingsError> {
+        let mut settings = self.settings.get().clone();
+        edit(&mut settings);
+        self.set_settings(settings)
+    }
+
+    /// Returns the settings service, e.g. to subscribe to changes.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::engine::{DeckbuilderEngine, EngineConfig};
+    /// let engine = DeckbuilderEngine::new(&egui::Context::default(), EngineConfig::new("Demo").audio(false));
+    /// engine.state().borrow_mut().settings_service().subscribe(|_, change| println!("{change:?}"));
+    /// ```
+    pub fn settings_service(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Returns the game clock.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::engine::{DeckbuilderEngine, EngineConfig};
+    /// let engine = DeckbuilderEngine::new(&egui::Context::default(), EngineConfig::new("Demo").audio(false));
+    /// assert!(!engine.state().borrow().clock().is_paused());
+    /// ```
+    pub fn clock(&self) -> &GameClock {
+        &self.clock
+    }
+
+    /// Returns the game clock mutably, e.g. to pause it behind a menu.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::engine::{DeckbuilderEngine, EngineConfig};
+    /// let engine = DeckbuilderEngine::new(&egui::Context::default(), EngineConfig::new("Demo").audio(false));
+    /// engine.state().borrow_mut().clock_mut().pause();
+    /// ```
+    pub fn clock_mut(&mut self) -> &mut GameClock {
+        &mut self.clock
+    }
+
+    /// Sets the speed of game time (0.25 for slow motion, 1 for normal) and the playback rate
+    /// of the audio to match.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::engine::{DeckbuilderEngine, EngineConfig};
+    /// let engine = DeckbuilderEngine::new(&egui::Context::default(), EngineConfig::new("Demo").audio(false));
+    /// engine.state().borrow_mut().set_time_scale(0.25);
+    /// assert_eq!(engine.state().borrow().clock().time_scale(), 0.25);
+    /// ```
+    pub fn set_time_scale(&mut self, scale: f32) {
+        self.clock.set_time_scale(scale);
+        #[cfg(feature = "kira")]
+        if let Some(audio) = self.assets.audio_mut()
+            && let Err(err) = audio.set_global_playback_rate(self.clock.time_scale(), Tween::default())
+        {
+            tracing::warn!(%err, "failed to set the audio playback rate");
+        }
+    }
+
+    /// Returns the actions triggered by input this frame.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::engine::SharedState;
+    /// # use deckbuilder_eng::input::Action;
+    /// # fn demo(state: &SharedState) {
+    /// if state.borrow().actions().contains(&Action::EndTurn) {
+    ///     println!("End turn");
+    /// }
+    /// # }
+    /// ```
+    pub fn actions(&self) -> &[Action] {
+        &self.actions
+    }
+
+    /// Returns the errors of loading the settings, the asset manifest or the audio output at
+    /// startup; the engine runs with defaults in their place.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::engine::{DeckbuilderEngine, EngineConfig};
+    /// let config = EngineConfig::new("Demo").audio(false).manifest("missing.manifest");
+    /// let engine = DeckbuilderEngine::new(&egui::Context::default(), config);
+    /// assert_eq!(engine.state().borrow().startup_errors().len(), 1);
+    /// ```
+    pub fn startup_errors(&self) -> &[crate::Error] {
+        &self.startup_errors
+    }
+
+    #[cfg(feature = "kira")]
+    fn apply_audio(&mut self) {
+        if let Some(audio) = self.assets.audio_mut()
+            && let Err(err) = self.settings.get().apply_audio(audio)
+        {
+            tracing::warn!(%err, "failed to apply the audio settings");
+        }
+    }
+}
+
+/// Starts the audio manager if `enabled` and sets its volumes from `settings`.
+#[cfg(feature = "kira")]
+fn start_audio(
+    mut assets: AssetManager,
+    enabled: bool,
+    settings: &GameSettings,
+    errors: &mut Vec<crate::Error>,
+) -> AssetManager {
+    if !enabled {
+        return assets;
+    }
+    match AudioManager::new() {
+        Ok(mut audio) => match settings.apply_audio(&mut audio) {
+            Ok(()) => assets.set_audio(audio),
+            Err(err) => {
+                errors.push(err);
+                assets.set_audio(audio);
+            }
+        },
+        Err(err) => errors.push(err),
+    }
+    assets
+}
+
+/// Engine access for [`EngineHook`]s.
+pub struct EngineContext<'a> {
+    pub ctx: &'a Context,
+    pub state: &'a mut GameState,
+    pub screens: &'a mut ScreenStack,
+}
+
+/// Extension points called by the engine every frame. All methods do nothing by default.
+pub trait EngineHook {
+    /// Called once, before the first frame.
+    fn on_start(&mut self, _engine: &mut EngineContext) {}
+
+    /// Called once per game clock tick with the tick length, before `before_frame`. Runs
+    /// zero or several times a frame, never while the clock is paused.
+    fn fixed_update(&mut self, _dt: f32, _engine: &mut EngineContext) {}
+
+    /// Called every frame before the screens are drawn.
+    fn before_frame(&mut self, _engine: &mut EngineContext) {}
+
+    /// Called for each input action triggered this frame, after `before_frame`.
+    fn on_action(&mut self, _action: Action, _engine: &mut EngineContext) {}
+
+    /// Called every frame after the screens are drawn.
+    fn after_frame(&mut self, _engine: &mut EngineContext) {}
+}
+
+type StartScreen = Box<dyn FnOnce(SharedState) -> Box<dyn Screen>>;
+
+/// Configuration of a [`DeckbuilderEngine`].
+pub struct EngineConfig {
+    title: String,
+    app_id: String,
+    window_size: Vec2,
+    manifest: Option<PathBuf>,
+    preload: Vec<String>,
+    settings: GameSettings,
+    persist_settings: bool,
+    audio: bool,
+    clock: GameClock,
+    transition: Transition,
+    start_screen: Option<StartScreen>,
+    hooks: Vec<Box<dyn EngineHook>>,
+    plugins: PluginRegistry,
+}
+
+impl EngineConfig {
+    /// Creates a configuration with the window `title`, app id `deckbuilder`, default settings,
+    /// audio enabled and no screens.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::engine::EngineConfig;
+    /// let config = EngineConfig::new("My Deckbuilder");
+    /// ```
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            app_id: "deckbuilder".to_owned(),
+            window_size: Vec2::new(1280.0, 720.0),
+            manifest: None,
+            preload: Vec::new(),
+            settings: GameSettings::default(),
+            persist_settings: true,
+            audio: true,
+            clock: GameClock::default(),
+            transition: Transition::Fade { duration: 0.3 },
+            start_screen: None,
+            hooks: Vec::new(),
+            plugins: PluginRegistry::new(),
+        }
+    }
+
+    /// Sets the name of the config directory settings are saved in.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::engine::EngineConfig;
+    /// let config = EngineConfig::new("My Deckbuilder").app_id("my_deckbuilder");
+    /// ```
+    pub fn app_id(mut self, app_id: impl Into<String>) -> Self {
+        self.app_id = app_id.into();
+        self
+    }
+
+    /// Sets the initial window size in points.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::engine::EngineConfig;
+    /// let config = EngineConfig::new("My Deckbuilder").window_size(egui::vec2(1600.0, 900.0));
+    /// ```
+    pub fn window_size(mut self, size: Vec2) -> Self {
+        self.window_size = size;
+        self
+    }
+
+    /// Sets the asset manifest file.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::engine::EngineConfig;
+    /// let config = EngineConfig::new("My Deckbuilder").manifest("assets/manifest.txt");
+    /// ```
+    pub fn manifest(mut self, path: impl Into<PathBuf>) -> Self {
+        self.manifest = Some(path.into());
+        self
+    }
+
+    /// Loads manifest `bundle` behind a loading screen before the start screen is shown.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::engine::EngineConfig;
+    /// let config = EngineConfig::new("My Deckbuilder").preload("ui").preload("combat");
+    /// ```
+    pub fn preload(mut self, bundle: impl Into<String>) -> Self {
+        self.preload.push(bundle.into());
+        self
+    }
+
+    /// Sets the default settings, used for values missing from the settings file.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::engine::EngineConfig;
+    /// # use deckbuilder_eng::settings::GameSettings;
+    /// let defaults = GameSettings { music_volume: 0.4, ..Default::default() };
+    /// let config = EngineConfig::new("My Deckbuilder").settings(defaults);
+    /// ```
+    pub fn settings(mut self, defaults: GameSettings) -> Self {
+        self.settings = defaults;
+        self
+    }
+
+    /// Enables or disables loading and saving the settings file (enabled by default). Without
+    /// it, settings start from the defaults and changes last until the game exits.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::engine::EngineConfig;
+    /// let config = EngineConfig::new("Balance Simulator").persist_settings(false);
+    /// ```
+    pub fn persist_settings(mut self, enabled: bool) -> Self {
+        self.persist_settings = enabled;
+        self
+    }
+
+    /// Enables or disables the audio manager (enabled by default; needs the `kira` feature).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::engine::EngineConfig;
+    /// let config = EngineConfig::new("Balance Simulator").audio(false);
+    /// ```
+    pub fn audio(mut self, enabled: bool) -> Self {
+        self.audio = enabled;
+        self
+    }
+
+    /// Sets the game clock, e.g. for a different tick rate (60 Hz by default).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::engine::EngineConfig;
+    /// # use deckbuilder_eng::game_time::GameClock;
+    /// let config = EngineConfig::new("My Deckbuilder").clock(GameClock::new(1.0 / 30.0));
+    /// ```
+    pub fn clock(mut self, clock: GameClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Sets the transition played when the top screen changes.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::engine::EngineConfig;
+    /// # use deckbuilder_eng::ui_screen::Transition;
+    /// let config = EngineConfig::new("My Deckbuilder").transition(Transition::None);
+    /// ```
+    pub fn transition(mut self, transition: Transition) -> Self {
+        self.transition = transition;
+        self
+    }
+
+    /// Sets the first screen, built with the handle to the game state.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::engine::{EngineConfig, SharedState};
+    /// # use deckbuilder_eng::ui_screen::Screen;
+    /// # fn main_menu(state: SharedState) -> Box<dyn Screen> { unimplemented!() }
+    /// let config = EngineConfig::new("My Deckbuilder").start_screen(main_menu);
+    /// ```
+    pub fn start_screen(mut self, screen: impl FnOnce(SharedState) -> Box<dyn Screen> + 'static) -> Self {
+        self.start_screen = Some(Box::new(screen));
+        self
+    }
+
+    /// Adds a hook; hooks run in the order they were added.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::engine::{EngineConfig, EngineContext, EngineHook};
+    /// struct Autosave;
+    /// impl EngineHook for Autosave {
+    ///     fn after_frame(&mut self, engine: &mut EngineContext) {
+    ///         // Save the run when a fight ends.
+    ///     }
+    /// }
+    /// let config = EngineConfig::new("My Deckbuilder").hook(Autosave);
+    /// ```
+    pub fn hook(mut self, hook: impl EngineHook + 'static) -> Self {
+        self.hooks.push(Box::new(hook));
+        self
+    }
+
+    /// Adds a plugin; plugins run in the order they were added. A plugin whose name is already
+    /// taken is ignored.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::engine::EngineConfig;
+    /// # use deckbuilder_eng::plugin::EnginePlugin;
+    /// struct Analytics;
+    /// impl EnginePlugin for Analytics {
+    ///     fn name(&self) -> &str {
+    ///         "analytics"
+    ///     }
+    /// }
+    /// let config = EngineConfig::new("My Deckbuilder").plugin(Analytics);
+    /// ```
+    pub fn plugin(mut self, plugin: impl EnginePlugin + 'static) -> Self {
+        self.plugins.add(plugin);
+        self
+    }
+}
+
+/// The game: screens, game state, hooks and plugins, run as an `eframe::App`.
+pub struct DeckbuilderEngine {
+    screens: ScreenStack,
+    state: SharedState,
+    hooks: Vec<Box<dyn EngineHook>>,
+    plugins: PluginRegistry,
+    started: bool,
+}
+
+impl DeckbuilderEngine {
+    /// Builds the engine for `ctx`: loads the settings and the manifest, starts the audio
+    /// manager and pushes the loading screen or the start screen.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::engine::{DeckbuilderEngine, EngineConfig};
+    /// let engine = DeckbuilderEngine::new(&egui::Context::default(), EngineConfig::new("Demo").audio(false));
+    /// assert!(engine.screens().is_empty());
+    /// ```
+    pub fn new(ctx: &Context, config: EngineConfig) -> Self {
+        let mut startup_errors = Vec::new();
+        let mut settings = if config.persist_settings {
+            Settings::open_for_app(&config.app_id, config.settings.clone()).unwrap_or_else(|err| {
+                startup_errors.push(err.into());
+                Settings::new(config.settings.clone())
+            })
+        } else {
+            Settings::new(config.settings.clone())
+        };
+        settings.get().apply_to_context(ctx);
+        let settings_ctx = ctx.clone();
+        settings.subscribe(move |current, change| current.apply_change_to_context(&settings_ctx, change));
+
+        let manifest = match &config.manifest {
+            Some(path) => AssetManifest::load(path).unwrap_or_else(|err| {
+                startup_errors.push(err.into());
+                AssetManifest::new()
+            }),
+            None => AssetManifest::new(),
+        };
+        let assets = AssetManager::new(ctx, manifest);
+        #[cfg(feature = "kira")]
+        let assets = start_audio(assets, config.audio, settings.get(), &mut startup_errors);
+        let state = Rc::new(RefCell::new(GameState {
+            assets,
+            run: RunState::default(),
+            events: EventBus::new(),
+            settings,
+            clock: config.clock,
+            actions: Vec::new(),
+            startup_errors,
+        }));
+        let mut screens = ScreenStack::new().transition(config.transition);
+        if let Some(start_screen) = config.start_screen {
+            let screen_state = Rc::clone(&state);
+            let next = move || start_screen(screen_state);
+            if config.preload.is_empty() {
+                screens.push(next());
+            } else {
+                let progress = state.borrow().assets.progress().clone();
+                let mut loading = LoadingScreen::new(progress, next);
+                for bundle in config.preload {
+                    let step_state = Rc::clone(&state);
+                    loading = loading.step(bundle.clone(), move || {
+                        step_state.borrow_mut().assets.load_bundle_step(&bundle).unwrap_or_else(|err| {
+                            tracing::warn!(%err, "failed to preload a bundle");
+                            1.0
+                        })
+                    });
+                }
+                screens.push(Box::new(loading));
+            }
+        }
+        Self {
+            screens,
+            state,
+            hooks: config.hooks,
+            plugins: config.plugins,
+            started: false,
+        }
+    }
+
+    /// Opens a native window and runs the game until it is closed.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use deckbuilder_eng::engine::{DeckbuilderEngine, EngineConfig};
+    /// DeckbuilderEngine::run(EngineConfig::new("My Deckbuilder")).unwrap();
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn run(config: EngineConfig) -> eframe::Result<()> {
+        let title = config.title.clone();
+        let options = eframe::NativeOptions {
+            viewport: egui::ViewportBuilder::default()
+                .with_title(&title)
+                .with_inner_size(config.window_size),
+            ..Default::default()
+        };
+        eframe::run_native(
+            &title,
+            options,
+            Box::new(move |cc| Box::new(DeckbuilderEngine::new(&cc.egui_ctx, config))),
+        )
+    }
+
+    /// Returns the handle to the game state.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::engine::{DeckbuilderEngine, EngineConfig};
+    /// let engine = DeckbuilderEngine::new(&egui::Context::default(), EngineConfig::new("Demo").audio(false));
+    /// engine.state().borrow_mut().run.gold = 99;
+    /// ```
+    pub fn state(&self) -> &SharedState {
+        &self.state
+    }
+
+    /// Returns the screen stack.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::engine::{DeckbuilderEngine, EngineConfig};
+    /// let engine = DeckbuilderEngine::new(&egui::Context::default(), EngineConfig::new("Demo").audio(false));
+    /// assert_eq!(engine.screens().len(), 0);
+    /// ```
+    pub fn screens(&self) -> &ScreenStack {
+        &self.screens
+    }
+
+    /// Returns the screen stack mutably, e.g. to push a screen.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::engine::{DeckbuilderEngine, EngineConfig};
+    /// # use deckbuilder_eng::ui_screen::Screen;
+    /// # fn pause_menu() -> Box<dyn Screen> { unimplemented!() }
+    /// # fn demo(engine: &mut DeckbuilderEngine) {
+    /// engine.screens_mut().push(pause_menu());
+    /// # }
+    /// ```
+    pub fn screens_mut(&mut self) -> &mut ScreenStack {
+        &mut self.screens
+    }
+
+    /// Adds a hook after the configured ones.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::engine::{DeckbuilderEngine, EngineConfig, EngineHook};
+    /// struct Nothing;
+    /// impl EngineHook for Nothing {}
+    /// let mut engine = DeckbuilderEngine::new(&egui::Context::default(), EngineConfig::new("Demo").audio(false));
+    /// engine.add_hook(Nothing);
+    /// ```
+    pub fn add_hook(&mut self, hook: impl EngineHook + 'static) {
+        self.hooks.push(Box::new(hook));
+    }
+
+    /// Adds a plugin after the configured ones; it is initialized on the next frame. Returns
+    /// false if a plugin of the same name is already registered.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::engine::{DeckbuilderEngine, EngineConfig};
+    /// # use deckbuilder_eng::plugin::EnginePlugin;
+    /// struct Analytics;
+    /// impl EnginePlugin for Analytics {
+    ///     fn name(&self) -> &str {
+    ///         "analytics"
+    ///     }
+    /// }
+    /// let mut engine = DeckbuilderEngine::new(&egui::Context::default(), EngineConfig::new("Demo").audio(false));
+    /// assert!(engine.add_plugin(Analytics));
+    /// ```
+    pub fn add_plugin(&mut self, plugin: impl EnginePlugin + 'static) -> bool {
+        self.plugins.add(plugin)
+    }
+
+    /// Returns the plugin registry.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::engine::{DeckbuilderEngine, EngineConfig};
+    /// let engine = DeckbuilderEngine::new(&egui::Context::default(), EngineConfig::new("Demo").audio(false));
+    /// assert!(engine.plugins().is_empty());
+    /// ```
+    pub fn plugins(&self) -> &PluginRegistry {
+        &self.plugins
+    }
+
+    /// Returns the plugin registry mutably, e.g. to disable a plugin.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::engine::{DeckbuilderEngine, EngineConfig};
+    /// let mut engine = DeckbuilderEngine::new(&egui::Context::default(), EngineConfig::new("Demo").audio(false));
+    /// engine.plugins_mut().set_enabled("analytics", false);
+    /// ```
+    pub fn plugins_mut(&mut self) -> &mut PluginRegistry {
+        &mut self.plugins
+    }
+
+    /// Runs one frame: polls input, advances the game clock, runs the hooks and draws the
+    /// screens. Called by eframe; call it yourself to embed the engine in another app.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::engine::{DeckbuilderEngine, EngineConfig};
+    /// let ctx = egui::Context::default();
+    /// let mut engine = DeckbuilderEngine::new(&ctx, EngineConfig::new("Demo").audio(false));
+    /// let _ = ctx.run(Default::default(), |ctx| engine.frame(ctx));
+    /// ```
+    pub fn frame(&mut self, ctx: &Context) {
+        let started = Instant::now();
+        let turn = self.state.borrow().run.battle.as_ref().map(|battle| battle.turn);
+        let _turn = turn.map(|turn| tracing::info_span!("combat_turn", turn).entered());
+        {
+            let mut state = self.state.borrow_mut();
+            let actions = state.input().poll_actions(ctx);
+            state.actions = actions.clone();
+            let steps = state.clock.advance(ctx.input(|i| i.stable_dt));
+            state.clock.publish(ctx);
+            let step = state.clock.step();
+            let mut engine = EngineContext {
+                ctx,
+                state: &mut state,
+                screens: &mut self.screens,
+            };
+            if !self.started {
+                self.started = true;
+                for hook in &mut self.hooks {
+                    hook.on_start(&mut engine);
+                }
+            }
+            self.plugins.init(&mut engine);
+            for _ in 0..steps {
+                for hook in &mut self.hooks {
+                    hook.fixed_update(step, &mut engine);
+                }
+            }
+            for hook in &mut self.hooks {
+                hook.before_frame(&mut engine);
+            }
+            for action in actions {
+                for hook in &mut self.hooks {
+                    hook.on_action(action, &mut engine);
+                }
+            }
+            self.plugins.update(&mut engine);
+        }
+        self.screens.show(ctx);
+        let mut state = self.state.borrow_mut();
+        let state = &mut *state;
+        if let Some(battle) = &mut state.run.battle {
+            state.events.extend(battle.take_events());
+        }
+        let events = state.events.pending().to_vec();
+        state.events.dispatch();
+        let mut engine = EngineContext {
+            ctx,
+            state,
+            screens: &mut self.screens,
+        };
+        for event in &events {
+            self.plugins.on_event(event, &mut engine);
+        }
+        self.plugins.ui(&mut engine);
+        for hook in &mut self.hooks {
+            hook.after_frame(&mut engine);
+        }
+        stats::record_frame(started.elapsed());
+    }
+}
+
+impl eframe::App for DeckbuilderEngine {
+    fn update(&mut self, ctx: &Context, _frame: &mut eframe::Frame) {
+        self.frame(ctx);
+    }
+}
@@ -0,0 +1,248 @@
+//! Crate-wide error type.
+//!
+//! Provides the [`Error`] enum returned by fallible loaders and audio calls across the crate,
+//! and a [`Result`] alias, so errors can be matched on and carry the path or name they concern.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::texture::TextureManager;
+//! use deckbuilder_eng::Error;
+//!
+//! let mut textures = TextureManager::new(&egui::Context::default());
+//! match textures.load("assets/cards/missing.png") {
+//!     Ok(_) => {}
+//!     Err(Error::Io { path, source }) => eprintln!("can't read {}: {source}", path.display()),
+//!     Err(err) => eprintln!("{err}"),
+//! }
+//! ```
+//!
+//! # Details
+//!
+//! - Module-specific errors ([`AssetError`], [`CardDbError`], [`CardThemeError`], [`I18nError`],
+//!   [`NetError`], [`SaveError`], [`SettingsError`], `LoggingError` with the `logging` feature and
+//!   `MetricsError` with the `metrics` feature) convert into [`Error`] with `?`.
+//! - The `Image` variant and the asset, card theme and settings conversions need the `egui`
+//!   feature.
+//! - [`crate::vfs`] returns plain `io::Result`s; wrap them with [`Error::io`] to add the path.
+//! - See each struct and function's documentation for more.
+
+use std::fmt::Display;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[cfg(feature = "egui")]
+use image::ImageError;
+use thiserror::Error;
+
+#[cfg(feature = "egui")]
+use crate::assets::AssetError;
+use crate::card_db::CardDbError;
+use crate::i18n::I18nError;
+#[cfg(feature = "logging")]
+use crate::logging::LoggingError;
+#[cfg(feature = "metrics")]
+use crate::metrics::MetricsError;
+use crate::net::NetError;
+use crate::savegame::SaveError;
+#[cfg(feature = "egui")]
+use crate::settings::SettingsError;
+#[cfg(feature = "egui")]
+use crate::ui_card_render::CardThemeError;
+
+/// Errors of the crate's loaders and audio calls.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// A sound failed to load or play, or a track command failed. `name` is the sound's name,
+    /// or `music`/`sfx` for track-wide calls.
+    #[error("audio `{name}`: {message}")]
+    Audio { name: String, message: String },
+    /// An image couldn't be decoded or encoded.
+    #[cfg(feature = "egui")]
+    #[error("image {}: {source}", path.display())]
+    Image {
+        path: PathBuf,
+        #[source]
+        source: ImageError,
+    },
+    /// A file couldn't be read or written.
+    #[error("{}: {source}", path.display())]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+    /// A data file (asset manifest, string table, ...) is malformed or incomplete.
+    #[error("{name}: {message}")]
+    Data { name: String, message: String },
+    /// A card or card theme is invalid.
+    #[error("card `{name}`: {message}")]
+    Card { name: String, message: String },
+}
+
+/// Result with the crate's [`Error`].
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+impl Error {
+    /// Creates an [`Error::Audio`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::Error;
+    /// let err = Error::audio("click", "sound is not loaded");
+    /// assert_eq!(err.to_string(), "audio `click`: sound is not loaded");
+    /// ```
+    pub fn audio(name: &str, message: impl Display) -> Self {
+        Error::Audio {
+            name: name.to_owned(),
+            message: message.to_string(),
+        }
+    }
+
+    /// Creates an [`Error::Io`] for `path`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::Error;
+    /// let source = std::io::Error::from(std::io::ErrorKind::NotFound);
+    /// assert!(matches!(Error::io("saves/run.json", source), Error::Io { .. }));
+    /// ```
+    pub fn io(path: impl AsRef<Path>, source: io::Error) -> Self {
+        Error::Io {
+            path: path.as_ref().to_path_buf(),
+            source,
+        }
+    }
+
+    /// Creates an [`Error::Image`] for `path`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::Error;
+    /// let source = image::load_from_memory(b"not an image").unwrap_err();
+    /// assert!(Error::image("broken.png", source).to_string().starts_with("image broken.png"));
+    /// ```
+    #[cfg(feature = "egui")]
+    pub fn image(path: impl AsRef<Path>, source: ImageError) -> Self {
+        Error::Image {
+            path: path.as_ref().to_path_buf(),
+            source,
+        }
+    }
+
+    /// Returns the path the error concerns, if it has one.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::Error;
+    /// let err = Error::io("assets/a.png", std::io::ErrorKind::NotFound.into());
+    /// assert_eq!(err.path(), Some(std::path::Path::new("assets/a.png")));
+    /// assert_eq!(Error::audio("click", "stopped").path(), None);
+    /// ```
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            #[cfg(feature = "egui")]
+            Error::Image { path, .. } => Some(path),
+            Error::Io { path, .. } => Some(path),
+            _ => None,
+        }
+    }
+}
+
+/// Maps a failed audio call on `name` into an [`Error::Audio`], for `map_err`.
+#[cfg(feature = "kira")]
+pub(crate) fn audio_error<E: Display>(name: &str) -> impl FnOnce(E) -> Error + '_ {
+    move |err| Error::audio(name, err)
+}
+
+#[cfg(feature = "egui")]
+impl From<AssetError> for Error {
+    fn from(err: AssetError) -> Self {
+        match err {
+            AssetError::Load { bundle, failed } => Error::Data {
+                name: format!("asset bundle `{bundle}`"),
+                message: format!("failed to load {} asset(s)", failed.len()),
+            },
+            err => Error::Data {
+                name: "asset manifest".to_owned(),
+                message: err.to_string(),
+            },
+        }
+    }
+}
+
+#[cfg(feature = "egui")]
+impl From<CardThemeError> for Error {
+    fn from(err: CardThemeError) -> Self {
+        Error::Card {
+            name: "theme".to_owned(),
+            message: err.to_string(),
+        }
+    }
+}
+
+impl From<CardDbError> for Error {
+    fn from(err: CardDbError) -> Self {
+        Error::Data {
+            name: "card database".to_owned(),
+            message: err.to_string(),
+        }
+    }
+}
+
+impl From<I18nError> for Error {
+    fn from(err: I18nError) -> Self {
+        Error::Data {
+            name: "string table".to_owned(),
+            message: err.to_string(),
+        }
+    }
+}
+
+#[cfg(feature = "logging")]
+impl From<LoggingError> for Error {
+    fn from(err: LoggingError) -> Self {
+        Error::Data {
+            name: "logging".to_owned(),
+            message: err.to_string(),
+        }
+    }
+}
+
+#[cfg(feature = "metrics")]
+impl From<MetricsError> for Error {
+    fn from(err: MetricsError) -> Self {
+        Error::Data {
+            name: "metrics".to_owned(),
+            message: err.to_string(),
+        }
+    }
+}
+
+impl From<NetError> for Error {
+    fn from(err: NetError) -> Self {
+        Error::Data {
+            name: "network".to_owned(),
+            message: err.to_string(),
+        }
+    }
+}
+
+impl From<SaveError> for Error {
+    fn from(err: SaveError) -> Self {
+        Error::Data {
+            name: "save file".to_owned(),
+            message: err.to_string(),
+        }
+    }
+}
+
+#[cfg(feature = "egui")]
+impl From<SettingsError> for Error {
+    fn from(err: SettingsError) -> Self {
+        Error::Data {
+            name: "settings".to_owned(),
+            message: err.to_string(),
+        }
+    }
+}
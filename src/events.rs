@@ -0,0 +1,347 @@
+//! Event bus for deckbuilder games.
+//!
+//! Provides [`EventBus`], a typed publish/subscribe queue, and [`GameEvent`], the combat and run
+//! events it carries by default. Combat emits events, and audio cues, floating texts, particles,
+//! achievements and other systems react to them as listeners, without calling each other.
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::cell::Cell;
+//! use std::rc::Rc;
+//! use deckbuilder_eng::card::GameContext;
+//! use deckbuilder_eng::events::*;
+//!
+//! let mut bus = EventBus::new();
+//!
+//! // A "first blood" achievement
+//! let unlocked = Rc::new(Cell::new(false));
+//! let achievement = Rc::clone(&unlocked);
+//! bus.subscribe_kind(EventKind::Defeated, move |_| achievement.set(true));
+//!
+//! // Combat records what happens...
+//! let mut battle = GameContext::new(30, 10);
+//! battle.deal_damage(12);
+//!
+//! // ...and the bus delivers it to the listeners
+//! bus.extend(battle.take_events());
+//! assert_eq!(bus.dispatch(), 2);
+//! assert!(unlocked.get());
+//! ```
+//!
+//! # Details
+//!
+//! - [`EventBus::emit`] queues an event; [`EventBus::dispatch`] delivers the queue to every
+//!   listener in subscription order. [`EventBus::publish`] does both at once.
+//! - [`GameContext`](crate::card::GameContext) records damage, heals, energy, turns and defeats;
+//!   collect them with `take_events`. The engine forwards the current battle's events to
+//!   `GameState::events` and dispatches them every frame; elsewhere, drain them or turn
+//!   recording off with `record_events(false)`.
+//! - Ready-made reactions: `FloatingTextManager::spawn_event`, `ParticleSystem::burst_event`
+//!   and `AudioCues::play`.
+//! - The bus works with any event type; [`GameEvent::Custom`] covers game-specific events
+//!   without one.
+//! - See each struct and function's documentation for more.
+
+use crate::stats::{self, Counter};
+
+/// Side of a fight an event applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Target {
+    Player,
+    Enemy,
+}
+
+/// Kind of a [`GameEvent`], for filtering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EventKind {
+    CardPlayed,
+    Damage,
+    Heal,
+    Block,
+    StatusApplied,
+    EnergySpent,
+    TurnStarted,
+    Defeated,
+    BattleEnded,
+    GoldChanged,
+    Custom,
+}
+
+/// Something that happened in a fight or a run.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GameEvent {
+    CardPlayed { name: String, cost: u32 },
+    Damage { target: Target, amount: i32 },
+    Heal { target: Target, amount: i32 },
+    Block { target: Target, amount: i32 },
+    StatusApplied { target: Target, name: String, stacks: i32 },
+    EnergySpent { amount: u32 },
+    TurnStarted { turn: u32 },
+    /// `target` dropped to zero health.
+    Defeated { target: Target },
+    BattleEnded { won: bool },
+    /// Gold gained (positive) or spent (negative).
+    GoldChanged { amount: i32 },
+    /// A game-specific event.
+    Custom { name: String, value: i32 },
+}
+
+impl GameEvent {
+    /// Returns the kind of the event.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::events::{EventKind, GameEvent};
+    /// assert_eq!(GameEvent::TurnStarted { turn: 2 }.kind(), EventKind::TurnStarted);
+    /// ```
+    pub fn kind(&self) -> EventKind {
+        match self {
+            GameEvent::CardPlayed { .. } => EventKind::CardPlayed,
+            GameEvent::Damage { .. } => EventKind::Damage,
+            GameEvent::Heal { .. } => EventKind::Heal,
+            GameEvent::Block { .. } => EventKind::Block,
+            GameEvent::StatusApplied { .. } => EventKind::StatusApplied,
+            GameEvent::EnergySpent { .. } => EventKind::EnergySpent,
+            GameEvent::TurnStarted { .. } => EventKind::TurnStarted,
+            GameEvent::Defeated { .. } => EventKind::Defeated,
+            GameEvent::BattleEnded { .. } => EventKind::BattleEnded,
+            GameEvent::GoldChanged { .. } => EventKind::GoldChanged,
+            GameEvent::Custom { .. } => EventKind::Custom,
+        }
+    }
+
+    /// Returns the side the event applies to, if any.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::events::{GameEvent, Target};
+    /// let hit = GameEvent::Damage { target: Target::Enemy, amount: 6 };
+    /// assert_eq!(hit.target(), Some(Target::Enemy));
+    /// ```
+    pub fn target(&self) -> Option<Target> {
+        match self {
+            GameEvent::Damage { target, .. }
+            | GameEvent::Heal { target, .. }
+            | GameEvent::Block { target, .. }
+            | GameEvent::StatusApplied { target, .. }
+            | GameEvent::Defeated { target } => Some(*target),
+            _ => None,
+        }
+    }
+}
+
+/// Handle of a listener subscribed to an [`EventBus`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ListenerId(u64);
+
+type Listener<E> = Box<dyn FnMut(&E)>;
+
+/// Queue of events and the listeners they are delivered to.
+pub struct EventBus<E = GameEvent> {
+    listeners: Vec<(ListenerId, Listener<E>)>,
+    queue: Vec<E>,
+    next_id: u64,
+}
+
+impl<E> Default for EventBus<E> {
+    fn default() -> Self {
+        Self {
+            listeners: Vec::new(),
+            queue: Vec::new(),
+            next_id: 0,
+        }
+    }
+}
+
+impl<E: 'static> EventBus<E> {
+    /// Creates a bus with no listeners.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::events::EventBus;
+    /// let bus: EventBus = EventBus::new();
+    /// assert_eq!(bus.listener_count(), 0);
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Calls `listener` with every dispatched event.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::events::EventBus;
+    /// let mut bus: EventBus = EventBus::new();
+    /// bus.subscribe(|event| println!("{event:?}"));
+    /// ```
+    pub fn subscribe(&mut self, listener: impl FnMut(&E) + 'static) -> ListenerId {
+        let id = ListenerId(self.next_id);
+        self.next_id += 1;
+        self.listeners.push((id, Box::new(listener)));
+        id
+    }
+
+    /// Calls `listener` with the dispatched events `filter` accepts.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::events::{EventBus, GameEvent};
+    /// let mut bus: EventBus = EventBus::new();
+    /// bus.subscribe_filtered(
+    ///     |event| matches!(event, GameEvent::Damage { amount, .. } if *amount >= 20),
+    ///     |_| println!("Big hit!"),
+    /// );
+    /// ```
+    pub fn subscribe_filtered(
+        &mut self,
+        filter: impl Fn(&E) -> bool + 'static,
+        mut listener: impl FnMut(&E) + 'static,
+    ) -> ListenerId {
+        self.subscribe(move |event| {
+            if filter(event) {
+                listener(event);
+            }
+        })
+    }
+
+    /// Removes a listener. Returns false if it was already removed.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::events::EventBus;
+    /// let mut bus: EventBus = EventBus::new();
+    /// let id = bus.subscribe(|_| {});
+    /// assert!(bus.unsubscribe(id));
+    /// assert!(!bus.unsubscribe(id));
+    /// ```
+    pub fn unsubscribe(&mut self, id: ListenerId) -> bool {
+        let len = self.listeners.len();
+        self.listeners.retain(|(listener, _)| *listener != id);
+        self.listeners.len() != len
+    }
+
+    /// Returns the number of listeners.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::events::EventBus;
+    /// let mut bus: EventBus = EventBus::new();
+    /// bus.subscribe(|_| {});
+    /// assert_eq!(bus.listener_count(), 1);
+    /// ```
+    pub fn listener_count(&self) -> usize {
+        self.listeners.len()
+    }
+
+    /// Queues `event` for the next [`EventBus::dispatch`].
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::events::{EventBus, GameEvent};
+    /// let mut bus = EventBus::new();
+    /// bus.emit(GameEvent::GoldChanged { amount: 25 });
+    /// assert_eq!(bus.pending().len(), 1);
+    /// ```
+    pub fn emit(&mut self, event: E) {
+        self.queue.push(event);
+    }
+
+    /// Queues several events in order.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::events::{EventBus, GameEvent};
+    /// let mut bus = EventBus::new();
+    /// bus.extend([GameEvent::TurnStarted { turn: 2 }, GameEvent::EnergySpent { amount: 1 }]);
+    /// assert_eq!(bus.pending().len(), 2);
+    /// ```
+    pub fn extend(&mut self, events: impl IntoIterator<Item = E>) {
+        self.queue.extend(events);
+    }
+
+    /// Returns the queued events.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::events::EventBus;
+    /// let bus: EventBus = EventBus::new();
+    /// assert!(bus.pending().is_empty());
+    /// ```
+    pub fn pending(&self) -> &[E] {
+        &self.queue
+    }
+
+    /// Delivers the queued events to the listeners and empties the queue. Returns the number
+    /// of events delivered.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::cell::Cell;
+    /// # use std::rc::Rc;
+    /// # use deckbuilder_eng::events::{EventBus, GameEvent};
+    /// let mut bus = EventBus::new();
+    /// let gold = Rc::new(Cell::new(0));
+    /// let counter = Rc::clone(&gold);
+    /// bus.subscribe(move |event| {
+    ///     if let GameEvent::GoldChanged { amount } = event {
+    ///         counter.set(counter.get() + amount);
+    ///     }
+    /// });
+    /// bus.emit(GameEvent::GoldChanged { amount: 25 });
+    /// assert_eq!(bus.dispatch(), 1);
+    /// assert_eq!(gold.get(), 25);
+    /// ```
+    pub fn dispatch(&mut self) -> usize {
+        let events = std::mem::take(&mut self.queue);
+        for event in &events {
+            for (_, listener) in &mut self.listeners {
+                listener(event);
+            }
+        }
+        stats::add(Counter::EventsDispatched, events.len() as u64);
+        events.len()
+    }
+
+    /// Delivers `event` to the listeners right away, after any queued events.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::events::{EventBus, GameEvent};
+    /// let mut bus = EventBus::new();
+    /// bus.publish(GameEvent::BattleEnded { won: true });
+    /// assert!(bus.pending().is_empty());
+    /// ```
+    pub fn publish(&mut self, event: E) {
+        self.emit(event);
+        self.dispatch();
+    }
+
+    /// Drops the queued events without delivering them.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::events::{EventBus, GameEvent};
+    /// let mut bus = EventBus::new();
+    /// bus.emit(GameEvent::TurnStarted { turn: 1 });
+    /// bus.clear();
+    /// assert_eq!(bus.dispatch(), 0);
+    /// ```
+    pub fn clear(&mut self) {
+        self.queue.clear();
+    }
+}
+
+impl EventBus<GameEvent> {
+    /// Calls `listener` with the dispatched events of `kind`.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::events::{EventBus, EventKind};
+    /// let mut bus = EventBus::new();
+    /// bus.subscribe_kind(EventKind::BattleEnded, |event| println!("{event:?}"));
+    /// ```
+    pub fn subscribe_kind(&mut self, kind: EventKind, listener: impl FnMut(&GameEvent) + 'static) -> ListenerId {
+        self.subscribe_filtered(move |event| event.kind() == kind, listener)
+    }
+}
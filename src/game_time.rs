@@ -0,0 +1,427 @@
+//! Game time for deckbuilder games.
+//!
+//! Provides [`GameClock`], a fixed-timestep clock that turns the frame time into a steady
+//! number of game ticks with pause and time scale (slow motion) controls, and the [`Tick`] trait
+//! for anything advanced by those ticks, plus helpers that make the clock available to the UI
+//! through the egui context.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::game_time::*;
+//!
+//! let mut clock = GameClock::new(1.0 / 60.0);
+//! let mut elapsed = 0.0;
+//! // A 30 FPS frame runs two 60 Hz ticks
+//! assert_eq!(clock.update(1.0 / 30.0, |dt| elapsed += dt), 2);
+//!
+//! // Half speed: the same frame now runs a single tick
+//! clock.set_time_scale(0.5);
+//! assert_eq!(clock.advance(1.0 / 30.0), 1);
+//!
+//! clock.pause();
+//! assert_eq!(clock.advance(1.0), 0);
+//!
+//! // Make it available to `ParticleSystem::show`, `ui_animated_sprite` and friends
+//! let ctx = egui::Context::default();
+//! clock.publish(&ctx);
+//! assert_eq!(game_dt(&ctx), 0.0);
+//! ```
+//!
+//! # Details
+//!
+//! - Each frame, [`GameClock::advance`] scales the real frame time, adds it to an accumulator and
+//!   returns how many fixed steps are due; [`GameClock::alpha`] is the leftover fraction of a step
+//!   for interpolating between ticks.
+//! - At most [`GameClock::max_steps`] ticks run per frame; the rest of a long hitch is dropped
+//!   instead of catching up over the next frames.
+//! - Once published with [`GameClock::publish`], particles, floating text, sprite animations and
+//!   health bar chips advance by [`game_dt`], so they freeze while paused and slow down with the
+//!   time scale. Without a published clock they use the egui frame time.
+//! - The `publish`, [`game_time`] and [`game_dt`] context helpers need the `egui` feature.
+//! - See each struct and function's documentation for more.
+
+#[cfg(feature = "egui")]
+use egui::{Context, Id};
+
+use crate::stats::{self, Counter};
+
+/// Something advanced by game time, e.g. a particle system or a visual timer.
+///
+/// # Example
+/// ```rust
+/// use deckbuilder_eng::game_time::{GameClock, Tick};
+///
+/// struct Flash {
+///     remaining: f32,
+/// }
+///
+/// impl Tick for Flash {
+///     fn tick(&mut self, dt: f32) {
+///         self.remaining = (self.remaining - dt).max(0.0);
+///     }
+/// }
+///
+/// let mut flash = Flash { remaining: 0.5 };
+/// let mut clock = GameClock::default();
+/// clock.update(0.25, |dt| flash.tick(dt));
+/// assert!(flash.remaining < 0.5);
+/// ```
+pub trait Tick {
+    /// Advances by `dt` seconds of game time.
+    fn tick(&mut self, dt: f32);
+}
+
+/// Fixed-timestep game clock with pause and time scale controls.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameClock {
+    step: f32,
+    max_steps: u32,
+    time_scale: f32,
+    paused: bool,
+    accumulator: f32,
+    frame_dt: f32,
+    steps: u32,
+    time: f64,
+    ticks: u64,
+}
+
+impl Default for GameClock {
+    /// A 60 Hz clock.
+    fn default() -> Self {
+        Self::new(1.0 / 60.0)
+    }
+}
+
+impl GameClock {
+    /// Creates a running clock that ticks every `step` seconds (at least a millisecond), at
+    /// normal speed and with up to 8 ticks per frame.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::game_time::GameClock;
+    /// let clock = GameClock::new(1.0 / 30.0);
+    /// assert_eq!(clock.step(), 1.0 / 30.0);
+    /// ```
+    pub fn new(step: f32) -> Self {
+        Self {
+            step: step.max(0.001),
+            max_steps: 8,
+            time_scale: 1.0,
+            paused: false,
+            accumulator: 0.0,
+            frame_dt: 0.0,
+            steps: 0,
+            time: 0.0,
+            ticks: 0,
+        }
+    }
+
+    /// Sets the most ticks run in a single frame (at least 1).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::game_time::GameClock;
+    /// let mut clock = GameClock::new(0.1).max_steps(2);
+    /// assert_eq!(clock.advance(1.0), 2);
+    /// ```
+    pub fn max_steps(mut self, max_steps: u32) -> Self {
+        self.max_steps = max_steps.max(1);
+        self
+    }
+
+    /// Returns the length of a tick in seconds.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::game_time::GameClock;
+    /// assert_eq!(GameClock::default().step(), 1.0 / 60.0);
+    /// ```
+    pub fn step(&self) -> f32 {
+        self.step
+    }
+
+    /// Advances the clock by a frame of `real_dt` seconds and returns how many ticks are due.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::game_time::GameClock;
+    /// let mut clock = GameClock::new(0.25);
+    /// assert_eq!(clock.advance(0.6), 2);
+    /// assert_eq!(clock.advance(0.2), 1);
+    /// ```
+    pub fn advance(&mut self, real_dt: f32) -> u32 {
+        self.frame_dt = if self.paused {
+            0.0
+        } else {
+            real_dt.max(0.0) * self.time_scale
+        };
+        self.accumulator += self.frame_dt;
+        let mut steps = 0;
+        while self.accumulator >= self.step && steps < self.max_steps {
+            self.accumulator -= self.step;
+            steps += 1;
+        }
+        if steps == self.max_steps {
+            self.accumulator = self.accumulator.min(self.step);
+        }
+        self.steps = steps;
+        self.ticks += u64::from(steps);
+        stats::add(Counter::FixedSteps, u64::from(steps));
+        self.time += f64::from(steps) * f64::from(self.step);
+        steps
+    }
+
+    /// Advances the clock like [`GameClock::advance`] and calls `tick` with the step length once
+    /// per due tick. Returns the number of ticks.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::game_time::GameClock;
+    /// let mut clock = GameClock::new(0.25);
+    /// let mut ticks = 0;
+    /// clock.update(0.75, |_| ticks += 1);
+    /// assert_eq!(ticks, 3);
+    /// ```
+    pub fn update(&mut self, real_dt: f32, mut tick: impl FnMut(f32)) -> u32 {
+        let steps = self.advance(real_dt);
+        for _ in 0..steps {
+            tick(self.step);
+        }
+        steps
+    }
+
+    /// Returns the number of ticks due in the last frame.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::game_time::GameClock;
+    /// let mut clock = GameClock::new(0.25);
+    /// clock.advance(0.5);
+    /// assert_eq!(clock.steps(), 2);
+    /// ```
+    pub fn steps(&self) -> u32 {
+        self.steps
+    }
+
+    /// Returns how far the clock is into the next tick, from 0 to 1, for interpolating what is
+    /// drawn between ticks.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::game_time::GameClock;
+    /// let mut clock = GameClock::new(0.1);
+    /// clock.advance(0.15);
+    /// assert!((clock.alpha() - 0.5).abs() < 1e-4);
+    /// ```
+    pub fn alpha(&self) -> f32 {
+        (self.accumulator / self.step).clamp(0.0, 1.0)
+    }
+
+    /// Returns the scaled length of the last frame: 0 while paused.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::game_time::GameClock;
+    /// let mut clock = GameClock::default();
+    /// clock.set_time_scale(0.5);
+    /// clock.advance(0.1);
+    /// assert_eq!(clock.frame_dt(), 0.05);
+    /// ```
+    pub fn frame_dt(&self) -> f32 {
+        self.frame_dt
+    }
+
+    /// Returns the game time in seconds: the length of all ticks so far.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::game_time::GameClock;
+    /// let mut clock = GameClock::new(0.5);
+    /// clock.advance(1.0);
+    /// assert_eq!(clock.time(), 1.0);
+    /// ```
+    pub fn time(&self) -> f64 {
+        self.time
+    }
+
+    /// Returns the number of ticks so far.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::game_time::GameClock;
+    /// let mut clock = GameClock::new(0.5);
+    /// clock.advance(1.0);
+    /// assert_eq!(clock.ticks(), 2);
+    /// ```
+    pub fn ticks(&self) -> u64 {
+        self.ticks
+    }
+
+    /// Sets the speed of game time: 1 is normal, 0.25 is slow motion. Negative values are
+    /// treated as 0.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::game_time::GameClock;
+    /// let mut clock = GameClock::default();
+    /// clock.set_time_scale(0.25);
+    /// assert_eq!(clock.time_scale(), 0.25);
+    /// ```
+    pub fn set_time_scale(&mut self, scale: f32) {
+        self.time_scale = scale.max(0.0);
+    }
+
+    /// Returns the speed of game time.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::game_time::GameClock;
+    /// assert_eq!(GameClock::default().time_scale(), 1.0);
+    /// ```
+    pub fn time_scale(&self) -> f32 {
+        self.time_scale
+    }
+
+    /// Stops game time; frames advance nothing until [`GameClock::resume`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::game_time::GameClock;
+    /// let mut clock = GameClock::default();
+    /// clock.pause();
+    /// assert!(clock.is_paused());
+    /// ```
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Restarts game time after [`GameClock::pause`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::game_time::GameClock;
+    /// let mut clock = GameClock::default();
+    /// clock.pause();
+    /// clock.resume();
+    /// assert!(!clock.is_paused());
+    /// ```
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    /// Pauses a running clock or resumes a paused one.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::game_time::GameClock;
+    /// let mut clock = GameClock::default();
+    /// clock.toggle_pause();
+    /// assert!(clock.is_paused());
+    /// ```
+    pub fn toggle_pause(&mut self) {
+        self.paused = !self.paused;
+    }
+
+    /// Returns true if game time is stopped.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::game_time::GameClock;
+    /// assert!(!GameClock::default().is_paused());
+    /// ```
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Returns a snapshot of the clock after the last frame.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::game_time::GameClock;
+    /// let mut clock = GameClock::new(0.1);
+    /// clock.advance(0.1);
+    /// assert_eq!(clock.snapshot().ticks, 1);
+    /// ```
+    pub fn snapshot(&self) -> GameTime {
+        GameTime {
+            dt: self.frame_dt,
+            steps: self.steps,
+            alpha: self.alpha(),
+            time: self.time,
+            ticks: self.ticks,
+            time_scale: self.time_scale,
+            paused: self.paused,
+        }
+    }
+
+    /// Makes the state of the clock after the last frame available to the UI of `ctx`. Call it
+    /// every frame after [`GameClock::advance`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::game_time::{game_time, GameClock};
+    /// let ctx = egui::Context::default();
+    /// let mut clock = GameClock::default();
+    /// clock.advance(0.02);
+    /// clock.publish(&ctx);
+    /// assert_eq!(game_time(&ctx).unwrap().ticks, 1);
+    /// ```
+    #[cfg(feature = "egui")]
+    pub fn publish(&self, ctx: &Context) {
+        ctx.data_mut(|d| d.insert_temp(game_time_id(), self.snapshot()));
+    }
+}
+
+/// State of a [`GameClock`] after a frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GameTime {
+    /// Scaled frame time in seconds; 0 while paused.
+    pub dt: f32,
+    /// Ticks due this frame.
+    pub steps: u32,
+    /// Fraction of the next tick already elapsed.
+    pub alpha: f32,
+    /// Game time in seconds.
+    pub time: f64,
+    /// Ticks so far.
+    pub ticks: u64,
+    pub time_scale: f32,
+    pub paused: bool,
+}
+
+#[cfg(feature = "egui")]
+fn game_time_id() -> Id {
+    Id::new("deckbuilder_game_time")
+}
+
+/// Returns the clock state published with [`GameClock::publish`].
+///
+/// # Example
+/// ```rust
+/// # use deckbuilder_eng::game_time::game_time;
+/// let ctx = egui::Context::default();
+/// assert!(game_time(&ctx).is_none());
+/// ```
+#[cfg(feature = "egui")]
+pub fn game_time(ctx: &Context) -> Option<GameTime> {
+    ctx.data(|d| d.get_temp::<GameTime>(game_time_id()))
+}
+
+/// Returns the game time of this frame for frame-based animations: the scaled frame time of the
+/// published clock, or the egui frame time if none is published.
+///
+/// # Example
+/// ```rust
+/// # use deckbuilder_eng::game_time::{game_dt, GameClock};
+/// let ctx = egui::Context::default();
+/// let mut clock = GameClock::default();
+/// clock.pause();
+/// clock.advance(0.016);
+/// clock.publish(&ctx);
+/// assert_eq!(game_dt(&ctx), 0.0);
+/// ```
+#[cfg(feature = "egui")]
+pub fn game_dt(ctx: &Context) -> f32 {
+    game_time(ctx).map_or_else(|| ctx.input(|i| i.stable_dt), |time| time.dt)
+}
@@ -0,0 +1,833 @@
+//! Localization for deckbuilder games.
+//!
+//! Provides [`I18n`], a set of per-language string tables loaded from Fluent-style (`.ftl`)
+//! or JSON files, with language switching, `{ $name }` argument interpolation, pluralization
+//! and a fallback chain, translations of card and keyword texts (including translated
+//! `CardDatabase` overlays), plus helpers that make it available to the UI through the egui
+//! context.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::i18n::*;
+//!
+//! let mut i18n = I18n::new("tr").with_fallback("en");
+//! i18n.load_ftl("en", "menu-start = Start\ncard-strike = Deal { $damage } damage.").unwrap();
+//! i18n.load_json("tr", r#"{ "menu-start": "Başla" }"#).unwrap();
+//!
+//! assert_eq!(i18n.tr("menu-start"), "Başla");
+//! // Missing in Turkish, so the English fallback is used
+//! assert_eq!(i18n.tr_args("card-strike", &[("damage", &6)]), "Deal 6 damage.");
+//!
+//! // Make it available to `ui_label_tr`, `ui_card` and friends
+//! let ctx = egui::Context::default();
+//! install_i18n(&ctx, i18n);
+//! assert_eq!(localize(&ctx, "menu-start"), "Başla");
+//! ```
+//!
+//! # Details
+//!
+//! - FTL support covers `key = value` messages, `#` comments, indented continuation lines and
+//!   `.attribute = value` lines;
+//!   JSON files are objects of strings, with nested objects flattened to `parent.child` keys.
+//! - Lookups try the current language, its base language (`tr` for `tr-TR`), then the
+//!   fallback languages in order. Missing keys are returned unchanged.
+//! - Plural messages are variants named after CLDR categories: `key.one`, `key.few`,
+//!   `key.other`, ... (FTL attributes or nested JSON objects), picked by [`I18n::tr_plural`].
+//! - Card texts are `card.<id>.name` and `card.<id>.description` messages, keyword texts
+//!   `keyword.<name>.name` and `keyword.<name>.description`. A translated card database file
+//!   adds them for a language with [`I18n::add_card_overlay`].
+//! - Installing or updating the tables of an egui context changes its `i18n_revision`, so
+//!   baked card textures are rendered again in the new language.
+//! - Once installed, `ui_label_tr` and `ui_label_args` look up message keys, and cards drawn
+//!   by `ui_card` and `CardRenderer` and keyword tooltips are translated; `ui_label` shows its
+//!   text literally. A key without a translation is shown as is.
+//! - The egui context helpers (`install_i18n`, `localize`, ...) need the `egui` feature.
+//! - See each struct and function's documentation for more.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+#[cfg(feature = "egui")]
+use std::sync::Arc;
+
+#[cfg(feature = "egui")]
+use egui::{Context, Id};
+
+use crate::card::{Card, CardId, Keyword};
+use crate::card_db::CardDatabase;
+use crate::json::{self, Value};
+
+/// Error returned when a string table can't be parsed.
+#[derive(Debug, thiserror::Error)]
+pub enum I18nError {
+    /// Malformed FTL line.
+    #[error("ftl line {line}: {message}")]
+    Ftl { line: usize, message: String },
+    /// Malformed JSON.
+    #[error("json: {0}")]
+    Json(String),
+}
+
+/// Per-language string tables with a current language and a fallback chain.
+#[derive(Debug, Clone, Default)]
+pub struct I18n {
+    tables: HashMap<String, HashMap<String, String>>,
+    language: String,
+    fallback: Vec<String>,
+}
+
+impl I18n {
+    /// Creates an empty set of tables with `language` as the current language.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::i18n::I18n;
+    /// let i18n = I18n::new("en");
+    /// assert_eq!(i18n.language(), "en");
+    /// ```
+    pub fn new(language: impl Into<String>) -> Self {
+        Self {
+            language: language.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Appends `language` to the fallback chain.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::i18n::I18n;
+    /// let i18n = I18n::new("tr").with_fallback("en");
+    /// ```
+    pub fn with_fallback(mut self, language: impl Into<String>) -> Self {
+        self.fallback.push(language.into());
+        self
+    }
+
+    /// Replaces the fallback chain.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::i18n::I18n;
+    /// let mut i18n = I18n::new("de");
+    /// i18n.set_fallback(vec!["en".to_owned()]);
+    /// ```
+    pub fn set_fallback(&mut self, languages: Vec<String>) {
+        self.fallback = languages;
+    }
+
+    /// Returns the current language.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::i18n::I18n;
+    /// assert_eq!(I18n::new("tr").language(), "tr");
+    /// ```
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    /// Switches the current language.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::i18n::I18n;
+    /// let mut i18n = I18n::new("en");
+    /// i18n.set_language("tr");
+    /// assert_eq!(i18n.language(), "tr");
+    /// ```
+    pub fn set_language(&mut self, language: impl Into<String>) {
+        self.language = language.into();
+    }
+
+    /// Returns the languages that have a string table, sorted.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::i18n::I18n;
+    /// let mut i18n = I18n::new("en");
+    /// i18n.add_message("tr", "yes", "Evet");
+    /// i18n.add_message("en", "yes", "Yes");
+    /// assert_eq!(i18n.languages(), vec!["en", "tr"]);
+    /// ```
+    pub fn languages(&self) -> Vec<&str> {
+        let mut languages: Vec<&str> = self.tables.keys().map(String::as_str).collect();
+        languages.sort_unstable();
+        languages
+    }
+
+    /// Adds or replaces one message of `language`.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::i18n::I18n;
+    /// let mut i18n = I18n::new("en");
+    /// i18n.add_message("en", "end-turn", "End Turn");
+    /// assert_eq!(i18n.tr("end-turn"), "End Turn");
+    /// ```
+    pub fn add_message(&mut self, language: &str, key: impl Into<String>, value: impl Into<String>) {
+        self.tables
+            .entry(language.to_owned())
+            .or_default()
+            .insert(key.into(), value.into());
+    }
+
+    /// Loads the messages of a Fluent-style (`.ftl`) file into `language`. Indented
+    /// `.name = value` attribute lines become `key.name` messages. Returns the number of
+    /// messages read.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::i18n::I18n;
+    /// let mut i18n = I18n::new("en");
+    /// let source = "# Main menu\nmenu-start = Start\nmenu-quit = Quit\n";
+    /// assert_eq!(i18n.load_ftl("en", source).unwrap(), 2);
+    ///
+    /// let source = "cards-left =\n    .one = { $count } card left\n    .other = { $count } cards left\n";
+    /// assert_eq!(i18n.load_ftl("en", source).unwrap(), 2);
+    /// assert_eq!(i18n.get("cards-left.one"), Some("{ $count } card left"));
+    /// ```
+    pub fn load_ftl(&mut self, language: &str, source: &str) -> Result<usize, I18nError> {
+        let mut messages: Vec<(String, String)> = Vec::new();
+        let mut parent: Option<String> = None;
+        let mut with_attributes = HashSet::new();
+        for (index, line) in source.lines().enumerate() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            if line.starts_with(char::is_whitespace) {
+                // `.one = ...` attributes become `parent.one` messages, e.g. plural variants
+                if let (Some(attribute), Some(parent)) = (trimmed.strip_prefix('.'), &parent)
+                    && let Some((name, value)) = attribute.split_once('=')
+                {
+                    with_attributes.insert(parent.clone());
+                    messages.push((format!("{}.{}", parent, name.trim()), value.trim().to_owned()));
+                    continue;
+                }
+                let Some((_, value)) = messages.last_mut() else {
+                    return Err(I18nError::Ftl { line: index + 1, message: "continuation without a message".to_owned() });
+                };
+                if !value.is_empty() {
+                    value.push('\n');
+                }
+                value.push_str(trimmed);
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(I18nError::Ftl { line: index + 1, message: "expected `key = value`".to_owned() });
+            };
+            let key = key.trim();
+            if key.is_empty() {
+                return Err(I18nError::Ftl { line: index + 1, message: "empty key".to_owned() });
+            }
+            parent = Some(key.to_owned());
+            messages.push((key.to_owned(), value.trim().to_owned()));
+        }
+        // Messages made only of attributes have no value of their own
+        messages.retain(|(key, value)| !value.is_empty() || !with_attributes.contains(key));
+        let count = messages.len();
+        let table = self.tables.entry(language.to_owned()).or_default();
+        table.extend(messages);
+        Ok(count)
+    }
+
+    /// Loads the messages of a JSON object of strings into `language`. Nested objects are
+    /// flattened to `parent.child` keys. Returns the number of messages read.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::i18n::I18n;
+    /// let mut i18n = I18n::new("tr");
+    /// i18n.load_json("tr", r#"{ "menu": { "start": "Başla", "quit": "Çık" } }"#).unwrap();
+    /// assert_eq!(i18n.tr("menu.quit"), "Çık");
+    /// ```
+    pub fn load_json(&mut self, language: &str, source: &str) -> Result<usize, I18nError> {
+        let Value::Map(fields) = json::parse(source).map_err(|err| I18nError::Json(err.to_string()))? else {
+            return Err(I18nError::Json("expected an object".to_owned()));
+        };
+        let mut messages = Vec::new();
+        flatten_json(&fields, "", &mut messages)?;
+        let count = messages.len();
+        let table = self.tables.entry(language.to_owned()).or_default();
+        table.extend(messages);
+        Ok(count)
+    }
+
+    /// Returns the message for `key`, following the fallback chain, or `None` if no language
+    /// has it.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::i18n::I18n;
+    /// let mut i18n = I18n::new("tr-TR").with_fallback("en");
+    /// i18n.add_message("tr", "yes", "Evet");
+    /// i18n.add_message("en", "no", "No");
+    /// assert_eq!(i18n.get("yes"), Some("Evet"));
+    /// assert_eq!(i18n.get("no"), Some("No"));
+    /// assert_eq!(i18n.get("maybe"), None);
+    /// ```
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.chain()
+            .find_map(|language| self.tables.get(language)?.get(key))
+            .map(String::as_str)
+    }
+
+    /// The languages lookups try, in order.
+    fn chain(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.language.as_str())
+            .chain(std::iter::once(base_language(&self.language)))
+            .chain(self.fallback.iter().map(String::as_str))
+    }
+
+    /// Returns `true` if some language in the chain has `key`.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::i18n::I18n;
+    /// assert!(!I18n::new("en").has("menu-start"));
+    /// ```
+    pub fn has(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Returns the message for `key`, or `key` itself if it is missing.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::i18n::I18n;
+    /// let i18n = I18n::new("en");
+    /// assert_eq!(i18n.tr("menu-start"), "menu-start");
+    /// ```
+    pub fn tr(&self, key: &str) -> String {
+        self.get(key).unwrap_or(key).to_owned()
+    }
+
+    /// Returns the message for `key` with its placeholders replaced by `args`, see
+    /// [`interpolate`].
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::i18n::I18n;
+    /// let mut i18n = I18n::new("en");
+    /// i18n.add_message("en", "gold", "You have { $amount } gold");
+    /// assert_eq!(i18n.tr_args("gold", &[("amount", &99)]), "You have 99 gold");
+    /// ```
+    pub fn tr_args(&self, key: &str, args: &[(&str, &dyn Display)]) -> String {
+        interpolate(self.get(key).unwrap_or(key), args)
+    }
+
+    /// Returns the variant of `key` for `count`: the `key.<category>` message for the
+    /// [`plural_category`] of `count` in the language that has it, then `key.other`, then
+    /// `key`. `{ $count }` and `args` are interpolated.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::i18n::I18n;
+    /// let mut i18n = I18n::new("ru").with_fallback("en");
+    /// i18n.load_json("en", r#"{ "cards-left": { "one": "{ $count } card left", "other": "{ $count } cards left" } }"#).unwrap();
+    /// i18n.load_json("ru", r#"{ "cards-left": { "one": "Осталась { $count } карта", "few": "Осталось { $count } карты", "many": "Осталось { $count } карт" } }"#).unwrap();
+    /// assert_eq!(i18n.tr_plural("cards-left", 3, &[]), "Осталось 3 карты");
+    /// assert_eq!(i18n.tr_plural("cards-left", 21, &[]), "Осталась 21 карта");
+    ///
+    /// i18n.set_language("en");
+    /// assert_eq!(i18n.tr_plural("cards-left", 1, &[]), "1 card left");
+    /// ```
+    pub fn tr_plural(&self, key: &str, count: i64, args: &[(&str, &dyn Display)]) -> String {
+        let template = self
+            .chain()
+            .find_map(|language| {
+                let table = self.tables.get(language)?;
+                let category = plural_category(language, count);
+                table
+                    .get(&format!("{}.{}", key, category.as_str()))
+                    .or_else(|| table.get(&format!("{}.other", key)))
+                    .or_else(|| table.get(key))
+            })
+            .map(String::as_str)
+            .unwrap_or(key);
+        let mut all: Vec<(&str, &dyn Display)> = vec![("count", &count)];
+        all.extend_from_slice(args);
+        interpolate(template, &all)
+    }
+
+    /// Returns the name of `card`: its `card.<id>.name` message, or its name translated as a
+    /// message key (see [`card_key`]).
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card::{Card, CardType};
+    /// # use deckbuilder_eng::i18n::I18n;
+    /// let mut i18n = I18n::new("tr");
+    /// i18n.load_json("tr", r#"{ "card": { "1": { "name": "Vuruş" } } }"#).unwrap();
+    /// let card = Card::new(1, "Strike", "Deal 6 damage.", 1, CardType::Attack);
+    /// assert_eq!(i18n.card_name(&card), "Vuruş");
+    /// ```
+    pub fn card_name(&self, card: &Card) -> String {
+        self.get(&card_key(card.id, "name")).map_or_else(|| self.tr(&card.name), str::to_owned)
+    }
+
+    /// Returns the description of `card`: its `card.<id>.description` message, or its
+    /// description translated as a message key.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card::{Card, CardType};
+    /// # use deckbuilder_eng::i18n::I18n;
+    /// let mut i18n = I18n::new("tr");
+    /// i18n.add_message("tr", "card.1.description", "6 hasar ver.");
+    /// let card = Card::new(1, "Strike", "Deal 6 damage.", 1, CardType::Attack);
+    /// assert_eq!(i18n.card_description(&card), "6 hasar ver.");
+    /// ```
+    pub fn card_description(&self, card: &Card) -> String {
+        self.get(&card_key(card.id, "description"))
+            .map_or_else(|| self.tr(&card.description), str::to_owned)
+    }
+
+    /// Returns a copy of `card` with its name and description in the current language.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card::{Card, CardType};
+    /// # use deckbuilder_eng::i18n::I18n;
+    /// let i18n = I18n::new("en");
+    /// let card = Card::new(1, "Strike", "Deal 6 damage.", 1, CardType::Attack);
+    /// assert_eq!(i18n.localize_card(&card).name, "Strike");
+    /// ```
+    pub fn localize_card(&self, card: &Card) -> Card {
+        Card {
+            name: self.card_name(card),
+            description: self.card_description(card),
+            ..card.clone()
+        }
+    }
+
+    /// Returns a copy of `keyword` with the `keyword.<name>.name` and
+    /// `keyword.<name>.description` messages of the current language, if any.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card::Keyword;
+    /// # use deckbuilder_eng::i18n::I18n;
+    /// let mut i18n = I18n::new("tr");
+    /// i18n.load_ftl("tr", "keyword.Exhaust.name = Tüket").unwrap();
+    /// let keyword = i18n.localize_keyword(&Keyword::new("Exhaust", "Removed until end of combat."));
+    /// assert_eq!(keyword.name, "Tüket");
+    /// assert_eq!(keyword.description, "Removed until end of combat.");
+    /// ```
+    pub fn localize_keyword(&self, keyword: &Keyword) -> Keyword {
+        let message = |field, default: &str| {
+            self.get(&keyword_key(&keyword.name, field)).unwrap_or(default).to_owned()
+        };
+        Keyword::new(message("name", &keyword.name), message("description", &keyword.description))
+    }
+
+    /// Returns a copy of `database` with card names and descriptions, keywords and the
+    /// keywords of cards in the current language, e.g. to show a translated card library.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card::{Card, CardType};
+    /// # use deckbuilder_eng::card_db::{CardDatabase, CardDef};
+    /// # use deckbuilder_eng::i18n::I18n;
+    /// let db = CardDatabase::new().with_card(CardDef::new(Card::new(1, "Strike", "Deal 6 damage.", 1, CardType::Attack)));
+    /// let mut i18n = I18n::new("tr");
+    /// i18n.add_message("tr", "card.1.name", "Vuruş");
+    /// assert_eq!(i18n.localize_database(&db).cards[0].card.name, "Vuruş");
+    /// ```
+    pub fn localize_database(&self, database: &CardDatabase) -> CardDatabase {
+        let mut localized = database.clone();
+        for def in &mut localized.cards {
+            def.card = self.localize_card(&def.card);
+            for keyword in &mut def.keywords {
+                if let Some(name) = self.get(&keyword_key(keyword, "name")) {
+                    *keyword = name.to_owned();
+                }
+            }
+        }
+        for keyword in &mut localized.keywords {
+            *keyword = self.localize_keyword(keyword);
+        }
+        localized
+    }
+
+    /// Adds the card names and descriptions of `overlay`, a translated copy of the game's
+    /// card database (e.g. `cards.tr.ron`), as `card.<id>.*` messages of `language`. Cards
+    /// are matched by id; empty texts are skipped. Returns the number of messages added.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card::{Card, CardType};
+    /// # use deckbuilder_eng::card_db::CardDatabase;
+    /// # use deckbuilder_eng::i18n::I18n;
+    /// let overlay = CardDatabase::parse(r#"(cards: [(id: 1, name: "Vuruş", description: "6 hasar ver.", card_type: Attack)])"#).unwrap();
+    /// let mut i18n = I18n::new("tr").with_fallback("en");
+    /// assert_eq!(i18n.add_card_overlay("tr", &overlay), 2);
+    ///
+    /// let card = Card::new(1, "Strike", "Deal 6 damage.", 1, CardType::Attack);
+    /// assert_eq!(i18n.card_name(&card), "Vuruş");
+    /// i18n.set_language("en");
+    /// assert_eq!(i18n.card_name(&card), "Strike");
+    /// ```
+    pub fn add_card_overlay(&mut self, language: &str, overlay: &CardDatabase) -> usize {
+        let mut count = 0;
+        for def in &overlay.cards {
+            for (field, text) in [("name", &def.card.name), ("description", &def.card.description)] {
+                if !text.is_empty() {
+                    self.add_message(language, card_key(def.card.id, field), text.as_str());
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+}
+
+/// Returns the message key of a card's text: `card.<id>.<field>`, where `field` is `name`
+/// or `description`. In JSON string tables these are nested objects,
+/// `{ "card": { "1": { "name": "..." } } }`.
+///
+/// # Example
+/// ```
+/// # use deckbuilder_eng::i18n::card_key;
+/// assert_eq!(card_key(12, "name"), "card.12.name");
+/// ```
+pub fn card_key(id: CardId, field: &str) -> String {
+    format!("card.{}.{}", id, field)
+}
+
+/// Returns the message key of a keyword's text: `keyword.<name>.<field>`, where `field` is
+/// `name` or `description`.
+///
+/// # Example
+/// ```
+/// # use deckbuilder_eng::i18n::keyword_key;
+/// assert_eq!(keyword_key("Exhaust", "description"), "keyword.Exhaust.description");
+/// ```
+pub fn keyword_key(name: &str, field: &str) -> String {
+    format!("keyword.{}.{}", name, field)
+}
+
+/// CLDR plural category of a count, selecting the variant of a plural message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralCategory {
+    /// Returns the category's name as used in message keys (`one`, `few`, ...).
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::i18n::PluralCategory;
+    /// assert_eq!(PluralCategory::Few.as_str(), "few");
+    /// ```
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PluralCategory::Zero => "zero",
+            PluralCategory::One => "one",
+            PluralCategory::Two => "two",
+            PluralCategory::Few => "few",
+            PluralCategory::Many => "many",
+            PluralCategory::Other => "other",
+        }
+    }
+}
+
+/// Returns the plural category of `count` in `language`. Covers the rules of English-like
+/// languages (one, other), French, East and Southeast Asian languages (other only), Russian,
+/// Ukrainian, Belarusian, Polish, Czech, Slovak and Arabic; other languages use the English
+/// rule.
+///
+/// # Example
+/// ```
+/// # use deckbuilder_eng::i18n::{plural_category, PluralCategory};
+/// assert_eq!(plural_category("en", 1), PluralCategory::One);
+/// assert_eq!(plural_category("fr", 0), PluralCategory::One);
+/// assert_eq!(plural_category("pl-PL", 5), PluralCategory::Many);
+/// assert_eq!(plural_category("ja", 1), PluralCategory::Other);
+/// ```
+pub fn plural_category(language: &str, count: i64) -> PluralCategory {
+    let n = count.unsigned_abs();
+    let (n10, n100) = (n % 10, n % 100);
+    let slavic = |one| {
+        if one {
+            PluralCategory::One
+        } else if (2..=4).contains(&n10) && !(12..=14).contains(&n100) {
+            PluralCategory::Few
+        } else {
+            PluralCategory::Many
+        }
+    };
+    match base_language(language).to_ascii_lowercase().as_str() {
+        "ja" | "zh" | "ko" | "th" | "vi" | "id" | "ms" => PluralCategory::Other,
+        "fr" if n <= 1 => PluralCategory::One,
+        "fr" => PluralCategory::Other,
+        "ru" | "uk" | "be" => slavic(n10 == 1 && n100 != 11),
+        "pl" => slavic(n == 1),
+        "cs" | "sk" => match n {
+            1 => PluralCategory::One,
+            2..=4 => PluralCategory::Few,
+            _ => PluralCategory::Other,
+        },
+        "ar" => match (n, n100) {
+            (0, _) => PluralCategory::Zero,
+            (1, _) => PluralCategory::One,
+            (2, _) => PluralCategory::Two,
+            (_, 3..=10) => PluralCategory::Few,
+            (_, 11..=99) => PluralCategory::Many,
+            _ => PluralCategory::Other,
+        },
+        _ if n == 1 => PluralCategory::One,
+        _ => PluralCategory::Other,
+    }
+}
+
+/// Returns the language without its region, `tr` for `tr-TR`.
+fn base_language(language: &str) -> &str {
+    language.split(['-', '_']).next().unwrap_or_default()
+}
+
+/// Replaces `{ $name }` (Fluent) and `{name}` placeholders in `template` with the matching
+/// argument. Placeholders without an argument are kept, so `ui_text` icon tokens like
+/// `{energy}` survive.
+///
+/// # Example
+/// ```
+/// # use deckbuilder_eng::i18n::interpolate;
+/// let text = interpolate("Deal { $damage } damage. Gain {energy}.", &[("damage", &8)]);
+/// assert_eq!(text, "Deal 8 damage. Gain {energy}.");
+/// ```
+pub fn interpolate(template: &str, args: &[(&str, &dyn Display)]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let Some(len) = rest[start..].find('}') else {
+            rest = &rest[start..];
+            break;
+        };
+        let placeholder = &rest[start..start + len + 1];
+        let name = placeholder[1..len].trim().trim_start_matches('$');
+        match args.iter().find(|(arg, _)| *arg == name) {
+            Some((_, value)) => out.push_str(&value.to_string()),
+            None => out.push_str(placeholder),
+        }
+        rest = &rest[start + len + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Flattens a JSON object of strings and nested objects into `parent.child` messages.
+fn flatten_json(fields: &[(String, Value)], prefix: &str, messages: &mut Vec<(String, String)>) -> Result<(), I18nError> {
+    for (key, value) in fields {
+        let key = if prefix.is_empty() { key.clone() } else { format!("{}.{}", prefix, key) };
+        match value {
+            Value::Map(fields) => flatten_json(fields, &key, messages)?,
+            Value::Str(text) => messages.push((key, text.clone())),
+            _ => return Err(I18nError::Json(format!("value of `{}` must be a string or an object", key))),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(feature = "egui")]
+fn i18n_id() -> Id {
+    Id::new("deckbuilder_i18n")
+}
+
+/// Makes `i18n` the string tables used by the UI of `ctx`.
+///
+/// # Example
+/// ```
+/// # use deckbuilder_eng::i18n::{install_i18n, I18n};
+/// let ctx = egui::Context::default();
+/// install_i18n(&ctx, I18n::new("en"));
+/// ```
+#[cfg(feature = "egui")]
+pub fn install_i18n(ctx: &Context, i18n: I18n) {
+    install_arc(ctx, Arc::new(i18n));
+}
+
+#[cfg(feature = "egui")]
+fn install_arc(ctx: &Context, i18n: Arc<I18n>) {
+    ctx.data_mut(|d| {
+        d.insert_temp(i18n_id(), i18n);
+        *d.get_temp_mut_or_default::<u64>(i18n_id().with("revision")) += 1;
+    });
+}
+
+/// Returns a number that changes whenever string tables are installed or updated, e.g. on a
+/// language switch. Caches of rendered text compare it to know when to render again, as
+/// [`CardRenderer::bake_cached`](crate::ui_card_render::CardRenderer::bake_cached) does.
+///
+/// # Example
+/// ```
+/// # use deckbuilder_eng::i18n::{i18n_revision, install_i18n, update_i18n, I18n};
+/// let ctx = egui::Context::default();
+/// install_i18n(&ctx, I18n::new("en"));
+/// let before = i18n_revision(&ctx);
+/// update_i18n(&ctx, |i18n| i18n.set_language("tr"));
+/// assert_ne!(i18n_revision(&ctx), before);
+/// ```
+#[cfg(feature = "egui")]
+pub fn i18n_revision(ctx: &Context) -> u64 {
+    ctx.data(|d| d.get_temp::<u64>(i18n_id().with("revision")).unwrap_or_default())
+}
+
+/// Returns the string tables installed with [`install_i18n`].
+///
+/// # Example
+/// ```
+/// # use deckbuilder_eng::i18n::i18n;
+/// let ctx = egui::Context::default();
+/// assert!(i18n(&ctx).is_none());
+/// ```
+#[cfg(feature = "egui")]
+pub fn i18n(ctx: &Context) -> Option<Arc<I18n>> {
+    ctx.data(|d| d.get_temp::<Arc<I18n>>(i18n_id()))
+}
+
+/// Modifies the installed string tables, e.g. to switch the language at runtime. Does nothing
+/// if none are installed.
+///
+/// # Example
+/// ```
+/// # use deckbuilder_eng::i18n::{i18n, install_i18n, update_i18n, I18n};
+/// let ctx = egui::Context::default();
+/// install_i18n(&ctx, I18n::new("en"));
+/// update_i18n(&ctx, |i18n| i18n.set_language("tr"));
+/// assert_eq!(i18n(&ctx).unwrap().language(), "tr");
+/// ```
+#[cfg(feature = "egui")]
+pub fn update_i18n(ctx: &Context, f: impl FnOnce(&mut I18n)) {
+    if let Some(mut i18n) = i18n(ctx) {
+        f(Arc::make_mut(&mut i18n));
+        install_arc(ctx, i18n);
+    }
+}
+
+/// Returns the translation of `text` if it is a message key of the installed string tables,
+/// or `text` unchanged otherwise.
+///
+/// # Example
+/// ```
+/// # use deckbuilder_eng::i18n::{install_i18n, localize, I18n};
+/// let ctx = egui::Context::default();
+/// let mut tables = I18n::new("tr");
+/// tables.add_message("tr", "end-turn", "Turu Bitir");
+/// install_i18n(&ctx, tables);
+/// assert_eq!(localize(&ctx, "end-turn"), "Turu Bitir");
+/// assert_eq!(localize(&ctx, "Plain text"), "Plain text");
+/// ```
+#[cfg(feature = "egui")]
+pub fn localize(ctx: &Context, text: &str) -> String {
+    match i18n(ctx) {
+        Some(i18n) => i18n.tr(text),
+        None => text.to_owned(),
+    }
+}
+
+/// Like [`localize`], with the placeholders of the message replaced by `args`.
+///
+/// # Example
+/// ```
+/// # use deckbuilder_eng::i18n::localize_args;
+/// let ctx = egui::Context::default();
+/// assert_eq!(localize_args(&ctx, "Draw { $n } cards", &[("n", &2)]), "Draw 2 cards");
+/// ```
+#[cfg(feature = "egui")]
+pub fn localize_args(ctx: &Context, text: &str, args: &[(&str, &dyn Display)]) -> String {
+    match i18n(ctx) {
+        Some(i18n) => i18n.tr_args(text, args),
+        None => interpolate(text, args),
+    }
+}
+
+/// Like [`I18n::tr_plural`], with the installed string tables. Without tables, `key` is
+/// interpolated as is.
+///
+/// # Example
+/// ```
+/// # use deckbuilder_eng::i18n::{install_i18n, localize_plural, I18n};
+/// let ctx = egui::Context::default();
+/// let mut tables = I18n::new("en");
+/// tables.load_ftl("en", "draw =\n    .one = Draw a card\n    .other = Draw { $count } cards").unwrap();
+/// install_i18n(&ctx, tables);
+/// assert_eq!(localize_plural(&ctx, "draw", 1, &[]), "Draw a card");
+/// assert_eq!(localize_plural(&ctx, "draw", 2, &[]), "Draw 2 cards");
+/// ```
+#[cfg(feature = "egui")]
+pub fn localize_plural(ctx: &Context, key: &str, count: i64, args: &[(&str, &dyn Display)]) -> String {
+    match i18n(ctx) {
+        Some(i18n) => i18n.tr_plural(key, count, args),
+        None => {
+            let mut all: Vec<(&str, &dyn Display)> = vec![("count", &count)];
+            all.extend_from_slice(args);
+            interpolate(key, &all)
+        }
+    }
+}
+
+/// Returns the name of `card` in the installed string tables' language, see
+/// [`I18n::card_name`].
+///
+/// # Example
+/// ```
+/// # use deckbuilder_eng::card::{Card, CardType};
+/// # use deckbuilder_eng::i18n::localize_card_name;
+/// let ctx = egui::Context::default();
+/// let card = Card::new(1, "Strike", "Deal 6 damage.", 1, CardType::Attack);
+/// assert_eq!(localize_card_name(&ctx, &card), "Strike");
+/// ```
+#[cfg(feature = "egui")]
+pub fn localize_card_name(ctx: &Context, card: &Card) -> String {
+    match i18n(ctx) {
+        Some(i18n) => i18n.card_name(card),
+        None => card.name.clone(),
+    }
+}
+
+/// Returns the description of `card` in the installed string tables' language, see
+/// [`I18n::card_description`].
+///
+/// # Example
+/// ```
+/// # use deckbuilder_eng::card::{Card, CardType};
+/// # use deckbuilder_eng::i18n::localize_card_description;
+/// let ctx = egui::Context::default();
+/// let card = Card::new(1, "Strike", "Deal 6 damage.", 1, CardType::Attack);
+/// assert_eq!(localize_card_description(&ctx, &card), "Deal 6 damage.");
+/// ```
+#[cfg(feature = "egui")]
+pub fn localize_card_description(ctx: &Context, card: &Card) -> String {
+    match i18n(ctx) {
+        Some(i18n) => i18n.card_description(card),
+        None => card.description.clone(),
+    }
+}
+
+/// Returns `keyword` in the installed string tables' language, see [`I18n::localize_keyword`].
+///
+/// # Example
+/// ```
+/// # use deckbuilder_eng::card::Keyword;
+/// # use deckbuilder_eng::i18n::localize_keyword;
+/// let ctx = egui::Context::default();
+/// let keyword = localize_keyword(&ctx, &Keyword::new("Exhaust", "Removed until end of combat."));
+/// assert_eq!(keyword.name, "Exhaust");
+/// ```
+#[cfg(feature = "egui")]
+pub fn localize_keyword(ctx: &Context, keyword: &Keyword) -> Keyword {
+    match i18n(ctx) {
+        Some(i18n) => i18n.localize_keyword(keyword),
+        None => keyword.clone(),
+    }
+}
+
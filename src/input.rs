@@ -0,0 +1,447 @@
+//! Input mapping for deckbuilder games.
+//!
+//! Provides an [`InputMap`] binding logical [`Action`]s (end turn, confirm target, open deck,
+//! play card 1–10, ...) to keys and gamepad buttons, and polling APIs that turn egui input into
+//! actions.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::input::{Action, Binding, InputMap};
+//! use egui::{Context, Key};
+//!
+//! let mut input = InputMap::default();
+//! input.bind(Action::EndTurn, Binding::Key(Key::Space));
+//!
+//! fn frame(ctx: &Context, input: &InputMap) {
+//!     for action in input.poll_actions(ctx) {
+//!         match action {
+//!             Action::EndTurn => println!("End turn"),
+//!             other => {
+//!                 if let Some(i) = other.card_index() {
+//!                     println!("Play card {}", i + 1);
+//!                 }
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! # Details
+//!
+//! - `poll_actions` consumes the bound key presses, so widgets don't react to them too.
+//! - egui does not read gamepads; feed button presses from a gamepad library into
+//!   `InputMap::poll_gamepad`.
+//! - See each struct and function's documentation for more.
+
+use egui::{Context, Key, Modifiers};
+
+/// Logical game actions that can be bound to inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    EndTurn,
+    ConfirmTarget,
+    CancelTarget,
+    OpenDeck,
+    OpenDiscard,
+    OpenMap,
+    Card1,
+    Card2,
+    Card3,
+    Card4,
+    Card5,
+    Card6,
+    Card7,
+    Card8,
+    Card9,
+    Card0,
+}
+
+impl Action {
+    /// All actions, in display order.
+    pub const ALL: [Action; 16] = [
+        Action::EndTurn,
+        Action::ConfirmTarget,
+        Action::CancelTarget,
+        Action::OpenDeck,
+        Action::OpenDiscard,
+        Action::OpenMap,
+        Action::Card1,
+        Action::Card2,
+        Action::Card3,
+        Action::Card4,
+        Action::Card5,
+        Action::Card6,
+        Action::Card7,
+        Action::Card8,
+        Action::Card9,
+        Action::Card0,
+    ];
+
+    /// Returns the play-card action for hand slot `index` (0 = `Card1`, 9 = `Card0`).
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::input::Action;
+    /// assert_eq!(Action::card(0), Some(Action::Card1));
+    /// assert_eq!(Action::card(9), Some(Action::Card0));
+    /// ```
+    pub fn card(index: usize) -> Option<Action> {
+        Self::ALL.get(6 + index).copied()
+    }
+
+    /// Returns the hand slot of a play-card action.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::input::Action;
+    /// assert_eq!(Action::Card3.card_index(), Some(2));
+    /// assert_eq!(Action::EndTurn.card_index(), None);
+    /// ```
+    pub fn card_index(self) -> Option<usize> {
+        Self::ALL.iter().position(|a| *a == self).and_then(|i| i.checked_sub(6))
+    }
+
+    /// Human-readable name of the action.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::input::Action;
+    /// assert_eq!(Action::OpenDeck.label(), "Open deck");
+    /// ```
+    pub fn label(self) -> &'static str {
+        match self {
+            Action::EndTurn => "End turn",
+            Action::ConfirmTarget => "Confirm target",
+            Action::CancelTarget => "Cancel target",
+            Action::OpenDeck => "Open deck",
+            Action::OpenDiscard => "Open discard pile",
+            Action::OpenMap => "Open map",
+            Action::Card1 => "Play card 1",
+            Action::Card2 => "Play card 2",
+            Action::Card3 => "Play card 3",
+            Action::Card4 => "Play card 4",
+            Action::Card5 => "Play card 5",
+            Action::Card6 => "Play card 6",
+            Action::Card7 => "Play card 7",
+            Action::Card8 => "Play card 8",
+            Action::Card9 => "Play card 9",
+            Action::Card0 => "Play card 10",
+        }
+    }
+
+    /// Stable identifier of the action, used in settings files.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::input::Action;
+    /// assert_eq!(Action::EndTurn.name(), "end_turn");
+    /// assert_eq!(Action::Card0.name(), "card_10");
+    /// ```
+    pub fn name(self) -> &'static str {
+        match self {
+            Action::EndTurn => "end_turn",
+            Action::ConfirmTarget => "confirm_target",
+            Action::CancelTarget => "cancel_target",
+            Action::OpenDeck => "open_deck",
+            Action::OpenDiscard => "open_discard",
+            Action::OpenMap => "open_map",
+            Action::Card1 => "card_1",
+            Action::Card2 => "card_2",
+            Action::Card3 => "card_3",
+            Action::Card4 => "card_4",
+            Action::Card5 => "card_5",
+            Action::Card6 => "card_6",
+            Action::Card7 => "card_7",
+            Action::Card8 => "card_8",
+            Action::Card9 => "card_9",
+            Action::Card0 => "card_10",
+        }
+    }
+
+    /// Returns the action with the given [`Action::name`].
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::input::Action;
+    /// assert_eq!(Action::from_name("open_map"), Some(Action::OpenMap));
+    /// assert_eq!(Action::from_name("fly"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<Action> {
+        Self::ALL.into_iter().find(|action| action.name() == name)
+    }
+}
+
+/// Gamepad buttons, named by position (South = A on Xbox, Cross on PlayStation).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GamepadButton {
+    South,
+    East,
+    West,
+    North,
+    LeftBumper,
+    RightBumper,
+    Select,
+    Start,
+    DPadUp,
+    DPadDown,
+    DPadLeft,
+    DPadRight,
+}
+
+impl GamepadButton {
+    /// All buttons.
+    pub const ALL: [GamepadButton; 12] = [
+        GamepadButton::South,
+        GamepadButton::East,
+        GamepadButton::West,
+        GamepadButton::North,
+        GamepadButton::LeftBumper,
+        GamepadButton::RightBumper,
+        GamepadButton::Select,
+        GamepadButton::Start,
+        GamepadButton::DPadUp,
+        GamepadButton::DPadDown,
+        GamepadButton::DPadLeft,
+        GamepadButton::DPadRight,
+    ];
+
+    /// Stable identifier of the button, used in settings files.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::input::GamepadButton;
+    /// assert_eq!(GamepadButton::LeftBumper.name(), "left_bumper");
+    /// ```
+    pub fn name(self) -> &'static str {
+        match self {
+            GamepadButton::South => "south",
+            GamepadButton::East => "east",
+            GamepadButton::West => "west",
+            GamepadButton::North => "north",
+            GamepadButton::LeftBumper => "left_bumper",
+            GamepadButton::RightBumper => "right_bumper",
+            GamepadButton::Select => "select",
+            GamepadButton::Start => "start",
+            GamepadButton::DPadUp => "dpad_up",
+            GamepadButton::DPadDown => "dpad_down",
+            GamepadButton::DPadLeft => "dpad_left",
+            GamepadButton::DPadRight => "dpad_right",
+        }
+    }
+
+    /// Returns the button with the given [`GamepadButton::name`].
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::input::GamepadButton;
+    /// assert_eq!(GamepadButton::from_name("start"), Some(GamepadButton::Start));
+    /// ```
+    pub fn from_name(name: &str) -> Option<GamepadButton> {
+        Self::ALL.into_iter().find(|button| button.name() == name)
+    }
+}
+
+/// A physical input bound to an action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Binding {
+    Key(Key),
+    Gamepad(GamepadButton),
+}
+
+/// Bindings from inputs to logical actions. An action may have several bindings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputMap {
+    bindings: Vec<(Action, Binding)>,
+}
+
+impl Default for InputMap {
+    fn default() -> Self {
+        let mut map = Self::new();
+        map.bind(Action::EndTurn, Binding::Key(Key::E));
+        map.bind(Action::ConfirmTarget, Binding::Key(Key::Enter));
+        map.bind(Action::CancelTarget, Binding::Key(Key::Escape));
+        map.bind(Action::OpenDeck, Binding::Key(Key::D));
+        map.bind(Action::OpenDiscard, Binding::Key(Key::S));
+        map.bind(Action::OpenMap, Binding::Key(Key::M));
+        let number_keys = [
+            Key::Num1,
+            Key::Num2,
+            Key::Num3,
+            Key::Num4,
+            Key::Num5,
+            Key::Num6,
+            Key::Num7,
+            Key::Num8,
+            Key::Num9,
+            Key::Num0,
+        ];
+        for (i, key) in number_keys.into_iter().enumerate() {
+            if let Some(action) = Action::card(i) {
+                map.bind(action, Binding::Key(key));
+            }
+        }
+        map.bind(Action::EndTurn, Binding::Gamepad(GamepadButton::Start));
+        map.bind(Action::ConfirmTarget, Binding::Gamepad(GamepadButton::South));
+        map.bind(Action::CancelTarget, Binding::Gamepad(GamepadButton::East));
+        map.bind(Action::OpenDeck, Binding::Gamepad(GamepadButton::North));
+        map.bind(Action::OpenMap, Binding::Gamepad(GamepadButton::Select));
+        map
+    }
+}
+
+impl InputMap {
+    /// Creates a map without any bindings. Use `InputMap::default()` for the default layout.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::input::InputMap;
+    /// let map = InputMap::new();
+    /// ```
+    pub fn new() -> Self {
+        Self { bindings: Vec::new() }
+    }
+
+    /// Adds `binding` to `action`. A binding used by another action is moved to this one.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::input::{Action, Binding, InputMap};
+    /// # use egui::Key;
+    /// let mut map = InputMap::new();
+    /// map.bind(Action::EndTurn, Binding::Key(Key::Space));
+    /// assert_eq!(map.action_for(Binding::Key(Key::Space)), Some(Action::EndTurn));
+    /// ```
+    pub fn bind(&mut self, action: Action, binding: Binding) {
+        self.bindings.retain(|(_, b)| *b != binding);
+        self.bindings.push((action, binding));
+    }
+
+    /// Replaces all keyboard bindings of `action` with `key`, keeping gamepad bindings.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::input::{Action, InputMap};
+    /// # use egui::Key;
+    /// let mut map = InputMap::default();
+    /// map.rebind_key(Action::EndTurn, Key::Space);
+    /// assert_eq!(map.key_for(Action::EndTurn), Some(Key::Space));
+    /// ```
+    pub fn rebind_key(&mut self, action: Action, key: Key) {
+        self.bindings.retain(|(a, b)| !(*a == action && matches!(b, Binding::Key(_))));
+        self.bind(action, Binding::Key(key));
+    }
+
+    /// Removes all bindings of `action`.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::input::{Action, InputMap};
+    /// let mut map = InputMap::default();
+    /// map.unbind(Action::OpenMap);
+    /// assert!(map.bindings_for(Action::OpenMap).is_empty());
+    /// ```
+    pub fn unbind(&mut self, action: Action) {
+        self.bindings.retain(|(a, _)| *a != action);
+    }
+
+    /// Returns all bindings of `action`.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::input::{Action, InputMap};
+    /// let bindings = InputMap::default().bindings_for(Action::ConfirmTarget);
+    /// assert_eq!(bindings.len(), 2);
+    /// ```
+    pub fn bindings_for(&self, action: Action) -> Vec<Binding> {
+        self.bindings.iter().filter(|(a, _)| *a == action).map(|(_, b)| *b).collect()
+    }
+
+    /// Returns all `(action, binding)` pairs, in the order they were bound.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::input::{Action, Binding, InputMap};
+    /// # use egui::Key;
+    /// let mut map = InputMap::new();
+    /// map.bind(Action::OpenMap, Binding::Key(Key::Tab));
+    /// assert_eq!(map.bindings(), [(Action::OpenMap, Binding::Key(Key::Tab))]);
+    /// ```
+    pub fn bindings(&self) -> &[(Action, Binding)] {
+        &self.bindings
+    }
+
+    /// Returns the first keyboard key bound to `action`.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::input::{Action, InputMap};
+    /// # use egui::Key;
+    /// assert_eq!(InputMap::default().key_for(Action::EndTurn), Some(Key::E));
+    /// ```
+    pub fn key_for(&self, action: Action) -> Option<Key> {
+        self.bindings.iter().find_map(|(a, b)| match b {
+            Binding::Key(key) if *a == action => Some(*key),
+            _ => None,
+        })
+    }
+
+    /// Returns the action bound to `binding`.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::input::{Action, Binding, InputMap};
+    /// # use egui::Key;
+    /// assert_eq!(InputMap::default().action_for(Binding::Key(Key::Num1)), Some(Action::Card1));
+    /// ```
+    pub fn action_for(&self, binding: Binding) -> Option<Action> {
+        self.bindings.iter().find(|(_, b)| *b == binding).map(|(a, _)| *a)
+    }
+
+    /// Returns the actions whose keys were pressed this frame (without modifiers), consuming
+    /// those key presses from egui's input.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::input::{Action, InputMap};
+    /// # fn demo(ctx: &egui::Context, input: &InputMap) {
+    /// if input.poll_actions(ctx).contains(&Action::OpenDeck) {
+    ///     println!("Show deck");
+    /// }
+    /// # }
+    /// ```
+    pub fn poll_actions(&self, ctx: &Context) -> Vec<Action> {
+        let mut actions = Vec::new();
+        ctx.input_mut(|input| {
+            for (action, binding) in &self.bindings {
+                if let Binding::Key(key) = binding
+                    && input.consume_key(Modifiers::NONE, *key)
+                    && !actions.contains(action)
+                {
+                    actions.push(*action);
+                }
+            }
+        });
+        actions
+    }
+
+    /// Returns the actions bound to the given pressed gamepad buttons.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::input::{Action, GamepadButton, InputMap};
+    /// let actions = InputMap::default().poll_gamepad(&[GamepadButton::South]);
+    /// assert_eq!(actions, vec![Action::ConfirmTarget]);
+    /// ```
+    pub fn poll_gamepad(&self, pressed: &[GamepadButton]) -> Vec<Action> {
+        let mut actions = Vec::new();
+        for button in pressed {
+            if let Some(action) = self.action_for(Binding::Gamepad(*button))
+                && !actions.contains(&action)
+            {
+                actions.push(action);
+            }
+        }
+        actions
+    }
+}
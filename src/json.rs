@@ -0,0 +1,294 @@
+//! JSON reading and writing shared by the crate's file formats.
+//!
+//! Provides [`quote`] and [`quote_ron`] for writing strings and [`parse`], a small parser for
+//! JSON values that also reads the RON subset card databases use: `//` and `/* */` comments,
+//! bare identifiers, `( ... )` records, optionally named (`Card(id: 1)`), unquoted field names
+//! and `\u{...}` escapes. Card databases, string tables and metrics files all go through it,
+//! so nesting is limited to [`MAX_DEPTH`] levels.
+
+use std::fmt;
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// Deepest nesting of lists and maps accepted, so untrusted input can't overflow the stack.
+pub(crate) const MAX_DEPTH: usize = 128;
+
+/// A parsed value. JSON objects and RON records are both maps, in source order.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Value {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Str(String),
+    /// A bare RON identifier, e.g. an enum variant.
+    Ident(String),
+    List(Vec<Value>),
+    Map(Vec<(String, Value)>),
+}
+
+/// Error returned for malformed input, with the line it was found on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ParseError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+/// Returns `text` as a JSON string literal.
+pub(crate) fn quote(text: &str) -> String {
+    escape(text, |c| format!("\\u{:04x}", c))
+}
+
+/// Returns `text` as a RON string literal.
+pub(crate) fn quote_ron(text: &str) -> String {
+    escape(text, |c| format!("\\u{{{:x}}}", c))
+}
+
+fn escape(text: &str, unicode: fn(u32) -> String) -> String {
+    let mut out = String::with_capacity(text.len() + 2);
+    out.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            '\r' => out.push_str("\\r"),
+            c if c.is_control() => out.push_str(&unicode(c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Parses one value that makes up all of `source`.
+pub(crate) fn parse(source: &str) -> Result<Value, ParseError> {
+    let mut parser = Parser {
+        chars: source.chars().peekable(),
+        line: 1,
+        depth: 0,
+    };
+    let value = parser.value()?;
+    parser.skip_whitespace()?;
+    match parser.chars.peek().copied() {
+        Some(c) => Err(parser.error(format!("unexpected `{}` after the value", c))),
+        None => Ok(value),
+    }
+}
+
+struct Parser<'a> {
+    chars: Peekable<Chars<'a>>,
+    line: usize,
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, message: String) -> ParseError {
+        ParseError { line: self.line, message }
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.chars.next();
+        if c == Some('\n') {
+            self.line += 1;
+        }
+        c
+    }
+
+    fn skip_whitespace(&mut self) -> Result<(), ParseError> {
+        loop {
+            match self.chars.peek() {
+                Some(c) if c.is_whitespace() => {
+                    self.bump();
+                }
+                Some('/') => {
+                    self.bump();
+                    match self.bump() {
+                        Some('/') => while self.chars.peek().is_some_and(|&c| c != '\n') {
+                            self.bump();
+                        },
+                        Some('*') => loop {
+                            match self.bump() {
+                                Some('*') if self.chars.peek() == Some(&'/') => {
+                                    self.bump();
+                                    break;
+                                }
+                                Some(_) => {}
+                                None => return Err(self.error("unterminated comment".to_owned())),
+                            }
+                        },
+                        _ => return Err(self.error("expected a comment after `/`".to_owned())),
+                    }
+                }
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+        self.skip_whitespace()?;
+        match self.bump() {
+            Some(c) if c == expected => Ok(()),
+            Some(c) => Err(self.error(format!("expected `{}`, found `{}`", expected, c))),
+            None => Err(self.error(format!("expected `{}`, found end of input", expected))),
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, ParseError> {
+        self.skip_whitespace()?;
+        match self.chars.peek().copied() {
+            Some('"') => self.string().map(Value::Str),
+            Some('{') => self.nested(|parser| parser.map('{', '}')),
+            Some('(') => self.nested(|parser| parser.map('(', ')')),
+            Some('[') => self.nested(Self::list),
+            Some(c) if c == '-' || c.is_ascii_digit() => self.int(),
+            Some(c) if c.is_alphabetic() || c == '_' => {
+                let ident = self.ident();
+                self.skip_whitespace()?;
+                // A named RON struct, `Card(id: 1, ...)`
+                if self.chars.peek() == Some(&'(') {
+                    return self.nested(|parser| parser.map('(', ')'));
+                }
+                Ok(match ident.as_str() {
+                    "null" => Value::Null,
+                    "true" => Value::Bool(true),
+                    "false" => Value::Bool(false),
+                    _ => Value::Ident(ident),
+                })
+            }
+            Some(c) => Err(self.error(format!("unexpected `{}`", c))),
+            None => Err(self.error("unexpected end of input".to_owned())),
+        }
+    }
+
+    /// Parses a list or map one level deeper, refusing to go past [`MAX_DEPTH`].
+    fn nested(&mut self, parse: impl FnOnce(&mut Self) -> Result<Value, ParseError>) -> Result<Value, ParseError> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error(format!("nested deeper than {} levels", MAX_DEPTH)));
+        }
+        self.depth += 1;
+        let value = parse(self);
+        self.depth -= 1;
+        value
+    }
+
+    fn ident(&mut self) -> String {
+        let mut ident = String::new();
+        while let Some(c) = self.chars.next_if(|c| c.is_alphanumeric() || *c == '_') {
+            ident.push(c);
+        }
+        ident
+    }
+
+    fn int(&mut self) -> Result<Value, ParseError> {
+        let mut text = String::new();
+        while let Some(c) = self.chars.next_if(|c| c.is_ascii_digit() || *c == '-') {
+            text.push(c);
+        }
+        text.parse()
+            .map(Value::Int)
+            .map_err(|_| self.error(format!("invalid integer `{}`", text)))
+    }
+
+    /// Reads the code point of a `\u` escape: JSON `\u00e9`, or RON `\u{e9}`.
+    fn unicode_escape(&mut self) -> Result<u32, ParseError> {
+        let hex: String = if self.chars.next_if_eq(&'{').is_some() {
+            let hex = std::iter::from_fn(|| self.chars.next_if(|&c| c != '}')).collect();
+            self.chars.next();
+            hex
+        } else {
+            self.chars.by_ref().take(4).collect()
+        };
+        u32::from_str_radix(&hex, 16).map_err(|_| self.error(format!("invalid escape `\\u{}`", hex)))
+    }
+
+    fn string(&mut self) -> Result<String, ParseError> {
+        self.expect('"')?;
+        let mut out = String::new();
+        loop {
+            match self.bump() {
+                Some('"') => return Ok(out),
+                Some('\\') => match self.bump() {
+                    Some('n') => out.push('\n'),
+                    Some('t') => out.push('\t'),
+                    Some('r') => out.push('\r'),
+                    Some('b') => out.push('\u{8}'),
+                    Some('f') => out.push('\u{c}'),
+                    Some('u') => {
+                        let mut code = self.unicode_escape()?;
+                        // JSON writes characters outside the BMP as a surrogate pair, `\ud83d\ude00`
+                        if (0xd800..0xdc00).contains(&code) {
+                            let low = match (self.bump(), self.bump()) {
+                                (Some('\\'), Some('u')) => self.unicode_escape()?,
+                                _ => return Err(self.error(format!("unpaired surrogate `\\u{:x}`", code))),
+                            };
+                            if !(0xdc00..0xe000).contains(&low) {
+                                return Err(self.error(format!("invalid surrogate pair `\\u{:x}\\u{:x}`", code, low)));
+                            }
+                            code = 0x10000 + ((code - 0xd800) << 10) + (low - 0xdc00);
+                        }
+                        let c = char::from_u32(code)
+                            .ok_or_else(|| self.error(format!("invalid character `\\u{:x}`", code)))?;
+                        out.push(c);
+                    }
+                    Some(c) => out.push(c),
+                    None => break,
+                },
+                Some(c) => out.push(c),
+                None => break,
+            }
+        }
+        Err(self.error("unterminated string".to_owned()))
+    }
+
+    fn list(&mut self) -> Result<Value, ParseError> {
+        self.expect('[')?;
+        let mut items = Vec::new();
+        loop {
+            self.skip_whitespace()?;
+            if self.chars.next_if_eq(&']').is_some() {
+                return Ok(Value::List(items));
+            }
+            items.push(self.value()?);
+            self.skip_whitespace()?;
+            match self.bump() {
+                Some(',') => {}
+                Some(']') => return Ok(Value::List(items)),
+                Some(c) => return Err(self.error(format!("expected `,` or `]`, found `{}`", c))),
+                None => return Err(self.error("unterminated list".to_owned())),
+            }
+        }
+    }
+
+    fn map(&mut self, open: char, close: char) -> Result<Value, ParseError> {
+        self.expect(open)?;
+        let mut fields = Vec::new();
+        loop {
+            self.skip_whitespace()?;
+            if self.chars.next_if_eq(&close).is_some() {
+                return Ok(Value::Map(fields));
+            }
+            let key = match self.chars.peek().copied() {
+                Some('"') => self.string()?,
+                Some(c) if c.is_alphabetic() || c == '_' => self.ident(),
+                Some(c) => return Err(self.error(format!("expected a field name, found `{}`", c))),
+                None => return Err(self.error("unterminated record".to_owned())),
+            };
+            self.expect(':')?;
+            fields.push((key, self.value()?));
+            self.skip_whitespace()?;
+            match self.bump() {
+                Some(',') => {}
+                Some(c) if c == close => return Ok(Value::Map(fields)),
+                Some(c) => return Err(self.error(format!("expected `,` or `{}`, found `{}`", close, c))),
+                None => return Err(self.error("unterminated record".to_owned())),
+            }
+        }
+    }
+}
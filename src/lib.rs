@@ -2,10 +2,179 @@
 //!
 //! A library crate for building deck‐builder games.
 //!
-//! Provides modules for audio, cards, UI, and UI image handling.
+//! Provides game logic modules that build without any UI, and egui widgets and screens on
+//! top of them (`egui` feature).
+//!
+//! # Modules
+//!
+//! - `ai`: computer opponents.
+//! - `assets`: asset bundles.
+//! - `audio`: music and sound effects (`kira` feature).
+//! - `card`: cards, relics, decks and the game context.
+//! - `card_db`: card databases in JSON/RON files.
+//! - `combat_log`: combat log entries grouped by turn.
+//! - `editor`: card editor screen (`editor` feature).
+//! - `engine`: game runner.
+//! - `error`: crate-wide error type.
+//! - `events`: event bus.
+//! - `game_time`: fixed-timestep game time.
+//! - `i18n`: localization.
+//! - `input`: input mapping.
+//! - `logging`: log buffer and log files (`logging` feature).
+//! - `map`: run maps.
+//! - `metrics`: playtest metrics (`metrics` feature).
+//! - `net`: networked play.
+//! - `plugin`: engine plugins.
+//! - `savegame`: save games with migrations.
+//! - `settings`: game settings.
+//! - `shop`: shops.
+//! - `stats`: runtime performance counters.
+//! - `status`: status effects.
+//! - `texture`: texture cache.
+//! - `ui`: egui widget helpers.
+//! - `ui_anim`: animation.
+//! - `ui_builder`: builder-style widgets.
+//! - `ui_capture`: screenshots.
+//! - `ui_card`: card widgets.
+//! - `ui_card_render`: layered card rendering.
+//! - `ui_chart`: charts.
+//! - `ui_combat_log`: combat log view.
+//! - `ui_debug`: developer tools.
+//! - `ui_drag`: drag-and-drop.
+//! - `ui_focus`: focus navigation.
+//! - `ui_font`: fonts.
+//! - `ui_fx`: visual effects.
+//! - `ui_hud`: combat HUD widgets.
+//! - `ui_image`: images and GIFs.
+//! - `ui_loading`: loading screen.
+//! - `ui_map`: run map widget.
+//! - `ui_menu`: context menus.
+//! - `ui_overlay`: overlays.
+//! - `ui_particles`: particle effects.
+//! - `ui_rest`: rest site screen.
+//! - `ui_screen`: screen management.
+//! - `ui_settings`: settings menu.
+//! - `ui_shop`: shop screen.
+//! - `ui_sprite`: sprite sheet animation.
+//! - `ui_status`: status effect icons.
+//! - `ui_table`: sortable tables.
+//! - `ui_tabs`: tabs.
+//! - `ui_text`: rich text.
+//! - `ui_theme`: theming.
+//! - `ui_tooltip`: tooltips.
+//! - `ui_tree`: collapsible sections and tree views.
+//! - `ui_tutorial`: tutorial highlights.
+//! - `ui_window`: window manager.
+//! - `vfs`: virtual filesystem with asset packs.
+//! - `web`: browser asset fetching and WebSocket sessions (`wasm32` builds).
 
 
+pub mod ai;
+#[cfg(feature = "egui")]
+pub mod assets;
+#[cfg(feature = "kira")]
 pub mod audio;
 pub mod card;
+pub mod card_db;
+pub mod combat_log;
+#[cfg(feature = "editor")]
+pub mod editor;
+#[cfg(feature = "egui")]
+pub mod engine;
+pub mod error;
+pub mod events;
+pub mod game_time;
+pub mod i18n;
+mod json;
+#[cfg(feature = "egui")]
+pub mod input;
+#[cfg(feature = "logging")]
+pub mod logging;
+pub mod map;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+pub mod net;
+#[cfg(feature = "egui")]
+pub mod plugin;
+pub mod savegame;
+#[cfg(feature = "egui")]
+pub mod settings;
+pub mod shop;
+pub mod stats;
+pub mod status;
+#[cfg(feature = "egui")]
+pub mod texture;
+#[cfg(feature = "egui")]
 pub mod ui;
+#[cfg(feature = "egui")]
+pub mod ui_anim;
+#[cfg(feature = "egui")]
+pub mod ui_builder;
+#[cfg(feature = "egui")]
+pub mod ui_capture;
+#[cfg(feature = "egui")]
+pub mod ui_card;
+#[cfg(feature = "egui")]
+pub mod ui_card_render;
+#[cfg(feature = "egui")]
+pub mod ui_chart;
+#[cfg(feature = "egui")]
+pub mod ui_combat_log;
+#[cfg(feature = "egui")]
+pub mod ui_debug;
+#[cfg(feature = "egui")]
+pub mod ui_drag;
+#[cfg(feature = "egui")]
+pub mod ui_focus;
+#[cfg(feature = "egui")]
+pub mod ui_font;
+#[cfg(feature = "egui")]
+pub mod ui_fx;
+#[cfg(feature = "egui")]
+pub mod ui_hud;
+#[cfg(feature = "egui")]
 pub mod ui_image;
+#[cfg(feature = "egui")]
+pub mod ui_loading;
+#[cfg(feature = "egui")]
+pub mod ui_map;
+#[cfg(feature = "egui")]
+pub mod ui_menu;
+#[cfg(feature = "egui")]
+pub mod ui_overlay;
+#[cfg(feature = "egui")]
+pub mod ui_particles;
+#[cfg(feature = "egui")]
+pub mod ui_rest;
+#[cfg(feature = "egui")]
+pub mod ui_screen;
+#[cfg(feature = "egui")]
+pub mod ui_settings;
+#[cfg(feature = "egui")]
+pub mod ui_shop;
+#[cfg(feature = "egui")]
+pub mod ui_sprite;
+#[cfg(feature = "egui")]
+pub mod ui_status;
+#[cfg(feature = "egui")]
+pub mod ui_table;
+#[cfg(feature = "egui")]
+pub mod ui_tabs;
+#[cfg(feature = "egui")]
+pub mod ui_text;
+#[cfg(feature = "egui")]
+pub mod ui_theme;
+#[cfg(feature = "egui")]
+pub mod ui_tooltip;
+#[cfg(feature = "egui")]
+pub mod ui_tree;
+#[cfg(feature = "egui")]
+pub mod ui_tutorial;
+#[cfg(feature = "egui")]
+pub mod ui_window;
+mod util;
+pub mod vfs;
+#[cfg(target_arch = "wasm32")]
+pub mod web;
+
+pub use error::{Error, Result};
@@ -0,0 +1,560 @@
+//! Logging for deckbuilder games.
+//!
+//! Provides [`LoggingConfig`] to collect the `tracing` output of the crate and the game into a
+//! [`LogBuffer`] shown by the debug overlay, to stderr and to a [`RotatingFile`], and the
+//! [`LogLayer`] that does it, for use with a custom `tracing_subscriber` stack.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::logging::LoggingConfig;
+//! use tracing::Level;
+//!
+//! let (subscriber, logs) = LoggingConfig::new().level(Level::DEBUG).stderr(false).subscriber().unwrap();
+//! tracing::subscriber::with_default(subscriber, || {
+//!     let _turn = tracing::info_span!("combat_turn", turn = 3).entered();
+//!     tracing::warn!(card = "Strike", "card has no target");
+//! });
+//! let record = &logs.records()[0];
+//! assert_eq!(record.level, Level::WARN);
+//! assert_eq!(record.spans, ["combat_turn{turn=3}"]);
+//! assert_eq!(record.message, "card has no target card=Strike");
+//!
+//! // In a game, install it once at startup instead:
+//! // let logs = LoggingConfig::new().file("logs/game.log").init().unwrap();
+//! ```
+//!
+//! # Details
+//!
+//! - The crate logs asset bundle and file loads (`load_bundle`, `load_asset` spans), audio
+//!   loads and playback (`load_sound` spans), combat turns (a `combat_turn` span around each
+//!   engine frame of a battle) and failures it recovers from, such as assets that fail to load.
+//! - The buffer keeps the newest records up to its capacity; pass it to
+//!   `DebugStats::logs` or `ui_log_view` in [`crate::ui_debug`] to see it in game.
+//! - Log files rotate when they would grow past the size limit: `game.log` becomes
+//!   `game.log.1`, `game.log.1` becomes `game.log.2`, and the oldest is deleted.
+//! - This module needs the `logging` feature. Without it, the crate's `tracing` calls cost
+//!   next to nothing and go wherever the game's own subscriber sends them.
+//! - See each struct and function's documentation for more.
+
+use std::collections::VecDeque;
+use std::fmt::{self, Write as _};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError};
+
+use thiserror::Error;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::{Event, Level, Metadata, Subscriber};
+use tracing_subscriber::layer::{Context, Layer, Layered, SubscriberExt};
+use tracing_subscriber::registry::{LookupSpan, Registry};
+
+use crate::util::unix_now;
+
+/// Error returned when logging can't be set up.
+#[derive(Debug, Error)]
+pub enum LoggingError {
+    /// The log file couldn't be opened.
+    #[error("log file: {0}")]
+    Io(#[from] io::Error),
+    /// Another global `tracing` subscriber is already installed.
+    #[error("a global tracing subscriber is already installed")]
+    AlreadyInstalled,
+}
+
+/// One logged event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogRecord {
+    pub level: Level,
+    /// Module the event was logged from.
+    pub target: String,
+    /// Enclosing spans from the outermost, as `name{field=value}`.
+    pub spans: Vec<String>,
+    /// The message followed by the other fields as `key=value`.
+    pub message: String,
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+}
+
+impl fmt::Display for LogRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {:>5} {}", self.timestamp, self.level, self.target)?;
+        for span in &self.spans {
+            write!(f, " {}", span)?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+/// Shared ring buffer of the newest log records. Clones share the same records.
+#[derive(Debug, Clone)]
+pub struct LogBuffer {
+    records: Arc<Mutex<VecDeque<LogRecord>>>,
+    capacity: usize,
+}
+
+impl Default for LogBuffer {
+    fn default() -> Self {
+        Self::new(500)
+    }
+}
+
+impl LogBuffer {
+    /// Creates an empty buffer keeping up to `capacity` records (at least 1).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::logging::LogBuffer;
+    /// let logs = LogBuffer::new(100);
+    /// assert_eq!(logs.capacity(), 100);
+    /// ```
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            records: Arc::new(Mutex::new(VecDeque::new())),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Returns the most records kept.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::logging::LogBuffer;
+    /// assert_eq!(LogBuffer::default().capacity(), 500);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Adds a record, dropping the oldest one if the buffer is full.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::logging::{LogBuffer, LogRecord};
+    /// # use tracing::Level;
+    /// let logs = LogBuffer::new(1);
+    /// for message in ["first", "second"] {
+    ///     logs.push(LogRecord {
+    ///         level: Level::INFO,
+    ///         target: "game".to_owned(),
+    ///         spans: Vec::new(),
+    ///         message: message.to_owned(),
+    ///         timestamp: 0,
+    ///     });
+    /// }
+    /// assert_eq!(logs.records()[0].message, "second");
+    /// ```
+    pub fn push(&self, record: LogRecord) {
+        let mut records = self.records.lock().unwrap_or_else(PoisonError::into_inner);
+        if records.len() >= self.capacity {
+            records.pop_front();
+        }
+        records.push_back(record);
+    }
+
+    /// Returns a copy of the records, oldest first.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::logging::LogBuffer;
+    /// assert!(LogBuffer::default().records().is_empty());
+    /// ```
+    pub fn records(&self) -> Vec<LogRecord> {
+        self.records.lock().unwrap_or_else(PoisonError::into_inner).iter().cloned().collect()
+    }
+
+    /// Returns a copy of the newest `count` records, oldest first.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::logging::LogBuffer;
+    /// assert!(LogBuffer::default().recent(8).is_empty());
+    /// ```
+    pub fn recent(&self, count: usize) -> Vec<LogRecord> {
+        let records = self.records.lock().unwrap_or_else(PoisonError::into_inner);
+        records.iter().skip(records.len().saturating_sub(count)).cloned().collect()
+    }
+
+    /// Returns the number of records.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::logging::LogBuffer;
+    /// assert_eq!(LogBuffer::default().len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.records.lock().unwrap_or_else(PoisonError::into_inner).len()
+    }
+
+    /// Returns true if there are no records.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::logging::LogBuffer;
+    /// assert!(LogBuffer::default().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all records.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::logging::LogBuffer;
+    /// let logs = LogBuffer::default();
+    /// logs.clear();
+    /// ```
+    pub fn clear(&self) {
+        self.records.lock().unwrap_or_else(PoisonError::into_inner).clear();
+    }
+}
+
+/// Log file that is rotated when it would grow past a size limit.
+#[derive(Debug)]
+pub struct RotatingFile {
+    path: PathBuf,
+    max_bytes: u64,
+    max_files: usize,
+    file: File,
+    written: u64,
+}
+
+impl RotatingFile {
+    /// Opens `path` for appending, creating it and its directory if needed. Once it would grow
+    /// past `max_bytes`, it is rotated, keeping `max_files` old files.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::logging::RotatingFile;
+    /// let path = std::env::temp_dir().join("deckbuilder_doc_open.log");
+    /// let file = RotatingFile::open(&path, 1024 * 1024, 3).unwrap();
+    /// assert_eq!(file.path(), path);
+    /// ```
+    pub fn open(path: impl Into<PathBuf>, max_bytes: u64, max_files: usize) -> io::Result<Self> {
+        let path = path.into();
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)?;
+        }
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let written = file.metadata()?.len();
+        Ok(Self {
+            path,
+            max_bytes: max_bytes.max(1),
+            max_files,
+            file,
+            written,
+        })
+    }
+
+    /// Returns the path of the current log file.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::logging::RotatingFile;
+    /// # let path = std::env::temp_dir().join("deckbuilder_doc_path.log");
+    /// let file = RotatingFile::open(&path, 1024, 1).unwrap();
+    /// assert!(file.path().ends_with("deckbuilder_doc_path.log"));
+    /// ```
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Moves the current file to `<path>.1`, shifting older files up and deleting the oldest,
+    /// and starts an empty file.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::io::Write;
+    /// # use deckbuilder_eng::logging::RotatingFile;
+    /// let path = std::env::temp_dir().join("deckbuilder_doc_rotate.log");
+    /// let mut file = RotatingFile::open(&path, 1024, 2).unwrap();
+    /// writeln!(file, "first run").unwrap();
+    /// file.rotate().unwrap();
+    /// assert!(path.with_extension("log.1").exists());
+    /// assert_eq!(std::fs::metadata(&path).unwrap().len(), 0);
+    /// ```
+    pub fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+        if self.max_files == 0 {
+            self.file = File::create(&self.path)?;
+        } else {
+            let _ = fs::remove_file(rotated_path(&self.path, self.max_files));
+            for n in (1..self.max_files).rev() {
+                let from = rotated_path(&self.path, n);
+                if from.exists() {
+                    fs::rename(&from, rotated_path(&self.path, n + 1))?;
+                }
+            }
+            fs::rename(&self.path, rotated_path(&self.path, 1))?;
+            self.file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        }
+        self.written = 0;
+        Ok(())
+    }
+}
+
+impl Write for RotatingFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.written > 0 && self.written + buf.len() as u64 > self.max_bytes {
+            self.rotate()?;
+        }
+        let written = self.file.write(buf)?;
+        self.written += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+fn rotated_path(path: &Path, n: usize) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(".{}", n));
+    PathBuf::from(name)
+}
+
+/// `tracing_subscriber` layer writing events to a [`LogBuffer`], stderr and a log file.
+pub struct LogLayer {
+    level: Level,
+    buffer: LogBuffer,
+    stderr: bool,
+    file: Option<Mutex<RotatingFile>>,
+}
+
+/// Formatted fields of a span, kept in its extensions.
+struct SpanFields(String);
+
+/// Collects the message and the other fields (as `key=value`) of an event or span.
+#[derive(Default)]
+struct FieldVisitor {
+    message: String,
+    fields: Vec<String>,
+}
+
+impl Visit for FieldVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message.push_str(value);
+        } else {
+            self.fields.push(format!("{}={}", field.name(), value));
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            let _ = write!(self.message, "{:?}", value);
+        } else {
+            self.fields.push(format!("{}={:?}", field.name(), value));
+        }
+    }
+}
+
+impl<S> Layer<S> for LogLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn enabled(&self, metadata: &Metadata<'_>, _ctx: Context<'_, S>) -> bool {
+        *metadata.level() <= self.level
+    }
+
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let mut visitor = FieldVisitor::default();
+        attrs.record(&mut visitor);
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(SpanFields(visitor.fields.join(",")));
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+        let spans = ctx
+            .event_scope(event)
+            .map(|scope| {
+                scope
+                    .from_root()
+                    .map(|span| match span.extensions().get::<SpanFields>() {
+                        Some(SpanFields(fields)) if !fields.is_empty() => format!("{}{{{}}}", span.name(), fields),
+                        _ => span.name().to_owned(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        let record = LogRecord {
+            level: *event.metadata().level(),
+            target: event.metadata().target().to_owned(),
+            spans,
+            message: std::iter::once(visitor.message)
+                .chain(visitor.fields)
+                .filter(|part| !part.is_empty())
+                .collect::<Vec<_>>()
+                .join(" "),
+            timestamp: unix_now(),
+        };
+        if self.stderr {
+            eprintln!("{}", record);
+        }
+        if let Some(file) = &self.file {
+            let mut file = file.lock().unwrap_or_else(PoisonError::into_inner);
+            // One write per line, so rotation never splits a record.
+            let _ = file.write_all(format!("{}\n", record).as_bytes());
+        }
+        self.buffer.push(record);
+    }
+}
+
+/// Where log records go and how many are kept.
+#[derive(Debug, Clone)]
+pub struct LoggingConfig {
+    level: Level,
+    capacity: usize,
+    stderr: bool,
+    file: Option<PathBuf>,
+    max_file_bytes: u64,
+    max_files: usize,
+}
+
+impl Default for LoggingConfig {
+    fn default() -> Self {
+        Self {
+            level: Level::INFO,
+            capacity: 500,
+            stderr: true,
+            file: None,
+            max_file_bytes: 1024 * 1024,
+            max_files: 3,
+        }
+    }
+}
+
+impl LoggingConfig {
+    /// Logs `INFO` and above to a 500-record buffer and stderr, with no file.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::logging::LoggingConfig;
+    /// let config = LoggingConfig::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the most verbose level logged.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::logging::LoggingConfig;
+    /// let config = LoggingConfig::new().level(tracing::Level::DEBUG);
+    /// ```
+    pub fn level(mut self, level: Level) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Sets how many records the in-game buffer keeps.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::logging::LoggingConfig;
+    /// let config = LoggingConfig::new().buffer_capacity(2000);
+    /// ```
+    pub fn buffer_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    /// Enables or disables printing records to stderr (enabled by default).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::logging::LoggingConfig;
+    /// let config = LoggingConfig::new().stderr(false);
+    /// ```
+    pub fn stderr(mut self, enabled: bool) -> Self {
+        self.stderr = enabled;
+        self
+    }
+
+    /// Also writes records to the log file at `path`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::logging::LoggingConfig;
+    /// let config = LoggingConfig::new().file("logs/game.log");
+    /// ```
+    pub fn file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.file = Some(path.into());
+        self
+    }
+
+    /// Sets the size a log file may reach before it is rotated and the number of old files
+    /// kept (1 MiB and 3 by default).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::logging::LoggingConfig;
+    /// let config = LoggingConfig::new().file("logs/game.log").rotation(256 * 1024, 5);
+    /// ```
+    pub fn rotation(mut self, max_bytes: u64, max_files: usize) -> Self {
+        self.max_file_bytes = max_bytes;
+        self.max_files = max_files;
+        self
+    }
+
+    /// Builds the layer and the buffer it fills, to add to your own subscriber. Fails if the log
+    /// file can't be opened.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::logging::LoggingConfig;
+    /// use tracing_subscriber::layer::SubscriberExt;
+    /// let (layer, logs) = LoggingConfig::new().layer().unwrap();
+    /// let subscriber = tracing_subscriber::registry().with(layer);
+    /// ```
+    pub fn layer(self) -> Result<(LogLayer, LogBuffer), LoggingError> {
+        let file = match &self.file {
+            Some(path) => Some(Mutex::new(RotatingFile::open(path, self.max_file_bytes, self.max_files)?)),
+            None => None,
+        };
+        let buffer = LogBuffer::new(self.capacity);
+        let layer = LogLayer {
+            level: self.level,
+            buffer: buffer.clone(),
+            stderr: self.stderr,
+            file,
+        };
+        Ok((layer, buffer))
+    }
+
+    /// Builds a subscriber with only the log layer, e.g. for `tracing::subscriber::with_default`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::logging::LoggingConfig;
+    /// let (subscriber, logs) = LoggingConfig::new().stderr(false).subscriber().unwrap();
+    /// tracing::subscriber::with_default(subscriber, || tracing::info!("hello"));
+    /// assert_eq!(logs.len(), 1);
+    /// ```
+    pub fn subscriber(self) -> Result<(Layered<LogLayer, Registry>, LogBuffer), LoggingError> {
+        let (layer, buffer) = self.layer()?;
+        Ok((tracing_subscriber::registry().with(layer), buffer))
+    }
+
+    /// Installs the log layer as the global subscriber and returns its buffer. Call once at
+    /// startup.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use deckbuilder_eng::logging::LoggingConfig;
+    /// let logs = LoggingConfig::new().file("logs/game.log").init().unwrap();
+    /// ```
+    pub fn init(self) -> Result<LogBuffer, LoggingError> {
+        let (subscriber, buffer) = self.subscriber()?;
+        tracing::subscriber::set_global_default(subscriber).map_err(|_| LoggingError::AlreadyInstalled)?;
+        Ok(buffer)
+    }
+}
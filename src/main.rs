@@ -2,7 +2,7 @@ use deckbuilder_eng::ui::*;
 use deckbuilder_eng::ui_image::*;
 use deckbuilder_eng::audio::AudioManager;
 use egui::{Color32, TextStyle, Vec2};
-use std::time::{Instant, Duration};
+use std::time::Duration;
 
 pub struct DeckbuilderApp {
     checkbox_value: bool,
@@ -12,9 +12,7 @@ pub struct DeckbuilderApp {
     text_area: String,
     combo_selected: String,
     texture: Option<egui::TextureHandle>,
-    gif_frames: Option<(Vec<egui::TextureHandle>, Vec<std::time::Duration>)>,
-    gif_index: usize,
-    gif_last: Instant,
+    gif: Option<GifPlayer>,
     audio_manager: AudioManager,
     music_volume: f32,
     sfx_volume: f32,
@@ -32,9 +30,7 @@ impl Default for DeckbuilderApp {
             text_area: String::new(),
             combo_selected: "Birinci".to_string(),
             texture: None,
-            gif_frames: None,
-            gif_index: 0,
-            gif_last: Instant::now(),
+            gif: None,
             audio_manager: AudioManager::new().expect("Ses başlatılamadı"),
             music_volume: 0.5,
             sfx_volume: 0.5,
@@ -44,7 +40,16 @@ impl Default for DeckbuilderApp {
     }
 }
 
+/// Ses komutlarının hatalarını loglar.
+fn report(result: deckbuilder_eng::Result<()>) {
+    if let Err(err) = result {
+        tracing::warn!(%err, "Ses komutu başarısız");
+    }
+}
+
 fn main() {
+    #[cfg(feature = "logging")]
+    let _logs = deckbuilder_eng::logging::LoggingConfig::new().init();
     let options = eframe::NativeOptions::default();
     eframe::run_native(
         "Deckbuilder Engine - UI Demo",
@@ -101,11 +106,11 @@ impl eframe::App for DeckbuilderApp {
                 ui_separator(ui);
 
                 ui_button(ui, "Buton", || {
-                    println!("Butona tıklandı!");
+                    tracing::info!("Butona tıklandı!");
                 });
 
                 ui_sized_button(ui, "Büyük Buton", Vec2::new(200.0, 40.0), || {
-                    println!("Büyük butona tıklandı!");
+                    tracing::info!("Büyük butona tıklandı!");
                 });
 
                 ui_separator(ui);
@@ -117,6 +122,7 @@ impl eframe::App for DeckbuilderApp {
                 if clicked {
                     ui_label(ui, "Basit Checkbox tıklandı!");
                 }
+                self.checkbox_value = ui_toggle(ui, "Anahtar", self.checkbox_value);
 
                 ui_separator(ui);
 
@@ -169,7 +175,7 @@ impl eframe::App for DeckbuilderApp {
                 let image_path = "assets/example.gif";
 
                 if self.texture.is_none() {
-                    self.texture = load_texture_from_path(ctx, image_path);
+                    self.texture = load_texture_from_path(ctx, image_path).ok();
                 }
 
                 if let Some(texture) = &self.texture {
@@ -197,24 +203,13 @@ impl eframe::App for DeckbuilderApp {
                 ui_label(ui, "GIF animasyon örneği:");
                 ui_slider(ui, "GIF Hızı (düşük= yavaş, yüksek= hızlı)", &mut self.gif_speed, 0.1..=20.0);
 
-                if self.gif_frames.is_none() {
-                    self.gif_frames = load_gif_frames(ctx, image_path, Some(self.gif_speed));
-                    self.gif_index = 0;
-                    self.gif_last = Instant::now();
+                if self.gif.is_none() {
+                    self.gif = GifPlayer::load(ctx, image_path);
                 }
 
-                if let Some((frames, delays)) = &self.gif_frames {
-                    if !frames.is_empty() {
-                        let orig = delays[self.gif_index];
-                        let adj = Duration::from_secs_f32(orig.as_secs_f32() / self.gif_speed);
-                        if self.gif_last.elapsed() > adj {
-                            self.gif_index = (self.gif_index + 1) % frames.len();
-                            self.gif_last = Instant::now();
-                        }
-                        ui_image(ui, &frames[self.gif_index], None);
-                    } else {
-                        ui_label(ui, "GIF kareleri yüklenemedi.");
-                    }
+                if let Some(gif) = &mut self.gif {
+                    gif.set_speed(self.gif_speed);
+                    gif.ui(ui, None);
                 } else {
                     ui_label(ui, "GIF yüklenemedi.");
                 }
@@ -223,31 +218,31 @@ impl eframe::App for DeckbuilderApp {
                 ui_label(ui, "Ses Kontrolleri:");
 
                 ui_button(ui, "Müzik Çal", || {
-                    let _ = self.audio_manager.play_music("background", 1.0);
+                    report(self.audio_manager.play_music("background", 1.0));
                 });
                 ui_button(ui, "Müzik Durdur", || {
-                    let _ = self.audio_manager.stop_music();
+                    report(self.audio_manager.stop_music());
                 });
                 ui_button(ui, "Tıkla (click)", || {
-                    let _ = self.audio_manager.play_sound("click", 1.0);
+                    report(self.audio_manager.play_sound("click", 1.0));
                 });
                 ui_slider(ui, "Müzik Sesi", &mut self.music_volume, 0.0..=1.0);
-                let _ = self.audio_manager.set_music_volume(self.music_volume);
+                report(self.audio_manager.set_music_volume(self.music_volume));
                 ui_slider(ui, "SFX Sesi", &mut self.sfx_volume, 0.0..=1.0);
-                let _ = self.audio_manager.set_sfx_volume(self.sfx_volume);
+                report(self.audio_manager.set_sfx_volume(self.sfx_volume));
 
                 ui_slider(ui, "Müzik Pitch", &mut self.music_pitch, 0.5..=2.0);
-                let _ = self.audio_manager.set_music_pitch(self.music_pitch);
+                report(self.audio_manager.set_music_pitch(self.music_pitch));
                 ui_button(ui, "Pause Müzik", || {
-                    let _ = self.audio_manager.pause_music();
+                    report(self.audio_manager.pause_music());
                 });
                 ui.add_space(8.0);
                 ui_button(ui, "Resume Müzik", || {
-                    let _ = self.audio_manager.resume_music();
+                    report(self.audio_manager.resume_music());
                 });
                 ui.add_space(8.0);
                 ui_button(ui, "Fade Out (2s)", || {
-                    let _ = self.audio_manager.fade_out_music(Duration::from_secs(2));
+                    report(self.audio_manager.fade_out_music(Duration::from_secs(2)));
                 });
             });
         });
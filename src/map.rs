@@ -0,0 +1,176 @@
+//! Run map types for deckbuilder games.
+//!
+//! Provides the branching node map a run progresses through (combats, elites, rest sites,
+//! shops, events, treasure and the boss).
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::map::*;
+//!
+//! let mut map = RunMap::new();
+//! let start = map.add_node(NodeType::Combat, 0, 0.5);
+//! let rest = map.add_node(NodeType::Rest, 1, 0.3);
+//! let shop = map.add_node(NodeType::Shop, 1, 0.7);
+//! let boss = map.add_node(NodeType::Boss, 2, 0.5);
+//! map.connect(start, rest);
+//! map.connect(start, shop);
+//! map.connect(rest, boss);
+//! map.connect(shop, boss);
+//!
+//! assert!(map.travel_to(start));
+//! assert_eq!(map.reachable(), vec![rest, shop]);
+//! ```
+//!
+//! # Details
+//!
+//! - Nodes are placed by `row` (floor, starting at 0) and `column` (horizontal position, 0..1).
+//! - Before the first move, every node on row 0 is reachable.
+//! - See each struct and function's documentation for more.
+
+/// Index of a node in a [`RunMap`].
+pub type NodeId = usize;
+
+/// Kind of encounter at a map node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NodeType {
+    Combat,
+    Elite,
+    Rest,
+    Shop,
+    Event,
+    Treasure,
+    Boss,
+}
+
+/// A single node of the run map.
+#[derive(Debug, Clone)]
+pub struct MapNode {
+    pub id: NodeId,
+    pub node_type: NodeType,
+    pub row: usize,
+    pub column: f32,
+    pub next: Vec<NodeId>,
+    pub visited: bool,
+}
+
+/// Branching map of nodes with the player's current position.
+#[derive(Debug, Clone, Default)]
+pub struct RunMap {
+    pub nodes: Vec<MapNode>,
+    pub current: Option<NodeId>,
+}
+
+impl RunMap {
+    /// Creates an empty map.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::map::RunMap;
+    /// let map = RunMap::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a node on `row` at horizontal position `column` (0..1) and returns its id.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::map::{NodeType, RunMap};
+    /// let mut map = RunMap::new();
+    /// let id = map.add_node(NodeType::Event, 0, 0.5);
+    /// ```
+    pub fn add_node(&mut self, node_type: NodeType, row: usize, column: f32) -> NodeId {
+        let id = self.nodes.len();
+        self.nodes.push(MapNode {
+            id,
+            node_type,
+            row,
+            column: column.clamp(0.0, 1.0),
+            next: Vec::new(),
+            visited: false,
+        });
+        id
+    }
+
+    /// Adds a path from `from` to `to`.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::map::{NodeType, RunMap};
+    /// let mut map = RunMap::new();
+    /// let a = map.add_node(NodeType::Combat, 0, 0.5);
+    /// let b = map.add_node(NodeType::Rest, 1, 0.5);
+    /// map.connect(a, b);
+    /// ```
+    pub fn connect(&mut self, from: NodeId, to: NodeId) {
+        if let Some(node) = self.nodes.get_mut(from)
+            && !node.next.contains(&to)
+        {
+            node.next.push(to);
+        }
+    }
+
+    /// Returns the nodes the player can move to next.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::map::{NodeType, RunMap};
+    /// let mut map = RunMap::new();
+    /// let a = map.add_node(NodeType::Combat, 0, 0.5);
+    /// assert_eq!(map.reachable(), vec![a]);
+    /// ```
+    pub fn reachable(&self) -> Vec<NodeId> {
+        match self.current {
+            Some(current) => self.nodes.get(current).map(|n| n.next.clone()).unwrap_or_default(),
+            None => self.nodes.iter().filter(|n| n.row == 0).map(|n| n.id).collect(),
+        }
+    }
+
+    /// Returns `true` if the player can move to `id`.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::map::{NodeType, RunMap};
+    /// let mut map = RunMap::new();
+    /// let a = map.add_node(NodeType::Combat, 0, 0.5);
+    /// assert!(map.is_reachable(a));
+    /// ```
+    pub fn is_reachable(&self, id: NodeId) -> bool {
+        self.reachable().contains(&id)
+    }
+
+    /// Moves the player to `id` if it is reachable, marking it visited.
+    /// Returns `false` if the move is not allowed.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::map::{NodeType, RunMap};
+    /// let mut map = RunMap::new();
+    /// let a = map.add_node(NodeType::Combat, 0, 0.5);
+    /// assert!(map.travel_to(a));
+    /// assert_eq!(map.current, Some(a));
+    /// ```
+    pub fn travel_to(&mut self, id: NodeId) -> bool {
+        if !self.is_reachable(id) {
+            return false;
+        }
+        self.nodes[id].visited = true;
+        self.current = Some(id);
+        true
+    }
+
+    /// Returns the number of rows (floors) of the map.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::map::{NodeType, RunMap};
+    /// let mut map = RunMap::new();
+    /// map.add_node(NodeType::Boss, 4, 0.5);
+    /// assert_eq!(map.rows(), 5);
+    /// ```
+    pub fn rows(&self) -> usize {
+        self.nodes.iter().map(|n| n.row + 1).max().unwrap_or(0)
+    }
+}
@@ -0,0 +1,706 @@
+//! Gameplay metrics for deckbuilder playtests.
+//!
+//! Provides [`MetricsRecorder`], which records gameplay events (cards picked and skipped,
+//! cards played, battles won and lost, runs with their cause of death) to a local JSON Lines
+//! file, and [`MetricsSummary`], which aggregates recorded files into win rates, pick rates,
+//! play counts and causes of death so designers can analyze balance.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::events::GameEvent;
+//! use deckbuilder_eng::metrics::*;
+//!
+//! let path = std::env::temp_dir().join("deckbuilder_metrics_example.jsonl");
+//! # let _ = std::fs::remove_file(&path);
+//! let mut metrics = MetricsRecorder::new(&path);
+//! metrics.start_run();
+//! metrics.record(MetricEvent::CardPicked { card: Some("Bash".into()), offered: vec!["Bash".into(), "Clash".into()] });
+//! metrics.start_battle("Jaw Worm");
+//! metrics.record_game_event(&GameEvent::TurnStarted { turn: 1 });
+//! metrics.record_game_event(&GameEvent::CardPlayed { name: "Bash".into(), cost: 2 });
+//! metrics.record_game_event(&GameEvent::BattleEnded { won: false });
+//! metrics.end_run(false, 3, None);
+//! metrics.flush().unwrap();
+//!
+//! let summary = MetricsSummary::from_records(&read_metrics(&path).unwrap().records);
+//! assert_eq!(summary.losses, 1);
+//! assert_eq!(summary.battle_turns, 1);
+//! assert_eq!(summary.card_plays["Bash"], 1);
+//! assert_eq!(summary.top_causes_of_death(1), [("Jaw Worm", 1)]);
+//! ```
+//!
+//! # Details
+//!
+//! - This module needs the `metrics` feature, so games record nothing unless they opt in.
+//! - Records are buffered and appended to the file by [`MetricsRecorder::flush`], every
+//!   `flush_every` records, and when the recorder is dropped. Each line is one JSON object
+//!   with `time` (seconds since the Unix epoch), `run` and `event` fields.
+//! - [`MetricsRecorder::record_game_event`] turns the engine's `GameEvent`s into metrics:
+//!   played cards, and battle results with the current encounter and turn count. With the
+//!   `egui` feature the recorder is also an `EnginePlugin` that does this automatically.
+//! - A battle's turn count is the number of `TurnStarted` events since it started.
+//! - Reading a file skips lines that aren't valid records, such as one cut off by a crash, and
+//!   counts them in [`MetricsLog::malformed`].
+//! - A lost run without an explicit cause of death is blamed on the last battle's encounter.
+//! - See each struct and function's documentation for more.
+
+use std::collections::HashMap;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+
+#[cfg(feature = "egui")]
+use crate::engine::EngineContext;
+use crate::events::GameEvent;
+use crate::json::{self, quote, Value};
+#[cfg(feature = "egui")]
+use crate::plugin::EnginePlugin;
+use crate::util::unix_now;
+
+/// Error returned when a metrics file can't be written or read.
+#[derive(Debug, Error)]
+pub enum MetricsError {
+    /// The file couldn't be written or read.
+    #[error("metrics io: {0}")]
+    Io(#[from] io::Error),
+}
+
+/// A recorded gameplay event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MetricEvent {
+    /// A new run began.
+    RunStarted,
+    /// A card reward was taken (`card`) or skipped (`None`) from the `offered` cards.
+    CardPicked { card: Option<String>, offered: Vec<String> },
+    /// A card was played.
+    CardPlayed { card: String },
+    /// A battle against `encounter` ended after `turns` turns.
+    BattleEnded { encounter: String, won: bool, turns: u32 },
+    /// The run ended on `floor`; `cause` is what killed the player in a lost run.
+    RunEnded { won: bool, floor: u32, cause: Option<String> },
+    /// A game-specific metric.
+    Custom { name: String, value: i64 },
+}
+
+impl MetricEvent {
+    /// Returns the event name stored in the `event` field of a metrics line.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::metrics::MetricEvent;
+    /// assert_eq!(MetricEvent::CardPlayed { card: "Strike".into() }.name(), "card_played");
+    /// ```
+    pub fn name(&self) -> &'static str {
+        match self {
+            MetricEvent::RunStarted => "run_started",
+            MetricEvent::CardPicked { .. } => "card_picked",
+            MetricEvent::CardPlayed { .. } => "card_played",
+            MetricEvent::BattleEnded { .. } => "battle_ended",
+            MetricEvent::RunEnded { .. } => "run_ended",
+            MetricEvent::Custom { .. } => "custom",
+        }
+    }
+}
+
+/// A [`MetricEvent`] with when and in which run it happened.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MetricRecord {
+    /// Seconds since the Unix epoch.
+    pub time: u64,
+    /// Id of the run, unique per recorder.
+    pub run: u64,
+    pub event: MetricEvent,
+}
+
+impl MetricRecord {
+    /// Returns the record as one line of JSON, without the line break.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::metrics::{MetricEvent, MetricRecord};
+    /// let record = MetricRecord { time: 10, run: 2, event: MetricEvent::CardPlayed { card: "Strike".into() } };
+    /// assert_eq!(record.encode(), r#"{"time":10,"run":2,"event":"card_played","card":"Strike"}"#);
+    /// ```
+    pub fn encode(&self) -> String {
+        let mut line = format!(r#"{{"time":{},"run":{},"event":"{}""#, self.time, self.run, self.event.name());
+        let mut field = |name: &str, value: String| {
+            line.push_str(&format!(r#","{}":{}"#, name, value));
+        };
+        let optional = |text: &Option<String>| text.as_deref().map_or_else(|| "null".to_owned(), quote);
+        match &self.event {
+            MetricEvent::RunStarted => {}
+            MetricEvent::CardPicked { card, offered } => {
+                field("card", optional(card));
+                let offered: Vec<String> = offered.iter().map(|card| quote(card)).collect();
+                field("offered", format!("[{}]", offered.join(",")));
+            }
+            MetricEvent::CardPlayed { card } => field("card", quote(card)),
+            MetricEvent::BattleEnded { encounter, won, turns } => {
+                field("encounter", quote(encounter));
+                field("won", won.to_string());
+                field("turns", turns.to_string());
+            }
+            MetricEvent::RunEnded { won, floor, cause } => {
+                field("won", won.to_string());
+                field("floor", floor.to_string());
+                field("cause", optional(cause));
+            }
+            MetricEvent::Custom { name, value } => {
+                field("name", quote(name));
+                field("value", value.to_string());
+            }
+        }
+        line.push('}');
+        line
+    }
+}
+
+/// Records gameplay events and appends them to a JSON Lines file.
+pub struct MetricsRecorder {
+    path: PathBuf,
+    pending: Vec<MetricRecord>,
+    flush_every: usize,
+    run: u64,
+    encounter: Option<String>,
+    turns: u32,
+    last_encounter: Option<String>,
+}
+
+impl MetricsRecorder {
+    /// Creates a recorder appending to the file at `path`, created on the first flush.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::metrics::MetricsRecorder;
+    /// let metrics = MetricsRecorder::new("playtests/metrics.jsonl");
+    /// assert_eq!(metrics.pending(), 0);
+    /// ```
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            pending: Vec::new(),
+            flush_every: 64,
+            run: 0,
+            encounter: None,
+            turns: 0,
+            last_encounter: None,
+        }
+    }
+
+    /// Sets how many records are buffered before they are written. `0` only writes on
+    /// [`MetricsRecorder::flush`] and drop. Defaults to 64.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::metrics::MetricsRecorder;
+    /// let metrics = MetricsRecorder::new("metrics.jsonl").flush_every(1);
+    /// ```
+    pub fn flush_every(mut self, records: usize) -> Self {
+        self.flush_every = records;
+        self
+    }
+
+    /// Returns the file records are appended to.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::metrics::MetricsRecorder;
+    /// assert!(MetricsRecorder::new("metrics.jsonl").path().ends_with("metrics.jsonl"));
+    /// ```
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the id of the current run, `0` before [`MetricsRecorder::start_run`].
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::metrics::MetricsRecorder;
+    /// let mut metrics = MetricsRecorder::new("metrics.jsonl").flush_every(0);
+    /// let run = metrics.start_run();
+    /// assert_eq!(metrics.run(), run);
+    /// # metrics.clear();
+    /// ```
+    pub fn run(&self) -> u64 {
+        self.run
+    }
+
+    /// Returns the number of records not written yet.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::metrics::{MetricEvent, MetricsRecorder};
+    /// let mut metrics = MetricsRecorder::new("metrics.jsonl").flush_every(0);
+    /// metrics.record(MetricEvent::RunStarted);
+    /// assert_eq!(metrics.pending(), 1);
+    /// # metrics.clear();
+    /// ```
+    pub fn pending(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Records `event` in the current run. Write failures of automatic flushes are logged
+    /// and the records are kept for the next flush.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::metrics::{MetricEvent, MetricsRecorder};
+    /// let mut metrics = MetricsRecorder::new("metrics.jsonl").flush_every(0);
+    /// metrics.record(MetricEvent::Custom { name: "gold_at_boss".into(), value: 212 });
+    /// # metrics.clear();
+    /// ```
+    pub fn record(&mut self, event: MetricEvent) {
+        self.pending.push(MetricRecord {
+            time: unix_now(),
+            run: self.run,
+            event,
+        });
+        if self.flush_every > 0
+            && self.pending.len() >= self.flush_every
+            && let Err(err) = self.flush()
+        {
+            tracing::warn!(%err, "failed to write metrics to {}", self.path.display());
+        }
+    }
+
+    /// Starts a new run, records [`MetricEvent::RunStarted`] and returns the run's id.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::metrics::MetricsRecorder;
+    /// let mut metrics = MetricsRecorder::new("metrics.jsonl").flush_every(0);
+    /// let first = metrics.start_run();
+    /// assert!(metrics.start_run() > first);
+    /// # metrics.clear();
+    /// ```
+    pub fn start_run(&mut self) -> u64 {
+        self.run = unix_now().max(self.run + 1);
+        self.encounter = None;
+        self.last_encounter = None;
+        self.record(MetricEvent::RunStarted);
+        self.run
+    }
+
+    /// Sets the encounter of the battle that starts, for the next battle result.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::metrics::MetricsRecorder;
+    /// let mut metrics = MetricsRecorder::new("metrics.jsonl");
+    /// metrics.start_battle("Gremlin Nob");
+    /// ```
+    pub fn start_battle(&mut self, encounter: impl Into<String>) {
+        self.encounter = Some(encounter.into());
+        self.turns = 0;
+    }
+
+    /// Records what `event` means for the metrics: [`GameEvent::CardPlayed`] as a played
+    /// card, [`GameEvent::TurnStarted`] counts battle turns, [`GameEvent::BattleEnded`] as a
+    /// battle result and [`GameEvent::Custom`] as a custom metric. Other events are ignored.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::events::GameEvent;
+    /// # use deckbuilder_eng::metrics::MetricsRecorder;
+    /// let mut metrics = MetricsRecorder::new("metrics.jsonl").flush_every(0);
+    /// metrics.record_game_event(&GameEvent::CardPlayed { name: "Strike".into(), cost: 1 });
+    /// metrics.record_game_event(&GameEvent::Damage { target: deckbuilder_eng::events::Target::Enemy, amount: 6 });
+    /// assert_eq!(metrics.pending(), 1);
+    /// # metrics.clear();
+    /// ```
+    pub fn record_game_event(&mut self, event: &GameEvent) {
+        match event {
+            GameEvent::CardPlayed { name, .. } => self.record(MetricEvent::CardPlayed { card: name.clone() }),
+            GameEvent::TurnStarted { .. } => self.turns += 1,
+            GameEvent::BattleEnded { won } => {
+                let encounter = self.encounter.take().unwrap_or_default();
+                self.last_encounter = Some(encounter.clone());
+                let turns = std::mem::take(&mut self.turns);
+                self.record(MetricEvent::BattleEnded { encounter, won: *won, turns });
+            }
+            GameEvent::Custom { name, value } => self.record(MetricEvent::Custom {
+                name: name.clone(),
+                value: i64::from(*value),
+            }),
+            _ => {}
+        }
+    }
+
+    /// Records the end of the run. A lost run without a `cause` is blamed on the encounter
+    /// of the last battle.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::metrics::MetricsRecorder;
+    /// let mut metrics = MetricsRecorder::new("metrics.jsonl").flush_every(0);
+    /// metrics.end_run(false, 12, Some("Poison".into()));
+    /// # metrics.clear();
+    /// ```
+    pub fn end_run(&mut self, won: bool, floor: u32, cause: Option<String>) {
+        let cause = if won { None } else { cause.or_else(|| self.last_encounter.take()) };
+        self.record(MetricEvent::RunEnded { won, floor, cause });
+    }
+
+    /// Appends the pending records to the file, creating it and its directory if needed.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use deckbuilder_eng::metrics::{MetricEvent, MetricsRecorder};
+    /// let mut metrics = MetricsRecorder::new("playtests/metrics.jsonl");
+    /// metrics.record(MetricEvent::RunStarted);
+    /// metrics.flush().unwrap();
+    /// ```
+    pub fn flush(&mut self) -> Result<(), MetricsError> {
+        if self.pending.is_empty() {
+            return Ok(());
+        }
+        if let Some(dir) = self.path.parent()
+            && !dir.as_os_str().is_empty()
+        {
+            fs::create_dir_all(dir)?;
+        }
+        let mut text = String::new();
+        for record in &self.pending {
+            text.push_str(&record.encode());
+            text.push('\n');
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        file.write_all(text.as_bytes())?;
+        self.pending.clear();
+        Ok(())
+    }
+
+    /// Drops the pending records without writing them, e.g. when the player opts out.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::metrics::{MetricEvent, MetricsRecorder};
+    /// let mut metrics = MetricsRecorder::new("metrics.jsonl").flush_every(0);
+    /// metrics.record(MetricEvent::RunStarted);
+    /// metrics.clear();
+    /// assert_eq!(metrics.pending(), 0);
+    /// ```
+    pub fn clear(&mut self) {
+        self.pending.clear();
+    }
+}
+
+impl Drop for MetricsRecorder {
+    fn drop(&mut self) {
+        if let Err(err) = self.flush() {
+            tracing::warn!(%err, "failed to write metrics to {}", self.path.display());
+        }
+    }
+}
+
+#[cfg(feature = "egui")]
+impl EnginePlugin for MetricsRecorder {
+    fn name(&self) -> &str {
+        "metrics"
+    }
+
+    fn on_event(&mut self, event: &GameEvent, _engine: &mut EngineContext) {
+        self.record_game_event(event);
+    }
+}
+
+/// Records read from a metrics file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MetricsLog {
+    pub records: Vec<MetricRecord>,
+    /// Lines that couldn't be read, e.g. one cut off by a crash, and were skipped.
+    pub malformed: usize,
+}
+
+/// Reads the records of a metrics file, skipping malformed lines.
+///
+/// # Example
+/// ```no_run
+/// # use deckbuilder_eng::metrics::{read_metrics, MetricsSummary};
+/// let log = read_metrics("playtests/metrics.jsonl").unwrap();
+/// let summary = MetricsSummary::from_records(&log.records);
+/// println!("win rate: {:.0}% ({} bad lines)", summary.win_rate() * 100.0, log.malformed);
+/// ```
+pub fn read_metrics(path: impl AsRef<Path>) -> Result<MetricsLog, MetricsError> {
+    Ok(parse_metrics(&fs::read_to_string(path)?))
+}
+
+/// Parses metrics lines. Blank lines are skipped; malformed lines are logged, skipped and
+/// counted in [`MetricsLog::malformed`].
+///
+/// # Example
+/// ```
+/// # use deckbuilder_eng::metrics::{parse_metrics, MetricEvent};
+/// let log = parse_metrics(concat!(
+///     r#"{"time":10,"run":2,"event":"battle_ended","encounter":"Lagavulin","won":true,"turns":6}"#, "\n",
+///     r#"{"time":11,"run":2,"event":"card_pl"#,
+/// ));
+/// assert_eq!(log.records[0].event, MetricEvent::BattleEnded { encounter: "Lagavulin".into(), won: true, turns: 6 });
+/// assert_eq!(log.malformed, 1);
+/// ```
+pub fn parse_metrics(source: &str) -> MetricsLog {
+    let mut log = MetricsLog::default();
+    for (index, line) in source.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        match record_from_line(line) {
+            Ok(record) => log.records.push(record),
+            Err(message) => {
+                tracing::warn!("skipping metrics line {}: {}", index + 1, message);
+                log.malformed += 1;
+            }
+        }
+    }
+    log
+}
+
+/// Aggregated metrics of one or more playtests.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MetricsSummary {
+    /// Finished runs.
+    pub runs: u32,
+    pub wins: u32,
+    pub losses: u32,
+    pub battles: u32,
+    pub battles_won: u32,
+    /// Total turns of all battles.
+    pub battle_turns: u32,
+    /// Times each card was played.
+    pub card_plays: HashMap<String, u32>,
+    /// Times each card was taken as a reward.
+    pub card_picks: HashMap<String, u32>,
+    /// Times each card was offered as a reward.
+    pub card_offers: HashMap<String, u32>,
+    /// Card rewards skipped.
+    pub skips: u32,
+    /// Lost runs per cause of death.
+    pub causes_of_death: HashMap<String, u32>,
+    /// Battles lost per encounter.
+    pub encounter_losses: HashMap<String, u32>,
+    /// Sum of each custom metric.
+    pub custom: HashMap<String, i64>,
+}
+
+impl MetricsSummary {
+    /// Aggregates `records`, e.g. those of several playtest files.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::metrics::{MetricEvent, MetricRecord, MetricsSummary};
+    /// let records = [
+    ///     MetricRecord { time: 0, run: 1, event: MetricEvent::RunEnded { won: true, floor: 50, cause: None } },
+    ///     MetricRecord { time: 0, run: 2, event: MetricEvent::RunEnded { won: false, floor: 8, cause: Some("Sentries".into()) } },
+    /// ];
+    /// let summary = MetricsSummary::from_records(&records);
+    /// assert_eq!((summary.runs, summary.wins, summary.losses), (2, 1, 1));
+    /// ```
+    pub fn from_records(records: &[MetricRecord]) -> Self {
+        let mut summary = Self::default();
+        for record in records {
+            summary.add(record);
+        }
+        summary
+    }
+
+    /// Adds one record to the totals. Sums of turns and custom values saturate instead of
+    /// overflowing.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::metrics::{MetricEvent, MetricRecord, MetricsSummary};
+    /// let mut summary = MetricsSummary::default();
+    /// summary.add(&MetricRecord { time: 0, run: 1, event: MetricEvent::CardPlayed { card: "Strike".into() } });
+    /// assert_eq!(summary.card_plays["Strike"], 1);
+    ///
+    /// let huge = MetricRecord { time: 0, run: 1, event: MetricEvent::Custom { name: "gold".into(), value: i64::MAX } };
+    /// summary.add(&huge);
+    /// summary.add(&huge);
+    /// assert_eq!(summary.custom["gold"], i64::MAX);
+    /// ```
+    pub fn add(&mut self, record: &MetricRecord) {
+        match &record.event {
+            MetricEvent::RunStarted => {}
+            MetricEvent::CardPicked { card, offered } => {
+                for offer in offered {
+                    *self.card_offers.entry(offer.clone()).or_default() += 1;
+                }
+                match card {
+                    Some(card) => *self.card_picks.entry(card.clone()).or_default() += 1,
+                    None => self.skips += 1,
+                }
+            }
+            MetricEvent::CardPlayed { card } => *self.card_plays.entry(card.clone()).or_default() += 1,
+            MetricEvent::BattleEnded { encounter, won, turns } => {
+                self.battles += 1;
+                self.battle_turns = self.battle_turns.saturating_add(*turns);
+                if *won {
+                    self.battles_won += 1;
+                } else {
+                    *self.encounter_losses.entry(encounter.clone()).or_default() += 1;
+                }
+            }
+            MetricEvent::RunEnded { won, cause, .. } => {
+                self.runs += 1;
+                if *won {
+                    self.wins += 1;
+                } else {
+                    self.losses += 1;
+                    let cause = cause.clone().unwrap_or_else(|| "unknown".to_owned());
+                    *self.causes_of_death.entry(cause).or_default() += 1;
+                }
+            }
+            MetricEvent::Custom { name, value } => {
+                let total = self.custom.entry(name.clone()).or_default();
+                *total = total.saturating_add(*value);
+            }
+        }
+    }
+
+    /// Returns the share of finished runs that were won, or `0.0` without runs.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::metrics::MetricsSummary;
+    /// let summary = MetricsSummary { runs: 4, wins: 1, ..Default::default() };
+    /// assert_eq!(summary.win_rate(), 0.25);
+    /// ```
+    pub fn win_rate(&self) -> f32 {
+        if self.runs == 0 { 0.0 } else { self.wins as f32 / self.runs as f32 }
+    }
+
+    /// Returns the share of offers of `card` in which it was taken, or `None` if it was never
+    /// offered.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::metrics::{parse_metrics, MetricsSummary};
+    /// let log = parse_metrics(concat!(
+    ///     r#"{"time":0,"run":1,"event":"card_picked","card":"Bash","offered":["Bash","Clash"]}"#, "\n",
+    ///     r#"{"time":0,"run":1,"event":"card_picked","card":null,"offered":["Bash","Anger"]}"#,
+    /// ));
+    /// let summary = MetricsSummary::from_records(&log.records);
+    /// assert_eq!(summary.pick_rate("Bash"), Some(0.5));
+    /// assert_eq!(summary.pick_rate("Clash"), Some(0.0));
+    /// assert_eq!(summary.pick_rate("Whirlwind"), None);
+    /// ```
+    pub fn pick_rate(&self, card: &str) -> Option<f32> {
+        let offers = *self.card_offers.get(card)?;
+        let picks = self.card_picks.get(card).copied().unwrap_or_default();
+        Some(picks as f32 / offers.max(1) as f32)
+    }
+
+    /// Returns the average number of turns of a battle, or `0.0` without battles.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::metrics::MetricsSummary;
+    /// let summary = MetricsSummary { battles: 2, battle_turns: 9, ..Default::default() };
+    /// assert_eq!(summary.average_battle_turns(), 4.5);
+    /// ```
+    pub fn average_battle_turns(&self) -> f32 {
+        if self.battles == 0 { 0.0 } else { self.battle_turns as f32 / self.battles as f32 }
+    }
+
+    /// Returns the `count` most played cards, most played first.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::metrics::MetricsSummary;
+    /// let mut summary = MetricsSummary::default();
+    /// summary.card_plays.insert("Strike".into(), 12);
+    /// summary.card_plays.insert("Bash".into(), 3);
+    /// assert_eq!(summary.most_played(1), [("Strike", 12)]);
+    /// ```
+    pub fn most_played(&self, count: usize) -> Vec<(&str, u32)> {
+        top(&self.card_plays, count)
+    }
+
+    /// Returns the `count` most common causes of death, most common first.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::metrics::MetricsSummary;
+    /// let mut summary = MetricsSummary::default();
+    /// summary.causes_of_death.insert("The Champ".into(), 4);
+    /// assert_eq!(summary.top_causes_of_death(3), [("The Champ", 4)]);
+    /// ```
+    pub fn top_causes_of_death(&self, count: usize) -> Vec<(&str, u32)> {
+        top(&self.causes_of_death, count)
+    }
+}
+
+/// The `count` largest entries of `counts`, ties by name.
+fn top(counts: &HashMap<String, u32>, count: usize) -> Vec<(&str, u32)> {
+    let mut entries: Vec<(&str, u32)> = counts.iter().map(|(name, n)| (name.as_str(), *n)).collect();
+    entries.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+    entries.truncate(count);
+    entries
+}
+
+fn record_from_line(line: &str) -> Result<MetricRecord, String> {
+    let Value::Map(fields) = json::parse(line).map_err(|err| err.message)? else {
+        return Err("expected an object".to_owned());
+    };
+    let fields: HashMap<String, Value> = fields.into_iter().collect();
+    let int = |name: &str| match fields.get(name) {
+        Some(Value::Int(n)) => Ok(*n),
+        _ => Err(format!("`{}` must be an integer", name)),
+    };
+    let unsigned = |name: &str| int(name).and_then(|n| u32::try_from(n).map_err(|_| format!("`{}` is out of range", name)));
+    let string = |name: &str| match fields.get(name) {
+        Some(Value::Str(s)) => Ok(s.clone()),
+        _ => Err(format!("`{}` must be a string", name)),
+    };
+    let optional = |name: &str| match fields.get(name) {
+        Some(Value::Str(s)) => Ok(Some(s.clone())),
+        Some(Value::Null) | None => Ok(None),
+        _ => Err(format!("`{}` must be a string or null", name)),
+    };
+    let boolean = |name: &str| match fields.get(name) {
+        Some(Value::Bool(b)) => Ok(*b),
+        _ => Err(format!("`{}` must be true or false", name)),
+    };
+    let event = match string("event")?.as_str() {
+        "run_started" => MetricEvent::RunStarted,
+        "card_picked" => MetricEvent::CardPicked {
+            card: optional("card")?,
+            offered: match fields.get("offered") {
+                Some(Value::List(cards)) => cards
+                    .iter()
+                    .map(|card| match card {
+                        Value::Str(card) => Ok(card.clone()),
+                        _ => Err("`offered` must be a list of strings".to_owned()),
+                    })
+                    .collect::<Result<_, _>>()?,
+                None => Vec::new(),
+                _ => return Err("`offered` must be a list of strings".to_owned()),
+            },
+        },
+        "card_played" => MetricEvent::CardPlayed { card: string("card")? },
+        "battle_ended" => MetricEvent::BattleEnded {
+            encounter: string("encounter")?,
+            won: boolean("won")?,
+            turns: unsigned("turns")?,
+        },
+        "run_ended" => MetricEvent::RunEnded {
+            won: boolean("won")?,
+            floor: unsigned("floor")?,
+            cause: optional("cause")?,
+        },
+        "custom" => MetricEvent::Custom {
+            name: string("name")?,
+            value: int("value")?,
+        },
+        other => return Err(format!("unknown event `{}`", other)),
+    };
+    let non_negative = |name: &str| int(name).and_then(|n| u64::try_from(n).map_err(|_| format!("`{}` is negative", name)));
+    Ok(MetricRecord {
+        time: non_negative("time")?,
+        run: non_negative("run")?,
+        event,
+    })
+}
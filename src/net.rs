@@ -0,0 +1,1364 @@
+//! Networked play for two-player deckbuilder modes.
+//!
+//! Provides a [`Host`] that runs a session (lobby of players, ordered action stream, state
+//! broadcasts) and a [`Client`] that joins it, exchanging [`PlayerAction`]s as text lines over a
+//! [`Transport`]: [`TcpTransport`] over the network, [`LocalTransport`] within one process, or
+//! `web::WebSocketTransport` from a browser (`wasm32` builds).
+//! [`BattleSnapshot`] syncs a [`GameContext`] from the host.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::card::GameContext;
+//! use deckbuilder_eng::events::Target;
+//! use deckbuilder_eng::net::*;
+//!
+//! // Lockstep co-op: every peer applies the same actions in the same order.
+//! let mut host = Host::new("Alice", SyncMode::Lockstep);
+//! let (host_end, client_end) = LocalTransport::pair();
+//! host.accept(host_end);
+//! let mut client = Client::with_transport(client_end, "Bob");
+//!
+//! assert!(matches!(host.poll()[..], [SessionEvent::Joined { .. }]));
+//! assert!(matches!(client.poll()[..], [SessionEvent::Welcome { .. }, SessionEvent::Joined { .. }]));
+//!
+//! client.send(PlayerAction::PlayCard { card: 1, target: Some(Target::Enemy) }).unwrap();
+//! let mut host_battle = GameContext::new(40, 30);
+//! let mut client_battle = GameContext::new(40, 30);
+//! for (events, battle) in [(host.poll(), &mut host_battle), (client.poll(), &mut client_battle)] {
+//!     for event in events {
+//!         if let SessionEvent::Action { action: PlayerAction::PlayCard { .. }, .. } = event {
+//!             battle.deal_damage(6);
+//!         }
+//!     }
+//! }
+//! assert_eq!(host_battle.enemy_health, client_battle.enemy_health);
+//! ```
+//!
+//! # Details
+//!
+//! - In [`SyncMode::Lockstep`] the host numbers every action (its own, via [`Host::submit`],
+//!   and the clients') and sends it to all peers, so all of them see one ordered stream.
+//!   In [`SyncMode::HostAuthoritative`] client actions only reach the host, which applies them
+//!   and sends the resulting state with [`Host::broadcast_state`] (e.g. a [`BattleSnapshot`]).
+//! - Each message is one line (`action 3 1 play 12 enemy`); text fields are escaped, so chat
+//!   and state data may contain newlines.
+//! - Reconnection: clients get a token when they join. After a dropped connection
+//!   ([`SessionEvent::Disconnected`]), [`Client::reconnect`] joins again with it and the host
+//!   resends the numbered messages the client missed; duplicates are skipped. If the host's
+//!   history (see [`Host::history`]) no longer has all of them, a host-authoritative session
+//!   resends only the newest state, and a lockstep session refuses the client, which then
+//!   leaves the session.
+//! - [`TcpTransport`] drops a peer that sends a line longer than [`MAX_LINE_LEN`].
+//! - Browser clients connect with `web::WebSocketTransport` and [`Client::with_transport`]; it
+//!   carries the same line stream as [`TcpTransport`], so a host reaches them through a
+//!   WebSocket-to-TCP proxy (e.g. websockify) in front of [`Host::listen`].
+//! - Transports never block: call `poll` every frame. Other transports plug in by implementing
+//!   [`Transport`].
+//! - See each struct and function's documentation for more.
+
+use std::collections::hash_map::RandomState;
+use std::collections::VecDeque;
+use std::fmt;
+use std::hash::{BuildHasher, Hasher};
+use std::io::{self, ErrorKind, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::mpsc::{self, Receiver, Sender, TryRecvError};
+
+use thiserror::Error;
+
+use crate::card::{CardId, GameContext};
+use crate::events::Target;
+
+/// Errors from network sessions.
+#[derive(Debug, Error)]
+pub enum NetError {
+    /// A connection failed.
+    #[error("network io: {0}")]
+    Io(#[from] io::Error),
+    /// A received message is malformed.
+    #[error("malformed message `{line}`: {message}")]
+    Parse { line: String, message: String },
+    /// The client has no connection to the host.
+    #[error("not connected to a host")]
+    NotConnected,
+}
+
+fn parse_error(line: &str, message: &str) -> NetError {
+    NetError::Parse {
+        line: line.to_owned(),
+        message: message.to_owned(),
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('\n', "\\n").replace('\r', "\\r")
+}
+
+fn unescape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+fn number<T: std::str::FromStr>(line: &str, field: Option<&str>, name: &str) -> Result<T, NetError> {
+    field
+        .and_then(|field| field.parse().ok())
+        .ok_or_else(|| parse_error(line, &format!("invalid {}", name)))
+}
+
+/// Something a player does, sent to the other peers.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PlayerAction {
+    /// Plays the card with id `card`, at `target` if it needs one.
+    PlayCard { card: CardId, target: Option<Target> },
+    EndTurn,
+    /// Picks option `index` of a choice (card reward, event option, map node).
+    Choose { index: u32 },
+    Chat { text: String },
+    /// A game-specific action.
+    Custom { name: String, value: i32 },
+}
+
+impl PlayerAction {
+    /// Encodes the action as one line.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::events::Target;
+    /// # use deckbuilder_eng::net::PlayerAction;
+    /// let action = PlayerAction::PlayCard { card: 12, target: Some(Target::Enemy) };
+    /// assert_eq!(action.encode(), "play 12 enemy");
+    /// ```
+    pub fn encode(&self) -> String {
+        match self {
+            PlayerAction::PlayCard { card, target } => {
+                let target = match target {
+                    Some(Target::Player) => "player",
+                    Some(Target::Enemy) => "enemy",
+                    None => "-",
+                };
+                format!("play {} {}", card, target)
+            }
+            PlayerAction::EndTurn => "end_turn".to_owned(),
+            PlayerAction::Choose { index } => format!("choose {}", index),
+            PlayerAction::Chat { text } => format!("chat {}", escape(text)),
+            PlayerAction::Custom { name, value } => format!("custom {} {}", value, escape(name)),
+        }
+    }
+
+    /// Parses an action encoded by [`PlayerAction::encode`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::net::PlayerAction;
+    /// let chat = PlayerAction::Chat { text: "gg\nwp".to_owned() };
+    /// assert_eq!(PlayerAction::parse(&chat.encode()).unwrap(), chat);
+    /// assert!(PlayerAction::parse("dance").is_err());
+    /// ```
+    pub fn parse(line: &str) -> Result<Self, NetError> {
+        let (kind, rest) = line.split_once(' ').unwrap_or((line, ""));
+        match kind {
+            "play" => {
+                let mut fields = rest.split(' ');
+                let card = number(line, fields.next(), "card id")?;
+                let target = match fields.next() {
+                    Some("player") => Some(Target::Player),
+                    Some("enemy") => Some(Target::Enemy),
+                    Some("-") => None,
+                    _ => return Err(parse_error(line, "invalid target")),
+                };
+                Ok(PlayerAction::PlayCard { card, target })
+            }
+            "end_turn" => Ok(PlayerAction::EndTurn),
+            "choose" => Ok(PlayerAction::Choose {
+                index: number(line, Some(rest), "index")?,
+            }),
+            "chat" => Ok(PlayerAction::Chat { text: unescape(rest) }),
+            "custom" => {
+                let (value, name) = rest.split_once(' ').ok_or_else(|| parse_error(line, "missing name"))?;
+                Ok(PlayerAction::Custom {
+                    name: unescape(name),
+                    value: number(line, Some(value), "value")?,
+                })
+            }
+            _ => Err(parse_error(line, "unknown action")),
+        }
+    }
+}
+
+/// The shared state of a [`GameContext`], for host-authoritative sync.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BattleSnapshot {
+    pub player_health: i32,
+    pub enemy_health: i32,
+    pub energy: u32,
+    pub turn: u32,
+}
+
+impl BattleSnapshot {
+    /// Captures the state of `battle`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::card::GameContext;
+    /// # use deckbuilder_eng::net::BattleSnapshot;
+    /// let snapshot = BattleSnapshot::of(&GameContext::new(40, 30));
+    /// assert_eq!(snapshot.enemy_health, 30);
+    /// ```
+    pub fn of(battle: &GameContext) -> Self {
+        Self {
+            player_health: battle.player_health,
+            enemy_health: battle.enemy_health,
+            energy: battle.energy,
+            turn: battle.turn,
+        }
+    }
+
+    /// Overwrites the state of `battle` with the snapshot. Recorded events are kept.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::card::GameContext;
+    /// # use deckbuilder_eng::net::BattleSnapshot;
+    /// let mut host_battle = GameContext::new(40, 30);
+    /// host_battle.deal_damage(6);
+    /// let mut client_battle = GameContext::new(40, 30);
+    /// BattleSnapshot::of(&host_battle).apply(&mut client_battle);
+    /// assert_eq!(client_battle.enemy_health, 24);
+    /// ```
+    pub fn apply(&self, battle: &mut GameContext) {
+        battle.player_health = self.player_health;
+        battle.enemy_health = self.enemy_health;
+        battle.energy = self.energy;
+        battle.turn = self.turn;
+    }
+
+    /// Encodes the snapshot as `key=value` pairs, for [`Host::broadcast_state`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::card::GameContext;
+    /// # use deckbuilder_eng::net::BattleSnapshot;
+    /// let text = BattleSnapshot::of(&GameContext::new(40, 30)).encode();
+    /// assert_eq!(text, "player_health=40 enemy_health=30 energy=0 turn=1");
+    /// ```
+    pub fn encode(&self) -> String {
+        format!(
+            "player_health={} enemy_health={} energy={} turn={}",
+            self.player_health, self.enemy_health, self.energy, self.turn
+        )
+    }
+
+    /// Parses a snapshot encoded by [`BattleSnapshot::encode`]. Unknown keys are ignored.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::card::GameContext;
+    /// # use deckbuilder_eng::net::BattleSnapshot;
+    /// let snapshot = BattleSnapshot::of(&GameContext::new(40, 30));
+    /// assert_eq!(BattleSnapshot::parse(&snapshot.encode()).unwrap(), snapshot);
+    /// ```
+    pub fn parse(text: &str) -> Result<Self, NetError> {
+        let (mut player_health, mut enemy_health, mut energy, mut turn) = (None, None, None, None);
+        for pair in text.split_whitespace() {
+            let (key, value) = pair.split_once('=').ok_or_else(|| parse_error(text, "expected `key=value`"))?;
+            match key {
+                "player_health" => player_health = Some(number(text, Some(value), key)?),
+                "enemy_health" => enemy_health = Some(number(text, Some(value), key)?),
+                "energy" => energy = Some(number(text, Some(value), key)?),
+                "turn" => turn = Some(number(text, Some(value), key)?),
+                _ => {}
+            }
+        }
+        let missing = |key: &str| parse_error(text, &format!("missing `{}`", key));
+        Ok(Self {
+            player_health: player_health.ok_or_else(|| missing("player_health"))?,
+            enemy_health: enemy_health.ok_or_else(|| missing("enemy_health"))?,
+            energy: energy.ok_or_else(|| missing("energy"))?,
+            turn: turn.ok_or_else(|| missing("turn"))?,
+        })
+    }
+}
+
+/// How peers keep their game state in sync.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncMode {
+    /// Every peer applies the same numbered actions in order.
+    Lockstep,
+    /// The host applies actions and broadcasts the resulting state.
+    HostAuthoritative,
+}
+
+/// Number of a player in a session; the host is [`PlayerId::HOST`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PlayerId(pub u32);
+
+impl PlayerId {
+    /// The hosting player.
+    pub const HOST: PlayerId = PlayerId(0);
+}
+
+impl fmt::Display for PlayerId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A message of the session protocol. `seq` numbers order the host's stream; 0 means unnumbered.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NetMessage {
+    /// Asks to join, or to rejoin with the `token` of an earlier join after message `last_seq`.
+    Join { name: String, token: Option<u64>, last_seq: u64 },
+    /// Accepts a join.
+    Welcome { player: PlayerId, token: u64, mode: SyncMode },
+    /// Refuses a join.
+    Reject { reason: String },
+    /// Announces a player of the session.
+    Joined { player: PlayerId, name: String },
+    /// Announces that a player left the session.
+    Left { player: PlayerId },
+    Action { seq: u64, player: PlayerId, action: PlayerAction },
+    State { seq: u64, data: String },
+    /// Leaves the session.
+    Leave,
+}
+
+impl NetMessage {
+    /// Encodes the message as one line.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::net::{NetMessage, PlayerAction, PlayerId};
+    /// let message = NetMessage::Action { seq: 3, player: PlayerId(1), action: PlayerAction::EndTurn };
+    /// assert_eq!(message.encode(), "action 3 1 end_turn");
+    /// ```
+    pub fn encode(&self) -> String {
+        match self {
+            NetMessage::Join { name, token, last_seq } => {
+                let token = token.map_or("-".to_owned(), |token| format!("{:016x}", token));
+                format!("join {} {} {}", token, last_seq, escape(name))
+            }
+            NetMessage::Welcome { player, token, mode } => {
+                let mode = match mode {
+                    SyncMode::Lockstep => "lockstep",
+                    SyncMode::HostAuthoritative => "host",
+                };
+                format!("welcome {} {:016x} {}", player, token, mode)
+            }
+            NetMessage::Reject { reason } => format!("reject {}", escape(reason)),
+            NetMessage::Joined { player, name } => format!("joined {} {}", player, escape(name)),
+            NetMessage::Left { player } => format!("left {}", player),
+            NetMessage::Action { seq, player, action } => format!("action {} {} {}", seq, player, action.encode()),
+            NetMessage::State { seq, data } => format!("state {} {}", seq, escape(data)),
+            NetMessage::Leave => "leave".to_owned(),
+        }
+    }
+
+    /// Parses a message encoded by [`NetMessage::encode`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::net::NetMessage;
+    /// let join = NetMessage::Join { name: "Bob".to_owned(), token: None, last_seq: 0 };
+    /// assert_eq!(NetMessage::parse(&join.encode()).unwrap(), join);
+    /// ```
+    pub fn parse(line: &str) -> Result<Self, NetError> {
+        let (kind, rest) = line.split_once(' ').unwrap_or((line, ""));
+        let player = |field: Option<&str>| number(line, field, "player").map(PlayerId);
+        let token = |field: Option<&str>| {
+            field
+                .and_then(|token| u64::from_str_radix(token, 16).ok())
+                .ok_or_else(|| parse_error(line, "invalid token"))
+        };
+        match kind {
+            "join" => {
+                let mut fields = rest.splitn(3, ' ');
+                let token = match fields.next() {
+                    Some("-") => None,
+                    field => Some(token(field)?),
+                };
+                Ok(NetMessage::Join {
+                    token,
+                    last_seq: number(line, fields.next(), "sequence number")?,
+                    name: unescape(fields.next().unwrap_or_default()),
+                })
+            }
+            "welcome" => {
+                let mut fields = rest.split(' ');
+                let player = player(fields.next())?;
+                let token = token(fields.next())?;
+                let mode = match fields.next() {
+                    Some("lockstep") => SyncMode::Lockstep,
+                    Some("host") => SyncMode::HostAuthoritative,
+                    _ => return Err(parse_error(line, "invalid sync mode")),
+                };
+                Ok(NetMessage::Welcome { player, token, mode })
+            }
+            "reject" => Ok(NetMessage::Reject { reason: unescape(rest) }),
+            "joined" => {
+                let (id, name) = rest.split_once(' ').ok_or_else(|| parse_error(line, "missing name"))?;
+                Ok(NetMessage::Joined {
+                    player: player(Some(id))?,
+                    name: unescape(name),
+                })
+            }
+            "left" => Ok(NetMessage::Left { player: player(Some(rest))? }),
+            "action" => {
+                let mut fields = rest.splitn(3, ' ');
+                Ok(NetMessage::Action {
+                    seq: number(line, fields.next(), "sequence number")?,
+                    player: player(fields.next())?,
+                    action: PlayerAction::parse(fields.next().unwrap_or_default())?,
+                })
+            }
+            "state" => {
+                let (seq, data) = rest.split_once(' ').unwrap_or((rest, ""));
+                Ok(NetMessage::State {
+                    seq: number(line, Some(seq), "sequence number")?,
+                    data: unescape(data),
+                })
+            }
+            "leave" => Ok(NetMessage::Leave),
+            _ => Err(parse_error(line, "unknown message")),
+        }
+    }
+}
+
+/// A connection that sends and receives lines of text without blocking.
+pub trait Transport {
+    /// Sends one line (without its newline).
+    fn send(&mut self, line: &str) -> io::Result<()>;
+
+    /// Returns the next received line, `None` if there is none yet, or an error once the
+    /// connection is lost.
+    fn receive(&mut self) -> io::Result<Option<String>>;
+}
+
+/// Longest line a [`TcpTransport`] or `web::WebSocketTransport` receives; a peer sending more
+/// without a newline is dropped.
+pub const MAX_LINE_LEN: usize = 1 << 20;
+
+/// Received bytes split into lines, for transports over a byte stream.
+#[derive(Default)]
+pub(crate) struct LineBuffer {
+    bytes: Vec<u8>,
+    /// How many bytes are known not to contain a newline, so each byte is searched once.
+    scanned: usize,
+}
+
+impl LineBuffer {
+    pub(crate) fn extend(&mut self, bytes: &[u8]) {
+        self.bytes.extend_from_slice(bytes);
+    }
+
+    /// Takes the next complete line off the buffer. Fails once more than [`MAX_LINE_LEN`]
+    /// bytes arrived without a newline.
+    pub(crate) fn next_line(&mut self) -> io::Result<Option<String>> {
+        let Some(end) = self.bytes[self.scanned..].iter().position(|&b| b == b'\n') else {
+            self.scanned = self.bytes.len();
+            if self.scanned > MAX_LINE_LEN {
+                return Err(io::Error::new(ErrorKind::InvalidData, "line too long"));
+            }
+            return Ok(None);
+        };
+        let end = self.scanned + end;
+        let line: Vec<u8> = self.bytes.drain(..=end).collect();
+        self.scanned = 0;
+        Ok(Some(String::from_utf8_lossy(&line[..end]).trim_end_matches('\r').to_owned()))
+    }
+}
+
+/// [`Transport`] over a TCP connection.
+pub struct TcpTransport {
+    stream: TcpStream,
+    incoming: LineBuffer,
+    outgoing: Vec<u8>,
+}
+
+impl TcpTransport {
+    /// Connects to a host at `addr`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::net::TcpTransport;
+    /// let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    /// let transport = TcpTransport::connect(listener.local_addr().unwrap()).unwrap();
+    /// ```
+    pub fn connect(addr: impl ToSocketAddrs) -> io::Result<Self> {
+        Self::from_stream(TcpStream::connect(addr)?)
+    }
+
+    /// Wraps a connected stream, switching it to non-blocking mode.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::net::TcpTransport;
+    /// let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    /// let stream = std::net::TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+    /// let transport = TcpTransport::from_stream(stream).unwrap();
+    /// ```
+    pub fn from_stream(stream: TcpStream) -> io::Result<Self> {
+        stream.set_nonblocking(true)?;
+        stream.set_nodelay(true)?;
+        Ok(Self {
+            stream,
+            incoming: LineBuffer::default(),
+            outgoing: Vec::new(),
+        })
+    }
+
+    /// Returns the address of the other end.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::net::TcpTransport;
+    /// # let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    /// let transport = TcpTransport::connect(listener.local_addr().unwrap()).unwrap();
+    /// assert_eq!(transport.peer_addr().unwrap(), listener.local_addr().unwrap());
+    /// ```
+    pub fn peer_addr(&self) -> io::Result<SocketAddr> {
+        self.stream.peer_addr()
+    }
+
+    fn flush_outgoing(&mut self) -> io::Result<()> {
+        while !self.outgoing.is_empty() {
+            match self.stream.write(&self.outgoing) {
+                Ok(0) => return Err(ErrorKind::WriteZero.into()),
+                Ok(written) => {
+                    self.outgoing.drain(..written);
+                }
+                Err(err) if err.kind() == ErrorKind::WouldBlock => break,
+                Err(err) if err.kind() == ErrorKind::Interrupted => {}
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Transport for TcpTransport {
+    fn send(&mut self, line: &str) -> io::Result<()> {
+        self.outgoing.extend_from_slice(line.as_bytes());
+        self.outgoing.push(b'\n');
+        self.flush_outgoing()
+    }
+
+    fn receive(&mut self) -> io::Result<Option<String>> {
+        self.flush_outgoing()?;
+        let mut buf = [0; 4096];
+        loop {
+            if let Some(line) = self.incoming.next_line()? {
+                return Ok(Some(line));
+            }
+            match self.stream.read(&mut buf) {
+                Ok(0) => return Err(ErrorKind::UnexpectedEof.into()),
+                Ok(read) => self.incoming.extend(&buf[..read]),
+                Err(err) if err.kind() == ErrorKind::WouldBlock => return Ok(None),
+                Err(err) if err.kind() == ErrorKind::Interrupted => {}
+                Err(err) => return Err(err),
+            }
+        }
+    }
+}
+
+/// [`Transport`] between two ends in the same process, e.g. for a host playing against a local
+/// client or for tests.
+pub struct LocalTransport {
+    sender: Sender<String>,
+    receiver: Receiver<String>,
+}
+
+impl LocalTransport {
+    /// Creates two connected ends.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::net::{LocalTransport, Transport};
+    /// let (mut a, mut b) = LocalTransport::pair();
+    /// a.send("hello").unwrap();
+    /// assert_eq!(b.receive().unwrap().as_deref(), Some("hello"));
+    /// drop(a);
+    /// assert!(b.receive().is_err());
+    /// ```
+    pub fn pair() -> (Self, Self) {
+        let (a_sender, b_receiver) = mpsc::channel();
+        let (b_sender, a_receiver) = mpsc::channel();
+        (
+            Self {
+                sender: a_sender,
+                receiver: a_receiver,
+            },
+            Self {
+                sender: b_sender,
+                receiver: b_receiver,
+            },
+        )
+    }
+}
+
+impl Transport for LocalTransport {
+    fn send(&mut self, line: &str) -> io::Result<()> {
+        self.sender
+            .send(line.to_owned())
+            .map_err(|_| io::Error::from(ErrorKind::BrokenPipe))
+    }
+
+    fn receive(&mut self) -> io::Result<Option<String>> {
+        match self.receiver.try_recv() {
+            Ok(line) => Ok(Some(line)),
+            Err(TryRecvError::Empty) => Ok(None),
+            Err(TryRecvError::Disconnected) => Err(ErrorKind::UnexpectedEof.into()),
+        }
+    }
+}
+
+/// Something that happened in a session, returned by [`Host::poll`] and [`Client::poll`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum SessionEvent {
+    /// The client was accepted into the session as `player`.
+    Welcome { player: PlayerId, mode: SyncMode },
+    /// The host refused the client.
+    Rejected { reason: String },
+    /// A player joined. Clients also get one for each player already in the session when they
+    /// join or rejoin.
+    Joined { player: PlayerId, name: String },
+    /// A disconnected player joined again.
+    Rejoined { player: PlayerId },
+    /// The connection to a player was lost; on a client, the connection to the host
+    /// ([`PlayerId::HOST`]).
+    Disconnected { player: PlayerId },
+    /// A player left the session.
+    Left { player: PlayerId },
+    /// A player's action. `seq` is its place in the session's stream, 0 if not numbered.
+    Action { seq: u64, player: PlayerId, action: PlayerAction },
+    /// State broadcast by the host.
+    State { seq: u64, data: String },
+}
+
+/// A player of a [`Host`]'s session.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlayerInfo {
+    pub id: PlayerId,
+    pub name: String,
+    /// Whether the player's connection is up (always true for the host).
+    pub connected: bool,
+}
+
+struct Peer {
+    id: PlayerId,
+    name: String,
+    token: u64,
+    transport: Option<Box<dyn Transport>>,
+}
+
+/// The hosting end of a session: accepts players and orders and relays their messages.
+pub struct Host {
+    name: String,
+    mode: SyncMode,
+    max_players: usize,
+    history_len: usize,
+    listener: Option<TcpListener>,
+    pending: Vec<Box<dyn Transport>>,
+    peers: Vec<Peer>,
+    history: VecDeque<(u64, String)>,
+    events: Vec<SessionEvent>,
+    seq: u64,
+    next_player: u32,
+    tokens: RandomState,
+}
+
+impl Host {
+    /// Creates a session hosted by the player `name`, for 2 players.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::net::{Host, SyncMode};
+    /// let host = Host::new("Alice", SyncMode::HostAuthoritative);
+    /// assert_eq!(host.players().len(), 1);
+    /// ```
+    pub fn new(name: impl Into<String>, mode: SyncMode) -> Self {
+        Self {
+            name: name.into(),
+            mode,
+            max_players: 2,
+            history_len: 1024,
+            listener: None,
+            pending: Vec::new(),
+            peers: Vec::new(),
+            history: VecDeque::new(),
+            events: Vec::new(),
+            seq: 0,
+            next_player: 1,
+            tokens: RandomState::new(),
+        }
+    }
+
+    /// Sets the most players in the session, the host included.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::net::{Host, SyncMode};
+    /// let host = Host::new("Alice", SyncMode::Lockstep).max_players(4);
+    /// ```
+    pub fn max_players(mut self, count: usize) -> Self {
+        self.max_players = count.max(1);
+        self
+    }
+
+    /// Sets how many numbered messages are kept to resend to reconnecting players (1024 by
+    /// default). A lockstep player who missed more is refused when reconnecting.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::net::*;
+    /// let mut host = Host::new("Alice", SyncMode::Lockstep).history(1);
+    /// let (host_end, client_end) = LocalTransport::pair();
+    /// host.accept(host_end);
+    /// let mut client = Client::with_transport(client_end, "Bob");
+    /// host.poll();
+    /// client.poll();
+    ///
+    /// // Bob misses two actions, but only the last one is kept.
+    /// host.submit(PlayerAction::EndTurn);
+    /// host.submit(PlayerAction::EndTurn);
+    /// let (host_end, client_end) = LocalTransport::pair();
+    /// host.accept(host_end);
+    /// client.reconnect_with(client_end);
+    /// assert!(host.poll().contains(&SessionEvent::Left { player: PlayerId(1) }));
+    /// assert!(matches!(client.poll()[..], [SessionEvent::Rejected { .. }]));
+    /// ```
+    pub fn history(mut self, count: usize) -> Self {
+        self.history_len = count;
+        self
+    }
+
+    /// Accepts TCP connections at `addr` from now on and returns the bound address.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::net::{Host, SyncMode};
+    /// let mut host = Host::new("Alice", SyncMode::Lockstep);
+    /// let addr = host.listen("127.0.0.1:0").unwrap();
+    /// assert_ne!(addr.port(), 0);
+    /// ```
+    pub fn listen(&mut self, addr: impl ToSocketAddrs) -> io::Result<SocketAddr> {
+        let listener = TcpListener::bind(addr)?;
+        listener.set_nonblocking(true)?;
+        let addr = listener.local_addr()?;
+        self.listener = Some(listener);
+        Ok(addr)
+    }
+
+    /// Adds a connection whose client will join with its first message.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::net::{Client, Host, LocalTransport, SessionEvent, SyncMode};
+    /// let mut host = Host::new("Alice", SyncMode::Lockstep);
+    /// let (host_end, client_end) = LocalTransport::pair();
+    /// host.accept(host_end);
+    /// let client = Client::with_transport(client_end, "Bob");
+    /// assert!(matches!(host.poll()[..], [SessionEvent::Joined { .. }]));
+    /// ```
+    pub fn accept(&mut self, transport: impl Transport + 'static) {
+        self.pending.push(Box::new(transport));
+    }
+
+    /// Returns the sync mode of the session.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::net::{Host, SyncMode};
+    /// assert_eq!(Host::new("Alice", SyncMode::Lockstep).mode(), SyncMode::Lockstep);
+    /// ```
+    pub fn mode(&self) -> SyncMode {
+        self.mode
+    }
+
+    /// Returns the players, the host first.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::net::{Host, PlayerId, SyncMode};
+    /// let host = Host::new("Alice", SyncMode::Lockstep);
+    /// assert_eq!(host.players()[0].id, PlayerId::HOST);
+    /// ```
+    pub fn players(&self) -> Vec<PlayerInfo> {
+        let host = PlayerInfo {
+            id: PlayerId::HOST,
+            name: self.name.clone(),
+            connected: true,
+        };
+        std::iter::once(host)
+            .chain(self.peers.iter().map(|peer| PlayerInfo {
+                id: peer.id,
+                name: peer.name.clone(),
+                connected: peer.transport.is_some(),
+            }))
+            .collect()
+    }
+
+    /// Returns the number of the last numbered message.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::net::{Host, PlayerAction, SyncMode};
+    /// let mut host = Host::new("Alice", SyncMode::Lockstep);
+    /// host.submit(PlayerAction::EndTurn);
+    /// assert_eq!(host.seq(), 1);
+    /// ```
+    pub fn seq(&self) -> u64 {
+        self.seq
+    }
+
+    /// Plays an action of the host. In lockstep it is numbered and sent to every client; in
+    /// both modes it is returned by the next [`Host::poll`] like the clients' actions.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::net::{Host, PlayerAction, PlayerId, SessionEvent, SyncMode};
+    /// let mut host = Host::new("Alice", SyncMode::Lockstep);
+    /// host.submit(PlayerAction::EndTurn);
+    /// let events = host.poll();
+    /// assert!(matches!(events[..], [SessionEvent::Action { seq: 1, player: PlayerId::HOST, .. }]));
+    /// ```
+    pub fn submit(&mut self, action: PlayerAction) {
+        self.relay_action(PlayerId::HOST, action);
+    }
+
+    /// Sends `data` (e.g. an encoded [`BattleSnapshot`]) to every client as a numbered state
+    /// message. Returns its number.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::card::GameContext;
+    /// # use deckbuilder_eng::net::{BattleSnapshot, Host, SyncMode};
+    /// let mut host = Host::new("Alice", SyncMode::HostAuthoritative);
+    /// let battle = GameContext::new(40, 30);
+    /// assert_eq!(host.broadcast_state(BattleSnapshot::of(&battle).encode()), 1);
+    /// ```
+    pub fn broadcast_state(&mut self, data: impl Into<String>) -> u64 {
+        self.seq += 1;
+        let seq = self.seq;
+        self.broadcast_numbered(NetMessage::State { seq, data: data.into() });
+        seq
+    }
+
+    /// Removes `player` from the session, telling the other clients. The next [`Host::poll`]
+    /// returns [`SessionEvent::Left`] for it, as when a player leaves. Returns false if there is
+    /// no such client.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::net::{Client, Host, LocalTransport, PlayerId, SessionEvent, SyncMode};
+    /// let mut host = Host::new("Alice", SyncMode::Lockstep);
+    /// let (host_end, client_end) = LocalTransport::pair();
+    /// host.accept(host_end);
+    /// let client = Client::with_transport(client_end, "Bob");
+    /// host.poll();
+    ///
+    /// assert!(host.kick(PlayerId(1)));
+    /// assert_eq!(host.poll(), [SessionEvent::Left { player: PlayerId(1) }]);
+    /// assert!(!host.kick(PlayerId(1)));
+    /// ```
+    pub fn kick(&mut self, player: PlayerId) -> bool {
+        let Some(index) = self.peers.iter().position(|peer| peer.id == player) else {
+            return false;
+        };
+        let mut peer = self.peers.remove(index);
+        if let Some(transport) = &mut peer.transport {
+            let _ = transport.send(&NetMessage::Reject { reason: "removed by the host".to_owned() }.encode());
+        }
+        self.broadcast(&NetMessage::Left { player });
+        self.events.push(SessionEvent::Left { player });
+        true
+    }
+
+    /// Accepts connections, handles joins and reconnections, relays messages and returns what
+    /// happened since the last call. Call it every frame.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::net::{Host, SessionEvent, SyncMode};
+    /// # let mut host = Host::new("Alice", SyncMode::Lockstep);
+    /// for event in host.poll() {
+    ///     if let SessionEvent::Disconnected { player } = event {
+    ///         println!("Waiting for player {} to reconnect", player);
+    ///     }
+    /// }
+    /// ```
+    pub fn poll(&mut self) -> Vec<SessionEvent> {
+        if let Some(listener) = &self.listener {
+            while let Ok((stream, _)) = listener.accept() {
+                match TcpTransport::from_stream(stream) {
+                    Ok(transport) => self.pending.push(Box::new(transport)),
+                    Err(err) => tracing::warn!(%err, "failed to accept a connection"),
+                }
+            }
+        }
+        for mut transport in std::mem::take(&mut self.pending) {
+            match transport.receive() {
+                Ok(Some(line)) => match NetMessage::parse(&line) {
+                    Ok(NetMessage::Join { name, token, last_seq }) => self.join(transport, name, token, last_seq),
+                    _ => tracing::warn!(line, "expected a join message"),
+                },
+                Ok(None) => self.pending.push(transport),
+                Err(_) => {}
+            }
+        }
+        for index in 0..self.peers.len() {
+            self.receive_from(index);
+        }
+        self.peers.retain(|peer| peer.token != 0);
+        std::mem::take(&mut self.events)
+    }
+
+    fn receive_from(&mut self, index: usize) {
+        loop {
+            let peer = &mut self.peers[index];
+            let id = peer.id;
+            let Some(transport) = &mut peer.transport else {
+                return;
+            };
+            let line = match transport.receive() {
+                Ok(Some(line)) => line,
+                Ok(None) => return,
+                Err(err) => {
+                    tracing::info!(player = %id, %err, "player disconnected");
+                    peer.transport = None;
+                    self.events.push(SessionEvent::Disconnected { player: id });
+                    return;
+                }
+            };
+            match NetMessage::parse(&line) {
+                Ok(NetMessage::Action { action, .. }) => self.relay_action(id, action),
+                Ok(NetMessage::Leave) => {
+                    // Dropped from `peers` at the end of the poll.
+                    peer.token = 0;
+                    peer.transport = None;
+                    self.broadcast(&NetMessage::Left { player: id });
+                    self.events.push(SessionEvent::Left { player: id });
+                    return;
+                }
+                Ok(message) => tracing::warn!(player = %id, ?message, "unexpected message from a client"),
+                Err(err) => tracing::warn!(player = %id, %err, "malformed message from a client"),
+            }
+        }
+    }
+
+    fn join(&mut self, mut transport: Box<dyn Transport>, name: String, token: Option<u64>, last_seq: u64) {
+        let mut reject = |reason: &str| {
+            let _ = transport.send(&NetMessage::Reject { reason: reason.to_owned() }.encode());
+        };
+        let rejoining = token.and_then(|token| self.peers.iter().position(|peer| peer.token == token));
+        if rejoining.is_none() {
+            if token.is_some() {
+                return reject("unknown session token");
+            }
+            if self.peers.len() + 1 >= self.max_players {
+                return reject("the session is full");
+            }
+        }
+        let Some(missed) = self.replay(last_seq) else {
+            reject("too far behind the session to catch up");
+            if let Some(index) = rejoining {
+                let player = self.peers.remove(index).id;
+                self.broadcast(&NetMessage::Left { player });
+                self.events.push(SessionEvent::Left { player });
+            }
+            return;
+        };
+        let (id, token) = match rejoining {
+            Some(index) => (self.peers[index].id, self.peers[index].token),
+            None => {
+                let id = PlayerId(self.next_player);
+                let mut hasher = self.tokens.build_hasher();
+                hasher.write_u32(id.0);
+                // 0 marks a player who left.
+                (id, hasher.finish().max(1))
+            }
+        };
+        let mut messages = vec![NetMessage::Welcome { player: id, token, mode: self.mode }.encode()];
+        messages.push(NetMessage::Joined { player: PlayerId::HOST, name: self.name.clone() }.encode());
+        for other in self.peers.iter().filter(|other| other.id != id) {
+            messages.push(NetMessage::Joined { player: other.id, name: other.name.clone() }.encode());
+        }
+        messages.extend(missed);
+        if !messages.iter().all(|line| transport.send(line).is_ok()) {
+            return;
+        }
+        match rejoining {
+            Some(index) => {
+                self.peers[index].transport = Some(transport);
+                self.events.push(SessionEvent::Rejoined { player: id });
+            }
+            None => {
+                self.next_player += 1;
+                self.broadcast(&NetMessage::Joined { player: id, name: name.clone() });
+                self.peers.push(Peer { id, name: name.clone(), token, transport: Some(transport) });
+                self.events.push(SessionEvent::Joined { player: id, name });
+            }
+        }
+    }
+
+    /// Returns the numbered messages after `last_seq` to resend, or `None` if some of them
+    /// were already dropped from the history. In host-authoritative mode every state message
+    /// is complete, so the newest one alone resyncs the client.
+    fn replay(&self, last_seq: u64) -> Option<Vec<String>> {
+        let missed = self.history.iter().filter(|(seq, _)| *seq > last_seq).map(|(_, line)| line.clone());
+        let complete = self.seq <= last_seq || self.history.front().is_some_and(|(seq, _)| *seq <= last_seq + 1);
+        match self.mode {
+            _ if complete => Some(missed.collect()),
+            SyncMode::Lockstep => None,
+            SyncMode::HostAuthoritative => self.history.back().map(|(_, state)| vec![state.clone()]),
+        }
+    }
+
+    fn relay_action(&mut self, player: PlayerId, action: PlayerAction) {
+        let seq = match self.mode {
+            SyncMode::Lockstep => {
+                self.seq += 1;
+                self.broadcast_numbered(NetMessage::Action { seq: self.seq, player, action: action.clone() });
+                self.seq
+            }
+            SyncMode::HostAuthoritative => 0,
+        };
+        self.events.push(SessionEvent::Action { seq, player, action });
+    }
+
+    fn broadcast_numbered(&mut self, message: NetMessage) {
+        let seq = match &message {
+            NetMessage::Action { seq, .. } | NetMessage::State { seq, .. } => *seq,
+            _ => 0,
+        };
+        self.broadcast(&message);
+        self.history.push_back((seq, message.encode()));
+        while self.history.len() > self.history_len {
+            self.history.pop_front();
+        }
+    }
+
+    fn broadcast(&mut self, message: &NetMessage) {
+        let line = message.encode();
+        for peer in &mut self.peers {
+            if let Some(transport) = &mut peer.transport
+                && transport.send(&line).is_err()
+            {
+                peer.transport = None;
+                self.events.push(SessionEvent::Disconnected { player: peer.id });
+            }
+        }
+    }
+}
+
+/// The joining end of a session.
+pub struct Client {
+    name: String,
+    addr: Option<SocketAddr>,
+    transport: Option<Box<dyn Transport>>,
+    player: Option<PlayerId>,
+    mode: Option<SyncMode>,
+    token: Option<u64>,
+    last_seq: u64,
+}
+
+impl Client {
+    /// Connects to a host at `addr` over TCP and asks to join as `name`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::net::{Client, Host, SyncMode};
+    /// let mut host = Host::new("Alice", SyncMode::Lockstep);
+    /// let addr = host.listen("127.0.0.1:0").unwrap();
+    /// let client = Client::connect(addr, "Bob").unwrap();
+    /// ```
+    pub fn connect(addr: impl ToSocketAddrs, name: impl Into<String>) -> Result<Self, NetError> {
+        let transport = TcpTransport::connect(addr)?;
+        let addr = transport.peer_addr()?;
+        let mut client = Self::with_transport(transport, name);
+        client.addr = Some(addr);
+        Ok(client)
+    }
+
+    /// Joins as `name` over `transport`, e.g. one end of a [`LocalTransport`] pair.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::net::{Client, LocalTransport};
+    /// let (host_end, client_end) = LocalTransport::pair();
+    /// let client = Client::with_transport(client_end, "Bob");
+    /// assert!(client.is_connected());
+    /// ```
+    pub fn with_transport(transport: impl Transport + 'static, name: impl Into<String>) -> Self {
+        let mut client = Self {
+            name: name.into(),
+            addr: None,
+            transport: None,
+            player: None,
+            mode: None,
+            token: None,
+            last_seq: 0,
+        };
+        client.join(Box::new(transport));
+        client
+    }
+
+    /// Returns the player number given by the host, once welcomed.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::net::{Client, LocalTransport};
+    /// let (_host_end, client_end) = LocalTransport::pair();
+    /// let client = Client::with_transport(client_end, "Bob");
+    /// assert_eq!(client.player(), None);
+    /// ```
+    pub fn player(&self) -> Option<PlayerId> {
+        self.player
+    }
+
+    /// Returns the sync mode of the session, once welcomed.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::net::{Client, LocalTransport};
+    /// # let (_host_end, client_end) = LocalTransport::pair();
+    /// let client = Client::with_transport(client_end, "Bob");
+    /// assert_eq!(client.mode(), None);
+    /// ```
+    pub fn mode(&self) -> Option<SyncMode> {
+        self.mode
+    }
+
+    /// Returns true while the connection to the host is up.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::net::{Client, LocalTransport};
+    /// let (host_end, client_end) = LocalTransport::pair();
+    /// let mut client = Client::with_transport(client_end, "Bob");
+    /// drop(host_end);
+    /// client.poll();
+    /// assert!(!client.is_connected());
+    /// ```
+    pub fn is_connected(&self) -> bool {
+        self.transport.is_some()
+    }
+
+    /// Returns the number of the last numbered message received.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::net::{Client, LocalTransport};
+    /// # let (_host_end, client_end) = LocalTransport::pair();
+    /// let client = Client::with_transport(client_end, "Bob");
+    /// assert_eq!(client.last_seq(), 0);
+    /// ```
+    pub fn last_seq(&self) -> u64 {
+        self.last_seq
+    }
+
+    /// Sends an action to the host.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::net::{Client, LocalTransport, PlayerAction};
+    /// # let (_host_end, client_end) = LocalTransport::pair();
+    /// let mut client = Client::with_transport(client_end, "Bob");
+    /// client.send(PlayerAction::Chat { text: "Good luck!".to_owned() }).unwrap();
+    /// ```
+    pub fn send(&mut self, action: PlayerAction) -> Result<(), NetError> {
+        let message = NetMessage::Action {
+            seq: 0,
+            player: self.player.unwrap_or(PlayerId::HOST),
+            action,
+        };
+        self.send_message(&message)
+    }
+
+    /// Leaves the session and closes the connection.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::net::{Client, LocalTransport};
+    /// # let (_host_end, client_end) = LocalTransport::pair();
+    /// let mut client = Client::with_transport(client_end, "Bob");
+    /// client.leave();
+    /// assert!(!client.is_connected());
+    /// ```
+    pub fn leave(&mut self) {
+        let _ = self.send_message(&NetMessage::Leave);
+        self.transport = None;
+        self.token = None;
+    }
+
+    /// Connects again to the address given to [`Client::connect`] and rejoins the session,
+    /// receiving the messages missed meanwhile.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use deckbuilder_eng::net::{Client, SessionEvent};
+    /// # fn demo(client: &mut Client) {
+    /// for event in client.poll() {
+    ///     if let SessionEvent::Disconnected { .. } = event {
+    ///         if let Err(err) = client.reconnect() {
+    ///             eprintln!("reconnect failed: {err}");
+    ///         }
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn reconnect(&mut self) -> Result<(), NetError> {
+        let addr = self.addr.ok_or(NetError::NotConnected)?;
+        let transport = TcpTransport::connect(addr)?;
+        self.join(Box::new(transport));
+        Ok(())
+    }
+
+    /// Rejoins the session over a new `transport`, receiving the messages missed meanwhile.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::net::*;
+    /// let mut host = Host::new("Alice", SyncMode::Lockstep);
+    /// let (host_end, client_end) = LocalTransport::pair();
+    /// host.accept(host_end);
+    /// let mut client = Client::with_transport(client_end, "Bob");
+    /// host.poll();
+    /// client.poll();
+    ///
+    /// // Bob's connection drops while Alice plays on...
+    /// let (host_end, client_end) = LocalTransport::pair();
+    /// host.submit(PlayerAction::EndTurn);
+    /// host.accept(host_end);
+    /// client.reconnect_with(client_end);
+    ///
+    /// // ...and the host resends what Bob missed.
+    /// assert!(host.poll().contains(&SessionEvent::Rejoined { player: PlayerId(1) }));
+    /// let events = client.poll();
+    /// assert!(events.iter().any(|event| matches!(event, SessionEvent::Action { seq: 1, .. })));
+    /// ```
+    pub fn reconnect_with(&mut self, transport: impl Transport + 'static) {
+        self.join(Box::new(transport));
+    }
+
+    /// Receives messages from the host and returns what happened since the last call. Call it
+    /// every frame.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::card::GameContext;
+    /// # use deckbuilder_eng::net::{BattleSnapshot, Client, SessionEvent};
+    /// # fn demo(client: &mut Client, battle: &mut GameContext) {
+    /// for event in client.poll() {
+    ///     if let SessionEvent::State { data, .. } = event {
+    ///         if let Ok(snapshot) = BattleSnapshot::parse(&data) {
+    ///             snapshot.apply(battle);
+    ///         }
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn poll(&mut self) -> Vec<SessionEvent> {
+        let mut events = Vec::new();
+        while let Some(transport) = &mut self.transport {
+            let line = match transport.receive() {
+                Ok(Some(line)) => line,
+                Ok(None) => break,
+                Err(err) => {
+                    tracing::info!(%err, "disconnected from the host");
+                    self.transport = None;
+                    events.push(SessionEvent::Disconnected { player: PlayerId::HOST });
+                    break;
+                }
+            };
+            let message = match NetMessage::parse(&line) {
+                Ok(message) => message,
+                Err(err) => {
+                    tracing::warn!(%err, "malformed message from the host");
+                    continue;
+                }
+            };
+            match message {
+                NetMessage::Welcome { player, token, mode } => {
+                    let rejoined = self.token == Some(token);
+                    self.player = Some(player);
+                    self.mode = Some(mode);
+                    self.token = Some(token);
+                    if !rejoined {
+                        events.push(SessionEvent::Welcome { player, mode });
+                    }
+                }
+                NetMessage::Reject { reason } => {
+                    self.transport = None;
+                    events.push(SessionEvent::Rejected { reason });
+                }
+                NetMessage::Joined { player, name } => {
+                    if Some(player) != self.player {
+                        events.push(SessionEvent::Joined { player, name });
+                    }
+                }
+                NetMessage::Left { player } => events.push(SessionEvent::Left { player }),
+                NetMessage::Action { seq, player, action } => {
+                    if self.accept_seq(seq) {
+                        events.push(SessionEvent::Action { seq, player, action });
+                    }
+                }
+                NetMessage::State { seq, data } => {
+                    if self.accept_seq(seq) {
+                        events.push(SessionEvent::State { seq, data });
+                    }
+                }
+                NetMessage::Join { .. } | NetMessage::Leave => {
+                    tracing::warn!(line, "unexpected message from the host");
+                }
+            }
+        }
+        events
+    }
+
+    /// Returns false for a numbered message already received, e.g. resent after a reconnect.
+    fn accept_seq(&mut self, seq: u64) -> bool {
+        if seq == 0 {
+            return true;
+        }
+        if seq <= self.last_seq {
+            return false;
+        }
+        self.last_seq = seq;
+        true
+    }
+
+    fn join(&mut self, transport: Box<dyn Transport>) {
+        self.transport = Some(transport);
+        let join = NetMessage::Join {
+            name: self.name.clone(),
+            token: self.token,
+            last_seq: self.last_seq,
+        };
+        if let Err(err) = self.send_message(&join) {
+            tracing::warn!(%err, "failed to join the session");
+        }
+    }
+
+    fn send_message(&mut self, message: &NetMessage) -> Result<(), NetError> {
+        let transport = self.transport.as_mut().ok_or(NetError::NotConnected)?;
+        if let Err(err) = transport.send(&message.encode()) {
+            self.transport = None;
+            return Err(err.into());
+        }
+        Ok(())
+    }
+}
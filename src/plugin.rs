@@ -0,0 +1,295 @@
+//! Plugins for the deckbuilder engine.
+//!
+//! Provides the [`EnginePlugin`] trait for self-contained features (analytics, debug tools,
+//! mod-provided systems) that initialize once, react to game events, update every frame and
+//! draw their own UI, and the [`PluginRegistry`] the engine runs them from.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use deckbuilder_eng::engine::{DeckbuilderEngine, EngineConfig, EngineContext};
+//! use deckbuilder_eng::events::GameEvent;
+//! use deckbuilder_eng::plugin::EnginePlugin;
+//!
+//! #[derive(Default)]
+//! struct DamageMeter {
+//!     total: i32,
+//! }
+//!
+//! impl EnginePlugin for DamageMeter {
+//!     fn name(&self) -> &str {
+//!         "damage_meter"
+//!     }
+//!
+//!     fn on_event(&mut self, event: &GameEvent, _engine: &mut EngineContext) {
+//!         if let GameEvent::Damage { amount, .. } = event {
+//!             self.total += amount;
+//!         }
+//!     }
+//!
+//!     fn ui(&mut self, engine: &mut EngineContext) {
+//!         egui::Window::new("Damage").show(engine.ctx, |ui| ui.label(self.total.to_string()));
+//!     }
+//! }
+//!
+//! fn main() -> eframe::Result<()> {
+//!     DeckbuilderEngine::run(EngineConfig::new("My Deckbuilder").plugin(DamageMeter::default()))
+//! }
+//! ```
+//!
+//! # Details
+//!
+//! - Each frame the engine initializes newly added plugins (after the `on_start` hooks), then
+//!   calls `update` after the hooks' `before_frame` and `on_action`, `on_event` for each event
+//!   dispatched on `GameState::events`, and `ui` after the screens are drawn.
+//! - Plugins are identified by [`EnginePlugin::name`]; a registry holds one plugin per name.
+//! - Disabled plugins are skipped until enabled again; they keep their state.
+//! - See each struct and function's documentation for more.
+
+use crate::engine::EngineContext;
+use crate::events::GameEvent;
+
+/// A feature added to the engine from outside. All methods but `name` do nothing by default.
+pub trait EnginePlugin {
+    /// Unique name of the plugin.
+    fn name(&self) -> &str;
+
+    /// Called once, on the first frame after the plugin is added.
+    fn init(&mut self, _engine: &mut EngineContext) {}
+
+    /// Called for each event dispatched on `GameState::events`.
+    fn on_event(&mut self, _event: &GameEvent, _engine: &mut EngineContext) {}
+
+    /// Called every frame before the screens are drawn.
+    fn update(&mut self, _engine: &mut EngineContext) {}
+
+    /// Called every frame after the screens are drawn, to show windows, panels or overlays.
+    fn ui(&mut self, _engine: &mut EngineContext) {}
+}
+
+struct Entry {
+    plugin: Box<dyn EnginePlugin>,
+    enabled: bool,
+    initialized: bool,
+}
+
+/// Plugins of an engine, in the order they were added.
+#[derive(Default)]
+pub struct PluginRegistry {
+    entries: Vec<Entry>,
+}
+
+impl PluginRegistry {
+    /// Creates an empty registry.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::plugin::PluginRegistry;
+    /// let plugins = PluginRegistry::new();
+    /// assert!(plugins.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an enabled plugin. Returns false, without adding it, if a plugin of the same name
+    /// is already registered.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::plugin::{EnginePlugin, PluginRegistry};
+    /// struct Analytics;
+    /// impl EnginePlugin for Analytics {
+    ///     fn name(&self) -> &str { "analytics" }
+    /// }
+    /// let mut plugins = PluginRegistry::new();
+    /// assert!(plugins.add(Analytics));
+    /// assert!(!plugins.add(Analytics));
+    /// ```
+    pub fn add(&mut self, plugin: impl EnginePlugin + 'static) -> bool {
+        self.add_boxed(Box::new(plugin))
+    }
+
+    /// Adds an already boxed plugin, e.g. one created by a mod loader; see
+    /// [`PluginRegistry::add`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::plugin::{EnginePlugin, PluginRegistry};
+    /// # struct Analytics;
+    /// # impl EnginePlugin for Analytics {
+    /// #     fn name(&self) -> &str { "analytics" }
+    /// # }
+    /// let plugin: Box<dyn EnginePlugin> = Box::new(Analytics);
+    /// let mut plugins = PluginRegistry::new();
+    /// assert!(plugins.add_boxed(plugin));
+    /// ```
+    pub fn add_boxed(&mut self, plugin: Box<dyn EnginePlugin>) -> bool {
+        if self.contains(plugin.name()) {
+            return false;
+        }
+        self.entries.push(Entry {
+            plugin,
+            enabled: true,
+            initialized: false,
+        });
+        true
+    }
+
+    /// Removes and returns the plugin called `name`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::plugin::{EnginePlugin, PluginRegistry};
+    /// # struct Analytics;
+    /// # impl EnginePlugin for Analytics {
+    /// #     fn name(&self) -> &str { "analytics" }
+    /// # }
+    /// let mut plugins = PluginRegistry::new();
+    /// plugins.add(Analytics);
+    /// assert!(plugins.remove("analytics").is_some());
+    /// assert!(plugins.is_empty());
+    /// ```
+    pub fn remove(&mut self, name: &str) -> Option<Box<dyn EnginePlugin>> {
+        let index = self.entries.iter().position(|e| e.plugin.name() == name)?;
+        Some(self.entries.remove(index).plugin)
+    }
+
+    /// Returns true if a plugin called `name` is registered.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::plugin::PluginRegistry;
+    /// assert!(!PluginRegistry::new().contains("analytics"));
+    /// ```
+    pub fn contains(&self, name: &str) -> bool {
+        self.entries.iter().any(|e| e.plugin.name() == name)
+    }
+
+    /// Returns the plugin called `name`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::plugin::PluginRegistry;
+    /// assert!(PluginRegistry::new().get("analytics").is_none());
+    /// ```
+    pub fn get(&self, name: &str) -> Option<&dyn EnginePlugin> {
+        self.entries.iter().find(|e| e.plugin.name() == name).map(|e| e.plugin.as_ref())
+    }
+
+    /// Returns the plugin called `name` mutably.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::plugin::PluginRegistry;
+    /// assert!(PluginRegistry::new().get_mut("analytics").is_none());
+    /// ```
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut (dyn EnginePlugin + 'static)> {
+        self.entries.iter_mut().find(|e| e.plugin.name() == name).map(|e| e.plugin.as_mut())
+    }
+
+    /// Returns the names of the plugins, in the order they run.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::plugin::{EnginePlugin, PluginRegistry};
+    /// # struct Analytics;
+    /// # impl EnginePlugin for Analytics {
+    /// #     fn name(&self) -> &str { "analytics" }
+    /// # }
+    /// let mut plugins = PluginRegistry::new();
+    /// plugins.add(Analytics);
+    /// assert_eq!(plugins.names().collect::<Vec<_>>(), ["analytics"]);
+    /// ```
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().map(|e| e.plugin.name())
+    }
+
+    /// Returns the number of plugins.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::plugin::PluginRegistry;
+    /// assert_eq!(PluginRegistry::new().len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if no plugins are registered.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::plugin::PluginRegistry;
+    /// assert!(PluginRegistry::new().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Enables or disables the plugin called `name`. Returns false if there is none.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::plugin::{EnginePlugin, PluginRegistry};
+    /// # struct Analytics;
+    /// # impl EnginePlugin for Analytics {
+    /// #     fn name(&self) -> &str { "analytics" }
+    /// # }
+    /// let mut plugins = PluginRegistry::new();
+    /// plugins.add(Analytics);
+    /// assert!(plugins.set_enabled("analytics", false));
+    /// assert_eq!(plugins.is_enabled("analytics"), Some(false));
+    /// ```
+    pub fn set_enabled(&mut self, name: &str, enabled: bool) -> bool {
+        match self.entries.iter_mut().find(|e| e.plugin.name() == name) {
+            Some(entry) => {
+                entry.enabled = enabled;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns whether the plugin called `name` is enabled, or `None` if there is none.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::plugin::PluginRegistry;
+    /// assert_eq!(PluginRegistry::new().is_enabled("analytics"), None);
+    /// ```
+    pub fn is_enabled(&self, name: &str) -> Option<bool> {
+        self.entries.iter().find(|e| e.plugin.name() == name).map(|e| e.enabled)
+    }
+
+    pub(crate) fn init(&mut self, engine: &mut EngineContext) {
+        for entry in self.enabled() {
+            if !entry.initialized {
+                entry.initialized = true;
+                entry.plugin.init(engine);
+            }
+        }
+    }
+
+    pub(crate) fn on_event(&mut self, event: &GameEvent, engine: &mut EngineContext) {
+        for entry in self.enabled() {
+            entry.plugin.on_event(event, engine);
+        }
+    }
+
+    pub(crate) fn update(&mut self, engine: &mut EngineContext) {
+        for entry in self.enabled() {
+            entry.plugin.update(engine);
+        }
+    }
+
+    pub(crate) fn ui(&mut self, engine: &mut EngineContext) {
+        for entry in self.enabled() {
+            entry.plugin.ui(engine);
+        }
+    }
+
+    fn enabled(&mut self) -> impl Iterator<Item = &mut Entry> {
+        self.entries.iter_mut().filter(|e| e.enabled)
+    }
+}
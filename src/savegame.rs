@@ -0,0 +1,574 @@
+//! Save games for deckbuilder runs.
+//!
+//! Provides a [`SaveFile`] envelope (data version, timestamp and checksum around the game's own
+//! serialized data), [`SaveSlots`] for named save slots on disk with atomic writes and a backup
+//! of the previous save, and a [`Migrations`] registry that upgrades older saves step by step.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::savegame::{Migrations, SaveSlots};
+//!
+//! // Version 2 renamed the `hp` field to `health`.
+//! let migrations = Migrations::new(2).add(1, |data| Ok(data.replace("hp=", "health=")));
+//! let dir = std::env::temp_dir().join("deckbuilder_savegame_example");
+//! let slots = SaveSlots::new(&dir, migrations);
+//!
+//! slots.save("run", "health=72\nfloor=5").unwrap();
+//! let save = slots.load("run").unwrap();
+//! assert_eq!(save.version, 2);
+//! assert_eq!(save.data, "health=72\nfloor=5");
+//! # std::fs::remove_dir_all(&dir).ok();
+//! ```
+//!
+//! # Details
+//!
+//! - The payload is any text (JSON, RON, key/value lines, ...); the envelope doesn't parse it.
+//! - A save file is a `DBSAVE 1` line, `key = value` header lines (`version`, `timestamp`,
+//!   `checksum`), a `---` line and the payload. The checksum is FNV-1a over the payload.
+//! - Writes go to a temporary file that replaces the slot's file, whose previous contents are
+//!   kept as `<slot>.sav.bak`. Loading falls back to the backup if the save is missing or corrupt.
+//! - Between the two renames of a write the slot has no `<slot>.sav`; a crash there leaves the
+//!   previous save as the backup (and the new one as `<slot>.sav.tmp`), so loading still
+//!   returns the previous save and the slot is still listed. On Unix the directory is synced after the renames.
+//! - Migrations upgrade a save's data from one version to the next; a save newer than the current
+//!   version is refused.
+//! - See each struct and function's documentation for more.
+
+use std::collections::{BTreeSet, HashMap};
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+
+use crate::util::{sync_dir, unix_now};
+
+const MAGIC: &str = "DBSAVE 1";
+const SEPARATOR: &str = "---";
+const EXTENSION: &str = "sav";
+
+/// Errors from reading, writing or migrating saves.
+#[derive(Debug, Error)]
+pub enum SaveError {
+    /// A save file couldn't be read or written.
+    #[error("save file io: {0}")]
+    Io(#[from] io::Error),
+    /// Malformed save file header.
+    #[error("save file line {line}: {message}")]
+    Parse { line: usize, message: String },
+    /// The payload doesn't match the stored checksum.
+    #[error("save file checksum mismatch: expected {expected:016x}, found {found:016x}")]
+    Checksum { expected: u64, found: u64 },
+    /// The save was written by a newer version of the game.
+    #[error("save version {version} is newer than the supported version {current}")]
+    TooNew { version: u32, current: u32 },
+    /// No migration is registered from this version.
+    #[error("no migration from save version {0}")]
+    MissingMigration(u32),
+    /// A migration rejected the save's data.
+    #[error("migration from save version {from} failed: {message}")]
+    Migration { from: u32, message: String },
+    /// The slot name is empty or contains characters other than letters, digits, `-` and `_`.
+    #[error("invalid save slot name `{0}`")]
+    InvalidSlot(String),
+}
+
+/// A save's data with its version and timestamp.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaveFile {
+    /// Version of the data's structure, used to pick migrations.
+    pub version: u32,
+    /// When the save was made, in seconds since the Unix epoch.
+    pub timestamp: u64,
+    /// The game's serialized data.
+    pub data: String,
+}
+
+impl SaveFile {
+    /// Creates a save of `data` at `version`, timestamped now.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::savegame::SaveFile;
+    /// let save = SaveFile::new(3, "floor=5");
+    /// assert_eq!(save.version, 3);
+    /// assert!(save.timestamp > 0);
+    /// ```
+    pub fn new(version: u32, data: impl Into<String>) -> Self {
+        Self {
+            version,
+            timestamp: unix_now(),
+            data: data.into(),
+        }
+    }
+
+    /// Returns the FNV-1a checksum of the data.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::savegame::SaveFile;
+    /// assert_ne!(SaveFile::new(1, "a").checksum(), SaveFile::new(1, "b").checksum());
+    /// ```
+    pub fn checksum(&self) -> u64 {
+        checksum(self.data.as_bytes())
+    }
+
+    /// Encodes the save with its header, as written to disk.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::savegame::SaveFile;
+    /// let text = SaveFile::new(1, "floor=5").encode();
+    /// assert!(text.starts_with("DBSAVE 1\nversion = 1\n"));
+    /// assert!(text.ends_with("---\nfloor=5"));
+    /// ```
+    pub fn encode(&self) -> String {
+        format!(
+            "{MAGIC}\nversion = {}\ntimestamp = {}\nchecksum = {:016x}\n{SEPARATOR}\n{}",
+            self.version,
+            self.timestamp,
+            self.checksum(),
+            self.data
+        )
+    }
+
+    /// Parses an encoded save and verifies its checksum. Unknown header keys are ignored.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::savegame::{SaveError, SaveFile};
+    /// let save = SaveFile::new(2, "floor=5");
+    /// assert_eq!(SaveFile::parse(&save.encode()).unwrap(), save);
+    ///
+    /// let tampered = save.encode().replace("floor=5", "floor=50");
+    /// assert!(matches!(SaveFile::parse(&tampered), Err(SaveError::Checksum { .. })));
+    /// ```
+    pub fn parse(source: &str) -> Result<Self, SaveError> {
+        let parse_error = |line: usize, message: &str| SaveError::Parse {
+            line,
+            message: message.to_owned(),
+        };
+        let mut rest = source;
+        if take_line(&mut rest) != Some(MAGIC) {
+            return Err(parse_error(1, "not a save file"));
+        }
+        let (mut version, mut timestamp, mut expected) = (None, 0, None);
+        let mut line_no = 1;
+        loop {
+            line_no += 1;
+            let line = take_line(&mut rest).ok_or_else(|| parse_error(line_no, "missing `---` before the data"))?;
+            if line == SEPARATOR {
+                break;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| parse_error(line_no, "expected `key = value`"))?;
+            let value = value.trim();
+            match key.trim() {
+                "version" => {
+                    version = Some(value.parse().map_err(|_| parse_error(line_no, "invalid version"))?);
+                }
+                "timestamp" => {
+                    timestamp = value.parse().map_err(|_| parse_error(line_no, "invalid timestamp"))?;
+                }
+                "checksum" => {
+                    expected =
+                        Some(u64::from_str_radix(value, 16).map_err(|_| parse_error(line_no, "invalid checksum"))?);
+                }
+                _ => {}
+            }
+        }
+        let version = version.ok_or_else(|| parse_error(line_no, "missing `version`"))?;
+        let expected = expected.ok_or_else(|| parse_error(line_no, "missing `checksum`"))?;
+        let found = checksum(rest.as_bytes());
+        if found != expected {
+            return Err(SaveError::Checksum { expected, found });
+        }
+        Ok(Self {
+            version,
+            timestamp,
+            data: rest.to_owned(),
+        })
+    }
+}
+
+type Migration = Box<dyn Fn(String) -> Result<String, String> + Send + Sync>;
+
+/// Upgrades saves from older data versions to the current one.
+pub struct Migrations {
+    current: u32,
+    steps: HashMap<u32, Migration>,
+}
+
+impl Migrations {
+    /// Creates a registry for data at version `current`, with no migrations.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::savegame::Migrations;
+    /// assert_eq!(Migrations::new(4).current_version(), 4);
+    /// ```
+    pub fn new(current: u32) -> Self {
+        Self {
+            current,
+            steps: HashMap::new(),
+        }
+    }
+
+    /// Registers the migration that turns data of version `from` into version `from + 1`.
+    /// The migration returns an error message if it can't upgrade the data.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::savegame::Migrations;
+    /// let migrations = Migrations::new(3)
+    ///     .add(1, |data| Ok(data.replace("hp=", "health=")))
+    ///     .add(2, |data| Ok(format!("{data}\nascension=0")));
+    /// assert!(migrations.has_migration(1) && migrations.has_migration(2));
+    /// ```
+    pub fn add(mut self, from: u32, migrate: impl Fn(String) -> Result<String, String> + Send + Sync + 'static) -> Self {
+        self.steps.insert(from, Box::new(migrate));
+        self
+    }
+
+    /// Returns the version saves are upgraded to.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::savegame::Migrations;
+    /// assert_eq!(Migrations::new(2).current_version(), 2);
+    /// ```
+    pub fn current_version(&self) -> u32 {
+        self.current
+    }
+
+    /// Returns `true` if a migration from version `from` is registered.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::savegame::Migrations;
+    /// assert!(!Migrations::new(2).has_migration(1));
+    /// ```
+    pub fn has_migration(&self, from: u32) -> bool {
+        self.steps.contains_key(&from)
+    }
+
+    /// Upgrades `save` to the current version, applying each migration in turn.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::savegame::{Migrations, SaveError, SaveFile};
+    /// let migrations = Migrations::new(3)
+    ///     .add(1, |data| Ok(data.replace("hp=", "health=")))
+    ///     .add(2, |data| Ok(format!("{data}\nascension=0")));
+    ///
+    /// let save = migrations.migrate(SaveFile::new(1, "hp=50")).unwrap();
+    /// assert_eq!((save.version, save.data.as_str()), (3, "health=50\nascension=0"));
+    ///
+    /// let newer = migrations.migrate(SaveFile::new(4, ""));
+    /// assert!(matches!(newer, Err(SaveError::TooNew { version: 4, current: 3 })));
+    /// ```
+    pub fn migrate(&self, mut save: SaveFile) -> Result<SaveFile, SaveError> {
+        if save.version > self.current {
+            return Err(SaveError::TooNew {
+                version: save.version,
+                current: self.current,
+            });
+        }
+        while save.version < self.current {
+            let step = self
+                .steps
+                .get(&save.version)
+                .ok_or(SaveError::MissingMigration(save.version))?;
+            save.data = step(save.data).map_err(|message| SaveError::Migration {
+                from: save.version,
+                message,
+            })?;
+            save.version += 1;
+        }
+        Ok(save)
+    }
+}
+
+/// A save slot found by [`SaveSlots::list`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlotInfo {
+    /// Name of the slot.
+    pub name: String,
+    /// Data version of the save, before migration.
+    pub version: u32,
+    /// When the save was made, in seconds since the Unix epoch.
+    pub timestamp: u64,
+}
+
+/// Named save slots stored as `<slot>.sav` files in a directory.
+pub struct SaveSlots {
+    dir: PathBuf,
+    migrations: Migrations,
+}
+
+impl SaveSlots {
+    /// Creates save slots in `dir`; the directory is created on the first save.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::savegame::{Migrations, SaveSlots};
+    /// let slots = SaveSlots::new("saves", Migrations::new(1));
+    /// assert_eq!(slots.dir(), std::path::Path::new("saves"));
+    /// ```
+    pub fn new(dir: impl Into<PathBuf>, migrations: Migrations) -> Self {
+        Self {
+            dir: dir.into(),
+            migrations,
+        }
+    }
+
+    /// Returns the directory the saves are stored in.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::savegame::{Migrations, SaveSlots};
+    /// let slots = SaveSlots::new("saves", Migrations::new(1));
+    /// assert!(slots.dir().ends_with("saves"));
+    /// ```
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Returns the migrations applied when loading.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::savegame::{Migrations, SaveSlots};
+    /// let slots = SaveSlots::new("saves", Migrations::new(5));
+    /// assert_eq!(slots.migrations().current_version(), 5);
+    /// ```
+    pub fn migrations(&self) -> &Migrations {
+        &self.migrations
+    }
+
+    /// Returns the file path of `slot`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::savegame::{Migrations, SaveSlots};
+    /// let slots = SaveSlots::new("saves", Migrations::new(1));
+    /// assert_eq!(slots.path("slot_1").unwrap(), std::path::Path::new("saves/slot_1.sav"));
+    /// assert!(slots.path("../escape").is_err());
+    /// ```
+    pub fn path(&self, slot: &str) -> Result<PathBuf, SaveError> {
+        let valid = !slot.is_empty() && slot.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !valid {
+            return Err(SaveError::InvalidSlot(slot.to_owned()));
+        }
+        Ok(self.dir.join(format!("{slot}.{EXTENSION}")))
+    }
+
+    /// Saves `data` to `slot` at the current version and returns the written save.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::savegame::{Migrations, SaveSlots};
+    /// let dir = std::env::temp_dir().join("deckbuilder_savegame_save");
+    /// let slots = SaveSlots::new(&dir, Migrations::new(2));
+    /// let save = slots.save("autosave", "floor=3").unwrap();
+    /// assert_eq!(save.version, 2);
+    /// assert!(slots.exists("autosave"));
+    /// # std::fs::remove_dir_all(&dir).ok();
+    /// ```
+    pub fn save(&self, slot: &str, data: impl Into<String>) -> Result<SaveFile, SaveError> {
+        let save = SaveFile::new(self.migrations.current, data);
+        self.write(slot, &save)?;
+        Ok(save)
+    }
+
+    /// Writes `save` to `slot` atomically: the save goes to a temporary file first, the slot's
+    /// previous save becomes its backup, then the temporary file takes its place and the save
+    /// directory is synced.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::savegame::{Migrations, SaveFile, SaveSlots};
+    /// let dir = std::env::temp_dir().join("deckbuilder_savegame_write");
+    /// let slots = SaveSlots::new(&dir, Migrations::new(1));
+    /// slots.write("run", &SaveFile::new(1, "floor=1")).unwrap();
+    /// slots.write("run", &SaveFile::new(1, "floor=2")).unwrap();
+    /// assert_eq!(slots.load_backup("run").unwrap().data, "floor=1");
+    /// # std::fs::remove_dir_all(&dir).ok();
+    /// ```
+    pub fn write(&self, slot: &str, save: &SaveFile) -> Result<(), SaveError> {
+        let path = self.path(slot)?;
+        fs::create_dir_all(&self.dir)?;
+        let temp = path.with_extension(format!("{EXTENSION}.tmp"));
+        let mut file = File::create(&temp)?;
+        file.write_all(save.encode().as_bytes())?;
+        file.sync_all()?;
+        drop(file);
+        if path.exists() {
+            fs::rename(&path, backup_path(&path))?;
+        }
+        fs::rename(&temp, &path)?;
+        sync_dir(&self.dir)?;
+        Ok(())
+    }
+
+    /// Loads `slot` and migrates it to the current version. If the save is missing or corrupt,
+    /// its backup is loaded instead; if both fail, the save's error is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::savegame::{Migrations, SaveSlots};
+    /// let dir = std::env::temp_dir().join("deckbuilder_savegame_load");
+    /// let slots = SaveSlots::new(&dir, Migrations::new(1));
+    /// slots.save("run", "floor=1").unwrap();
+    /// slots.save("run", "floor=2").unwrap();
+    ///
+    /// // A crash corrupted the latest save; the previous one is loaded.
+    /// std::fs::write(slots.path("run").unwrap(), "DBSAVE 1\nversion = 1").unwrap();
+    /// assert_eq!(slots.load("run").unwrap().data, "floor=1");
+    /// # std::fs::remove_dir_all(&dir).ok();
+    /// ```
+    pub fn load(&self, slot: &str) -> Result<SaveFile, SaveError> {
+        let save = read_or_backup(&self.path(slot)?)?;
+        self.migrations.migrate(save)
+    }
+
+    /// Loads the backup of `slot` (its previous save) and migrates it to the current version.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::savegame::{Migrations, SaveSlots};
+    /// let dir = std::env::temp_dir().join("deckbuilder_savegame_backup");
+    /// let slots = SaveSlots::new(&dir, Migrations::new(1));
+    /// slots.save("run", "floor=1").unwrap();
+    /// assert!(slots.load_backup("run").is_err());
+    /// # std::fs::remove_dir_all(&dir).ok();
+    /// ```
+    pub fn load_backup(&self, slot: &str) -> Result<SaveFile, SaveError> {
+        let save = read_save(&backup_path(&self.path(slot)?))?;
+        self.migrations.migrate(save)
+    }
+
+    /// Returns `true` if `slot` has a save or a backup, i.e. something [`SaveSlots::load`] can
+    /// try.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::savegame::{Migrations, SaveSlots};
+    /// let dir = std::env::temp_dir().join("deckbuilder_savegame_exists");
+    /// let slots = SaveSlots::new(&dir, Migrations::new(1));
+    /// assert!(!slots.exists("no_such_slot"));
+    ///
+    /// // A crash between the renames of a write left only the backup
+    /// slots.save("run", "floor=1").unwrap();
+    /// slots.save("run", "floor=2").unwrap();
+    /// std::fs::remove_file(slots.path("run").unwrap()).unwrap();
+    /// assert!(slots.exists("run"));
+    /// # std::fs::remove_dir_all(&dir).ok();
+    /// ```
+    pub fn exists(&self, slot: &str) -> bool {
+        self.path(slot).is_ok_and(|path| path.is_file() || backup_path(&path).is_file())
+    }
+
+    /// Deletes the save and the backup of `slot`. Deleting an empty slot is not an error.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::savegame::{Migrations, SaveSlots};
+    /// let dir = std::env::temp_dir().join("deckbuilder_savegame_delete");
+    /// let slots = SaveSlots::new(&dir, Migrations::new(1));
+    /// slots.save("run", "floor=1").unwrap();
+    /// slots.delete("run").unwrap();
+    /// assert!(!slots.exists("run"));
+    /// # std::fs::remove_dir_all(&dir).ok();
+    /// ```
+    pub fn delete(&self, slot: &str) -> Result<(), SaveError> {
+        let path = self.path(slot)?;
+        for path in [backup_path(&path), path] {
+            match fs::remove_file(&path) {
+                Err(err) if err.kind() != io::ErrorKind::NotFound => return Err(err.into()),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Lists the slots [`SaveSlots::load`] can read, newest first. A slot whose save is missing
+    /// or corrupt is listed with the details of its backup.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::savegame::{Migrations, SaveSlots};
+    /// let dir = std::env::temp_dir().join("deckbuilder_savegame_list");
+    /// let slots = SaveSlots::new(&dir, Migrations::new(1));
+    /// slots.save("slot_1", "floor=1").unwrap();
+    /// slots.save("slot_2", "floor=9").unwrap();
+    /// slots.save("slot_2", "floor=10").unwrap();
+    /// std::fs::write(slots.path("slot_2").unwrap(), "corrupt").unwrap();
+    /// let mut names: Vec<String> = slots.list().into_iter().map(|info| info.name).collect();
+    /// names.sort();
+    /// assert_eq!(names, ["slot_1", "slot_2"]);
+    /// # std::fs::remove_dir_all(&dir).ok();
+    /// ```
+    pub fn list(&self) -> Vec<SlotInfo> {
+        let Ok(entries) = fs::read_dir(&self.dir) else {
+            return Vec::new();
+        };
+        let suffix = format!(".{EXTENSION}");
+        let names: BTreeSet<String> = entries
+            .flatten()
+            .filter_map(|entry| {
+                let file_name = entry.file_name().into_string().ok()?;
+                let slot = file_name.strip_suffix(".bak").unwrap_or(&file_name).strip_suffix(&suffix)?;
+                Some(slot.to_owned())
+            })
+            .collect();
+        let mut slots: Vec<SlotInfo> = names
+            .into_iter()
+            .filter_map(|name| {
+                let path = self.path(&name).ok()?;
+                let save = read_or_backup(&path)
+                    .inspect_err(|err| tracing::warn!(%err, "skipping unreadable save `{}`", path.display()))
+                    .ok()?;
+                Some(SlotInfo {
+                    name,
+                    version: save.version,
+                    timestamp: save.timestamp,
+                })
+            })
+            .collect();
+        slots.sort_by(|a, b| b.timestamp.cmp(&a.timestamp).then_with(|| a.name.cmp(&b.name)));
+        slots
+    }
+}
+
+/// Splits the first line off `rest`; `None` once nothing is left.
+fn take_line<'a>(rest: &mut &'a str) -> Option<&'a str> {
+    if rest.is_empty() {
+        return None;
+    }
+    let (line, tail) = rest.split_once('\n').unwrap_or((rest, ""));
+    *rest = tail;
+    Some(line.trim_end_matches('\r'))
+}
+
+fn read_save(path: &Path) -> Result<SaveFile, SaveError> {
+    SaveFile::parse(&fs::read_to_string(path)?)
+}
+
+/// Reads the save at `path`, or its backup if the save is missing or corrupt. If both fail,
+/// the save's error is returned.
+fn read_or_backup(path: &Path) -> Result<SaveFile, SaveError> {
+    read_save(path).or_else(|err| read_save(&backup_path(path)).map_err(|_| err))
+}
+
+fn backup_path(path: &Path) -> PathBuf {
+    path.with_extension(format!("{EXTENSION}.bak"))
+}
+
+fn checksum(bytes: &[u8]) -> u64 {
+    bytes
+        .iter()
+        .fold(0xcbf2_9ce4_8422_2325, |hash, &byte| (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3))
+}
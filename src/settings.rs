@@ -0,0 +1,644 @@
+//! Game settings for deckbuilder games.
+//!
+//! Provides [`GameSettings`] (audio volumes, window mode, UI scale, language and keybindings),
+//! helpers that apply them to the [`AudioManager`] and the egui context, and a [`Settings`]
+//! service that loads them from the platform's config directory on start, saves them on every
+//! change and notifies subscribers of what changed.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::settings::{GameSettings, WindowMode};
+//! use egui::Context;
+//!
+//! let mut settings = GameSettings::default();
+//! settings.music_volume = 0.5;
+//! settings.window_mode = WindowMode::Fullscreen;
+//!
+//! fn apply(ctx: &Context, settings: &GameSettings) {
+//!     settings.apply_to_context(ctx);
+//! }
+//! ```
+//!
+//! Persisted settings:
+//!
+//! ```rust
+//! use deckbuilder_eng::settings::{GameSettings, Settings};
+//! use egui::Context;
+//!
+//! fn start(ctx: &Context) -> Settings {
+//!     // Loads `<config dir>/my_deckbuilder/settings.cfg`, or the defaults on first start.
+//!     let mut settings = Settings::open_for_app("my_deckbuilder", GameSettings::default())
+//!         .unwrap_or_else(|_| Settings::new(GameSettings::default()));
+//!     settings.get().apply_to_context(ctx);
+//!     let ctx = ctx.clone();
+//!     settings.subscribe(move |settings, change| settings.apply_change_to_context(&ctx, change));
+//!     settings
+//! }
+//! ```
+//!
+//! # Details
+//!
+//! - Volumes are in `0.0..=1.0`; the effective music/SFX volume is multiplied by `master_volume`.
+//! - Invalid lines of a settings file (a UI scale outside [`UI_SCALE_RANGE`], a non-finite
+//!   volume, a language missing from `available_languages`, an unknown binding, ...) are
+//!   logged and keep their defaults; the file is rewritten on the next change.
+//!   [`GameSettings::validate_config`] reports them instead.
+//! - Applying audio settings needs an [`AudioManager`]; window and UI scale settings need
+//!   the egui `Context`. [`GameSettings::apply_audio`] needs the `kira` feature.
+//! - Settings files are `key = value` lines; keybindings are `bind.<action> = key:E, pad:start`.
+//!   Unknown keys are ignored and missing ones keep their defaults, so older files still load.
+//! - [`Settings`] writes through a temporary file, so a crash never leaves a half-written file.
+//! - See each struct and function's documentation for more.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use egui::{Context, Key, ViewportCommand};
+use thiserror::Error;
+
+#[cfg(feature = "kira")]
+use crate::audio::AudioManager;
+use crate::i18n::{i18n, update_i18n};
+use crate::input::{Action, Binding, GamepadButton, InputMap};
+use crate::ui_theme::set_ui_scale;
+
+/// Smallest and largest UI scale a settings file may hold, as clamped by [`set_ui_scale`].
+pub const UI_SCALE_RANGE: std::ops::RangeInclusive<f32> = 0.5..=3.0;
+
+/// Errors from reading or writing a settings file.
+#[derive(Debug, Error)]
+pub enum SettingsError {
+    /// The settings file couldn't be read or written.
+    #[error("failed to access settings file: {0}")]
+    Io(#[from] io::Error),
+    /// Malformed settings line.
+    #[error("settings line {line}: {message}")]
+    Parse { line: usize, message: String },
+}
+
+/// How the game window is shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowMode {
+    Windowed,
+    Fullscreen,
+    Borderless,
+}
+
+/// Player-facing game settings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameSettings {
+    pub master_volume: f32,
+    pub music_volume: f32,
+    pub sfx_volume: f32,
+    pub window_mode: WindowMode,
+    pub ui_scale: f32,
+    pub language: String,
+    pub available_languages: Vec<String>,
+    pub keybindings: InputMap,
+}
+
+impl Default for GameSettings {
+    fn default() -> Self {
+        Self {
+            master_volume: 1.0,
+            music_volume: 0.7,
+            sfx_volume: 0.8,
+            window_mode: WindowMode::Windowed,
+            ui_scale: 1.0,
+            language: "en".to_owned(),
+            available_languages: vec!["en".to_owned(), "tr".to_owned()],
+            keybindings: InputMap::default(),
+        }
+    }
+}
+
+impl GameSettings {
+    /// Effective music volume (`master_volume * music_volume`).
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::settings::GameSettings;
+    /// let settings = GameSettings { master_volume: 0.5, music_volume: 0.5, ..Default::default() };
+    /// assert_eq!(settings.effective_music_volume(), 0.25);
+    /// ```
+    pub fn effective_music_volume(&self) -> f32 {
+        (self.master_volume * self.music_volume).clamp(0.0, 1.0)
+    }
+
+    /// Effective SFX volume (`master_volume * sfx_volume`).
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::settings::GameSettings;
+    /// let settings = GameSettings::default();
+    /// let volume = settings.effective_sfx_volume();
+    /// ```
+    pub fn effective_sfx_volume(&self) -> f32 {
+        (self.master_volume * self.sfx_volume).clamp(0.0, 1.0)
+    }
+
+    /// Sets the music and SFX track volumes of `audio`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use deckbuilder_eng::audio::AudioManager;
+    /// # use deckbuilder_eng::settings::GameSettings;
+    /// let mut audio = AudioManager::new().unwrap();
+    /// GameSettings::default().apply_audio(&mut audio).unwrap();
+    /// ```
+    #[cfg(feature = "kira")]
+    pub fn apply_audio(&self, audio: &mut AudioManager) -> Result<(), crate::Error> {
+        audio.set_music_volume(self.effective_music_volume())?;
+        audio.set_sfx_volume(self.effective_sfx_volume())?;
+        Ok(())
+    }
+
+    /// Applies the window mode, UI scale and language to the egui context. The language is set
+    /// on the string tables installed with [`crate::i18n::install_i18n`], if any.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::settings::GameSettings;
+    /// let ctx = egui::Context::default();
+    /// GameSettings::default().apply_to_context(&ctx);
+    /// ```
+    pub fn apply_to_context(&self, ctx: &Context) {
+        let all = SettingsChange {
+            video: true,
+            ui_scale: true,
+            language: true,
+            ..Default::default()
+        };
+        self.apply_change_to_context(ctx, all);
+    }
+
+    /// Applies only the window mode, UI scale and language parts flagged in `change`, e.g. from
+    /// a [`Settings::subscribe`] listener. The string tables are only touched if their language
+    /// differs, since switching it re-renders baked card textures.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::i18n::{i18n_revision, install_i18n, I18n};
+    /// # use deckbuilder_eng::settings::{GameSettings, SettingsChange};
+    /// let ctx = egui::Context::default();
+    /// install_i18n(&ctx, I18n::new("en"));
+    /// let revision = i18n_revision(&ctx);
+    ///
+    /// let old = GameSettings::default();
+    /// let new = GameSettings { ui_scale: 1.5, ..old.clone() };
+    /// new.apply_change_to_context(&ctx, SettingsChange::between(&old, &new));
+    /// assert_eq!(i18n_revision(&ctx), revision);
+    /// ```
+    pub fn apply_change_to_context(&self, ctx: &Context, change: SettingsChange) {
+        if change.video {
+            let (fullscreen, decorations) = match self.window_mode {
+                WindowMode::Windowed => (false, true),
+                WindowMode::Fullscreen => (true, true),
+                WindowMode::Borderless => (false, false),
+            };
+            ctx.send_viewport_cmd(ViewportCommand::Fullscreen(fullscreen));
+            ctx.send_viewport_cmd(ViewportCommand::Decorations(decorations));
+        }
+        if change.ui_scale {
+            set_ui_scale(ctx, self.ui_scale);
+        }
+        if change.language && i18n(ctx).is_some_and(|i18n| i18n.language() != self.language) {
+            update_i18n(ctx, |i18n| i18n.set_language(self.language.as_str()));
+        }
+    }
+
+    /// Encodes the settings as the lines of a settings file. `available_languages` is not
+    /// saved; it comes from the game.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::settings::GameSettings;
+    /// let config = GameSettings::default().to_config();
+    /// assert!(config.contains("language = en\n"));
+    /// assert!(config.contains("bind.end_turn = key:E, pad:start\n"));
+    /// ```
+    pub fn to_config(&self) -> String {
+        let mut config = format!(
+            "master_volume = {}\nmusic_volume = {}\nsfx_volume = {}\nwindow_mode = {}\nui_scale = {}\nlanguage = {}\n",
+            self.master_volume,
+            self.music_volume,
+            self.sfx_volume,
+            window_mode_name(self.window_mode),
+            self.ui_scale,
+            self.language,
+        );
+        for action in Action::ALL {
+            let bindings: Vec<String> = self.keybindings.bindings_for(action).into_iter().map(binding_name).collect();
+            config.push_str(&format!("bind.{} = {}\n", action.name(), bindings.join(", ")));
+        }
+        config
+    }
+
+    /// Returns these settings with the values of a settings file applied on top. Keys missing
+    /// from `source` keep their current values; unknown keys and `#` comments are ignored.
+    /// Invalid lines are logged with `tracing::warn!` and keep their current values too, so a
+    /// file from an older or newer game version still loads; see
+    /// [`GameSettings::validate_config`] to reject them instead.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::input::Action;
+    /// # use deckbuilder_eng::settings::{GameSettings, WindowMode};
+    /// # use egui::Key;
+    /// let settings = GameSettings::default()
+    ///     .with_config("window_mode = fullscreen\nbind.end_turn = key:Space\nui_scale = huge");
+    /// assert_eq!(settings.window_mode, WindowMode::Fullscreen);
+    /// assert_eq!(settings.keybindings.key_for(Action::EndTurn), Some(Key::Space));
+    /// assert_eq!(settings.music_volume, GameSettings::default().music_volume);
+    /// assert_eq!(settings.ui_scale, GameSettings::default().ui_scale);
+    /// ```
+    pub fn with_config(&self, source: &str) -> GameSettings {
+        self.apply_config(source, |err| tracing::warn!(%err, "ignoring invalid settings line"))
+    }
+
+    /// Checks a settings file strictly, returning the first line
+    /// [`GameSettings::with_config`] would ignore: a malformed line, a volume or UI scale
+    /// outside its range, a language missing from `available_languages` or an unknown
+    /// window mode or binding.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::settings::GameSettings;
+    /// let defaults = GameSettings::default();
+    /// assert!(defaults.validate_config("window_mode = fullscreen\nui_scale = 1.5").is_ok());
+    /// assert!(defaults.validate_config("ui_scale = huge").is_err());
+    /// assert!(defaults.validate_config("ui_scale = NaN").is_err());
+    /// assert!(defaults.validate_config("ui_scale = 0").is_err());
+    /// assert!(defaults.validate_config("language = xx").is_err());
+    /// assert!(defaults.validate_config("bind.end_turn = key:Nope").is_err());
+    /// ```
+    pub fn validate_config(&self, source: &str) -> Result<(), SettingsError> {
+        let mut first = None;
+        self.apply_config(source, |err| {
+            first.get_or_insert(err);
+        });
+        first.map_or(Ok(()), Err)
+    }
+
+    fn apply_config(&self, source: &str, mut invalid: impl FnMut(SettingsError)) -> GameSettings {
+        let mut settings = self.clone();
+        for (i, line) in source.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Err(message) = settings.apply_config_line(line) {
+                invalid(SettingsError::Parse { line: i + 1, message });
+            }
+        }
+        settings
+    }
+
+    fn apply_config_line(&mut self, line: &str) -> Result<(), String> {
+        let (key, value) = line.split_once('=').ok_or("expected `key = value`")?;
+        let (key, value) = (key.trim(), value.trim());
+        let volume = || {
+            value
+                .parse::<f32>()
+                .ok()
+                .filter(|v| v.is_finite())
+                .map(|v| v.clamp(0.0, 1.0))
+                .ok_or_else(|| format!("invalid volume `{value}`"))
+        };
+        match key {
+            "master_volume" => self.master_volume = volume()?,
+            "music_volume" => self.music_volume = volume()?,
+            "sfx_volume" => self.sfx_volume = volume()?,
+            "window_mode" => {
+                self.window_mode = window_mode_from_name(value).ok_or_else(|| format!("unknown window mode `{value}`"))?;
+            }
+            "ui_scale" => {
+                self.ui_scale = value
+                    .parse::<f32>()
+                    .ok()
+                    .filter(|scale| UI_SCALE_RANGE.contains(scale))
+                    .ok_or_else(|| format!("invalid UI scale `{value}`"))?;
+            }
+            "language" => {
+                if !self.available_languages.iter().any(|language| language == value) {
+                    return Err(format!("unavailable language `{value}`"));
+                }
+                self.language = value.to_owned();
+            }
+            _ => {
+                let Some(action) = key.strip_prefix("bind.").and_then(Action::from_name) else {
+                    return Ok(());
+                };
+                let bindings = value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|binding| !binding.is_empty())
+                    .map(|binding| binding_from_name(binding).ok_or_else(|| format!("unknown binding `{binding}`")))
+                    .collect::<Result<Vec<_>, _>>()?;
+                self.keybindings.unbind(action);
+                for binding in bindings {
+                    self.keybindings.bind(action, binding);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn window_mode_name(mode: WindowMode) -> &'static str {
+    match mode {
+        WindowMode::Windowed => "windowed",
+        WindowMode::Fullscreen => "fullscreen",
+        WindowMode::Borderless => "borderless",
+    }
+}
+
+fn window_mode_from_name(name: &str) -> Option<WindowMode> {
+    [WindowMode::Windowed, WindowMode::Fullscreen, WindowMode::Borderless]
+        .into_iter()
+        .find(|mode| window_mode_name(*mode) == name)
+}
+
+fn binding_name(binding: Binding) -> String {
+    match binding {
+        Binding::Key(key) => format!("key:{}", key.name()),
+        Binding::Gamepad(button) => format!("pad:{}", button.name()),
+    }
+}
+
+fn binding_from_name(name: &str) -> Option<Binding> {
+    match name.split_once(':')? {
+        ("key", key) => Key::from_name(key).map(Binding::Key),
+        ("pad", button) => GamepadButton::from_name(button).map(Binding::Gamepad),
+        _ => None,
+    }
+}
+
+/// Which groups of settings differ between two [`GameSettings`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SettingsChange {
+    /// A volume changed.
+    pub audio: bool,
+    /// The window mode changed.
+    pub video: bool,
+    /// The UI scale changed.
+    pub ui_scale: bool,
+    /// The language changed.
+    pub language: bool,
+    /// A keybinding changed.
+    pub keybindings: bool,
+}
+
+impl SettingsChange {
+    /// Compares `old` and `new`.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::settings::{GameSettings, SettingsChange};
+    /// let old = GameSettings::default();
+    /// let new = GameSettings { sfx_volume: 0.2, ..old.clone() };
+    /// let change = SettingsChange::between(&old, &new);
+    /// assert!(change.audio && !change.language);
+    /// ```
+    pub fn between(old: &GameSettings, new: &GameSettings) -> Self {
+        Self {
+            audio: old.master_volume != new.master_volume
+                || old.music_volume != new.music_volume
+                || old.sfx_volume != new.sfx_volume,
+            video: old.window_mode != new.window_mode,
+            ui_scale: old.ui_scale != new.ui_scale,
+            language: old.language != new.language,
+            keybindings: old.keybindings != new.keybindings,
+        }
+    }
+
+    /// Returns `true` if anything changed.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::settings::SettingsChange;
+    /// assert!(!SettingsChange::default().any());
+    /// ```
+    pub fn any(self) -> bool {
+        self.audio || self.video || self.ui_scale || self.language || self.keybindings
+    }
+}
+
+/// Returns the per-user config directory for `app`: `%APPDATA%\<app>` on Windows,
+/// `~/Library/Application Support/<app>` on macOS and `$XDG_CONFIG_HOME/<app>` (default
+/// `~/.config/<app>`) elsewhere. `None` if the environment doesn't say where that is.
+///
+/// # Example
+/// ```
+/// # use deckbuilder_eng::settings::config_dir;
+/// if let Some(dir) = config_dir("my_deckbuilder") {
+///     assert!(dir.ends_with("my_deckbuilder"));
+/// }
+/// ```
+pub fn config_dir(app: &str) -> Option<PathBuf> {
+    let var = |name: &str| std::env::var_os(name).filter(|value| !value.is_empty()).map(PathBuf::from);
+    let base = if cfg!(target_os = "windows") {
+        var("APPDATA")
+    } else if cfg!(target_os = "macos") {
+        var("HOME").map(|home| home.join("Library/Application Support"))
+    } else {
+        var("XDG_CONFIG_HOME").or_else(|| var("HOME").map(|home| home.join(".config")))
+    };
+    base.map(|base| base.join(app))
+}
+
+type Listener = Box<dyn FnMut(&GameSettings, SettingsChange)>;
+
+/// The game's current settings, saved to a file whenever they change.
+pub struct Settings {
+    current: GameSettings,
+    path: Option<PathBuf>,
+    listeners: Vec<Listener>,
+}
+
+impl Settings {
+    /// Creates in-memory settings that are never saved.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::settings::{GameSettings, Settings};
+    /// let settings = Settings::new(GameSettings::default());
+    /// assert!(settings.path().is_none());
+    /// ```
+    pub fn new(settings: GameSettings) -> Self {
+        Self {
+            current: settings,
+            path: None,
+            listeners: Vec::new(),
+        }
+    }
+
+    /// Loads the settings file at `path` on top of `defaults`; a missing file gives the defaults
+    /// and invalid lines keep theirs (see [`GameSettings::with_config`]). Changes are saved to
+    /// `path`, which repairs a file with invalid lines.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::settings::{GameSettings, Settings};
+    /// let path = std::env::temp_dir().join("deckbuilder_settings_open.cfg");
+    /// # std::fs::remove_file(&path).ok();
+    /// let mut settings = Settings::open(&path, GameSettings::default()).unwrap();
+    /// settings.update(|s| s.language = "tr".to_owned()).unwrap();
+    ///
+    /// let reopened = Settings::open(&path, GameSettings::default()).unwrap();
+    /// assert_eq!(reopened.get().language, "tr");
+    ///
+    /// std::fs::write(&path, "language = xx\nui_scale = 1.5").unwrap();
+    /// let repaired = Settings::open(&path, GameSettings::default()).unwrap();
+    /// assert_eq!(repaired.get().language, "en");
+    /// assert_eq!(repaired.path(), Some(path.as_path()));
+    /// # std::fs::remove_file(&path).ok();
+    /// ```
+    pub fn open(path: impl Into<PathBuf>, defaults: GameSettings) -> Result<Self, SettingsError> {
+        let path = path.into();
+        let current = match fs::read_to_string(&path) {
+            Ok(source) => defaults.with_config(&source),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => defaults,
+            Err(err) => return Err(err.into()),
+        };
+        Ok(Self {
+            current,
+            path: Some(path),
+            listeners: Vec::new(),
+        })
+    }
+
+    /// Opens `settings.cfg` in the [`config_dir`] of `app`, or in-memory settings if there is
+    /// no config directory (e.g. in the browser).
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::settings::{GameSettings, Settings};
+    /// # fn demo() -> Result<(), deckbuilder_eng::settings::SettingsError> {
+    /// let settings = Settings::open_for_app("my_deckbuilder", GameSettings::default())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn open_for_app(app: &str, defaults: GameSettings) -> Result<Self, SettingsError> {
+        match config_dir(app) {
+            Some(dir) => Self::open(dir.join("settings.cfg"), defaults),
+            None => Ok(Self::new(defaults)),
+        }
+    }
+
+    /// Returns the file the settings are saved to.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::settings::{GameSettings, Settings};
+    /// let settings = Settings::open("settings.cfg", GameSettings::default()).unwrap();
+    /// assert_eq!(settings.path(), Some(std::path::Path::new("settings.cfg")));
+    /// ```
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Returns the current settings.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::settings::{GameSettings, Settings};
+    /// let settings = Settings::new(GameSettings::default());
+    /// assert_eq!(settings.get().ui_scale, 1.0);
+    /// ```
+    pub fn get(&self) -> &GameSettings {
+        &self.current
+    }
+
+    /// Calls `listener` with the new settings and what changed after every change.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::cell::Cell;
+    /// # use std::rc::Rc;
+    /// # use deckbuilder_eng::settings::{GameSettings, Settings};
+    /// let mut settings = Settings::new(GameSettings::default());
+    /// let audio_changed = Rc::new(Cell::new(false));
+    /// let flag = Rc::clone(&audio_changed);
+    /// settings.subscribe(move |_, change| flag.set(change.audio));
+    ///
+    /// settings.update(|s| s.master_volume = 0.5).unwrap();
+    /// assert!(audio_changed.get());
+    /// ```
+    pub fn subscribe(&mut self, listener: impl FnMut(&GameSettings, SettingsChange) + 'static) {
+        self.listeners.push(Box::new(listener));
+    }
+
+    /// Replaces the settings, e.g. with the draft applied in
+    /// [`ui_settings_panel`](crate::ui_settings::ui_settings_panel). If anything changed,
+    /// the file is saved and then subscribers are notified; the returned change says what changed,
+    /// e.g. to reapply [`GameSettings::apply_audio`]. If saving fails, the settings are left as
+    /// they were and no one is notified.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::settings::{GameSettings, Settings};
+    /// let mut settings = Settings::new(GameSettings::default());
+    /// let mut draft = settings.get().clone();
+    /// draft.ui_scale = 1.25;
+    /// let change = settings.set(draft).unwrap();
+    /// assert!(change.ui_scale && !change.audio);
+    /// assert!(!settings.set(settings.get().clone()).unwrap().any());
+    /// ```
+    pub fn set(&mut self, settings: GameSettings) -> Result<SettingsChange, SettingsError> {
+        let change = SettingsChange::between(&self.current, &settings);
+        if !change.any() {
+            return Ok(change);
+        }
+        let old = std::mem::replace(&mut self.current, settings);
+        if let Err(err) = self.save() {
+            self.current = old;
+            return Err(err);
+        }
+        for listener in &mut self.listeners {
+            listener(&self.current, change);
+        }
+        Ok(change)
+    }
+
+    /// Changes the settings in place; see [`Settings::set`].
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::settings::{GameSettings, Settings, WindowMode};
+    /// let mut settings = Settings::new(GameSettings::default());
+    /// let change = settings.update(|s| s.window_mode = WindowMode::Borderless).unwrap();
+    /// assert!(change.video);
+    /// ```
+    pub fn update(&mut self, edit: impl FnOnce(&mut GameSettings)) -> Result<SettingsChange, SettingsError> {
+        let mut settings = self.current.clone();
+        edit(&mut settings);
+        self.set(settings)
+    }
+
+    /// Writes the settings to their file, creating its directory if needed. Does nothing for
+    /// in-memory settings.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::settings::{GameSettings, Settings};
+    /// let path = std::env::temp_dir().join("deckbuilder_settings_save").join("settings.cfg");
+    /// Settings::open(&path, GameSettings::default()).unwrap().save().unwrap();
+    /// assert!(path.exists());
+    /// # std::fs::remove_dir_all(path.parent().unwrap()).ok();
+    /// ```
+    pub fn save(&self) -> Result<(), SettingsError> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)?;
+        }
+        let temp = path.with_extension("tmp");
+        fs::write(&temp, self.current.to_config())?;
+        fs::rename(&temp, path)?;
+        Ok(())
+    }
+}
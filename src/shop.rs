@@ -0,0 +1,228 @@
+//! Shop types for deckbuilder games.
+//!
+//! Provides the [`Shop`] found at shop nodes of the run map: cards and relics for sale, the
+//! card removal service and rerolling, plus the [`ShopAction`]s the shop UI emits.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::card::{Card, CardType, Relic};
+//! use deckbuilder_eng::shop::*;
+//!
+//! let mut shop = Shop::new(120)
+//!     .card(Card::new(1, "Cleave", "Deal 8 damage to ALL enemies", 1, CardType::Attack), 50)
+//!     .relic(Relic::new("Anchor", "Start each combat with 10 block."), 150)
+//!     .removal(75)
+//!     .reroll(20);
+//!
+//! assert!(shop.can_afford(shop.items[0].price));
+//! assert!(!shop.can_afford(shop.items[1].price));
+//!
+//! if let Some(ShopOffer::Card(card)) = shop.buy(0) {
+//!     println!("Bought {}", card.name);
+//! }
+//! assert_eq!(shop.gold, 70);
+//! ```
+//!
+//! # Details
+//!
+//! - `gold` is the player's gold while shopping; copy it back to the run state when leaving.
+//! - Bought items stay in the list, marked as sold, so the layout doesn't shift.
+//! - See each struct and function's documentation for more.
+
+use crate::card::{Card, Relic};
+
+/// Something a shop sells.
+#[derive(Debug, Clone)]
+pub enum ShopOffer {
+    Card(Card),
+    Relic(Relic),
+}
+
+impl ShopOffer {
+    /// Returns the name of the card or relic.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card::Relic;
+    /// # use deckbuilder_eng::shop::ShopOffer;
+    /// assert_eq!(ShopOffer::Relic(Relic::new("Anchor", "")).name(), "Anchor");
+    /// ```
+    pub fn name(&self) -> &str {
+        match self {
+            ShopOffer::Card(card) => &card.name,
+            ShopOffer::Relic(relic) => &relic.name,
+        }
+    }
+}
+
+/// An offer with its price.
+#[derive(Debug, Clone)]
+pub struct ShopItem {
+    pub offer: ShopOffer,
+    pub price: u32,
+    pub sold: bool,
+}
+
+/// Request emitted by the shop UI for the game layer to apply.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShopAction {
+    /// Buy the item at this index of [`Shop::items`].
+    Buy(usize),
+    /// Use the card removal service (the game lets the player pick the card).
+    RemoveCard,
+    /// Replace the unsold offers.
+    Reroll,
+    /// Leave the shop.
+    Leave,
+}
+
+/// Offers and services of a shop, and the gold the player has to spend.
+#[derive(Debug, Clone, Default)]
+pub struct Shop {
+    pub items: Vec<ShopItem>,
+    /// Price of removing a card; `None` once used or if not offered.
+    pub removal_price: Option<u32>,
+    /// Price of a reroll; `None` if rerolling isn't offered.
+    pub reroll_price: Option<u32>,
+    pub gold: u32,
+}
+
+impl Shop {
+    /// Creates an empty shop for a player with `gold`.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::shop::Shop;
+    /// let shop = Shop::new(99);
+    /// ```
+    pub fn new(gold: u32) -> Self {
+        Self {
+            gold,
+            ..Self::default()
+        }
+    }
+
+    /// Adds a card for sale.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card::{Card, CardType};
+    /// # use deckbuilder_eng::shop::Shop;
+    /// let shop = Shop::new(99).card(Card::new(1, "Cleave", "Deal 8 damage to ALL enemies", 1, CardType::Attack), 50);
+    /// ```
+    pub fn card(mut self, card: Card, price: u32) -> Self {
+        self.items.push(ShopItem { offer: ShopOffer::Card(card), price, sold: false });
+        self
+    }
+
+    /// Adds a relic for sale.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card::Relic;
+    /// # use deckbuilder_eng::shop::Shop;
+    /// let shop = Shop::new(99).relic(Relic::new("Anchor", "Start each combat with 10 block."), 150);
+    /// ```
+    pub fn relic(mut self, relic: Relic, price: u32) -> Self {
+        self.items.push(ShopItem { offer: ShopOffer::Relic(relic), price, sold: false });
+        self
+    }
+
+    /// Offers the card removal service.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::shop::Shop;
+    /// let shop = Shop::new(99).removal(75);
+    /// ```
+    pub fn removal(mut self, price: u32) -> Self {
+        self.removal_price = Some(price);
+        self
+    }
+
+    /// Offers rerolling the unsold items.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::shop::Shop;
+    /// let shop = Shop::new(99).reroll(20);
+    /// ```
+    pub fn reroll(mut self, price: u32) -> Self {
+        self.reroll_price = Some(price);
+        self
+    }
+
+    /// Returns `true` if the player has at least `price` gold.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::shop::Shop;
+    /// assert!(Shop::new(50).can_afford(50));
+    /// ```
+    pub fn can_afford(&self, price: u32) -> bool {
+        self.gold >= price
+    }
+
+    /// Deducts `price` gold; returns `false` without paying if the player can't afford it.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::shop::Shop;
+    /// let mut shop = Shop::new(30);
+    /// assert!(shop.pay(20));
+    /// assert!(!shop.pay(20));
+    /// assert_eq!(shop.gold, 10);
+    /// ```
+    pub fn pay(&mut self, price: u32) -> bool {
+        if self.can_afford(price) {
+            self.gold -= price;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Buys the item at `index`: pays for it, marks it sold and returns the offer. Returns
+    /// `None` if it is sold out, missing or unaffordable.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::card::Relic;
+    /// # use deckbuilder_eng::shop::Shop;
+    /// let mut shop = Shop::new(200).relic(Relic::new("Anchor", ""), 150);
+    /// assert!(shop.buy(0).is_some());
+    /// assert!(shop.buy(0).is_none());
+    /// ```
+    pub fn buy(&mut self, index: usize) -> Option<ShopOffer> {
+        let item = self.items.get(index).filter(|item| !item.sold)?;
+        let price = item.price;
+        if !self.pay(price) {
+            return None;
+        }
+        let item = &mut self.items[index];
+        item.sold = true;
+        Some(item.offer.clone())
+    }
+
+    /// Pays for the removal service and disables it. Returns `false` if unavailable or
+    /// unaffordable.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::shop::Shop;
+    /// let mut shop = Shop::new(100).removal(75);
+    /// assert!(shop.use_removal());
+    /// assert!(shop.removal_price.is_none());
+    /// ```
+    pub fn use_removal(&mut self) -> bool {
+        let Some(price) = self.removal_price else {
+            return false;
+        };
+        if !self.pay(price) {
+            return false;
+        }
+        self.removal_price = None;
+        true
+    }
+}
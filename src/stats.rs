@@ -0,0 +1,191 @@
+//! Runtime performance counters for deckbuilder games.
+//!
+//! Provides [`engine_stats`], a snapshot of counters the crate keeps while the game runs (frames
+//! and their CPU time, fixed ticks, dispatched events, cards drawn, deck shuffles, status ticks
+//! and decoded animations), to show in the debug overlay or to compare between builds so
+//! performance regressions are caught.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::card::{Card, CardType, Deck};
+//! use deckbuilder_eng::stats::engine_stats;
+//!
+//! let before = engine_stats();
+//! let mut deck = Deck::new(vec![Card::new(1, "Strike", "Deal 6 damage", 1, CardType::Attack); 10]);
+//! deck.draw_multiple(5);
+//!
+//! let delta = engine_stats().since(&before);
+//! assert!(delta.cards_drawn >= 5);
+//! ```
+//!
+//! # Details
+//!
+//! - Counters are global and shared by all threads; they only grow until
+//!   [`reset_engine_stats`]. Use [`EngineStats::since`] to measure a section of code.
+//! - Frame times are recorded by `DeckbuilderEngine::frame` (or [`record_frame`] in a custom
+//!   game loop) and measure the CPU time of a frame, not the time between frames.
+//! - Counting is a relaxed atomic add, cheap enough to stay on in release builds.
+//! - The `hot_paths` and `ui` benchmarks (`cargo bench`) time deck, status, card grid and GIF
+//!   decoding code with criterion.
+//! - See each struct and function's documentation for more.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// Counters kept by the crate.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Counter {
+    Frames,
+    FrameNanos,
+    MaxFrameNanos,
+    LastFrameNanos,
+    FixedSteps,
+    EventsDispatched,
+    CardsDrawn,
+    Shuffles,
+    StatusTicks,
+    AnimationsDecoded,
+    AnimationFrames,
+}
+
+const COUNTERS: usize = 11;
+
+static VALUES: [AtomicU64; COUNTERS] = [const { AtomicU64::new(0) }; COUNTERS];
+
+pub(crate) fn add(counter: Counter, amount: u64) {
+    VALUES[counter as usize].fetch_add(amount, Ordering::Relaxed);
+}
+
+/// Records a frame that took `time` of CPU time. `DeckbuilderEngine` records its frames; call
+/// this from your own game loop instead if you don't use it.
+///
+/// # Example
+/// ```rust
+/// # use std::time::{Duration, Instant};
+/// # use deckbuilder_eng::stats::{engine_stats, record_frame};
+/// let started = Instant::now();
+/// // ... update and draw ...
+/// record_frame(started.elapsed());
+/// assert!(engine_stats().frames >= 1);
+/// ```
+pub fn record_frame(time: Duration) {
+    let nanos = time.as_nanos().min(u64::MAX as u128) as u64;
+    add(Counter::Frames, 1);
+    add(Counter::FrameNanos, nanos);
+    VALUES[Counter::LastFrameNanos as usize].store(nanos, Ordering::Relaxed);
+    VALUES[Counter::MaxFrameNanos as usize].fetch_max(nanos, Ordering::Relaxed);
+}
+
+fn get(counter: Counter) -> u64 {
+    VALUES[counter as usize].load(Ordering::Relaxed)
+}
+
+/// Snapshot of the runtime counters, returned by [`engine_stats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct EngineStats {
+    /// Engine frames run.
+    pub frames: u64,
+    /// Total CPU time of those frames.
+    pub frame_time: Duration,
+    /// CPU time of the last frame.
+    pub last_frame_time: Duration,
+    /// CPU time of the slowest frame.
+    pub max_frame_time: Duration,
+    /// Fixed-timestep ticks of all game clocks.
+    pub fixed_steps: u64,
+    /// Events delivered by all event buses.
+    pub events_dispatched: u64,
+    /// Cards drawn from all decks.
+    pub cards_drawn: u64,
+    /// Discard piles shuffled back into draw piles.
+    pub shuffles: u64,
+    /// Turns counted down on status containers.
+    pub status_ticks: u64,
+    /// Animations (GIF, WebP, APNG, or still images loaded as one frame) decoded.
+    pub animations_decoded: u64,
+    /// Frames of those animations.
+    pub animation_frames: u64,
+}
+
+impl EngineStats {
+    /// Returns the average CPU time of a frame, or zero if no frame ran.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use deckbuilder_eng::stats::EngineStats;
+    /// let stats = EngineStats { frames: 4, frame_time: Duration::from_millis(8), ..Default::default() };
+    /// assert_eq!(stats.average_frame_time(), Duration::from_millis(2));
+    /// ```
+    pub fn average_frame_time(&self) -> Duration {
+        if self.frames == 0 {
+            Duration::ZERO
+        } else {
+            self.frame_time / self.frames.min(u32::MAX as u64) as u32
+        }
+    }
+
+    /// Returns the counts added since `earlier`. The last and slowest frame times are kept
+    /// from `self`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::stats::EngineStats;
+    /// let earlier = EngineStats { shuffles: 2, ..Default::default() };
+    /// let now = EngineStats { shuffles: 5, ..Default::default() };
+    /// assert_eq!(now.since(&earlier).shuffles, 3);
+    /// ```
+    pub fn since(&self, earlier: &EngineStats) -> EngineStats {
+        EngineStats {
+            frames: self.frames.saturating_sub(earlier.frames),
+            frame_time: self.frame_time.saturating_sub(earlier.frame_time),
+            last_frame_time: self.last_frame_time,
+            max_frame_time: self.max_frame_time,
+            fixed_steps: self.fixed_steps.saturating_sub(earlier.fixed_steps),
+            events_dispatched: self.events_dispatched.saturating_sub(earlier.events_dispatched),
+            cards_drawn: self.cards_drawn.saturating_sub(earlier.cards_drawn),
+            shuffles: self.shuffles.saturating_sub(earlier.shuffles),
+            status_ticks: self.status_ticks.saturating_sub(earlier.status_ticks),
+            animations_decoded: self.animations_decoded.saturating_sub(earlier.animations_decoded),
+            animation_frames: self.animation_frames.saturating_sub(earlier.animation_frames),
+        }
+    }
+}
+
+/// Returns the current values of the runtime counters.
+///
+/// # Example
+/// ```rust
+/// # use deckbuilder_eng::stats::engine_stats;
+/// let stats = engine_stats();
+/// println!("{} frames, {:?} on average", stats.frames, stats.average_frame_time());
+/// ```
+pub fn engine_stats() -> EngineStats {
+    EngineStats {
+        frames: get(Counter::Frames),
+        frame_time: Duration::from_nanos(get(Counter::FrameNanos)),
+        last_frame_time: Duration::from_nanos(get(Counter::LastFrameNanos)),
+        max_frame_time: Duration::from_nanos(get(Counter::MaxFrameNanos)),
+        fixed_steps: get(Counter::FixedSteps),
+        events_dispatched: get(Counter::EventsDispatched),
+        cards_drawn: get(Counter::CardsDrawn),
+        shuffles: get(Counter::Shuffles),
+        status_ticks: get(Counter::StatusTicks),
+        animations_decoded: get(Counter::AnimationsDecoded),
+        animation_frames: get(Counter::AnimationFrames),
+    }
+}
+
+/// Sets all runtime counters back to zero, e.g. when a new run starts.
+///
+/// # Example
+/// ```rust
+/// # use deckbuilder_eng::stats::reset_engine_stats;
+/// reset_engine_stats();
+/// ```
+pub fn reset_engine_stats() {
+    for value in &VALUES {
+        value.store(0, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,279 @@
+//! Status effect types for deckbuilder games.
+//!
+//! Provides [`Status`] (a named buff or debuff with stacks and an optional duration) and the
+//! [`StatusContainer`] each combatant keeps its statuses in.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::status::*;
+//!
+//! let mut statuses = StatusContainer::new();
+//! statuses.apply(Status::buff("Strength", 2));
+//! statuses.apply(Status::debuff("Vulnerable", 1).turns(2));
+//! statuses.apply(Status::buff("Strength", 1));
+//! assert_eq!(statuses.stacks("Strength"), 3);
+//!
+//! statuses.tick();
+//! statuses.tick();
+//! assert!(statuses.get("Vulnerable").is_none());
+//! ```
+//!
+//! # Details
+//!
+//! - Statuses are identified by name; applying an existing status adds its stacks and keeps
+//!   the longer duration.
+//! - Statuses without a duration last until removed.
+//! - [`predict_damage`] applies the common "Strength", "Weak" and "Vulnerable" rules, which
+//!   [`default_glossary`] explains.
+//! - See each struct and function's documentation for more.
+
+use std::sync::LazyLock;
+
+use crate::card::Keyword;
+use crate::stats::{self, Counter};
+
+/// Whether a status helps or hinders its owner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatusKind {
+    Buff,
+    Debuff,
+}
+
+/// A status effect on a combatant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Status {
+    pub name: String,
+    pub kind: StatusKind,
+    pub stacks: i32,
+    /// Turns remaining; `None` lasts until removed.
+    pub turns: Option<u32>,
+}
+
+impl Status {
+    /// Creates a buff with `stacks` and no duration.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::status::Status;
+    /// let strength = Status::buff("Strength", 2);
+    /// ```
+    pub fn buff(name: impl Into<String>, stacks: i32) -> Self {
+        Self {
+            name: name.into(),
+            kind: StatusKind::Buff,
+            stacks,
+            turns: None,
+        }
+    }
+
+    /// Creates a debuff with `stacks` and no duration.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::status::Status;
+    /// let poison = Status::debuff("Poison", 5);
+    /// ```
+    pub fn debuff(name: impl Into<String>, stacks: i32) -> Self {
+        Self {
+            kind: StatusKind::Debuff,
+            ..Self::buff(name, stacks)
+        }
+    }
+
+    /// Makes the status expire after `turns` turns.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::status::Status;
+    /// let weak = Status::debuff("Weak", 1).turns(2);
+    /// assert_eq!(weak.turns, Some(2));
+    /// ```
+    pub fn turns(mut self, turns: u32) -> Self {
+        self.turns = Some(turns);
+        self
+    }
+}
+
+/// The statuses of one combatant, in the order they were applied.
+#[derive(Debug, Clone, Default)]
+pub struct StatusContainer {
+    pub statuses: Vec<Status>,
+}
+
+impl StatusContainer {
+    /// Creates an empty container.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::status::StatusContainer;
+    /// let statuses = StatusContainer::new();
+    /// assert!(statuses.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Applies `status`, merging it into an existing status of the same name.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::status::{Status, StatusContainer};
+    /// let mut statuses = StatusContainer::new();
+    /// statuses.apply(Status::debuff("Weak", 1).turns(1));
+    /// statuses.apply(Status::debuff("Weak", 1).turns(3));
+    /// assert_eq!(statuses.get("Weak").unwrap().turns, Some(3));
+    /// ```
+    pub fn apply(&mut self, status: Status) {
+        match self.statuses.iter_mut().find(|s| s.name == status.name) {
+            Some(existing) => {
+                existing.stacks += status.stacks;
+                existing.turns = match (existing.turns, status.turns) {
+                    (Some(a), Some(b)) => Some(a.max(b)),
+                    _ => None,
+                };
+            }
+            None => self.statuses.push(status),
+        }
+    }
+
+    /// Removes the status called `name` and returns it.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::status::{Status, StatusContainer};
+    /// let mut statuses = StatusContainer::new();
+    /// statuses.apply(Status::buff("Artifact", 1));
+    /// assert!(statuses.remove("Artifact").is_some());
+    /// ```
+    pub fn remove(&mut self, name: &str) -> Option<Status> {
+        let index = self.statuses.iter().position(|s| s.name == name)?;
+        Some(self.statuses.remove(index))
+    }
+
+    /// Returns the status called `name`.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::status::StatusContainer;
+    /// assert!(StatusContainer::new().get("Strength").is_none());
+    /// ```
+    pub fn get(&self, name: &str) -> Option<&Status> {
+        self.statuses.iter().find(|s| s.name == name)
+    }
+
+    /// Returns the stacks of the status called `name`, or 0.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::status::{Status, StatusContainer};
+    /// let mut statuses = StatusContainer::new();
+    /// statuses.apply(Status::buff("Dexterity", 2));
+    /// assert_eq!(statuses.stacks("Dexterity"), 2);
+    /// assert_eq!(statuses.stacks("Strength"), 0);
+    /// ```
+    pub fn stacks(&self, name: &str) -> i32 {
+        self.get(name).map_or(0, |s| s.stacks)
+    }
+
+    /// Counts down the duration of every timed status and removes the expired ones.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::status::{Status, StatusContainer};
+    /// let mut statuses = StatusContainer::new();
+    /// statuses.apply(Status::debuff("Frail", 1).turns(1));
+    /// statuses.tick();
+    /// assert!(statuses.is_empty());
+    /// ```
+    pub fn tick(&mut self) {
+        stats::add(Counter::StatusTicks, 1);
+        for status in &mut self.statuses {
+            if let Some(turns) = &mut status.turns {
+                *turns = turns.saturating_sub(1);
+            }
+        }
+        self.statuses.retain(|s| s.turns != Some(0));
+    }
+
+    /// Returns an iterator over the statuses.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::status::{Status, StatusContainer};
+    /// let mut statuses = StatusContainer::new();
+    /// statuses.apply(Status::buff("Strength", 1));
+    /// assert_eq!(statuses.iter().count(), 1);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &Status> {
+        self.statuses.iter()
+    }
+
+    /// Returns the number of statuses.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::status::StatusContainer;
+    /// assert_eq!(StatusContainer::new().len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.statuses.len()
+    }
+
+    /// Returns `true` if there are no statuses.
+    ///
+    /// # Example
+    /// ```
+    /// # use deckbuilder_eng::status::StatusContainer;
+    /// assert!(StatusContainer::new().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.statuses.is_empty()
+    }
+}
+
+/// Damage an attack of `base` deals after the attacker's "Strength" (added per stack) and
+/// "Weak" (25% less) and the target's "Vulnerable" (50% more). Never negative.
+///
+/// # Example
+/// ```
+/// # use deckbuilder_eng::status::{predict_damage, Status, StatusContainer};
+/// let mut attacker = StatusContainer::new();
+/// attacker.apply(Status::buff("Strength", 2));
+/// let mut target = StatusContainer::new();
+/// target.apply(Status::debuff("Vulnerable", 1).turns(1));
+/// assert_eq!(predict_damage(6, &attacker, &target), 12);
+/// ```
+pub fn predict_damage(base: i32, attacker: &StatusContainer, target: &StatusContainer) -> i32 {
+    let mut damage = (base + attacker.stacks("Strength")) as f32;
+    if attacker.get("Weak").is_some() {
+        damage *= 0.75;
+    }
+    if target.get("Vulnerable").is_some() {
+        damage *= 1.5;
+    }
+    (damage.floor() as i32).max(0)
+}
+
+/// Rules text of the common statuses, for tooltips. Built once and shared.
+///
+/// # Example
+/// ```
+/// # use deckbuilder_eng::status::default_glossary;
+/// let glossary = default_glossary();
+/// assert!(glossary.iter().any(|k| k.name == "Vulnerable"));
+/// ```
+pub fn default_glossary() -> &'static [Keyword] {
+    static GLOSSARY: LazyLock<Vec<Keyword>> = LazyLock::new(|| {
+        vec![
+            Keyword::new("Strength", "Increases attack damage by <b>1</b> per stack."),
+            Keyword::new("Dexterity", "Increases block gained from cards by <b>1</b> per stack."),
+            Keyword::new("Weak", "Deals [red]25%[/red] less attack damage."),
+            Keyword::new("Vulnerable", "Takes [red]50%[/red] more damage from attacks."),
+            Keyword::new("Frail", "Gains [red]25%[/red] less block from cards."),
+            Keyword::new("Poison", "Loses health at the start of its turn, then Poison is reduced by 1."),
+            Keyword::new("Artifact", "Negates the next debuff."),
+        ]
+    });
+    &GLOSSARY
+}
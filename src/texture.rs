@@ -0,0 +1,795 @@
+//! Texture cache for egui-based deckbuilder games.
+//!
+//! Provides a [`TextureManager`] that loads images and GIFs once, hands out shared
+//! [`TextureHandle`]s on later requests, reuses textures of files with identical contents and
+//! tracks how much texture memory it holds.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::texture::TextureManager;
+//! use deckbuilder_eng::ui_image::ui_image;
+//! use egui::{CentralPanel, Context};
+//!
+//! fn combat_screen(ctx: &Context, textures: &mut TextureManager) {
+//!     CentralPanel::default().show(ctx, |ui| {
+//!         // Decoded and uploaded on the first call, a cheap handle clone afterwards
+//!         if let Ok(art) = textures.load("assets/cards/strike.png") {
+//!             ui_image(ui, &art, None);
+//!         }
+//!     });
+//! }
+//!
+//! fn leave_combat(textures: &mut TextureManager) {
+//!     textures.clear();
+//! }
+//!
+//! let ctx = Context::default();
+//! let mut textures = TextureManager::new(&ctx);
+//! combat_screen(&ctx, &mut textures);
+//! ```
+//!
+//! # Details
+//!
+//! - Textures are cached by path; files with the same contents and load options share one
+//!   texture.
+//! - Effect variants (grayscale, brightness) are cached per path and effect.
+//! - GIF frames are cached without their playback speed, so changing the speed doesn't
+//!   upload the frames again.
+//! - With a memory budget ([`TextureManager::set_budget`]), the least recently used paths
+//!   are evicted automatically; [`TextureManager::texture_stats`] reports usage.
+//! - Files are read from disk, or from an [`AssetSource`] such as an asset pack
+//!   ([`TextureManager::set_source`]).
+//! - With the `hot-reload` feature, cached files are watched and re-uploaded when they change
+//!   on disk, keeping their handles where possible (see [`TextureManager::reload_changed`]).
+//! - Evicting a texture only drops the manager's handle; the GPU texture is freed once the
+//!   last clone of the handle is dropped.
+//! - See each struct and function's documentation for more.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+#[cfg(feature = "hot-reload")]
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+#[cfg(feature = "hot-reload")]
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::time::Duration;
+
+use egui::{ColorImage, Context, TextureHandle};
+
+use crate::error::Error;
+use crate::ui_image::{load_animation_frames_from_bytes_with, load_texture_from_bytes_with, ImageEffect, LoadOptions};
+use crate::vfs::AssetSource;
+
+struct TextureEntry {
+    handle: TextureHandle,
+    hash: u64,
+}
+
+/// A texture shared by every path whose file has the same contents.
+struct SharedTexture {
+    handle: TextureHandle,
+    /// Number of paths using it.
+    users: usize,
+}
+
+struct GifEntry {
+    frames: Vec<TextureHandle>,
+    delays: Vec<Duration>,
+}
+
+/// File watcher of the cached paths. Directories are watched rather than files, so editors
+/// that save by replacing the file are noticed too.
+#[cfg(feature = "hot-reload")]
+struct HotReload {
+    watcher: notify::RecommendedWatcher,
+    changes: mpsc::Receiver<PathBuf>,
+    dirs: HashSet<PathBuf>,
+    /// Watched file path (canonical directory + file name) → cache key.
+    files: HashMap<PathBuf, PathBuf>,
+}
+
+#[cfg(feature = "hot-reload")]
+impl HotReload {
+    fn new(ctx: &Context) -> Option<Self> {
+        use notify::{Event, EventKind};
+
+        let (sender, changes) = mpsc::channel();
+        let ctx = ctx.clone();
+        let watcher = notify::recommended_watcher(move |event: notify::Result<Event>| {
+            if let Ok(event) = event
+                && matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_))
+            {
+                for path in event.paths {
+                    let _ = sender.send(path);
+                }
+                ctx.request_repaint();
+            }
+        })
+        .ok()?;
+        Some(Self { watcher, changes, dirs: HashSet::new(), files: HashMap::new() })
+    }
+
+    fn watch(&mut self, path: &Path) {
+        use notify::{RecursiveMode, Watcher};
+
+        let Some(name) = path.file_name() else {
+            return;
+        };
+        let dir = match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+        let Ok(dir) = dir.canonicalize() else {
+            return;
+        };
+        if !self.dirs.contains(&dir) && self.watcher.watch(&dir, RecursiveMode::NonRecursive).is_ok() {
+            self.dirs.insert(dir.clone());
+        }
+        self.files.insert(dir.join(name), path.to_path_buf());
+    }
+}
+
+/// Cache statistics returned by [`TextureManager::texture_stats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TextureStats {
+    /// Number of cached images (including inserted ones).
+    pub textures: usize,
+    /// Number of cached GIFs.
+    pub gifs: usize,
+    /// Number of cached effect variants.
+    pub effects: usize,
+    /// Approximate GPU memory of the cache in bytes.
+    pub memory_bytes: usize,
+    /// Memory budget in bytes, if any.
+    pub budget: Option<usize>,
+    /// Loads served from the cache.
+    pub hits: u64,
+    /// Loads that had to decode and upload.
+    pub misses: u64,
+    /// Paths evicted to stay within the budget.
+    pub evictions: u64,
+}
+
+/// Cache of textures keyed by path.
+pub struct TextureManager {
+    ctx: Context,
+    textures: HashMap<PathBuf, TextureEntry>,
+    by_hash: HashMap<u64, SharedTexture>,
+    gifs: HashMap<PathBuf, GifEntry>,
+    effects: HashMap<(PathBuf, (u8, u32)), TextureHandle>,
+    options: LoadOptions,
+    budget: Option<usize>,
+    /// Bytes of the unique textures, GIF frames and effect variants, kept up to date so the
+    /// budget can be checked cheaply.
+    memory: usize,
+    last_used: HashMap<PathBuf, u64>,
+    clock: u64,
+    hits: u64,
+    misses: u64,
+    evictions: u64,
+    source: Option<Arc<dyn AssetSource>>,
+    #[cfg(feature = "hot-reload")]
+    hot_reload: Option<HotReload>,
+}
+
+/// Key of a shared texture: the same contents loaded with other options is another texture.
+fn content_hash(content: impl Hash, options: &LoadOptions) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    content.hash(&mut hasher);
+    options.max_size.hash(&mut hasher);
+    (options.filter as u8).hash(&mut hasher);
+    options.texture.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn texture_bytes(handle: &TextureHandle) -> usize {
+    let [w, h] = handle.size();
+    w * h * 4
+}
+
+impl TextureManager {
+    /// Creates an empty cache uploading to `ctx`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::texture::TextureManager;
+    /// let textures = TextureManager::new(&egui::Context::default());
+    /// assert!(textures.is_empty());
+    /// ```
+    pub fn new(ctx: &Context) -> Self {
+        Self {
+            ctx: ctx.clone(),
+            textures: HashMap::new(),
+            by_hash: HashMap::new(),
+            gifs: HashMap::new(),
+            effects: HashMap::new(),
+            options: LoadOptions::default(),
+            budget: None,
+            memory: 0,
+            last_used: HashMap::new(),
+            clock: 0,
+            hits: 0,
+            misses: 0,
+            evictions: 0,
+            source: None,
+            #[cfg(feature = "hot-reload")]
+            hot_reload: HotReload::new(ctx),
+        }
+    }
+
+    /// Sets a memory budget in bytes. When the cache grows beyond it, the least recently used
+    /// paths are evicted until it fits again (the texture just loaded is always kept). `None`
+    /// removes the budget.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::{Color32, ColorImage};
+    /// # use deckbuilder_eng::texture::TextureManager;
+    /// let mut textures = TextureManager::new(&egui::Context::default());
+    /// textures.set_budget(Some(100));
+    /// textures.insert("a", ColorImage::new([4, 4], Color32::RED));
+    /// textures.insert("b", ColorImage::new([4, 4], Color32::BLUE));
+    /// // 64 bytes each: "a" was used least recently and is evicted
+    /// assert!(!textures.contains("a"));
+    /// assert!(textures.contains("b"));
+    /// ```
+    pub fn set_budget(&mut self, budget: Option<usize>) {
+        self.budget = budget;
+        self.enforce_budget(None);
+    }
+
+    /// Returns the memory budget in bytes.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::texture::TextureManager;
+    /// assert_eq!(TextureManager::new(&egui::Context::default()).budget(), None);
+    /// ```
+    pub fn budget(&self) -> Option<usize> {
+        self.budget
+    }
+
+    /// Returns counts, memory use and hit/miss/eviction statistics of the cache.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::texture::TextureManager;
+    /// # use deckbuilder_eng::ui_debug::DebugStats;
+    /// # fn demo(textures: &TextureManager) {
+    /// let stats = textures.texture_stats();
+    /// let debug = DebugStats::new().texture_bytes(stats.memory_bytes);
+    /// println!("{} hits, {} misses, {} evictions", stats.hits, stats.misses, stats.evictions);
+    /// # }
+    /// ```
+    pub fn texture_stats(&self) -> TextureStats {
+        TextureStats {
+            textures: self.textures.len(),
+            gifs: self.gifs.len(),
+            effects: self.effects.len(),
+            memory_bytes: self.memory,
+            budget: self.budget,
+            hits: self.hits,
+            misses: self.misses,
+            evictions: self.evictions,
+        }
+    }
+
+    fn touch(&mut self, path: &Path) {
+        self.clock += 1;
+        self.last_used.insert(path.to_path_buf(), self.clock);
+    }
+
+    /// Returns the texture of `hash` with one more user, if a path already uses it.
+    fn reuse(&mut self, hash: u64) -> Option<TextureHandle> {
+        let shared = self.by_hash.get_mut(&hash)?;
+        shared.users += 1;
+        Some(shared.handle.clone())
+    }
+
+    /// Adds a newly uploaded texture of `hash` with one user.
+    fn share(&mut self, hash: u64, handle: &TextureHandle) {
+        self.memory += texture_bytes(handle);
+        self.by_hash.insert(hash, SharedTexture { handle: handle.clone(), users: 1 });
+    }
+
+    /// Stops a path using the shared texture of `hash`, dropping it after its last user.
+    fn release(&mut self, hash: u64) {
+        if let Some(shared) = self.by_hash.get_mut(&hash) {
+            shared.users -= 1;
+            if shared.users == 0 {
+                self.memory -= texture_bytes(&shared.handle);
+                self.by_hash.remove(&hash);
+            }
+        }
+    }
+
+    /// Evicts least recently used paths, except `keep`, until the cache fits the budget.
+    fn enforce_budget(&mut self, keep: Option<&Path>) {
+        let Some(budget) = self.budget else {
+            return;
+        };
+        let mut candidates: Vec<(u64, PathBuf)> = self
+            .last_used
+            .iter()
+            .filter(|(path, _)| Some(path.as_path()) != keep)
+            .map(|(path, used)| (*used, path.clone()))
+            .collect();
+        candidates.sort();
+        for (_, path) in candidates {
+            if self.memory <= budget {
+                break;
+            }
+            if self.evict(&path) {
+                self.evictions += 1;
+            }
+        }
+    }
+
+    /// Sets how images are scaled down and sampled, e.g. a smaller `max_size` on low-end
+    /// machines or [`LoadOptions::pixel_art`] for crisp pixel art. Changing the options clears
+    /// the cache, so textures are loaded again with the new options on their next use.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::{Color32, ColorImage};
+    /// # use deckbuilder_eng::texture::TextureManager;
+    /// # use deckbuilder_eng::ui_image::LoadOptions;
+    /// let mut textures = TextureManager::new(&egui::Context::default());
+    /// textures.insert("white", ColorImage::new([4, 4], Color32::WHITE));
+    /// textures.set_load_options(LoadOptions::max_size(1024));
+    /// assert!(textures.is_empty());
+    /// ```
+    pub fn set_load_options(&mut self, options: LoadOptions) {
+        if options != self.options {
+            self.options = options;
+            self.clear();
+        }
+    }
+
+    /// Sets where files are read from, e.g. a [`Vfs`](crate::vfs::Vfs) over the shipped asset
+    /// pack and mod directories. Without a source, paths are read from disk. Already cached
+    /// textures are kept.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::sync::Arc;
+    /// # use deckbuilder_eng::texture::TextureManager;
+    /// # use deckbuilder_eng::vfs::DirSource;
+    /// let mut textures = TextureManager::new(&egui::Context::default());
+    /// textures.set_source(Arc::new(DirSource::new("assets")));
+    /// assert!(textures.load_gif("example.gif", 1.0).is_ok());
+    /// ```
+    pub fn set_source(&mut self, source: Arc<dyn AssetSource>) {
+        self.source = Some(source);
+    }
+
+    /// Returns the source files are read from, if one is set.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::texture::TextureManager;
+    /// assert!(TextureManager::new(&egui::Context::default()).source().is_none());
+    /// ```
+    pub fn source(&self) -> Option<&Arc<dyn AssetSource>> {
+        self.source.as_ref()
+    }
+
+    fn read_file(&self, path: &Path) -> std::io::Result<Vec<u8>> {
+        match &self.source {
+            Some(source) => source.read(path),
+            None => std::fs::read(path),
+        }
+    }
+
+    /// Returns the texture of the image at `path`, loading it on first use. Fails with
+    /// [`Error::Io`] if the file can't be read and [`Error::Image`] if it can't be decoded.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::texture::TextureManager;
+    /// # use deckbuilder_eng::Error;
+    /// let mut textures = TextureManager::new(&egui::Context::default());
+    /// assert!(matches!(textures.load("missing.png"), Err(Error::Io { .. })));
+    /// ```
+    pub fn load(&mut self, path: impl AsRef<Path>) -> Result<TextureHandle, Error> {
+        let path = path.as_ref();
+        #[cfg(feature = "hot-reload")]
+        self.reload_changed();
+        if let Some(handle) = self.textures.get(path).map(|entry| entry.handle.clone()) {
+            self.hits += 1;
+            self.touch(path);
+            return Ok(handle);
+        }
+        self.misses += 1;
+        tracing::debug!(path = %path.display(), "loading texture");
+        let bytes = self.read_file(path).map_err(|err| Error::io(path, err))?;
+        let hash = content_hash(&bytes, &self.options);
+        let handle = match self.reuse(hash) {
+            Some(handle) => handle,
+            None => {
+                let handle = load_texture_from_bytes_with(&self.ctx, &path.to_string_lossy(), &bytes, &self.options)?;
+                self.share(hash, &handle);
+                handle
+            }
+        };
+        self.textures.insert(path.to_path_buf(), TextureEntry { handle: handle.clone(), hash });
+        #[cfg(feature = "hot-reload")]
+        self.watch(path);
+        self.touch(path);
+        self.enforce_budget(Some(path));
+        Ok(handle)
+    }
+
+    /// Caches a generated image under `name` (replacing any texture of that name) and returns
+    /// its handle. An image identical to a cached one shares its texture.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::{Color32, ColorImage};
+    /// # use deckbuilder_eng::texture::TextureManager;
+    /// let mut textures = TextureManager::new(&egui::Context::default());
+    /// let handle = textures.insert("white", ColorImage::new([4, 4], Color32::WHITE));
+    /// assert!(textures.get("white").is_some());
+    /// ```
+    pub fn insert(&mut self, name: impl AsRef<Path>, image: ColorImage) -> TextureHandle {
+        let name = name.as_ref();
+        self.evict(name);
+        let hash = content_hash((image.size, image.as_raw()), &self.options);
+        let handle = match self.reuse(hash) {
+            Some(handle) => handle,
+            None => {
+                let handle = self.ctx.load_texture(name.to_string_lossy(), image, self.options.texture);
+                self.share(hash, &handle);
+                handle
+            }
+        };
+        self.textures.insert(name.to_path_buf(), TextureEntry { handle: handle.clone(), hash });
+        self.touch(name);
+        self.enforce_budget(Some(name));
+        handle
+    }
+
+    /// Returns the cached texture of `path` without loading it. Doesn't count as a use for the
+    /// budget's eviction order.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::texture::TextureManager;
+    /// let textures = TextureManager::new(&egui::Context::default());
+    /// assert!(textures.get("assets/example.png").is_none());
+    /// ```
+    pub fn get(&self, path: impl AsRef<Path>) -> Option<TextureHandle> {
+        self.textures.get(path.as_ref()).map(|entry| entry.handle.clone())
+    }
+
+    /// Returns `true` if the texture or GIF of `path` is cached.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::texture::TextureManager;
+    /// let textures = TextureManager::new(&egui::Context::default());
+    /// assert!(!textures.contains("assets/example.png"));
+    /// ```
+    pub fn contains(&self, path: impl AsRef<Path>) -> bool {
+        let path = path.as_ref();
+        self.textures.contains_key(path) || self.gifs.contains_key(path)
+    }
+
+    /// Returns the frames of the GIF (or animated WebP/APNG) at `path` and their delays
+    /// adjusted for `speed` (`1.0` = normal, `2.0` = twice as fast), decoding and uploading it
+    /// on first use.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::texture::TextureManager;
+    /// # fn demo(textures: &mut TextureManager) {
+    /// if let Ok((frames, delays)) = textures.load_gif("assets/example.gif", 2.0) {
+    ///     println!("{} frames", frames.len());
+    /// }
+    /// # }
+    /// ```
+    pub fn load_gif(&mut self, path: impl AsRef<Path>, speed: f32) -> Result<(Vec<TextureHandle>, Vec<Duration>), Error> {
+        let path = path.as_ref();
+        #[cfg(feature = "hot-reload")]
+        self.reload_changed();
+        if self.gifs.contains_key(path) {
+            self.hits += 1;
+            self.touch(path);
+        } else {
+            self.misses += 1;
+            let bytes = self.read_file(path).map_err(|err| Error::io(path, err))?;
+            let (frames, delays) = load_animation_frames_from_bytes_with(&self.ctx, &path.to_string_lossy(), &bytes, None, &self.options)?;
+            self.memory += frames.iter().map(texture_bytes).sum::<usize>();
+            self.gifs.insert(path.to_path_buf(), GifEntry { frames, delays });
+            #[cfg(feature = "hot-reload")]
+            self.watch(path);
+            self.touch(path);
+            self.enforce_budget(Some(path));
+        }
+        let gif = &self.gifs[path];
+        let speed = if speed > 0.0 { speed } else { 1.0 };
+        let delays = gif
+            .delays
+            .iter()
+            .map(|delay| Duration::from_secs_f32(delay.as_secs_f32() / speed).max(Duration::from_millis(1)))
+            .collect();
+        Ok((gif.frames.clone(), delays))
+    }
+
+    /// Returns the image file at `path` with `effect` applied, e.g. a grayscale variant for
+    /// unaffordable cards. Variants are cached per path and effect, alongside the plain
+    /// texture.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Ui;
+    /// # use deckbuilder_eng::texture::TextureManager;
+    /// # use deckbuilder_eng::ui_image::{ui_image, ImageEffect};
+    /// # fn demo(ui: &mut Ui, textures: &mut TextureManager, affordable: bool) {
+    /// let path = "assets/cards/strike.png";
+    /// let art = if affordable {
+    ///     textures.load(path)
+    /// } else {
+    ///     textures.load_with_effect(path, ImageEffect::Grayscale(1.0))
+    /// };
+    /// if let Ok(art) = art {
+    ///     ui_image(ui, &art, None);
+    /// }
+    /// # }
+    /// ```
+    pub fn load_with_effect(&mut self, path: impl AsRef<Path>, effect: ImageEffect) -> Result<TextureHandle, Error> {
+        let path = path.as_ref();
+        #[cfg(feature = "hot-reload")]
+        self.reload_changed();
+        let key = (path.to_path_buf(), effect.cache_key());
+        if let Some(handle) = self.effects.get(&key).cloned() {
+            self.hits += 1;
+            self.touch(path);
+            return Ok(handle);
+        }
+        self.misses += 1;
+        tracing::debug!(path = %path.display(), "loading texture");
+        let bytes = self.read_file(path).map_err(|err| Error::io(path, err))?;
+        let max_texture_side = self.ctx.input(|i| i.max_texture_side);
+        let image = self.options.decode(&bytes, max_texture_side).map_err(|err| Error::image(path, err))?;
+        let color_image = effect.apply(&image);
+        let name = format!("{}#{:?}", path.to_string_lossy(), effect);
+        let handle = self.ctx.load_texture(name, color_image, self.options.texture);
+        self.memory += texture_bytes(&handle);
+        self.effects.insert(key, handle.clone());
+        #[cfg(feature = "hot-reload")]
+        self.watch(path);
+        self.touch(path);
+        self.enforce_budget(Some(path));
+        Ok(handle)
+    }
+
+    /// Drops the cached texture or GIF of `path`, and its effect variants. Returns `true` if
+    /// something was cached.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::{Color32, ColorImage};
+    /// # use deckbuilder_eng::texture::TextureManager;
+    /// let mut textures = TextureManager::new(&egui::Context::default());
+    /// textures.insert("white", ColorImage::new([4, 4], Color32::WHITE));
+    /// assert!(textures.evict("white"));
+    /// assert!(!textures.evict("white"));
+    /// ```
+    pub fn evict(&mut self, path: impl AsRef<Path>) -> bool {
+        let path = path.as_ref();
+        self.last_used.remove(path);
+        #[cfg(feature = "hot-reload")]
+        if let Some(hot) = &mut self.hot_reload {
+            hot.files.retain(|_, key| key != path);
+        }
+        let mut freed = 0;
+        self.effects.retain(|(effect_path, _), handle| {
+            let keep = effect_path != path;
+            if !keep {
+                freed += texture_bytes(handle);
+            }
+            keep
+        });
+        let gif = self.gifs.remove(path);
+        if let Some(gif) = &gif {
+            freed += gif.frames.iter().map(texture_bytes).sum::<usize>();
+        }
+        let other = gif.is_some() || freed > 0;
+        self.memory -= freed;
+        let Some(entry) = self.textures.remove(path) else {
+            return other;
+        };
+        self.release(entry.hash);
+        true
+    }
+
+    /// Drops all cached textures and GIFs, e.g. when leaving a scene.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::texture::TextureManager;
+    /// let mut textures = TextureManager::new(&egui::Context::default());
+    /// textures.clear();
+    /// assert!(textures.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.textures.clear();
+        self.by_hash.clear();
+        self.gifs.clear();
+        self.effects.clear();
+        self.last_used.clear();
+        self.memory = 0;
+        #[cfg(feature = "hot-reload")]
+        if let Some(hot) = &mut self.hot_reload {
+            hot.files.clear();
+        }
+    }
+
+    /// Returns the number of cached textures, GIFs and effect variants.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::texture::TextureManager;
+    /// assert_eq!(TextureManager::new(&egui::Context::default()).len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.textures.len() + self.gifs.len() + self.effects.len()
+    }
+
+    /// Returns `true` if nothing is cached.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::texture::TextureManager;
+    /// assert!(TextureManager::new(&egui::Context::default()).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the approximate GPU memory of the cached textures in bytes (RGBA8), counting
+    /// shared textures once.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::{Color32, ColorImage};
+    /// # use deckbuilder_eng::texture::TextureManager;
+    /// let mut textures = TextureManager::new(&egui::Context::default());
+    /// textures.insert("a", ColorImage::new([4, 4], Color32::WHITE));
+    /// textures.insert("b", ColorImage::new([4, 4], Color32::WHITE));
+    /// assert_eq!(textures.memory_bytes(), 64);
+    /// // "b" keeps the shared texture when "a" is replaced
+    /// textures.insert("a", ColorImage::new([8, 8], Color32::RED));
+    /// assert_eq!(textures.memory_bytes(), 64 + 256);
+    /// ```
+    pub fn memory_bytes(&self) -> usize {
+        self.memory
+    }
+
+    /// Returns `true` if cached files are watched for changes (requires the `hot-reload`
+    /// feature; `false` if the platform's file watcher couldn't be started).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::texture::TextureManager;
+    /// let textures = TextureManager::new(&egui::Context::default());
+    /// println!("hot reload: {}", textures.is_hot_reloading());
+    /// ```
+    #[cfg(feature = "hot-reload")]
+    pub fn is_hot_reloading(&self) -> bool {
+        self.hot_reload.is_some()
+    }
+
+    /// Re-uploads the cached images, GIFs and effect variants whose files changed on disk and
+    /// returns their paths (requires the `hot-reload` feature). Images and effect variants keep
+    /// their handles, so widgets holding them update in place; GIFs get new frame handles.
+    ///
+    /// `load`, `load_gif` and `load_with_effect` call this automatically. A file that fails to
+    /// decode (e.g. while it is still being written) keeps its old texture.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::texture::TextureManager;
+    /// # fn demo(textures: &mut TextureManager) {
+    /// for path in textures.reload_changed() {
+    ///     println!("reloaded {}", path.display());
+    /// }
+    /// # }
+    /// ```
+    #[cfg(feature = "hot-reload")]
+    pub fn reload_changed(&mut self) -> Vec<PathBuf> {
+        let Some(hot) = &self.hot_reload else {
+            return Vec::new();
+        };
+        let mut changed: Vec<PathBuf> = hot.changes.try_iter().filter_map(|path| hot.files.get(&path).cloned()).collect();
+        changed.sort();
+        changed.dedup();
+        changed.retain(|path| self.reload(path));
+        changed
+    }
+
+    #[cfg(feature = "hot-reload")]
+    fn watch(&mut self, path: &Path) {
+        if let Some(hot) = &mut self.hot_reload {
+            hot.watch(path);
+        }
+    }
+
+    /// Decodes `path` again and updates its cached textures. Returns `true` if anything changed.
+    #[cfg(feature = "hot-reload")]
+    fn reload(&mut self, path: &Path) -> bool {
+        let Ok(bytes) = self.read_file(path) else {
+            return false;
+        };
+        let hash = content_hash(&bytes, &self.options);
+        let max_texture_side = self.ctx.input(|i| i.max_texture_side);
+        let name = path.to_string_lossy();
+        let mut reloaded = false;
+
+        if let Some(old_hash) = self.textures.get(path).map(|entry| entry.hash)
+            && old_hash != hash
+        {
+            let shared = self.by_hash.get(&old_hash).is_some_and(|shared| shared.users > 1);
+            let handle = match self.by_hash.get(&hash) {
+                Some(existing) => Some(existing.handle.clone()),
+                None => self.options.decode(&bytes, max_texture_side).ok().map(|image| match self.textures.get(path) {
+                    // Update the texture in place so handles held elsewhere show the new art.
+                    Some(entry) if !shared => {
+                        let mut handle = entry.handle.clone();
+                        handle.set(image, self.options.texture);
+                        handle
+                    }
+                    _ => self.ctx.load_texture(name.clone(), image, self.options.texture),
+                }),
+            };
+            if let Some(handle) = handle {
+                match self.by_hash.get_mut(&old_hash) {
+                    Some(old) if shared => old.users -= 1,
+                    _ => {
+                        self.by_hash.remove(&old_hash);
+                    }
+                }
+                let shared = SharedTexture { handle: handle.clone(), users: 0 };
+                self.by_hash.entry(hash).or_insert(shared).users += 1;
+                self.textures.insert(path.to_path_buf(), TextureEntry { handle, hash });
+                reloaded = true;
+            }
+        }
+
+        if self.gifs.contains_key(path)
+            && let Ok((frames, delays)) = load_animation_frames_from_bytes_with(&self.ctx, &name, &bytes, None, &self.options)
+        {
+            self.gifs.insert(path.to_path_buf(), GifEntry { frames, delays });
+            reloaded = true;
+        }
+
+        let variants: Vec<ImageEffect> = self
+            .effects
+            .keys()
+            .filter(|(effect_path, _)| effect_path == path)
+            .filter_map(|(_, key)| ImageEffect::from_cache_key(*key))
+            .collect();
+        if !variants.is_empty()
+            && let Ok(image) = self.options.decode(&bytes, max_texture_side)
+        {
+            for effect in variants {
+                if let Some(handle) = self.effects.get_mut(&(path.to_path_buf(), effect.cache_key())) {
+                    handle.set(effect.apply(&image), self.options.texture);
+                    reloaded = true;
+                }
+            }
+        }
+        if reloaded {
+            // Reloaded files may have changed size
+            let textures: usize = self.by_hash.values().map(|shared| texture_bytes(&shared.handle)).sum();
+            let gifs: usize = self.gifs.values().flat_map(|gif| &gif.frames).map(texture_bytes).sum();
+            let effects: usize = self.effects.values().map(texture_bytes).sum();
+            self.memory = textures + gifs + effects;
+        }
+        reloaded
+    }
+}
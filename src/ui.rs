@@ -19,8 +19,8 @@
 //!     let mut color = Color32::from_rgb(128, 64, 200);
 //!     let mut radio_selected = "A".to_string();
 //!     let radio_options = vec!["A".to_string(), "B".to_string(), "C".to_string()];
-//!     let mut popup_selected = String::from("None");
 //!     let popup_options = ["Option 1", "Option 2", "Option 3"];
+//!     let mut popup_selected = popup_options[0];
 //!
 //!     CentralPanel::default().show(ctx, |ui| {
 //!         ui_heading(ui, "Deckbuilder UI Example");
@@ -42,6 +42,7 @@
 //!         if clicked {
 //!             ui_label(ui, "Simple Checkbox clicked!");
 //!         }
+//!         checkbox_value = ui_toggle(ui, "Toggle", checkbox_value);
 //!         ui_separator(ui);
 //!
 //!         ui_slider(ui, "Slider", &mut slider_value, 0.0..=100.0);
@@ -106,14 +107,34 @@
 //! # Details
 //!
 //! - All functions are designed to be ergonomic and composable with egui's `Ui`.
-//! - Some helpers (like `ui_grid`, `ui_columns`) use unique IDs to avoid layout conflicts.
+//! - For access to the widget `Response` (tooltips, right-clicks, ...), use the builders in
+//!   [`crate::ui_builder`]. `ui_button_ex` returns the `Response` of a button that explains
+//!   why it is disabled when hovered.
+//! - `ui_grid`, `ui_scroll`, `ui_virtual_list` and `ui_columns` derive their ids from their
+//!   position in the layout. Use the `_id` variants with an explicit id source when the layout
+//!   before them can change between frames, so their state (column widths, scroll position)
+//!   persists.
+//! - Combo boxes and popup menus take any `ToString + Clone` options; use the `_id` variants
+//!   when several share a label.
+//! - Numeric helpers (`ui_slider`, `ui_drag_value`) accept any `egui::emath::Numeric` type;
+//!   the `_ex` variants take [`NumericOptions`] for step, suffix and clamping.
+//! - `with_badge` decorates any widget's `Response` with a corner counter badge.
+//! - `ui_label` shows its text as is; `ui_label_tr` and `ui_label_args` translate a message
+//!   key with the [`crate::i18n::I18n`] installed in the context.
 //! - See each function's documentation for usage and customization options.
 
+use std::fmt::Display;
+use std::hash::Hash;
+
 use egui::{
-    Button, Color32, ComboBox, Context, DragValue, FontId, Grid, RichText, ScrollArea,
-    Separator, Slider, TextEdit, TextStyle, Ui, Vec2,
+    color_picker::{self, Alpha},
+    emath::Numeric, Align2, Button, Color32, ComboBox, Context, DragValue, FontId, Grid, Id, Rect,
+    Response, RichText, Rounding, ScrollArea, Sense, Separator, Slider, Stroke, TextEdit, TextStyle,
+    Ui, Vec2,
 };
 
+use crate::i18n::{localize, localize_args};
+
 /// Heading (large text)
 ///
 /// # Example
@@ -142,6 +163,35 @@ pub fn ui_label(ui: &mut Ui, text: &str) {
     ui.label(text);
 }
 
+/// Label for a message key of the installed [`crate::i18n::I18n`]; a key without a
+/// translation is shown as is.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui::ui_label_tr;
+/// # fn demo(ui: &mut Ui) {
+/// ui_label_tr(ui, "menu-start");
+/// # }
+/// ```
+pub fn ui_label_tr(ui: &mut Ui, key: &str) {
+    ui.label(localize(ui.ctx(), key));
+}
+
+/// Label for a message key with `{ $name }` placeholders filled from `args`.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui::ui_label_args;
+/// # fn demo(ui: &mut Ui, gold: u32) {
+/// ui_label_args(ui, "hud-gold", &[("amount", &gold)]);
+/// # }
+/// ```
+pub fn ui_label_args(ui: &mut Ui, key: &str, args: &[(&str, &dyn Display)]) {
+    ui.label(localize_args(ui.ctx(), key, args));
+}
+
 /// Bold text
 ///
 /// # Example
@@ -202,6 +252,22 @@ pub fn ui_button<F: FnOnce()>(ui: &mut Ui, label: &str, on_click: F) {
     }
 }
 
+/// Button that can be disabled; hovering it while disabled shows `disabled_reason`.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui::ui_button_ex;
+/// # fn demo(ui: &mut Ui, energy: u32) {
+/// if ui_button_ex(ui, "Play", energy > 0, "Not enough energy").clicked() {
+///     println!("Card played!");
+/// }
+/// # }
+/// ```
+pub fn ui_button_ex(ui: &mut Ui, label: &str, enabled: bool, disabled_reason: &str) -> Response {
+    ui.add_enabled(enabled, Button::new(label)).on_disabled_hover_text(disabled_reason)
+}
+
 /// Checkbox / Toggle
 ///
 /// # Example
@@ -217,25 +283,66 @@ pub fn ui_checkbox(ui: &mut Ui, label: &str, checked: &mut bool) {
     ui.checkbox(checked, label);
 }
 
-/// Slider (f32)
+/// Options for [`ui_slider_ex`] and [`ui_drag_value_ex`].
+#[derive(Clone, Default)]
+pub struct NumericOptions {
+    /// Round the value to multiples of this step.
+    pub step: Option<f64>,
+    /// Text shown after the number, e.g. `" gold"` or `"%"`.
+    pub suffix: String,
+    /// Drag speed per point of mouse movement (drag value only; `None` uses egui's default).
+    pub speed: Option<f64>,
+    /// Clamp the value to this range (drag value only; sliders always clamp to their range).
+    pub range: Option<std::ops::RangeInclusive<f64>>,
+}
+
+/// Slider for any numeric type (`f32`, `f64`, `u32`, `i64`, ...)
 ///
 /// # Example
 /// ```rust
 /// # use egui::Ui;
 /// # use deckbuilder_eng::ui::ui_slider;
 /// # fn demo(ui: &mut Ui) {
-/// let mut value = 0.5;
+/// let mut value = 0.5_f32;
 /// ui_slider(ui, "Value", &mut value, 0.0..=1.0);
+/// let mut energy = 3_u32;
+/// ui_slider(ui, "Energy", &mut energy, 0..=10);
+/// # }
+/// ```
+pub fn ui_slider<N: Numeric>(ui: &mut Ui, label: &str, value: &mut N, range: std::ops::RangeInclusive<N>) {
+    ui_slider_ex(ui, label, value, range, &NumericOptions::default());
+}
+
+/// Slider with step and suffix options
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui::{ui_slider_ex, NumericOptions};
+/// # fn demo(ui: &mut Ui) {
+/// let mut volume = 80.0_f64;
+/// let options = NumericOptions { step: Some(5.0), suffix: "%".into(), ..Default::default() };
+/// ui_slider_ex(ui, "Volume", &mut volume, 0.0..=100.0, &options);
 /// # }
 /// ```
-pub fn ui_slider(ui: &mut Ui, label: &str, value: &mut f32, range: std::ops::RangeInclusive<f32>) {
+pub fn ui_slider_ex<N: Numeric>(
+    ui: &mut Ui,
+    label: &str,
+    value: &mut N,
+    range: std::ops::RangeInclusive<N>,
+    options: &NumericOptions,
+) {
     ui.horizontal(|ui| {
         ui.label(label);
-        ui.add(Slider::new(value, range));
+        let mut slider = Slider::new(value, range).suffix(options.suffix.as_str());
+        if let Some(step) = options.step {
+            slider = slider.step_by(step);
+        }
+        ui.add(slider);
     });
 }
 
-/// DragValue (increment/decrement number)
+/// DragValue for any numeric type (increment/decrement number)
 ///
 /// # Example
 /// ```rust
@@ -244,12 +351,46 @@ pub fn ui_slider(ui: &mut Ui, label: &str, value: &mut f32, range: std::ops::Ran
 /// # fn demo(ui: &mut Ui) {
 /// let mut value = 10;
 /// ui_drag_value(ui, "Drag", &mut value);
+/// let mut gold = 250_i64;
+/// ui_drag_value(ui, "Gold", &mut gold);
 /// # }
 /// ```
-pub fn ui_drag_value(ui: &mut Ui, label: &str, value: &mut i32) {
+pub fn ui_drag_value<N: Numeric>(ui: &mut Ui, label: &str, value: &mut N) {
+    ui_drag_value_ex(ui, label, value, &NumericOptions::default());
+}
+
+/// DragValue with step, suffix, speed and clamping options
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui::{ui_drag_value_ex, NumericOptions};
+/// # fn demo(ui: &mut Ui) {
+/// let mut gold = 99_u32;
+/// let options = NumericOptions { suffix: " gold".into(), range: Some(0.0..=999.0), ..Default::default() };
+/// ui_drag_value_ex(ui, "Gold", &mut gold, &options);
+/// # }
+/// ```
+pub fn ui_drag_value_ex<N: Numeric>(ui: &mut Ui, label: &str, value: &mut N, options: &NumericOptions) {
     ui.horizontal(|ui| {
         ui.label(label);
-        ui.add(DragValue::new(value));
+        let mut drag = DragValue::new(value).suffix(options.suffix.as_str());
+        if let Some(speed) = options.speed {
+            drag = drag.speed(speed);
+        }
+        if let Some(range) = &options.range {
+            drag = drag.clamp_range(range.clone());
+        }
+        // DragValue has no step option, so snap the value after editing.
+        if ui.add(drag).changed()
+            && let Some(step) = options.step.filter(|s| *s > 0.0)
+        {
+            let mut snapped = (value.to_f64() / step).round() * step;
+            if let Some(range) = &options.range {
+                snapped = snapped.clamp(*range.start(), *range.end());
+            }
+            *value = N::from_f64(snapped);
+        }
     });
 }
 
@@ -319,11 +460,37 @@ pub fn ui_combo_box<T: ToString + PartialEq + Clone>(
     options: &[T],
     selected: &mut T,
 ) {
-    ComboBox::from_label(label).show_ui(ui, |ui| {
-        for option in options {
-            ui.selectable_value(selected, option.clone(), option.to_string());
-        }
-    });
+    ui_combo_box_id(ui, label, label, options, selected);
+}
+
+/// ComboBox with an explicit id source, for several combo boxes sharing a label
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui::ui_combo_box_id;
+/// # fn demo(ui: &mut Ui) {
+/// let options = [1, 2, 3];
+/// let mut left = 1;
+/// let mut right = 2;
+/// ui_combo_box_id(ui, "left_slot", "Slot", &options, &mut left);
+/// ui_combo_box_id(ui, "right_slot", "Slot", &options, &mut right);
+/// # }
+/// ```
+pub fn ui_combo_box_id<T: ToString + PartialEq + Clone>(
+    ui: &mut Ui,
+    id_source: impl Hash,
+    label: &str,
+    options: &[T],
+    selected: &mut T,
+) {
+    ComboBox::new(id_source, label)
+        .selected_text(selected.to_string())
+        .show_ui(ui, |ui| {
+            for option in options {
+                ui.selectable_value(selected, option.clone(), option.to_string());
+            }
+        });
 }
 
 /// Grid (table view)
@@ -340,12 +507,28 @@ pub fn ui_combo_box<T: ToString + PartialEq + Clone>(
 /// # }
 /// ```
 pub fn ui_grid<F: FnOnce(&mut Ui)>(ui: &mut Ui, f: F) {
-    // Give a different id for each call (e.g. random, non-static string or via parameter)
-    use std::sync::atomic::{AtomicUsize, Ordering};
-    static GRID_ID: AtomicUsize = AtomicUsize::new(0);
-    let id = GRID_ID.fetch_add(1, Ordering::Relaxed);
-    let grid = Grid::new(format!("custom_grid_{}", id));
-    grid.show(ui, |ui_grid| {
+    // The next auto id is stable between frames as long as the layout doesn't change.
+    let id = ui.next_auto_id();
+    ui_grid_id(ui, id, f);
+}
+
+/// Grid with an explicit id source, so its state (column widths) persists even when the
+/// surrounding layout changes
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui::ui_grid_id;
+/// # fn demo(ui: &mut Ui) {
+/// ui_grid_id(ui, "deck_stats", |grid| {
+///     grid.label("Cards");
+///     grid.label("20");
+///     grid.end_row();
+/// });
+/// # }
+/// ```
+pub fn ui_grid_id<F: FnOnce(&mut Ui)>(ui: &mut Ui, id_source: impl Hash, f: F) {
+    Grid::new(id_source).show(ui, |ui_grid| {
         f(ui_grid);
     });
 }
@@ -365,11 +548,74 @@ pub fn ui_grid<F: FnOnce(&mut Ui)>(ui: &mut Ui, f: F) {
 /// # }
 /// ```
 pub fn ui_scroll<F: FnOnce(&mut Ui)>(ui: &mut Ui, inner: F) {
-    ScrollArea::vertical().show(ui, |inner_ui| {
+    let id = ui.next_auto_id();
+    ui_scroll_id(ui, id, inner);
+}
+
+/// Scrollable vertical area with an explicit id source, so the scroll position persists
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui::ui_scroll_id;
+/// # fn demo(ui: &mut Ui) {
+/// ui_scroll_id(ui, "combat_log", |inner| {
+///     inner.label("Enemy attacks for 6");
+/// });
+/// # }
+/// ```
+pub fn ui_scroll_id<F: FnOnce(&mut Ui)>(ui: &mut Ui, id_source: impl Hash, inner: F) {
+    ScrollArea::vertical().id_source(id_source).show(ui, |inner_ui| {
         inner(inner_ui);
     });
 }
 
+/// Scrollable list that only lays out the visible items, for large collections. All items
+/// must have the same height (`item_height`, without item spacing).
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui::ui_virtual_list;
+/// # fn demo(ui: &mut Ui, names: &[String]) {
+/// ui_virtual_list(ui, names.len(), 18.0, |ui, index| {
+///     ui.label(&names[index]);
+/// });
+/// # }
+/// ```
+pub fn ui_virtual_list<F: FnMut(&mut Ui, usize)>(ui: &mut Ui, item_count: usize, item_height: f32, add_item: F) {
+    let id = ui.next_auto_id();
+    ui_virtual_list_id(ui, id, item_count, item_height, add_item);
+}
+
+/// Virtualized list with an explicit id source, so the scroll position persists
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui::ui_virtual_list_id;
+/// # fn demo(ui: &mut Ui) {
+/// ui_virtual_list_id(ui, "collection", 2000, 18.0, |ui, index| {
+///     ui.label(format!("Card #{}", index));
+/// });
+/// # }
+/// ```
+pub fn ui_virtual_list_id<F: FnMut(&mut Ui, usize)>(
+    ui: &mut Ui,
+    id_source: impl Hash,
+    item_count: usize,
+    item_height: f32,
+    mut add_item: F,
+) {
+    ScrollArea::vertical()
+        .id_source(id_source)
+        .show_rows(ui, item_height, item_count, |ui, range| {
+            for index in range {
+                add_item(ui, index);
+            }
+        });
+}
+
 /// Two-column layout
 ///
 /// # Example
@@ -384,14 +630,29 @@ pub fn ui_scroll<F: FnOnce(&mut Ui)>(ui: &mut Ui, inner: F) {
 /// # }
 /// ```
 pub fn ui_columns<F: FnOnce(&mut Ui, &mut Ui)>(ui: &mut Ui, content: F) {
-    use std::sync::atomic::{AtomicUsize, Ordering};
-    static COL_ID: AtomicUsize = AtomicUsize::new(0);
-    let id = COL_ID.fetch_add(1, Ordering::Relaxed);
-    ui.columns(2, |columns| {
-        let (left, right) = columns.split_at_mut(1);
-        // Even if we don't use id, it ensures unique call for columns
-        let _ = id;
-        content(&mut left[0], &mut right[0]);
+    let id = ui.next_auto_id();
+    ui_columns_id(ui, id, content);
+}
+
+/// Two-column layout whose widget ids are salted with `id_source`
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui::ui_columns_id;
+/// # fn demo(ui: &mut Ui) {
+/// ui_columns_id(ui, "compare", |left, right| {
+///     left.label("Before");
+///     right.label("After");
+/// });
+/// # }
+/// ```
+pub fn ui_columns_id<F: FnOnce(&mut Ui, &mut Ui)>(ui: &mut Ui, id_source: impl Hash, content: F) {
+    ui.push_id(id_source, |ui| {
+        ui.columns(2, |columns| {
+            let (left, right) = columns.split_at_mut(1);
+            content(&mut left[0], &mut right[0]);
+        });
     });
 }
 
@@ -428,7 +689,8 @@ pub fn ui_sized_button<F: FnOnce()>(ui: &mut Ui, label: &str, size: Vec2, on_cli
     }
 }
 
-/// Simple checkbox (returns true if clicked)
+/// Simple checkbox (returns true if clicked). The checkbox shows `checked` but does not
+/// change it; use [`ui_toggle`] to get the new value back.
 ///
 /// # Example
 /// ```rust
@@ -443,6 +705,22 @@ pub fn ui_checkbox_simple(ui: &mut Ui, label: &str, checked: bool) -> bool {
     ui.checkbox(&mut checked.clone(), label).clicked()
 }
 
+/// Checkbox that takes the current value and returns the updated one
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui::ui_toggle;
+/// # fn demo(ui: &mut Ui, mut show_intents: bool) {
+/// show_intents = ui_toggle(ui, "Show enemy intents", show_intents);
+/// # }
+/// ```
+pub fn ui_toggle(ui: &mut Ui, label: &str, value: bool) -> bool {
+    let mut value = value;
+    ui.checkbox(&mut value, label);
+    value
+}
+
 /// Returns the context (e.g. theme, clipboard, etc.)
 ///
 /// # Example
@@ -500,7 +778,32 @@ pub fn ui_radio_buttons<T: ToString + PartialEq + Clone>(
     });
 }
 
-/// RGBA color-picker using four sliders
+/// Options for [`ui_color_picker_ex`].
+#[derive(Clone)]
+pub struct ColorPickerOptions {
+    /// Allow editing the alpha channel.
+    pub alpha: bool,
+    /// Show a hex input field (`#rrggbb` or `#rrggbbaa`).
+    pub hex_input: bool,
+    /// Preset swatches shown below the picker; click one to pick it.
+    pub swatches: Vec<Color32>,
+    /// Show a button that saves the current color as a swatch. Saved swatches are shared by
+    /// all pickers and kept in egui memory.
+    pub allow_saving: bool,
+}
+
+impl Default for ColorPickerOptions {
+    fn default() -> Self {
+        Self {
+            alpha: true,
+            hex_input: true,
+            swatches: Vec::new(),
+            allow_saving: false,
+        }
+    }
+}
+
+/// Color picker (hue/saturation square, value and alpha sliders) with a hex input field
 ///
 /// # Example
 /// ```rust
@@ -512,6 +815,91 @@ pub fn ui_radio_buttons<T: ToString + PartialEq + Clone>(
 /// # }
 /// ```
 pub fn ui_color_picker(ui: &mut Ui, label: &str, color: &mut Color32) {
+    ui_color_picker_ex(ui, label, color, &ColorPickerOptions::default());
+}
+
+/// Color picker with alpha, hex input and swatch options. Returns `true` if the color changed.
+///
+/// # Example
+/// ```rust
+/// # use egui::{Ui, Color32};
+/// # use deckbuilder_eng::ui::{ui_color_picker_ex, ColorPickerOptions};
+/// # fn demo(ui: &mut Ui, color: &mut Color32) {
+/// let options = ColorPickerOptions {
+///     alpha: false,
+///     swatches: vec![Color32::RED, Color32::GOLD, Color32::LIGHT_BLUE],
+///     allow_saving: true,
+///     ..Default::default()
+/// };
+/// if ui_color_picker_ex(ui, "Card frame", color, &options) {
+///     println!("New color: {}", color.to_hex());
+/// }
+/// # }
+/// ```
+pub fn ui_color_picker_ex(ui: &mut Ui, label: &str, color: &mut Color32, options: &ColorPickerOptions) -> bool {
+    let id = ui.make_persistent_id(("color_picker", label));
+    let alpha = if options.alpha { Alpha::BlendOrAdditive } else { Alpha::Opaque };
+    ui.label(label);
+    let mut changed = color_picker::color_picker_color32(ui, color, alpha);
+
+    if options.hex_input {
+        ui.horizontal(|ui| {
+            ui.label("Hex:");
+            // While editing, keep the typed text; otherwise show the current color.
+            let hex_id = id.with("hex");
+            let mut text = ui
+                .data(|d| d.get_temp::<String>(hex_id))
+                .filter(|_| ui.memory(|m| m.has_focus(hex_id)))
+                .unwrap_or_else(|| color.to_hex());
+            let response = ui.add(TextEdit::singleline(&mut text).id(hex_id).desired_width(90.0));
+            if response.changed() {
+                let trimmed = text.trim();
+                let hex = if trimmed.starts_with('#') { trimmed.to_owned() } else { format!("#{}", trimmed) };
+                if let Ok(parsed) = Color32::from_hex(&hex) {
+                    *color = if options.alpha { parsed } else { parsed.to_opaque() };
+                    changed = true;
+                }
+            }
+            ui.data_mut(|d| d.insert_temp(hex_id, text));
+        });
+    }
+
+    let saved_id = Id::new("deckbuilder_saved_swatches");
+    let saved: Vec<Color32> = ui.data(|d| d.get_temp(saved_id)).unwrap_or_default();
+    if !options.swatches.is_empty() || options.allow_saving {
+        ui.horizontal_wrapped(|ui| {
+            for swatch in options.swatches.iter().chain(saved.iter()) {
+                let response = color_picker::show_color(ui, *swatch, Vec2::splat(18.0))
+                    .interact(Sense::click())
+                    .on_hover_text(swatch.to_hex());
+                if response.clicked() && *color != *swatch {
+                    *color = *swatch;
+                    changed = true;
+                }
+            }
+            let save = options.allow_saving && ui.small_button("+").on_hover_text("Save swatch").clicked();
+            if save && !saved.contains(color) {
+                let mut saved = saved.clone();
+                saved.push(*color);
+                ui.data_mut(|d| d.insert_temp(saved_id, saved));
+            }
+        });
+    }
+    changed
+}
+
+/// RGBA color editor using four sliders (the previous `ui_color_picker`)
+///
+/// # Example
+/// ```rust
+/// # use egui::{Ui, Color32};
+/// # use deckbuilder_eng::ui::ui_color_sliders;
+/// # fn demo(ui: &mut Ui) {
+/// let mut color = Color32::from_rgb(128, 64, 200);
+/// ui_color_sliders(ui, "Color", &mut color);
+/// # }
+/// ```
+pub fn ui_color_sliders(ui: &mut Ui, label: &str, color: &mut Color32) {
     ui.label(label);
     let mut r = color.r() as f32;
     let mut g = color.g() as f32;
@@ -565,18 +953,97 @@ pub fn ui_tooltip(ui: &mut Ui, hover_text: &str) {
 /// # use egui::Ui;
 /// # use deckbuilder_eng::ui::ui_popup_menu;
 /// # fn demo(ui: &mut Ui) {
-/// let mut selected = String::from("None");
 /// let options = ["Option 1", "Option 2"];
+/// let mut selected = options[0];
 /// ui_popup_menu(ui, "Menu", &options, &mut selected);
 /// # }
 /// ```
-pub fn ui_popup_menu(ui: &mut Ui, button_label: &str, options: &[&str], selected: &mut String) {
+pub fn ui_popup_menu<T: ToString + Clone>(ui: &mut Ui, button_label: &str, options: &[T], selected: &mut T) {
     ui.menu_button(button_label, |ui| {
-        for &opt in options {
-            if ui.button(opt).clicked() {
-                *selected = opt.to_string();
+        for opt in options {
+            if ui.button(opt.to_string()).clicked() {
+                *selected = opt.clone();
                 ui.close_menu();
             }
         }
     });
 }
+
+/// Popup menu whose widget ids are salted with `id_source`, for menus sharing a label
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui::ui_popup_menu_id;
+/// # fn demo(ui: &mut Ui) {
+/// let targets = vec!["Slime".to_string(), "Cultist".to_string()];
+/// let mut target = targets[0].clone();
+/// ui_popup_menu_id(ui, "target_menu", "Target", &targets, &mut target);
+/// # }
+/// ```
+pub fn ui_popup_menu_id<T: ToString + Clone>(
+    ui: &mut Ui,
+    id_source: impl Hash,
+    button_label: &str,
+    options: &[T],
+    selected: &mut T,
+) {
+    ui.push_id(id_source, |ui| ui_popup_menu(ui, button_label, options, selected));
+}
+
+/// Look of a [`with_badge`] counter.
+#[derive(Debug, Clone)]
+pub struct BadgeStyle {
+    /// Corner of the widget the badge is centered on.
+    pub anchor: Align2,
+    /// Background color.
+    pub fill: Color32,
+    /// Text color.
+    pub text_color: Color32,
+    /// Badge radius (half its height) in points.
+    pub radius: f32,
+    /// Counts above this are shown as e.g. `99+`.
+    pub max: u32,
+    /// Show the badge when the count is zero.
+    pub show_zero: bool,
+}
+
+impl Default for BadgeStyle {
+    fn default() -> Self {
+        Self {
+            anchor: Align2::RIGHT_TOP,
+            fill: Color32::from_rgb(200, 50, 50),
+            text_color: Color32::WHITE,
+            radius: 8.0,
+            max: 99,
+            show_zero: false,
+        }
+    }
+}
+
+/// Draws a small counter badge on a corner of a widget, e.g. the number of unseen cards on
+/// the Deck button. Returns the widget's response for chaining.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui::{with_badge, BadgeStyle};
+/// # fn demo(ui: &mut Ui, unseen: u32) {
+/// if with_badge(ui.button("Deck"), unseen, &BadgeStyle::default()).clicked() {
+///     println!("Open deck");
+/// }
+/// # }
+/// ```
+pub fn with_badge(response: Response, count: u32, style: &BadgeStyle) -> Response {
+    if count == 0 && !style.show_zero {
+        return response;
+    }
+    let text = if count > style.max { format!("{}+", style.max) } else { count.to_string() };
+    let painter = response.ctx.layer_painter(response.layer_id);
+    let galley = painter.layout_no_wrap(text, FontId::proportional(style.radius * 1.3), style.text_color);
+    let size = Vec2::new((galley.size().x + style.radius).max(style.radius * 2.0), style.radius * 2.0);
+    let badge = Rect::from_center_size(style.anchor.pos_in_rect(&response.rect), size);
+    painter.rect(badge, Rounding::same(style.radius), style.fill, Stroke::new(1.0, Color32::from_black_alpha(120)));
+    painter.galley(badge.center() - galley.size() / 2.0, galley, style.text_color);
+    response
+}
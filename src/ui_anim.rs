@@ -0,0 +1,535 @@
+//! Tween/animation helpers for egui-based deckbuilder UIs.
+//!
+//! Provides an [`Animator`] that smoothly interpolates `f32`, `Vec2`, `Pos2`, `Color32` and
+//! `Rect` values towards a target over time, keyed by egui `Id`, plus easing functions and
+//! [`ui_animated_number`], a label that counts up or down to its value. [`shake`] and [`flash`]
+//! trigger hit feedback that widgets pick up with [`shake_offset`] and [`flash_color`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::ui_anim::{animate_rect, Animator, Easing};
+//! use egui::{CentralPanel, Color32, Context, Id, Rect, Pos2, Vec2};
+//!
+//! fn my_ui(ctx: &Context, highlighted: bool) {
+//!     CentralPanel::default().show(ctx, |ui| {
+//!         let animator = Animator::new(0.3).easing(Easing::EaseOutBack);
+//!         let size = animator.animate(ctx, Id::new("card_size"), if highlighted { 1.2 } else { 1.0 });
+//!         let color = animator.animate(
+//!             ctx,
+//!             Id::new("card_glow"),
+//!             if highlighted { Color32::GOLD } else { Color32::TRANSPARENT },
+//!         );
+//!         let rect = animate_rect(
+//!             ctx,
+//!             Id::new("card_rect"),
+//!             Rect::from_min_size(Pos2::new(100.0, 100.0), Vec2::new(140.0, 200.0) * size),
+//!             0.2,
+//!             Easing::EaseOut,
+//!         );
+//!         ui.painter().rect_stroke(rect, 8.0, (3.0, color));
+//!     });
+//! }
+//! ```
+//!
+//! # Details
+//!
+//! - Animation state lives in egui's temporary memory, one entry per `Id` and value type.
+//! - When the target changes mid-animation, the new tween starts from the current value.
+//! - Repaints are requested only while a value is animating.
+//! - `ui_animated_number` formats with [`format_number`]; see [`NumberStyle`] for the options.
+//! - Shake and flash are keyed by the widget's `Id` (e.g. `response.id`) and decay linearly.
+//! - See each function's documentation for usage and customization options.
+
+use egui::{Color32, Context, FontId, Id, Label, Pos2, Rect, Response, RichText, Ui, Vec2};
+
+/// Easing curves mapping linear progress `t` in `0..=1` to eased progress.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Easing {
+    /// Constant speed.
+    Linear,
+    /// Starts slow, speeds up (quadratic).
+    EaseIn,
+    /// Starts fast, slows down (cubic).
+    #[default]
+    EaseOut,
+    /// Slow at both ends (cubic).
+    EaseInOut,
+    /// Overshoots the target slightly and settles back; good for card draws.
+    EaseOutBack,
+    /// Bounces at the end like a dropped object.
+    EaseOutBounce,
+}
+
+impl Easing {
+    /// Applies the curve to `t`, which is clamped to `0..=1`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_anim::Easing;
+    /// assert_eq!(Easing::Linear.apply(0.5), 0.5);
+    /// assert_eq!(Easing::EaseOut.apply(1.0), 1.0);
+    /// ```
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+            Easing::EaseOutBack => {
+                let c1 = 1.70158;
+                let c3 = c1 + 1.0;
+                1.0 + c3 * (t - 1.0).powi(3) + c1 * (t - 1.0).powi(2)
+            }
+            Easing::EaseOutBounce => {
+                let n1 = 7.5625;
+                let d1 = 2.75;
+                if t < 1.0 / d1 {
+                    n1 * t * t
+                } else if t < 2.0 / d1 {
+                    let t = t - 1.5 / d1;
+                    n1 * t * t + 0.75
+                } else if t < 2.5 / d1 {
+                    let t = t - 2.25 / d1;
+                    n1 * t * t + 0.9375
+                } else {
+                    let t = t - 2.625 / d1;
+                    n1 * t * t + 0.984375
+                }
+            }
+        }
+    }
+}
+
+/// Values that can be linearly interpolated by the [`Animator`].
+pub trait Lerp: Clone + PartialEq + Send + Sync + 'static {
+    /// Interpolates between `self` (at `t = 0`) and `other` (at `t = 1`).
+    /// `t` may leave `0..=1` for overshooting easings.
+    fn lerp(&self, other: &Self, t: f32) -> Self;
+}
+
+impl Lerp for f32 {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Lerp for f64 {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        self + (other - self) * t as f64
+    }
+}
+
+impl Lerp for Vec2 {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        *self + (*other - *self) * t
+    }
+}
+
+impl Lerp for Pos2 {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        *self + (*other - *self) * t
+    }
+}
+
+impl Lerp for Rect {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        Rect::from_min_max(Lerp::lerp(&self.min, &other.min, t), Lerp::lerp(&self.max, &other.max, t))
+    }
+}
+
+impl Lerp for Color32 {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        let channel = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round().clamp(0.0, 255.0) as u8;
+        Color32::from_rgba_premultiplied(
+            channel(self.r(), other.r()),
+            channel(self.g(), other.g()),
+            channel(self.b(), other.b()),
+            channel(self.a(), other.a()),
+        )
+    }
+}
+
+/// Remembered tween of one animated value.
+#[derive(Clone)]
+struct Tween<T> {
+    from: T,
+    to: T,
+    start: f64,
+}
+
+/// Interpolates values towards their targets over `duration` seconds with an easing curve.
+#[derive(Debug, Clone, Copy)]
+pub struct Animator {
+    duration: f32,
+    easing: Easing,
+}
+
+impl Default for Animator {
+    fn default() -> Self {
+        Self {
+            duration: 0.25,
+            easing: Easing::default(),
+        }
+    }
+}
+
+impl Animator {
+    /// Creates an animator with the given duration in seconds and ease-out easing.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_anim::Animator;
+    /// let animator = Animator::new(0.3);
+    /// ```
+    pub fn new(duration: f32) -> Self {
+        Self {
+            duration,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the easing curve.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_anim::{Animator, Easing};
+    /// let animator = Animator::new(0.4).easing(Easing::EaseOutBack);
+    /// ```
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Returns the current animated value for `id`, moving towards `target`.
+    /// The first call for an `id` returns `target` immediately.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::{Context, Id, Vec2};
+    /// # use deckbuilder_eng::ui_anim::Animator;
+    /// # fn demo(ctx: &Context, hovered: bool) {
+    /// let offset = Animator::new(0.2).animate(
+    ///     ctx,
+    ///     Id::new("lift"),
+    ///     if hovered { Vec2::new(0.0, -30.0) } else { Vec2::ZERO },
+    /// );
+    /// # }
+    /// ```
+    pub fn animate<T: Lerp>(&self, ctx: &Context, id: Id, target: T) -> T {
+        let now = ctx.input(|i| i.time);
+        let tween = ctx.data(|d| d.get_temp::<Tween<T>>(id));
+        let tween = match tween {
+            None => Tween { from: target.clone(), to: target.clone(), start: now },
+            Some(tween) if tween.to != target => {
+                // Retarget from wherever the value currently is.
+                let current = self.value_at(&tween, now);
+                Tween { from: current, to: target.clone(), start: now }
+            }
+            Some(tween) => tween,
+        };
+        let value = self.value_at(&tween, now);
+        if now - tween.start < self.duration as f64 {
+            ctx.request_repaint();
+        }
+        ctx.data_mut(|d| d.insert_temp(id, tween));
+        value
+    }
+
+    /// Jumps the value of `id` to `value` without animating.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::{Context, Id};
+    /// # use deckbuilder_eng::ui_anim::Animator;
+    /// # fn demo(ctx: &Context) {
+    /// Animator::default().set(ctx, Id::new("card_size"), 1.0_f32);
+    /// # }
+    /// ```
+    pub fn set<T: Lerp>(&self, ctx: &Context, id: Id, value: T) {
+        let now = ctx.input(|i| i.time);
+        ctx.data_mut(|d| d.insert_temp(id, Tween { from: value.clone(), to: value, start: now }));
+    }
+
+    fn value_at<T: Lerp>(&self, tween: &Tween<T>, now: f64) -> T {
+        if self.duration <= 0.0 {
+            return tween.to.clone();
+        }
+        let t = ((now - tween.start) as f32 / self.duration).clamp(0.0, 1.0);
+        if t >= 1.0 {
+            tween.to.clone()
+        } else {
+            tween.from.lerp(&tween.to, self.easing.apply(t))
+        }
+    }
+}
+
+/// Animates an `f32` towards `target`.
+///
+/// # Example
+/// ```rust
+/// # use egui::{Context, Id};
+/// # use deckbuilder_eng::ui_anim::{animate_value, Easing};
+/// # fn demo(ctx: &Context, hovered: bool) {
+/// let scale = animate_value(ctx, Id::new("scale"), if hovered { 1.15 } else { 1.0 }, 0.15, Easing::EaseOut);
+/// # }
+/// ```
+pub fn animate_value(ctx: &Context, id: Id, target: f32, duration: f32, easing: Easing) -> f32 {
+    Animator::new(duration).easing(easing).animate(ctx, id, target)
+}
+
+/// Animates a position towards `target`.
+///
+/// # Example
+/// ```rust
+/// # use egui::{Context, Id, Pos2};
+/// # use deckbuilder_eng::ui_anim::{animate_pos, Easing};
+/// # fn demo(ctx: &Context) {
+/// let pos = animate_pos(ctx, Id::new("deck_anchor"), Pos2::new(40.0, 500.0), 0.3, Easing::EaseInOut);
+/// # }
+/// ```
+pub fn animate_pos(ctx: &Context, id: Id, target: Pos2, duration: f32, easing: Easing) -> Pos2 {
+    Animator::new(duration).easing(easing).animate(ctx, id, target)
+}
+
+/// Animates a color towards `target`.
+///
+/// # Example
+/// ```rust
+/// # use egui::{Color32, Context, Id};
+/// # use deckbuilder_eng::ui_anim::{animate_color, Easing};
+/// # fn demo(ctx: &Context, selected: bool) {
+/// let glow = animate_color(
+///     ctx,
+///     Id::new("glow"),
+///     if selected { Color32::GOLD } else { Color32::TRANSPARENT },
+///     0.2,
+///     Easing::Linear,
+/// );
+/// # }
+/// ```
+pub fn animate_color(ctx: &Context, id: Id, target: Color32, duration: f32, easing: Easing) -> Color32 {
+    Animator::new(duration).easing(easing).animate(ctx, id, target)
+}
+
+/// Animates a rectangle (position and size) towards `target`, e.g. a card moving from the
+/// draw pile into the hand.
+///
+/// # Example
+/// ```rust
+/// # use egui::{Context, Id, Rect, Pos2, Vec2};
+/// # use deckbuilder_eng::ui_anim::{animate_rect, Easing};
+/// # fn demo(ctx: &Context) {
+/// let target = Rect::from_min_size(Pos2::new(300.0, 400.0), Vec2::new(140.0, 200.0));
+/// let rect = animate_rect(ctx, Id::new(("card", 7)), target, 0.35, Easing::EaseOutBack);
+/// # }
+/// ```
+pub fn animate_rect(ctx: &Context, id: Id, target: Rect, duration: f32, easing: Easing) -> Rect {
+    Animator::new(duration).easing(easing).animate(ctx, id, target)
+}
+
+/// Formatting and timing of [`ui_animated_number`].
+#[derive(Debug, Clone)]
+pub struct NumberStyle {
+    /// Seconds to count from the old value to a new one.
+    pub duration: f32,
+    /// Easing of the count.
+    pub easing: Easing,
+    /// Digit group separator, e.g. `Some(',')` for "12,500".
+    pub thousands_separator: Option<char>,
+    /// Show a `+` in front of positive values.
+    pub show_plus: bool,
+    /// Color positive values with `positive_color` and negative ones with `negative_color`.
+    pub color_sign: bool,
+    pub positive_color: Color32,
+    pub negative_color: Color32,
+    /// Text color when `color_sign` is off or the value is 0; `None` uses the theme.
+    pub color: Option<Color32>,
+    pub prefix: String,
+    pub suffix: String,
+    pub font_size: f32,
+}
+
+impl Default for NumberStyle {
+    fn default() -> Self {
+        Self {
+            duration: 0.6,
+            easing: Easing::EaseOut,
+            thousands_separator: Some(','),
+            show_plus: false,
+            color_sign: false,
+            positive_color: Color32::from_rgb(110, 210, 110),
+            negative_color: Color32::from_rgb(230, 90, 80),
+            color: None,
+            prefix: String::new(),
+            suffix: String::new(),
+            font_size: 16.0,
+        }
+    }
+}
+
+/// Formats `value` with the sign, separator, prefix and suffix of `style`.
+///
+/// # Example
+/// ```rust
+/// # use deckbuilder_eng::ui_anim::{format_number, NumberStyle};
+/// let style = NumberStyle { show_plus: true, suffix: " gold".to_owned(), ..Default::default() };
+/// assert_eq!(format_number(12500, &style), "+12,500 gold");
+/// assert_eq!(format_number(-7, &style), "-7 gold");
+/// ```
+pub fn format_number(value: i64, style: &NumberStyle) -> String {
+    let digits = value.unsigned_abs().to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, digit) in digits.chars().enumerate() {
+        if let Some(separator) = style.thousands_separator
+            && i > 0
+            && (digits.len() - i).is_multiple_of(3)
+        {
+            grouped.push(separator);
+        }
+        grouped.push(digit);
+    }
+    let sign = if value < 0 {
+        "-"
+    } else if value > 0 && style.show_plus {
+        "+"
+    } else {
+        ""
+    };
+    format!("{}{}{}{}", style.prefix, sign, grouped, style.suffix)
+}
+
+/// Number label that counts towards `target` whenever it changes (gold gained, score
+/// totals). The first frame shows `target` directly.
+///
+/// # Example
+/// ```rust
+/// # use egui::{Id, Ui};
+/// # use deckbuilder_eng::ui_anim::{ui_animated_number, NumberStyle};
+/// # fn demo(ui: &mut Ui, gold: i64) {
+/// let style = NumberStyle { suffix: " gold".to_owned(), ..Default::default() };
+/// ui_animated_number(ui, Id::new("gold"), gold, &style);
+/// # }
+/// ```
+pub fn ui_animated_number(ui: &mut Ui, id: Id, target: i64, style: &NumberStyle) -> Response {
+    let animator = Animator::new(style.duration).easing(style.easing);
+    let value = animator.animate(ui.ctx(), id, target as f64).round() as i64;
+    let color = match value.signum() {
+        1 if style.color_sign => Some(style.positive_color),
+        -1 if style.color_sign => Some(style.negative_color),
+        _ => style.color,
+    };
+    let mut text = RichText::new(format_number(value, style)).font(FontId::proportional(style.font_size));
+    if let Some(color) = color {
+        text = text.color(color);
+    }
+    ui.add(Label::new(text).selectable(false))
+}
+
+/// Remembered shake or flash effect of a widget.
+#[derive(Clone, Copy)]
+struct Feedback {
+    start: f64,
+    duration: f32,
+    magnitude: f32,
+    color: Color32,
+}
+
+/// Returns the progress of the effect stored under `id` in `0..1`, or `None` when it is over.
+fn feedback_progress(ctx: &Context, id: Id) -> Option<(Feedback, f32)> {
+    let feedback = ctx.data(|d| d.get_temp::<Feedback>(id))?;
+    let now = ctx.input(|i| i.time);
+    let t = ((now - feedback.start) as f32 / feedback.duration.max(f32::EPSILON)).max(0.0);
+    if t >= 1.0 {
+        ctx.data_mut(|d| d.remove::<Feedback>(id));
+        return None;
+    }
+    ctx.request_repaint();
+    Some((feedback, t))
+}
+
+fn start_feedback(ctx: &Context, id: Id, duration: f32, magnitude: f32, color: Color32) {
+    let start = ctx.input(|i| i.time);
+    ctx.data_mut(|d| d.insert_temp(id, Feedback { start, duration, magnitude, color }));
+    ctx.request_repaint();
+}
+
+/// Starts shaking the widget `id` by up to `magnitude` points for `duration` seconds, e.g. an
+/// enemy that was hit or a button pressed while unavailable.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui_anim::shake;
+/// # fn demo(ui: &mut Ui, energy: u32) {
+/// let response = ui.button("Play");
+/// if response.clicked() && energy == 0 {
+///     shake(ui.ctx(), response.id, 4.0, 0.3);
+/// }
+/// # }
+/// ```
+pub fn shake(ctx: &Context, id: Id, magnitude: f32, duration: f32) {
+    start_feedback(ctx, id.with("shake"), duration, magnitude, Color32::TRANSPARENT);
+}
+
+/// Starts flashing the widget `id` with `color`, fading out over `duration` seconds.
+///
+/// # Example
+/// ```rust
+/// # use egui::{Color32, Id, Ui};
+/// # use deckbuilder_eng::ui_anim::flash;
+/// # fn demo(ui: &mut Ui) {
+/// flash(ui.ctx(), Id::new(("enemy", 0)), Color32::from_rgb(255, 80, 60), 0.25);
+/// # }
+/// ```
+pub fn flash(ctx: &Context, id: Id, color: Color32, duration: f32) {
+    start_feedback(ctx, id.with("flash"), duration, 0.0, color);
+}
+
+/// Current shake offset of the widget `id`; add it to the widget's rect before painting.
+/// Zero when the widget isn't shaking.
+///
+/// # Example
+/// ```rust
+/// # use egui::{Id, Rect, Ui};
+/// # use deckbuilder_eng::ui_anim::shake_offset;
+/// # fn demo(ui: &mut Ui, rect: Rect) {
+/// let id = Id::new(("enemy", 0));
+/// let rect = rect.translate(shake_offset(ui.ctx(), id));
+/// ui.painter().rect_filled(rect, 4.0, egui::Color32::DARK_GRAY);
+/// # }
+/// ```
+pub fn shake_offset(ctx: &Context, id: Id) -> Vec2 {
+    let Some((feedback, t)) = feedback_progress(ctx, id.with("shake")) else {
+        return Vec2::ZERO;
+    };
+    let amplitude = feedback.magnitude * (1.0 - t);
+    let phase = t * feedback.duration * 60.0;
+    Vec2::new(phase.sin() * amplitude, (phase * 1.3).cos() * amplitude * 0.5)
+}
+
+/// Current flash tint of the widget `id`, fading to transparent. Paint it over the widget,
+/// e.g. with `painter.rect_filled(rect, rounding, flash_color(ctx, id))`.
+///
+/// # Example
+/// ```rust
+/// # use egui::{Id, Rect, Ui};
+/// # use deckbuilder_eng::ui_anim::flash_color;
+/// # fn demo(ui: &mut Ui, rect: Rect) {
+/// let tint = flash_color(ui.ctx(), Id::new(("enemy", 0)));
+/// ui.painter().rect_filled(rect, 4.0, tint);
+/// # }
+/// ```
+pub fn flash_color(ctx: &Context, id: Id) -> Color32 {
+    match feedback_progress(ctx, id.with("flash")) {
+        Some((feedback, t)) => feedback.color.gamma_multiply(1.0 - t),
+        None => Color32::TRANSPARENT,
+    }
+}
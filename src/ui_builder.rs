@@ -0,0 +1,822 @@
+//! Builder-style widgets for egui-based deckbuilder UIs.
+//!
+//! A parallel API to the helpers in [`crate::ui`]: instead of taking a click callback, every
+//! widget is configured with builder methods and returns the egui [`Response`] from `show`, so
+//! tooltips, right-clicks, double-clicks and `changed()` checks are available.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::ui_builder::*;
+//! use egui::{CentralPanel, Context, Vec2};
+//!
+//! fn my_ui(ctx: &Context, volume: &mut f32, gold: &mut i64, name: &mut String) {
+//!     CentralPanel::default().show(ctx, |ui| {
+//!         let end_turn = UiButton::new("End Turn")
+//!             .size(Vec2::new(120.0, 40.0))
+//!             .tooltip("Discard your hand and let enemies act")
+//!             .show(ui);
+//!         if end_turn.clicked() {
+//!             println!("Turn ended");
+//!         }
+//!         if end_turn.secondary_clicked() {
+//!             println!("Right-clicked");
+//!         }
+//!
+//!         if UiSlider::new("Volume", volume, 0.0..=1.0).show(ui).changed() {
+//!             println!("Volume: {}", volume);
+//!         }
+//!         UiDragValue::new("Gold", gold).range(0.0..=9999.0).suffix(" g").show(ui);
+//!         UiTextInput::new("Name", name).hint("Hero").show(ui);
+//!     });
+//! }
+//! ```
+//!
+//! # Details
+//!
+//! - Labeled widgets lay out their label and control horizontally and return the control's
+//!   response.
+//! - All builders support `enabled` and `tooltip`.
+//! - See each struct and function's documentation for more.
+
+use std::ops::RangeInclusive;
+
+use egui::{
+    emath::Numeric, Button, Checkbox, Color32, ComboBox, DragValue, Id, Response, Slider, TextEdit, Ui,
+    Vec2,
+};
+
+fn finish(response: Response, tooltip: Option<String>) -> Response {
+    match tooltip {
+        Some(text) => response.on_hover_text(text),
+        None => response,
+    }
+}
+
+/// Button builder.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui_builder::UiButton;
+/// # fn demo(ui: &mut Ui) {
+/// if UiButton::new("Play").show(ui).double_clicked() {
+///     println!("Double-clicked");
+/// }
+/// # }
+/// ```
+pub struct UiButton {
+    label: String,
+    size: Option<Vec2>,
+    fill: Option<Color32>,
+    small: bool,
+    enabled: bool,
+    tooltip: Option<String>,
+}
+
+impl UiButton {
+    /// Creates a button with the given label.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_builder::UiButton;
+    /// let widget = UiButton::new("End Turn");
+    /// ```
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            size: None,
+            fill: None,
+            small: false,
+            enabled: true,
+            tooltip: None,
+        }
+    }
+
+    /// Sets a fixed size.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Vec2;
+    /// # use deckbuilder_eng::ui_builder::UiButton;
+    /// let widget = UiButton::new("End Turn").size(Vec2::new(120.0, 40.0));
+    /// ```
+    pub fn size(mut self, size: Vec2) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the background color.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Color32;
+    /// # use deckbuilder_eng::ui_builder::UiButton;
+    /// let widget = UiButton::new("End Turn").fill(Color32::DARK_RED);
+    /// ```
+    pub fn fill(mut self, fill: Color32) -> Self {
+        self.fill = Some(fill);
+        self
+    }
+
+    /// Uses the small button style.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_builder::UiButton;
+    /// let widget = UiButton::new("End Turn").small(true);
+    /// ```
+    pub fn small(mut self, small: bool) -> Self {
+        self.small = small;
+        self
+    }
+
+    /// Enables or disables the button.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_builder::UiButton;
+    /// let widget = UiButton::new("End Turn").enabled(false);
+    /// ```
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Shows `text` when hovered.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_builder::UiButton;
+    /// let widget = UiButton::new("End Turn").tooltip("Hint");
+    /// ```
+    pub fn tooltip(mut self, text: impl Into<String>) -> Self {
+        self.tooltip = Some(text.into());
+        self
+    }
+
+    /// Adds the button to `ui`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Ui;
+    /// # use deckbuilder_eng::ui_builder::UiButton;
+    /// # fn demo(ui: &mut Ui) {
+    /// let response = UiButton::new("End Turn").show(ui);
+    /// # }
+    /// ```
+    pub fn show(self, ui: &mut Ui) -> Response {
+        let mut button = Button::new(self.label);
+        if self.small {
+            button = button.small();
+        }
+        if let Some(fill) = self.fill {
+            button = button.fill(fill);
+        }
+        let response = match self.size {
+            Some(size) => ui.add_enabled_ui(self.enabled, |ui| ui.add_sized(size, button)).inner,
+            None => ui.add_enabled(self.enabled, button),
+        };
+        finish(response, self.tooltip)
+    }
+}
+
+/// Checkbox builder.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui_builder::UiCheckbox;
+/// # fn demo(ui: &mut Ui) {
+/// let mut fast_mode = false;
+/// if UiCheckbox::new("Fast mode", &mut fast_mode).show(ui).changed() {
+///     println!("Fast mode: {}", fast_mode);
+/// }
+/// # }
+/// ```
+pub struct UiCheckbox<'a> {
+    label: String,
+    value: &'a mut bool,
+    enabled: bool,
+    tooltip: Option<String>,
+}
+
+impl<'a> UiCheckbox<'a> {
+    /// Creates a checkbox editing `value`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_builder::UiCheckbox;
+    /// let mut value = false;
+    /// let widget = UiCheckbox::new("Fast mode", &mut value);
+    /// ```
+    pub fn new(label: impl Into<String>, value: &'a mut bool) -> Self {
+        Self {
+            label: label.into(),
+            value,
+            enabled: true,
+            tooltip: None,
+        }
+    }
+
+    /// Enables or disables the checkbox.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_builder::UiCheckbox;
+    /// let mut value = false;
+    /// let widget = UiCheckbox::new("Fast mode", &mut value).enabled(false);
+    /// ```
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Shows `text` when hovered.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_builder::UiCheckbox;
+    /// let mut value = false;
+    /// let widget = UiCheckbox::new("Fast mode", &mut value).tooltip("Hint");
+    /// ```
+    pub fn tooltip(mut self, text: impl Into<String>) -> Self {
+        self.tooltip = Some(text.into());
+        self
+    }
+
+    /// Adds the checkbox to `ui`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Ui;
+    /// # use deckbuilder_eng::ui_builder::UiCheckbox;
+    /// # fn demo(ui: &mut Ui) {
+    /// let mut value = false;
+    /// let response = UiCheckbox::new("Fast mode", &mut value).show(ui);
+    /// # }
+    /// ```
+    pub fn show(self, ui: &mut Ui) -> Response {
+        let response = ui.add_enabled(self.enabled, Checkbox::new(self.value, self.label));
+        finish(response, self.tooltip)
+    }
+}
+
+/// Labeled slider builder for any numeric type.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui_builder::UiSlider;
+/// # fn demo(ui: &mut Ui) {
+/// let mut energy = 3_u32;
+/// UiSlider::new("Energy", &mut energy, 0..=10).show(ui);
+/// # }
+/// ```
+pub struct UiSlider<'a, N: Numeric> {
+    label: String,
+    value: &'a mut N,
+    range: RangeInclusive<N>,
+    step: Option<f64>,
+    suffix: String,
+    enabled: bool,
+    tooltip: Option<String>,
+}
+
+impl<'a, N: Numeric> UiSlider<'a, N> {
+    /// Creates a slider editing `value` within `range`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_builder::UiSlider;
+    /// let mut volume = 0.5_f32;
+    /// let widget = UiSlider::new("Volume", &mut volume, 0.0..=1.0);
+    /// ```
+    pub fn new(label: impl Into<String>, value: &'a mut N, range: RangeInclusive<N>) -> Self {
+        Self {
+            label: label.into(),
+            value,
+            range,
+            step: None,
+            suffix: String::new(),
+            enabled: true,
+            tooltip: None,
+        }
+    }
+
+    /// Rounds the value to multiples of `step`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_builder::UiSlider;
+    /// let mut volume = 0.5_f32;
+    /// let widget = UiSlider::new("Volume", &mut volume, 0.0..=1.0).step(0.1);
+    /// ```
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    /// Text shown after the number.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_builder::UiSlider;
+    /// let mut volume = 0.5_f32;
+    /// let widget = UiSlider::new("Volume", &mut volume, 0.0..=1.0).suffix("%");
+    /// ```
+    pub fn suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.suffix = suffix.into();
+        self
+    }
+
+    /// Enables or disables the slider.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_builder::UiSlider;
+    /// let mut volume = 0.5_f32;
+    /// let widget = UiSlider::new("Volume", &mut volume, 0.0..=1.0).enabled(false);
+    /// ```
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Shows `text` when hovered.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_builder::UiSlider;
+    /// let mut volume = 0.5_f32;
+    /// let widget = UiSlider::new("Volume", &mut volume, 0.0..=1.0).tooltip("Hint");
+    /// ```
+    pub fn tooltip(mut self, text: impl Into<String>) -> Self {
+        self.tooltip = Some(text.into());
+        self
+    }
+
+    /// Adds the label and slider to `ui`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Ui;
+    /// # use deckbuilder_eng::ui_builder::UiSlider;
+    /// # fn demo(ui: &mut Ui) {
+    /// let mut volume = 0.5_f32;
+    /// let response = UiSlider::new("Volume", &mut volume, 0.0..=1.0).show(ui);
+    /// # }
+    /// ```
+    pub fn show(self, ui: &mut Ui) -> Response {
+        let response = ui
+            .horizontal(|ui| {
+                ui.label(self.label);
+                let mut slider = Slider::new(self.value, self.range).suffix(self.suffix);
+                if let Some(step) = self.step {
+                    slider = slider.step_by(step);
+                }
+                ui.add_enabled(self.enabled, slider)
+            })
+            .inner;
+        finish(response, self.tooltip)
+    }
+}
+
+/// Labeled drag value builder for any numeric type.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui_builder::UiDragValue;
+/// # fn demo(ui: &mut Ui) {
+/// let mut gold = 100_i64;
+/// UiDragValue::new("Gold", &mut gold).speed(5.0).range(0.0..=999.0).show(ui);
+/// # }
+/// ```
+pub struct UiDragValue<'a, N: Numeric> {
+    label: String,
+    value: &'a mut N,
+    speed: Option<f64>,
+    range: Option<RangeInclusive<f64>>,
+    suffix: String,
+    enabled: bool,
+    tooltip: Option<String>,
+}
+
+impl<'a, N: Numeric> UiDragValue<'a, N> {
+    /// Creates a drag value editing `value`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_builder::UiDragValue;
+    /// let mut gold = 100_i64;
+    /// let widget = UiDragValue::new("Gold", &mut gold);
+    /// ```
+    pub fn new(label: impl Into<String>, value: &'a mut N) -> Self {
+        Self {
+            label: label.into(),
+            value,
+            speed: None,
+            range: None,
+            suffix: String::new(),
+            enabled: true,
+            tooltip: None,
+        }
+    }
+
+    /// Value change per point of mouse movement.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_builder::UiDragValue;
+    /// let mut gold = 100_i64;
+    /// let widget = UiDragValue::new("Gold", &mut gold).speed(5.0);
+    /// ```
+    pub fn speed(mut self, speed: f64) -> Self {
+        self.speed = Some(speed);
+        self
+    }
+
+    /// Clamps the value to `range`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_builder::UiDragValue;
+    /// let mut gold = 100_i64;
+    /// let widget = UiDragValue::new("Gold", &mut gold).range(0.0..=999.0);
+    /// ```
+    pub fn range(mut self, range: RangeInclusive<f64>) -> Self {
+        self.range = Some(range);
+        self
+    }
+
+    /// Text shown after the number.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_builder::UiDragValue;
+    /// let mut gold = 100_i64;
+    /// let widget = UiDragValue::new("Gold", &mut gold).suffix("%");
+    /// ```
+    pub fn suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.suffix = suffix.into();
+        self
+    }
+
+    /// Enables or disables the drag value.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_builder::UiDragValue;
+    /// let mut gold = 100_i64;
+    /// let widget = UiDragValue::new("Gold", &mut gold).enabled(false);
+    /// ```
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Shows `text` when hovered.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_builder::UiDragValue;
+    /// let mut gold = 100_i64;
+    /// let widget = UiDragValue::new("Gold", &mut gold).tooltip("Hint");
+    /// ```
+    pub fn tooltip(mut self, text: impl Into<String>) -> Self {
+        self.tooltip = Some(text.into());
+        self
+    }
+
+    /// Adds the label and drag value to `ui`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Ui;
+    /// # use deckbuilder_eng::ui_builder::UiDragValue;
+    /// # fn demo(ui: &mut Ui) {
+    /// let mut gold = 100_i64;
+    /// let response = UiDragValue::new("Gold", &mut gold).show(ui);
+    /// # }
+    /// ```
+    pub fn show(self, ui: &mut Ui) -> Response {
+        let response = ui
+            .horizontal(|ui| {
+                ui.label(self.label);
+                let mut drag = DragValue::new(self.value).suffix(self.suffix);
+                if let Some(speed) = self.speed {
+                    drag = drag.speed(speed);
+                }
+                if let Some(range) = self.range {
+                    drag = drag.clamp_range(range);
+                }
+                ui.add_enabled(self.enabled, drag)
+            })
+            .inner;
+        finish(response, self.tooltip)
+    }
+}
+
+/// Labeled text input builder (single or multi-line).
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui_builder::UiTextInput;
+/// # fn demo(ui: &mut Ui) {
+/// let mut seed = String::new();
+/// let res = UiTextInput::new("Seed", &mut seed).hint("random").show(ui);
+/// if res.lost_focus() {
+///     println!("Seed: {}", seed);
+/// }
+/// # }
+/// ```
+pub struct UiTextInput<'a> {
+    label: String,
+    value: &'a mut String,
+    hint: String,
+    rows: Option<usize>,
+    password: bool,
+    width: Option<f32>,
+    enabled: bool,
+    tooltip: Option<String>,
+}
+
+impl<'a> UiTextInput<'a> {
+    /// Creates a single-line text input editing `value`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_builder::UiTextInput;
+    /// let mut name = String::new();
+    /// let widget = UiTextInput::new("Name", &mut name);
+    /// ```
+    pub fn new(label: impl Into<String>, value: &'a mut String) -> Self {
+        Self {
+            label: label.into(),
+            value,
+            hint: String::new(),
+            rows: None,
+            password: false,
+            width: None,
+            enabled: true,
+            tooltip: None,
+        }
+    }
+
+    /// Grey text shown while the input is empty.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_builder::UiTextInput;
+    /// let mut name = String::new();
+    /// let widget = UiTextInput::new("Name", &mut name).hint("Hero");
+    /// ```
+    pub fn hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = hint.into();
+        self
+    }
+
+    /// Makes the input multi-line with `rows` visible rows. The label is placed above it.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_builder::UiTextInput;
+    /// let mut name = String::new();
+    /// let widget = UiTextInput::new("Name", &mut name).multiline(4);
+    /// ```
+    pub fn multiline(mut self, rows: usize) -> Self {
+        self.rows = Some(rows);
+        self
+    }
+
+    /// Hides the typed characters.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_builder::UiTextInput;
+    /// let mut name = String::new();
+    /// let widget = UiTextInput::new("Name", &mut name).password(true);
+    /// ```
+    pub fn password(mut self, password: bool) -> Self {
+        self.password = password;
+        self
+    }
+
+    /// Sets the desired width of the input.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_builder::UiTextInput;
+    /// let mut name = String::new();
+    /// let widget = UiTextInput::new("Name", &mut name).width(200.0);
+    /// ```
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Enables or disables the input.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_builder::UiTextInput;
+    /// let mut name = String::new();
+    /// let widget = UiTextInput::new("Name", &mut name).enabled(false);
+    /// ```
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Shows `text` when hovered.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_builder::UiTextInput;
+    /// let mut name = String::new();
+    /// let widget = UiTextInput::new("Name", &mut name).tooltip("Hint");
+    /// ```
+    pub fn tooltip(mut self, text: impl Into<String>) -> Self {
+        self.tooltip = Some(text.into());
+        self
+    }
+
+    /// Adds the label and text input to `ui`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Ui;
+    /// # use deckbuilder_eng::ui_builder::UiTextInput;
+    /// # fn demo(ui: &mut Ui) {
+    /// let mut name = String::new();
+    /// let response = UiTextInput::new("Name", &mut name).show(ui);
+    /// # }
+    /// ```
+    pub fn show(self, ui: &mut Ui) -> Response {
+        let mut edit = match self.rows {
+            Some(rows) => TextEdit::multiline(self.value).desired_rows(rows),
+            None => TextEdit::singleline(self.value),
+        }
+        .hint_text(self.hint)
+        .password(self.password);
+        if let Some(width) = self.width {
+            edit = edit.desired_width(width);
+        }
+        let enabled = self.enabled;
+        let response = if self.rows.is_some() {
+            ui.label(self.label);
+            ui.add_enabled(enabled, edit)
+        } else {
+            ui.horizontal(|ui| {
+                ui.label(self.label);
+                ui.add_enabled(enabled, edit)
+            })
+            .inner
+        };
+        finish(response, self.tooltip)
+    }
+}
+
+/// Combo box (dropdown) builder over any list of options. The returned response is marked
+/// changed when the selection changes.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui_builder::UiComboBox;
+/// # fn demo(ui: &mut Ui) {
+/// let options = ["Easy", "Normal", "Hard"];
+/// let mut difficulty = "Normal";
+/// if UiComboBox::new("Difficulty", &options, &mut difficulty).show(ui).changed() {
+///     println!("Difficulty: {}", difficulty);
+/// }
+/// # }
+/// ```
+pub struct UiComboBox<'a, T: ToString + PartialEq + Clone> {
+    label: String,
+    options: &'a [T],
+    selected: &'a mut T,
+    id_salt: Option<Id>,
+    width: Option<f32>,
+    enabled: bool,
+    tooltip: Option<String>,
+}
+
+impl<'a, T: ToString + PartialEq + Clone> UiComboBox<'a, T> {
+    /// Creates a combo box choosing `selected` from `options`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_builder::UiComboBox;
+    /// let options = ["Easy", "Hard"];
+    /// let mut selected = "Easy";
+    /// let widget = UiComboBox::new("Difficulty", &options, &mut selected);
+    /// ```
+    pub fn new(label: impl Into<String>, options: &'a [T], selected: &'a mut T) -> Self {
+        Self {
+            label: label.into(),
+            options,
+            selected,
+            id_salt: None,
+            width: None,
+            enabled: true,
+            tooltip: None,
+        }
+    }
+
+    /// Id source for the popup, needed when several combo boxes share a label.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_builder::UiComboBox;
+    /// let options = ["Easy", "Hard"];
+    /// let mut selected = "Easy";
+    /// let widget = UiComboBox::new("Difficulty", &options, &mut selected).id_salt("difficulty");
+    /// ```
+    pub fn id_salt(mut self, salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(Id::new(salt));
+        self
+    }
+
+    /// Sets the width of the closed combo box.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_builder::UiComboBox;
+    /// let options = ["Easy", "Hard"];
+    /// let mut selected = "Easy";
+    /// let widget = UiComboBox::new("Difficulty", &options, &mut selected).width(200.0);
+    /// ```
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Enables or disables the combo box.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_builder::UiComboBox;
+    /// let options = ["Easy", "Hard"];
+    /// let mut selected = "Easy";
+    /// let widget = UiComboBox::new("Difficulty", &options, &mut selected).enabled(false);
+    /// ```
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Shows `text` when hovered.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_builder::UiComboBox;
+    /// let options = ["Easy", "Hard"];
+    /// let mut selected = "Easy";
+    /// let widget = UiComboBox::new("Difficulty", &options, &mut selected).tooltip("Hint");
+    /// ```
+    pub fn tooltip(mut self, text: impl Into<String>) -> Self {
+        self.tooltip = Some(text.into());
+        self
+    }
+
+    /// Adds the combo box to `ui`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Ui;
+    /// # use deckbuilder_eng::ui_builder::UiComboBox;
+    /// # fn demo(ui: &mut Ui) {
+    /// let options = ["Easy", "Hard"];
+    /// let mut selected = "Easy";
+    /// let response = UiComboBox::new("Difficulty", &options, &mut selected).show(ui);
+    /// # }
+    /// ```
+    pub fn show(self, ui: &mut Ui) -> Response {
+        let id = self.id_salt.unwrap_or_else(|| Id::new(&self.label));
+        let mut combo = ComboBox::new(id, self.label).selected_text(self.selected.to_string());
+        if let Some(width) = self.width {
+            combo = combo.width(width);
+        }
+        let mut changed = false;
+        let selected = self.selected;
+        let options = self.options;
+        let mut response = ui
+            .add_enabled_ui(self.enabled, |ui| {
+                combo
+                    .show_ui(ui, |ui| {
+                        for option in options {
+                            changed |= ui.selectable_value(selected, option.clone(), option.to_string()).changed();
+                        }
+                    })
+                    .response
+            })
+            .inner;
+        if changed {
+            response.mark_changed();
+        }
+        finish(response, self.tooltip)
+    }
+}
@@ -0,0 +1,175 @@
+//! Screenshot helpers for egui-based deckbuilder UIs.
+//!
+//! Provides [`request_screenshot`], which asks eframe for a screenshot and saves it to a file,
+//! and a photo mode ([`request_photo`]) that hides HUD overlays for the captured frame, so
+//! clean card and board images can be taken.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::ui_capture::{handle_screenshots, is_photo_mode, request_photo};
+//! use egui::{CentralPanel, Context, Key};
+//!
+//! fn update(ctx: &Context) {
+//!     if ctx.input(|i| i.key_pressed(Key::F12)) {
+//!         request_photo(ctx, "screenshots/board.png");
+//!     }
+//!     CentralPanel::default().show(ctx, |ui| {
+//!         ui.label("Board");
+//!     });
+//!     if !is_photo_mode(ctx) {
+//!         // HUD, tooltips, debug text...
+//!     }
+//!     // Call last, after everything is drawn
+//!     if let Some(result) = handle_screenshots(ctx) {
+//!         match result {
+//!             Ok(path) => println!("Saved {}", path.display()),
+//!             Err(err) => eprintln!("Screenshot failed: {}", err),
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! # Details
+//!
+//! - Screenshots arrive one frame after they are requested; [`handle_screenshots`] must be
+//!   called every frame to save them.
+//! - Toasts and the debug overlay hide themselves in photo mode; check [`is_photo_mode`] to
+//!   hide your own HUD.
+//! - Images are saved with the `image` crate; the format follows the file extension.
+//! - See each function's documentation for usage and customization options.
+
+use std::path::{Path, PathBuf};
+
+use egui::{Context, Event, Id, Rect, ViewportCommand};
+use image::ColorType;
+
+use crate::error::Error;
+
+#[derive(Clone)]
+struct PendingCapture {
+    path: PathBuf,
+    region: Option<Rect>,
+    hide_hud: bool,
+    sent: bool,
+}
+
+fn capture_id() -> Id {
+    Id::new("deckbuilder_capture")
+}
+
+fn pending(ctx: &Context) -> Option<PendingCapture> {
+    ctx.data(|d| d.get_temp::<PendingCapture>(capture_id()))
+}
+
+/// Requests a screenshot of the current frame, saved to `path` by [`handle_screenshots`].
+///
+/// # Example
+/// ```rust
+/// # use egui::Context;
+/// # use deckbuilder_eng::ui_capture::request_screenshot;
+/// # fn demo(ctx: &Context) {
+/// request_screenshot(ctx, "screenshots/debug.png");
+/// # }
+/// ```
+pub fn request_screenshot(ctx: &Context, path: impl AsRef<Path>) {
+    let capture = PendingCapture {
+        path: path.as_ref().to_path_buf(),
+        region: None,
+        hide_hud: false,
+        sent: true,
+    };
+    ctx.data_mut(|d| d.insert_temp(capture_id(), capture));
+    ctx.send_viewport_cmd(ViewportCommand::Screenshot);
+}
+
+/// Requests a screenshot of the next frame with HUD overlays hidden, saved to `path` by
+/// [`handle_screenshots`].
+///
+/// # Example
+/// ```rust
+/// # use egui::Context;
+/// # use deckbuilder_eng::ui_capture::{is_photo_mode, request_photo};
+/// let ctx = Context::default();
+/// request_photo(&ctx, "screenshots/board.png");
+/// assert!(is_photo_mode(&ctx));
+/// ```
+pub fn request_photo(ctx: &Context, path: impl AsRef<Path>) {
+    request_photo_region(ctx, path, None);
+}
+
+/// Like [`request_photo`], cropped to `region` (in points), e.g. the rect of a card.
+///
+/// # Example
+/// ```rust
+/// # use egui::{Response, Ui};
+/// # use deckbuilder_eng::ui_capture::request_photo_region;
+/// # fn demo(ui: &mut Ui, card: &Response) {
+/// request_photo_region(ui.ctx(), "screenshots/card.png", Some(card.rect));
+/// # }
+/// ```
+pub fn request_photo_region(ctx: &Context, path: impl AsRef<Path>, region: Option<Rect>) {
+    let capture = PendingCapture {
+        path: path.as_ref().to_path_buf(),
+        region,
+        hide_hud: true,
+        sent: false,
+    };
+    ctx.data_mut(|d| d.insert_temp(capture_id(), capture));
+    ctx.request_repaint();
+}
+
+/// Returns `true` during the frame a photo is taken; HUD overlays should not be drawn.
+///
+/// # Example
+/// ```rust
+/// # use egui::Context;
+/// # use deckbuilder_eng::ui_capture::is_photo_mode;
+/// assert!(!is_photo_mode(&Context::default()));
+/// ```
+pub fn is_photo_mode(ctx: &Context) -> bool {
+    pending(ctx).is_some_and(|capture| capture.hide_hud && !capture.sent)
+}
+
+/// Sends pending photo requests and saves screenshots that arrived. Call once per frame,
+/// after all UI is drawn. Returns the saved path, or the error, when a screenshot was saved
+/// this frame.
+///
+/// # Example
+/// ```rust
+/// # use egui::Context;
+/// # use deckbuilder_eng::ui_capture::handle_screenshots;
+/// # fn demo(ctx: &Context) {
+/// if let Some(Err(err)) = handle_screenshots(ctx) {
+///     eprintln!("Screenshot failed: {}", err);
+/// }
+/// # }
+/// ```
+pub fn handle_screenshots(ctx: &Context) -> Option<Result<PathBuf, Error>> {
+    let mut capture = pending(ctx)?;
+    if !capture.sent {
+        capture.sent = true;
+        ctx.data_mut(|d| d.insert_temp(capture_id(), capture));
+        ctx.send_viewport_cmd(ViewportCommand::Screenshot);
+        ctx.request_repaint();
+        return None;
+    }
+
+    let image = ctx.input(|i| {
+        i.raw.events.iter().find_map(|event| match event {
+            Event::Screenshot { image, .. } => Some(image.clone()),
+            _ => None,
+        })
+    })?;
+    ctx.data_mut(|d| d.remove::<PendingCapture>(capture_id()));
+    let image = match capture.region {
+        Some(region) => image.region(&region, Some(ctx.pixels_per_point())),
+        None => (*image).clone(),
+    };
+    let [width, height] = image.size;
+    Some(
+        image::save_buffer(&capture.path, image.as_raw(), width as u32, height as u32, ColorType::Rgba8)
+            .map_err(|err| Error::image(&capture.path, err))
+            .map(|()| capture.path),
+    )
+}
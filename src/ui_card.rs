@@ -0,0 +1,829 @@
+//! Card widgets for egui-based deckbuilder UIs.
+//!
+//! Provides a framed card widget that renders a [`Card`] with its cost, title, art, type and description,
+//! a fanned hand layout built on top of it, draw/discard pile widgets, a filterable card grid
+//! for deck-edit screens, and a card reward overlay.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::card::{Card, CardType};
+//! use deckbuilder_eng::ui_card::*;
+//! use egui::{CentralPanel, Context};
+//!
+//! fn my_card_ui(ctx: &Context) {
+//!     let card = Card::new(1, "Strike", "Deal 6 damage", 1, CardType::Attack);
+//!     let visuals = CardVisuals::default();
+//!
+//!     CentralPanel::default().show(ctx, |ui| {
+//!         let res = ui_card(ui, &card, &visuals);
+//!         if res.clicked {
+//!             println!("Played {}", card.name);
+//!         }
+//!     });
+//! }
+//! ```
+//!
+//! # Details
+//!
+//! - Sizes, fonts and the cost bubble scale with `CardVisuals::size`.
+//! - Cards without art show a flat placeholder in the art area.
+//! - Card names and descriptions are localized through [`crate::i18n`] when string tables are
+//!   installed.
+//! - `ui_hand` allocates a single area and hit-tests the topmost card under the pointer.
+//!   Cards animate towards their slots, so hovering and hand changes don't snap.
+//! - Pile widgets open a modal card list when clicked; the draw pile list is sorted.
+//! - `ui_card_grid` fits as many columns as the available width allows and only lays out the
+//!   visible rows.
+//! - Cards in `ui_card`, `ui_card_grid` and `ui_card_choice` are highlighted while they have
+//!   keyboard focus (see [`crate::ui_focus`]).
+//! - See each function's documentation for usage and customization options.
+
+use crate::card::{Card, CardType, Deck, Rarity};
+use crate::i18n::{localize_card_description, localize_card_name};
+use crate::ui_anim::Animator;
+use crate::ui_overlay::ui_modal;
+use egui::{
+    Align2, Color32, Context, FontFamily, FontId, Id, Key, Painter, Pos2, Rect, Response, Rounding, ScrollArea,
+    Sense, Stroke, TextureHandle, Ui, Vec2,
+};
+
+/// Visual settings used when drawing a card.
+#[derive(Clone)]
+pub struct CardVisuals {
+    /// Size of the whole card in points.
+    pub size: Vec2,
+    /// Optional art texture shown in the middle of the card.
+    pub art: Option<TextureHandle>,
+    /// Card body color.
+    pub background: Color32,
+    /// Frame (border) color.
+    pub frame_color: Color32,
+    /// Frame color while the card is hovered.
+    pub hover_color: Color32,
+    /// Fill color of the cost bubble.
+    pub cost_color: Color32,
+    /// Color of the title and description text.
+    pub text_color: Color32,
+    /// Corner radius of the card frame.
+    pub rounding: f32,
+}
+
+impl Default for CardVisuals {
+    fn default() -> Self {
+        Self {
+            size: Vec2::new(140.0, 200.0),
+            art: None,
+            background: Color32::from_rgb(40, 36, 48),
+            frame_color: Color32::from_rgb(150, 130, 90),
+            hover_color: Color32::from_rgb(250, 220, 120),
+            cost_color: Color32::from_rgb(40, 110, 200),
+            text_color: Color32::from_rgb(235, 230, 220),
+            rounding: 8.0,
+        }
+    }
+}
+
+/// Interaction state returned by [`ui_card`].
+pub struct CardResponse {
+    /// The underlying egui response (for tooltips, context menus, etc.).
+    pub response: Response,
+    /// Pointer is over the card.
+    pub hovered: bool,
+    /// Card was clicked this frame.
+    pub clicked: bool,
+    /// A drag started on the card this frame.
+    pub drag_started: bool,
+    /// Card is being dragged.
+    pub dragged: bool,
+    /// A drag of the card ended this frame.
+    pub drag_released: bool,
+}
+
+impl CardResponse {
+    pub(crate) fn from_response(response: Response) -> Self {
+        Self {
+            hovered: response.hovered(),
+            clicked: response.clicked(),
+            drag_started: response.drag_started(),
+            dragged: response.dragged(),
+            drag_released: response.drag_stopped(),
+            response,
+        }
+    }
+}
+
+/// Returns the banner color used for a card type.
+///
+/// # Example
+/// ```rust
+/// # use deckbuilder_eng::card::CardType;
+/// # use deckbuilder_eng::ui_card::card_type_color;
+/// let color = card_type_color(&CardType::Attack);
+/// ```
+pub fn card_type_color(card_type: &CardType) -> Color32 {
+    match card_type {
+        CardType::Attack => Color32::from_rgb(170, 50, 50),
+        CardType::Skill => Color32::from_rgb(50, 120, 170),
+        CardType::Power => Color32::from_rgb(150, 90, 180),
+    }
+}
+
+/// Returns the display label of a card type.
+///
+/// # Example
+/// ```rust
+/// # use deckbuilder_eng::card::CardType;
+/// # use deckbuilder_eng::ui_card::card_type_label;
+/// assert_eq!(card_type_label(&CardType::Skill), "Skill");
+/// ```
+pub fn card_type_label(card_type: &CardType) -> &'static str {
+    match card_type {
+        CardType::Attack => "Attack",
+        CardType::Skill => "Skill",
+        CardType::Power => "Power",
+    }
+}
+
+/// Paints a card into `rect` without allocating space or handling input.
+/// Useful for custom layouts (hands, piles, overlays). The name and description are
+/// translated by the installed [`crate::i18n::I18n`], see [`crate::i18n::I18n::card_name`].
+///
+/// # Example
+/// ```rust
+/// # use egui::{Ui, Rect, Pos2, Vec2};
+/// # use deckbuilder_eng::card::{Card, CardType};
+/// # use deckbuilder_eng::ui_card::{paint_card, CardVisuals};
+/// # fn demo(ui: &mut Ui) {
+/// let card = Card::new(1, "Strike", "Deal 6 damage", 1, CardType::Attack);
+/// let rect = Rect::from_min_size(Pos2::new(10.0, 10.0), Vec2::new(140.0, 200.0));
+/// paint_card(ui.painter(), rect, &card, &CardVisuals::default(), false);
+/// # }
+/// ```
+pub fn paint_card(painter: &Painter, rect: Rect, card: &Card, visuals: &CardVisuals, hovered: bool) {
+    let scale = rect.height() / 200.0;
+    let pad = 6.0 * scale;
+    let rounding = Rounding::same(visuals.rounding * scale);
+
+    // Body and frame
+    painter.rect_filled(rect, rounding, visuals.background);
+    let frame = if hovered { visuals.hover_color } else { visuals.frame_color };
+    painter.rect_stroke(rect, rounding, Stroke::new(2.0 * scale, frame));
+
+    // Title
+    let title_rect = Rect::from_min_size(
+        rect.min + Vec2::new(pad, pad),
+        Vec2::new(rect.width() - 2.0 * pad, 22.0 * scale),
+    );
+    painter.text(
+        title_rect.center() + Vec2::new(8.0 * scale, 0.0),
+        Align2::CENTER_CENTER,
+        localize_card_name(painter.ctx(), card),
+        FontId::new(13.0 * scale, FontFamily::Proportional),
+        visuals.text_color,
+    );
+
+    // Art
+    let art_rect = Rect::from_min_size(
+        Pos2::new(rect.min.x + pad, title_rect.max.y + 2.0 * scale),
+        Vec2::new(rect.width() - 2.0 * pad, rect.height() * 0.38),
+    );
+    if let Some(art) = &visuals.art {
+        painter.image(
+            art.id(),
+            art_rect,
+            Rect::from_min_max(Pos2::new(0.0, 0.0), Pos2::new(1.0, 1.0)),
+            Color32::WHITE,
+        );
+    } else {
+        painter.rect_filled(art_rect, Rounding::same(2.0 * scale), visuals.background.gamma_multiply(0.6));
+    }
+    painter.rect_stroke(art_rect, Rounding::same(2.0 * scale), Stroke::new(1.0 * scale, frame));
+
+    // Type banner
+    let banner_rect = Rect::from_min_size(
+        Pos2::new(rect.min.x + pad * 2.0, art_rect.max.y - 2.0 * scale),
+        Vec2::new(rect.width() - 4.0 * pad, 16.0 * scale),
+    );
+    painter.rect_filled(banner_rect, Rounding::same(3.0 * scale), card_type_color(&card.card_type));
+    painter.text(
+        banner_rect.center(),
+        Align2::CENTER_CENTER,
+        card_type_label(&card.card_type),
+        FontId::new(10.0 * scale, FontFamily::Proportional),
+        Color32::WHITE,
+    );
+
+    // Description
+    let desc_rect = Rect::from_min_max(
+        Pos2::new(rect.min.x + pad, banner_rect.max.y + 4.0 * scale),
+        Pos2::new(rect.max.x - pad, rect.max.y - pad),
+    );
+    let galley = painter.layout(
+        localize_card_description(painter.ctx(), card),
+        FontId::new(11.0 * scale, FontFamily::Proportional),
+        visuals.text_color,
+        desc_rect.width(),
+    );
+    let text_pos = Pos2::new(desc_rect.center().x - galley.size().x / 2.0, desc_rect.min.y);
+    painter.with_clip_rect(desc_rect).galley(text_pos, galley, visuals.text_color);
+
+    // Cost bubble
+    let radius = 13.0 * scale;
+    let center = rect.min + Vec2::splat(radius * 0.8);
+    painter.circle_filled(center, radius, visuals.cost_color);
+    painter.circle_stroke(center, radius, Stroke::new(1.5 * scale, frame));
+    painter.text(
+        center,
+        Align2::CENTER_CENTER,
+        card.cost.to_string(),
+        FontId::new(14.0 * scale, FontFamily::Proportional),
+        Color32::WHITE,
+    );
+}
+
+/// Framed card widget showing cost bubble, title, art, type banner and description.
+/// Returns hover/click/drag state.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::card::{Card, CardType};
+/// # use deckbuilder_eng::ui_card::{ui_card, CardVisuals};
+/// # fn demo(ui: &mut Ui) {
+/// let card = Card::new(1, "Strike", "Deal 6 damage", 1, CardType::Attack);
+/// let res = ui_card(ui, &card, &CardVisuals::default());
+/// if res.clicked {
+///     // play the card
+/// }
+/// # }
+/// ```
+pub fn ui_card(ui: &mut Ui, card: &Card, visuals: &CardVisuals) -> CardResponse {
+    let (rect, response) = ui.allocate_exact_size(visuals.size, Sense::click_and_drag());
+    if ui.is_rect_visible(rect) {
+        paint_card(ui.painter(), rect, card, visuals, response.hovered() || response.has_focus());
+    }
+    CardResponse::from_response(response)
+}
+
+/// Layout settings for [`ui_hand`].
+#[derive(Clone)]
+pub struct HandLayoutConfig {
+    /// Visuals used for every card in the hand.
+    pub visuals: CardVisuals,
+    /// How much neighbouring cards overlap, as a fraction of card width (0.0 to 1.0).
+    pub overlap: f32,
+    /// How far the outermost cards drop below the middle ones, forming the fan arc.
+    pub arc_height: f32,
+    /// How far the hovered card is raised.
+    pub hover_lift: f32,
+    /// Scale factor applied to the hovered card.
+    pub hover_scale: f32,
+    /// Maximum width of the hand; defaults to the available width.
+    pub max_width: Option<f32>,
+}
+
+impl Default for HandLayoutConfig {
+    fn default() -> Self {
+        Self {
+            visuals: CardVisuals::default(),
+            overlap: 0.3,
+            arc_height: 20.0,
+            hover_lift: 30.0,
+            hover_scale: 1.15,
+            max_width: None,
+        }
+    }
+}
+
+/// Interaction state returned by [`ui_hand`]. Indices refer to the `cards` slice.
+pub struct HandResponse {
+    /// The response covering the whole hand area.
+    pub response: Response,
+    /// Index of the card under the pointer.
+    pub hovered: Option<usize>,
+    /// Index of the card clicked this frame.
+    pub clicked: Option<usize>,
+    /// Index of the card a drag started on this frame.
+    pub drag_started: Option<usize>,
+}
+
+/// Computes the resting rect of each card of a fanned hand inside `area`.
+fn hand_card_rects(area: Rect, count: usize, config: &HandLayoutConfig) -> Vec<Rect> {
+    let size = config.visuals.size;
+    if count == 0 {
+        return Vec::new();
+    }
+    let step = if count > 1 {
+        (size.x * (1.0 - config.overlap)).min((area.width() - size.x) / (count - 1) as f32)
+    } else {
+        0.0
+    };
+    let total = size.x + step * (count - 1) as f32;
+    let left = area.center().x - total / 2.0;
+    let half = (count - 1) as f32 / 2.0;
+    (0..count)
+        .map(|i| {
+            let t = if half > 0.0 { (i as f32 - half) / half } else { 0.0 };
+            let top = area.max.y - size.y - config.arc_height + config.arc_height * t * t;
+            Rect::from_min_size(Pos2::new(left + step * i as f32, top), size)
+        })
+        .collect()
+}
+
+/// Fanned hand of cards laid out on an arc with overlap. The hovered card is raised,
+/// enlarged and drawn on top. Reports which card is hovered, clicked or started dragging.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::card::{Card, CardType};
+/// # use deckbuilder_eng::ui_card::{ui_hand, HandLayoutConfig};
+/// # fn demo(ui: &mut Ui) {
+/// let hand = vec![
+///     Card::new(1, "Strike", "Deal 6 damage", 1, CardType::Attack),
+///     Card::new(2, "Defend", "Gain 5 block", 1, CardType::Skill),
+/// ];
+/// let res = ui_hand(ui, &hand, &HandLayoutConfig::default());
+/// if let Some(i) = res.clicked {
+///     println!("Clicked {}", hand[i].name);
+/// }
+/// # }
+/// ```
+pub fn ui_hand(ui: &mut Ui, cards: &[Card], config: &HandLayoutConfig) -> HandResponse {
+    let size = config.visuals.size;
+    let width = config.max_width.unwrap_or_else(|| ui.available_width());
+    let height = size.y * config.hover_scale + config.arc_height + config.hover_lift;
+    let (area, response) = ui.allocate_exact_size(Vec2::new(width, height), Sense::click_and_drag());
+    let rects = hand_card_rects(area, cards.len(), config);
+
+    // Topmost card under the pointer wins; cards are hit-tested including their lifted area.
+    let hovered = response.hover_pos().and_then(|pos| {
+        rects.iter().rposition(|r| {
+            Rect::from_min_max(r.min - Vec2::new(0.0, config.hover_lift), r.max).contains(pos)
+        })
+    });
+    let pressed = ui
+        .input(|i| i.pointer.press_origin())
+        .and_then(|pos| rects.iter().rposition(|r| r.contains(pos)));
+
+    if ui.is_rect_visible(area) {
+        // Cards slide and lift smoothly instead of snapping to their new slots.
+        let animator = Animator::new(0.12);
+        let painted: Vec<Rect> = rects
+            .iter()
+            .enumerate()
+            .map(|(i, rect)| {
+                let target = if Some(i) == hovered {
+                    Rect::from_center_size(
+                        Pos2::new(rect.center().x, area.max.y - size.y * config.hover_scale / 2.0 - config.hover_lift),
+                        size * config.hover_scale,
+                    )
+                } else {
+                    *rect
+                };
+                animator.animate(ui.ctx(), response.id.with(("hand_card", cards[i].id)), target)
+            })
+            .collect();
+        let painter = ui.painter();
+        for (i, (card, rect)) in cards.iter().zip(&painted).enumerate() {
+            if Some(i) != hovered {
+                paint_card(painter, *rect, card, &config.visuals, false);
+            }
+        }
+        if let Some(i) = hovered {
+            paint_card(painter, painted[i], &cards[i], &config.visuals, true);
+        }
+    }
+
+    HandResponse {
+        hovered,
+        clicked: if response.clicked() { hovered } else { None },
+        drag_started: if response.drag_started() { pressed } else { None },
+        response,
+    }
+}
+
+/// Paints a card back into `rect` (used for piles and face-down cards).
+///
+/// # Example
+/// ```rust
+/// # use egui::{Ui, Rect, Pos2, Vec2};
+/// # use deckbuilder_eng::ui_card::{paint_card_back, CardVisuals};
+/// # fn demo(ui: &mut Ui) {
+/// let rect = Rect::from_min_size(Pos2::new(10.0, 10.0), Vec2::new(70.0, 100.0));
+/// paint_card_back(ui.painter(), rect, &CardVisuals::default(), false);
+/// # }
+/// ```
+pub fn paint_card_back(painter: &Painter, rect: Rect, visuals: &CardVisuals, hovered: bool) {
+    let scale = rect.height() / 200.0;
+    let rounding = Rounding::same(visuals.rounding * scale);
+    let frame = if hovered { visuals.hover_color } else { visuals.frame_color };
+    painter.rect_filled(rect, rounding, visuals.background);
+    painter.rect_stroke(rect, rounding, Stroke::new(2.0 * scale, frame));
+    let inner = rect.shrink(10.0 * scale);
+    painter.rect_stroke(inner, Rounding::same(4.0 * scale), Stroke::new(1.5 * scale, frame.gamma_multiply(0.7)));
+    painter.circle_stroke(inner.center(), inner.width() * 0.3, Stroke::new(2.0 * scale, frame.gamma_multiply(0.7)));
+}
+
+/// Stack of card backs with a count badge; clicking it toggles a list of the cards.
+fn ui_pile(ui: &mut Ui, title: &str, cards: &[Card], sort: bool) -> Response {
+    let visuals = CardVisuals {
+        size: Vec2::new(70.0, 100.0),
+        ..CardVisuals::default()
+    };
+    let layers = cards.len().min(3);
+    let offset = Vec2::new(3.0, -3.0);
+    let (rect, response) =
+        ui.allocate_exact_size(visuals.size + Vec2::new(offset.x, -offset.y) * 2.0, Sense::click());
+    let response = response.on_hover_text(format!("{} ({})", title, cards.len()));
+
+    if ui.is_rect_visible(rect) {
+        let painter = ui.painter();
+        let base = Rect::from_min_size(Pos2::new(rect.min.x, rect.max.y - visuals.size.y), visuals.size);
+        if layers == 0 {
+            painter.rect_stroke(
+                base,
+                Rounding::same(visuals.rounding * 0.5),
+                Stroke::new(1.5, visuals.frame_color.gamma_multiply(0.4)),
+            );
+        }
+        for i in 0..layers {
+            let hovered = response.hovered() && i + 1 == layers;
+            paint_card_back(painter, base.translate(offset * i as f32), &visuals, hovered);
+        }
+
+        let badge = Pos2::new(rect.max.x - 6.0, rect.max.y - 6.0);
+        painter.circle_filled(badge, 12.0, Color32::from_rgb(30, 30, 30));
+        painter.circle_stroke(badge, 12.0, Stroke::new(1.5, visuals.frame_color));
+        painter.text(
+            badge,
+            Align2::CENTER_CENTER,
+            cards.len().to_string(),
+            FontId::proportional(13.0),
+            Color32::WHITE,
+        );
+    }
+
+    let open_id = response.id.with("open");
+    let mut open = ui.data(|d| d.get_temp::<bool>(open_id)).unwrap_or(false);
+    if response.clicked() {
+        open = !open;
+    }
+    if open {
+        let mut listed: Vec<&Card> = cards.iter().collect();
+        if sort {
+            // Sorted so the overlay doesn't reveal the draw order.
+            listed.sort_by(|a, b| a.cost.cmp(&b.cost).then_with(|| a.name.cmp(&b.name)));
+        }
+        let small = CardVisuals {
+            size: Vec2::new(105.0, 150.0),
+            ..CardVisuals::default()
+        };
+        ui_modal(ui.ctx(), open_id.with("overlay"), |ui| {
+            ui.horizontal(|ui| {
+                ui.heading(format!("{} ({})", title, cards.len()));
+                if ui.button("Close").clicked() {
+                    open = false;
+                }
+            });
+            ui.separator();
+            ScrollArea::vertical().max_height(420.0).show(ui, |ui| {
+                ui.set_width(5.0 * (small.size.x + ui.spacing().item_spacing.x));
+                if listed.is_empty() {
+                    ui.label("Empty");
+                }
+                ui.horizontal_wrapped(|ui| {
+                    for card in listed {
+                        ui_card(ui, card, &small);
+                    }
+                });
+            });
+        });
+        if ui.input(|i| i.key_pressed(Key::Escape)) {
+            open = false;
+        }
+    }
+    ui.data_mut(|d| d.insert_temp(open_id, open));
+    response
+}
+
+/// Draw pile widget: a card-back stack with a count badge. Clicking it opens an overlay
+/// listing the remaining cards, sorted by cost and name so the draw order stays hidden.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::card::Deck;
+/// # use deckbuilder_eng::ui_card::ui_draw_pile;
+/// # fn demo(ui: &mut Ui, deck: &Deck) {
+/// ui_draw_pile(ui, deck);
+/// # }
+/// ```
+pub fn ui_draw_pile(ui: &mut Ui, deck: &Deck) -> Response {
+    ui_pile(ui, "Draw pile", &deck.draw_pile, true)
+}
+
+/// Discard pile widget: a card-back stack with a count badge. Clicking it opens an overlay
+/// listing the discarded cards in discard order.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::card::Deck;
+/// # use deckbuilder_eng::ui_card::ui_discard_pile;
+/// # fn demo(ui: &mut Ui, deck: &Deck) {
+/// ui.horizontal(|ui| {
+///     ui_discard_pile(ui, deck);
+/// });
+/// # }
+/// ```
+pub fn ui_discard_pile(ui: &mut Ui, deck: &Deck) -> Response {
+    ui_pile(ui, "Discard pile", &deck.discard_pile, false)
+}
+
+/// Returns the display label of a rarity.
+///
+/// # Example
+/// ```rust
+/// # use deckbuilder_eng::card::Rarity;
+/// # use deckbuilder_eng::ui_card::rarity_label;
+/// assert_eq!(rarity_label(Rarity::Rare), "Rare");
+/// ```
+pub fn rarity_label(rarity: Rarity) -> &'static str {
+    match rarity {
+        Rarity::Common => "Common",
+        Rarity::Uncommon => "Uncommon",
+        Rarity::Rare => "Rare",
+    }
+}
+
+/// Search text, filters and selection of a [`ui_card_grid`]. Keep it between frames.
+#[derive(Clone)]
+pub struct CardGridState {
+    /// Text matched against card names and descriptions (case-insensitive).
+    pub search: String,
+    /// Only show cards of this type.
+    pub type_filter: Option<CardType>,
+    /// Only show cards of this cost; `3` also matches higher costs.
+    pub cost_filter: Option<u32>,
+    /// Only show cards of this rarity.
+    pub rarity_filter: Option<Rarity>,
+    /// Index of the selected card in the slice passed to the grid.
+    pub selected: Option<usize>,
+    /// Whether the search box and filter row are shown.
+    pub show_filters: bool,
+    /// Visuals of the cards in the grid.
+    pub visuals: CardVisuals,
+}
+
+impl Default for CardGridState {
+    fn default() -> Self {
+        Self {
+            search: String::new(),
+            type_filter: None,
+            cost_filter: None,
+            rarity_filter: None,
+            selected: None,
+            show_filters: true,
+            visuals: CardVisuals {
+                size: Vec2::new(105.0, 150.0),
+                ..CardVisuals::default()
+            },
+        }
+    }
+}
+
+impl CardGridState {
+    /// Returns `true` if `card` passes the search text and all filters.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::card::{Card, CardType};
+    /// # use deckbuilder_eng::ui_card::CardGridState;
+    /// let mut state = CardGridState::default();
+    /// state.type_filter = Some(CardType::Skill);
+    /// let strike = Card::new(1, "Strike", "Deal 6 damage", 1, CardType::Attack);
+    /// assert!(!state.matches(&strike));
+    /// ```
+    pub fn matches(&self, card: &Card) -> bool {
+        let search = self.search.trim().to_lowercase();
+        (search.is_empty()
+            || card.name.to_lowercase().contains(&search)
+            || card.description.to_lowercase().contains(&search))
+            && self.type_filter.as_ref().is_none_or(|t| *t == card.card_type)
+            && self.cost_filter.is_none_or(|c| if c >= 3 { card.cost >= 3 } else { card.cost == c })
+            && self.rarity_filter.is_none_or(|r| r == card.rarity)
+    }
+}
+
+/// Result of a [`ui_card_grid`] frame. Indices refer to the slice passed to the grid.
+pub struct CardGridResponse {
+    /// Card under the pointer.
+    pub hovered: Option<usize>,
+    /// Card clicked this frame.
+    pub clicked: Option<usize>,
+    /// Card double-clicked this frame (e.g. to confirm a pick).
+    pub double_clicked: Option<usize>,
+    /// Whether `CardGridState::selected` changed this frame.
+    pub selection_changed: bool,
+}
+
+fn ui_grid_filters(ui: &mut Ui, state: &mut CardGridState) {
+    ui.horizontal_wrapped(|ui| {
+        ui.add(egui::TextEdit::singleline(&mut state.search).hint_text("Search...").desired_width(160.0));
+
+        egui::ComboBox::from_id_source("card_grid_type")
+            .selected_text(state.type_filter.as_ref().map_or("All types", card_type_label))
+            .show_ui(ui, |ui| {
+                ui.selectable_value(&mut state.type_filter, None, "All types");
+                for t in [CardType::Attack, CardType::Skill, CardType::Power] {
+                    let label = card_type_label(&t);
+                    ui.selectable_value(&mut state.type_filter, Some(t), label);
+                }
+            });
+
+        egui::ComboBox::from_id_source("card_grid_rarity")
+            .selected_text(state.rarity_filter.map_or("All rarities", rarity_label))
+            .show_ui(ui, |ui| {
+                ui.selectable_value(&mut state.rarity_filter, None, "All rarities");
+                for r in [Rarity::Common, Rarity::Uncommon, Rarity::Rare] {
+                    ui.selectable_value(&mut state.rarity_filter, Some(r), rarity_label(r));
+                }
+            });
+
+        ui.label("Cost:");
+        ui.selectable_value(&mut state.cost_filter, None, "Any");
+        for cost in 0..=3 {
+            let label = if cost == 3 { "3+".to_owned() } else { cost.to_string() };
+            ui.selectable_value(&mut state.cost_filter, Some(cost), label);
+        }
+    });
+}
+
+/// Scrollable, responsive grid of cards with a search box and type/cost/rarity filters.
+/// Clicking a card selects it; the response reports hover, clicks and selection changes.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::card::{Card, CardType};
+/// # use deckbuilder_eng::ui_card::{ui_card_grid, CardGridState};
+/// # fn demo(ui: &mut Ui, state: &mut CardGridState) {
+/// let cards = vec![
+///     Card::new(1, "Strike", "Deal 6 damage", 1, CardType::Attack),
+///     Card::new(2, "Defend", "Gain 5 block", 1, CardType::Skill),
+/// ];
+/// let res = ui_card_grid(ui, &cards, state);
+/// if let Some(i) = res.double_clicked {
+///     println!("Picked {}", cards[i].name);
+/// }
+/// # }
+/// ```
+pub fn ui_card_grid(ui: &mut Ui, cards: &[Card], state: &mut CardGridState) -> CardGridResponse {
+    if state.show_filters {
+        ui_grid_filters(ui, state);
+        ui.separator();
+    }
+
+    let mut result = CardGridResponse {
+        hovered: None,
+        clicked: None,
+        double_clicked: None,
+        selection_changed: false,
+    };
+    let visible: Vec<usize> = (0..cards.len()).filter(|&i| state.matches(&cards[i])).collect();
+    let spacing = ui.spacing().item_spacing;
+    let size = state.visuals.size;
+    let columns = (((ui.available_width() + spacing.x) / (size.x + spacing.x)).floor() as usize).max(1);
+
+    if visible.is_empty() {
+        ui.label("No cards match the filters.");
+    }
+    let rows: Vec<&[usize]> = visible.chunks(columns).collect();
+    // Only visible rows are laid out, so large collections stay fast.
+    ScrollArea::vertical().id_source("card_grid_scroll").show_rows(ui, size.y, rows.len(), |ui, range| {
+        for row in &rows[range] {
+            ui.horizontal(|ui| {
+                for &i in row.iter() {
+                    let (rect, response) = ui.allocate_exact_size(size, Sense::click());
+                    let selected = state.selected == Some(i);
+                    if ui.is_rect_visible(rect) {
+                        let highlighted = response.hovered() || response.has_focus() || selected;
+                        paint_card(ui.painter(), rect, &cards[i], &state.visuals, highlighted);
+                        if selected {
+                            ui.painter().rect_stroke(
+                                rect.expand(3.0),
+                                Rounding::same(state.visuals.rounding),
+                                Stroke::new(2.0, state.visuals.hover_color),
+                            );
+                        }
+                    }
+                    if response.hovered() {
+                        result.hovered = Some(i);
+                    }
+                    if response.clicked() {
+                        result.clicked = Some(i);
+                        if !selected {
+                            state.selected = Some(i);
+                            result.selection_changed = true;
+                        }
+                    }
+                    if response.double_clicked() {
+                        result.double_clicked = Some(i);
+                    }
+                }
+            });
+        }
+    });
+    result
+}
+
+/// Settings of the [`ui_card_choice`] overlay.
+#[derive(Clone)]
+pub struct CardChoiceConfig {
+    /// Heading shown above the cards.
+    pub title: String,
+    /// Visuals of the offered cards.
+    pub visuals: CardVisuals,
+    /// Scale of the hovered card.
+    pub hover_scale: f32,
+    /// Whether a Skip button is shown.
+    pub allow_skip: bool,
+    /// Text of the Skip button.
+    pub skip_label: String,
+}
+
+impl Default for CardChoiceConfig {
+    fn default() -> Self {
+        Self {
+            title: "Choose a card".to_owned(),
+            visuals: CardVisuals::default(),
+            hover_scale: 1.15,
+            allow_skip: true,
+            skip_label: "Skip".to_owned(),
+        }
+    }
+}
+
+/// Outcome of a [`ui_card_choice`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChoiceResult {
+    /// The card at this index was picked.
+    Picked(usize),
+    /// The player skipped the reward.
+    Skipped,
+}
+
+/// Card reward overlay: shows the offered cards side by side in a modal, zooming the hovered
+/// one, with an optional Skip button. Returns `None` until the player decides.
+///
+/// # Example
+/// ```rust
+/// # use egui::Context;
+/// # use deckbuilder_eng::card::{Card, CardType};
+/// # use deckbuilder_eng::ui_card::{ui_card_choice, CardChoiceConfig, ChoiceResult};
+/// # fn demo(ctx: &Context, rewards: &mut Vec<Card>, deck: &mut Vec<Card>) {
+/// match ui_card_choice(ctx, rewards, &CardChoiceConfig::default()) {
+///     Some(ChoiceResult::Picked(i)) => {
+///         deck.push(rewards.remove(i));
+///         rewards.clear();
+///     }
+///     Some(ChoiceResult::Skipped) => rewards.clear(),
+///     None => {}
+/// }
+/// # }
+/// ```
+pub fn ui_card_choice(ctx: &Context, cards: &[Card], config: &CardChoiceConfig) -> Option<ChoiceResult> {
+    let mut result = None;
+    let size = config.visuals.size;
+    let slot = size * config.hover_scale;
+    ui_modal(ctx, Id::new("card_choice"), |ui| {
+        ui.vertical_centered(|ui| {
+            ui.heading(&config.title);
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                for (i, card) in cards.iter().enumerate() {
+                    let (rect, response) = ui.allocate_exact_size(slot, Sense::click());
+                    let highlighted = response.hovered() || response.has_focus();
+                    let target = if highlighted { config.hover_scale } else { 1.0 };
+                    let scale = Animator::new(0.1).animate(ui.ctx(), response.id.with("zoom"), target);
+                    if ui.is_rect_visible(rect) {
+                        let card_rect = Rect::from_center_size(rect.center(), size * scale);
+                        paint_card(ui.painter(), card_rect, card, &config.visuals, highlighted);
+                    }
+                    if response.clicked() {
+                        result = Some(ChoiceResult::Picked(i));
+                    }
+                }
+            });
+            if config.allow_skip {
+                ui.add_space(8.0);
+                if ui.button(&config.skip_label).clicked() {
+                    result = Some(ChoiceResult::Skipped);
+                }
+            }
+        });
+    });
+    result
+}
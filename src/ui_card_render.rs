@@ -0,0 +1,878 @@
+//! Layered card rendering for egui-based deckbuilder games.
+//!
+//! Provides a [`CardRenderer`] that composites a card from layers (frame, art, cost gem, type
+//! banner, title, description and decoration images) described by a [`CardTheme`]. Cards are
+//! either painted live or baked once into a texture that is cheap to draw many times, e.g. for
+//! a large hand or a deck view.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::card::{Card, CardType};
+//! use deckbuilder_eng::ui_card_render::{CardRenderer, CardTheme};
+//! use egui::{CentralPanel, Context, Vec2};
+//!
+//! fn make_renderer(ctx: &Context) -> CardRenderer {
+//!     let theme = CardTheme::load("assets/themes/card.theme").unwrap_or_default();
+//!     let mut renderer = CardRenderer::new(theme);
+//!     for path in renderer.load_images(ctx) {
+//!         eprintln!("Missing card layer image: {}", path);
+//!     }
+//!     renderer
+//! }
+//!
+//! fn hand_ui(ctx: &Context, renderer: &mut CardRenderer) {
+//!     let card = Card::new(1, "Strike", "Deal 6 damage.", 1, CardType::Attack);
+//!     CentralPanel::default().show(ctx, |ui| {
+//!         // Live: painted layer by layer every frame
+//!         renderer.ui(ui, &card, None, Vec2::new(140.0, 200.0));
+//!         // Baked: composited once, then drawn as a single image
+//!         let texture = renderer.bake_cached(ctx, &card, None, Vec2::new(140.0, 200.0));
+//!         ui.image((texture.id(), Vec2::new(140.0, 200.0)));
+//!     });
+//! }
+//! ```
+//!
+//! # Details
+//!
+//! - Layer rects are fractions of the card size; font sizes, radii and stroke widths are given
+//!   for a 200 point tall card and scale with the card height.
+//! - Theme files are plain text: a `[layer]` header per layer, in drawing order, followed by
+//!   `key = value` lines (see [`CardTheme::parse`]).
+//! - Baking composites on the CPU and rasterizes text from egui's font atlas, so it must be
+//!   called during a frame. [`CardRenderer::bake_cached`] keeps baked textures until the card
+//!   or the language changes.
+//! - [`export_deck_image`] composites a whole deck into a PNG grid for sharing.
+//! - Names and descriptions are translated by the installed [`crate::i18n::I18n`].
+//! - See each struct and function's documentation for more.
+
+use std::collections::HashMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::path::Path;
+
+use egui::{Align2, Color32, Context, FontFamily, FontId, Painter, Pos2, Rect, Response, Rounding, Sense, Stroke, TextureHandle, Ui, Vec2};
+use image::imageops::FilterType;
+use image::{Rgba, RgbaImage};
+use thiserror::Error;
+
+use crate::card::{Card, Deck};
+use crate::i18n::{i18n_revision, localize_card_description, localize_card_name};
+use crate::ui_card::{card_type_color, card_type_label};
+use crate::ui_image::ImageFit;
+
+/// Card height the theme's font sizes, radii and stroke widths are given for.
+const REFERENCE_HEIGHT: f32 = 200.0;
+
+/// Errors from loading a [`CardTheme`].
+#[derive(Debug, Error)]
+pub enum CardThemeError {
+    /// The theme file couldn't be read.
+    #[error("failed to read card theme: {0}")]
+    Io(#[from] std::io::Error),
+    /// Malformed theme line.
+    #[error("card theme line {line}: {message}")]
+    Parse { line: usize, message: String },
+}
+
+/// What a layer draws on top of its background.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayerKind {
+    /// Card body; background only.
+    Frame,
+    /// The card's art, cropped to fill the layer.
+    Art,
+    /// The cost, centered.
+    CostGem,
+    /// The card type label; the background defaults to the type color.
+    TypeBanner,
+    /// The card name, centered on one line.
+    Title,
+    /// The description, wrapped and clipped to the layer.
+    Description,
+    /// A decoration; background only.
+    Image,
+}
+
+impl LayerKind {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "frame" => Self::Frame,
+            "art" => Self::Art,
+            "cost" => Self::CostGem,
+            "banner" => Self::TypeBanner,
+            "title" => Self::Title,
+            "description" => Self::Description,
+            "image" => Self::Image,
+            _ => return None,
+        })
+    }
+}
+
+/// One layer of a card: a rounded background (color and/or image), an optional border, and
+/// the content of its [`LayerKind`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CardLayer {
+    /// What the layer draws.
+    pub kind: LayerKind,
+    /// Area of the layer as fractions of the card size (`0..1`).
+    pub rect: Rect,
+    /// Path of an image stretched over the background.
+    pub image: Option<String>,
+    /// Background fill; `None` draws no fill (type banners use the type color).
+    pub color: Option<Color32>,
+    /// Border color.
+    pub stroke_color: Color32,
+    /// Border width; `0.0` draws no border.
+    pub stroke_width: f32,
+    /// Color of the layer's text.
+    pub text_color: Color32,
+    /// Font size of the layer's text.
+    pub font_size: f32,
+    /// Corner radius of the background and border.
+    pub rounding: f32,
+}
+
+impl CardLayer {
+    /// Creates a layer covering `rect` (fractions of the card size) with no fill or border.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::{Color32, Pos2, Rect};
+    /// # use deckbuilder_eng::ui_card_render::{CardLayer, LayerKind};
+    /// let mut seal = CardLayer::new(LayerKind::Image, Rect::from_min_max(Pos2::new(0.8, 0.9), Pos2::new(0.95, 1.0)));
+    /// seal.image = Some("assets/ui/seal.png".into());
+    /// ```
+    pub fn new(kind: LayerKind, rect: Rect) -> Self {
+        Self {
+            kind,
+            rect,
+            image: None,
+            color: None,
+            stroke_color: Color32::TRANSPARENT,
+            stroke_width: 0.0,
+            text_color: Color32::WHITE,
+            font_size: 12.0,
+            rounding: 0.0,
+        }
+    }
+
+    fn background(&self, card: &Card) -> Option<Color32> {
+        match self.kind {
+            LayerKind::TypeBanner => Some(self.color.unwrap_or_else(|| card_type_color(&card.card_type))),
+            _ => self.color,
+        }
+    }
+
+    fn text(&self, ctx: &Context, card: &Card) -> Option<String> {
+        match self.kind {
+            LayerKind::CostGem => Some(card.cost.to_string()),
+            LayerKind::TypeBanner => Some(card_type_label(&card.card_type).to_string()),
+            LayerKind::Title => Some(localize_card_name(ctx, card)),
+            LayerKind::Description => Some(localize_card_description(ctx, card)),
+            _ => None,
+        }
+    }
+}
+
+/// Layer definitions of a card, drawn in order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CardTheme {
+    /// Default card size in points.
+    pub size: Vec2,
+    /// Layers, bottom first.
+    pub layers: Vec<CardLayer>,
+}
+
+fn fraction_rect(x: f32, y: f32, w: f32, h: f32) -> Rect {
+    Rect::from_min_size(Pos2::new(x, y), Vec2::new(w, h))
+}
+
+impl Default for CardTheme {
+    /// The layout of [`crate::ui_card::paint_card`].
+    fn default() -> Self {
+        let frame_color = Color32::from_rgb(150, 130, 90);
+        let text_color = Color32::from_rgb(235, 230, 220);
+        let layers = vec![
+            CardLayer {
+                color: Some(Color32::from_rgb(40, 36, 48)),
+                stroke_color: frame_color,
+                stroke_width: 2.0,
+                rounding: 8.0,
+                ..CardLayer::new(LayerKind::Frame, fraction_rect(0.0, 0.0, 1.0, 1.0))
+            },
+            CardLayer {
+                color: Some(Color32::from_rgb(24, 22, 29)),
+                stroke_color: frame_color,
+                stroke_width: 1.0,
+                rounding: 2.0,
+                ..CardLayer::new(LayerKind::Art, fraction_rect(0.043, 0.15, 0.914, 0.38))
+            },
+            CardLayer {
+                font_size: 10.0,
+                rounding: 3.0,
+                ..CardLayer::new(LayerKind::TypeBanner, fraction_rect(0.086, 0.52, 0.828, 0.08))
+            },
+            CardLayer {
+                text_color,
+                font_size: 13.0,
+                ..CardLayer::new(LayerKind::Title, fraction_rect(0.1, 0.03, 0.857, 0.11))
+            },
+            CardLayer {
+                text_color,
+                font_size: 11.0,
+                ..CardLayer::new(LayerKind::Description, fraction_rect(0.043, 0.62, 0.914, 0.35))
+            },
+            CardLayer {
+                color: Some(Color32::from_rgb(40, 110, 200)),
+                stroke_color: frame_color,
+                stroke_width: 1.5,
+                font_size: 14.0,
+                rounding: 13.0,
+                ..CardLayer::new(LayerKind::CostGem, fraction_rect(-0.019, -0.013, 0.186, 0.13))
+            },
+        ];
+        Self {
+            size: Vec2::new(140.0, 200.0),
+            layers,
+        }
+    }
+}
+
+fn parse_floats<const N: usize>(value: &str) -> Option<[f32; N]> {
+    let mut out = [0.0; N];
+    let mut parts = value.split(',');
+    for slot in &mut out {
+        *slot = parts.next()?.trim().parse().ok()?;
+    }
+    parts.next().is_none().then_some(out)
+}
+
+fn parse_theme_color(value: &str) -> Option<Color32> {
+    if value == "none" {
+        return Some(Color32::TRANSPARENT);
+    }
+    Color32::from_hex(value).ok()
+}
+
+impl CardTheme {
+    /// Parses a theme file. Lines are `key = value` pairs; lines starting with `#` are
+    /// comments. A `[kind]` header starts a layer, where kind is one of `frame`, `art`, `cost`,
+    /// `banner`, `title`, `description` or `image`. Layer keys:
+    ///
+    /// - `rect = x, y, width, height` as fractions of the card size (required)
+    /// - `image = path`
+    /// - `color`, `stroke_color`, `text_color` as `#rrggbb` or `#rrggbbaa`
+    /// - `stroke_width`, `font_size`, `rounding`
+    ///
+    /// The only key before the first layer is `size = width, height`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_card_render::{CardTheme, LayerKind};
+    /// let theme = CardTheme::parse(
+    ///     "size = 150, 210\n\
+    ///      [frame]\n\
+    ///      rect = 0, 0, 1, 1\n\
+    ///      image = assets/ui/card_frame.png\n\
+    ///      [title]\n\
+    ///      rect = 0.1, 0.04, 0.8, 0.1\n\
+    ///      text_color = #f0e6d2\n\
+    ///      font_size = 14\n",
+    /// )
+    /// .unwrap();
+    /// assert_eq!(theme.layers.len(), 2);
+    /// assert_eq!(theme.layers[1].kind, LayerKind::Title);
+    /// ```
+    pub fn parse(source: &str) -> Result<Self, CardThemeError> {
+        let mut theme = CardTheme {
+            size: CardTheme::default().size,
+            layers: Vec::new(),
+        };
+        let mut has_rect = true;
+        for (index, line) in source.lines().enumerate() {
+            let error = |message: &str| CardThemeError::Parse {
+                line: index + 1,
+                message: message.to_owned(),
+            };
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(name) = line.strip_prefix('[').and_then(|rest| rest.strip_suffix(']')) {
+                if !has_rect {
+                    return Err(error("previous layer has no `rect`"));
+                }
+                let kind = LayerKind::from_name(name.trim()).ok_or_else(|| error("unknown layer kind"))?;
+                theme.layers.push(CardLayer::new(kind, Rect::NOTHING));
+                has_rect = false;
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(error("expected `key = value`"));
+            };
+            let (key, value) = (key.trim(), value.trim());
+            let Some(layer) = theme.layers.last_mut() else {
+                match key {
+                    "size" => {
+                        let [w, h] = parse_floats(value).ok_or_else(|| error("expected `width, height`"))?;
+                        theme.size = Vec2::new(w, h);
+                    }
+                    _ => return Err(error("unknown key outside of a layer")),
+                }
+                continue;
+            };
+            let number = || value.parse::<f32>().map_err(|_| error("expected a number"));
+            let color = || parse_theme_color(value).ok_or_else(|| error("expected a `#rrggbb` color"));
+            match key {
+                "rect" => {
+                    let [x, y, w, h] = parse_floats(value).ok_or_else(|| error("expected `x, y, width, height`"))?;
+                    layer.rect = fraction_rect(x, y, w, h);
+                    has_rect = true;
+                }
+                "image" => layer.image = Some(value.to_owned()),
+                "color" => layer.color = Some(color()?),
+                "stroke_color" => layer.stroke_color = color()?,
+                "text_color" => layer.text_color = color()?,
+                "stroke_width" => layer.stroke_width = number()?,
+                "font_size" => layer.font_size = number()?,
+                "rounding" => layer.rounding = number()?,
+                _ => return Err(error("unknown layer key")),
+            }
+        }
+        if !has_rect {
+            return Err(CardThemeError::Parse {
+                line: source.lines().count(),
+                message: "last layer has no `rect`".to_owned(),
+            });
+        }
+        Ok(theme)
+    }
+
+    /// Reads and parses the theme file at `path`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_card_render::CardTheme;
+    /// let theme = CardTheme::load("missing.theme").unwrap_or_default();
+    /// assert!(!theme.layers.is_empty());
+    /// ```
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, CardThemeError> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+}
+
+/// Composites cards from the layers of a [`CardTheme`], live or into baked textures.
+pub struct CardRenderer {
+    theme: CardTheme,
+    images: HashMap<String, (TextureHandle, RgbaImage)>,
+    baked: HashMap<u64, TextureHandle>,
+    baked_revision: u64,
+}
+
+impl CardRenderer {
+    /// Creates a renderer for `theme`. Call [`CardRenderer::load_images`] before drawing
+    /// themes with image layers.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_card_render::{CardRenderer, CardTheme};
+    /// let renderer = CardRenderer::new(CardTheme::default());
+    /// ```
+    pub fn new(theme: CardTheme) -> Self {
+        Self {
+            theme,
+            images: HashMap::new(),
+            baked: HashMap::new(),
+            baked_revision: 0,
+        }
+    }
+
+    /// Returns the theme.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_card_render::{CardRenderer, CardTheme};
+    /// let renderer = CardRenderer::new(CardTheme::default());
+    /// assert_eq!(renderer.theme().size.y, 200.0);
+    /// ```
+    pub fn theme(&self) -> &CardTheme {
+        &self.theme
+    }
+
+    /// Loads the images of all layers that aren't loaded yet. Returns the paths that failed.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Context;
+    /// # use deckbuilder_eng::ui_card_render::{CardRenderer, CardTheme};
+    /// let mut renderer = CardRenderer::new(CardTheme::default());
+    /// assert!(renderer.load_images(&Context::default()).is_empty());
+    /// ```
+    pub fn load_images(&mut self, ctx: &Context) -> Vec<String> {
+        let mut failed = Vec::new();
+        let paths: Vec<String> = self.theme.layers.iter().filter_map(|layer| layer.image.clone()).collect();
+        for path in paths {
+            if self.images.contains_key(&path) {
+                continue;
+            }
+            match image::open(&path) {
+                Ok(img) => self.set_image(ctx, path, img.to_rgba8()),
+                Err(err) => {
+                    tracing::warn!(%err, "failed to load card layer image `{}`", path);
+                    failed.push(path);
+                }
+            }
+        }
+        failed
+    }
+
+    /// Provides the image for layers whose `image` is `key`, e.g. embedded with
+    /// `include_bytes!` instead of loaded from disk.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Context;
+    /// # use image::{Rgba, RgbaImage};
+    /// # use deckbuilder_eng::ui_card_render::{CardRenderer, CardTheme};
+    /// let mut renderer = CardRenderer::new(CardTheme::default());
+    /// let gold = RgbaImage::from_pixel(8, 8, Rgba([212, 175, 55, 255]));
+    /// renderer.set_image(&Context::default(), "gold_frame", gold);
+    /// ```
+    pub fn set_image(&mut self, ctx: &Context, key: impl Into<String>, image: RgbaImage) {
+        let key = key.into();
+        let size = [image.width() as usize, image.height() as usize];
+        let color_image = egui::ColorImage::from_rgba_unmultiplied(size, image.as_raw());
+        let texture = ctx.load_texture(&key, color_image, Default::default());
+        self.images.insert(key, (texture, image));
+        self.baked.clear();
+    }
+
+    fn layer_image(&self, layer: &CardLayer) -> Option<&(TextureHandle, RgbaImage)> {
+        layer.image.as_ref().and_then(|path| self.images.get(path))
+    }
+
+    /// Paints `card` into `rect` layer by layer, with `art` in the art layer.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::{Pos2, Rect, TextureHandle, Ui, Vec2};
+    /// # use deckbuilder_eng::card::Card;
+    /// # use deckbuilder_eng::ui_card_render::CardRenderer;
+    /// # fn demo(ui: &mut Ui, renderer: &CardRenderer, card: &Card, art: &TextureHandle) {
+    /// let rect = Rect::from_min_size(Pos2::new(40.0, 40.0), Vec2::new(140.0, 200.0));
+    /// renderer.paint(ui.painter(), rect, card, Some(art));
+    /// # }
+    /// ```
+    pub fn paint(&self, painter: &Painter, rect: Rect, card: &Card, art: Option<&TextureHandle>) {
+        let scale = rect.height() / REFERENCE_HEIGHT;
+        for layer in &self.theme.layers {
+            let area = Rect::from_min_max(rect.lerp_inside(layer.rect.min.to_vec2()), rect.lerp_inside(layer.rect.max.to_vec2()));
+            let rounding = Rounding::same(layer.rounding * scale);
+            if let Some(fill) = layer.background(card) {
+                painter.rect_filled(area, rounding, fill);
+            }
+            if let Some((texture, _)) = self.layer_image(layer) {
+                painter.image(texture.id(), area, Rect::from_min_max(Pos2::ZERO, Pos2::new(1.0, 1.0)), Color32::WHITE);
+            }
+            if let (LayerKind::Art, Some(art)) = (layer.kind, art) {
+                let (_, uv) = ImageFit::Fill(area.size()).layout(art.size_vec2());
+                painter.image(art.id(), area, uv, Color32::WHITE);
+            }
+            if let Some(text) = layer.text(painter.ctx(), card) {
+                let font = FontId::new(layer.font_size * scale, FontFamily::Proportional);
+                if layer.kind == LayerKind::Description {
+                    let galley = painter.layout(text, font, layer.text_color, area.width());
+                    let pos = Pos2::new(area.center().x - galley.size().x / 2.0, area.min.y);
+                    painter.with_clip_rect(area).galley(pos, galley, layer.text_color);
+                } else {
+                    painter.text(area.center(), Align2::CENTER_CENTER, text, font, layer.text_color);
+                }
+            }
+            if layer.stroke_width > 0.0 {
+                painter.rect_stroke(area, rounding, Stroke::new(layer.stroke_width * scale, layer.stroke_color));
+            }
+        }
+    }
+
+    /// Shows `card` at `size` and returns a clickable response.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::{Ui, Vec2};
+    /// # use deckbuilder_eng::card::Card;
+    /// # use deckbuilder_eng::ui_card_render::CardRenderer;
+    /// # fn demo(ui: &mut Ui, renderer: &CardRenderer, card: &Card) {
+    /// if renderer.ui(ui, card, None, Vec2::new(140.0, 200.0)).clicked() {
+    ///     println!("Play {}", card.name);
+    /// }
+    /// # }
+    /// ```
+    pub fn ui(&self, ui: &mut Ui, card: &Card, art: Option<&TextureHandle>, size: Vec2) -> Response {
+        let (rect, response) = ui.allocate_exact_size(size, Sense::click());
+        if ui.is_rect_visible(rect) {
+            self.paint(ui.painter(), rect, card, art);
+        }
+        response
+    }
+
+    /// Composites `card` at `size` points into a texture, rendered at the current pixels per
+    /// point. `art` is the card art as pixels, since textures can't be read back. Call during a
+    /// frame, as text is rasterized from egui's font atlas.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::{Context, Vec2};
+    /// # use deckbuilder_eng::card::{Card, CardType};
+    /// # use deckbuilder_eng::ui_card_render::{CardRenderer, CardTheme};
+    /// let ctx = Context::default();
+    /// let renderer = CardRenderer::new(CardTheme::default());
+    /// let card = Card::new(1, "Strike", "Deal 6 damage.", 1, CardType::Attack);
+    /// let _ = ctx.run(Default::default(), |ctx| {
+    ///     let texture = renderer.bake(ctx, &card, None, Vec2::new(140.0, 200.0));
+    ///     assert_eq!(texture.size(), [140, 200]);
+    /// });
+    /// ```
+    pub fn bake(&self, ctx: &Context, card: &Card, art: Option<&RgbaImage>, size: Vec2) -> TextureHandle {
+        let canvas = self.composite(ctx, card, art, size);
+        let image_size = [canvas.width() as usize, canvas.height() as usize];
+        let color_image = egui::ColorImage::from_rgba_unmultiplied(image_size, canvas.as_raw());
+        ctx.load_texture(format!("baked_card_{}", card.id), color_image, Default::default())
+    }
+
+    /// Like [`CardRenderer::bake`], but keeps the texture and returns it again while the
+    /// card's stats and texts, the size and the pixels per point stay the same. The cache is
+    /// cleared when the string tables change (see `i18n_revision`), so a language switch
+    /// re-renders the cards in the new language. Art isn't part of the cache key; call
+    /// [`CardRenderer::clear_baked`] after changing it.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::{Context, Vec2};
+    /// # use deckbuilder_eng::card::{Card, CardType};
+    /// # use deckbuilder_eng::i18n::{install_i18n, update_i18n, I18n};
+    /// # use deckbuilder_eng::ui_card_render::{CardRenderer, CardTheme};
+    /// let ctx = Context::default();
+    /// install_i18n(&ctx, I18n::new("en"));
+    /// let mut renderer = CardRenderer::new(CardTheme::default());
+    /// let card = Card::new(1, "Strike", "Deal 6 damage.", 1, CardType::Attack);
+    /// let size = Vec2::new(140.0, 200.0);
+    /// let _ = ctx.run(Default::default(), |ctx| {
+    ///     let first = renderer.bake_cached(ctx, &card, None, size);
+    ///     assert_eq!(renderer.bake_cached(ctx, &card, None, size).id(), first.id());
+    ///
+    ///     update_i18n(ctx, |i18n| i18n.set_language("tr"));
+    ///     assert_ne!(renderer.bake_cached(ctx, &card, None, size).id(), first.id());
+    /// });
+    /// ```
+    pub fn bake_cached(&mut self, ctx: &Context, card: &Card, art: Option<&RgbaImage>, size: Vec2) -> TextureHandle {
+        let revision = i18n_revision(ctx);
+        if revision != self.baked_revision {
+            self.baked.clear();
+            self.baked_revision = revision;
+        }
+        let mut hasher = DefaultHasher::new();
+        (card.id, &card.name, &card.description, card.cost, &card.card_type, card.rarity).hash(&mut hasher);
+        (size.x.to_bits(), size.y.to_bits(), ctx.pixels_per_point().to_bits()).hash(&mut hasher);
+        let key = hasher.finish();
+        if let Some(texture) = self.baked.get(&key) {
+            return texture.clone();
+        }
+        let texture = self.bake(ctx, card, art, size);
+        self.baked.insert(key, texture.clone());
+        texture
+    }
+
+    /// Drops the textures kept by [`CardRenderer::bake_cached`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_card_render::{CardRenderer, CardTheme};
+    /// let mut renderer = CardRenderer::new(CardTheme::default());
+    /// renderer.clear_baked();
+    /// ```
+    pub fn clear_baked(&mut self) {
+        self.baked.clear();
+    }
+
+    /// Composites `cards` into a grid image laid out by `options`, at the current pixels per
+    /// point. `art` returns the art pixels of a card, if any. Call during a frame, like
+    /// [`CardRenderer::bake`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::{Context, Vec2};
+    /// # use deckbuilder_eng::card::{Card, CardType};
+    /// # use deckbuilder_eng::ui_card_render::{CardRenderer, CardTheme, DeckImageOptions};
+    /// let ctx = Context::default();
+    /// let renderer = CardRenderer::new(CardTheme::default());
+    /// let cards = vec![Card::new(1, "Strike", "Deal 6 damage.", 1, CardType::Attack); 3];
+    /// let options = DeckImageOptions { columns: 2, card_size: Vec2::new(70.0, 100.0), spacing: 10.0, ..Default::default() };
+    /// let _ = ctx.run(Default::default(), |ctx| {
+    ///     let image = renderer.render_cards(ctx, &cards, |_| None, &options);
+    ///     assert_eq!((image.width(), image.height()), (170, 230));
+    /// });
+    /// ```
+    pub fn render_cards<'a>(
+        &self,
+        ctx: &Context,
+        cards: &[Card],
+        art: impl Fn(&Card) -> Option<&'a RgbaImage>,
+        options: &DeckImageOptions,
+    ) -> RgbaImage {
+        let ppp = ctx.pixels_per_point();
+        let columns = options.columns.max(1).min(cards.len().max(1));
+        let rows = cards.len().div_ceil(columns).max(1);
+        let cell = options.card_size + Vec2::splat(options.spacing);
+        let size = Vec2::new(columns as f32, rows as f32) * cell + Vec2::splat(options.spacing);
+        let pixels = (size * ppp).round();
+        let [r, g, b, a] = options.background.to_srgba_unmultiplied();
+        let mut canvas = RgbaImage::from_pixel(pixels.x.max(1.0) as u32, pixels.y.max(1.0) as u32, Rgba([r, g, b, a]));
+        for (i, card) in cards.iter().enumerate() {
+            let (column, row) = (i % columns, i / columns);
+            let pos = (Vec2::splat(options.spacing) + Vec2::new(column as f32, row as f32) * cell) * ppp;
+            let image = self.composite(ctx, card, art(card), options.card_size);
+            image::imageops::overlay(&mut canvas, &image, pos.x.round() as i64, pos.y.round() as i64);
+        }
+        canvas
+    }
+
+    /// Composites `card` at `size` points into pixels, at the current pixels per point.
+    fn composite(&self, ctx: &Context, card: &Card, art: Option<&RgbaImage>, size: Vec2) -> RgbaImage {
+        let ppp = ctx.pixels_per_point();
+        let pixels = (size * ppp).round();
+        let mut canvas = RgbaImage::new(pixels.x.max(1.0) as u32, pixels.y.max(1.0) as u32);
+        let card_rect = Rect::from_min_size(Pos2::ZERO, size);
+        let scale = size.y / REFERENCE_HEIGHT;
+
+        for layer in &self.theme.layers {
+            let area = Rect::from_min_max(
+                card_rect.lerp_inside(layer.rect.min.to_vec2()),
+                card_rect.lerp_inside(layer.rect.max.to_vec2()),
+            );
+            let px_area = Rect::from_min_max((area.min.to_vec2() * ppp).to_pos2(), (area.max.to_vec2() * ppp).to_pos2());
+            let radius = layer.rounding * scale * ppp;
+            if let Some(fill) = layer.background(card) {
+                fill_rounded_rect(&mut canvas, px_area, radius, fill);
+            }
+            if let Some((_, image)) = self.layer_image(layer) {
+                draw_image(&mut canvas, image, Rect::from_min_max(Pos2::ZERO, Pos2::new(1.0, 1.0)), px_area, radius);
+            }
+            if let (LayerKind::Art, Some(art)) = (layer.kind, art) {
+                let (_, uv) = ImageFit::Fill(area.size()).layout(Vec2::new(art.width() as f32, art.height() as f32));
+                draw_image(&mut canvas, art, uv, px_area, radius);
+            }
+            if let Some(text) = layer.text(ctx, card) {
+                let font = FontId::new(layer.font_size * scale, FontFamily::Proportional);
+                let wrap = if layer.kind == LayerKind::Description { area.width() } else { f32::INFINITY };
+                let (galley, atlas) = ctx.fonts(|fonts| (fonts.layout(text, font, layer.text_color, wrap), fonts.image()));
+                let pos = if layer.kind == LayerKind::Description {
+                    Pos2::new(area.center().x - galley.size().x / 2.0, area.min.y)
+                } else {
+                    area.center() - galley.size() / 2.0
+                };
+                draw_galley(&mut canvas, &galley, &atlas, pos, ppp, layer.text_color, px_area);
+            }
+            if layer.stroke_width > 0.0 {
+                stroke_rounded_rect(&mut canvas, px_area, radius, layer.stroke_width * scale * ppp, layer.stroke_color);
+            }
+        }
+        canvas
+    }
+}
+
+/// Grid layout of an exported deck image.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DeckImageOptions {
+    /// Cards per row.
+    pub columns: usize,
+    /// Size of each card in points.
+    pub card_size: Vec2,
+    /// Gap between cards and around the grid in points.
+    pub spacing: f32,
+    /// Background color behind the cards.
+    pub background: Color32,
+}
+
+impl Default for DeckImageOptions {
+    fn default() -> Self {
+        Self {
+            columns: 5,
+            card_size: Vec2::new(140.0, 200.0),
+            spacing: 12.0,
+            background: Color32::from_rgb(24, 24, 30),
+        }
+    }
+}
+
+/// Renders every card of `deck` (draw pile, then discard pile) into a PNG grid at `path`, for
+/// sharing deck screenshots. Uses the default [`DeckImageOptions`] and no card art. Call
+/// during a frame, like [`CardRenderer::bake`].
+///
+/// # Example
+/// ```rust
+/// # use egui::Context;
+/// # use deckbuilder_eng::card::Deck;
+/// # use deckbuilder_eng::ui_card_render::{export_deck_image, CardRenderer};
+/// # fn demo(ctx: &Context, renderer: &CardRenderer, deck: &Deck) {
+/// match export_deck_image(ctx, renderer, deck, "screenshots/deck.png") {
+///     Ok(()) => println!("Deck image saved"),
+///     Err(err) => eprintln!("Export failed: {}", err),
+/// }
+/// # }
+/// ```
+pub fn export_deck_image(
+    ctx: &Context,
+    renderer: &CardRenderer,
+    deck: &Deck,
+    path: impl AsRef<Path>,
+) -> Result<(), crate::Error> {
+    export_deck_image_with(ctx, renderer, deck, path, &DeckImageOptions::default(), |_| None)
+}
+
+/// Like [`export_deck_image`], with a custom layout and `art` returning the art pixels of a
+/// card.
+///
+/// # Example
+/// ```rust
+/// # use std::collections::HashMap;
+/// # use egui::Context;
+/// # use image::RgbaImage;
+/// # use deckbuilder_eng::card::{CardId, Deck};
+/// # use deckbuilder_eng::ui_card_render::{export_deck_image_with, CardRenderer, DeckImageOptions};
+/// # fn demo(ctx: &Context, renderer: &CardRenderer, deck: &Deck, art: &HashMap<CardId, RgbaImage>) {
+/// let options = DeckImageOptions { columns: 8, ..Default::default() };
+/// let _ = export_deck_image_with(ctx, renderer, deck, "deck.png", &options, |card| art.get(&card.id));
+/// # }
+/// ```
+pub fn export_deck_image_with<'a>(
+    ctx: &Context,
+    renderer: &CardRenderer,
+    deck: &Deck,
+    path: impl AsRef<Path>,
+    options: &DeckImageOptions,
+    art: impl Fn(&Card) -> Option<&'a RgbaImage>,
+) -> Result<(), crate::Error> {
+    let cards: Vec<Card> = deck.draw_pile.iter().chain(&deck.discard_pile).cloned().collect();
+    let path = path.as_ref();
+    renderer.render_cards(ctx, &cards, art, options).save(path).map_err(|err| crate::Error::image(path, err))
+}
+
+/// Blends `color` over the pixel at (`x`, `y`) with `coverage` in `0..=1`.
+fn blend(canvas: &mut RgbaImage, x: u32, y: u32, color: Color32, coverage: f32) {
+    let [r, g, b, a] = color.to_srgba_unmultiplied();
+    let src_a = a as f32 / 255.0 * coverage.clamp(0.0, 1.0);
+    if src_a <= 0.0 {
+        return;
+    }
+    let dst = canvas.get_pixel_mut(x, y);
+    let dst_a = dst[3] as f32 / 255.0;
+    let out_a = src_a + dst_a * (1.0 - src_a);
+    for (channel, src) in [r, g, b].into_iter().enumerate() {
+        let mixed = (src as f32 * src_a + dst[channel] as f32 * dst_a * (1.0 - src_a)) / out_a;
+        dst[channel] = mixed.round() as u8;
+    }
+    dst[3] = (out_a * 255.0).round() as u8;
+}
+
+/// Signed distance from pixel center `p` to the edge of a rounded rect (negative inside).
+fn rounded_rect_distance(p: Pos2, rect: Rect, radius: f32) -> f32 {
+    let radius = radius.min(rect.width() / 2.0).min(rect.height() / 2.0).max(0.0);
+    let q = (p - rect.center()).abs() - (rect.size() / 2.0 - Vec2::splat(radius));
+    q.max(Vec2::ZERO).length() + q.x.max(q.y).min(0.0) - radius
+}
+
+/// Calls `f` with every canvas pixel touching `rect` and its center.
+fn for_each_pixel(canvas: &RgbaImage, rect: Rect, mut f: impl FnMut(u32, u32, Pos2)) {
+    let x0 = rect.min.x.floor().max(0.0) as u32;
+    let y0 = rect.min.y.floor().max(0.0) as u32;
+    let x1 = (rect.max.x.ceil().max(0.0) as u32).min(canvas.width());
+    let y1 = (rect.max.y.ceil().max(0.0) as u32).min(canvas.height());
+    for y in y0..y1 {
+        for x in x0..x1 {
+            f(x, y, Pos2::new(x as f32 + 0.5, y as f32 + 0.5));
+        }
+    }
+}
+
+fn fill_rounded_rect(canvas: &mut RgbaImage, rect: Rect, radius: f32, color: Color32) {
+    let mut covered = Vec::new();
+    for_each_pixel(canvas, rect, |x, y, p| covered.push((x, y, 0.5 - rounded_rect_distance(p, rect, radius))));
+    for (x, y, coverage) in covered {
+        blend(canvas, x, y, color, coverage);
+    }
+}
+
+fn stroke_rounded_rect(canvas: &mut RgbaImage, rect: Rect, radius: f32, width: f32, color: Color32) {
+    let mut covered = Vec::new();
+    for_each_pixel(canvas, rect.expand(width), |x, y, p| {
+        let distance = rounded_rect_distance(p, rect, radius).abs();
+        covered.push((x, y, width / 2.0 + 0.5 - distance));
+    });
+    for (x, y, coverage) in covered {
+        blend(canvas, x, y, color, coverage);
+    }
+}
+
+/// Draws the `uv` part of `image` stretched into `rect`, clipped to its rounded corners.
+fn draw_image(canvas: &mut RgbaImage, image: &RgbaImage, uv: Rect, rect: Rect, radius: f32) {
+    let (w, h) = (rect.width().round() as u32, rect.height().round() as u32);
+    if w == 0 || h == 0 || image.width() == 0 || image.height() == 0 {
+        return;
+    }
+    let crop_x = (uv.min.x * image.width() as f32) as u32;
+    let crop_y = (uv.min.y * image.height() as f32) as u32;
+    let crop_w = ((uv.width() * image.width() as f32) as u32).clamp(1, image.width() - crop_x.min(image.width() - 1));
+    let crop_h = ((uv.height() * image.height() as f32) as u32).clamp(1, image.height() - crop_y.min(image.height() - 1));
+    let cropped = image::imageops::crop_imm(image, crop_x, crop_y, crop_w, crop_h).to_image();
+    let scaled = image::imageops::resize(&cropped, w, h, FilterType::Triangle);
+    let origin = rect.min.round();
+    let mut covered = Vec::new();
+    for_each_pixel(canvas, rect, |x, y, p| {
+        let (sx, sy) = (x as i64 - origin.x as i64, y as i64 - origin.y as i64);
+        if sx >= 0 && sy >= 0 && (sx as u32) < w && (sy as u32) < h {
+            let Rgba([r, g, b, a]) = *scaled.get_pixel(sx as u32, sy as u32);
+            let coverage = 0.5 - rounded_rect_distance(p, rect, radius);
+            covered.push((x, y, Color32::from_rgba_unmultiplied(r, g, b, a), coverage));
+        }
+    });
+    for (x, y, color, coverage) in covered {
+        blend(canvas, x, y, color, coverage);
+    }
+}
+
+/// Blits the glyphs of `galley` at `pos` (points) from the font atlas, clipped to `clip`
+/// (pixels).
+fn draw_galley(
+    canvas: &mut RgbaImage,
+    galley: &egui::Galley,
+    atlas: &egui::epaint::FontImage,
+    pos: Pos2,
+    ppp: f32,
+    color: Color32,
+    clip: Rect,
+) {
+    for row in &galley.rows {
+        for glyph in &row.glyphs {
+            let uv = glyph.uv_rect;
+            if uv.is_nothing() {
+                continue;
+            }
+            let left_top = ((pos + glyph.pos.to_vec2() + uv.offset).to_vec2() * ppp).round();
+            let (w, h) = ((uv.max[0] - uv.min[0]) as i64, (uv.max[1] - uv.min[1]) as i64);
+            for ty in 0..h {
+                for tx in 0..w {
+                    let (x, y) = (left_top.x as i64 + tx, left_top.y as i64 + ty);
+                    if x < 0 || y < 0 || x >= canvas.width() as i64 || y >= canvas.height() as i64 {
+                        continue;
+                    }
+                    if !clip.contains(Pos2::new(x as f32 + 0.5, y as f32 + 0.5)) {
+                        continue;
+                    }
+                    let (ax, ay) = (uv.min[0] as usize + tx as usize, uv.min[1] as usize + ty as usize);
+                    let coverage = atlas.pixels[ay * atlas.size[0] + ax];
+                    blend(canvas, x as u32, y as u32, color, coverage);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,322 @@
+//! Chart widgets for egui-based deckbuilder UIs.
+//!
+//! Provides bar, line and pie charts drawn with the egui painter, for mana curves,
+//! damage-per-turn graphs and run statistics screens, plus [`mana_curve`] to build the data
+//! for the most common one.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::card::Card;
+//! use deckbuilder_eng::ui_chart::*;
+//! use egui::{CentralPanel, Context};
+//!
+//! fn run_stats(ctx: &Context, deck: &[Card], damage_per_turn: &[f32]) {
+//!     CentralPanel::default().show(ctx, |ui| {
+//!         ui.heading("Mana curve");
+//!         ui_bar_chart(ui, &mana_curve(deck, 5), &ChartStyle::default());
+//!
+//!         ui.heading("Damage per turn");
+//!         ui_line_chart(ui, damage_per_turn, &ChartStyle::default());
+//!
+//!         ui.heading("Card types");
+//!         ui_pie_chart(ui, &[("Attack".into(), 8.0), ("Skill".into(), 6.0), ("Power".into(), 2.0)], &ChartStyle::default());
+//!     });
+//! }
+//! ```
+//!
+//! # Details
+//!
+//! - Charts allocate `ChartStyle::size` and show the value under the pointer as a tooltip.
+//! - Bar and line charts start their value axis at zero, or at the smallest value if it is
+//!   negative.
+//! - See each function's documentation for usage and customization options.
+
+use std::f32::consts::TAU;
+
+use egui::{Align2, Color32, FontId, Pos2, Rect, Response, Rounding, Sense, Shape, Stroke, Ui, Vec2};
+
+use crate::card::Card;
+
+/// Look of a chart.
+#[derive(Debug, Clone)]
+pub struct ChartStyle {
+    /// Size of the whole chart, labels included.
+    pub size: Vec2,
+    /// Bar and line color.
+    pub color: Color32,
+    /// Slice colors of pie charts, repeated if there are more slices.
+    pub palette: Vec<Color32>,
+    /// Print values above bars and points.
+    pub show_values: bool,
+    /// Font size of labels and values.
+    pub label_size: f32,
+}
+
+impl Default for ChartStyle {
+    fn default() -> Self {
+        Self {
+            size: Vec2::new(240.0, 120.0),
+            color: Color32::from_rgb(90, 150, 230),
+            palette: vec![
+                Color32::from_rgb(200, 70, 60),
+                Color32::from_rgb(70, 140, 210),
+                Color32::from_rgb(220, 180, 60),
+                Color32::from_rgb(80, 170, 90),
+                Color32::from_rgb(160, 90, 200),
+                Color32::from_rgb(230, 130, 60),
+            ],
+            show_values: true,
+            label_size: 11.0,
+        }
+    }
+}
+
+/// Counts the cards of each cost, from 0 to `max_cost`; costlier cards are counted in the
+/// last bucket, labeled e.g. `5+`.
+///
+/// # Example
+/// ```rust
+/// # use deckbuilder_eng::card::{Card, CardType};
+/// # use deckbuilder_eng::ui_chart::mana_curve;
+/// let deck = vec![
+///     Card::new(1, "Strike", "Deal 6 damage", 1, CardType::Attack),
+///     Card::new(2, "Bash", "Deal 8 damage", 2, CardType::Attack),
+///     Card::new(3, "Offering", "Gain 2 energy", 0, CardType::Skill),
+///     Card::new(4, "Demon Form", "Gain 2 Strength each turn", 3, CardType::Power),
+/// ];
+/// let curve = mana_curve(&deck, 2);
+/// assert_eq!(curve, vec![("0".to_owned(), 1.0), ("1".to_owned(), 1.0), ("2+".to_owned(), 2.0)]);
+/// ```
+pub fn mana_curve(cards: &[Card], max_cost: u32) -> Vec<(String, f32)> {
+    let mut curve: Vec<(String, f32)> = (0..=max_cost).map(|cost| (cost.to_string(), 0.0)).collect();
+    if let Some(last) = curve.last_mut() {
+        last.0 = format!("{}+", max_cost);
+    }
+    for card in cards {
+        curve[card.cost.min(max_cost) as usize].1 += 1.0;
+    }
+    curve
+}
+
+fn format_value(value: f32) -> String {
+    if value.fract() == 0.0 {
+        format!("{}", value as i64)
+    } else {
+        format!("{:.1}", value)
+    }
+}
+
+/// Value range of the axis, always including zero and never empty.
+fn value_range(values: impl Iterator<Item = f32>) -> (f32, f32) {
+    let (min, max) = values.fold((0.0_f32, 0.0_f32), |(lo, hi), v| (lo.min(v), hi.max(v)));
+    if max - min > 0.0 { (min, max) } else { (min, min + 1.0) }
+}
+
+/// Bar chart of labeled values, e.g. a mana curve.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui_chart::{ui_bar_chart, ChartStyle};
+/// # fn demo(ui: &mut Ui) {
+/// let gold = vec![("Act 1".to_owned(), 240.0), ("Act 2".to_owned(), 410.0), ("Act 3".to_owned(), 180.0)];
+/// ui_bar_chart(ui, &gold, &ChartStyle::default());
+/// # }
+/// ```
+pub fn ui_bar_chart(ui: &mut Ui, data: &[(String, f32)], style: &ChartStyle) -> Response {
+    let (rect, response) = ui.allocate_exact_size(style.size, Sense::hover());
+    if data.is_empty() || !ui.is_rect_visible(rect) {
+        return response;
+    }
+    let painter = ui.painter_at(rect);
+    let font = FontId::proportional(style.label_size);
+    let text_color = ui.visuals().text_color();
+    let plot = Rect::from_min_max(
+        rect.min + Vec2::new(0.0, style.label_size + 2.0),
+        rect.max - Vec2::new(0.0, style.label_size + 4.0),
+    );
+    let (min, max) = value_range(data.iter().map(|(_, v)| *v));
+    let y_of = |v: f32| plot.bottom() - (v - min) / (max - min) * plot.height();
+    let slot = plot.width() / data.len() as f32;
+    let pointer = response.hover_pos();
+    let mut hovered = None;
+
+    for (i, (label, value)) in data.iter().enumerate() {
+        let x = plot.left() + slot * i as f32;
+        let (top, bottom) = (y_of(value.max(0.0)), y_of(value.min(0.0)));
+        let bar = Rect::from_min_max(Pos2::new(x + slot * 0.15, top), Pos2::new(x + slot * 0.85, bottom));
+        let column = Rect::from_x_y_ranges(x..=x + slot, rect.y_range());
+        let is_hovered = pointer.is_some_and(|p| column.contains(p));
+        if is_hovered {
+            hovered = Some(i);
+        }
+        let fill = if is_hovered { style.color.linear_multiply(1.3) } else { style.color };
+        painter.rect_filled(bar, Rounding::same(2.0), fill);
+        painter.text(
+            Pos2::new(column.center().x, rect.bottom()),
+            Align2::CENTER_BOTTOM,
+            label,
+            font.clone(),
+            text_color,
+        );
+        if style.show_values {
+            painter.text(
+                Pos2::new(column.center().x, top - 2.0),
+                Align2::CENTER_BOTTOM,
+                format_value(*value),
+                font.clone(),
+                text_color,
+            );
+        }
+    }
+    painter.hline(plot.x_range(), y_of(0.0), Stroke::new(1.0, ui.visuals().weak_text_color()));
+
+    match hovered {
+        Some(i) => response.on_hover_text_at_pointer(format!("{}: {}", data[i].0, format_value(data[i].1))),
+        None => response,
+    }
+}
+
+/// Line chart of values over time, e.g. damage per turn; point `i` is labeled `i + 1`.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui_chart::{ui_line_chart, ChartStyle};
+/// # fn demo(ui: &mut Ui) {
+/// ui_line_chart(ui, &[6.0, 14.0, 9.0, 21.0], &ChartStyle { show_values: false, ..Default::default() });
+/// # }
+/// ```
+pub fn ui_line_chart(ui: &mut Ui, values: &[f32], style: &ChartStyle) -> Response {
+    let (rect, response) = ui.allocate_exact_size(style.size, Sense::hover());
+    if values.is_empty() || !ui.is_rect_visible(rect) {
+        return response;
+    }
+    let painter = ui.painter_at(rect);
+    let font = FontId::proportional(style.label_size);
+    let text_color = ui.visuals().text_color();
+    let plot = Rect::from_min_max(
+        rect.min + Vec2::new(style.label_size, style.label_size + 2.0),
+        rect.max - Vec2::new(style.label_size, style.label_size + 4.0),
+    );
+    let (min, max) = value_range(values.iter().copied());
+    let step = if values.len() > 1 { plot.width() / (values.len() - 1) as f32 } else { 0.0 };
+    let points: Vec<Pos2> = values
+        .iter()
+        .enumerate()
+        .map(|(i, v)| {
+            let x = if values.len() > 1 { plot.left() + step * i as f32 } else { plot.center().x };
+            Pos2::new(x, plot.bottom() - (v - min) / (max - min) * plot.height())
+        })
+        .collect();
+
+    let axis = Stroke::new(1.0, ui.visuals().weak_text_color());
+    painter.hline(plot.x_range(), plot.bottom() - (0.0 - min) / (max - min) * plot.height(), axis);
+    painter.add(Shape::line(points.clone(), Stroke::new(2.0, style.color)));
+
+    let hovered = response.hover_pos().and_then(|p| {
+        points
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| (a.x - p.x).abs().total_cmp(&(b.x - p.x).abs()))
+            .map(|(i, _)| i)
+    });
+    let label_every = (values.len() / 10).max(1);
+    for (i, (point, value)) in points.iter().zip(values).enumerate() {
+        let radius = if hovered == Some(i) { 4.5 } else { 3.0 };
+        painter.circle_filled(*point, radius, style.color);
+        if i % label_every == 0 {
+            painter.text(
+                Pos2::new(point.x, rect.bottom()),
+                Align2::CENTER_BOTTOM,
+                (i + 1).to_string(),
+                font.clone(),
+                text_color,
+            );
+        }
+        if style.show_values {
+            painter.text(*point - Vec2::new(0.0, 5.0), Align2::CENTER_BOTTOM, format_value(*value), font.clone(), text_color);
+        }
+    }
+
+    match hovered {
+        Some(i) => response.on_hover_text_at_pointer(format!("{}: {}", i + 1, format_value(values[i]))),
+        None => response,
+    }
+}
+
+/// Pie chart of labeled shares, with a legend to the right of the pie. Non-positive values
+/// are skipped.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui_chart::{ui_pie_chart, ChartStyle};
+/// # fn demo(ui: &mut Ui) {
+/// let types = vec![("Attack".to_owned(), 8.0), ("Skill".to_owned(), 6.0), ("Power".to_owned(), 2.0)];
+/// ui_pie_chart(ui, &types, &ChartStyle::default());
+/// # }
+/// ```
+pub fn ui_pie_chart(ui: &mut Ui, data: &[(String, f32)], style: &ChartStyle) -> Response {
+    let (rect, response) = ui.allocate_exact_size(style.size, Sense::hover());
+    let total: f32 = data.iter().map(|(_, v)| v.max(0.0)).sum();
+    if total <= 0.0 || style.palette.is_empty() || !ui.is_rect_visible(rect) {
+        return response;
+    }
+    let painter = ui.painter_at(rect);
+    let font = FontId::proportional(style.label_size);
+    let text_color = ui.visuals().text_color();
+    let radius = (rect.height().min(rect.width() / 2.0) / 2.0 - 2.0).max(1.0);
+    let center = Pos2::new(rect.left() + radius + 2.0, rect.center().y);
+
+    // Angle of the pointer, clockwise from 12 o'clock, if it is over the pie.
+    let pointer_angle = response.hover_pos().filter(|p| p.distance(center) <= radius).map(|p| {
+        let d = p - center;
+        d.x.atan2(-d.y).rem_euclid(TAU)
+    });
+    let mut hovered = None;
+    let mut start = 0.0;
+    let mut legend_y = rect.top() + style.label_size;
+    for (i, (label, value)) in data.iter().enumerate() {
+        if *value <= 0.0 {
+            continue;
+        }
+        let color = style.palette[i % style.palette.len()];
+        let sweep = value / total * TAU;
+        let end = start + sweep;
+        if pointer_angle.is_some_and(|a| a >= start && a < end) {
+            hovered = Some(i);
+        }
+        // Convex pieces of at most a quarter turn each.
+        let pieces = (sweep / (TAU / 4.0)).ceil().max(1.0) as usize;
+        let segments = ((sweep / TAU * 64.0).ceil() as usize).max(pieces);
+        let at = |angle: f32| center + radius * Vec2::new(angle.sin(), -angle.cos());
+        for piece in 0..pieces {
+            let from = start + sweep * piece as f32 / pieces as f32;
+            let to = start + sweep * (piece + 1) as f32 / pieces as f32;
+            let steps = (segments / pieces).max(1);
+            let mut polygon = vec![center];
+            polygon.extend((0..=steps).map(|s| at(from + (to - from) * s as f32 / steps as f32)));
+            painter.add(Shape::convex_polygon(polygon, color, Stroke::NONE));
+        }
+
+        let swatch = Rect::from_center_size(
+            Pos2::new(center.x + radius + 14.0, legend_y),
+            Vec2::splat(style.label_size * 0.8),
+        );
+        painter.rect_filled(swatch, Rounding::same(2.0), color);
+        let text = if style.show_values { format!("{} ({})", label, format_value(*value)) } else { label.clone() };
+        painter.text(swatch.right_center() + Vec2::new(4.0, 0.0), Align2::LEFT_CENTER, text, font.clone(), text_color);
+        legend_y += style.label_size + 4.0;
+        start = end;
+    }
+
+    match hovered {
+        Some(i) => {
+            let percent = data[i].1 / total * 100.0;
+            response.on_hover_text_at_pointer(format!("{}: {} ({:.0}%)", data[i].0, format_value(data[i].1), percent))
+        }
+        None => response,
+    }
+}
@@ -0,0 +1,144 @@
+//! Combat log widget for egui-based deckbuilder UIs.
+//!
+//! Provides [`ui_combat_log`], which renders a [`CombatLog`] grouped by turn with color-coded
+//! entry kinds, kind and text filters, and an auto-scroll-to-latest toggle.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::combat_log::{CombatLog, LogKind};
+//! use deckbuilder_eng::ui_combat_log::{ui_combat_log, LogViewState};
+//! use egui::Context;
+//!
+//! fn log_window(ctx: &Context, log: &CombatLog, view: &mut LogViewState) {
+//!     egui::Window::new("Combat log").show(ctx, |ui| {
+//!         ui_combat_log(ui, log, view);
+//!     });
+//! }
+//!
+//! let mut log = CombatLog::new();
+//! log.push(LogKind::Damage, "Jaw Worm takes [red]6[/red] damage");
+//! let mut view = LogViewState::default();
+//! ```
+//!
+//! # Details
+//!
+//! - Entry text is rendered with the rich text markup of [`crate::ui_text`].
+//! - The current turn's group starts open, older turns start collapsed.
+//! - See each function's documentation for usage and customization options.
+
+use std::collections::HashSet;
+
+use egui::{Color32, RichText, ScrollArea, TextEdit, Ui};
+
+use crate::combat_log::{CombatLog, LogKind};
+use crate::ui_text::ui_rich_label;
+use crate::ui_tree::ui_collapsible;
+
+/// Filter and scroll state of a [`ui_combat_log`] view.
+#[derive(Debug, Clone)]
+pub struct LogViewState {
+    /// Kinds that are not shown.
+    pub hidden: HashSet<LogKind>,
+    /// Only entries containing this text (case-insensitive) are shown.
+    pub search: String,
+    /// Keeps the view scrolled to the latest entry.
+    pub auto_scroll: bool,
+    /// Height of the scrolling entry list.
+    pub max_height: f32,
+}
+
+impl Default for LogViewState {
+    fn default() -> Self {
+        Self {
+            hidden: HashSet::new(),
+            search: String::new(),
+            auto_scroll: true,
+            max_height: 240.0,
+        }
+    }
+}
+
+/// Text color of entries of `kind`.
+///
+/// # Example
+/// ```rust
+/// # use deckbuilder_eng::combat_log::LogKind;
+/// # use deckbuilder_eng::ui_combat_log::log_kind_color;
+/// let color = log_kind_color(LogKind::Damage);
+/// ```
+pub fn log_kind_color(kind: LogKind) -> Color32 {
+    match kind {
+        LogKind::Damage => Color32::from_rgb(230, 100, 90),
+        LogKind::Block => Color32::from_rgb(110, 160, 230),
+        LogKind::Heal => Color32::from_rgb(110, 210, 120),
+        LogKind::Status => Color32::from_rgb(200, 130, 230),
+        LogKind::Card => Color32::from_rgb(230, 200, 110),
+        LogKind::Enemy => Color32::from_rgb(230, 150, 80),
+        LogKind::System => Color32::from_gray(160),
+    }
+}
+
+/// Combat log with a filter bar and per-turn groups.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::combat_log::CombatLog;
+/// # use deckbuilder_eng::ui_combat_log::{ui_combat_log, LogViewState};
+/// # fn demo(ui: &mut Ui, log: &CombatLog, view: &mut LogViewState) {
+/// ui_combat_log(ui, log, view);
+/// # }
+/// ```
+pub fn ui_combat_log(ui: &mut Ui, log: &CombatLog, state: &mut LogViewState) {
+    ui.horizontal_wrapped(|ui| {
+        for kind in LogKind::ALL {
+            let mut shown = !state.hidden.contains(&kind);
+            let text = RichText::new(kind.label()).color(log_kind_color(kind));
+            if ui.toggle_value(&mut shown, text).changed() {
+                if shown {
+                    state.hidden.remove(&kind);
+                } else {
+                    state.hidden.insert(kind);
+                }
+            }
+        }
+    });
+    ui.horizontal(|ui| {
+        ui.add(TextEdit::singleline(&mut state.search).hint_text("Search").desired_width(140.0));
+        ui.checkbox(&mut state.auto_scroll, "Auto-scroll");
+    });
+    ui.separator();
+
+    let search = state.search.to_lowercase();
+    let visible = |text: &str, kind: LogKind| {
+        !state.hidden.contains(&kind) && (search.is_empty() || text.to_lowercase().contains(&search))
+    };
+    let mut turns: Vec<u32> = log.entries().map(|e| e.turn).collect();
+    turns.sort_unstable();
+    turns.dedup();
+
+    ScrollArea::vertical()
+        .id_source("combat_log_scroll")
+        .max_height(state.max_height)
+        .auto_shrink([false, true])
+        .stick_to_bottom(state.auto_scroll)
+        .show(ui, |ui| {
+            for turn in turns {
+                let entries: Vec<_> = log.entries_for_turn(turn).filter(|e| visible(&e.text, e.kind)).collect();
+                if entries.is_empty() {
+                    continue;
+                }
+                let title = format!("Turn {}", turn);
+                ui_collapsible(ui, ("combat_log_turn", turn), &title, turn == log.turn(), |ui| {
+                    for entry in entries {
+                        ui.horizontal(|ui| {
+                            ui.label(RichText::new("●").color(log_kind_color(entry.kind)))
+                                .on_hover_text(entry.kind.label());
+                            ui_rich_label(ui, &entry.text);
+                        });
+                    }
+                });
+            }
+        });
+}
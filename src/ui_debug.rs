@@ -0,0 +1,475 @@
+//! Developer tools for egui-based deckbuilder UIs.
+//!
+//! Provides [`ui_debug_overlay`], a hotkey-toggled overlay showing FPS, a frame time graph,
+//! texture memory, active sounds and game object counts, to help profiling during development,
+//! and [`ui_state_inspector`], a collapsible tree over the battle state whose values can be
+//! edited live to debug card interactions. With the `logging` feature, [`ui_log_view`] lists the
+//! records of a [`LogBuffer`] and the overlay shows the newest ones.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::card::{Deck, GameContext};
+//! use deckbuilder_eng::ui_debug::{ui_debug_overlay, ui_inspect_values, ui_state_inspector, DebugStats};
+//! use egui::Context;
+//!
+//! fn frame(ctx: &Context, deck: &Deck, hand_size: usize, playing_sounds: usize) {
+//!     let stats = DebugStats::new()
+//!         .with_deck(deck)
+//!         .count("Hand", hand_size)
+//!         .active_sounds(playing_sounds);
+//!     // Press F3 to show or hide.
+//!     ui_debug_overlay(ctx, &stats);
+//! }
+//!
+//! fn inspector(ctx: &Context, game: &mut GameContext, deck: &mut Deck, statuses: &mut Vec<(String, i32)>) {
+//!     egui::Window::new("State").show(ctx, |ui| {
+//!         ui_state_inspector(ui, game, deck);
+//!         ui_inspect_values(ui, "statuses", "Statuses", statuses);
+//!     });
+//! }
+//! ```
+//!
+//! # Details
+//!
+//! - Frame times are sampled from egui's input every frame the overlay function is called,
+//!   even while it is hidden.
+//! - Texture memory defaults to the total size of all textures allocated in egui.
+//! - Inspector functions return `true` when a value was edited this frame.
+//! - Log lines are colored by level; the overlay shows the last 6 records of
+//!   [`DebugStats::logs`].
+//! - See each struct and function's documentation for more.
+
+use std::collections::VecDeque;
+
+use std::hash::Hash;
+
+use egui::{
+    Align2, Area, Color32, Context, DragValue, Frame, Id, Key, Modifiers, Order, Pos2, Sense, Shape, Stroke, Ui,
+    Vec2,
+};
+
+use crate::card::{Card, Deck, GameContext};
+#[cfg(feature = "logging")]
+use crate::logging::LogBuffer;
+use crate::stats::EngineStats;
+use crate::ui_capture::is_photo_mode;
+use crate::ui_tree::ui_collapsible;
+
+/// Numbers shown by [`ui_debug_overlay`].
+#[derive(Debug, Clone, Default)]
+pub struct DebugStats {
+    /// Texture memory in bytes; `None` uses egui's allocated textures.
+    pub texture_bytes: Option<usize>,
+    /// Number of sounds currently playing.
+    pub active_sounds: Option<usize>,
+    /// Named counts from the game, e.g. `("Draw pile", 12)`.
+    pub counts: Vec<(String, usize)>,
+    /// Log records to show the newest of.
+    #[cfg(feature = "logging")]
+    pub logs: Option<LogBuffer>,
+}
+
+impl DebugStats {
+    /// Creates empty stats.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_debug::DebugStats;
+    /// let stats = DebugStats::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a named count.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_debug::DebugStats;
+    /// let stats = DebugStats::new().count("Enemies", 3);
+    /// ```
+    pub fn count(mut self, name: impl Into<String>, value: usize) -> Self {
+        self.counts.push((name.into(), value));
+        self
+    }
+
+    /// Adds the draw and discard pile sizes of `deck`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::card::Deck;
+    /// # use deckbuilder_eng::ui_debug::DebugStats;
+    /// let stats = DebugStats::new().with_deck(&Deck::new(Vec::new()));
+    /// assert_eq!(stats.counts.len(), 2);
+    /// ```
+    pub fn with_deck(self, deck: &Deck) -> Self {
+        self.count("Draw pile", deck.draw_pile.len())
+            .count("Discard pile", deck.discard_pile.len())
+    }
+
+    /// Adds the per-frame counts of `stats` (e.g. the change of [`engine_stats`](crate::stats::engine_stats) since the
+    /// last frame) and its frame times in microseconds.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::stats::engine_stats;
+    /// # use deckbuilder_eng::ui_debug::DebugStats;
+    /// let stats = DebugStats::new().with_engine_stats(&engine_stats());
+    /// assert_eq!(stats.counts.len(), 7);
+    /// ```
+    pub fn with_engine_stats(self, stats: &EngineStats) -> Self {
+        self.count("Frame CPU (µs)", stats.last_frame_time.as_micros() as usize)
+            .count("Slowest frame (µs)", stats.max_frame_time.as_micros() as usize)
+            .count("Fixed ticks", stats.fixed_steps as usize)
+            .count("Events", stats.events_dispatched as usize)
+            .count("Cards drawn", stats.cards_drawn as usize)
+            .count("Status ticks", stats.status_ticks as usize)
+            .count("Animations decoded", stats.animations_decoded as usize)
+    }
+
+    /// Sets the number of playing sounds.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_debug::DebugStats;
+    /// let stats = DebugStats::new().active_sounds(4);
+    /// ```
+    pub fn active_sounds(mut self, count: usize) -> Self {
+        self.active_sounds = Some(count);
+        self
+    }
+
+    /// Sets the texture memory in bytes.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_debug::DebugStats;
+    /// let stats = DebugStats::new().texture_bytes(64 * 1024 * 1024);
+    /// ```
+    pub fn texture_bytes(mut self, bytes: usize) -> Self {
+        self.texture_bytes = Some(bytes);
+        self
+    }
+
+    /// Shows the newest records of `logs` in the overlay.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::logging::LogBuffer;
+    /// # use deckbuilder_eng::ui_debug::DebugStats;
+    /// let logs = LogBuffer::default();
+    /// let stats = DebugStats::new().logs(&logs);
+    /// ```
+    #[cfg(feature = "logging")]
+    pub fn logs(mut self, logs: &LogBuffer) -> Self {
+        self.logs = Some(logs.clone());
+        self
+    }
+}
+
+/// Settings of [`ui_debug_overlay_ex`].
+#[derive(Clone)]
+pub struct DebugOverlayConfig {
+    /// Key that shows and hides the overlay.
+    pub toggle_key: Key,
+    /// Number of frames kept for the graph and averages.
+    pub history: usize,
+    /// Screen corner the overlay is anchored to.
+    pub anchor: Align2,
+    /// Whether the overlay starts visible.
+    pub visible_by_default: bool,
+}
+
+impl Default for DebugOverlayConfig {
+    fn default() -> Self {
+        Self {
+            toggle_key: Key::F3,
+            history: 120,
+            anchor: Align2::RIGHT_TOP,
+            visible_by_default: false,
+        }
+    }
+}
+
+fn format_bytes(bytes: usize) -> String {
+    let bytes = bytes as f64;
+    if bytes >= 1024.0 * 1024.0 {
+        format!("{:.1} MiB", bytes / (1024.0 * 1024.0))
+    } else {
+        format!("{:.1} KiB", bytes / 1024.0)
+    }
+}
+
+/// Debug overlay toggled with F3. Returns `true` while it is visible; it is hidden while a
+/// photo is taken (see [`crate::ui_capture`]).
+///
+/// # Example
+/// ```rust
+/// # use deckbuilder_eng::ui_debug::{ui_debug_overlay, DebugStats};
+/// # fn demo(ctx: &egui::Context) {
+/// ui_debug_overlay(ctx, &DebugStats::new().count("Relics", 5));
+/// # }
+/// ```
+pub fn ui_debug_overlay(ctx: &Context, stats: &DebugStats) -> bool {
+    ui_debug_overlay_ex(ctx, stats, &DebugOverlayConfig::default())
+}
+
+/// Debug overlay with a custom hotkey, history length and position.
+///
+/// # Example
+/// ```rust
+/// # use egui::Key;
+/// # use deckbuilder_eng::ui_debug::{ui_debug_overlay_ex, DebugOverlayConfig, DebugStats};
+/// # fn demo(ctx: &egui::Context) {
+/// let config = DebugOverlayConfig { toggle_key: Key::F12, visible_by_default: true, ..Default::default() };
+/// ui_debug_overlay_ex(ctx, &DebugStats::new(), &config);
+/// # }
+/// ```
+pub fn ui_debug_overlay_ex(ctx: &Context, stats: &DebugStats, config: &DebugOverlayConfig) -> bool {
+    let id = Id::new("deckbuilder_debug_overlay");
+    let times_id = id.with("frame_times");
+    let visible_id = id.with("visible");
+
+    let dt = ctx.input(|i| i.unstable_dt) * 1000.0;
+    let mut times: VecDeque<f32> = ctx.data(|d| d.get_temp(times_id)).unwrap_or_default();
+    times.push_back(dt);
+    while times.len() > config.history.max(1) {
+        times.pop_front();
+    }
+    let mut visible = ctx.data(|d| d.get_temp(visible_id)).unwrap_or(config.visible_by_default);
+    if ctx.input_mut(|i| i.consume_key(Modifiers::NONE, config.toggle_key)) {
+        visible = !visible;
+    }
+    ctx.data_mut(|d| {
+        d.insert_temp(visible_id, visible);
+        d.insert_temp(times_id, times.clone());
+    });
+    if !visible || is_photo_mode(ctx) {
+        return false;
+    }
+
+    let average = times.iter().sum::<f32>() / times.len() as f32;
+    let worst = times.iter().copied().fold(0.0, f32::max);
+    let fps = if average > 0.0 { 1000.0 / average } else { 0.0 };
+    let texture_bytes = stats.texture_bytes.unwrap_or_else(|| {
+        let manager = ctx.tex_manager();
+        let manager = manager.read();
+        manager.allocated().map(|(_, meta)| meta.bytes_used()).sum()
+    });
+    let texture_count = ctx.tex_manager().read().num_allocated();
+
+    let margin = Vec2::splat(8.0);
+    let offset = Vec2::new(
+        -config.anchor.x().to_sign() * margin.x,
+        -config.anchor.y().to_sign() * margin.y,
+    );
+    Area::new(id)
+        .order(Order::Debug)
+        .anchor(config.anchor, offset)
+        .interactable(false)
+        .show(ctx, |ui| {
+            Frame::popup(ui.style()).fill(Color32::from_black_alpha(200)).show(ui, |ui| {
+                ui.set_width(200.0);
+                ui.style_mut().visuals.override_text_color = Some(Color32::from_gray(230));
+                ui.monospace(format!("{:>5.0} FPS  {:>5.1} ms", fps, average));
+                ui.monospace(format!("worst {:>5.1} ms", worst));
+
+                // Frame time graph; the dashed line marks 60 FPS.
+                let (rect, _) = ui.allocate_exact_size(Vec2::new(ui.available_width(), 48.0), Sense::hover());
+                let painter = ui.painter_at(rect);
+                painter.rect_filled(rect, 2.0, Color32::from_black_alpha(120));
+                let scale = (worst.max(1000.0 / 30.0)) * 1.1;
+                let y_of = |ms: f32| rect.bottom() - (ms / scale).min(1.0) * rect.height();
+                let step = rect.width() / (config.history.max(2) - 1) as f32;
+                let start = rect.right() - step * (times.len().saturating_sub(1)) as f32;
+                let points: Vec<Pos2> = times
+                    .iter()
+                    .enumerate()
+                    .map(|(i, ms)| Pos2::new(start + step * i as f32, y_of(*ms)))
+                    .collect();
+                let target = y_of(1000.0 / 60.0);
+                painter.extend(Shape::dashed_line(
+                    &[Pos2::new(rect.left(), target), Pos2::new(rect.right(), target)],
+                    Stroke::new(1.0, Color32::from_rgb(90, 160, 90)),
+                    4.0,
+                    4.0,
+                ));
+                let color = if worst > 1000.0 / 30.0 {
+                    Color32::from_rgb(230, 120, 90)
+                } else {
+                    Color32::from_rgb(120, 200, 240)
+                };
+                painter.add(Shape::line(points, Stroke::new(1.5, color)));
+
+                ui.monospace(format!("textures {} ({})", texture_count, format_bytes(texture_bytes)));
+                if let Some(sounds) = stats.active_sounds {
+                    ui.monospace(format!("sounds   {}", sounds));
+                }
+                for (name, value) in &stats.counts {
+                    ui.monospace(format!("{:<12} {}", name, value));
+                }
+                #[cfg(feature = "logging")]
+                if let Some(logs) = &stats.logs {
+                    ui.separator();
+                    for record in logs.recent(6) {
+                        let text = egui::RichText::new(&record.message).monospace().color(level_color(record.level));
+                        ui.add(egui::Label::new(text).truncate(true));
+                    }
+                }
+            });
+        });
+    ctx.request_repaint();
+    true
+}
+
+/// Labeled DragValue row of the inspector. Returns `true` when the value changed.
+fn inspect_number<N: egui::emath::Numeric>(ui: &mut Ui, label: &str, value: &mut N) -> bool {
+    ui.horizontal(|ui| {
+        ui.label(label);
+        ui.add(DragValue::new(value)).changed()
+    })
+    .inner
+}
+
+/// Collapsible list of `cards` with editable costs and a remove button per card.
+/// Returns `true` when a card was edited or removed.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::card::Card;
+/// # use deckbuilder_eng::ui_debug::ui_inspect_cards;
+/// # fn demo(ui: &mut Ui, hand: &mut Vec<Card>) {
+/// if ui_inspect_cards(ui, "hand", "Hand", hand) {
+///     println!("Hand edited");
+/// }
+/// # }
+/// ```
+pub fn ui_inspect_cards(ui: &mut Ui, id: impl Hash, title: &str, cards: &mut Vec<Card>) -> bool {
+    let mut changed = false;
+    let mut remove = None;
+    ui_collapsible(ui, id, &format!("{} ({})", title, cards.len()), false, |ui| {
+        if cards.is_empty() {
+            ui.weak("empty");
+        }
+        for (i, card) in cards.iter_mut().enumerate() {
+            ui.push_id(i, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(format!("#{} {}", card.id, card.name))
+                        .on_hover_text(format!("{:?}, {:?}\n{}", card.card_type, card.rarity, card.description));
+                    ui.weak("cost");
+                    changed |= ui.add(DragValue::new(&mut card.cost).clamp_range(0..=99)).changed();
+                    if ui.small_button("✖").on_hover_text("Remove").clicked() {
+                        remove = Some(i);
+                    }
+                });
+            });
+        }
+    });
+    if let Some(i) = remove {
+        cards.remove(i);
+        changed = true;
+    }
+    changed
+}
+
+/// Collapsible list of named integer values, e.g. statuses (`("Vulnerable", 2)`) or enemy
+/// intents (`("Attack", 12)`), each editable in place. Returns `true` when a value changed.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui_debug::ui_inspect_values;
+/// # fn demo(ui: &mut Ui) {
+/// let mut intents = vec![("Jaw Worm: Attack".to_owned(), 11)];
+/// ui_inspect_values(ui, "intents", "Enemy intents", &mut intents);
+/// # }
+/// ```
+pub fn ui_inspect_values(ui: &mut Ui, id: impl Hash, title: &str, values: &mut [(String, i32)]) -> bool {
+    let mut changed = false;
+    ui_collapsible(ui, id, title, true, |ui| {
+        if values.is_empty() {
+            ui.weak("none");
+        }
+        for (i, (name, value)) in values.iter_mut().enumerate() {
+            ui.push_id(i, |ui| changed |= inspect_number(ui, name, value));
+        }
+    });
+    changed
+}
+
+/// Developer panel over the battle state: the [`GameContext`] values and both piles of the
+/// [`Deck`], all editable live. Returns `true` when anything was edited this frame.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::card::{Deck, GameContext};
+/// # use deckbuilder_eng::ui_debug::ui_state_inspector;
+/// # fn demo(ui: &mut Ui, game: &mut GameContext, deck: &mut Deck) {
+/// if ui_state_inspector(ui, game, deck) {
+///     println!("Battle state edited, health now {}", game.player_health);
+/// }
+/// # }
+/// ```
+pub fn ui_state_inspector(ui: &mut Ui, game: &mut GameContext, deck: &mut Deck) -> bool {
+    let mut changed = false;
+    ui.push_id("deckbuilder_state_inspector", |ui| {
+        ui_collapsible(ui, "game", "Game context", true, |ui| {
+            changed |= inspect_number(ui, "Player health", &mut game.player_health);
+            changed |= inspect_number(ui, "Enemy health", &mut game.enemy_health);
+            changed |= inspect_number(ui, "Energy", &mut game.energy);
+            changed |= inspect_number(ui, "Turn", &mut game.turn);
+        });
+        ui_collapsible(ui, "deck", "Deck", true, |ui| {
+            changed |= ui_inspect_cards(ui, "draw", "Draw pile", &mut deck.draw_pile);
+            changed |= ui_inspect_cards(ui, "discard", "Discard pile", &mut deck.discard_pile);
+            ui.horizontal(|ui| {
+                if ui.button("Draw").on_hover_text("Top card into discard pile").clicked()
+                    && let Some(card) = deck.draw()
+                {
+                    deck.discard(card);
+                    changed = true;
+                }
+                if ui.button("Shuffle").on_hover_text("Discard pile into draw pile").clicked() {
+                    deck.shuffle();
+                    changed = true;
+                }
+            });
+        });
+    });
+    changed
+}
+
+#[cfg(feature = "logging")]
+fn level_color(level: tracing::Level) -> Color32 {
+    match level {
+        tracing::Level::ERROR => Color32::from_rgb(240, 90, 80),
+        tracing::Level::WARN => Color32::from_rgb(240, 190, 80),
+        tracing::Level::INFO => Color32::from_gray(230),
+        _ => Color32::from_gray(150),
+    }
+}
+
+/// Scrolling list of the records in `logs` at `level` or more severe, newest at the bottom.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::logging::LogBuffer;
+/// # use deckbuilder_eng::ui_debug::ui_log_view;
+/// # fn demo(ctx: &egui::Context, logs: &LogBuffer) {
+/// egui::Window::new("Log").show(ctx, |ui| ui_log_view(ui, logs, tracing::Level::INFO));
+/// # }
+/// ```
+#[cfg(feature = "logging")]
+pub fn ui_log_view(ui: &mut Ui, logs: &LogBuffer, level: tracing::Level) {
+    egui::ScrollArea::vertical().stick_to_bottom(true).auto_shrink([false, false]).show(ui, |ui| {
+        for record in logs.records().iter().filter(|record| record.level <= level) {
+            let spans: String = record.spans.iter().map(|span| format!(" {}", span)).collect();
+            let text = format!("{:>5} {}{}: {}", record.level, record.target, spans, record.message);
+            ui.label(egui::RichText::new(text).monospace().color(level_color(record.level)));
+        }
+    });
+}
@@ -0,0 +1,568 @@
+//! Drag-and-drop helpers for egui-based deckbuilder UIs.
+//!
+//! Provides a typed [`DragState`] plus drag source and drop target widgets, so cards can be
+//! dragged from the hand onto enemies or play zones, and a targeting arrow overlay with a
+//! small [`TargetingState`] helper for "select a target" mode, and a drag-to-reorder list.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::card::{Card, CardType};
+//! use deckbuilder_eng::ui_card::CardVisuals;
+//! use deckbuilder_eng::ui_drag::*;
+//! use egui::{CentralPanel, Context};
+//!
+//! fn my_drag_ui(ctx: &Context, drag: &mut DragState<Card>, energy: u32) {
+//!     let card = Card::new(1, "Strike", "Deal 6 damage", 1, CardType::Attack);
+//!
+//!     CentralPanel::default().show(ctx, |ui| {
+//!         ui_card_drag_source(ui, drag, &card, &CardVisuals::default());
+//!
+//!         let drop = ui_drop_target(ui, drag, |c: &Card| c.cost <= energy, |ui| {
+//!             ui.label("Enemy");
+//!         });
+//!         if let Some(played) = drop.dropped {
+//!             println!("Played {} on the enemy", played.name);
+//!         }
+//!     });
+//! }
+//! ```
+//!
+//! # Details
+//!
+//! - The payload type is a generic parameter, so a `DragState<Card>` never delivers anything else.
+//! - While dragging, the source contents are painted at the pointer as a ghost.
+//! - A drop that no target accepts is discarded on the following frame.
+//! - The targeting arrow is painted on the foreground layer, above panels and windows.
+//! - [`ui_reorderable_list`] reorders a `Vec` in place when a row is dropped; Escape cancels
+//!   the drag.
+//! - See each function's documentation for usage and customization options.
+
+use std::hash::Hash;
+
+use crate::card::Card;
+use crate::ui_card::{paint_card, CardResponse, CardVisuals};
+use egui::{
+    emath::TSTransform, epaint::QuadraticBezierShape, Color32, CursorIcon, Id, Key, LayerId,
+    Order, PointerButton, Pos2, Response, Rounding, Sense, Shape, Stroke, Ui, Vec2,
+};
+
+/// Holds the payload of the drag currently in progress.
+///
+/// Keep one `DragState` per payload type in your app state and pass it to the
+/// drag sources and drop targets that should cooperate.
+pub struct DragState<P> {
+    payload: Option<P>,
+    source: Option<Id>,
+}
+
+impl<P> Default for DragState<P> {
+    fn default() -> Self {
+        Self {
+            payload: None,
+            source: None,
+        }
+    }
+}
+
+impl<P: Clone> DragState<P> {
+    /// Creates an empty drag state.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_drag::DragState;
+    /// let drag: DragState<u32> = DragState::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` while a payload is being dragged.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_drag::DragState;
+    /// let drag: DragState<u32> = DragState::new();
+    /// assert!(!drag.is_dragging());
+    /// ```
+    pub fn is_dragging(&self) -> bool {
+        self.payload.is_some()
+    }
+
+    /// Returns the payload being dragged, if any.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_drag::DragState;
+    /// let drag: DragState<u32> = DragState::new();
+    /// let payload = drag.payload();
+    /// ```
+    pub fn payload(&self) -> Option<&P> {
+        self.payload.as_ref()
+    }
+
+    /// Returns the id of the drag source that started the current drag.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_drag::DragState;
+    /// let drag: DragState<u32> = DragState::new();
+    /// let source = drag.source();
+    /// ```
+    pub fn source(&self) -> Option<Id> {
+        self.source
+    }
+
+    /// Cancels the current drag; no target will receive the payload.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_drag::DragState;
+    /// let mut drag: DragState<u32> = DragState::new();
+    /// drag.cancel();
+    /// ```
+    pub fn cancel(&mut self) {
+        self.payload = None;
+        self.source = None;
+    }
+
+    /// Drops a stale payload once the pointer is neither held nor just released.
+    fn refresh(&mut self, ui: &Ui) {
+        if self.payload.is_some()
+            && !ui.input(|i| i.pointer.any_down() || i.pointer.any_released())
+        {
+            self.cancel();
+        }
+    }
+}
+
+/// Makes the given contents draggable, carrying `payload` while dragged.
+/// During the drag the contents are painted at the pointer position (ghost).
+///
+/// # Example
+/// ```rust
+/// # use egui::{Ui, Id};
+/// # use deckbuilder_eng::ui_drag::{ui_drag_source, DragState};
+/// # fn demo(ui: &mut Ui, drag: &mut DragState<u32>) {
+/// ui_drag_source(ui, drag, Id::new("relic_7"), 7, |ui| {
+///     ui.label("Relic #7");
+/// });
+/// # }
+/// ```
+pub fn ui_drag_source<P: Clone, R>(
+    ui: &mut Ui,
+    state: &mut DragState<P>,
+    id: Id,
+    payload: P,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> Response {
+    state.refresh(ui);
+
+    if ui.ctx().is_being_dragged(id) {
+        state.payload = Some(payload);
+        state.source = Some(id);
+
+        // Paint the contents on a top layer and move them under the pointer.
+        let layer_id = LayerId::new(Order::Tooltip, id);
+        let response = ui.with_layer_id(layer_id, add_contents).response;
+        if let Some(pointer) = ui.ctx().pointer_interact_pos() {
+            let delta = pointer - response.rect.center();
+            ui.ctx()
+                .transform_layer_shapes(layer_id, TSTransform::from_translation(delta));
+        }
+        ui.ctx().set_cursor_icon(CursorIcon::Grabbing);
+        response
+    } else {
+        let response = ui.scope(add_contents).response;
+        ui.interact(response.rect, id, Sense::drag())
+            .on_hover_cursor(CursorIcon::Grab)
+    }
+}
+
+/// Drag source showing a card; the dragged ghost is the card itself.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::card::{Card, CardType};
+/// # use deckbuilder_eng::ui_card::CardVisuals;
+/// # use deckbuilder_eng::ui_drag::{ui_card_drag_source, DragState};
+/// # fn demo(ui: &mut Ui, drag: &mut DragState<Card>) {
+/// let card = Card::new(1, "Strike", "Deal 6 damage", 1, CardType::Attack);
+/// let res = ui_card_drag_source(ui, drag, &card, &CardVisuals::default());
+/// # }
+/// ```
+pub fn ui_card_drag_source(
+    ui: &mut Ui,
+    state: &mut DragState<Card>,
+    card: &Card,
+    visuals: &CardVisuals,
+) -> CardResponse {
+    let id = ui.id().with(("card_drag", card.id));
+    let response = ui_drag_source(ui, state, id, card.clone(), |ui| {
+        let (rect, _) = ui.allocate_exact_size(visuals.size, Sense::hover());
+        paint_card(ui.painter(), rect, card, visuals, false);
+    });
+    CardResponse::from_response(response)
+}
+
+/// Result of [`ui_drop_target`].
+pub struct DropResponse<P> {
+    /// Response covering the drop target contents.
+    pub response: Response,
+    /// A payload is being dragged over the target.
+    pub hovered: bool,
+    /// The hovered payload passes the target's validation.
+    pub can_accept: bool,
+    /// Payload dropped on the target this frame.
+    pub dropped: Option<P>,
+}
+
+/// Drop target around the given contents. `can_accept` validates the dragged payload;
+/// the target is outlined while a drag is active (green = accepts, red = rejects) and
+/// returns the payload when it is released over it.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui_drag::{ui_drop_target, DragState};
+/// # fn demo(ui: &mut Ui, drag: &mut DragState<u32>) {
+/// let drop = ui_drop_target(ui, drag, |id| *id != 0, |ui| {
+///     ui.label("Drop here");
+/// });
+/// if let Some(id) = drop.dropped {
+///     println!("Dropped {}", id);
+/// }
+/// # }
+/// ```
+pub fn ui_drop_target<P: Clone, R>(
+    ui: &mut Ui,
+    state: &mut DragState<P>,
+    can_accept: impl Fn(&P) -> bool,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> DropResponse<P> {
+    state.refresh(ui);
+
+    let response = ui.scope(add_contents).response;
+    let pointer_over = ui
+        .input(|i| i.pointer.interact_pos())
+        .is_some_and(|pos| response.rect.contains(pos));
+    let accepts = state.payload().is_some_and(&can_accept);
+    let hovered = state.is_dragging() && pointer_over;
+
+    if state.is_dragging() {
+        let color = match (hovered, accepts) {
+            (true, true) => Color32::from_rgb(90, 200, 90),
+            (true, false) => Color32::from_rgb(200, 70, 70),
+            (false, true) => Color32::from_rgba_unmultiplied(90, 200, 90, 90),
+            (false, false) => Color32::TRANSPARENT,
+        };
+        ui.painter()
+            .rect_stroke(response.rect.expand(2.0), Rounding::same(4.0), Stroke::new(2.0, color));
+    }
+
+    let mut dropped = None;
+    if hovered && accepts && ui.input(|i| i.pointer.any_released()) {
+        dropped = state.payload.take();
+        state.source = None;
+    }
+
+    DropResponse {
+        response,
+        hovered,
+        can_accept: accepts,
+        dropped,
+    }
+}
+
+/// Appearance of the targeting arrow.
+#[derive(Clone, Copy)]
+pub struct ArrowStyle {
+    /// Arrow color while no target is hovered.
+    pub color: Color32,
+    /// Arrow color while pointing at a valid target.
+    pub target_color: Color32,
+    /// Line width of the arrow body.
+    pub width: f32,
+    /// How far the curve bends upward, as a fraction of the arrow length.
+    pub curvature: f32,
+    /// Length of the arrowhead.
+    pub head_size: f32,
+}
+
+impl Default for ArrowStyle {
+    fn default() -> Self {
+        Self {
+            color: Color32::from_rgb(230, 230, 230),
+            target_color: Color32::from_rgb(230, 70, 60),
+            width: 6.0,
+            curvature: 0.35,
+            head_size: 22.0,
+        }
+    }
+}
+
+/// Draws a curved bezier arrow with an arrowhead from `from` to `to`
+/// on the foreground layer (e.g. from a selected card to the hovered enemy).
+///
+/// # Example
+/// ```rust
+/// # use egui::{Ui, Pos2};
+/// # use deckbuilder_eng::ui_drag::{ui_targeting_arrow, ArrowStyle};
+/// # fn demo(ui: &mut Ui) {
+/// ui_targeting_arrow(ui, Pos2::new(100.0, 400.0), Pos2::new(500.0, 150.0), &ArrowStyle::default());
+/// # }
+/// ```
+pub fn ui_targeting_arrow(ui: &Ui, from: Pos2, to: Pos2, style: &ArrowStyle) {
+    paint_arrow(ui, from, to, style, style.color);
+}
+
+fn paint_arrow(ui: &Ui, from: Pos2, to: Pos2, style: &ArrowStyle, color: Color32) {
+    let length = (to - from).length();
+    if length < 1.0 {
+        return;
+    }
+    let painter = ui
+        .ctx()
+        .layer_painter(LayerId::new(Order::Foreground, Id::new("targeting_arrow")));
+
+    let control = from + (to - from) / 2.0 - Vec2::new(0.0, length * style.curvature);
+    // Tangent at the end of a quadratic bezier points from the control point to the end.
+    let dir = (to - control).normalized();
+    let base = to - dir * style.head_size;
+    painter.add(QuadraticBezierShape::from_points_stroke(
+        [from, control, base],
+        false,
+        Color32::TRANSPARENT,
+        Stroke::new(style.width, color),
+    ));
+
+    let side = Vec2::new(-dir.y, dir.x) * style.head_size * 0.6;
+    painter.add(Shape::convex_polygon(
+        vec![to, base + side, base - side],
+        color,
+        Stroke::NONE,
+    ));
+}
+
+/// Tracks "selecting target" mode: which source (e.g. a card) is waiting for a target
+/// and where the arrow starts.
+pub struct TargetingState<S> {
+    source: Option<(S, Pos2)>,
+}
+
+impl<S> Default for TargetingState<S> {
+    fn default() -> Self {
+        Self { source: None }
+    }
+}
+
+impl<S> TargetingState<S> {
+    /// Creates an inactive targeting state.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_drag::TargetingState;
+    /// let targeting: TargetingState<usize> = TargetingState::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enters targeting mode for `source`, drawing the arrow from `origin`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Pos2;
+    /// # use deckbuilder_eng::ui_drag::TargetingState;
+    /// let mut targeting = TargetingState::new();
+    /// targeting.begin(3usize, Pos2::new(200.0, 500.0));
+    /// assert!(targeting.is_active());
+    /// ```
+    pub fn begin(&mut self, source: S, origin: Pos2) {
+        self.source = Some((source, origin));
+    }
+
+    /// Returns `true` while waiting for a target.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_drag::TargetingState;
+    /// let targeting: TargetingState<usize> = TargetingState::new();
+    /// assert!(!targeting.is_active());
+    /// ```
+    pub fn is_active(&self) -> bool {
+        self.source.is_some()
+    }
+
+    /// Returns the source waiting for a target.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_drag::TargetingState;
+    /// let targeting: TargetingState<usize> = TargetingState::new();
+    /// let source = targeting.source();
+    /// ```
+    pub fn source(&self) -> Option<&S> {
+        self.source.as_ref().map(|(s, _)| s)
+    }
+
+    /// Leaves targeting mode and returns the source, e.g. once a target was clicked.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Pos2;
+    /// # use deckbuilder_eng::ui_drag::TargetingState;
+    /// let mut targeting = TargetingState::new();
+    /// targeting.begin(3usize, Pos2::ZERO);
+    /// assert_eq!(targeting.confirm(), Some(3));
+    /// ```
+    pub fn confirm(&mut self) -> Option<S> {
+        self.source.take().map(|(s, _)| s)
+    }
+
+    /// Leaves targeting mode without a target.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_drag::TargetingState;
+    /// let mut targeting: TargetingState<usize> = TargetingState::new();
+    /// targeting.cancel();
+    /// ```
+    pub fn cancel(&mut self) {
+        self.source = None;
+    }
+
+    /// Draws the arrow while targeting. It snaps to `hovered_target` (e.g. the center of the
+    /// enemy under the pointer) and follows the pointer otherwise.
+    /// Right click or Escape cancels targeting.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Ui;
+    /// # use deckbuilder_eng::ui_drag::{TargetingState, ArrowStyle};
+    /// # fn demo(ui: &mut Ui, targeting: &mut TargetingState<usize>) {
+    /// targeting.show(ui, None, &ArrowStyle::default());
+    /// # }
+    /// ```
+    pub fn show(&mut self, ui: &Ui, hovered_target: Option<Pos2>, style: &ArrowStyle) {
+        let cancelled = ui.input(|i| {
+            i.key_pressed(Key::Escape) || i.pointer.button_clicked(PointerButton::Secondary)
+        });
+        if cancelled {
+            self.cancel();
+            return;
+        }
+        let Some((_, origin)) = &self.source else {
+            return;
+        };
+        let (to, color) = match hovered_target {
+            Some(target) => (target, style.target_color),
+            None => match ui.input(|i| i.pointer.hover_pos()) {
+                Some(pos) => (pos, style.color),
+                None => return,
+            },
+        };
+        paint_arrow(ui, *origin, to, style, color);
+    }
+}
+
+/// List whose items can be reordered by dragging their handles, with a drop indicator line.
+/// `add_item` draws each row. Returns `true` if the order changed this frame.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui_drag::ui_reorderable_list;
+/// # fn demo(ui: &mut Ui, playlist: &mut Vec<String>) {
+/// if ui_reorderable_list(ui, playlist, |ui, track| {
+///     ui.label(track.as_str());
+/// }) {
+///     println!("New order: {:?}", playlist);
+/// }
+/// # }
+/// ```
+pub fn ui_reorderable_list<T>(ui: &mut Ui, items: &mut Vec<T>, add_item: impl FnMut(&mut Ui, &mut T)) -> bool {
+    let id = ui.next_auto_id();
+    ui_reorderable_list_id(ui, id, items, add_item)
+}
+
+/// [`ui_reorderable_list`] with an explicit id source, for lists whose position in the
+/// layout can change between frames.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::card::Card;
+/// # use deckbuilder_eng::ui_drag::ui_reorderable_list_id;
+/// # fn demo(ui: &mut Ui, deck: &mut Vec<Card>) {
+/// ui_reorderable_list_id(ui, "deck_order", deck, |ui, card| {
+///     ui.label(format!("{} ({})", card.name, card.cost));
+/// });
+/// # }
+/// ```
+pub fn ui_reorderable_list_id<T>(
+    ui: &mut Ui,
+    id_source: impl Hash,
+    items: &mut Vec<T>,
+    mut add_item: impl FnMut(&mut Ui, &mut T),
+) -> bool {
+    let id = ui.make_persistent_id(id_source);
+    let mut dragging: Option<usize> = ui.data(|d| d.get_temp(id)).flatten();
+    let mut released = false;
+    let mut rows = Vec::with_capacity(items.len());
+
+    for (i, item) in items.iter_mut().enumerate() {
+        let row = ui.horizontal(|ui| {
+            let size = Vec2::new(14.0, ui.spacing().interact_size.y);
+            let (rect, handle) = ui.allocate_exact_size(size, Sense::drag());
+            let handle = handle.on_hover_cursor(CursorIcon::Grab);
+            let color = ui.style().interact(&handle).fg_stroke.color;
+            for dy in [-4.0, 0.0, 4.0] {
+                let y = rect.center().y + dy;
+                ui.painter().hline(rect.x_range().shrink(2.0), y, Stroke::new(1.5, color));
+            }
+            if handle.drag_started() {
+                dragging = Some(i);
+            }
+            released |= handle.drag_stopped();
+            add_item(ui, item);
+        });
+        rows.push(row.response.rect);
+    }
+
+    let mut changed = false;
+    if let Some(from) = dragging.filter(|&from| from < rows.len()) {
+        ui.ctx().set_cursor_icon(CursorIcon::Grabbing);
+        let pointer_y = ui.ctx().pointer_interact_pos().map(|p| p.y);
+        let to = pointer_y.map_or(from, |y| rows.iter().filter(|r| r.center().y < y).count());
+
+        let stroke = Stroke::new(2.0, ui.visuals().selection.stroke.color);
+        ui.painter().rect_filled(rows[from], Rounding::same(2.0), ui.visuals().selection.bg_fill.gamma_multiply(0.3));
+        let gap = ui.spacing().item_spacing.y / 2.0;
+        let line_y = match to {
+            0 => rows[0].top() - gap,
+            n if n == rows.len() => rows[n - 1].bottom() + gap,
+            n => (rows[n - 1].bottom() + rows[n].top()) / 2.0,
+        };
+        let left = rows.iter().map(|r| r.left()).fold(f32::INFINITY, f32::min);
+        let right = rows.iter().map(|r| r.right()).fold(f32::NEG_INFINITY, f32::max);
+        ui.painter().hline(left..=right, line_y, stroke);
+
+        if ui.input(|i| i.key_pressed(Key::Escape)) {
+            dragging = None;
+        } else if released {
+            let to = if to > from { to - 1 } else { to };
+            if to != from {
+                let item = items.remove(from);
+                items.insert(to, item);
+                changed = true;
+            }
+            dragging = None;
+        }
+    } else {
+        dragging = None;
+    }
+    ui.data_mut(|d| d.insert_temp(id, dragging));
+    changed
+}
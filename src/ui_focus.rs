@@ -0,0 +1,192 @@
+//! Keyboard and gamepad focus navigation for egui-based deckbuilder UIs.
+//!
+//! egui already moves keyboard focus between widgets with the arrow keys and Tab, and clicks
+//! the focused widget on Enter/Space. This module fills the gaps needed for controller play:
+//! feeding gamepad buttons in as key presses, picking an initial focus, and painting a clearly
+//! visible focus ring on custom widgets such as cards.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::input::GamepadButton;
+//! use deckbuilder_eng::ui_focus::*;
+//! use egui::{CentralPanel, Context, RawInput};
+//!
+//! fn before_frame(raw_input: &mut RawInput, pressed: &[GamepadButton]) {
+//!     inject_gamepad(raw_input, pressed);
+//! }
+//!
+//! fn main_menu(ctx: &Context) {
+//!     CentralPanel::default().show(ctx, |ui| {
+//!         let start = ui.button("Start run");
+//!         focus_default(ui, &start);
+//!         focus_highlight(ui, &start);
+//!         if start.clicked() {
+//!             println!("Starting");
+//!         }
+//!         let quit = ui.button("Quit");
+//!         focus_highlight(ui, &quit);
+//!     });
+//! }
+//! ```
+//!
+//! # Details
+//!
+//! - D-pad maps to the arrow keys, South to Enter (confirm), East to Escape (cancel) and the
+//!   bumpers to Tab/Shift+Tab. Call [`inject_gamepad`] from `eframe::App::raw_input_hook`.
+//! - Only widgets that sense clicks can take focus.
+//! - See each function's documentation for usage and customization options.
+
+use egui::{Color32, Event, Id, Key, Modifiers, RawInput, Response, Rounding, Stroke, Ui};
+
+use crate::input::GamepadButton;
+
+/// Appearance of the focus ring painted by [`focus_highlight_ex`].
+#[derive(Clone, Copy)]
+pub struct FocusStyle {
+    /// Ring color and width.
+    pub stroke: Stroke,
+    /// How far the ring sits outside the widget rect.
+    pub expand: f32,
+    /// Corner radius of the ring.
+    pub rounding: f32,
+    /// Whether the ring pulses to draw attention.
+    pub pulse: bool,
+}
+
+impl Default for FocusStyle {
+    fn default() -> Self {
+        Self {
+            stroke: Stroke::new(2.5, Color32::GOLD),
+            expand: 3.0,
+            rounding: 6.0,
+            pulse: true,
+        }
+    }
+}
+
+/// Returns the key a gamepad button is translated to for focus navigation, if any. The flag
+/// is `true` when Shift is held (Shift+Tab for the left bumper).
+///
+/// # Example
+/// ```rust
+/// # use deckbuilder_eng::input::GamepadButton;
+/// # use deckbuilder_eng::ui_focus::gamepad_nav_key;
+/// # use egui::Key;
+/// assert_eq!(gamepad_nav_key(GamepadButton::DPadUp), Some((Key::ArrowUp, false)));
+/// ```
+pub fn gamepad_nav_key(button: GamepadButton) -> Option<(Key, bool)> {
+    match button {
+        GamepadButton::DPadUp => Some((Key::ArrowUp, false)),
+        GamepadButton::DPadDown => Some((Key::ArrowDown, false)),
+        GamepadButton::DPadLeft => Some((Key::ArrowLeft, false)),
+        GamepadButton::DPadRight => Some((Key::ArrowRight, false)),
+        GamepadButton::South => Some((Key::Enter, false)),
+        GamepadButton::East => Some((Key::Escape, false)),
+        GamepadButton::RightBumper => Some((Key::Tab, false)),
+        GamepadButton::LeftBumper => Some((Key::Tab, true)),
+        _ => None,
+    }
+}
+
+/// Appends key press/release events for the navigation buttons in `pressed` to `raw_input`,
+/// so egui's focus navigation and Enter-to-click work with a controller.
+///
+/// # Example
+/// ```rust
+/// # use deckbuilder_eng::input::GamepadButton;
+/// # use deckbuilder_eng::ui_focus::inject_gamepad;
+/// let mut raw_input = egui::RawInput::default();
+/// inject_gamepad(&mut raw_input, &[GamepadButton::DPadDown]);
+/// assert_eq!(raw_input.events.len(), 2);
+/// ```
+pub fn inject_gamepad(raw_input: &mut RawInput, pressed: &[GamepadButton]) {
+    for &button in pressed {
+        let Some((key, shift)) = gamepad_nav_key(button) else { continue };
+        let modifiers = if shift { Modifiers::SHIFT } else { Modifiers::NONE };
+        for pressed in [true, false] {
+            raw_input.events.push(Event::Key {
+                key,
+                physical_key: None,
+                pressed,
+                repeat: false,
+                modifiers,
+            });
+        }
+    }
+}
+
+/// Makes `response` the widget that receives focus when a navigation key is pressed while
+/// nothing has focus. Call it on the first button of a menu or the first card of a row.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui_focus::focus_default;
+/// # fn demo(ui: &mut Ui) {
+/// let end_turn = ui.button("End turn");
+/// focus_default(ui, &end_turn);
+/// # }
+/// ```
+pub fn focus_default(ui: &Ui, response: &Response) {
+    // Focus is given one frame after the key press; otherwise egui would immediately move it
+    // on to the neighbour in the pressed direction.
+    let pending_id = Id::new("deckbuilder_focus_pending");
+    let ctx = ui.ctx();
+    if ctx.data(|d| d.get_temp::<Id>(pending_id)) == Some(response.id) {
+        ctx.data_mut(|d| d.remove::<Id>(pending_id));
+        response.request_focus();
+        return;
+    }
+    let nav_pressed = ui.input(|i| {
+        [Key::ArrowUp, Key::ArrowDown, Key::ArrowLeft, Key::ArrowRight, Key::Tab]
+            .into_iter()
+            .any(|key| i.key_pressed(key))
+    });
+    if nav_pressed && ctx.memory(|m| m.focused().is_none()) {
+        ctx.data_mut(|d| d.insert_temp(pending_id, response.id));
+        ctx.request_repaint();
+    }
+}
+
+/// Paints the default focus ring around `response` if it has keyboard focus.
+/// Returns `true` if it has focus.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui_focus::focus_highlight;
+/// # fn demo(ui: &mut Ui) {
+/// let res = ui.button("Shop");
+/// focus_highlight(ui, &res);
+/// # }
+/// ```
+pub fn focus_highlight(ui: &Ui, response: &Response) -> bool {
+    focus_highlight_ex(ui, response, &FocusStyle::default())
+}
+
+/// Same as [`focus_highlight`], with a custom [`FocusStyle`].
+///
+/// # Example
+/// ```rust
+/// # use egui::{Color32, Stroke, Ui};
+/// # use deckbuilder_eng::ui_focus::{focus_highlight_ex, FocusStyle};
+/// # fn demo(ui: &mut Ui) {
+/// let res = ui.button("Rest");
+/// let style = FocusStyle { stroke: Stroke::new(3.0, Color32::LIGHT_BLUE), pulse: false, ..Default::default() };
+/// focus_highlight_ex(ui, &res, &style);
+/// # }
+/// ```
+pub fn focus_highlight_ex(ui: &Ui, response: &Response, style: &FocusStyle) -> bool {
+    if !response.has_focus() {
+        return false;
+    }
+    let mut stroke = style.stroke;
+    if style.pulse {
+        let time = ui.input(|i| i.time) as f32;
+        stroke.color = stroke.color.gamma_multiply(0.6 + 0.4 * (time * 5.0).sin().abs());
+        ui.ctx().request_repaint();
+    }
+    ui.painter().rect_stroke(response.rect.expand(style.expand), Rounding::same(style.rounding), stroke);
+    true
+}
@@ -0,0 +1,180 @@
+//! Font loading helpers for egui-based deckbuilder UIs.
+//!
+//! Provides [`install_font`] to add a TTF/OTF font from bytes or a file at runtime, and
+//! [`set_default_fonts`] to replace the whole font setup from a [`FontConfig`], without
+//! hand-writing egui `FontDefinitions` code.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use deckbuilder_eng::ui_font::{install_font_from_path, set_default_fonts, FontConfig};
+//! use egui::{Context, FontFamily};
+//!
+//! fn setup_fonts(ctx: &Context) -> std::io::Result<()> {
+//!     // Body text in a custom font, egui's fonts stay as fallback for missing glyphs
+//!     install_font_from_path(ctx, "NotoSans", "assets/fonts/NotoSans.ttf", FontFamily::Proportional)?;
+//!
+//!     // Or describe everything at once
+//!     let config = FontConfig::new()
+//!         .font("Cinzel", std::fs::read("assets/fonts/Cinzel.ttf")?, FontFamily::Name("title".into()))
+//!         .font("NotoSans", std::fs::read("assets/fonts/NotoSans.ttf")?, FontFamily::Proportional);
+//!     set_default_fonts(ctx, &config);
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # Details
+//!
+//! - Installed fonts take priority in their family; egui's built-in fonts stay behind them as
+//!   fallback unless disabled with [`FontConfig::keep_egui_fonts`].
+//! - Named families (e.g. `FontFamily::Name("title".into())`) fall back to the proportional
+//!   fonts, so glyphs missing from a decorative font still render.
+//! - Font changes take effect on the next frame.
+//! - See each function's documentation for usage and customization options.
+
+use std::path::Path;
+
+use egui::{Context, FontData, FontDefinitions, FontFamily, Id};
+
+/// Description of a complete font setup for [`set_default_fonts`].
+#[derive(Clone)]
+pub struct FontConfig {
+    fonts: Vec<(String, Vec<u8>, FontFamily)>,
+    keep_egui_fonts: bool,
+}
+
+impl Default for FontConfig {
+    fn default() -> Self {
+        Self {
+            fonts: Vec::new(),
+            keep_egui_fonts: true,
+        }
+    }
+}
+
+impl FontConfig {
+    /// Creates an empty configuration that keeps egui's fonts as fallback.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_font::FontConfig;
+    /// let config = FontConfig::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a font to `family`. Fonts added earlier take priority over later ones.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::FontFamily;
+    /// # use deckbuilder_eng::ui_font::FontConfig;
+    /// # let bytes: Vec<u8> = Vec::new();
+    /// let config = FontConfig::new().font("MyFont", bytes, FontFamily::Proportional);
+    /// ```
+    pub fn font(mut self, name: impl Into<String>, bytes: Vec<u8>, family: FontFamily) -> Self {
+        self.fonts.push((name.into(), bytes, family));
+        self
+    }
+
+    /// Sets whether egui's built-in fonts are kept as fallback (default `true`).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_font::FontConfig;
+    /// let config = FontConfig::new().keep_egui_fonts(false);
+    /// ```
+    pub fn keep_egui_fonts(mut self, keep: bool) -> Self {
+        self.keep_egui_fonts = keep;
+        self
+    }
+}
+
+fn fonts_id() -> Id {
+    Id::new("deckbuilder_fonts")
+}
+
+/// Adds `name` with `bytes` to `definitions` as the highest-priority font of `family`.
+fn add_font(definitions: &mut FontDefinitions, name: &str, bytes: Vec<u8>, family: FontFamily) {
+    definitions.font_data.insert(name.to_owned(), FontData::from_owned(bytes));
+    if matches!(family, FontFamily::Name(_)) && !definitions.families.contains_key(&family) {
+        let fallback = definitions
+            .families
+            .get(&FontFamily::Proportional)
+            .cloned()
+            .unwrap_or_default();
+        definitions.families.insert(family.clone(), fallback);
+    }
+    let list = definitions.families.entry(family).or_default();
+    list.retain(|n| n != name);
+    list.insert(0, name.to_owned());
+}
+
+/// Installs a TTF/OTF font from memory as the primary font of `family`, keeping the
+/// currently installed fonts as fallback.
+///
+/// # Example
+/// ```rust,no_run
+/// # use egui::{Context, FontFamily};
+/// # use deckbuilder_eng::ui_font::install_font;
+/// # fn demo(ctx: &Context) {
+/// let bytes = std::fs::read("assets/fonts/NotoSans.ttf").unwrap();
+/// install_font(ctx, "NotoSans", bytes, FontFamily::Proportional);
+/// # }
+/// ```
+pub fn install_font(ctx: &Context, name: &str, bytes: Vec<u8>, family: FontFamily) {
+    let mut definitions = ctx
+        .data(|d| d.get_temp::<FontDefinitions>(fonts_id()))
+        .unwrap_or_default();
+    add_font(&mut definitions, name, bytes, family);
+    ctx.data_mut(|d| d.insert_temp(fonts_id(), definitions.clone()));
+    ctx.set_fonts(definitions);
+}
+
+/// Reads a font file and installs it like [`install_font`].
+///
+/// # Example
+/// ```rust,no_run
+/// # use egui::{Context, FontFamily};
+/// # use deckbuilder_eng::ui_font::install_font_from_path;
+/// # fn demo(ctx: &Context) {
+/// if let Err(e) = install_font_from_path(ctx, "Cinzel", "assets/fonts/Cinzel.ttf", FontFamily::Name("title".into())) {
+///     eprintln!("Font could not be loaded: {}", e);
+/// }
+/// # }
+/// ```
+pub fn install_font_from_path(
+    ctx: &Context,
+    name: &str,
+    path: impl AsRef<Path>,
+    family: FontFamily,
+) -> std::io::Result<()> {
+    let bytes = std::fs::read(path)?;
+    install_font(ctx, name, bytes, family);
+    Ok(())
+}
+
+/// Replaces all fonts with the ones described by `config`.
+///
+/// # Example
+/// ```rust
+/// # use egui::Context;
+/// # use deckbuilder_eng::ui_font::{set_default_fonts, FontConfig};
+/// let ctx = Context::default();
+/// // Resets to egui's built-in fonts
+/// set_default_fonts(&ctx, &FontConfig::new());
+/// ```
+pub fn set_default_fonts(ctx: &Context, config: &FontConfig) {
+    let mut definitions = if config.keep_egui_fonts {
+        FontDefinitions::default()
+    } else {
+        FontDefinitions::empty()
+    };
+    // Insert in reverse so the first configured font ends up with the highest priority.
+    for (name, bytes, family) in config.fonts.iter().rev() {
+        add_font(&mut definitions, name, bytes.clone(), family.clone());
+    }
+    ctx.data_mut(|d| d.insert_temp(fonts_id(), definitions.clone()));
+    ctx.set_fonts(definitions);
+}
@@ -0,0 +1,346 @@
+//! Visual feedback effects for egui-based deckbuilder UIs.
+//!
+//! Provides a [`FloatingTextManager`] for damage/heal/block numbers that rise, scale and fade
+//! over time.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::ui_fx::FloatingTextManager;
+//! use egui::{CentralPanel, Context, Pos2};
+//!
+//! fn my_fx(ctx: &Context, floating: &mut FloatingTextManager, enemy_pos: Pos2) {
+//!     CentralPanel::default().show(ctx, |ui| {
+//!         if ui.button("Attack").clicked() {
+//!             floating.damage(enemy_pos, 6);
+//!         }
+//!     });
+//!     // Draw (and advance) all active texts once per frame
+//!     floating.show(ctx);
+//! }
+//! ```
+//!
+//! # Details
+//!
+//! - Effects are painted on the foreground layer, so they appear above panels.
+//! - [`FloatingTextManager::spawn_event`] shows the preset text of a [`GameEvent`] from the
+//!   event bus.
+//! - Time advances with [`game_dt`] (egui's frame delta unless a game clock is published);
+//!   repaints are requested while effects are alive.
+//! - See each function's documentation for usage and customization options.
+
+use egui::{Align2, Color32, Context, FontId, Id, LayerId, Order, Pos2};
+
+use crate::events::GameEvent;
+use crate::game_time::{game_dt, Tick};
+
+/// Preset categories for floating texts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FloatingTextKind {
+    /// Red damage number.
+    Damage,
+    /// Green heal number.
+    Heal,
+    /// Blue-gray block gain.
+    Block,
+    /// Purple status application text.
+    Status,
+}
+
+impl FloatingTextKind {
+    /// Returns the preset color of this kind.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_fx::FloatingTextKind;
+    /// let color = FloatingTextKind::Heal.color();
+    /// ```
+    pub fn color(self) -> Color32 {
+        match self {
+            FloatingTextKind::Damage => Color32::from_rgb(240, 60, 50),
+            FloatingTextKind::Heal => Color32::from_rgb(80, 220, 90),
+            FloatingTextKind::Block => Color32::from_rgb(150, 180, 220),
+            FloatingTextKind::Status => Color32::from_rgb(200, 120, 230),
+        }
+    }
+}
+
+/// A single floating text instance.
+#[derive(Clone)]
+pub struct FloatingText {
+    /// Text to display.
+    pub text: String,
+    /// Screen position where the text spawned.
+    pub pos: Pos2,
+    /// Text color.
+    pub color: Color32,
+    /// Font size in points.
+    pub size: f32,
+    /// Seconds since spawn.
+    pub age: f32,
+    /// Seconds until the text disappears.
+    pub lifetime: f32,
+    /// Points the text rises over its lifetime.
+    pub rise: f32,
+}
+
+/// Spawns and draws floating texts (damage numbers, heals, status names).
+#[derive(Default)]
+pub struct FloatingTextManager {
+    texts: Vec<FloatingText>,
+}
+
+impl FloatingTextManager {
+    /// Creates an empty manager.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_fx::FloatingTextManager;
+    /// let floating = FloatingTextManager::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Spawns a custom floating text at `pos`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::{Pos2, Color32};
+    /// # use deckbuilder_eng::ui_fx::FloatingTextManager;
+    /// let mut floating = FloatingTextManager::new();
+    /// floating.spawn("Critical!", Pos2::new(200.0, 100.0), Color32::GOLD);
+    /// ```
+    pub fn spawn(&mut self, text: impl Into<String>, pos: Pos2, color: Color32) {
+        self.texts.push(FloatingText {
+            text: text.into(),
+            pos,
+            color,
+            size: 28.0,
+            age: 0.0,
+            lifetime: 1.0,
+            rise: 60.0,
+        });
+    }
+
+    /// Spawns a fully customized floating text.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::{Pos2, Color32};
+    /// # use deckbuilder_eng::ui_fx::{FloatingText, FloatingTextManager};
+    /// let mut floating = FloatingTextManager::new();
+    /// floating.spawn_text(FloatingText {
+    ///     text: "Level up".into(),
+    ///     pos: Pos2::new(300.0, 200.0),
+    ///     color: Color32::GOLD,
+    ///     size: 40.0,
+    ///     age: 0.0,
+    ///     lifetime: 2.0,
+    ///     rise: 80.0,
+    /// });
+    /// ```
+    pub fn spawn_text(&mut self, text: FloatingText) {
+        self.texts.push(text);
+    }
+
+    /// Spawns `text` using the color of a preset `kind`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Pos2;
+    /// # use deckbuilder_eng::ui_fx::{FloatingTextKind, FloatingTextManager};
+    /// let mut floating = FloatingTextManager::new();
+    /// floating.spawn_preset(FloatingTextKind::Status, Pos2::new(200.0, 100.0), "Weak");
+    /// ```
+    pub fn spawn_preset(&mut self, kind: FloatingTextKind, pos: Pos2, text: impl Into<String>) {
+        self.spawn(text, pos, kind.color());
+    }
+
+    /// Spawns a damage number ("-6").
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Pos2;
+    /// # use deckbuilder_eng::ui_fx::FloatingTextManager;
+    /// let mut floating = FloatingTextManager::new();
+    /// floating.damage(Pos2::new(200.0, 100.0), 6);
+    /// ```
+    pub fn damage(&mut self, pos: Pos2, amount: i32) {
+        self.spawn_preset(FloatingTextKind::Damage, pos, format!("-{}", amount));
+    }
+
+    /// Spawns a heal number ("+3").
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Pos2;
+    /// # use deckbuilder_eng::ui_fx::FloatingTextManager;
+    /// let mut floating = FloatingTextManager::new();
+    /// floating.heal(Pos2::new(200.0, 100.0), 3);
+    /// ```
+    pub fn heal(&mut self, pos: Pos2, amount: i32) {
+        self.spawn_preset(FloatingTextKind::Heal, pos, format!("+{}", amount));
+    }
+
+    /// Spawns a block gain number ("+5 Block").
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Pos2;
+    /// # use deckbuilder_eng::ui_fx::FloatingTextManager;
+    /// let mut floating = FloatingTextManager::new();
+    /// floating.block(Pos2::new(200.0, 100.0), 5);
+    /// ```
+    pub fn block(&mut self, pos: Pos2, amount: i32) {
+        self.spawn_preset(FloatingTextKind::Block, pos, format!("+{} Block", amount));
+    }
+
+    /// Spawns a status application text (e.g. "Vulnerable").
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Pos2;
+    /// # use deckbuilder_eng::ui_fx::FloatingTextManager;
+    /// let mut floating = FloatingTextManager::new();
+    /// floating.status(Pos2::new(200.0, 100.0), "Vulnerable");
+    /// ```
+    pub fn status(&mut self, pos: Pos2, name: &str) {
+        self.spawn_preset(FloatingTextKind::Status, pos, name);
+    }
+
+    /// Spawns the preset text of `event` at `pos`: damage, heal and block numbers and status
+    /// names. Returns false for other events.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Pos2;
+    /// # use deckbuilder_eng::events::{GameEvent, Target};
+    /// # use deckbuilder_eng::ui_fx::FloatingTextManager;
+    /// let mut floating = FloatingTextManager::new();
+    /// let hit = GameEvent::Damage { target: Target::Enemy, amount: 6 };
+    /// assert!(floating.spawn_event(&hit, Pos2::new(600.0, 200.0)));
+    /// assert!(!floating.spawn_event(&GameEvent::TurnStarted { turn: 2 }, Pos2::ZERO));
+    /// ```
+    pub fn spawn_event(&mut self, event: &GameEvent, pos: Pos2) -> bool {
+        match event {
+            GameEvent::Damage { amount, .. } => self.damage(pos, *amount),
+            GameEvent::Heal { amount, .. } => self.heal(pos, *amount),
+            GameEvent::Block { amount, .. } => self.block(pos, *amount),
+            GameEvent::StatusApplied { name, .. } => self.status(pos, name),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Returns the number of active texts.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_fx::FloatingTextManager;
+    /// let floating = FloatingTextManager::new();
+    /// assert_eq!(floating.len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.texts.len()
+    }
+
+    /// Returns `true` if no texts are active.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_fx::FloatingTextManager;
+    /// let floating = FloatingTextManager::new();
+    /// assert!(floating.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.texts.is_empty()
+    }
+
+    /// Removes all active texts.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_fx::FloatingTextManager;
+    /// let mut floating = FloatingTextManager::new();
+    /// floating.clear();
+    /// ```
+    pub fn clear(&mut self) {
+        self.texts.clear();
+    }
+
+    /// Advances all texts by `dt` seconds and removes expired ones.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_fx::FloatingTextManager;
+    /// let mut floating = FloatingTextManager::new();
+    /// floating.update(1.0 / 60.0);
+    /// ```
+    pub fn update(&mut self, dt: f32) {
+        for text in &mut self.texts {
+            text.age += dt;
+        }
+        self.texts.retain(|t| t.age < t.lifetime);
+    }
+
+    /// Paints all texts without advancing time.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Context;
+    /// # use deckbuilder_eng::ui_fx::FloatingTextManager;
+    /// # fn demo(ctx: &Context, floating: &FloatingTextManager) {
+    /// floating.paint(ctx);
+    /// # }
+    /// ```
+    pub fn paint(&self, ctx: &Context) {
+        let painter = ctx.layer_painter(LayerId::new(Order::Foreground, Id::new("floating_text")));
+        for text in &self.texts {
+            let t = (text.age / text.lifetime).clamp(0.0, 1.0);
+            // Pop in slightly larger, then settle; fade out towards the end.
+            let scale = 1.0 + 0.4 * (1.0 - (t * 5.0).min(1.0));
+            let alpha = 1.0 - t * t;
+            let pos = text.pos - egui::vec2(0.0, text.rise * t);
+            painter.text(
+                pos + egui::vec2(2.0, 2.0),
+                Align2::CENTER_CENTER,
+                &text.text,
+                FontId::proportional(text.size * scale),
+                Color32::BLACK.gamma_multiply(alpha * 0.6),
+            );
+            painter.text(
+                pos,
+                Align2::CENTER_CENTER,
+                &text.text,
+                FontId::proportional(text.size * scale),
+                text.color.gamma_multiply(alpha),
+            );
+        }
+    }
+
+    /// Advances time by the game time of this frame, paints all texts, and requests a repaint
+    /// while any are alive. Call once per frame.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Context;
+    /// # use deckbuilder_eng::ui_fx::FloatingTextManager;
+    /// # fn demo(ctx: &Context, floating: &mut FloatingTextManager) {
+    /// floating.show(ctx);
+    /// # }
+    /// ```
+    pub fn show(&mut self, ctx: &Context) {
+        self.update(game_dt(ctx));
+        self.paint(ctx);
+        if !self.is_empty() {
+            ctx.request_repaint();
+        }
+    }
+}
+
+impl Tick for FloatingTextManager {
+    fn tick(&mut self, dt: f32) {
+        self.update(dt);
+    }
+}
@@ -0,0 +1,989 @@
+//! Combat HUD widgets for egui-based deckbuilder UIs.
+//!
+//! Provides health and resource bars for player/enemy status displays, the energy orb, the
+//! end-turn button, the enemy panel with its telegraphed intent, and turn timers.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::card::GameContext;
+//! use deckbuilder_eng::ui_hud::*;
+//! use egui::{CentralPanel, Context};
+//!
+//! fn my_hud(ctx: &Context, game: &GameContext) {
+//!     CentralPanel::default().show(ctx, |ui| {
+//!         ui_health_bar(ui, game.player_health, 80, &BarStyle::health());
+//!         ui_resource_bar(ui, 12, 20, &BarStyle::block());
+//!         ui_energy_orb(ui, game.energy, 3);
+//!         if ui_end_turn_button(ui, &BattleView::from_game(game, &[])) {
+//!             println!("Turn ended");
+//!         }
+//!     });
+//! }
+//! ```
+//!
+//! # Details
+//!
+//! - `ui_health_bar` remembers the previous value per widget and shows recent damage as a
+//!   draining "chip" segment, timed by [`game_dt`].
+//! - Animated widgets request repaints only while they are animating.
+//! - Countdowns turn yellow at `CountdownStyle::warning_at` and pulse red at `critical_at`;
+//!   [`TurnTimer::poll`] reports each state change once.
+//! - `ui_enemy` returns a clickable response, so it can be placed inside
+//!   [`crate::ui_drag::ui_drop_target`] to act as a card target.
+//! - See each function's documentation for usage and customization options.
+
+use std::time::Duration;
+
+use web_time::Instant;
+
+use egui::{
+    Align2, Button, Color32, FontId, Id, Pos2, Rect, Response, RichText, Rounding, Sense, Stroke, TextureHandle, Ui,
+    Vec2,
+};
+
+use crate::card::{Card, GameContext};
+use crate::game_time::game_dt;
+use crate::ui_anim::{flash_color, shake_offset};
+use crate::status::{predict_damage, StatusContainer};
+use crate::ui_status::{ui_status_icons_ex, StatusIconStyle};
+use crate::ui_text::IconRegistry;
+
+/// Appearance of health and resource bars.
+#[derive(Clone, Copy)]
+pub struct BarStyle {
+    /// Size of the bar in points.
+    pub size: Vec2,
+    /// Color of the filled part.
+    pub fill: Color32,
+    /// Color of the draining damage segment (health bar only).
+    pub chip_color: Color32,
+    /// Color of the empty part.
+    pub background: Color32,
+    /// Color of the "current/max" text.
+    pub text_color: Color32,
+    /// Corner radius.
+    pub rounding: f32,
+    /// Whether to draw the "current/max" text on the bar.
+    pub show_text: bool,
+    /// Seconds the damage chip waits before draining.
+    pub chip_delay: f32,
+    /// Drain speed of the damage chip, as a fraction of `max` per second.
+    pub chip_speed: f32,
+}
+
+impl BarStyle {
+    /// Red health bar preset.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_hud::BarStyle;
+    /// let style = BarStyle::health();
+    /// ```
+    pub fn health() -> Self {
+        Self::default()
+    }
+
+    /// Blue-gray block/armor bar preset.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_hud::BarStyle;
+    /// let style = BarStyle::block();
+    /// ```
+    pub fn block() -> Self {
+        Self {
+            fill: Color32::from_rgb(110, 140, 170),
+            ..Self::default()
+        }
+    }
+
+    /// Blue mana bar preset.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_hud::BarStyle;
+    /// let style = BarStyle::mana();
+    /// ```
+    pub fn mana() -> Self {
+        Self {
+            fill: Color32::from_rgb(60, 100, 220),
+            ..Self::default()
+        }
+    }
+}
+
+impl Default for BarStyle {
+    fn default() -> Self {
+        Self {
+            size: Vec2::new(160.0, 18.0),
+            fill: Color32::from_rgb(200, 40, 40),
+            chip_color: Color32::from_rgb(240, 200, 80),
+            background: Color32::from_rgb(40, 30, 30),
+            text_color: Color32::WHITE,
+            rounding: 4.0,
+            show_text: true,
+            chip_delay: 0.4,
+            chip_speed: 0.5,
+        }
+    }
+}
+
+/// Remembered damage-chip state of a health bar.
+#[derive(Clone, Copy)]
+struct ChipState {
+    chip: f32,
+    last: f32,
+    hold: f32,
+}
+
+fn paint_bar(ui: &Ui, rect: Rect, fraction: f32, chip: f32, text: &str, style: &BarStyle) {
+    let painter = ui.painter();
+    let rounding = Rounding::same(style.rounding);
+    painter.rect_filled(rect, rounding, style.background);
+
+    let width_of = |f: f32| rect.width() * f.clamp(0.0, 1.0);
+    if chip > fraction {
+        let chip_rect = Rect::from_min_size(rect.min, Vec2::new(width_of(chip), rect.height()));
+        painter.rect_filled(chip_rect, rounding, style.chip_color);
+    }
+    let fill_rect = Rect::from_min_size(rect.min, Vec2::new(width_of(fraction), rect.height()));
+    painter.rect_filled(fill_rect, rounding, style.fill);
+    painter.rect_stroke(rect, rounding, Stroke::new(1.0, Color32::from_black_alpha(160)));
+
+    if style.show_text {
+        painter.text(
+            rect.center(),
+            Align2::CENTER_CENTER,
+            text,
+            FontId::proportional(rect.height() * 0.75),
+            style.text_color,
+        );
+    }
+}
+
+/// Health bar with damage-chip animation: recently lost health is shown as a segment
+/// that drains after a short delay.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui_hud::{ui_health_bar, BarStyle};
+/// # fn demo(ui: &mut Ui) {
+/// ui_health_bar(ui, 42, 80, &BarStyle::health());
+/// # }
+/// ```
+pub fn ui_health_bar(ui: &mut Ui, current: i32, max: i32, style: &BarStyle) {
+    let (rect, response) = ui.allocate_exact_size(style.size, Sense::hover());
+    let max_f = max.max(1) as f32;
+    let fraction = current.max(0) as f32 / max_f;
+
+    let id = response.id.with("chip");
+    let dt = game_dt(ui.ctx());
+    let mut state = ui
+        .data(|d| d.get_temp::<ChipState>(id))
+        .unwrap_or(ChipState { chip: fraction, last: fraction, hold: 0.0 });
+    if fraction >= state.chip {
+        state = ChipState { chip: fraction, last: fraction, hold: 0.0 };
+    } else {
+        // New damage restarts the delay before the chip drains.
+        if fraction < state.last {
+            state.hold = 0.0;
+        }
+        state.last = fraction;
+        if state.hold < style.chip_delay {
+            state.hold += dt;
+        } else {
+            state.chip = (state.chip - style.chip_speed * dt).max(fraction);
+        }
+        ui.ctx().request_repaint();
+    }
+    ui.data_mut(|d| d.insert_temp(id, state));
+
+    if ui.is_rect_visible(rect) {
+        paint_bar(ui, rect, fraction, state.chip, &format!("{}/{}", current, max), style);
+    }
+}
+
+/// Generic resource bar (block, mana, ...) without animation.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui_hud::{ui_resource_bar, BarStyle};
+/// # fn demo(ui: &mut Ui) {
+/// ui_resource_bar(ui, 3, 10, &BarStyle::mana());
+/// # }
+/// ```
+pub fn ui_resource_bar(ui: &mut Ui, current: u32, max: u32, style: &BarStyle) {
+    let (rect, _) = ui.allocate_exact_size(style.size, Sense::hover());
+    let fraction = current as f32 / max.max(1) as f32;
+    if ui.is_rect_visible(rect) {
+        paint_bar(ui, rect, fraction, 0.0, &format!("{}/{}", current, max), style);
+    }
+}
+
+/// Appearance of the energy orb.
+#[derive(Clone, Copy)]
+pub struct OrbStyle {
+    /// Diameter of the orb in points.
+    pub diameter: f32,
+    /// Orb fill color while energy is available.
+    pub fill: Color32,
+    /// Orb fill color at zero energy.
+    pub empty_fill: Color32,
+    /// Ring color.
+    pub ring_color: Color32,
+    /// Color of the pulsing ring shown when energy is insufficient.
+    pub warning_color: Color32,
+    /// Color of the "current/max" text.
+    pub text_color: Color32,
+}
+
+impl Default for OrbStyle {
+    fn default() -> Self {
+        Self {
+            diameter: 64.0,
+            fill: Color32::from_rgb(230, 120, 40),
+            empty_fill: Color32::from_rgb(90, 70, 60),
+            ring_color: Color32::from_rgb(250, 210, 140),
+            warning_color: Color32::from_rgb(230, 50, 50),
+            text_color: Color32::WHITE,
+        }
+    }
+}
+
+/// Circular energy indicator showing "current/max".
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui_hud::ui_energy_orb;
+/// # fn demo(ui: &mut Ui) {
+/// ui_energy_orb(ui, 2, 3);
+/// # }
+/// ```
+pub fn ui_energy_orb(ui: &mut Ui, current: u32, max: u32) -> Response {
+    ui_energy_orb_ex(ui, current, max, &OrbStyle::default(), false)
+}
+
+/// Energy orb with custom style. When `pulse` is true (e.g. the hovered card costs more
+/// than `current`), the orb pulses with a warning ring.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui_hud::{ui_energy_orb_ex, OrbStyle};
+/// # fn demo(ui: &mut Ui) {
+/// let hovered_cost = 2;
+/// let energy = 1;
+/// ui_energy_orb_ex(ui, energy, 3, &OrbStyle::default(), hovered_cost > energy);
+/// # }
+/// ```
+pub fn ui_energy_orb_ex(ui: &mut Ui, current: u32, max: u32, style: &OrbStyle, pulse: bool) -> Response {
+    let (rect, response) = ui.allocate_exact_size(Vec2::splat(style.diameter), Sense::hover());
+    if !ui.is_rect_visible(rect) {
+        return response;
+    }
+
+    let painter = ui.painter();
+    let center = rect.center();
+    let mut radius = style.diameter / 2.0 - 2.0;
+    if pulse {
+        let wave = (ui.input(|i| i.time) * 8.0).sin() as f32;
+        radius *= 1.0 + 0.05 * wave;
+        painter.circle_stroke(
+            center,
+            radius + 2.0,
+            Stroke::new(3.0, style.warning_color.gamma_multiply(0.6 + 0.4 * wave.abs())),
+        );
+        ui.ctx().request_repaint();
+    }
+
+    let fill = if current > 0 { style.fill } else { style.empty_fill };
+    painter.circle_filled(center, radius, fill);
+    painter.circle_stroke(center, radius, Stroke::new(2.0, style.ring_color));
+    painter.text(
+        center,
+        Align2::CENTER_CENTER,
+        format!("{}/{}", current, max),
+        FontId::proportional(style.diameter * 0.3),
+        style.text_color,
+    );
+    response
+}
+
+/// Phase of a battle, as far as the HUD is concerned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BattlePhase {
+    /// The player may play cards and end the turn.
+    #[default]
+    PlayerTurn,
+    /// Enemies are acting; player input is locked.
+    EnemyTurn,
+    /// All enemies are defeated.
+    Victory,
+    /// The player is defeated.
+    Defeat,
+}
+
+/// What the combat HUD needs to know about the battle state.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BattleView {
+    /// Current phase.
+    pub phase: BattlePhase,
+    /// Number of cards in hand that can be played right now.
+    pub playable_cards: usize,
+}
+
+impl BattleView {
+    /// Creates a view from a phase and the number of playable cards.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_hud::{BattlePhase, BattleView};
+    /// let view = BattleView::new(BattlePhase::EnemyTurn, 0);
+    /// ```
+    pub fn new(phase: BattlePhase, playable_cards: usize) -> Self {
+        Self { phase, playable_cards }
+    }
+
+    /// Derives the view from the game state: the battle is won or lost once a side's health
+    /// drops to 0, and a card in `hand` is playable if its cost fits the remaining energy.
+    /// Use [`phase`](Self::phase) to mark the enemy turn.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::card::{Card, CardType, GameContext};
+    /// # use deckbuilder_eng::ui_hud::{BattlePhase, BattleView};
+    /// let mut game = GameContext::new(30, 30);
+    /// game.energy = 1;
+    /// let hand = vec![
+    ///     Card::new(1, "Strike", "Deal 6 damage", 1, CardType::Attack),
+    ///     Card::new(2, "Bash", "Deal 8 damage", 2, CardType::Attack),
+    /// ];
+    /// let view = BattleView::from_game(&game, &hand);
+    /// assert_eq!(view.phase, BattlePhase::PlayerTurn);
+    /// assert_eq!(view.playable_cards, 1);
+    /// ```
+    pub fn from_game(game: &GameContext, hand: &[Card]) -> Self {
+        let phase = if game.player_health <= 0 {
+            BattlePhase::Defeat
+        } else if game.enemy_health <= 0 {
+            BattlePhase::Victory
+        } else {
+            BattlePhase::PlayerTurn
+        };
+        let playable_cards = hand.iter().filter(|card| card.cost <= game.energy).count();
+        Self { phase, playable_cards }
+    }
+
+    /// Overrides the phase.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::card::GameContext;
+    /// # use deckbuilder_eng::ui_hud::{BattlePhase, BattleView};
+    /// # let game = GameContext::new(30, 30);
+    /// let view = BattleView::from_game(&game, &[]).phase(BattlePhase::EnemyTurn);
+    /// ```
+    pub fn phase(mut self, phase: BattlePhase) -> Self {
+        self.phase = phase;
+        self
+    }
+}
+
+/// End-turn button reflecting the battle phase: "End Turn" on the player's turn (pulsing once
+/// no playable cards remain), and a disabled "Enemy Turn..." / "Victory" / "Defeat" otherwise.
+/// Returns `true` when the player ends the turn.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui_hud::{ui_end_turn_button, BattlePhase, BattleView};
+/// # fn demo(ui: &mut Ui, phase: &mut BattlePhase, playable_cards: usize) {
+/// if ui_end_turn_button(ui, &BattleView::new(*phase, playable_cards)) {
+///     *phase = BattlePhase::EnemyTurn;
+/// }
+/// # }
+/// ```
+pub fn ui_end_turn_button(ui: &mut Ui, view: &BattleView) -> bool {
+    let (label, enabled) = match view.phase {
+        BattlePhase::PlayerTurn => ("End Turn", true),
+        BattlePhase::EnemyTurn => ("Enemy Turn...", false),
+        BattlePhase::Victory => ("Victory", false),
+        BattlePhase::Defeat => ("Defeat", false),
+    };
+    let pulse = enabled && view.playable_cards == 0;
+    let fill = if enabled {
+        Color32::from_rgb(190, 120, 40)
+    } else {
+        ui.visuals().widgets.inactive.weak_bg_fill
+    };
+    let button = Button::new(RichText::new(label).size(18.0).strong())
+        .fill(fill)
+        .rounding(Rounding::same(8.0))
+        .min_size(Vec2::new(140.0, 44.0));
+    let response = ui.add_enabled(enabled, button);
+
+    if pulse && ui.is_rect_visible(response.rect) {
+        let wave = ((ui.input(|i| i.time) * 4.0).sin() * 0.5 + 0.5) as f32;
+        ui.painter().rect_stroke(
+            response.rect.expand(2.0 + 2.0 * wave),
+            Rounding::same(10.0),
+            Stroke::new(2.0, Color32::from_rgb(250, 210, 120).gamma_multiply(0.4 + 0.6 * wave)),
+        );
+        ui.ctx().request_repaint();
+    }
+    response.clicked()
+}
+
+/// What an enemy plans to do on its next turn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Intent {
+    /// Attack for `damage`, `hits` times.
+    Attack { damage: i32, hits: u32 },
+    /// Gain block.
+    Defend(i32),
+    /// Buff itself.
+    Buff,
+    /// Debuff the player.
+    Debuff,
+    /// Intent hidden from the player.
+    Unknown,
+}
+
+impl Intent {
+    /// Returns the intent with its attack damage adjusted by [`predict_damage`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::status::{Status, StatusContainer};
+    /// # use deckbuilder_eng::ui_hud::Intent;
+    /// let mut enemy = StatusContainer::new();
+    /// enemy.apply(Status::buff("Strength", 3));
+    /// let intent = Intent::Attack { damage: 5, hits: 2 }.predicted(&enemy, &StatusContainer::new());
+    /// assert_eq!(intent, Intent::Attack { damage: 8, hits: 2 });
+    /// ```
+    pub fn predicted(self, attacker: &StatusContainer, target: &StatusContainer) -> Self {
+        match self {
+            Intent::Attack { damage, hits } => Intent::Attack {
+                damage: predict_damage(damage, attacker, target),
+                hits,
+            },
+            other => other,
+        }
+    }
+
+    fn glyph(self) -> (&'static str, Color32) {
+        match self {
+            Intent::Attack { .. } => ("⚔", Color32::from_rgb(230, 80, 60)),
+            Intent::Defend(_) => ("🛡", Color32::from_rgb(90, 150, 230)),
+            Intent::Buff => ("⬆", Color32::from_rgb(110, 200, 110)),
+            Intent::Debuff => ("⬇", Color32::from_rgb(180, 110, 220)),
+            Intent::Unknown => ("?", Color32::from_gray(180)),
+        }
+    }
+}
+
+/// Everything [`ui_enemy`] shows about an enemy.
+#[derive(Clone)]
+pub struct EnemyView<'a> {
+    pub name: String,
+    pub health: i32,
+    pub max_health: i32,
+    pub block: u32,
+    /// Enemy art; a placeholder silhouette is drawn without it.
+    pub art: Option<&'a TextureHandle>,
+    /// Size of the art in points.
+    pub art_size: Vec2,
+    pub statuses: Option<&'a StatusContainer>,
+    pub icons: Option<&'a IconRegistry>,
+    /// Next action, with damage already predicted (see [`Intent::predicted`]).
+    pub intent: Option<Intent>,
+}
+
+impl<'a> EnemyView<'a> {
+    /// Creates a view of an enemy without art, block, statuses or intent.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_hud::EnemyView;
+    /// let view = EnemyView::new("Jaw Worm", 40, 44);
+    /// ```
+    pub fn new(name: impl Into<String>, health: i32, max_health: i32) -> Self {
+        Self {
+            name: name.into(),
+            health,
+            max_health,
+            block: 0,
+            art: None,
+            art_size: Vec2::new(160.0, 160.0),
+            statuses: None,
+            icons: None,
+            intent: None,
+        }
+    }
+
+    /// Sets the enemy art.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::TextureHandle;
+    /// # use deckbuilder_eng::ui_hud::EnemyView;
+    /// # fn demo(texture: &TextureHandle) {
+    /// let view = EnemyView::new("Cultist", 50, 50).art(texture);
+    /// # }
+    /// ```
+    pub fn art(mut self, texture: &'a TextureHandle) -> Self {
+        self.art = Some(texture);
+        self
+    }
+
+    /// Sets the current block.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_hud::EnemyView;
+    /// let view = EnemyView::new("Jaw Worm", 40, 44).block(6);
+    /// ```
+    pub fn block(mut self, block: u32) -> Self {
+        self.block = block;
+        self
+    }
+
+    /// Shows `statuses` with icons from `icons`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::status::StatusContainer;
+    /// # use deckbuilder_eng::ui_hud::EnemyView;
+    /// # use deckbuilder_eng::ui_text::IconRegistry;
+    /// # fn demo(statuses: &StatusContainer, icons: &IconRegistry) {
+    /// let view = EnemyView::new("Jaw Worm", 40, 44).statuses(statuses, icons);
+    /// # }
+    /// ```
+    pub fn statuses(mut self, statuses: &'a StatusContainer, icons: &'a IconRegistry) -> Self {
+        self.statuses = Some(statuses);
+        self.icons = Some(icons);
+        self
+    }
+
+    /// Sets the telegraphed intent.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_hud::{EnemyView, Intent};
+    /// let view = EnemyView::new("Jaw Worm", 40, 44).intent(Intent::Attack { damage: 11, hits: 1 });
+    /// ```
+    pub fn intent(mut self, intent: Intent) -> Self {
+        self.intent = Some(intent);
+        self
+    }
+}
+
+fn paint_intent(ui: &Ui, center: Pos2, intent: Intent) {
+    let painter = ui.painter();
+    let (glyph, color) = intent.glyph();
+    painter.circle_filled(center, 14.0, Color32::from_black_alpha(160));
+    painter.circle_stroke(center, 14.0, Stroke::new(1.5, color));
+    painter.text(center, Align2::CENTER_CENTER, glyph, FontId::proportional(16.0), color);
+    let number = match intent {
+        Intent::Attack { damage, hits } if hits > 1 => Some(format!("{}x{}", damage, hits)),
+        Intent::Attack { damage, .. } => Some(damage.to_string()),
+        Intent::Defend(block) => Some(block.to_string()),
+        _ => None,
+    };
+    if let Some(number) = number {
+        let pos = center + Vec2::new(16.0, 0.0);
+        let font = FontId::proportional(15.0);
+        painter.text(pos + Vec2::splat(1.0), Align2::LEFT_CENTER, &number, font.clone(), Color32::BLACK);
+        painter.text(pos, Align2::LEFT_CENTER, number, font, Color32::WHITE);
+    }
+}
+
+/// Enemy panel: intent above the art, then name, health bar with block and status icons.
+/// Returns the panel's response, which senses clicks, so it can double as a drop target. Its
+/// id can be passed to [`crate::ui_anim::shake`] and [`crate::ui_anim::flash`] for hit feedback; enemies are told apart by name.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use egui::Color32;
+/// # use deckbuilder_eng::ui_anim::{flash, shake};
+/// # use deckbuilder_eng::ui_hud::{ui_enemy, EnemyView, Intent};
+/// # fn demo(ui: &mut Ui) {
+/// let view = EnemyView::new("Jaw Worm", 40, 44).block(5).intent(Intent::Attack { damage: 11, hits: 1 });
+/// let response = ui_enemy(ui, &view);
+/// if response.clicked() {
+///     shake(ui.ctx(), response.id, 6.0, 0.3);
+///     flash(ui.ctx(), response.id, Color32::from_rgb(255, 80, 60), 0.25);
+/// }
+/// # }
+/// ```
+pub fn ui_enemy(ui: &mut Ui, view: &EnemyView<'_>) -> Response {
+    let id = Id::new(("deckbuilder_enemy", view.name.as_str()));
+    let inner = ui.vertical_centered(|ui| {
+        ui.set_width(view.art_size.x.max(BarStyle::health().size.x));
+        let (intent_rect, _) = ui.allocate_exact_size(Vec2::new(view.art_size.x, 30.0), Sense::hover());
+        if let Some(intent) = view.intent
+            && ui.is_rect_visible(intent_rect)
+        {
+            paint_intent(ui, intent_rect.center() - Vec2::new(12.0, 0.0), intent);
+        }
+
+        let (art_rect, _) = ui.allocate_exact_size(view.art_size, Sense::hover());
+        let art_rect = art_rect.translate(shake_offset(ui.ctx(), id));
+        if ui.is_rect_visible(art_rect) {
+            match view.art {
+                Some(texture) => {
+                    ui.painter().image(
+                        texture.id(),
+                        art_rect,
+                        Rect::from_min_max(Pos2::ZERO, Pos2::new(1.0, 1.0)),
+                        Color32::WHITE,
+                    );
+                }
+                None => {
+                    let silhouette = Color32::from_gray(60);
+                    let body = Rect::from_center_size(
+                        art_rect.center() + Vec2::new(0.0, art_rect.height() * 0.15),
+                        art_rect.size() * Vec2::new(0.5, 0.6),
+                    );
+                    ui.painter().rect_filled(body, Rounding::same(body.width() * 0.3), silhouette);
+                    ui.painter().circle_filled(
+                        Pos2::new(art_rect.center().x, body.top()),
+                        art_rect.width() * 0.15,
+                        silhouette,
+                    );
+                }
+            }
+            ui.painter().rect_filled(art_rect, Rounding::same(8.0), flash_color(ui.ctx(), id));
+        }
+
+        ui.strong(&view.name);
+        let mut bar = BarStyle::health();
+        if view.block > 0 {
+            bar.fill = Color32::from_rgb(90, 120, 170);
+        }
+        let bar_rect = ui.available_rect_before_wrap();
+        ui_health_bar(ui, view.health, view.max_health, &bar);
+        if view.block > 0 {
+            let center = Pos2::new(bar_rect.center().x - bar.size.x / 2.0, bar_rect.top() + bar.size.y / 2.0);
+            ui.painter().circle_filled(center, bar.size.y * 0.7, Color32::from_rgb(70, 110, 180));
+            ui.painter().text(
+                center,
+                Align2::CENTER_CENTER,
+                view.block.to_string(),
+                FontId::proportional(bar.size.y * 0.7),
+                Color32::WHITE,
+            );
+        }
+
+        if let Some(statuses) = view.statuses {
+            let icons = IconRegistry::new();
+            let icons = view.icons.unwrap_or(&icons);
+            let style = StatusIconStyle { size: 22.0, ..Default::default() };
+            ui_status_icons_ex(ui, statuses, icons, &style);
+        }
+    });
+    ui.interact(inner.response.rect, id, Sense::click())
+}
+
+/// How close a countdown is to running out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimerState {
+    Running,
+    Warning,
+    Critical,
+    Expired,
+}
+
+/// Appearance and warning thresholds of a countdown.
+#[derive(Debug, Clone, Copy)]
+pub struct CountdownStyle {
+    /// Remaining time below which the countdown shows [`TimerState::Warning`].
+    pub warning_at: Duration,
+    /// Remaining time below which the countdown shows [`TimerState::Critical`].
+    pub critical_at: Duration,
+    /// Text color while running.
+    pub color: Color32,
+    /// Text color in the warning state.
+    pub warning_color: Color32,
+    /// Text color in the critical and expired states.
+    pub critical_color: Color32,
+    /// Font size in points.
+    pub font_size: f32,
+    /// Pulse the text in the critical state.
+    pub pulse: bool,
+}
+
+impl Default for CountdownStyle {
+    fn default() -> Self {
+        Self {
+            warning_at: Duration::from_secs(10),
+            critical_at: Duration::from_secs(5),
+            color: Color32::from_gray(230),
+            warning_color: Color32::from_rgb(240, 190, 60),
+            critical_color: Color32::from_rgb(230, 60, 50),
+            font_size: 22.0,
+            pulse: true,
+        }
+    }
+}
+
+impl CountdownStyle {
+    /// Returns the state of a countdown with `remaining` time left.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use deckbuilder_eng::ui_hud::{CountdownStyle, TimerState};
+    /// let style = CountdownStyle::default();
+    /// assert_eq!(style.state(Duration::from_secs(8)), TimerState::Warning);
+    /// assert_eq!(style.state(Duration::ZERO), TimerState::Expired);
+    /// ```
+    pub fn state(&self, remaining: Duration) -> TimerState {
+        if remaining.is_zero() {
+            TimerState::Expired
+        } else if remaining <= self.critical_at {
+            TimerState::Critical
+        } else if remaining <= self.warning_at {
+            TimerState::Warning
+        } else {
+            TimerState::Running
+        }
+    }
+}
+
+fn format_duration(remaining: Duration) -> String {
+    let secs = remaining.as_secs_f32().ceil() as u64;
+    if secs >= 3600 {
+        format!("{}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+    } else {
+        format!("{}:{:02}", secs / 60, secs % 60)
+    }
+}
+
+fn paint_countdown(ui: &mut Ui, remaining: Duration, running: bool, style: &CountdownStyle) -> Response {
+    let state = style.state(remaining);
+    let color = match state {
+        TimerState::Running => style.color,
+        TimerState::Warning => style.warning_color,
+        TimerState::Critical | TimerState::Expired => style.critical_color,
+    };
+    let mut size = style.font_size;
+    if running && style.pulse && state == TimerState::Critical {
+        let wave = ((ui.input(|i| i.time) * 6.0).sin() * 0.5 + 0.5) as f32;
+        size *= 1.0 + 0.12 * wave;
+        ui.ctx().request_repaint();
+    } else if running && state != TimerState::Expired {
+        // Repaint when the displayed second changes.
+        let until_tick = remaining.as_secs_f32().fract();
+        ui.ctx().request_repaint_after(Duration::from_secs_f32(until_tick.max(0.01)));
+    }
+
+    let box_size = Vec2::new(style.font_size * 3.2, style.font_size * 1.5);
+    let (rect, response) = ui.allocate_exact_size(box_size, Sense::hover());
+    if ui.is_rect_visible(rect) {
+        ui.painter().text(
+            rect.center(),
+            Align2::CENTER_CENTER,
+            format_duration(remaining),
+            FontId::monospace(size),
+            color,
+        );
+    }
+    response
+}
+
+/// Countdown to `deadline` as `m:ss`, turning yellow and then red (pulsing) as time runs out.
+///
+/// # Example
+/// ```rust
+/// # use std::time::{Duration, Instant};
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui_hud::{ui_countdown, CountdownStyle};
+/// # fn demo(ui: &mut Ui, daily_reset: Instant) {
+/// ui_countdown(ui, daily_reset, &CountdownStyle { warning_at: Duration::from_secs(600), ..Default::default() });
+/// # }
+/// ```
+pub fn ui_countdown(ui: &mut Ui, deadline: Instant, style: &CountdownStyle) -> Response {
+    let remaining = deadline.saturating_duration_since(Instant::now());
+    paint_countdown(ui, remaining, true, style)
+}
+
+/// Pausable countdown for timed turns.
+#[derive(Debug, Clone)]
+pub struct TurnTimer {
+    duration: Duration,
+    deadline: Option<Instant>,
+    paused_remaining: Duration,
+    last_state: Option<TimerState>,
+}
+
+impl TurnTimer {
+    /// Creates a paused timer with `duration` left.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use deckbuilder_eng::ui_hud::TurnTimer;
+    /// let timer = TurnTimer::new(Duration::from_secs(30));
+    /// assert_eq!(timer.remaining(), Duration::from_secs(30));
+    /// assert!(!timer.is_running());
+    /// ```
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            deadline: None,
+            paused_remaining: duration,
+            last_state: None,
+        }
+    }
+
+    /// Restarts the timer with its full duration, e.g. at the start of a turn.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use deckbuilder_eng::ui_hud::TurnTimer;
+    /// let mut timer = TurnTimer::new(Duration::from_secs(30));
+    /// timer.start();
+    /// assert!(timer.is_running());
+    /// ```
+    pub fn start(&mut self) {
+        self.deadline = Some(Instant::now() + self.duration);
+        self.last_state = None;
+    }
+
+    /// Stops the timer, keeping the remaining time.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use deckbuilder_eng::ui_hud::TurnTimer;
+    /// let mut timer = TurnTimer::new(Duration::from_secs(30));
+    /// timer.start();
+    /// timer.pause();
+    /// assert!(!timer.is_running());
+    /// ```
+    pub fn pause(&mut self) {
+        if self.deadline.is_some() {
+            self.paused_remaining = self.remaining();
+            self.deadline = None;
+        }
+    }
+
+    /// Continues a paused timer.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use deckbuilder_eng::ui_hud::TurnTimer;
+    /// let mut timer = TurnTimer::new(Duration::from_secs(30));
+    /// timer.resume();
+    /// assert!(timer.is_running());
+    /// ```
+    pub fn resume(&mut self) {
+        if self.deadline.is_none() {
+            self.deadline = Some(Instant::now() + self.paused_remaining);
+        }
+    }
+
+    /// Adds time, e.g. from a relic or card effect.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use deckbuilder_eng::ui_hud::TurnTimer;
+    /// let mut timer = TurnTimer::new(Duration::from_secs(30));
+    /// timer.add_time(Duration::from_secs(5));
+    /// assert_eq!(timer.remaining(), Duration::from_secs(35));
+    /// ```
+    pub fn add_time(&mut self, extra: Duration) {
+        match &mut self.deadline {
+            Some(deadline) => *deadline += extra,
+            None => self.paused_remaining += extra,
+        }
+    }
+
+    /// Returns `true` while the timer counts down.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use deckbuilder_eng::ui_hud::TurnTimer;
+    /// assert!(!TurnTimer::new(Duration::from_secs(30)).is_running());
+    /// ```
+    pub fn is_running(&self) -> bool {
+        self.deadline.is_some()
+    }
+
+    /// Returns the time left.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use deckbuilder_eng::ui_hud::TurnTimer;
+    /// let timer = TurnTimer::new(Duration::from_secs(30));
+    /// assert_eq!(timer.remaining().as_secs(), 30);
+    /// ```
+    pub fn remaining(&self) -> Duration {
+        match self.deadline {
+            Some(deadline) => deadline.saturating_duration_since(Instant::now()),
+            None => self.paused_remaining,
+        }
+    }
+
+    /// Returns `true` once no time is left.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use deckbuilder_eng::ui_hud::TurnTimer;
+    /// assert!(TurnTimer::new(Duration::ZERO).is_expired());
+    /// ```
+    pub fn is_expired(&self) -> bool {
+        self.remaining().is_zero()
+    }
+
+    /// Returns the current state when it differs from the last poll, so warning sounds or an
+    /// automatic end of turn trigger only once.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use deckbuilder_eng::ui_hud::{CountdownStyle, TimerState, TurnTimer};
+    /// let mut timer = TurnTimer::new(Duration::from_secs(3));
+    /// let style = CountdownStyle::default();
+    /// assert_eq!(timer.poll(&style), Some(TimerState::Critical));
+    /// assert_eq!(timer.poll(&style), None);
+    /// ```
+    pub fn poll(&mut self, style: &CountdownStyle) -> Option<TimerState> {
+        let state = style.state(self.remaining());
+        if self.last_state == Some(state) {
+            return None;
+        }
+        self.last_state = Some(state);
+        Some(state)
+    }
+}
+
+/// Countdown display of a [`TurnTimer`]; paused timers are shown without pulsing.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui_hud::{ui_turn_timer, CountdownStyle, TimerState, TurnTimer};
+/// # fn demo(ui: &mut Ui, timer: &mut TurnTimer) {
+/// let style = CountdownStyle::default();
+/// ui_turn_timer(ui, timer, &style);
+/// if timer.poll(&style) == Some(TimerState::Expired) {
+///     println!("Time's up, ending turn");
+/// }
+/// # }
+/// ```
+pub fn ui_turn_timer(ui: &mut Ui, timer: &TurnTimer, style: &CountdownStyle) -> Response {
+    paint_countdown(ui, timer.remaining(), timer.is_running(), style)
+}
@@ -11,7 +11,7 @@
 //! fn my_image_ui(ctx: &Context) {
 //!     CentralPanel::default().show(ctx, |ui| {
 //!         // Load and show an image
-//!         if let Some(texture) = load_texture_from_path(ctx, "assets/example.png") {
+//!         if let Ok(texture) = load_texture_from_path(ctx, "assets/example.png") {
 //!             ui_image(ui, &texture, None);
 //!             ui_image_scaled(ui, &texture, 0.5);
 //!             ui_image_tinted(ui, &texture, Vec2::new(64.0, 64.0), Color32::from_rgba_premultiplied(255, 0, 0, 128));
@@ -19,7 +19,7 @@
 //!         }
 //!
 //!         // Load and show a GIF animation
-//!         if let Some((frames, delays)) = load_gif_frames(ctx, "assets/example.gif", Some(1.0)) {
+//!         if let Ok((frames, delays)) = load_gif_frames(ctx, "assets/example.gif", Some(1.0)) {
 //!             if !frames.is_empty() {
 //!                 ui_image(ui, &frames[0], None); // show first frame
 //!             }
@@ -31,16 +31,147 @@
 //! # Details
 //!
 //! - All functions are designed to work with egui's `Ui` and `Context`.
-//! - GIF helpers return all frames and their delays for manual animation.
-//! - Some functions (e.g. `ui_image_circle`, `ui_image_rotated`) may be stubs or limited by egui's capabilities.
+//! - [`LoadOptions`] scale huge images down before upload and choose the texture sampling
+//!   (`LoadOptions::pixel_art` keeps pixel art crisp); every loader stays within the GPU's
+//!   texture size limit.
+//! - The `*_from_bytes` loaders take images from memory, e.g. `include_bytes!` art or archives.
+//! - Loaders return [`crate::Error`]: `Io` with the path if a file can't be read, `Image` if
+//!   it can't be decoded.
+//! - With the `svg` feature, `ui_svg` and `load_svg_texture` rasterize SVG icons at the
+//!   requested pixel size, cached per path and size.
+//! - Sizes are given as an [`ImageFit`]: original, scale factor, exact, fit or fill (crop).
+//!   The `Option<Vec2>` size of `ui_image` and friends fits the image within that size.
+//! - GIF helpers return all frames and their delays for manual animation; [`GifPlayer`]
+//!   plays them with its own timing, speed and pause state. The `*_with` variants take
+//!   [`LoadOptions`] like the image loaders.
+//! - `load_animation_frames` also reads animated WebP and APNG through the same frames and
+//!   delays interface, so animated art isn't limited to GIF's 256 colors.
+//! - `load_texture_async` decodes on a small pool of worker threads and returns a
+//!   [`TextureTicket`] that shows a placeholder until the texture arrives.
+//! - Images that fail to load show an [`ImageFallback`] (a "missing" checkerboard by default)
+//!   with the path on hover, and are reported once as [`ImageLoadWarning`]s
+//!   (see [`take_image_warnings`]).
+//! - [`ImageEffect`]s (grayscale, brightness) are applied on the CPU before upload; the
+//!   `TextureManager` caches effect variants per file.
+//! - For art iteration, enable the `hot-reload` feature and load through the `TextureManager`,
+//!   which re-uploads textures whose files change on disk.
+//! - Rotated images are drawn as a textured mesh, so any angle and pivot work; use
+//!   `paint_image_rotated` for custom layouts.
+//! - `ui_image_button_ex` gives image buttons hover, pressed and disabled looks, a hover
+//!   scale and a click hook (e.g. for sounds).
+//! - `ui_image_uv` shows a region of a texture; `ui_image_flipped` also mirrors it.
+//! - `ui_image_with_border_ex` draws rounded borders inside, on or outside the image edge.
+//! - `ui_image_nine_slice` stretches only the center and edges of panel and frame art.
+//! - `ui_image_tiled` repeats a texture across an area, for backgrounds and patterns.
+//! - `ui_image_masked` reveals an image with a radial or linear progress mask, or dissolves it
+//!   in noisy blotches with a glowing edge.
+//! - `ui_image_circle` and `ui_image_rounded` clip the image itself to the shape, so the
+//!   corners stay transparent over any background.
 //! - See each function's documentation for usage and customization options.
 
-use egui::{Color32, Context, Pos2, Rect, Stroke, TextureHandle, Ui, Vec2};
+use egui::emath::Rot2;
+use egui::epaint::{RectShape, Vertex};
+use egui::{
+    Color32, Context, Image, Margin, Mesh, Painter, Pos2, Rect, Response, Rounding, Sense, Shape, Stroke,
+    TextureHandle, TextureId, TextureOptions, Ui, Vec2,
+};
+use image::imageops::FilterType;
 use image::io::Reader as ImageReader;
 use image::AnimationDecoder;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
+use crate::error::Error;
+use crate::stats::{self, Counter};
+use crate::texture::TextureManager;
+
+/// Downscaling and texture sampling settings applied when images are uploaded.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LoadOptions {
+    /// Largest allowed width or height in pixels; larger images are scaled down, keeping their
+    /// aspect ratio. `None` only applies the GPU's texture size limit.
+    pub max_size: Option<u32>,
+    /// Resampling filter used when scaling down.
+    pub filter: FilterType,
+    /// How the GPU samples the texture: nearest or linear filtering, and the wrap mode used
+    /// for UVs outside `0..=1`.
+    pub texture: TextureOptions,
+}
+
+impl Default for LoadOptions {
+    fn default() -> Self {
+        Self {
+            max_size: None,
+            filter: FilterType::Triangle,
+            texture: TextureOptions::LINEAR,
+        }
+    }
+}
+
+impl LoadOptions {
+    /// Creates options that scale images down to at most `max_size` pixels per side.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_image::LoadOptions;
+    /// // Card art never needs more than 512 px on low-end machines
+    /// let options = LoadOptions::max_size(512);
+    /// assert_eq!(options.max_size, Some(512));
+    /// ```
+    pub fn max_size(max_size: u32) -> Self {
+        Self {
+            max_size: Some(max_size),
+            ..Self::default()
+        }
+    }
+
+    /// Creates options for pixel art: nearest-neighbor sampling and downscaling, so pixels stay
+    /// crisp at any zoom.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::{Context, TextureOptions};
+    /// # use deckbuilder_eng::ui_image::{load_texture_from_path_with, LoadOptions};
+    /// # fn demo(ctx: &Context) {
+    /// let options = LoadOptions::pixel_art();
+    /// assert_eq!(options.texture, TextureOptions::NEAREST);
+    /// let knight = load_texture_from_path_with(ctx, "assets/sprites/knight.png", &options);
+    /// # }
+    /// ```
+    pub fn pixel_art() -> Self {
+        Self {
+            max_size: None,
+            filter: FilterType::Nearest,
+            texture: TextureOptions::NEAREST,
+        }
+    }
+
+    /// Decodes `bytes` into an image, scaled down to fit `self.max_size` and `max_texture_side`.
+    pub(crate) fn decode(&self, bytes: &[u8], max_texture_side: usize) -> Result<egui::ColorImage, image::ImageError> {
+        let img = image::load_from_memory(bytes)?;
+        Ok(self.prepare(img, max_texture_side))
+    }
+
+    fn prepare(&self, img: image::DynamicImage, max_texture_side: usize) -> egui::ColorImage {
+        let limit = self
+            .max_size
+            .unwrap_or(u32::MAX)
+            .min(max_texture_side.min(u32::MAX as usize) as u32)
+            .max(1);
+        let img = if img.width() > limit || img.height() > limit {
+            img.resize(limit, limit, self.filter)
+        } else {
+            img
+        };
+        let img = img.to_rgba8();
+        let size = [img.width() as usize, img.height() as usize];
+        egui::ColorImage::from_rgba_unmultiplied(size, img.as_raw())
+    }
+}
+
 /// Loads an image from file and returns it as an egui texture handle.
+/// Images larger than the GPU's texture size limit are scaled down. Fails with
+/// [`Error::Io`] if the file can't be read and [`Error::Image`] if it can't be decoded.
 ///
 /// # Example
 /// ```rust
@@ -50,21 +181,126 @@ use std::time::Duration;
 /// let texture = load_texture_from_path(ctx, "assets/example.png");
 /// # }
 /// ```
-pub fn load_texture_from_path(ctx: &Context, path: &str) -> Option<TextureHandle> {
-    // Read image from file
-    let img = ImageReader::open(path).ok()?.decode().ok()?;
-    
-    // Convert to RGBA8 format (for egui)
-    let img = img.to_rgba8();
+pub fn load_texture_from_path(ctx: &Context, path: &str) -> Result<TextureHandle, Error> {
+    load_texture_from_path_with(ctx, path, &LoadOptions::default())
+}
 
-    let size = [img.width() as usize, img.height() as usize];
-    let pixels = img.into_raw();
+/// Like [`load_texture_from_path`], scaling the image down according to `options` before
+/// upload, so huge art doesn't exhaust GPU memory, and sampling it with `options.texture`.
+///
+/// # Example
+/// ```rust
+/// # use egui::Context;
+/// # use deckbuilder_eng::ui_image::{load_texture_from_path_with, LoadOptions};
+/// # fn demo(ctx: &Context) {
+/// let art = load_texture_from_path_with(ctx, "assets/cards/dragon_4k.png", &LoadOptions::max_size(1024));
+/// # }
+/// ```
+pub fn load_texture_from_path_with(ctx: &Context, path: &str, options: &LoadOptions) -> Result<TextureHandle, Error> {
+    // Read image from file
+    let reader = ImageReader::open(path).map_err(|err| Error::io(path, err))?;
+    let img = reader.decode().map_err(|err| Error::image(path, err))?;
 
-    // Convert to egui's ColorImage
-    let color_image = egui::ColorImage::from_rgba_unmultiplied(size, &pixels);
+    // Downscale and convert to egui's ColorImage
+    let color_image = options.prepare(img, ctx.input(|i| i.max_texture_side));
 
     // Upload texture to egui context
-    Some(ctx.load_texture(path, color_image, Default::default()))
+    Ok(ctx.load_texture(path, color_image, options.texture))
+}
+
+/// A CPU-side color effect applied to image pixels before upload.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImageEffect {
+    /// Desaturates by an amount in `0..=1` (`1.0` is fully gray).
+    Grayscale(f32),
+    /// Multiplies the colors by a factor (`0.5` is half as bright).
+    Brightness(f32),
+}
+
+impl ImageEffect {
+    /// Returns a copy of `image` with the effect applied.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::{Color32, ColorImage};
+    /// # use deckbuilder_eng::ui_image::ImageEffect;
+    /// let red = ColorImage::new([1, 1], Color32::from_rgb(200, 0, 0));
+    /// let gray = ImageEffect::Grayscale(1.0).apply(&red);
+    /// let [r, g, b, _] = gray.pixels[0].to_array();
+    /// assert!(r == g && g == b);
+    /// ```
+    pub fn apply(self, image: &egui::ColorImage) -> egui::ColorImage {
+        let pixels = image
+            .pixels
+            .iter()
+            .map(|pixel| {
+                let [r, g, b, a] = pixel.to_array().map(|c| c as f32);
+                let (r, g, b) = match self {
+                    Self::Grayscale(amount) => {
+                        let amount = amount.clamp(0.0, 1.0);
+                        let luma = 0.299 * r + 0.587 * g + 0.114 * b;
+                        (r + (luma - r) * amount, g + (luma - g) * amount, b + (luma - b) * amount)
+                    }
+                    Self::Brightness(factor) => {
+                        let factor = factor.max(0.0);
+                        (r * factor, g * factor, b * factor)
+                    }
+                };
+                // Premultiplied colors can't exceed their alpha.
+                let channel = |c: f32| c.round().clamp(0.0, a) as u8;
+                Color32::from_rgba_premultiplied(channel(r), channel(g), channel(b), a as u8)
+            })
+            .collect();
+        egui::ColorImage {
+            size: image.size,
+            pixels,
+        }
+    }
+
+    pub(crate) fn cache_key(self) -> (u8, u32) {
+        match self {
+            Self::Grayscale(amount) => (0, amount.to_bits()),
+            Self::Brightness(factor) => (1, factor.to_bits()),
+        }
+    }
+    #[cfg(feature = "hot-reload")]
+    pub(crate) fn from_cache_key((kind, bits): (u8, u32)) -> Option<Self> {
+        match kind {
+            0 => Some(Self::Grayscale(f32::from_bits(bits))),
+            1 => Some(Self::Brightness(f32::from_bits(bits))),
+            _ => None,
+        }
+    }
+}
+
+/// Uploads a desaturated copy of `image` (`amount` in `0..=1`), e.g. for unaffordable cards.
+/// Use [`crate::texture::TextureManager::load_with_effect`] to cache it per file.
+///
+/// # Example
+/// ```rust
+/// # use egui::{Color32, ColorImage, Context};
+/// # use deckbuilder_eng::ui_image::texture_grayscale;
+/// let ctx = Context::default();
+/// let art = ColorImage::new([16, 16], Color32::from_rgb(200, 60, 40));
+/// let locked = texture_grayscale(&ctx, "locked_art", &art, 1.0);
+/// ```
+pub fn texture_grayscale(ctx: &Context, name: &str, image: &egui::ColorImage, amount: f32) -> TextureHandle {
+    ctx.load_texture(name, ImageEffect::Grayscale(amount).apply(image), Default::default())
+}
+
+/// Uploads a copy of `image` with its colors multiplied by `factor`, e.g. `0.5` to dim locked
+/// content.
+///
+/// # Example
+/// ```rust
+/// # use egui::{Color32, ColorImage, Context};
+/// # use deckbuilder_eng::ui_image::texture_brightness;
+/// let ctx = Context::default();
+/// let art = ColorImage::new([16, 16], Color32::from_rgb(200, 60, 40));
+/// let dimmed = texture_brightness(&ctx, "dimmed_art", &art, 0.5);
+/// ```
+pub fn texture_brightness(ctx: &Context, name: &str, image: &egui::ColorImage, factor: f32) -> TextureHandle {
+    ctx.load_texture(name, ImageEffect::Brightness(factor).apply(image), Default::default())
 }
 
 /// Loads a GIF animation into memory and returns its frames as TextureHandles and their delays.
@@ -82,136 +318,989 @@ pub fn load_gif_frames(
     ctx: &Context,
     path: &str,
     speed: Option<f32>, // 1.0 = normal, >1.0 faster, <1.0 slower
-) -> Option<(Vec<TextureHandle>, Vec<Duration>)> {
-    let speed = speed.unwrap_or(1.0);
-    let file = std::fs::File::open(path).ok()?;
-    let decoder = image::codecs::gif::GifDecoder::new(file).ok()?;
-    let frames = decoder.into_frames();
-    let frames = frames.collect_frames().ok()?;
+) -> Result<(Vec<TextureHandle>, Vec<Duration>), Error> {
+    load_gif_frames_with(ctx, path, speed, &LoadOptions::default())
+}
+
+/// Like [`load_gif_frames`], scaling and sampling the frames according to `options`.
+///
+/// # Example
+/// ```rust
+/// # use egui::Context;
+/// # use deckbuilder_eng::ui_image::{load_gif_frames_with, LoadOptions};
+/// # fn demo(ctx: &Context) {
+/// let walk = load_gif_frames_with(ctx, "assets/sprites/knight_walk.gif", None, &LoadOptions::pixel_art());
+/// # }
+/// ```
+pub fn load_gif_frames_with(
+    ctx: &Context,
+    path: &str,
+    speed: Option<f32>,
+    options: &LoadOptions,
+) -> Result<(Vec<TextureHandle>, Vec<Duration>), Error> {
+    let file = std::fs::File::open(path).map_err(|err| Error::io(path, err))?;
+    decode_gif_frames(ctx, path, std::io::BufReader::new(file), speed, options)
+}
+
+/// Loads an image (any format the `image` crate detects) from memory, e.g. art embedded with
+/// `include_bytes!` or read from an archive. `name` identifies the texture in egui's debug
+/// views.
+///
+/// # Example
+/// ```rust
+/// # use egui::Context;
+/// # use deckbuilder_eng::ui_image::load_texture_from_bytes;
+/// let ctx = Context::default();
+/// assert!(load_texture_from_bytes(&ctx, "broken", b"not an image").is_err());
+/// ```
+pub fn load_texture_from_bytes(ctx: &Context, name: &str, bytes: &[u8]) -> Result<TextureHandle, Error> {
+    load_texture_from_bytes_with(ctx, name, bytes, &LoadOptions::default())
+}
+
+/// Like [`load_texture_from_bytes`], scaling and sampling the image according to `options`.
+///
+/// # Example
+/// ```rust
+/// # use egui::Context;
+/// # use deckbuilder_eng::ui_image::{load_texture_from_bytes_with, LoadOptions};
+/// let ctx = Context::default();
+/// assert!(load_texture_from_bytes_with(&ctx, "broken", b"", &LoadOptions::max_size(256)).is_err());
+/// ```
+pub fn load_texture_from_bytes_with(ctx: &Context, name: &str, bytes: &[u8], options: &LoadOptions) -> Result<TextureHandle, Error> {
+    let color_image = options.decode(bytes, ctx.input(|i| i.max_texture_side)).map_err(|err| Error::image(name, err))?;
+    Ok(ctx.load_texture(name, color_image, options.texture))
+}
+
+/// Like [`load_gif_frames`], reading the GIF from memory.
+///
+/// # Example
+/// ```rust
+/// # use egui::Context;
+/// # use deckbuilder_eng::ui_image::load_gif_frames_from_bytes;
+/// # fn demo(ctx: &Context) {
+/// static SPINNER: &[u8] = &[]; // e.g. include_bytes!("../assets/spinner.gif")
+/// let frames_and_delays = load_gif_frames_from_bytes(ctx, "spinner", SPINNER, None);
+/// # }
+/// ```
+pub fn load_gif_frames_from_bytes(
+    ctx: &Context,
+    name: &str,
+    bytes: &[u8],
+    speed: Option<f32>,
+) -> Result<(Vec<TextureHandle>, Vec<Duration>), Error> {
+    load_gif_frames_from_bytes_with(ctx, name, bytes, speed, &LoadOptions::default())
+}
+
+/// Like [`load_gif_frames_from_bytes`], scaling and sampling the frames according to
+/// `options`.
+///
+/// # Example
+/// ```rust
+/// # use egui::Context;
+/// # use deckbuilder_eng::ui_image::{load_gif_frames_from_bytes_with, LoadOptions};
+/// let ctx = Context::default();
+/// assert!(load_gif_frames_from_bytes_with(&ctx, "broken", b"GIF89a", None, &LoadOptions::pixel_art()).is_err());
+/// ```
+pub fn load_gif_frames_from_bytes_with(
+    ctx: &Context,
+    name: &str,
+    bytes: &[u8],
+    speed: Option<f32>,
+    options: &LoadOptions,
+) -> Result<(Vec<TextureHandle>, Vec<Duration>), Error> {
+    decode_gif_frames(ctx, name, std::io::Cursor::new(bytes), speed, options)
+}
+
+/// Loads an animation (GIF, animated WebP or APNG) and returns its frames and delays like
+/// [`load_gif_frames`]. Still images of any format load as a single frame.
+///
+/// # Example
+/// ```rust
+/// # use egui::Context;
+/// # use deckbuilder_eng::ui_image::load_animation_frames;
+/// # fn demo(ctx: &Context) {
+/// if let Ok((frames, delays)) = load_animation_frames(ctx, "assets/cards/inferno.webp", None) {
+///     println!("{} frames", frames.len());
+/// }
+/// # }
+/// ```
+pub fn load_animation_frames(
+    ctx: &Context,
+    path: &str,
+    speed: Option<f32>,
+) -> Result<(Vec<TextureHandle>, Vec<Duration>), Error> {
+    load_animation_frames_with(ctx, path, speed, &LoadOptions::default())
+}
+
+/// Like [`load_animation_frames`], scaling and sampling the frames according to `options`.
+///
+/// # Example
+/// ```rust
+/// # use egui::Context;
+/// # use deckbuilder_eng::ui_image::{load_animation_frames_with, LoadOptions};
+/// # fn demo(ctx: &Context) {
+/// let idle = load_animation_frames_with(ctx, "assets/sprites/slime_idle.gif", None, &LoadOptions::pixel_art());
+/// # }
+/// ```
+pub fn load_animation_frames_with(
+    ctx: &Context,
+    path: &str,
+    speed: Option<f32>,
+    options: &LoadOptions,
+) -> Result<(Vec<TextureHandle>, Vec<Duration>), Error> {
+    let bytes = std::fs::read(path).map_err(|err| Error::io(path, err))?;
+    load_animation_frames_from_bytes_with(ctx, path, &bytes, speed, options)
+}
+
+/// Like [`load_animation_frames`], reading the animation from memory.
+///
+/// # Example
+/// ```rust
+/// # use egui::Context;
+/// # use deckbuilder_eng::ui_image::load_animation_frames_from_bytes;
+/// let ctx = Context::default();
+/// assert!(load_animation_frames_from_bytes(&ctx, "broken", b"not an image", None).is_err());
+/// ```
+pub fn load_animation_frames_from_bytes(
+    ctx: &Context,
+    name: &str,
+    bytes: &[u8],
+    speed: Option<f32>,
+) -> Result<(Vec<TextureHandle>, Vec<Duration>), Error> {
+    load_animation_frames_from_bytes_with(ctx, name, bytes, speed, &LoadOptions::default())
+}
+
+/// Like [`load_animation_frames_from_bytes`], scaling and sampling the frames according to
+/// `options`.
+///
+/// # Example
+/// ```rust
+/// # use egui::Context;
+/// # use deckbuilder_eng::ui_image::{load_animation_frames_from_bytes_with, LoadOptions};
+/// let ctx = Context::default();
+/// let options = LoadOptions::pixel_art();
+/// assert!(load_animation_frames_from_bytes_with(&ctx, "broken", b"", None, &options).is_err());
+/// ```
+pub fn load_animation_frames_from_bytes_with(
+    ctx: &Context,
+    name: &str,
+    bytes: &[u8],
+    speed: Option<f32>,
+    options: &LoadOptions,
+) -> Result<(Vec<TextureHandle>, Vec<Duration>), Error> {
+    use image::codecs::png::PngDecoder;
+    use image::codecs::webp::WebPDecoder;
+    use image::{Frame, ImageFormat};
+
+    let error = |err| Error::image(name, err);
+    let frames = match image::guess_format(bytes).map_err(error)? {
+        ImageFormat::Gif => {
+            return decode_gif_frames(ctx, name, std::io::Cursor::new(bytes), speed, options);
+        }
+        ImageFormat::WebP => {
+            let decoder = WebPDecoder::new(std::io::Cursor::new(bytes)).map_err(error)?;
+            if decoder.has_animation() {
+                decoder.into_frames().collect_frames().map_err(error)?
+            } else {
+                vec![Frame::new(image::load_from_memory(bytes).map_err(error)?.to_rgba8())]
+            }
+        }
+        ImageFormat::Png => {
+            let decoder = PngDecoder::new(std::io::Cursor::new(bytes)).map_err(error)?;
+            if decoder.is_apng() {
+                decoder.apng().into_frames().collect_frames().map_err(error)?
+            } else {
+                vec![Frame::new(image::load_from_memory(bytes).map_err(error)?.to_rgba8())]
+            }
+        }
+        _ => vec![Frame::new(image::load_from_memory(bytes).map_err(error)?.to_rgba8())],
+    };
+    Ok(upload_frames(ctx, name, frames, speed, options))
+}
+
+fn decode_gif_frames<R: std::io::Read>(
+    ctx: &Context,
+    name: &str,
+    reader: R,
+    speed: Option<f32>,
+    options: &LoadOptions,
+) -> Result<(Vec<TextureHandle>, Vec<Duration>), Error> {
+    let frames = image::codecs::gif::GifDecoder::new(reader)
+        .and_then(|decoder| decoder.into_frames().collect_frames())
+        .map_err(|err| Error::image(name, err))?;
+    Ok(upload_frames(ctx, name, frames, speed, options))
+}
 
+fn upload_frames(
+    ctx: &Context,
+    name: &str,
+    frames: Vec<image::Frame>,
+    speed: Option<f32>,
+    options: &LoadOptions,
+) -> (Vec<TextureHandle>, Vec<Duration>) {
+    let max_texture_side = ctx.input(|i| i.max_texture_side);
+    let speed = speed.unwrap_or(1.0);
     let mut textures = Vec::new();
     let mut delays = Vec::new();
 
-    for frame in frames {
+    for (i, frame) in frames.into_iter().enumerate() {
         let delay = frame.delay();
         let delay_ms = delay.numer_denom_ms().0 as f32 / delay.numer_denom_ms().1 as f32;
         let adj = (delay_ms / speed).max(1.0);
         let delay = Duration::from_millis(adj as u64);
-        let img = frame.into_buffer();
-        let size = [img.width() as usize, img.height() as usize];
-        let pixels = img.into_raw();
-        let color_image = egui::ColorImage::from_rgba_unmultiplied(size, &pixels);
-        let texture = ctx.load_texture("gif_frame", color_image, Default::default());
+        let color_image = options.prepare(image::DynamicImage::ImageRgba8(frame.into_buffer()), max_texture_side);
+        let texture = ctx.load_texture(format!("{}#{}", name, i), color_image, options.texture);
         textures.push(texture);
         delays.push(delay);
     }
 
-    Some((textures, delays))
+    stats::add(Counter::AnimationsDecoded, 1);
+    stats::add(Counter::AnimationFrames, textures.len() as u64);
+    (textures, delays)
+}
+
+/// Plays a GIF (or animated WebP/APNG): owns its frames, delays, current frame and speed, and
+/// advances itself each time it is shown.
+#[derive(Clone)]
+pub struct GifPlayer {
+    frames: Vec<TextureHandle>,
+    delays: Vec<Duration>,
+    index: usize,
+    elapsed: f32,
+    speed: f32,
+    playing: bool,
+}
+
+impl GifPlayer {
+    /// Loads the GIF, animated WebP or APNG at `path`. Returns `None` if it can't be read or
+    /// has no frames.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Context;
+    /// # use deckbuilder_eng::ui_image::GifPlayer;
+    /// let ctx = Context::default();
+    /// assert!(GifPlayer::load(&ctx, "missing.gif").is_none());
+    /// ```
+    pub fn load(ctx: &Context, path: &str) -> Option<Self> {
+        Self::load_with(ctx, path, &LoadOptions::default())
+    }
+
+    /// Like [`GifPlayer::load`], scaling and sampling the frames according to `options`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Context;
+    /// # use deckbuilder_eng::ui_image::{GifPlayer, LoadOptions};
+    /// let ctx = Context::default();
+    /// assert!(GifPlayer::load_with(&ctx, "missing.gif", &LoadOptions::pixel_art()).is_none());
+    /// ```
+    pub fn load_with(ctx: &Context, path: &str, options: &LoadOptions) -> Option<Self> {
+        let (frames, delays) = load_animation_frames_with(ctx, path, None, options).ok()?;
+        Self::from_frames(frames, delays)
+    }
+
+    /// Like [`GifPlayer::load`], reading the GIF from memory.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Context;
+    /// # use deckbuilder_eng::ui_image::GifPlayer;
+    /// let ctx = Context::default();
+    /// assert!(GifPlayer::from_bytes(&ctx, "broken", b"GIF89a").is_none());
+    /// ```
+    pub fn from_bytes(ctx: &Context, name: &str, bytes: &[u8]) -> Option<Self> {
+        Self::from_bytes_with(ctx, name, bytes, &LoadOptions::default())
+    }
+
+    /// Like [`GifPlayer::from_bytes`], scaling and sampling the frames according to `options`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Context;
+    /// # use deckbuilder_eng::ui_image::{GifPlayer, LoadOptions};
+    /// let ctx = Context::default();
+    /// assert!(GifPlayer::from_bytes_with(&ctx, "broken", b"GIF89a", &LoadOptions::pixel_art()).is_none());
+    /// ```
+    pub fn from_bytes_with(ctx: &Context, name: &str, bytes: &[u8], options: &LoadOptions) -> Option<Self> {
+        let (frames, delays) = load_animation_frames_from_bytes_with(ctx, name, bytes, None, options).ok()?;
+        Self::from_frames(frames, delays)
+    }
+
+    /// Creates a player from already loaded frames and their delays at normal speed. Returns
+    /// `None` if there are no frames.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::texture::TextureManager;
+    /// # use deckbuilder_eng::ui_image::GifPlayer;
+    /// # fn demo(textures: &mut TextureManager) {
+    /// let player = textures
+    ///     .load_gif("assets/example.gif", 1.0)
+    ///     .ok()
+    ///     .and_then(|(frames, delays)| GifPlayer::from_frames(frames, delays));
+    /// # }
+    /// ```
+    pub fn from_frames(frames: Vec<TextureHandle>, mut delays: Vec<Duration>) -> Option<Self> {
+        if frames.is_empty() {
+            return None;
+        }
+        delays.resize(frames.len(), Duration::from_millis(100));
+        Some(Self {
+            frames,
+            delays,
+            index: 0,
+            elapsed: 0.0,
+            speed: 1.0,
+            playing: true,
+        })
+    }
+
+    /// Sets the playback speed (`1.0` = normal, `2.0` = twice as fast). Non-positive values
+    /// are ignored.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_image::GifPlayer;
+    /// # fn demo(player: &mut GifPlayer) {
+    /// player.set_speed(2.0);
+    /// assert_eq!(player.speed(), 2.0);
+    /// # }
+    /// ```
+    pub fn set_speed(&mut self, speed: f32) {
+        if speed > 0.0 {
+            self.speed = speed;
+        }
+    }
+
+    /// Returns the playback speed.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_image::GifPlayer;
+    /// # fn demo(player: &GifPlayer) {
+    /// println!("{}x", player.speed());
+    /// # }
+    /// ```
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    /// Resumes playback.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_image::GifPlayer;
+    /// # fn demo(player: &mut GifPlayer) {
+    /// player.play();
+    /// assert!(player.is_playing());
+    /// # }
+    /// ```
+    pub fn play(&mut self) {
+        self.playing = true;
+    }
+
+    /// Pauses playback on the current frame.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_image::GifPlayer;
+    /// # fn demo(player: &mut GifPlayer) {
+    /// player.pause();
+    /// assert!(!player.is_playing());
+    /// # }
+    /// ```
+    pub fn pause(&mut self) {
+        self.playing = false;
+    }
+
+    /// Returns `true` unless paused.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_image::GifPlayer;
+    /// # fn demo(player: &mut GifPlayer) {
+    /// if player.is_playing() {
+    ///     player.pause();
+    /// } else {
+    ///     player.play();
+    /// }
+    /// # }
+    /// ```
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    /// Returns the index of the current frame.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_image::GifPlayer;
+    /// # fn demo(player: &GifPlayer) {
+    /// println!("Frame {}/{}", player.frame_index() + 1, player.frame_count());
+    /// # }
+    /// ```
+    pub fn frame_index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the number of frames.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_image::GifPlayer;
+    /// # fn demo(player: &GifPlayer) {
+    /// assert!(player.frame_count() > 0);
+    /// # }
+    /// ```
+    pub fn frame_count(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Returns the texture of the current frame.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Ui;
+    /// # use deckbuilder_eng::ui_image::{ui_image_circle, GifPlayer};
+    /// # fn demo(ui: &mut Ui, player: &GifPlayer) {
+    /// ui_image_circle(ui, player.current_frame(), 48.0);
+    /// # }
+    /// ```
+    pub fn current_frame(&self) -> &TextureHandle {
+        &self.frames[self.index]
+    }
+
+    /// Jumps back to the first frame.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_image::GifPlayer;
+    /// # fn demo(player: &mut GifPlayer) {
+    /// player.restart();
+    /// assert_eq!(player.frame_index(), 0);
+    /// # }
+    /// ```
+    pub fn restart(&mut self) {
+        self.index = 0;
+        self.elapsed = 0.0;
+    }
+
+    /// Advances playback by `dt` seconds. Returns the time until the next frame, or `None`
+    /// while paused or for single-frame images.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use egui::{Color32, ColorImage, Context};
+    /// # use deckbuilder_eng::ui_image::GifPlayer;
+    /// let ctx = Context::default();
+    /// let frame = ctx.load_texture("frame", ColorImage::new([1, 1], Color32::WHITE), Default::default());
+    /// let delays = vec![Duration::from_millis(100); 3];
+    /// let mut player = GifPlayer::from_frames(vec![frame; 3], delays).unwrap();
+    /// player.set_speed(2.0);
+    /// player.update(0.06);
+    /// assert_eq!(player.frame_index(), 1);
+    /// ```
+    pub fn update(&mut self, dt: f32) -> Option<Duration> {
+        if !self.playing || self.frames.len() < 2 {
+            return None;
+        }
+        self.elapsed += dt.max(0.0);
+        loop {
+            let delay = (self.delays[self.index].as_secs_f32() / self.speed).max(0.001);
+            if self.elapsed < delay {
+                return Some(Duration::from_secs_f32(delay - self.elapsed));
+            }
+            self.elapsed -= delay;
+            self.index = (self.index + 1) % self.frames.len();
+        }
+    }
+
+    /// Advances by the frame time and shows the current frame. `None` shows it at its
+    /// original size, `Some(size)` fits it within `size`. Repaints are requested for the next
+    /// frame while playing.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::{Ui, Vec2};
+    /// # use deckbuilder_eng::ui_image::GifPlayer;
+    /// # fn demo(ui: &mut Ui, player: &mut Option<GifPlayer>) {
+    /// if player.is_none() {
+    ///     *player = GifPlayer::load(ui.ctx(), "assets/example.gif");
+    /// }
+    /// if let Some(player) = player {
+    ///     player.ui(ui, Some(Vec2::new(128.0, 128.0)));
+    /// }
+    /// # }
+    /// ```
+    pub fn ui(&mut self, ui: &mut Ui, size: Option<Vec2>) -> Response {
+        let dt = ui.input(|i| i.unstable_dt);
+        if let Some(next) = self.update(dt) {
+            ui.ctx().request_repaint_after(next);
+        }
+        ui_image_fit(ui, self.current_frame(), size.into())
+    }
+}
+
+/// How an image is sized in the layout.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImageFit {
+    /// The texture size, one point per pixel.
+    Original,
+    /// The texture size times a factor; the aspect ratio is preserved.
+    Scale(f32),
+    /// Exactly this size; the image is stretched if the aspect ratios differ.
+    Exact(Vec2),
+    /// The largest size within these bounds that preserves the aspect ratio.
+    Fit(Vec2),
+    /// Exactly this size, preserving the aspect ratio by cropping the image around its center.
+    Fill(Vec2),
+}
+
+impl From<Option<Vec2>> for ImageFit {
+    /// `None` is [`ImageFit::Original`], `Some(size)` is [`ImageFit::Fit`].
+    fn from(size: Option<Vec2>) -> Self {
+        match size {
+            Some(size) => ImageFit::Fit(size),
+            None => ImageFit::Original,
+        }
+    }
+}
+
+impl ImageFit {
+    /// Returns the size to show a texture of `texture_size` at and the UV rect to sample.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::{Rect, Vec2};
+    /// # use deckbuilder_eng::ui_image::ImageFit;
+    /// let texture = Vec2::new(200.0, 100.0);
+    /// assert_eq!(ImageFit::Fit(Vec2::splat(50.0)).layout(texture).0, Vec2::new(50.0, 25.0));
+    /// assert_eq!(ImageFit::Scale(0.5).layout(texture).0, Vec2::new(100.0, 50.0));
+    ///
+    /// let (size, uv) = ImageFit::Fill(Vec2::splat(50.0)).layout(texture);
+    /// assert_eq!(size, Vec2::splat(50.0));
+    /// assert_eq!(uv.width(), 0.5);
+    /// ```
+    pub fn layout(self, texture_size: Vec2) -> (Vec2, Rect) {
+        let full = Rect::from_min_max(Pos2::ZERO, Pos2::new(1.0, 1.0));
+        if texture_size.x <= 0.0 || texture_size.y <= 0.0 {
+            let size = match self {
+                ImageFit::Exact(size) | ImageFit::Fit(size) | ImageFit::Fill(size) => size,
+                ImageFit::Original | ImageFit::Scale(_) => Vec2::ZERO,
+            };
+            return (size, full);
+        }
+        match self {
+            ImageFit::Original => (texture_size, full),
+            ImageFit::Scale(factor) => (texture_size * factor.max(0.0), full),
+            ImageFit::Exact(size) => (size, full),
+            ImageFit::Fit(bounds) => {
+                let factor = (bounds.x / texture_size.x).min(bounds.y / texture_size.y);
+                (texture_size * factor.max(0.0), full)
+            }
+            ImageFit::Fill(size) => {
+                let factor = (size.x / texture_size.x).max(size.y / texture_size.y);
+                let visible = if factor > 0.0 {
+                    (size / (texture_size * factor)).min(Vec2::splat(1.0))
+                } else {
+                    Vec2::splat(1.0)
+                };
+                (size, Rect::from_center_size(Pos2::new(0.5, 0.5), visible))
+            }
+        }
+    }
+}
+
+/// Shows a texture sized by `fit`. Returns the response of the image rect.
+///
+/// # Example
+/// ```rust
+/// # use egui::{Ui, Context, Vec2};
+/// # use deckbuilder_eng::ui_image::{load_texture_from_path, ui_image_fit, ImageFit};
+/// # fn demo(ui: &mut Ui, ctx: &Context) {
+/// if let Ok(texture) = load_texture_from_path(ctx, "assets/example.png") {
+///     // Square portrait cropped from a wide image
+///     ui_image_fit(ui, &texture, ImageFit::Fill(Vec2::splat(96.0)));
+/// }
+/// # }
+/// ```
+pub fn ui_image_fit(ui: &mut Ui, texture: &TextureHandle, fit: ImageFit) -> Response {
+    let (size, uv) = fit.layout(texture.size_vec2());
+    ui.add(Image::new((texture.id(), size)).uv(uv))
+}
+
+/// Mirroring applied to an image's UV rect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImageFlip {
+    /// Drawn as is.
+    #[default]
+    None,
+    /// Mirrored left to right, e.g. for an enemy facing the player.
+    Horizontal,
+    /// Mirrored top to bottom.
+    Vertical,
+    /// Mirrored both ways (a 180 degree turn).
+    Both,
+}
+
+impl ImageFlip {
+    /// Builds a flip from horizontal and vertical flags.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_image::ImageFlip;
+    /// let facing_left = true;
+    /// assert_eq!(ImageFlip::from_flags(facing_left, false), ImageFlip::Horizontal);
+    /// ```
+    pub fn from_flags(horizontal: bool, vertical: bool) -> Self {
+        match (horizontal, vertical) {
+            (false, false) => Self::None,
+            (true, false) => Self::Horizontal,
+            (false, true) => Self::Vertical,
+            (true, true) => Self::Both,
+        }
+    }
+
+    /// Returns `uv` mirrored by this flip.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::{Pos2, Rect};
+    /// # use deckbuilder_eng::ui_image::ImageFlip;
+    /// let uv = Rect::from_min_max(Pos2::new(0.0, 0.0), Pos2::new(0.5, 1.0));
+    /// let flipped = ImageFlip::Horizontal.apply(uv);
+    /// assert_eq!(flipped.min, Pos2::new(0.5, 0.0));
+    /// assert_eq!(flipped.max, Pos2::new(0.0, 1.0));
+    /// ```
+    pub fn apply(self, uv: Rect) -> Rect {
+        let (horizontal, vertical) = match self {
+            Self::None => (false, false),
+            Self::Horizontal => (true, false),
+            Self::Vertical => (false, true),
+            Self::Both => (true, true),
+        };
+        let (min_x, max_x) = if horizontal { (uv.max.x, uv.min.x) } else { (uv.min.x, uv.max.x) };
+        let (min_y, max_y) = if vertical { (uv.max.y, uv.min.y) } else { (uv.min.y, uv.max.y) };
+        Rect::from_min_max(Pos2::new(min_x, min_y), Pos2::new(max_x, max_y))
+    }
+}
+
+/// Shows the `uv` part of a texture (normalized, `(0, 0)` to `(1, 1)` is the whole texture)
+/// at `size`, e.g. a region of an atlas, without creating a new texture.
+///
+/// # Example
+/// ```rust
+/// # use egui::{Pos2, Rect, TextureHandle, Ui, Vec2};
+/// # use deckbuilder_eng::ui_image::ui_image_uv;
+/// # fn demo(ui: &mut Ui, icons: &TextureHandle) {
+/// // Top-left icon of a 4x4 atlas
+/// let uv = Rect::from_min_max(Pos2::ZERO, Pos2::new(0.25, 0.25));
+/// ui_image_uv(ui, icons, Vec2::splat(32.0), uv);
+/// # }
+/// ```
+pub fn ui_image_uv(ui: &mut Ui, texture: &TextureHandle, size: Vec2, uv: Rect) -> Response {
+    ui_image_flipped(ui, texture, size, uv, ImageFlip::None)
+}
+
+/// Like [`ui_image_uv`], mirrored by `flip`.
+///
+/// # Example
+/// ```rust
+/// # use egui::{Pos2, Rect, TextureHandle, Ui, Vec2};
+/// # use deckbuilder_eng::ui_image::{ui_image_flipped, ImageFlip};
+/// # fn demo(ui: &mut Ui, slime: &TextureHandle, facing_left: bool) {
+/// let whole = Rect::from_min_max(Pos2::ZERO, Pos2::new(1.0, 1.0));
+/// ui_image_flipped(ui, slime, Vec2::splat(96.0), whole, ImageFlip::from_flags(facing_left, false));
+/// # }
+/// ```
+pub fn ui_image_flipped(ui: &mut Ui, texture: &TextureHandle, size: Vec2, uv: Rect, flip: ImageFlip) -> Response {
+    ui.add(Image::new((texture.id(), size)).uv(flip.apply(uv)))
 }
 
 /// Shows a texture in the UI, with optional width and height.
+/// `None` shows it at its original size; `Some(size)` fits it within `size`, keeping the
+/// aspect ratio (see [`ui_image_fit`] for other modes).
 ///
 /// # Example
 /// ```rust
 /// # use egui::{Ui, Context};
 /// # use deckbuilder_eng::ui_image::{load_texture_from_path, ui_image};
 /// # fn demo(ui: &mut Ui, ctx: &Context) {
-/// if let Some(texture) = load_texture_from_path(ctx, "assets/example.png") {
+/// if let Ok(texture) = load_texture_from_path(ctx, "assets/example.png") {
 ///     ui_image(ui, &texture, None);
 /// }
 /// # }
 /// ```
-pub fn ui_image(ui: &mut Ui, texture: &TextureHandle, _size: Option<Vec2>) {
-    ui.image(texture);
+pub fn ui_image(ui: &mut Ui, texture: &TextureHandle, size: Option<Vec2>) {
+    ui_image_fit(ui, texture, size.into());
 }
 
-/// Loads and shows an image from a file path (shortcut).
+/// Shows the image at `path` (shortcut), loaded through `textures` so it is decoded and
+/// uploaded only once. If loading fails, the configured [`ImageFallback`] is shown and an
+/// [`ImageLoadWarning`] is reported.
 ///
 /// # Example
 /// ```rust
-/// # use egui::{Ui, Context};
+/// # use egui::Ui;
+/// # use deckbuilder_eng::texture::TextureManager;
 /// # use deckbuilder_eng::ui_image::ui_image_from_path;
-/// # fn demo(ui: &mut Ui, ctx: &Context) {
-/// ui_image_from_path(ui, ctx, "assets/example.png", None);
+/// # fn demo(ui: &mut Ui, textures: &mut TextureManager) {
+/// ui_image_from_path(ui, textures, "assets/example.png", None);
 /// # }
 /// ```
-pub fn ui_image_from_path(ui: &mut Ui, ctx: &Context, path: &str, _size: Option<Vec2>) {
-    if let Some(texture) = load_texture_from_path(ctx, path) {
-        ui_image(ui, &texture, None);
-    } else {
-        ui.label(format!("Failed to load image from: {}", path));
+pub fn ui_image_from_path(ui: &mut Ui, textures: &mut TextureManager, path: &str, size: Option<Vec2>) {
+    match textures.load(path) {
+        Ok(texture) => ui_image(ui, &texture, size),
+        Err(err) => {
+            let err = err.to_string();
+            report_image_warning(ui.ctx(), path, &err);
+            ui_image_fallback(ui, path, &err, size.unwrap_or(Vec2::splat(64.0)));
+        }
     }
 }
 
-/// Shows a texture at a given scale factor.
+/// Shows a texture at a given scale factor of its original size.
 ///
 /// # Example
 /// ```rust
 /// # use egui::{Ui, Context};
 /// # use deckbuilder_eng::ui_image::{load_texture_from_path, ui_image_scaled};
 /// # fn demo(ui: &mut Ui, ctx: &Context) {
-/// if let Some(texture) = load_texture_from_path(ctx, "assets/example.png") {
+/// if let Ok(texture) = load_texture_from_path(ctx, "assets/example.png") {
 ///     ui_image_scaled(ui, &texture, 0.5);
 /// }
 /// # }
 /// ```
-pub fn ui_image_scaled(ui: &mut Ui, texture: &TextureHandle, _scale: f32) {
-    ui.image(texture);
+pub fn ui_image_scaled(ui: &mut Ui, texture: &TextureHandle, scale: f32) {
+    ui_image_fit(ui, texture, ImageFit::Scale(scale));
 }
 
-/// Shows a texture cropped as a circle.
+/// Paints the `uv` part of a texture into `rect` with corners rounded by `radius`; the image
+/// is clipped to the rounded shape. A radius of half the size of a square rect gives a circle.
+///
+/// # Example
+/// ```rust
+/// # use egui::{Color32, Pos2, Rect, TextureHandle, Ui, Vec2};
+/// # use deckbuilder_eng::ui_image::paint_image_rounded;
+/// # fn demo(ui: &mut Ui, relic: &TextureHandle) {
+/// let rect = Rect::from_min_size(Pos2::new(20.0, 20.0), Vec2::splat(48.0));
+/// let uv = Rect::from_min_max(Pos2::ZERO, Pos2::new(1.0, 1.0));
+/// paint_image_rounded(ui.painter(), relic.id(), rect, uv, 8.0, Color32::WHITE);
+/// # }
+/// ```
+pub fn paint_image_rounded(painter: &Painter, texture_id: TextureId, rect: Rect, uv: Rect, radius: f32, tint: Color32) {
+    painter.add(RectShape {
+        fill_texture_id: texture_id,
+        uv,
+        ..RectShape::filled(rect, Rounding::same(radius), tint)
+    });
+}
+
+/// Shows a texture cropped to a rounded rectangle of `size`, e.g. for relic icons. The image
+/// fills the size, cropped around its center if the aspect ratios differ.
+///
+/// # Example
+/// ```rust
+/// # use egui::{Ui, Context, Vec2};
+/// # use deckbuilder_eng::ui_image::{load_texture_from_path, ui_image_rounded};
+/// # fn demo(ui: &mut Ui, ctx: &Context) {
+/// if let Ok(texture) = load_texture_from_path(ctx, "assets/example.png") {
+///     ui_image_rounded(ui, &texture, Vec2::splat(48.0), 8.0);
+/// }
+/// # }
+/// ```
+pub fn ui_image_rounded(ui: &mut Ui, texture: &TextureHandle, size: Vec2, radius: f32) -> Response {
+    let (_, uv) = ImageFit::Fill(size).layout(texture.size_vec2());
+    let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+    if ui.is_rect_visible(rect) {
+        paint_image_rounded(ui.painter(), texture.id(), rect, uv, radius, Color32::WHITE);
+    }
+    response
+}
+
+/// Shows a texture cropped as a circle, e.g. for avatars.
 ///
 /// # Example
 /// ```rust
 /// # use egui::{Ui, Context};
 /// # use deckbuilder_eng::ui_image::{load_texture_from_path, ui_image_circle};
 /// # fn demo(ui: &mut Ui, ctx: &Context) {
-/// if let Some(texture) = load_texture_from_path(ctx, "assets/example.png") {
+/// if let Ok(texture) = load_texture_from_path(ctx, "assets/example.png") {
 ///     ui_image_circle(ui, &texture, 64.0);
 /// }
 /// # }
 /// ```
-pub fn ui_image_circle(ui: &mut Ui, texture: &TextureHandle, diameter: f32) {
-    use egui::Shape;
-    let pos = ui.cursor().min;
-    let rect = egui::Rect::from_center_size(pos + egui::vec2(diameter/2.0, diameter/2.0), Vec2::splat(diameter));
-    let _mesh = egui::Mesh::with_texture(texture.id());
-    ui.painter().add(Shape::circle_filled(rect.center(), diameter/2.0, egui::Color32::WHITE));
-    ui.allocate_ui_at_rect(rect, |_ui| {});
+pub fn ui_image_circle(ui: &mut Ui, texture: &TextureHandle, diameter: f32) -> Response {
+    ui_image_rounded(ui, texture, Vec2::splat(diameter), diameter / 2.0)
 }
 
-/// Shows a texture as a button (clickable).
+/// Shows a texture as a button (clickable), sized like [`ui_image`].
 ///
 /// # Example
 /// ```rust
 /// # use egui::{Ui, Context};
 /// # use deckbuilder_eng::ui_image::{load_texture_from_path, ui_image_button};
 /// # fn demo(ui: &mut Ui, ctx: &Context) {
-/// if let Some(texture) = load_texture_from_path(ctx, "assets/example.png") {
+/// if let Ok(texture) = load_texture_from_path(ctx, "assets/example.png") {
 ///     if ui_image_button(ui, &texture, None) {
 ///         // clicked
 ///     }
 /// }
 /// # }
 /// ```
-pub fn ui_image_button(ui: &mut Ui, texture: &TextureHandle, _size: Option<Vec2>) -> bool {
-    ui.add(egui::ImageButton::new(texture)).clicked()
+pub fn ui_image_button(ui: &mut Ui, texture: &TextureHandle, size: Option<Vec2>) -> bool {
+    let (size, uv) = ImageFit::from(size).layout(texture.size_vec2());
+    ui.add(egui::ImageButton::new(Image::new((texture.id(), size)).uv(uv))).clicked()
 }
 
-/// Shows a texture as a link (opens URL when clicked).
+/// Appearance of an image button in each state, for [`ui_image_button_ex`].
+#[derive(Clone)]
+pub struct ImageButtonStyle {
+    /// Texture shown normally.
+    pub texture: TextureHandle,
+    /// Texture shown while hovered; `None` uses `texture`.
+    pub hover_texture: Option<TextureHandle>,
+    /// Texture shown while pressed; `None` uses the hover texture.
+    pub pressed_texture: Option<TextureHandle>,
+    /// Texture shown while disabled; `None` uses `texture`.
+    pub disabled_texture: Option<TextureHandle>,
+    /// Size of the button; `None` uses the texture size.
+    pub size: Option<Vec2>,
+    /// Tint in the normal state.
+    pub tint: Color32,
+    /// Tint while hovered.
+    pub hover_tint: Color32,
+    /// Tint while pressed.
+    pub pressed_tint: Color32,
+    /// Tint while disabled.
+    pub disabled_tint: Color32,
+    /// Scale while hovered (`1.0` = no growth); the layout size doesn't change.
+    pub hover_scale: f32,
+    /// Whether the button can be clicked.
+    pub enabled: bool,
+    /// Called when the button is clicked, e.g. to play a click sound.
+    pub on_click: Option<Arc<dyn Fn() + Send + Sync>>,
+}
+
+impl ImageButtonStyle {
+    /// Creates a style showing `texture` that brightens and grows slightly on hover.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::{Color32, TextureHandle, Vec2};
+    /// # use deckbuilder_eng::ui_image::ImageButtonStyle;
+    /// # fn demo(play: &TextureHandle) {
+    /// let style = ImageButtonStyle {
+    ///     size: Some(Vec2::new(96.0, 40.0)),
+    ///     hover_scale: 1.1,
+    ///     ..ImageButtonStyle::new(play.clone())
+    /// };
+    /// # }
+    /// ```
+    pub fn new(texture: TextureHandle) -> Self {
+        Self {
+            texture,
+            hover_texture: None,
+            pressed_texture: None,
+            disabled_texture: None,
+            size: None,
+            tint: Color32::from_gray(225),
+            hover_tint: Color32::WHITE,
+            pressed_tint: Color32::from_gray(170),
+            disabled_tint: Color32::from_gray(90),
+            hover_scale: 1.05,
+            enabled: true,
+            on_click: None,
+        }
+    }
+
+    /// Sets the click hook.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::TextureHandle;
+    /// # use deckbuilder_eng::ui_image::ImageButtonStyle;
+    /// # fn demo(play: &TextureHandle) {
+    /// let style = ImageButtonStyle::new(play.clone()).on_click(|| println!("click"));
+    /// # }
+    /// ```
+    pub fn on_click(mut self, hook: impl Fn() + Send + Sync + 'static) -> Self {
+        self.on_click = Some(Arc::new(hook));
+        self
+    }
+}
+
+/// Shows an image button with distinct normal, hover, pressed and disabled looks (see
+/// [`ImageButtonStyle`]). Calls the style's click hook when clicked.
+///
+/// # Example
+/// ```rust
+/// # use egui::{TextureHandle, Ui};
+/// # use deckbuilder_eng::ui_image::{ui_image_button_ex, ImageButtonStyle};
+/// # fn demo(ui: &mut Ui, end_turn: &TextureHandle, end_turn_hover: &TextureHandle, my_turn: bool) {
+/// let style = ImageButtonStyle {
+///     hover_texture: Some(end_turn_hover.clone()),
+///     enabled: my_turn,
+///     ..ImageButtonStyle::new(end_turn.clone())
+/// };
+/// if ui_image_button_ex(ui, &style).clicked() {
+///     // end the turn
+/// }
+/// # }
+/// ```
+pub fn ui_image_button_ex(ui: &mut Ui, style: &ImageButtonStyle) -> Response {
+    let (size, uv) = ImageFit::from(style.size).layout(style.texture.size_vec2());
+    let sense = if style.enabled { Sense::click() } else { Sense::hover() };
+    let (rect, response) = ui.allocate_exact_size(size, sense);
+
+    let hovered = style.enabled && response.hovered();
+    let pressed = hovered && response.is_pointer_button_down_on();
+    let scale = ui.ctx().animate_value_with_time(
+        response.id,
+        if hovered { style.hover_scale } else { 1.0 },
+        0.1,
+    );
+    if response.clicked() && let Some(hook) = &style.on_click {
+        hook();
+    }
+
+    if ui.is_rect_visible(rect) {
+        let hover_texture = style.hover_texture.as_ref().unwrap_or(&style.texture);
+        let (texture, tint) = if !style.enabled {
+            (style.disabled_texture.as_ref().unwrap_or(&style.texture), style.disabled_tint)
+        } else if pressed {
+            (style.pressed_texture.as_ref().unwrap_or(hover_texture), style.pressed_tint)
+        } else if hovered {
+            (hover_texture, style.hover_tint)
+        } else {
+            (&style.texture, style.tint)
+        };
+        let draw_rect = Rect::from_center_size(rect.center(), rect.size() * scale);
+        ui.painter().image(texture.id(), draw_rect, uv, tint);
+    }
+    if style.enabled {
+        response.on_hover_cursor(egui::CursorIcon::PointingHand)
+    } else {
+        response
+    }
+}
+
+/// Shows a texture as a link (opens URL when clicked), sized like [`ui_image`].
 ///
 /// # Example
 /// ```rust
 /// # use egui::{Ui, Context};
 /// # use deckbuilder_eng::ui_image::{load_texture_from_path, ui_image_link};
 /// # fn demo(ui: &mut Ui, ctx: &Context) {
-/// if let Some(texture) = load_texture_from_path(ctx, "assets/example.png") {
+/// if let Ok(texture) = load_texture_from_path(ctx, "assets/example.png") {
 ///     ui_image_link(ui, &texture, "https://github.com/", None);
 /// }
 /// # }
 /// ```
-pub fn ui_image_link(ui: &mut Ui, texture: &TextureHandle, url: &str, _size: Option<Vec2>) {
-    if ui.add(egui::ImageButton::new(texture)).clicked() {
+pub fn ui_image_link(ui: &mut Ui, texture: &TextureHandle, url: &str, size: Option<Vec2>) {
+    if ui_image_button(ui, texture, size) {
         ui.ctx().open_url(egui::OpenUrl::same_tab(url));
     }
 }
@@ -223,7 +1312,7 @@ pub fn ui_image_link(ui: &mut Ui, texture: &TextureHandle, url: &str, _size: Opt
 /// # use egui::{Ui, Context, Vec2, Color32};
 /// # use deckbuilder_eng::ui_image::{load_texture_from_path, ui_image_tinted};
 /// # fn demo(ui: &mut Ui, ctx: &Context) {
-/// if let Some(texture) = load_texture_from_path(ctx, "assets/example.png") {
+/// if let Ok(texture) = load_texture_from_path(ctx, "assets/example.png") {
 ///     ui_image_tinted(ui, &texture, Vec2::new(64.0, 64.0), Color32::from_rgba_premultiplied(255, 0, 0, 128));
 /// }
 /// # }
@@ -238,31 +1327,463 @@ pub fn ui_image_tinted(ui: &mut Ui, texture: &TextureHandle, size: Vec2, tint: C
     );
 }
 
-/// Shows a rotated image (in radians).
-/// (temporary stub—not supported in this build)
+/// Paints a texture into `rect` rotated by `angle` radians (clockwise) around `pivot`, given
+/// in normalized image coordinates (`(0.5, 0.5)` is the center, `(0.5, 1.0)` the bottom edge).
+/// Useful for tilting, shuffling and flipping cards in custom layouts.
+///
+/// # Example
+/// ```rust
+/// # use egui::{Color32, Pos2, Rect, Ui, Vec2};
+/// # use egui::TextureHandle;
+/// # use deckbuilder_eng::ui_image::paint_image_rotated;
+/// # fn demo(ui: &mut Ui, card_art: &TextureHandle) {
+/// let rect = Rect::from_min_size(Pos2::new(100.0, 100.0), Vec2::new(140.0, 200.0));
+/// // Tilt around the bottom edge, like a card fanned in a hand
+/// paint_image_rotated(ui.painter(), card_art.id(), rect, 0.2, Vec2::new(0.5, 1.0), Color32::WHITE);
+/// # }
+/// ```
+pub fn paint_image_rotated(
+    painter: &Painter,
+    texture_id: TextureId,
+    rect: Rect,
+    angle: f32,
+    pivot: Vec2,
+    tint: Color32,
+) {
+    let origin = rect.min + rect.size() * pivot;
+    let rotation = Rot2::from_angle(angle);
+    let corners = [rect.left_top(), rect.right_top(), rect.right_bottom(), rect.left_bottom()];
+    let uvs = [Pos2::new(0.0, 0.0), Pos2::new(1.0, 0.0), Pos2::new(1.0, 1.0), Pos2::new(0.0, 1.0)];
+    let mut mesh = Mesh::with_texture(texture_id);
+    for (corner, uv) in corners.into_iter().zip(uvs) {
+        mesh.vertices.push(Vertex {
+            pos: origin + rotation * (corner - origin),
+            uv,
+            color: tint,
+        });
+    }
+    mesh.add_triangle(0, 1, 2);
+    mesh.add_triangle(0, 2, 3);
+    painter.add(Shape::mesh(mesh));
+}
+
+/// Shows a rotated image (in radians), rotated around its center. The layout space is the
+/// unrotated `size`.
 ///
 /// # Example
 /// ```rust
 /// # use egui::{Ui, Context, Vec2};
 /// # use deckbuilder_eng::ui_image::{load_texture_from_path, ui_image_rotated};
 /// # fn demo(ui: &mut Ui, ctx: &Context) {
-/// if let Some(texture) = load_texture_from_path(ctx, "assets/example.png") {
+/// if let Ok(texture) = load_texture_from_path(ctx, "assets/example.png") {
 ///     ui_image_rotated(ui, &texture, Vec2::new(64.0, 64.0), 1.57);
 /// }
 /// # }
 /// ```
-pub fn ui_image_rotated(_ui: &mut Ui, _texture: &TextureHandle, _size: Vec2, _angle: f32) {
-    // rotation unsupported in this build
+pub fn ui_image_rotated(ui: &mut Ui, texture: &TextureHandle, size: Vec2, angle: f32) -> Response {
+    ui_image_rotated_ex(ui, texture, size, angle, Vec2::splat(0.5))
+}
+
+/// Shows an image rotated by `angle` radians around `pivot` (normalized image coordinates,
+/// see [`paint_image_rotated`]).
+///
+/// # Example
+/// ```rust
+/// # use egui::{Ui, Context, Vec2};
+/// # use deckbuilder_eng::ui_image::{load_texture_from_path, ui_image_rotated_ex};
+/// # fn demo(ui: &mut Ui, ctx: &Context) {
+/// if let Ok(texture) = load_texture_from_path(ctx, "assets/example.png") {
+///     // Swing from the top-left corner
+///     ui_image_rotated_ex(ui, &texture, Vec2::new(64.0, 64.0), -0.3, Vec2::ZERO);
+/// }
+/// # }
+/// ```
+pub fn ui_image_rotated_ex(ui: &mut Ui, texture: &TextureHandle, size: Vec2, angle: f32, pivot: Vec2) -> Response {
+    let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+    if ui.is_rect_visible(rect) {
+        paint_image_rotated(ui.painter(), texture.id(), rect, angle, pivot, Color32::WHITE);
+    }
+    response
+}
+
+/// Paints a texture into `rect` as a nine-slice (9-patch): the corners keep their size, the
+/// edges stretch along one axis and the center stretches both ways. `margins` are the border
+/// widths in texture pixels, drawn one point per pixel; they shrink proportionally if `rect`
+/// is too small to fit them.
+///
+/// # Example
+/// ```rust
+/// # use egui::{Color32, Margin, Pos2, Rect, Ui, Vec2};
+/// # use egui::TextureHandle;
+/// # use deckbuilder_eng::ui_image::paint_image_nine_slice;
+/// # fn demo(ui: &mut Ui, panel: &TextureHandle) {
+/// let rect = Rect::from_min_size(Pos2::new(20.0, 20.0), Vec2::new(300.0, 180.0));
+/// paint_image_nine_slice(ui.painter(), panel.id(), panel.size_vec2(), rect, Margin::same(12.0), Color32::WHITE);
+/// # }
+/// ```
+pub fn paint_image_nine_slice(
+    painter: &Painter,
+    texture_id: TextureId,
+    texture_size: Vec2,
+    rect: Rect,
+    margins: Margin,
+    tint: Color32,
+) {
+    if texture_size.x <= 0.0 || texture_size.y <= 0.0 {
+        return;
+    }
+    let fit = |start: f32, end: f32, available: f32| {
+        let total = start + end;
+        if total > available && total > 0.0 {
+            let factor = available / total;
+            (start * factor, end * factor)
+        } else {
+            (start, end)
+        }
+    };
+    let (left, right) = fit(margins.left, margins.right, rect.width());
+    let (top, bottom) = fit(margins.top, margins.bottom, rect.height());
+    let xs = [rect.left(), rect.left() + left, rect.right() - right, rect.right()];
+    let ys = [rect.top(), rect.top() + top, rect.bottom() - bottom, rect.bottom()];
+    let us = [0.0, margins.left / texture_size.x, 1.0 - margins.right / texture_size.x, 1.0];
+    let vs = [0.0, margins.top / texture_size.y, 1.0 - margins.bottom / texture_size.y, 1.0];
+
+    let mut mesh = Mesh::with_texture(texture_id);
+    for (y, v) in ys.into_iter().zip(vs) {
+        for (x, u) in xs.into_iter().zip(us) {
+            mesh.vertices.push(Vertex {
+                pos: Pos2::new(x, y),
+                uv: Pos2::new(u, v),
+                color: tint,
+            });
+        }
+    }
+    for row in 0..3 {
+        for col in 0..3 {
+            let i = (row * 4 + col) as u32;
+            mesh.add_triangle(i, i + 1, i + 5);
+            mesh.add_triangle(i, i + 5, i + 4);
+        }
+    }
+    painter.add(Shape::mesh(mesh));
+}
+
+/// Shows a texture stretched to `size` as a nine-slice (see [`paint_image_nine_slice`]), so
+/// panel backgrounds, card frames and buttons scale without distorting their borders.
+///
+/// # Example
+/// ```rust
+/// # use egui::{Context, Margin, Ui, Vec2};
+/// # use deckbuilder_eng::ui_image::{load_texture_from_path, ui_image_nine_slice};
+/// # fn demo(ui: &mut Ui, ctx: &Context) {
+/// if let Ok(frame) = load_texture_from_path(ctx, "assets/ui/panel.png") {
+///     ui_image_nine_slice(ui, &frame, Vec2::new(320.0, 200.0), Margin::same(16.0));
+/// }
+/// # }
+/// ```
+pub fn ui_image_nine_slice(ui: &mut Ui, texture: &TextureHandle, size: Vec2, margins: Margin) -> Response {
+    let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+    if ui.is_rect_visible(rect) {
+        paint_image_nine_slice(ui.painter(), texture.id(), texture.size_vec2(), rect, margins, Color32::WHITE);
+    }
+    response
+}
+
+/// Paints a texture repeated across `rect`, each tile `tile_size` points large. `offset`
+/// shifts the pattern (e.g. animate it for a scrolling background); tiles at the edges are
+/// cut off. Works with any texture, as the tiles are separate quads of one mesh.
+///
+/// # Example
+/// ```rust
+/// # use egui::{Color32, Pos2, Rect, TextureHandle, Ui, Vec2};
+/// # use deckbuilder_eng::ui_image::paint_image_tiled;
+/// # fn demo(ui: &mut Ui, felt: &TextureHandle, time: f32) {
+/// let rect = ui.max_rect();
+/// paint_image_tiled(ui.painter(), felt.id(), rect, Vec2::splat(64.0), Vec2::new(time * 10.0, 0.0), Color32::WHITE);
+/// # }
+/// ```
+pub fn paint_image_tiled(painter: &Painter, texture_id: TextureId, rect: Rect, tile_size: Vec2, offset: Vec2, tint: Color32) {
+    let tile = tile_size.max(Vec2::splat(1.0));
+    if rect.width() <= 0.0 || rect.height() <= 0.0 {
+        return;
+    }
+    // Start at the tile containing the top-left corner.
+    let shift = Vec2::new(offset.x.rem_euclid(tile.x), offset.y.rem_euclid(tile.y));
+    let back = Vec2::new(if shift.x > 0.0 { tile.x } else { 0.0 }, if shift.y > 0.0 { tile.y } else { 0.0 });
+    let start = rect.min + shift - back;
+    let mut mesh = Mesh::with_texture(texture_id);
+    let mut y = start.y;
+    while y < rect.bottom() {
+        let mut x = start.x;
+        while x < rect.right() {
+            let tile_rect = Rect::from_min_size(Pos2::new(x, y), tile);
+            let shown = tile_rect.intersect(rect);
+            if shown.is_positive() {
+                let uv_min = ((shown.min - tile_rect.min) / tile).to_pos2();
+                let uv_max = ((shown.max - tile_rect.min) / tile).to_pos2();
+                mesh.add_rect_with_uv(shown, Rect::from_min_max(uv_min, uv_max), tint);
+            }
+            x += tile.x;
+        }
+        y += tile.y;
+    }
+    painter.add(Shape::mesh(mesh));
+}
+
+/// Shows a texture repeated across `area_size`, each tile `tile_size` points large, e.g. for
+/// backgrounds, table felt and frame patterns.
+///
+/// # Example
+/// ```rust
+/// # use egui::{Context, Ui, Vec2};
+/// # use deckbuilder_eng::ui_image::{load_texture_from_path, ui_image_tiled};
+/// # fn demo(ui: &mut Ui, ctx: &Context) {
+/// if let Ok(felt) = load_texture_from_path(ctx, "assets/ui/felt.png") {
+///     ui_image_tiled(ui, &felt, Vec2::new(600.0, 300.0), felt.size_vec2());
+/// }
+/// # }
+/// ```
+pub fn ui_image_tiled(ui: &mut Ui, texture: &TextureHandle, area_size: Vec2, tile_size: Vec2) -> Response {
+    let (rect, response) = ui.allocate_exact_size(area_size, Sense::hover());
+    if ui.is_rect_visible(rect) {
+        let visible = rect.intersect(ui.clip_rect());
+        // Only tile the visible part, keeping the pattern anchored to the area's corner.
+        let offset = rect.min - visible.min;
+        paint_image_tiled(ui.painter(), texture.id(), visible, tile_size, offset, Color32::WHITE);
+    }
+    response
+}
+
+/// Direction a [`ImageMask::Linear`] reveals the image in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MaskDirection {
+    /// From the left edge to the right.
+    #[default]
+    LeftToRight,
+    /// From the right edge to the left.
+    RightToLeft,
+    /// From the top edge down.
+    TopToBottom,
+    /// From the bottom edge up.
+    BottomToTop,
 }
 
-/// Shows an image with a border.
+/// Which part of an image [`paint_image_masked`] shows. `progress` runs from `0.0` to `1.0`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImageMask {
+    /// A pie slice starting at 12 o'clock covering `progress` of a full turn, e.g. a cooldown
+    /// sweep.
+    Radial {
+        /// Shown fraction of the full turn.
+        progress: f32,
+        /// Sweep clockwise (otherwise counterclockwise).
+        clockwise: bool,
+    },
+    /// A bar covering `progress` of the image, growing in `direction`.
+    Linear {
+        /// Shown fraction of the image.
+        progress: f32,
+        /// Edge the bar grows from.
+        direction: MaskDirection,
+    },
+    /// Noisy blotches disappearing as `progress` rises, e.g. a burning card. Cells close to
+    /// vanishing are tinted with `edge`.
+    Dissolve {
+        /// Dissolved fraction (`0.0` = whole image, `1.0` = gone).
+        progress: f32,
+        /// Noise seed, so several dissolving cards don't look alike.
+        seed: u32,
+        /// Color of the burning edge (`Color32::TRANSPARENT` for none).
+        edge: Color32,
+    },
+}
+
+/// Number of noise cells per side of a dissolve mask.
+const DISSOLVE_CELLS: usize = 24;
+
+/// Paints the `uv` part of a texture into `rect`, showing only the part selected by `mask`.
+///
+/// # Example
+/// ```rust
+/// # use egui::{Color32, Pos2, Rect, TextureHandle, Ui, Vec2};
+/// # use deckbuilder_eng::ui_image::{paint_image_masked, ImageMask};
+/// # fn demo(ui: &mut Ui, ability: &TextureHandle, cooldown_left: f32) {
+/// let rect = Rect::from_min_size(Pos2::new(20.0, 20.0), Vec2::splat(48.0));
+/// let uv = Rect::from_min_max(Pos2::ZERO, Pos2::new(1.0, 1.0));
+/// // Dimmed icon, with the ready part swept in at full brightness
+/// ui.painter().image(ability.id(), rect, uv, Color32::from_gray(80));
+/// let mask = ImageMask::Radial { progress: 1.0 - cooldown_left, clockwise: true };
+/// paint_image_masked(ui.painter(), ability.id(), rect, uv, mask, Color32::WHITE);
+/// # }
+/// ```
+pub fn paint_image_masked(painter: &Painter, texture_id: TextureId, rect: Rect, uv: Rect, mask: ImageMask, tint: Color32) {
+    let to_uv = |pos: Pos2| uv.lerp_inside((pos - rect.min) / rect.size());
+    let mut mesh = Mesh::with_texture(texture_id);
+    match mask {
+        ImageMask::Radial { progress, clockwise } => {
+            let progress = progress.clamp(0.0, 1.0);
+            if progress <= 0.0 {
+                return;
+            }
+            let half = rect.size() / 2.0;
+            let end = progress * std::f32::consts::TAU;
+
+            // Sample the sweep, plus the corner angles so the rect's corners aren't cut off.
+            let corner = half.x.atan2(half.y);
+            let (pi, tau) = (std::f32::consts::PI, std::f32::consts::TAU);
+            let corners = [corner, pi - corner, pi + corner, tau - corner];
+            let steps = (progress * 64.0).ceil() as usize;
+            let mut angles: Vec<f32> = (0..=steps).map(|i| end * i as f32 / steps as f32).collect();
+            angles.extend(corners.into_iter().filter(|&a| a < end));
+            angles.sort_by(f32::total_cmp);
+            let sign = if clockwise { 1.0 } else { -1.0 };
+            let center = rect.center();
+            mesh.vertices.push(Vertex { pos: center, uv: to_uv(center), color: tint });
+            for angle in angles {
+                let dir = Vec2::new(sign * angle.sin(), -angle.cos());
+                // Distance along `dir` to the rect's edge.
+                let t = (half.x / dir.x.abs().max(f32::EPSILON)).min(half.y / dir.y.abs().max(f32::EPSILON));
+                let pos = center + dir * t;
+                mesh.vertices.push(Vertex { pos, uv: to_uv(pos), color: tint });
+            }
+            for i in 1..mesh.vertices.len() as u32 - 1 {
+                mesh.add_triangle(0, i, i + 1);
+            }
+        }
+        ImageMask::Linear { progress, direction } => {
+            let progress = progress.clamp(0.0, 1.0);
+            if progress <= 0.0 {
+                return;
+            }
+            let (w, h) = (rect.width() * progress, rect.height() * progress);
+            let shown = match direction {
+                MaskDirection::LeftToRight => Rect::from_min_size(rect.min, Vec2::new(w, rect.height())),
+                MaskDirection::RightToLeft => Rect::from_min_max(Pos2::new(rect.right() - w, rect.top()), rect.max),
+                MaskDirection::TopToBottom => Rect::from_min_size(rect.min, Vec2::new(rect.width(), h)),
+                MaskDirection::BottomToTop => Rect::from_min_max(Pos2::new(rect.left(), rect.bottom() - h), rect.max),
+            };
+            mesh.add_rect_with_uv(shown, Rect::from_min_max(to_uv(shown.min), to_uv(shown.max)), tint);
+        }
+        ImageMask::Dissolve { progress, seed, edge } => {
+            // Noise width of the fade from the edge color to the image.
+            const EDGE: f32 = 0.08;
+            let threshold = progress.clamp(0.0, 1.0) * (1.0 + EDGE);
+            let cell = rect.size() / DISSOLVE_CELLS as f32;
+            for y in 0..DISSOLVE_CELLS {
+                for x in 0..DISSOLVE_CELLS {
+                    let noise = cell_noise(x as u32, y as u32, seed);
+                    if noise < threshold - EDGE {
+                        continue;
+                    }
+                    let color = if noise < threshold {
+                        // Vanishing: fade out, turning into the edge color.
+                        let t = (threshold - noise) / EDGE;
+                        lerp_color(tint, edge, t).gamma_multiply(1.0 - t * t)
+                    } else if noise < threshold + EDGE {
+                        lerp_color(edge, tint, (noise - threshold) / EDGE)
+                    } else {
+                        tint
+                    };
+                    let min = rect.min + Vec2::new(x as f32, y as f32) * cell;
+                    let cell_rect = Rect::from_min_size(min, cell);
+                    mesh.add_rect_with_uv(cell_rect, Rect::from_min_max(to_uv(cell_rect.min), to_uv(cell_rect.max)), color);
+                }
+            }
+        }
+    }
+    painter.add(Shape::mesh(mesh));
+}
+
+/// Noise value in `0.0..1.0` of a dissolve cell.
+fn cell_noise(x: u32, y: u32, seed: u32) -> f32 {
+    let mut h = x.wrapping_mul(0x27d4_eb2d) ^ y.wrapping_mul(0x1656_67b1) ^ seed.wrapping_mul(0x9e37_79b9);
+    h ^= h >> 15;
+    h = h.wrapping_mul(0x85eb_ca6b);
+    h ^= h >> 13;
+    (h >> 8) as f32 / (1u32 << 24) as f32
+}
+
+fn lerp_color(from: Color32, to: Color32, t: f32) -> Color32 {
+    let t = t.clamp(0.0, 1.0);
+    let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+    Color32::from_rgba_premultiplied(mix(from.r(), to.r()), mix(from.g(), to.g()), mix(from.b(), to.b()), mix(from.a(), to.a()))
+}
+
+/// Shows a texture at `size` with only the part selected by `mask` visible (see
+/// [`ImageMask`]), e.g. ability cooldown sweeps or a card burning away.
+///
+/// # Example
+/// ```rust
+/// # use egui::{Color32, TextureHandle, Ui, Vec2};
+/// # use deckbuilder_eng::ui_image::{ui_image_masked, ImageMask};
+/// # fn demo(ui: &mut Ui, card_art: &TextureHandle, burn: f32) {
+/// let mask = ImageMask::Dissolve { progress: burn, seed: 7, edge: Color32::from_rgb(255, 140, 20) };
+/// ui_image_masked(ui, card_art, Vec2::new(140.0, 200.0), mask);
+/// # }
+/// ```
+pub fn ui_image_masked(ui: &mut Ui, texture: &TextureHandle, size: Vec2, mask: ImageMask) -> Response {
+    let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+    if ui.is_rect_visible(rect) {
+        let uv = Rect::from_min_max(Pos2::ZERO, Pos2::new(1.0, 1.0));
+        paint_image_masked(ui.painter(), texture.id(), rect, uv, mask, Color32::WHITE);
+    }
+    response
+}
+
+/// Where a border is drawn relative to the image edge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BorderPlacement {
+    /// Inside the image, covering its outer pixels; the layout size is the image size.
+    #[default]
+    Inside,
+    /// Centered on the image edge.
+    Center,
+    /// Around the image; the layout grows by the border width on each side.
+    Outside,
+}
+
+/// Border drawn by [`ui_image_with_border_ex`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BorderStyle {
+    /// Border width and color.
+    pub stroke: Stroke,
+    /// Corner radius of the image; the border follows it.
+    pub radius: f32,
+    /// Where the border sits relative to the image edge.
+    pub placement: BorderPlacement,
+}
+
+impl BorderStyle {
+    /// Creates a square border inside the image.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::{Color32, Stroke};
+    /// # use deckbuilder_eng::ui_image::{BorderPlacement, BorderStyle};
+    /// let rare = BorderStyle {
+    ///     radius: 6.0,
+    ///     placement: BorderPlacement::Outside,
+    ///     ..BorderStyle::new(Stroke::new(2.0, Color32::GOLD))
+    /// };
+    /// ```
+    pub fn new(stroke: Stroke) -> Self {
+        Self {
+            stroke,
+            radius: 0.0,
+            placement: BorderPlacement::Inside,
+        }
+    }
+}
+
+/// Shows an image with a border around exactly the image, fitted within `size` like
+/// [`ui_image`].
 ///
 /// # Example
 /// ```rust
 /// # use egui::{Ui, Context, Vec2, Color32, Stroke};
 /// # use deckbuilder_eng::ui_image::{load_texture_from_path, ui_image_with_border};
 /// # fn demo(ui: &mut Ui, ctx: &Context) {
-/// if let Some(texture) = load_texture_from_path(ctx, "assets/example.png") {
+/// if let Ok(texture) = load_texture_from_path(ctx, "assets/example.png") {
 ///     ui_image_with_border(ui, &texture, Vec2::new(64.0, 64.0), Stroke::new(2.0, Color32::YELLOW));
 /// }
 /// # }
@@ -272,23 +1793,550 @@ pub fn ui_image_with_border(
     texture: &TextureHandle,
     size: Vec2,
     stroke: Stroke,
-) {
-    ui_image(ui, texture, Some(size));
-    let rect = ui.min_rect();
-    ui.painter().rect_stroke(rect, 0.0, stroke);
+) -> Response {
+    ui_image_with_border_ex(ui, texture, ImageFit::Fit(size), &BorderStyle::new(stroke))
 }
 
-/// Shows a placeholder for async loading (sync fallback).
+/// Shows an image sized by `fit` with a border (see [`BorderStyle`]). The image is clipped to
+/// the border's corner radius. The response covers the image and, for outside borders, the
+/// border.
+///
+/// # Example
+/// ```rust
+/// # use egui::{Color32, Stroke, TextureHandle, Ui, Vec2};
+/// # use deckbuilder_eng::ui_image::{ui_image_with_border_ex, BorderPlacement, BorderStyle, ImageFit};
+/// # fn demo(ui: &mut Ui, portrait: &TextureHandle) {
+/// let style = BorderStyle {
+///     radius: 8.0,
+///     placement: BorderPlacement::Outside,
+///     ..BorderStyle::new(Stroke::new(3.0, Color32::GOLD))
+/// };
+/// ui_image_with_border_ex(ui, portrait, ImageFit::Fill(Vec2::splat(72.0)), &style);
+/// # }
+/// ```
+pub fn ui_image_with_border_ex(ui: &mut Ui, texture: &TextureHandle, fit: ImageFit, style: &BorderStyle) -> Response {
+    let (size, uv) = fit.layout(texture.size_vec2());
+    let width = style.stroke.width;
+    let margin = match style.placement {
+        BorderPlacement::Inside => 0.0,
+        BorderPlacement::Center => width / 2.0,
+        BorderPlacement::Outside => width,
+    };
+    let (rect, response) = ui.allocate_exact_size(size + Vec2::splat(margin * 2.0), Sense::hover());
+    if ui.is_rect_visible(rect) {
+        let image_rect = rect.shrink(margin);
+        paint_image_rounded(ui.painter(), texture.id(), image_rect, uv, style.radius, Color32::WHITE);
+        // Strokes are centered on their rect, so move the rect by half the width.
+        let offset = match style.placement {
+            BorderPlacement::Inside => -width / 2.0,
+            BorderPlacement::Center => 0.0,
+            BorderPlacement::Outside => width / 2.0,
+        };
+        let radius = (style.radius + offset).max(0.0);
+        ui.painter().rect_stroke(image_rect.expand(offset), radius, style.stroke);
+    }
+    response
+}
+
+
+/// What image widgets show in place of a texture that failed to load.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ImageFallback {
+    /// A magenta and black checkerboard, hard to miss during development.
+    #[default]
+    Checkerboard,
+    /// A solid color rectangle.
+    Color(Color32),
+    /// A text label naming the path.
+    Label,
+    /// Empty space of the requested size.
+    Hidden,
+}
+
+/// A texture that failed to load, reported by image widgets and [`load_texture_async`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageLoadWarning {
+    /// Path of the image.
+    pub path: String,
+    /// What went wrong.
+    pub message: String,
+}
+
+impl std::fmt::Display for ImageLoadWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// Warnings not yet taken, and the paths already reported (each path is reported once).
+#[derive(Clone, Default)]
+struct ImageWarnings {
+    pending: Vec<ImageLoadWarning>,
+    reported: std::collections::HashSet<String>,
+}
+
+fn image_fallback_id() -> egui::Id {
+    egui::Id::new("deckbuilder_image_fallback")
+}
+
+fn image_warnings_id() -> egui::Id {
+    egui::Id::new("deckbuilder_image_warnings")
+}
+
+/// Sets what image widgets of this context show for textures that failed to load.
+///
+/// # Example
+/// ```rust
+/// # use egui::{Color32, Context};
+/// # use deckbuilder_eng::ui_image::{image_fallback, set_image_fallback, ImageFallback};
+/// let ctx = Context::default();
+/// // Release builds: a neutral gray box instead of the checkerboard
+/// set_image_fallback(&ctx, ImageFallback::Color(Color32::DARK_GRAY));
+/// assert_eq!(image_fallback(&ctx), ImageFallback::Color(Color32::DARK_GRAY));
+/// ```
+pub fn set_image_fallback(ctx: &Context, fallback: ImageFallback) {
+    ctx.data_mut(|d| d.insert_temp(image_fallback_id(), fallback));
+}
+
+/// Returns the fallback set with [`set_image_fallback`] (the checkerboard by default).
 ///
 /// # Example
 /// ```rust
 /// # use egui::Context;
-/// # use deckbuilder_eng::ui_image::load_texture_async;
+/// # use deckbuilder_eng::ui_image::{image_fallback, ImageFallback};
+/// assert_eq!(image_fallback(&Context::default()), ImageFallback::Checkerboard);
+/// ```
+pub fn image_fallback(ctx: &Context) -> ImageFallback {
+    ctx.data(|d| d.get_temp(image_fallback_id())).unwrap_or_default()
+}
+
+/// Records that `path` failed to load, unless it was already reported. Safe to call from
+/// worker threads.
+pub(crate) fn report_image_warning(ctx: &Context, path: &str, message: &str) {
+    ctx.data_mut(|d| {
+        let warnings = d.get_temp_mut_or_default::<ImageWarnings>(image_warnings_id());
+        if warnings.reported.insert(path.to_string()) {
+            warnings.pending.push(ImageLoadWarning { path: path.to_string(), message: message.to_string() });
+        }
+    });
+}
+
+/// Returns and clears the image load warnings reported since the last call. Each failing
+/// path is reported once per context.
+///
+/// # Example
+/// ```rust
+/// # use egui::Context;
+/// # use deckbuilder_eng::ui_image::take_image_warnings;
 /// # fn demo(ctx: &Context) {
-/// let texture = load_texture_async(ctx, "assets/example.png");
+/// for warning in take_image_warnings(ctx) {
+///     eprintln!("warning: {}", warning);
+/// }
 /// # }
 /// ```
-pub fn load_texture_async(ctx: &Context, path: &str) -> TextureHandle {
-    // Simple sync loading (placeholder logic to be added)
-    load_texture_from_path(ctx, path).unwrap()
+pub fn take_image_warnings(ctx: &Context) -> Vec<ImageLoadWarning> {
+    ctx.data_mut(|d| std::mem::take(&mut d.get_temp_mut_or_default::<ImageWarnings>(image_warnings_id()).pending))
+}
+
+/// Returns the generated "missing texture" checkerboard, created once per context.
+///
+/// # Example
+/// ```rust
+/// # use egui::Context;
+/// # use deckbuilder_eng::ui_image::missing_texture;
+/// let ctx = Context::default();
+/// assert_eq!(missing_texture(&ctx).size(), [64, 64]);
+/// ```
+pub fn missing_texture(ctx: &Context) -> TextureHandle {
+    let id = egui::Id::new("deckbuilder_missing_texture");
+    if let Some(texture) = ctx.data(|d| d.get_temp::<TextureHandle>(id)) {
+        return texture;
+    }
+    const CELL: usize = 8;
+    const SIZE: usize = 64;
+    let magenta = Color32::from_rgb(255, 0, 255);
+    let pixels = (0..SIZE * SIZE)
+        .map(|i| if ((i % SIZE) / CELL + (i / SIZE) / CELL).is_multiple_of(2) { magenta } else { Color32::BLACK })
+        .collect();
+    let image = egui::ColorImage { size: [SIZE, SIZE], pixels };
+    let texture = ctx.load_texture("deckbuilder_missing", image, TextureOptions::NEAREST);
+    ctx.data_mut(|d| d.insert_temp(id, texture.clone()));
+    texture
+}
+
+/// Shows the configured [`ImageFallback`] at `size` for an image that failed to load. Hovering
+/// it shows the path and `error`.
+///
+/// # Example
+/// ```rust
+/// # use egui::{Ui, Vec2};
+/// # use deckbuilder_eng::ui_image::{load_texture_from_path, ui_image, ui_image_fallback};
+/// # fn demo(ui: &mut Ui) {
+/// match load_texture_from_path(ui.ctx(), "assets/cards/strike.png") {
+///     Ok(texture) => ui_image(ui, &texture, Some(Vec2::splat(96.0))),
+///     Err(err) => {
+///         ui_image_fallback(ui, "assets/cards/strike.png", &err.to_string(), Vec2::splat(96.0));
+///     }
+/// }
+/// # }
+/// ```
+pub fn ui_image_fallback(ui: &mut Ui, path: &str, error: &str, size: Vec2) -> Response {
+    let response = match image_fallback(ui.ctx()) {
+        ImageFallback::Label => ui.label(format!("Failed to load image from: {}", path)),
+        fallback => {
+            let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+            if ui.is_rect_visible(rect) {
+                match fallback {
+                    ImageFallback::Checkerboard => {
+                        let texture = missing_texture(ui.ctx());
+                        let uv = Rect::from_min_max(Pos2::ZERO, Pos2::new(1.0, 1.0));
+                        ui.painter().image(texture.id(), rect, uv, Color32::WHITE);
+                    }
+                    ImageFallback::Color(color) => {
+                        ui.painter().rect_filled(rect, 0.0, color);
+                    }
+                    _ => {}
+                }
+            }
+            response
+        }
+    };
+    response.on_hover_text(format!("{}: {}", path, error))
+}
+
+/// What a [`TextureTicket`] shows while its texture is loading or after loading failed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TexturePlaceholder {
+    /// A spinner while loading, the context's [`ImageFallback`] on failure.
+    Spinner,
+    /// A solid color rectangle.
+    Color(Color32),
+}
+
+#[derive(Clone)]
+enum TicketState {
+    Loading,
+    Ready(TextureHandle),
+    Failed(String),
+}
+
+/// Handle to a texture loaded on a worker thread by [`load_texture_async`]. Clones share the
+/// same load.
+#[derive(Clone)]
+pub struct TextureTicket {
+    path: String,
+    state: Arc<Mutex<TicketState>>,
+}
+
+impl TextureTicket {
+    fn state(&self) -> TicketState {
+        self.state.lock().map(|state| state.clone()).unwrap_or(TicketState::Loading)
+    }
+
+    /// Returns the path being loaded.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Context;
+    /// # use deckbuilder_eng::ui_image::load_texture_async;
+    /// let ticket = load_texture_async(&Context::default(), "assets/example.png");
+    /// assert_eq!(ticket.path(), "assets/example.png");
+    /// ```
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns the texture once it has been loaded.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Ui;
+    /// # use deckbuilder_eng::ui_image::{ui_image, TextureTicket};
+    /// # fn demo(ui: &mut Ui, ticket: &TextureTicket) {
+    /// if let Some(texture) = ticket.texture() {
+    ///     ui_image(ui, &texture, None);
+    /// }
+    /// # }
+    /// ```
+    pub fn texture(&self) -> Option<TextureHandle> {
+        match self.state() {
+            TicketState::Ready(texture) => Some(texture),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` while the texture is still loading.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_image::TextureTicket;
+    /// # fn demo(ticket: &TextureTicket) {
+    /// if ticket.is_loading() {
+    ///     println!("Loading {}", ticket.path());
+    /// }
+    /// # }
+    /// ```
+    pub fn is_loading(&self) -> bool {
+        matches!(self.state(), TicketState::Loading)
+    }
+
+    /// Returns `true` once the texture has been loaded.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_image::TextureTicket;
+    /// # fn demo(ticket: &TextureTicket) {
+    /// let ready = ticket.is_ready();
+    /// # }
+    /// ```
+    pub fn is_ready(&self) -> bool {
+        matches!(self.state(), TicketState::Ready(_))
+    }
+
+    /// Returns the error message if loading failed.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_image::TextureTicket;
+    /// # fn demo(ticket: &TextureTicket) {
+    /// if let Some(err) = ticket.error() {
+    ///     eprintln!("{}: {}", ticket.path(), err);
+    /// }
+    /// # }
+    /// ```
+    pub fn error(&self) -> Option<String> {
+        match self.state() {
+            TicketState::Failed(err) => Some(err),
+            _ => None,
+        }
+    }
+
+    /// Shows the texture fitted within `size`, or `placeholder` while it is loading or if
+    /// loading failed. The hover text of a failed load names the path and the error.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::{Ui, Vec2};
+    /// # use deckbuilder_eng::ui_image::{TexturePlaceholder, TextureTicket};
+    /// # fn demo(ui: &mut Ui, ticket: &TextureTicket) {
+    /// ticket.show(ui, Vec2::new(128.0, 128.0), TexturePlaceholder::Spinner);
+    /// # }
+    /// ```
+    pub fn show(&self, ui: &mut Ui, size: Vec2, placeholder: TexturePlaceholder) -> Response {
+        match self.state() {
+            TicketState::Ready(texture) => ui_image_fit(ui, &texture, ImageFit::Fit(size)),
+            TicketState::Loading => {
+                let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+                match placeholder {
+                    TexturePlaceholder::Spinner => {
+                        let spinner_size = size.min_elem().min(32.0);
+                        egui::Spinner::new()
+                            .size(spinner_size)
+                            .paint_at(ui, Rect::from_center_size(rect.center(), Vec2::splat(spinner_size)));
+                    }
+                    TexturePlaceholder::Color(color) => {
+                        ui.painter().rect_filled(rect, 0.0, color);
+                    }
+                }
+                response
+            }
+            TicketState::Failed(err) => match placeholder {
+                TexturePlaceholder::Spinner => ui_image_fallback(ui, &self.path, &err, size),
+                TexturePlaceholder::Color(color) => {
+                    let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+                    ui.painter().rect_filled(rect, 0.0, color);
+                    response.on_hover_text(format!("{}: {}", self.path, err))
+                }
+            },
+        }
+    }
+}
+
+type LoadJob = Box<dyn FnOnce() + Send>;
+
+#[cfg(not(target_arch = "wasm32"))]
+fn texture_workers() -> &'static Mutex<std::sync::mpsc::Sender<LoadJob>> {
+    use std::sync::{mpsc, OnceLock};
+
+    static WORKERS: OnceLock<Mutex<mpsc::Sender<LoadJob>>> = OnceLock::new();
+    WORKERS.get_or_init(|| {
+        let (sender, receiver) = mpsc::channel::<LoadJob>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let count = std::thread::available_parallelism().map_or(2, |n| n.get()).clamp(1, 4);
+        for i in 0..count {
+            let receiver = Arc::clone(&receiver);
+            let _ = std::thread::Builder::new()
+                .name(format!("texture-loader-{}", i))
+                .spawn(move || {
+                    loop {
+                        let job = match receiver.lock() {
+                            Ok(receiver) => receiver.recv(),
+                            Err(_) => return,
+                        };
+                        match job {
+                            Ok(job) => job(),
+                            Err(_) => return,
+                        }
+                    }
+                });
+        }
+        Mutex::new(sender)
+    })
+}
+
+/// Loads an image on a worker thread and returns a [`TextureTicket`] for it. The texture is
+/// uploaded as soon as it is decoded, and a repaint is requested so it shows up right away.
+/// Failures (including a panicking decoder) mark the ticket as failed and report an
+/// [`ImageLoadWarning`].
+///
+/// # Example
+/// ```rust
+/// # use egui::{Ui, Vec2};
+/// # use deckbuilder_eng::ui_image::{load_texture_async, TexturePlaceholder, TextureTicket};
+/// # fn demo(ui: &mut Ui, art: &mut Option<TextureTicket>) {
+/// let ticket = art.get_or_insert_with(|| load_texture_async(ui.ctx(), "assets/example.png"));
+/// ticket.show(ui, Vec2::new(128.0, 128.0), TexturePlaceholder::Spinner);
+/// # }
+/// ```
+pub fn load_texture_async(ctx: &Context, path: &str) -> TextureTicket {
+    load_texture_async_with(ctx, path, &LoadOptions::default())
+}
+
+/// Like [`load_texture_async`], scaling and sampling the image according to `options`.
+///
+/// # Example
+/// ```rust
+/// # use egui::Context;
+/// # use deckbuilder_eng::ui_image::{load_texture_async_with, LoadOptions};
+/// let ticket = load_texture_async_with(&Context::default(), "assets/sprites/map.png", &LoadOptions::pixel_art());
+/// ```
+pub fn load_texture_async_with(ctx: &Context, path: &str, options: &LoadOptions) -> TextureTicket {
+    let options = *options;
+    let ticket = TextureTicket {
+        path: path.to_string(),
+        state: Arc::new(Mutex::new(TicketState::Loading)),
+    };
+    let job_ctx = ctx.clone();
+    let path = ticket.path.clone();
+    let state = Arc::clone(&ticket.state);
+    let job: LoadJob = Box::new(move || {
+        let ctx = job_ctx;
+        let decoded = std::panic::catch_unwind(|| {
+            ImageReader::open(&path)
+                .map_err(image::ImageError::IoError)
+                .and_then(|reader| reader.decode())
+                .map_err(|err| err.to_string())
+        });
+        let next = match decoded {
+            Ok(Ok(img)) => {
+                let color_image = options.prepare(img, ctx.input(|i| i.max_texture_side));
+                TicketState::Ready(ctx.load_texture(&path, color_image, options.texture))
+            }
+            Ok(Err(err)) => TicketState::Failed(err),
+            Err(_) => TicketState::Failed("image decoder panicked".to_string()),
+        };
+        if let TicketState::Failed(err) = &next {
+            report_image_warning(&ctx, &path, err);
+        }
+        if let Ok(mut state) = state.lock() {
+            *state = next;
+        }
+        ctx.request_repaint();
+    });
+    // Browsers have no threads, so the image is decoded right away there.
+    #[cfg(target_arch = "wasm32")]
+    job();
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let sent = texture_workers().lock().is_ok_and(|sender| sender.send(job).is_ok());
+        if !sent && let Ok(mut state) = ticket.state.lock() {
+            let err = "texture loader is not running";
+            report_image_warning(ctx, &ticket.path, err);
+            *state = TicketState::Failed(err.to_string());
+        }
+    }
+    ticket
+}
+
+/// Rasterizes an SVG from memory into a texture of exactly `size` pixels (requires the `svg`
+/// feature). Fails with [`Error::Image`] if the SVG can't be parsed or the size is zero.
+///
+/// # Example
+/// ```rust
+/// # use egui::Context;
+/// # use deckbuilder_eng::ui_image::load_svg_from_bytes;
+/// let ctx = Context::default();
+/// let svg = br#"<svg xmlns="http://www.w3.org/2000/svg" width="10" height="10"><circle cx="5" cy="5" r="5" fill="red"/></svg>"#;
+/// let texture = load_svg_from_bytes(&ctx, "dot", svg, [32, 32]).unwrap();
+/// assert_eq!(texture.size(), [32, 32]);
+/// ```
+#[cfg(feature = "svg")]
+pub fn load_svg_from_bytes(ctx: &Context, name: &str, bytes: &[u8], size: [u32; 2]) -> Result<TextureHandle, Error> {
+    use image::error::{DecodingError, ImageFormatHint, ParameterError, ParameterErrorKind};
+    use image::ImageError;
+    use resvg::{tiny_skia, usvg};
+
+    let tree = usvg::Tree::from_data(bytes, &usvg::Options::default()).map_err(|err| {
+        Error::image(name, ImageError::Decoding(DecodingError::new(ImageFormatHint::Name("SVG".to_owned()), err)))
+    })?;
+    let mut pixmap = tiny_skia::Pixmap::new(size[0], size[1]).ok_or_else(|| {
+        Error::image(name, ImageError::Parameter(ParameterError::from_kind(ParameterErrorKind::DimensionMismatch)))
+    })?;
+    let svg_size = tree.size();
+    let transform = tiny_skia::Transform::from_scale(
+        size[0] as f32 / svg_size.width(),
+        size[1] as f32 / svg_size.height(),
+    );
+    resvg::render(&tree, transform, &mut pixmap.as_mut());
+    let color_image = egui::ColorImage::from_rgba_premultiplied([size[0] as usize, size[1] as usize], pixmap.data());
+    Ok(ctx.load_texture(name, color_image, Default::default()))
+}
+
+/// Rasterizes the SVG file at `path` at `size` pixels (requires the `svg` feature). Textures
+/// are cached per path and size, so asking again for the same size is cheap.
+///
+/// # Example
+/// ```rust
+/// # use egui::Context;
+/// # use deckbuilder_eng::ui_image::load_svg_texture;
+/// # fn demo(ctx: &Context) {
+/// let icon = load_svg_texture(ctx, "assets/icons/energy.svg", [64, 64]);
+/// # }
+/// ```
+#[cfg(feature = "svg")]
+pub fn load_svg_texture(ctx: &Context, path: &str, size: [u32; 2]) -> Result<TextureHandle, Error> {
+    let id = egui::Id::new(("deckbuilder_svg", path, size));
+    if let Some(texture) = ctx.data(|d| d.get_temp::<TextureHandle>(id)) {
+        return Ok(texture);
+    }
+    let bytes = std::fs::read(path).map_err(|err| Error::io(path, err))?;
+    let texture = load_svg_from_bytes(ctx, path, &bytes, size)?;
+    ctx.data_mut(|d| d.insert_temp(id, texture.clone()));
+    Ok(texture)
+}
+
+/// Shows the SVG file at `path` at `size` points, rasterized for the current pixels per point
+/// so it stays crisp at any UI scale (requires the `svg` feature). Shows the configured
+/// [`ImageFallback`] and reports an [`ImageLoadWarning`] if the file can't be loaded.
+///
+/// # Example
+/// ```rust
+/// # use egui::{Ui, Vec2};
+/// # use deckbuilder_eng::ui_image::ui_svg;
+/// # fn demo(ui: &mut Ui) {
+/// ui_svg(ui, "assets/icons/energy.svg", Vec2::splat(24.0));
+/// # }
+/// ```
+#[cfg(feature = "svg")]
+pub fn ui_svg(ui: &mut Ui, path: &str, size: Vec2) -> Response {
+    let pixels = (size * ui.ctx().pixels_per_point()).round();
+    let pixel_size = [pixels.x.max(1.0) as u32, pixels.y.max(1.0) as u32];
+    match load_svg_texture(ui.ctx(), path, pixel_size) {
+        Ok(texture) => ui.add(Image::new((texture.id(), size))),
+        Err(err) => {
+            let err = err.to_string();
+            report_image_warning(ui.ctx(), path, &err);
+            ui_image_fallback(ui, path, &err, size)
+        }
+    }
 }
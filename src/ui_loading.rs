@@ -0,0 +1,365 @@
+//! Loading screen for egui-based deckbuilder UIs.
+//!
+//! Provides a [`LoadProgress`] handle that asset loaders (textures, audio, card database)
+//! report into, and a [`LoadingScreen`] that shows the combined progress with rotating tips and
+//! switches to the next [`Screen`] once everything is ready.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::ui_loading::{LoadProgress, LoadingScreen};
+//! use deckbuilder_eng::ui_screen::{Screen, ScreenAction, ScreenStack};
+//! use egui::Ui;
+//!
+//! struct MainMenu;
+//! impl Screen for MainMenu {
+//!     fn update(&mut self, ui: &mut Ui) -> ScreenAction {
+//!         ui.heading("Deckbuilder");
+//!         ScreenAction::None
+//!     }
+//! }
+//!
+//! let progress = LoadProgress::new();
+//! progress.register("Music", 2.0);
+//!
+//! // A loader thread reports into its own clone of the handle.
+//! let music = progress.clone();
+//! std::thread::spawn(move || {
+//!     music.set("Music", 0.5);
+//!     music.finish("Music");
+//! });
+//!
+//! let mut cards_loaded = 0;
+//! let loading = LoadingScreen::new(progress, || Box::new(MainMenu))
+//!     .tip("Block is removed at the start of your turn.")
+//!     .tip("Elites drop relics.")
+//!     .step("Card database", move || {
+//!         // Load one chunk per frame and report the fraction done.
+//!         cards_loaded += 50;
+//!         cards_loaded as f32 / 500.0
+//!     });
+//!
+//! let mut screens = ScreenStack::new();
+//! screens.push(Box::new(loading));
+//! ```
+//!
+//! # Details
+//!
+//! - Progress is the weighted average of all registered tasks; give slow loads a larger weight.
+//! - Steps run on the UI thread once per frame until they report `1.0` or more.
+//! - The screen stays up for at least `min_duration` seconds, so tips can be read.
+//! - See each struct and function's documentation for more.
+
+use std::sync::{Arc, Mutex};
+
+use egui::{Align, Layout, ProgressBar, Ui};
+
+use crate::ui_anim::{animate_value, Easing};
+use crate::ui_screen::{Screen, ScreenAction};
+
+#[derive(Debug, Clone)]
+struct LoadTask {
+    name: String,
+    weight: f32,
+    progress: f32,
+}
+
+/// Shared, thread-safe progress of a set of named load tasks. Clones share the same state.
+#[derive(Debug, Clone, Default)]
+pub struct LoadProgress {
+    tasks: Arc<Mutex<Vec<LoadTask>>>,
+}
+
+impl LoadProgress {
+    /// Creates an empty progress tracker.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_loading::LoadProgress;
+    /// let progress = LoadProgress::new();
+    /// assert!(progress.is_ready());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a task with the given weight. Registering an existing name resets its progress.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_loading::LoadProgress;
+    /// let progress = LoadProgress::new();
+    /// progress.register("Textures", 3.0);
+    /// assert!(!progress.is_ready());
+    /// ```
+    pub fn register(&self, name: &str, weight: f32) {
+        let mut tasks = self.tasks.lock().unwrap();
+        tasks.retain(|t| t.name != name);
+        tasks.push(LoadTask {
+            name: name.to_owned(),
+            weight: weight.max(0.0),
+            progress: 0.0,
+        });
+    }
+
+    /// Sets the progress (0..1) of a task. Unknown tasks are registered with weight 1.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_loading::LoadProgress;
+    /// let progress = LoadProgress::new();
+    /// progress.set("Audio", 0.5);
+    /// assert_eq!(progress.fraction(), 0.5);
+    /// ```
+    pub fn set(&self, name: &str, progress: f32) {
+        let mut tasks = self.tasks.lock().unwrap();
+        let progress = progress.clamp(0.0, 1.0);
+        match tasks.iter_mut().find(|t| t.name == name) {
+            Some(task) => task.progress = progress,
+            None => tasks.push(LoadTask {
+                name: name.to_owned(),
+                weight: 1.0,
+                progress,
+            }),
+        }
+    }
+
+    /// Marks a task as done.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_loading::LoadProgress;
+    /// let progress = LoadProgress::new();
+    /// progress.register("Fonts", 1.0);
+    /// progress.finish("Fonts");
+    /// assert!(progress.is_ready());
+    /// ```
+    pub fn finish(&self, name: &str) {
+        self.set(name, 1.0);
+    }
+
+    /// Weighted progress of all tasks (1.0 when there are none).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_loading::LoadProgress;
+    /// let progress = LoadProgress::new();
+    /// progress.register("Cards", 1.0);
+    /// progress.register("Music", 3.0);
+    /// progress.finish("Music");
+    /// assert_eq!(progress.fraction(), 0.75);
+    /// ```
+    pub fn fraction(&self) -> f32 {
+        let tasks = self.tasks.lock().unwrap();
+        let total: f32 = tasks.iter().map(|t| t.weight).sum();
+        if total <= 0.0 {
+            return 1.0;
+        }
+        tasks.iter().map(|t| t.weight * t.progress).sum::<f32>() / total
+    }
+
+    /// Returns `true` when every task is done.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_loading::LoadProgress;
+    /// let progress = LoadProgress::new();
+    /// progress.set("Cards", 0.9);
+    /// assert!(!progress.is_ready());
+    /// ```
+    pub fn is_ready(&self) -> bool {
+        self.tasks.lock().unwrap().iter().all(|t| t.progress >= 1.0)
+    }
+
+    /// Name of the first unfinished task.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_loading::LoadProgress;
+    /// let progress = LoadProgress::new();
+    /// progress.register("Relics", 1.0);
+    /// assert_eq!(progress.current_task().as_deref(), Some("Relics"));
+    /// ```
+    pub fn current_task(&self) -> Option<String> {
+        self.tasks.lock().unwrap().iter().find(|t| t.progress < 1.0).map(|t| t.name.clone())
+    }
+
+    fn task_progress(&self, name: &str) -> Option<f32> {
+        self.tasks.lock().unwrap().iter().find(|t| t.name == name).map(|t| t.progress)
+    }
+}
+
+type Step = (String, Box<dyn FnMut() -> f32>);
+
+/// A [`Screen`] showing load progress and tips, which replaces itself with the next screen
+/// once loading is done.
+pub struct LoadingScreen {
+    progress: LoadProgress,
+    steps: Vec<Step>,
+    next: Option<Box<dyn FnOnce() -> Box<dyn Screen>>>,
+    title: String,
+    tips: Vec<String>,
+    tip_interval: f32,
+    min_duration: f32,
+    started: Option<f64>,
+}
+
+impl LoadingScreen {
+    /// Creates a loading screen tracking `progress` that builds the next screen with `next`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_loading::{LoadProgress, LoadingScreen};
+    /// # use deckbuilder_eng::ui_screen::Screen;
+    /// # fn main_menu() -> Box<dyn Screen> { unimplemented!() }
+    /// let loading = LoadingScreen::new(LoadProgress::new(), main_menu);
+    /// ```
+    pub fn new(progress: LoadProgress, next: impl FnOnce() -> Box<dyn Screen> + 'static) -> Self {
+        Self {
+            progress,
+            steps: Vec::new(),
+            next: Some(Box::new(next)),
+            title: "Loading".to_owned(),
+            tips: Vec::new(),
+            tip_interval: 4.0,
+            min_duration: 0.5,
+            started: None,
+        }
+    }
+
+    /// Sets the heading.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_loading::{LoadProgress, LoadingScreen};
+    /// # use deckbuilder_eng::ui_screen::Screen;
+    /// # fn main_menu() -> Box<dyn Screen> { unimplemented!() }
+    /// let loading = LoadingScreen::new(LoadProgress::new(), main_menu).title("Entering the Spire");
+    /// ```
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Adds a tip to the rotation.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_loading::{LoadProgress, LoadingScreen};
+    /// # use deckbuilder_eng::ui_screen::Screen;
+    /// # fn main_menu() -> Box<dyn Screen> { unimplemented!() }
+    /// let loading = LoadingScreen::new(LoadProgress::new(), main_menu).tip("Exhausted cards are gone for the combat.");
+    /// ```
+    pub fn tip(mut self, tip: impl Into<String>) -> Self {
+        self.tips.push(tip.into());
+        self
+    }
+
+    /// Seconds each tip is shown (default 4).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_loading::{LoadProgress, LoadingScreen};
+    /// # use deckbuilder_eng::ui_screen::Screen;
+    /// # fn main_menu() -> Box<dyn Screen> { unimplemented!() }
+    /// let loading = LoadingScreen::new(LoadProgress::new(), main_menu).tip_interval(6.0);
+    /// ```
+    pub fn tip_interval(mut self, seconds: f32) -> Self {
+        self.tip_interval = seconds.max(0.1);
+        self
+    }
+
+    /// Minimum time in seconds the screen is shown (default 0.5).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_loading::{LoadProgress, LoadingScreen};
+    /// # use deckbuilder_eng::ui_screen::Screen;
+    /// # fn main_menu() -> Box<dyn Screen> { unimplemented!() }
+    /// let loading = LoadingScreen::new(LoadProgress::new(), main_menu).min_duration(2.0);
+    /// ```
+    pub fn min_duration(mut self, seconds: f32) -> Self {
+        self.min_duration = seconds.max(0.0);
+        self
+    }
+
+    /// Adds a task that runs on the UI thread once per frame and returns its progress (0..1),
+    /// until it reaches 1.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_loading::{LoadProgress, LoadingScreen};
+    /// # use deckbuilder_eng::ui_screen::Screen;
+    /// # fn main_menu() -> Box<dyn Screen> { unimplemented!() }
+    /// let mut frames = 0;
+    /// let loading = LoadingScreen::new(LoadProgress::new(), main_menu).step("Warm-up", move || {
+    ///     frames += 1;
+    ///     frames as f32 / 10.0
+    /// });
+    /// ```
+    pub fn step(mut self, name: impl Into<String>, step: impl FnMut() -> f32 + 'static) -> Self {
+        let name = name.into();
+        self.progress.register(&name, 1.0);
+        self.steps.push((name, Box::new(step)));
+        self
+    }
+
+    /// The progress handle loaders report into.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_loading::{LoadProgress, LoadingScreen};
+    /// # use deckbuilder_eng::ui_screen::Screen;
+    /// # fn main_menu() -> Box<dyn Screen> { unimplemented!() }
+    /// let loading = LoadingScreen::new(LoadProgress::new(), main_menu);
+    /// let handle = loading.progress().clone();
+    /// ```
+    pub fn progress(&self) -> &LoadProgress {
+        &self.progress
+    }
+}
+
+impl Screen for LoadingScreen {
+    fn update(&mut self, ui: &mut Ui) -> ScreenAction {
+        let now = ui.input(|i| i.time);
+        let started = *self.started.get_or_insert(now);
+        let elapsed = (now - started) as f32;
+
+        for (name, step) in &mut self.steps {
+            if self.progress.task_progress(name).is_some_and(|p| p < 1.0) {
+                let value = step();
+                self.progress.set(name, value);
+            }
+        }
+
+        let fraction = self.progress.fraction();
+        let shown = animate_value(ui.ctx(), ui.id().with("loading_bar"), fraction, 0.3, Easing::EaseOut);
+        ui.with_layout(Layout::top_down(Align::Center), |ui| {
+            ui.add_space((ui.available_height() * 0.35).max(0.0));
+            ui.heading(&self.title);
+            ui.add_space(12.0);
+            ui.add(
+                ProgressBar::new(shown)
+                    .desired_width((ui.available_width() * 0.6).min(480.0))
+                    .show_percentage(),
+            );
+            if let Some(task) = self.progress.current_task() {
+                ui.weak(task);
+            }
+            if !self.tips.is_empty() {
+                ui.add_space(24.0);
+                let index = (elapsed / self.tip_interval) as usize % self.tips.len();
+                ui.label(&self.tips[index]);
+            }
+        });
+        ui.ctx().request_repaint();
+
+        if self.progress.is_ready()
+            && elapsed >= self.min_duration
+            && let Some(next) = self.next.take()
+        {
+            return ScreenAction::Replace(next());
+        }
+        ScreenAction::None
+    }
+}
@@ -0,0 +1,233 @@
+//! Run map widget for egui-based deckbuilder UIs.
+//!
+//! Provides [`ui_run_map`], which draws a [`RunMap`] as a branching node graph with icons per
+//! node type, connecting paths, the current position and highlighted reachable nodes.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::map::{NodeType, RunMap};
+//! use deckbuilder_eng::ui_map::{ui_run_map, MapViewState};
+//! use egui::{CentralPanel, Context};
+//!
+//! fn my_map(ctx: &Context, map: &mut RunMap, view: &mut MapViewState) {
+//!     CentralPanel::default().show(ctx, |ui| {
+//!         let res = ui_run_map(ui, map, view);
+//!         if let Some(node) = res.clicked {
+//!             if map.travel_to(node) {
+//!                 println!("Entering {:?}", map.nodes[node].node_type);
+//!             }
+//!         }
+//!     });
+//! }
+//! ```
+//!
+//! # Details
+//!
+//! - Drag to pan, scroll to move vertically, and pinch or ctrl+scroll to zoom.
+//! - Row 0 is drawn at the bottom; the map grows upwards like a tower.
+//! - Only reachable nodes report clicks.
+//! - See each function's documentation for usage and customization options.
+
+use egui::{Align2, Color32, FontId, Pos2, Rect, Response, Sense, Shape, Stroke, Ui, Vec2};
+
+use crate::map::{NodeId, NodeType, RunMap};
+
+/// Pan/zoom state of a [`ui_run_map`]. Keep it between frames.
+#[derive(Debug, Clone, Copy)]
+pub struct MapViewState {
+    /// Pan offset in points.
+    pub offset: Vec2,
+    /// Zoom factor.
+    pub zoom: f32,
+    /// Vertical distance between rows at zoom 1.
+    pub row_spacing: f32,
+    /// Radius of node circles at zoom 1.
+    pub node_radius: f32,
+}
+
+impl Default for MapViewState {
+    fn default() -> Self {
+        Self {
+            offset: Vec2::ZERO,
+            zoom: 1.0,
+            row_spacing: 80.0,
+            node_radius: 18.0,
+        }
+    }
+}
+
+/// Result of a [`ui_run_map`] frame.
+pub struct MapResponse {
+    /// Response of the whole map area.
+    pub response: Response,
+    /// Node under the pointer.
+    pub hovered: Option<NodeId>,
+    /// Reachable node clicked this frame.
+    pub clicked: Option<NodeId>,
+}
+
+/// Returns the icon glyph and color used for a node type.
+///
+/// # Example
+/// ```rust
+/// # use deckbuilder_eng::map::NodeType;
+/// # use deckbuilder_eng::ui_map::node_icon;
+/// let (glyph, color) = node_icon(NodeType::Rest);
+/// ```
+pub fn node_icon(node_type: NodeType) -> (&'static str, Color32) {
+    match node_type {
+        NodeType::Combat => ("⚔", Color32::from_rgb(200, 200, 200)),
+        NodeType::Elite => ("☠", Color32::from_rgb(230, 90, 70)),
+        NodeType::Rest => ("🔥", Color32::from_rgb(240, 150, 60)),
+        NodeType::Shop => ("💰", Color32::from_rgb(240, 210, 80)),
+        NodeType::Event => ("?", Color32::from_rgb(120, 190, 240)),
+        NodeType::Treasure => ("🎁", Color32::from_rgb(220, 180, 90)),
+        NodeType::Boss => ("👑", Color32::from_rgb(200, 60, 200)),
+    }
+}
+
+/// Branching run map with pan/zoom. Reports hovered and clicked nodes; only reachable nodes
+/// can be clicked.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::map::RunMap;
+/// # use deckbuilder_eng::ui_map::{ui_run_map, MapViewState};
+/// # fn demo(ui: &mut Ui, map: &RunMap, view: &mut MapViewState) {
+/// let res = ui_run_map(ui, map, view);
+/// if let Some(node) = res.hovered {
+///     res.response.on_hover_text(format!("{:?}", map.nodes[node].node_type));
+/// }
+/// # }
+/// ```
+pub fn ui_run_map(ui: &mut Ui, map: &RunMap, view: &mut MapViewState) -> MapResponse {
+    let (area, response) = ui.allocate_exact_size(ui.available_size(), Sense::click_and_drag());
+
+    // Pan and zoom
+    if response.dragged() {
+        view.offset += response.drag_delta();
+    }
+    if response.hovered() {
+        let (scroll, zoom) = ui.input(|i| (i.smooth_scroll_delta, i.zoom_delta()));
+        view.offset += scroll;
+        if zoom != 1.0 {
+            view.zoom = (view.zoom * zoom).clamp(0.4, 3.0);
+        }
+    }
+
+    let zoom = view.zoom;
+    let radius = view.node_radius * zoom;
+    let margin = radius * 2.0;
+    let width = (area.width() - 2.0 * margin).max(0.0);
+    let node_pos = |row: usize, column: f32| {
+        Pos2::new(
+            area.center().x + (column - 0.5) * width * zoom,
+            area.max.y - margin - row as f32 * view.row_spacing * zoom,
+        ) + view.offset
+    };
+
+    let reachable = map.reachable();
+    let hovered = response.hover_pos().and_then(|p| {
+        map.nodes.iter().find(|n| node_pos(n.row, n.column).distance(p) <= radius).map(|n| n.id)
+    });
+    let clicked = if response.clicked() {
+        hovered.filter(|id| reachable.contains(id))
+    } else {
+        None
+    };
+
+    if ui.is_rect_visible(area) {
+        let painter = ui.painter_at(area);
+        let visuals = ui.visuals();
+        let time = ui.input(|i| i.time) as f32;
+
+        // Paths
+        for node in &map.nodes {
+            let from = node_pos(node.row, node.column);
+            for &next in &node.next {
+                let Some(target) = map.nodes.get(next) else { continue };
+                let to = node_pos(target.row, target.column);
+                let dir = (to - from).normalized();
+                let taken = node.visited && target.visited;
+                let active = map.current == Some(node.id) && reachable.contains(&next);
+                let color = if taken || active {
+                    visuals.strong_text_color()
+                } else {
+                    visuals.weak_text_color()
+                };
+                painter.extend(Shape::dashed_line(
+                    &[from + dir * radius, to - dir * radius],
+                    Stroke::new(2.0 * zoom, color),
+                    6.0 * zoom,
+                    5.0 * zoom,
+                ));
+            }
+        }
+
+        // Nodes
+        let pulse = 0.5 + 0.5 * (time * 4.0).sin();
+        for node in &map.nodes {
+            let pos = node_pos(node.row, node.column);
+            let (glyph, color) = node_icon(node.node_type);
+            let is_reachable = reachable.contains(&node.id);
+            let size = if node.node_type == NodeType::Boss { radius * 1.5 } else { radius };
+            let grow = if hovered == Some(node.id) && is_reachable { 1.15 } else { 1.0 };
+
+            let fill = if node.visited {
+                visuals.faint_bg_color
+            } else {
+                visuals.extreme_bg_color
+            };
+            painter.circle_filled(pos, size * grow, fill);
+            painter.circle_stroke(pos, size * grow, Stroke::new(1.5 * zoom, color.gamma_multiply(0.7)));
+            if is_reachable {
+                painter.circle_stroke(
+                    pos,
+                    size * grow + 4.0 * zoom,
+                    Stroke::new(2.5 * zoom, Color32::GOLD.gamma_multiply(0.4 + 0.6 * pulse)),
+                );
+            }
+            if map.current == Some(node.id) {
+                painter.circle_stroke(pos, size + 7.0 * zoom, Stroke::new(3.0 * zoom, visuals.strong_text_color()));
+            }
+            let glyph_color = if node.visited && map.current != Some(node.id) {
+                color.gamma_multiply(0.5)
+            } else {
+                color
+            };
+            painter.text(pos, Align2::CENTER_CENTER, glyph, FontId::proportional(size * 1.1), glyph_color);
+        }
+        if !reachable.is_empty() {
+            ui.ctx().request_repaint();
+        }
+    }
+
+    MapResponse { response, hovered, clicked }
+}
+
+/// Returns the screen rectangle the whole map occupies for `area` and `view`, useful to
+/// clamp panning or center the view.
+///
+/// # Example
+/// ```rust
+/// # use egui::{Rect, Pos2, Vec2};
+/// # use deckbuilder_eng::map::{NodeType, RunMap};
+/// # use deckbuilder_eng::ui_map::{map_bounds, MapViewState};
+/// let mut map = RunMap::new();
+/// map.add_node(NodeType::Boss, 10, 0.5);
+/// let area = Rect::from_min_size(Pos2::ZERO, Vec2::new(400.0, 600.0));
+/// let bounds = map_bounds(&map, &MapViewState::default(), area);
+/// assert!(bounds.height() > 600.0);
+/// ```
+pub fn map_bounds(map: &RunMap, view: &MapViewState, area: Rect) -> Rect {
+    let zoom = view.zoom;
+    let margin = view.node_radius * zoom * 2.0;
+    let height = map.rows().saturating_sub(1) as f32 * view.row_spacing * zoom;
+    let bottom = area.max.y - margin + view.offset.y;
+    Rect::from_min_max(
+        Pos2::new(area.min.x + view.offset.x, bottom - height - margin),
+        Pos2::new(area.max.x + view.offset.x, bottom + margin),
+    )
+}
@@ -0,0 +1,220 @@
+//! Context menu helpers for egui-based deckbuilder UIs.
+//!
+//! Provides [`ui_context_menu`], which opens a right-click menu on any widget from a
+//! [`ContextMenu`] description with typed items, separators and nested submenus, and returns
+//! the action the player picked.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::card::Card;
+//! use deckbuilder_eng::ui_card::{ui_card, CardVisuals};
+//! use deckbuilder_eng::ui_menu::ui_context_menu;
+//! use egui::Ui;
+//!
+//! #[derive(Clone, Copy)]
+//! enum CardAction {
+//!     Inspect,
+//!     Upgrade,
+//!     Remove,
+//!     MoveToTop,
+//!     MoveToBottom,
+//! }
+//!
+//! fn deck_card(ui: &mut Ui, card: &Card) {
+//!     let response = ui_card(ui, card, &CardVisuals::default()).response;
+//!     let action = ui_context_menu(&response, |menu| {
+//!         menu.item("Inspect", CardAction::Inspect)
+//!             .item_enabled("Upgrade", CardAction::Upgrade, !card.name.ends_with('+'))
+//!             .separator()
+//!             .submenu("Move", |menu| {
+//!                 menu.item("To top", CardAction::MoveToTop).item("To bottom", CardAction::MoveToBottom);
+//!             })
+//!             .item("Remove", CardAction::Remove);
+//!     });
+//!     if let Some(CardAction::Remove) = action {
+//!         println!("Remove {}", card.name);
+//!     }
+//! }
+//! ```
+//!
+//! # Details
+//!
+//! - The menu is described anew each frame, but the closure only runs while the menu is open.
+//! - Picking an item closes the whole menu, including open submenus.
+//! - See each function's documentation for usage and customization options.
+
+use egui::{Button, Response, Ui};
+
+enum MenuEntry<A> {
+    Item { label: String, action: A, enabled: bool },
+    Separator,
+    Submenu { label: String, menu: ContextMenu<A> },
+}
+
+/// Description of a context menu whose items carry actions of type `A`.
+pub struct ContextMenu<A> {
+    entries: Vec<MenuEntry<A>>,
+}
+
+impl<A> Default for ContextMenu<A> {
+    fn default() -> Self {
+        Self { entries: Vec::new() }
+    }
+}
+
+impl<A> ContextMenu<A> {
+    /// Creates an empty menu.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_menu::ContextMenu;
+    /// let menu = ContextMenu::<u32>::new();
+    /// assert!(menu.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an item that yields `action` when clicked.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_menu::ContextMenu;
+    /// let mut menu = ContextMenu::new();
+    /// menu.item("Inspect", 1).item("Remove", 2);
+    /// ```
+    pub fn item(&mut self, label: impl Into<String>, action: A) -> &mut Self {
+        self.item_enabled(label, action, true)
+    }
+
+    /// Adds an item that is grayed out unless `enabled`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_menu::ContextMenu;
+    /// let upgraded = true;
+    /// let mut menu = ContextMenu::new();
+    /// menu.item_enabled("Upgrade", "upgrade", !upgraded);
+    /// ```
+    pub fn item_enabled(&mut self, label: impl Into<String>, action: A, enabled: bool) -> &mut Self {
+        self.entries.push(MenuEntry::Item {
+            label: label.into(),
+            action,
+            enabled,
+        });
+        self
+    }
+
+    /// Adds a separator line.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_menu::ContextMenu;
+    /// let mut menu = ContextMenu::new();
+    /// menu.item("Inspect", 1).separator().item("Remove", 2);
+    /// ```
+    pub fn separator(&mut self) -> &mut Self {
+        self.entries.push(MenuEntry::Separator);
+        self
+    }
+
+    /// Adds a nested submenu filled by `build`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_menu::ContextMenu;
+    /// let mut menu = ContextMenu::new();
+    /// menu.submenu("Move", |menu| {
+    ///     menu.item("To top", "top").item("To bottom", "bottom");
+    /// });
+    /// ```
+    pub fn submenu(&mut self, label: impl Into<String>, build: impl FnOnce(&mut ContextMenu<A>)) -> &mut Self {
+        let mut menu = ContextMenu::new();
+        build(&mut menu);
+        self.entries.push(MenuEntry::Submenu {
+            label: label.into(),
+            menu,
+        });
+        self
+    }
+
+    /// Returns `true` if the menu has no entries.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_menu::ContextMenu;
+    /// let mut menu = ContextMenu::new();
+    /// menu.item("Inspect", ());
+    /// assert!(!menu.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Draws the entries into an open menu `ui`. Returns the action of the clicked item.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Ui;
+    /// # use deckbuilder_eng::ui_menu::ContextMenu;
+    /// # fn demo(ui: &mut Ui) {
+    /// ui.menu_button("Game", |ui| {
+    ///     let mut menu = ContextMenu::new();
+    ///     menu.item("Save", "save").item("Quit", "quit");
+    ///     if let Some(action) = menu.show(ui) {
+    ///         println!("{}", action);
+    ///     }
+    /// });
+    /// # }
+    /// ```
+    pub fn show(self, ui: &mut Ui) -> Option<A> {
+        let mut chosen = None;
+        for entry in self.entries {
+            match entry {
+                MenuEntry::Item { label, action, enabled } => {
+                    if ui.add_enabled(enabled, Button::new(label)).clicked() {
+                        chosen = Some(action);
+                        ui.close_menu();
+                    }
+                }
+                MenuEntry::Separator => {
+                    ui.separator();
+                }
+                MenuEntry::Submenu { label, menu } => {
+                    if let Some(action) = ui.menu_button(label, |ui| menu.show(ui)).inner.flatten() {
+                        chosen = Some(action);
+                    }
+                }
+            }
+        }
+        chosen
+    }
+}
+
+/// Opens a context menu when `response` is right-clicked. The menu is built by `build` and
+/// the action of the clicked item is returned.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui_menu::ui_context_menu;
+/// # fn demo(ui: &mut Ui) {
+/// let response = ui.label("Strike");
+/// match ui_context_menu(&response, |menu| {
+///     menu.item("Inspect", "inspect").item("Remove", "remove");
+/// }) {
+///     Some("remove") => println!("Removed"),
+///     _ => {}
+/// }
+/// # }
+/// ```
+pub fn ui_context_menu<A>(response: &Response, build: impl FnOnce(&mut ContextMenu<A>)) -> Option<A> {
+    let mut chosen = None;
+    response.context_menu(|ui| {
+        let mut menu = ContextMenu::new();
+        build(&mut menu);
+        chosen = menu.show(ui);
+    });
+    chosen
+}
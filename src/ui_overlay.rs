@@ -0,0 +1,377 @@
+//! Overlay helpers for egui-based deckbuilder UIs.
+//!
+//! Provides a [`Toasts`] notification queue ("Card added to deck") rendered in a screen corner,
+//! and modal dialogs that dim and block the rest of the UI.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::ui_overlay::{ui_confirm_dialog, Toasts};
+//! use egui::{Align2, CentralPanel, Context};
+//!
+//! fn my_ui(ctx: &Context, toasts: &mut Toasts) {
+//!     CentralPanel::default().show(ctx, |ui| {
+//!         if ui.button("Take card").clicked() {
+//!             toasts.push_success("Card added to deck");
+//!         }
+//!     });
+//!     toasts.show(ctx);
+//! }
+//!
+//! let toasts = Toasts::new().anchor(Align2::RIGHT_TOP).max_stack(3);
+//!
+//! fn abandon_flow(ctx: &Context, asking: &mut bool) {
+//!     if *asking {
+//!         if let Some(yes) = ui_confirm_dialog(ctx, "Abandon run?", "All progress will be lost.") {
+//!             *asking = false;
+//!             if yes {
+//!                 println!("Run abandoned");
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! # Details
+//!
+//! - Overlays use egui `Area`s on the foreground layer.
+//! - Toasts dismiss themselves after their duration, or when clicked.
+//! - Modals swallow all pointer input outside their window while shown.
+//! - See each function's documentation for usage and customization options.
+
+use std::collections::VecDeque;
+
+use egui::{
+    Align2, Area, Color32, Context, Frame, Id, Key, LayerId, Order, RichText, Sense, Ui, Vec2,
+};
+
+use crate::ui_capture::is_photo_mode;
+
+/// Category of a toast, which decides its color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastKind {
+    /// Neutral information.
+    Info,
+    /// Something good happened.
+    Success,
+    /// Something failed.
+    Error,
+}
+
+impl ToastKind {
+    fn color(self) -> Color32 {
+        match self {
+            ToastKind::Info => Color32::from_rgb(60, 90, 140),
+            ToastKind::Success => Color32::from_rgb(50, 130, 70),
+            ToastKind::Error => Color32::from_rgb(160, 50, 50),
+        }
+    }
+}
+
+/// A single notification.
+#[derive(Clone)]
+pub struct Toast {
+    /// Category of the toast.
+    pub kind: ToastKind,
+    /// Message text.
+    pub text: String,
+    /// Seconds left before the toast disappears.
+    pub remaining: f32,
+}
+
+/// Queue of auto-dismissing notifications anchored to a screen corner.
+pub struct Toasts {
+    toasts: VecDeque<Toast>,
+    anchor: Align2,
+    max_stack: usize,
+    duration: f32,
+}
+
+impl Default for Toasts {
+    fn default() -> Self {
+        Self {
+            toasts: VecDeque::new(),
+            anchor: Align2::RIGHT_BOTTOM,
+            max_stack: 5,
+            duration: 3.0,
+        }
+    }
+}
+
+impl Toasts {
+    /// Creates an empty toast queue anchored bottom-right.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_overlay::Toasts;
+    /// let toasts = Toasts::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the screen corner the toasts stack from.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Align2;
+    /// # use deckbuilder_eng::ui_overlay::Toasts;
+    /// let toasts = Toasts::new().anchor(Align2::LEFT_TOP);
+    /// ```
+    pub fn anchor(mut self, anchor: Align2) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    /// Sets the maximum number of visible toasts; older ones are dropped first.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_overlay::Toasts;
+    /// let toasts = Toasts::new().max_stack(3);
+    /// ```
+    pub fn max_stack(mut self, max_stack: usize) -> Self {
+        self.max_stack = max_stack.max(1);
+        self
+    }
+
+    /// Sets how many seconds new toasts stay visible.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_overlay::Toasts;
+    /// let toasts = Toasts::new().duration(5.0);
+    /// ```
+    pub fn duration(mut self, seconds: f32) -> Self {
+        self.duration = seconds;
+        self
+    }
+
+    /// Pushes a toast of the given kind.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_overlay::{Toasts, ToastKind};
+    /// let mut toasts = Toasts::new();
+    /// toasts.push(ToastKind::Info, "Turn 2");
+    /// ```
+    pub fn push(&mut self, kind: ToastKind, text: impl Into<String>) {
+        self.toasts.push_back(Toast {
+            kind,
+            text: text.into(),
+            remaining: self.duration,
+        });
+        while self.toasts.len() > self.max_stack {
+            self.toasts.pop_front();
+        }
+    }
+
+    /// Pushes an info toast.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_overlay::Toasts;
+    /// let mut toasts = Toasts::new();
+    /// toasts.push_info("Enemy is preparing an attack");
+    /// ```
+    pub fn push_info(&mut self, text: impl Into<String>) {
+        self.push(ToastKind::Info, text);
+    }
+
+    /// Pushes a success toast.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_overlay::Toasts;
+    /// let mut toasts = Toasts::new();
+    /// toasts.push_success("Card added to deck");
+    /// ```
+    pub fn push_success(&mut self, text: impl Into<String>) {
+        self.push(ToastKind::Success, text);
+    }
+
+    /// Pushes an error toast.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_overlay::Toasts;
+    /// let mut toasts = Toasts::new();
+    /// toasts.push_error("Not enough energy");
+    /// ```
+    pub fn push_error(&mut self, text: impl Into<String>) {
+        self.push(ToastKind::Error, text);
+    }
+
+    /// Returns the number of visible toasts.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_overlay::Toasts;
+    /// let toasts = Toasts::new();
+    /// assert_eq!(toasts.len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.toasts.len()
+    }
+
+    /// Returns `true` if there are no visible toasts.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_overlay::Toasts;
+    /// let toasts = Toasts::new();
+    /// assert!(toasts.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+
+    /// Removes all toasts.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_overlay::Toasts;
+    /// let mut toasts = Toasts::new();
+    /// toasts.clear();
+    /// ```
+    pub fn clear(&mut self) {
+        self.toasts.clear();
+    }
+
+    /// Advances the dismiss timers and draws the toasts, unless a photo is being taken (see
+    /// [`crate::ui_capture`]). Call once per frame.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Context;
+    /// # use deckbuilder_eng::ui_overlay::Toasts;
+    /// # fn demo(ctx: &Context, toasts: &mut Toasts) {
+    /// toasts.show(ctx);
+    /// # }
+    /// ```
+    pub fn show(&mut self, ctx: &Context) {
+        let dt = ctx.input(|i| i.stable_dt);
+        for toast in &mut self.toasts {
+            toast.remaining -= dt;
+        }
+        self.toasts.retain(|t| t.remaining > 0.0);
+        if self.toasts.is_empty() || is_photo_mode(ctx) {
+            return;
+        }
+
+        let margin = 12.0;
+        let offset = Vec2::new(
+            -self.anchor.x().to_sign() * margin,
+            -self.anchor.y().to_sign() * margin,
+        );
+        let mut dismissed = None;
+        Area::new(Id::new("deckbuilder_toasts"))
+            .order(Order::Foreground)
+            .anchor(self.anchor, offset)
+            .interactable(true)
+            .show(ctx, |ui| {
+                for (i, toast) in self.toasts.iter().enumerate() {
+                    // Fade out during the last half second.
+                    let alpha = (toast.remaining / 0.5).clamp(0.0, 1.0);
+                    let response = Frame::popup(ui.style())
+                        .fill(toast.kind.color().gamma_multiply(alpha))
+                        .show(ui, |ui| {
+                            ui.label(
+                                RichText::new(&toast.text)
+                                    .color(Color32::WHITE.gamma_multiply(alpha)),
+                            );
+                        })
+                        .response;
+                    if ui.interact(response.rect, ui.id().with(("toast", i)), Sense::click()).clicked() {
+                        dismissed = Some(i);
+                    }
+                }
+            });
+        if let Some(i) = dismissed {
+            self.toasts.remove(i);
+        }
+        ctx.request_repaint();
+    }
+}
+
+/// Modal window centered on screen. Dims the background and blocks interaction with
+/// everything behind it while shown. Returns the value of `add_contents`.
+///
+/// # Example
+/// ```rust
+/// # use egui::{Context, Id};
+/// # use deckbuilder_eng::ui_overlay::ui_modal;
+/// # fn demo(ctx: &Context, open: &mut bool) {
+/// if *open {
+///     ui_modal(ctx, Id::new("deck_full"), |ui| {
+///         ui.label("Your deck is full.");
+///         if ui.button("OK").clicked() {
+///             *open = false;
+///         }
+///     });
+/// }
+/// # }
+/// ```
+pub fn ui_modal<R>(ctx: &Context, id: Id, add_contents: impl FnOnce(&mut Ui) -> R) -> R {
+    let screen = ctx.screen_rect();
+    let backdrop_id = id.with("backdrop");
+    Area::new(backdrop_id)
+        .order(Order::Foreground)
+        .fixed_pos(screen.min)
+        .interactable(true)
+        .show(ctx, |ui| {
+            ui.painter().rect_filled(screen, 0.0, Color32::from_black_alpha(160));
+            // Swallow clicks and drags so nothing behind the modal reacts.
+            ui.allocate_rect(screen, Sense::click_and_drag());
+        });
+
+    let inner = Area::new(id)
+        .order(Order::Foreground)
+        .anchor(Align2::CENTER_CENTER, Vec2::ZERO)
+        .show(ctx, |ui| Frame::window(ui.style()).show(ui, add_contents).inner)
+        .inner;
+
+    ctx.move_to_top(LayerId::new(Order::Foreground, backdrop_id));
+    ctx.move_to_top(LayerId::new(Order::Foreground, id));
+    inner
+}
+
+/// Yes/No confirmation modal. Returns `Some(true)` for Yes, `Some(false)` for No or Escape,
+/// and `None` while the player has not decided yet.
+///
+/// # Example
+/// ```rust
+/// # use egui::Context;
+/// # use deckbuilder_eng::ui_overlay::ui_confirm_dialog;
+/// # fn demo(ctx: &Context) {
+/// match ui_confirm_dialog(ctx, "Abandon run?", "All progress will be lost.") {
+///     Some(true) => println!("Abandoned"),
+///     Some(false) => println!("Kept playing"),
+///     None => {}
+/// }
+/// # }
+/// ```
+pub fn ui_confirm_dialog(ctx: &Context, title: &str, message: &str) -> Option<bool> {
+    let mut answer = None;
+    ui_modal(ctx, Id::new(("confirm_dialog", title)), |ui| {
+        ui.set_min_width(240.0);
+        ui.vertical_centered(|ui| {
+            ui.heading(title);
+            ui.add_space(6.0);
+            ui.label(message);
+            ui.add_space(10.0);
+            ui.horizontal(|ui| {
+                if ui.button("Yes").clicked() {
+                    answer = Some(true);
+                }
+                if ui.button("No").clicked() {
+                    answer = Some(false);
+                }
+            });
+        });
+    });
+    if answer.is_none() && ctx.input(|i| i.key_pressed(Key::Escape)) {
+        answer = Some(false);
+    }
+    answer
+}
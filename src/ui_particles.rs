@@ -0,0 +1,690 @@
+//! Lightweight 2D particle effects for egui-based deckbuilder UIs.
+//!
+//! Provides a [`ParticleSystem`] holding emitters and their particles, configured by an
+//! [`EmitterConfig`] (spawn rate, lifetime, velocity, gravity, color and size over life, optional
+//! sprite texture), with presets for card-play sparks, heal glows and hit impacts.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::ui_particles::{EmitterConfig, ParticleSystem};
+//! use egui::{CentralPanel, Context, Pos2};
+//!
+//! fn my_fx(ctx: &Context, particles: &mut ParticleSystem, enemy_pos: Pos2) {
+//!     CentralPanel::default().show(ctx, |ui| {
+//!         if ui.button("Attack").clicked() {
+//!             particles.burst(enemy_pos, &EmitterConfig::hit_impact(), 30);
+//!         }
+//!     });
+//!     // Advance and draw all particles once per frame
+//!     particles.show(ctx);
+//! }
+//! ```
+//!
+//! # Details
+//!
+//! - Emitters spawn continuously at their rate until stopped, removed or their duration ends;
+//!   [`ParticleSystem::burst`] spawns a one-off batch without an emitter.
+//! - [`ParticleSystem::burst_event`] plays the preset effect of a [`GameEvent`] from the event bus.
+//! - Particles are painted on the foreground layer, as circles or as tinted sprites.
+//! - Randomness comes from a small internal generator seeded with [`ParticleSystem::with_seed`].
+//! - See each function's documentation for usage and customization options.
+
+use egui::{Color32, Context, Id, LayerId, Order, Painter, Pos2, Rect, TextureId, Vec2};
+
+use crate::events::GameEvent;
+use crate::game_time::{game_dt, Tick};
+
+/// Settings of a particle emitter.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmitterConfig {
+    /// Particles spawned per second while the emitter is active.
+    pub rate: f32,
+    /// Seconds the emitter spawns before it is removed (`None` = until stopped).
+    pub duration: Option<f32>,
+    /// Minimum and maximum particle lifetime in seconds.
+    pub lifetime: (f32, f32),
+    /// Minimum and maximum initial speed in points per second.
+    pub speed: (f32, f32),
+    /// Emission direction in radians (0 = right, -π/2 = up).
+    pub direction: f32,
+    /// Total emission cone angle in radians (2π = all directions).
+    pub spread: f32,
+    /// Radius around the emitter position where particles spawn.
+    pub spawn_radius: f32,
+    /// Constant acceleration in points per second squared.
+    pub gravity: Vec2,
+    /// Fraction of velocity lost per second (0 = none).
+    pub drag: f32,
+    /// Color at spawn.
+    pub start_color: Color32,
+    /// Color at the end of life (interpolated from `start_color`).
+    pub end_color: Color32,
+    /// Size (diameter) at spawn in points.
+    pub start_size: f32,
+    /// Size at the end of life in points.
+    pub end_size: f32,
+    /// Optional sprite drawn (tinted with the particle color) instead of a circle.
+    pub texture: Option<TextureId>,
+    /// Maximum number of live particles of this emitter.
+    pub max_particles: usize,
+}
+
+impl Default for EmitterConfig {
+    fn default() -> Self {
+        Self {
+            rate: 30.0,
+            duration: None,
+            lifetime: (0.6, 1.2),
+            speed: (40.0, 120.0),
+            direction: -std::f32::consts::FRAC_PI_2,
+            spread: std::f32::consts::TAU,
+            spawn_radius: 0.0,
+            gravity: Vec2::ZERO,
+            drag: 0.0,
+            start_color: Color32::WHITE,
+            end_color: Color32::TRANSPARENT,
+            start_size: 6.0,
+            end_size: 1.0,
+            texture: None,
+            max_particles: 500,
+        }
+    }
+}
+
+impl EmitterConfig {
+    /// Creates the default configuration (white particles in all directions).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_particles::EmitterConfig;
+    /// let config = EmitterConfig::new().rate(60.0);
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Golden sparks flying outwards and falling, for playing a card.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_particles::EmitterConfig;
+    /// let sparks = EmitterConfig::sparks();
+    /// ```
+    pub fn sparks() -> Self {
+        Self {
+            rate: 80.0,
+            duration: Some(0.25),
+            lifetime: (0.4, 0.9),
+            speed: (120.0, 320.0),
+            gravity: Vec2::new(0.0, 500.0),
+            drag: 1.5,
+            start_color: Color32::from_rgb(255, 220, 120),
+            end_color: Color32::from_rgba_unmultiplied(255, 120, 30, 0),
+            start_size: 5.0,
+            end_size: 1.0,
+            ..Self::default()
+        }
+    }
+
+    /// Soft green motes rising slowly, for heals.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_particles::EmitterConfig;
+    /// let glow = EmitterConfig::heal_glow();
+    /// ```
+    pub fn heal_glow() -> Self {
+        Self {
+            rate: 40.0,
+            duration: Some(0.8),
+            lifetime: (0.8, 1.6),
+            speed: (20.0, 60.0),
+            spread: 0.8,
+            spawn_radius: 30.0,
+            gravity: Vec2::new(0.0, -40.0),
+            start_color: Color32::from_rgba_unmultiplied(120, 255, 140, 220),
+            end_color: Color32::from_rgba_unmultiplied(80, 220, 90, 0),
+            start_size: 4.0,
+            end_size: 10.0,
+            ..Self::default()
+        }
+    }
+
+    /// Fast red-orange debris bursting outwards, for hits. Meant for [`ParticleSystem::burst`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_particles::EmitterConfig;
+    /// let impact = EmitterConfig::hit_impact();
+    /// ```
+    pub fn hit_impact() -> Self {
+        Self {
+            rate: 0.0,
+            duration: Some(0.0),
+            lifetime: (0.2, 0.5),
+            speed: (200.0, 450.0),
+            drag: 4.0,
+            start_color: Color32::from_rgb(255, 90, 50),
+            end_color: Color32::from_rgba_unmultiplied(120, 20, 10, 0),
+            start_size: 7.0,
+            end_size: 2.0,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the spawn rate in particles per second.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_particles::EmitterConfig;
+    /// let config = EmitterConfig::new().rate(120.0);
+    /// ```
+    pub fn rate(mut self, rate: f32) -> Self {
+        self.rate = rate;
+        self
+    }
+
+    /// Sets how long the emitter spawns particles (`None` = until stopped).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_particles::EmitterConfig;
+    /// let config = EmitterConfig::new().duration(Some(0.5));
+    /// ```
+    pub fn duration(mut self, duration: Option<f32>) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Sets the minimum and maximum particle lifetime in seconds.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_particles::EmitterConfig;
+    /// let config = EmitterConfig::new().lifetime(0.5, 1.0);
+    /// ```
+    pub fn lifetime(mut self, min: f32, max: f32) -> Self {
+        self.lifetime = (min, max);
+        self
+    }
+
+    /// Sets the minimum and maximum initial speed in points per second.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_particles::EmitterConfig;
+    /// let config = EmitterConfig::new().speed(50.0, 200.0);
+    /// ```
+    pub fn speed(mut self, min: f32, max: f32) -> Self {
+        self.speed = (min, max);
+        self
+    }
+
+    /// Sets the emission direction and cone angle, both in radians.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_particles::EmitterConfig;
+    /// // Upwards in a 60° cone
+    /// let config = EmitterConfig::new().direction(-std::f32::consts::FRAC_PI_2, 60f32.to_radians());
+    /// ```
+    pub fn direction(mut self, direction: f32, spread: f32) -> Self {
+        self.direction = direction;
+        self.spread = spread;
+        self
+    }
+
+    /// Sets the constant acceleration applied to particles.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Vec2;
+    /// # use deckbuilder_eng::ui_particles::EmitterConfig;
+    /// let config = EmitterConfig::new().gravity(Vec2::new(0.0, 300.0));
+    /// ```
+    pub fn gravity(mut self, gravity: Vec2) -> Self {
+        self.gravity = gravity;
+        self
+    }
+
+    /// Sets the start and end color; particles interpolate between them over their life.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Color32;
+    /// # use deckbuilder_eng::ui_particles::EmitterConfig;
+    /// let config = EmitterConfig::new().colors(Color32::LIGHT_BLUE, Color32::TRANSPARENT);
+    /// ```
+    pub fn colors(mut self, start: Color32, end: Color32) -> Self {
+        self.start_color = start;
+        self.end_color = end;
+        self
+    }
+
+    /// Sets the start and end size in points.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_particles::EmitterConfig;
+    /// let config = EmitterConfig::new().sizes(8.0, 0.0);
+    /// ```
+    pub fn sizes(mut self, start: f32, end: f32) -> Self {
+        self.start_size = start;
+        self.end_size = end;
+        self
+    }
+
+    /// Draws particles with a sprite texture instead of circles.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::TextureHandle;
+    /// # use deckbuilder_eng::ui_particles::EmitterConfig;
+    /// # fn demo(star: &TextureHandle) {
+    /// let config = EmitterConfig::sparks().texture(star.id());
+    /// # }
+    /// ```
+    pub fn texture(mut self, texture: TextureId) -> Self {
+        self.texture = Some(texture);
+        self
+    }
+}
+
+/// Handle of an emitter added to a [`ParticleSystem`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EmitterId(u64);
+
+struct Emitter {
+    id: EmitterId,
+    config: EmitterConfig,
+    pos: Pos2,
+    active: bool,
+    elapsed: f32,
+    pending: f32,
+}
+
+#[derive(Clone)]
+struct Particle {
+    emitter: Option<EmitterId>,
+    pos: Pos2,
+    vel: Vec2,
+    age: f32,
+    lifetime: f32,
+    gravity: Vec2,
+    drag: f32,
+    start_color: Color32,
+    end_color: Color32,
+    start_size: f32,
+    end_size: f32,
+    texture: Option<TextureId>,
+}
+
+/// Emitters and live particles, updated per frame and painted with the egui painter.
+pub struct ParticleSystem {
+    emitters: Vec<Emitter>,
+    particles: Vec<Particle>,
+    next_id: u64,
+    rng: u64,
+}
+
+impl Default for ParticleSystem {
+    fn default() -> Self {
+        Self::with_seed(0x9E37_79B9_7F4A_7C15)
+    }
+}
+
+impl ParticleSystem {
+    /// Creates an empty particle system.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_particles::ParticleSystem;
+    /// let particles = ParticleSystem::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty particle system whose randomness is derived from `seed`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_particles::ParticleSystem;
+    /// let particles = ParticleSystem::with_seed(42);
+    /// ```
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            emitters: Vec::new(),
+            particles: Vec::new(),
+            next_id: 0,
+            // xorshift must not start at zero
+            rng: seed.max(1),
+        }
+    }
+
+    /// Adds an emitter at `pos` and returns its handle.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Pos2;
+    /// # use deckbuilder_eng::ui_particles::{EmitterConfig, ParticleSystem};
+    /// let mut particles = ParticleSystem::new();
+    /// let glow = particles.add_emitter(Pos2::new(100.0, 300.0), EmitterConfig::heal_glow());
+    /// assert!(particles.has_emitter(glow));
+    /// ```
+    pub fn add_emitter(&mut self, pos: Pos2, config: EmitterConfig) -> EmitterId {
+        let id = EmitterId(self.next_id);
+        self.next_id += 1;
+        self.emitters.push(Emitter { id, config, pos, active: true, elapsed: 0.0, pending: 0.0 });
+        id
+    }
+
+    /// Moves an emitter, e.g. to follow a dragged card. Spawned particles keep their positions.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Pos2;
+    /// # use deckbuilder_eng::ui_particles::{EmitterConfig, ParticleSystem};
+    /// let mut particles = ParticleSystem::new();
+    /// let trail = particles.add_emitter(Pos2::ZERO, EmitterConfig::sparks().duration(None));
+    /// particles.set_emitter_pos(trail, Pos2::new(50.0, 50.0));
+    /// ```
+    pub fn set_emitter_pos(&mut self, id: EmitterId, pos: Pos2) {
+        if let Some(emitter) = self.emitters.iter_mut().find(|e| e.id == id) {
+            emitter.pos = pos;
+        }
+    }
+
+    /// Stops an emitter from spawning; it is removed once its particles have died.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Pos2;
+    /// # use deckbuilder_eng::ui_particles::{EmitterConfig, ParticleSystem};
+    /// let mut particles = ParticleSystem::new();
+    /// let glow = particles.add_emitter(Pos2::ZERO, EmitterConfig::heal_glow().duration(None));
+    /// particles.stop_emitter(glow);
+    /// particles.update(0.1);
+    /// assert!(!particles.has_emitter(glow));
+    /// ```
+    pub fn stop_emitter(&mut self, id: EmitterId) {
+        if let Some(emitter) = self.emitters.iter_mut().find(|e| e.id == id) {
+            emitter.active = false;
+        }
+    }
+
+    /// Removes an emitter together with its live particles.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Pos2;
+    /// # use deckbuilder_eng::ui_particles::{EmitterConfig, ParticleSystem};
+    /// let mut particles = ParticleSystem::new();
+    /// let glow = particles.add_emitter(Pos2::ZERO, EmitterConfig::heal_glow());
+    /// particles.update(0.5);
+    /// particles.remove_emitter(glow);
+    /// assert!(particles.is_empty());
+    /// ```
+    pub fn remove_emitter(&mut self, id: EmitterId) {
+        self.emitters.retain(|e| e.id != id);
+        self.particles.retain(|p| p.emitter != Some(id));
+    }
+
+    /// Returns `true` if the emitter still exists (spawning or waiting for its particles).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Pos2;
+    /// # use deckbuilder_eng::ui_particles::{EmitterConfig, ParticleSystem};
+    /// let mut particles = ParticleSystem::new();
+    /// let sparks = particles.add_emitter(Pos2::ZERO, EmitterConfig::sparks());
+    /// particles.update(1.0); // spawns its sparks and stops
+    /// particles.update(1.0); // the sparks die
+    /// assert!(!particles.has_emitter(sparks));
+    /// ```
+    pub fn has_emitter(&self, id: EmitterId) -> bool {
+        self.emitters.iter().any(|e| e.id == id)
+    }
+
+    /// Spawns `count` particles at `pos` at once, without adding an emitter.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Pos2;
+    /// # use deckbuilder_eng::ui_particles::{EmitterConfig, ParticleSystem};
+    /// let mut particles = ParticleSystem::new();
+    /// particles.burst(Pos2::new(400.0, 200.0), &EmitterConfig::hit_impact(), 25);
+    /// assert_eq!(particles.len(), 25);
+    /// ```
+    pub fn burst(&mut self, pos: Pos2, config: &EmitterConfig, count: usize) {
+        for _ in 0..count {
+            let particle = self.spawn_particle(None, pos, config);
+            self.particles.push(particle);
+        }
+    }
+
+    /// Plays the preset effect of `event` at `pos`: an impact burst sized by the damage, a heal
+    /// glow, or sparks for a played card. Returns false for other events.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Pos2;
+    /// # use deckbuilder_eng::events::{GameEvent, Target};
+    /// # use deckbuilder_eng::ui_particles::ParticleSystem;
+    /// let mut particles = ParticleSystem::new();
+    /// let hit = GameEvent::Damage { target: Target::Enemy, amount: 12 };
+    /// assert!(particles.burst_event(&hit, Pos2::new(600.0, 200.0)));
+    /// assert_eq!(particles.len(), 12);
+    /// ```
+    pub fn burst_event(&mut self, event: &GameEvent, pos: Pos2) -> bool {
+        match event {
+            GameEvent::Damage { amount, .. } => {
+                self.burst(pos, &EmitterConfig::hit_impact(), (*amount).clamp(4, 40) as usize);
+            }
+            GameEvent::Heal { .. } => {
+                self.add_emitter(pos, EmitterConfig::heal_glow());
+            }
+            GameEvent::CardPlayed { .. } => {
+                self.add_emitter(pos, EmitterConfig::sparks());
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// Returns the number of live particles.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_particles::ParticleSystem;
+    /// assert_eq!(ParticleSystem::new().len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.particles.len()
+    }
+
+    /// Returns `true` if no particles are alive.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_particles::ParticleSystem;
+    /// assert!(ParticleSystem::new().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.particles.is_empty()
+    }
+
+    /// Returns `true` if no emitters or particles are left, i.e. nothing needs painting.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_particles::ParticleSystem;
+    /// assert!(ParticleSystem::new().is_idle());
+    /// ```
+    pub fn is_idle(&self) -> bool {
+        self.emitters.is_empty() && self.particles.is_empty()
+    }
+
+    /// Removes all emitters and particles.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_particles::ParticleSystem;
+    /// let mut particles = ParticleSystem::new();
+    /// particles.clear();
+    /// ```
+    pub fn clear(&mut self) {
+        self.emitters.clear();
+        self.particles.clear();
+    }
+
+    /// Advances emitters and particles by `dt` seconds, spawning new particles and
+    /// removing dead ones and finished emitters.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Pos2;
+    /// # use deckbuilder_eng::ui_particles::{EmitterConfig, ParticleSystem};
+    /// let mut particles = ParticleSystem::new();
+    /// particles.add_emitter(Pos2::ZERO, EmitterConfig::new().rate(10.0));
+    /// particles.update(1.0);
+    /// assert_eq!(particles.len(), 10);
+    /// ```
+    pub fn update(&mut self, dt: f32) {
+        for particle in &mut self.particles {
+            particle.age += dt;
+            particle.vel += particle.gravity * dt;
+            particle.vel *= (1.0 - particle.drag * dt).max(0.0);
+            particle.pos += particle.vel * dt;
+        }
+        self.particles.retain(|p| p.age < p.lifetime);
+
+        let mut spawned = Vec::new();
+        let mut emitters = std::mem::take(&mut self.emitters);
+        for emitter in &mut emitters {
+            if !emitter.active {
+                continue;
+            }
+            // Only spawn for the part of `dt` that lies within the duration.
+            let remaining = emitter.config.duration.map_or(dt, |d| (d - emitter.elapsed).clamp(0.0, dt));
+            emitter.elapsed += dt;
+            emitter.pending += emitter.config.rate * remaining;
+            let live = self.particles.iter().filter(|p| p.emitter == Some(emitter.id)).count();
+            let mut capacity = emitter.config.max_particles.saturating_sub(live);
+            while emitter.pending >= 1.0 {
+                emitter.pending -= 1.0;
+                if capacity > 0 {
+                    capacity -= 1;
+                    spawned.push(self.spawn_particle(Some(emitter.id), emitter.pos, &emitter.config));
+                }
+            }
+            if emitter.config.duration.is_some_and(|d| emitter.elapsed >= d) {
+                emitter.active = false;
+            }
+        }
+        self.particles.extend(spawned);
+        let particles = &self.particles;
+        emitters.retain(|e| e.active || particles.iter().any(|p| p.emitter == Some(e.id)));
+        self.emitters = emitters;
+    }
+
+    /// Paints all particles with `painter` without advancing time.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Ui;
+    /// # use deckbuilder_eng::ui_particles::ParticleSystem;
+    /// # fn demo(ui: &mut Ui, particles: &ParticleSystem) {
+    /// // Clip particles to a panel
+    /// particles.paint(ui.painter());
+    /// # }
+    /// ```
+    pub fn paint(&self, painter: &Painter) {
+        for particle in &self.particles {
+            let t = (particle.age / particle.lifetime).clamp(0.0, 1.0);
+            let color = lerp_color(particle.start_color, particle.end_color, t);
+            let size = particle.start_size + (particle.end_size - particle.start_size) * t;
+            if size <= 0.0 || color.a() == 0 {
+                continue;
+            }
+            match particle.texture {
+                Some(texture) => {
+                    let rect = Rect::from_center_size(particle.pos, Vec2::splat(size));
+                    let uv = Rect::from_min_max(Pos2::ZERO, Pos2::new(1.0, 1.0));
+                    painter.image(texture, rect, uv, color);
+                }
+                None => {
+                    painter.circle_filled(particle.pos, size * 0.5, color);
+                }
+            }
+        }
+    }
+
+    /// Advances time by the game time of this frame, paints all particles on the foreground layer, and
+    /// requests a repaint while any emitters or particles remain. Call once per frame.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Context;
+    /// # use deckbuilder_eng::ui_particles::ParticleSystem;
+    /// # fn demo(ctx: &Context, particles: &mut ParticleSystem) {
+    /// particles.show(ctx);
+    /// # }
+    /// ```
+    pub fn show(&mut self, ctx: &Context) {
+        self.update(game_dt(ctx));
+        self.paint(&ctx.layer_painter(LayerId::new(Order::Foreground, Id::new("deckbuilder_particles"))));
+        if !self.is_idle() {
+            ctx.request_repaint();
+        }
+    }
+
+    fn spawn_particle(&mut self, emitter: Option<EmitterId>, pos: Pos2, config: &EmitterConfig) -> Particle {
+        let angle = config.direction + (self.random() - 0.5) * config.spread;
+        let speed = self.range(config.speed);
+        let offset = if config.spawn_radius > 0.0 {
+            let a = self.random() * std::f32::consts::TAU;
+            Vec2::angled(a) * config.spawn_radius * self.random().sqrt()
+        } else {
+            Vec2::ZERO
+        };
+        Particle {
+            emitter,
+            pos: pos + offset,
+            vel: Vec2::angled(angle) * speed,
+            age: 0.0,
+            lifetime: self.range(config.lifetime).max(f32::EPSILON),
+            gravity: config.gravity,
+            drag: config.drag,
+            start_color: config.start_color,
+            end_color: config.end_color,
+            start_size: config.start_size,
+            end_size: config.end_size,
+            texture: config.texture,
+        }
+    }
+
+    /// Returns a pseudo-random number in `0.0..1.0` (xorshift64).
+    fn random(&mut self) -> f32 {
+        self.rng ^= self.rng << 13;
+        self.rng ^= self.rng >> 7;
+        self.rng ^= self.rng << 17;
+        (self.rng >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    fn range(&mut self, (min, max): (f32, f32)) -> f32 {
+        min + (max - min) * self.random()
+    }
+}
+
+impl Tick for ParticleSystem {
+    fn tick(&mut self, dt: f32) {
+        self.update(dt);
+    }
+}
+
+fn lerp_color(a: Color32, b: Color32, t: f32) -> Color32 {
+    let mix = |x: u8, y: u8| (x as f32 + (y as f32 - x as f32) * t).round() as u8;
+    Color32::from_rgba_premultiplied(mix(a.r(), b.r()), mix(a.g(), b.g()), mix(a.b(), b.b()), mix(a.a(), b.a()))
+}
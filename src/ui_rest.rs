@@ -0,0 +1,241 @@
+//! Rest site (campfire) screen prefab for egui-based deckbuilder UIs.
+//!
+//! Provides [`ui_rest_site`], the genre-standard rest node: heal a share of max health, upgrade
+//! a card chosen from a card grid, or pick one of the extra options from [`RestConfig`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::card::Card;
+//! use deckbuilder_eng::ui_rest::{ui_rest_site, RestAction, RestConfig, RestOption, RestState};
+//! use egui::{CentralPanel, Context};
+//!
+//! fn rest_screen(ctx: &Context, health: &mut i32, max_health: i32, deck: &[Card], state: &mut RestState) {
+//!     let config = RestConfig::new(*health, max_health)
+//!         .option(RestOption::new("dig", "Dig", "Obtain a random relic."));
+//!     let action = CentralPanel::default().show(ctx, |ui| ui_rest_site(ui, &config, deck, state)).inner;
+//!     match action {
+//!         Some(RestAction::Heal(amount)) => *health += amount,
+//!         Some(RestAction::Upgrade(index)) => println!("Upgrade {}", deck[index].name),
+//!         Some(RestAction::Option(id)) => println!("Chose {}", id),
+//!         None => {}
+//!     }
+//! }
+//! ```
+//!
+//! # Details
+//!
+//! - The heal amount is rounded up and capped at the missing health.
+//! - Choosing Upgrade switches to a card grid; confirm with a double-click or the Upgrade button.
+//! - See each function's documentation for usage and customization options.
+
+use egui::{Button, RichText, Ui, Vec2};
+
+use crate::card::Card;
+use crate::ui_card::{ui_card_grid, CardGridState};
+
+/// Option chosen at a rest site.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RestAction {
+    /// Heal this much health.
+    Heal(i32),
+    /// Upgrade the card at this index of the cards passed to [`ui_rest_site`].
+    Upgrade(usize),
+    /// One of the extra [`RestOption`]s, by id.
+    Option(String),
+}
+
+/// An extra rest site option, e.g. "Dig" or "Lift".
+#[derive(Debug, Clone)]
+pub struct RestOption {
+    pub id: String,
+    pub label: String,
+    pub description: String,
+    pub enabled: bool,
+}
+
+impl RestOption {
+    /// Creates an enabled option.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_rest::RestOption;
+    /// let lift = RestOption::new("lift", "Lift", "Permanently gain 1 Strength.");
+    /// ```
+    pub fn new(id: impl Into<String>, label: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            description: description.into(),
+            enabled: true,
+        }
+    }
+
+    /// Enables or disables the option.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_rest::RestOption;
+    /// let recall = RestOption::new("recall", "Recall", "Obtain the Ruby Key.").enabled(false);
+    /// ```
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+}
+
+/// What a rest site offers.
+#[derive(Debug, Clone)]
+pub struct RestConfig {
+    pub health: i32,
+    pub max_health: i32,
+    /// Share of max health restored by resting.
+    pub heal_fraction: f32,
+    /// Whether resting is offered.
+    pub allow_heal: bool,
+    /// Whether upgrading a card is offered.
+    pub allow_upgrade: bool,
+    /// Extra options shown after Rest and Smith.
+    pub options: Vec<RestOption>,
+}
+
+impl RestConfig {
+    /// Creates a config offering Rest (30% of max health) and Smith.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_rest::RestConfig;
+    /// let config = RestConfig::new(40, 80);
+    /// assert_eq!(config.heal_amount(), 24);
+    /// ```
+    pub fn new(health: i32, max_health: i32) -> Self {
+        Self {
+            health,
+            max_health,
+            heal_fraction: 0.3,
+            allow_heal: true,
+            allow_upgrade: true,
+            options: Vec::new(),
+        }
+    }
+
+    /// Sets the share of max health restored by resting.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_rest::RestConfig;
+    /// let config = RestConfig::new(10, 80).heal_fraction(0.5);
+    /// assert_eq!(config.heal_amount(), 40);
+    /// ```
+    pub fn heal_fraction(mut self, fraction: f32) -> Self {
+        self.heal_fraction = fraction;
+        self
+    }
+
+    /// Adds an extra option.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_rest::{RestConfig, RestOption};
+    /// let config = RestConfig::new(40, 80).option(RestOption::new("dig", "Dig", "Obtain a random relic."));
+    /// ```
+    pub fn option(mut self, option: RestOption) -> Self {
+        self.options.push(option);
+        self
+    }
+
+    /// Health restored by resting: `heal_fraction` of max health, rounded up and capped at the
+    /// missing health.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_rest::RestConfig;
+    /// assert_eq!(RestConfig::new(75, 80).heal_amount(), 5);
+    /// ```
+    pub fn heal_amount(&self) -> i32 {
+        let amount = (self.max_health as f32 * self.heal_fraction).ceil() as i32;
+        amount.min(self.max_health - self.health).max(0)
+    }
+}
+
+/// UI state of a [`ui_rest_site`] screen.
+#[derive(Default)]
+pub struct RestState {
+    /// Whether the card grid for choosing an upgrade is open.
+    pub choosing_upgrade: bool,
+    /// State of the upgrade card grid.
+    pub grid: CardGridState,
+}
+
+fn option_button(ui: &mut Ui, enabled: bool, label: &str, description: &str) -> bool {
+    ui.vertical(|ui| {
+        ui.set_width(160.0);
+        let text = RichText::new(label).size(20.0).strong();
+        let clicked = ui.add_enabled(enabled, Button::new(text).min_size(Vec2::new(160.0, 64.0))).clicked();
+        ui.label(description);
+        clicked
+    })
+    .inner
+}
+
+/// Rest site screen. `cards` are the cards that can be upgraded. Returns the chosen action.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::card::Card;
+/// # use deckbuilder_eng::ui_rest::{ui_rest_site, RestConfig, RestState};
+/// # fn demo(ui: &mut Ui, upgradable: &[Card], state: &mut RestState) {
+/// if let Some(action) = ui_rest_site(ui, &RestConfig::new(40, 80), upgradable, state) {
+///     println!("{:?}", action);
+/// }
+/// # }
+/// ```
+pub fn ui_rest_site(ui: &mut Ui, config: &RestConfig, cards: &[Card], state: &mut RestState) -> Option<RestAction> {
+    let mut action = None;
+
+    if state.choosing_upgrade {
+        ui.horizontal(|ui| {
+            if ui.button("Back").clicked() {
+                state.choosing_upgrade = false;
+            }
+            ui.heading("Choose a card to upgrade");
+            let selected = state.grid.selected.filter(|&i| i < cards.len());
+            if ui.add_enabled(selected.is_some(), Button::new("Upgrade")).clicked() {
+                action = selected.map(RestAction::Upgrade);
+            }
+        });
+        ui.separator();
+        if let Some(i) = ui_card_grid(ui, cards, &mut state.grid).double_clicked {
+            action = Some(RestAction::Upgrade(i));
+        }
+        if action.is_some() {
+            state.choosing_upgrade = false;
+            state.grid.selected = None;
+        }
+        return action;
+    }
+
+    ui.vertical_centered(|ui| {
+        ui.heading("Rest Site");
+        ui.label(format!("Health: {}/{}", config.health, config.max_health));
+    });
+    ui.add_space(12.0);
+    ui.horizontal_wrapped(|ui| {
+        if config.allow_heal {
+            let amount = config.heal_amount();
+            if option_button(ui, amount > 0, "Rest", &format!("Heal {} health.", amount)) {
+                action = Some(RestAction::Heal(amount));
+            }
+        }
+        if config.allow_upgrade && option_button(ui, !cards.is_empty(), "Smith", "Upgrade a card in your deck.") {
+            state.choosing_upgrade = true;
+        }
+        for option in &config.options {
+            if option_button(ui, option.enabled, &option.label, &option.description) {
+                action = Some(RestAction::Option(option.id.clone()));
+            }
+        }
+    });
+    action
+}
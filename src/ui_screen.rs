@@ -0,0 +1,764 @@
+//! Screen/scene management for egui-based deckbuilder UIs.
+//!
+//! Provides a [`ScreenStack`] of [`Screen`]s (main menu, run map, combat, ...) with push, pop
+//! and replace navigation and built-in fade/slide transitions, and a [`TransitionOverlay`] that
+//! covers the whole window (fade to black, wipe, dissolve) for scene changes.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::ui_screen::{Screen, ScreenAction, ScreenStack, Transition};
+//! use egui::{Context, Ui};
+//!
+//! struct MainMenu;
+//! struct Combat;
+//!
+//! impl Screen for MainMenu {
+//!     fn update(&mut self, ui: &mut Ui) -> ScreenAction {
+//!         ui.heading("Deckbuilder");
+//!         if ui.button("New run").clicked() {
+//!             return ScreenAction::Push(Box::new(Combat));
+//!         }
+//!         ScreenAction::None
+//!     }
+//! }
+//!
+//! impl Screen for Combat {
+//!     fn update(&mut self, ui: &mut Ui) -> ScreenAction {
+//!         ui.label("Fight!");
+//!         if ui.button("Flee").clicked() {
+//!             return ScreenAction::Pop;
+//!         }
+//!         ScreenAction::None
+//!     }
+//! }
+//!
+//! let mut screens = ScreenStack::new().transition(Transition::Fade { duration: 0.4 });
+//! screens.push(Box::new(MainMenu));
+//!
+//! fn frame(ctx: &Context, screens: &mut ScreenStack) {
+//!     screens.show(ctx);
+//! }
+//! ```
+//!
+//! Pairing the stack with an overlay, swapping screens while the window is covered:
+//!
+//! ```rust
+//! use deckbuilder_eng::ui_screen::{ScreenStack, Transition, TransitionEffect, TransitionEvent, TransitionOverlay};
+//! use egui::Context;
+//! use std::time::Duration;
+//!
+//! let mut screens = ScreenStack::new().transition(Transition::None);
+//! let mut overlay = TransitionOverlay::new(TransitionEffect::Dissolve, Duration::from_millis(600));
+//! overlay.play(|| println!("covered"));
+//!
+//! fn frame(ctx: &Context, screens: &mut ScreenStack, overlay: &mut TransitionOverlay) {
+//!     screens.show(ctx);
+//!     if overlay.show(ctx) == Some(TransitionEvent::Covered) {
+//!         screens.pop();
+//!     }
+//! }
+//! ```
+//!
+//! # Details
+//!
+//! - Only the top screen is updated, except during a transition, where the outgoing screen is
+//!   drawn too. Navigation actions returned during a transition are ignored.
+//! - Actions returned by `Screen::update` are applied after the frame's drawing.
+//! - The overlay is painted on the foreground layer; dissolves upload a small noise mask texture
+//!   each frame that is stretched over the window.
+//! - See each function's documentation for usage and customization options.
+
+use std::time::Duration;
+
+use egui::{
+    CentralPanel, Color32, ColorImage, Context, Id, LayerId, Order, Pos2, Rect, TextureHandle, TextureOptions, Ui,
+    Vec2,
+};
+
+use crate::ui_anim::Easing;
+
+/// Navigation request returned from [`Screen::update`].
+pub enum ScreenAction {
+    /// Stay on the current screen.
+    None,
+    /// Put a new screen on top of the current one.
+    Push(Box<dyn Screen>),
+    /// Remove the current screen and return to the one below.
+    Pop,
+    /// Swap the current screen for a new one.
+    Replace(Box<dyn Screen>),
+}
+
+/// A full-window screen managed by a [`ScreenStack`].
+pub trait Screen {
+    /// Draws the screen and returns the navigation to perform, if any.
+    fn update(&mut self, ui: &mut Ui) -> ScreenAction;
+
+    /// Called when the screen is pushed or swapped in.
+    fn on_enter(&mut self) {}
+
+    /// Called when the screen is popped or replaced.
+    fn on_exit(&mut self) {}
+}
+
+/// Animation played when the top screen changes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Transition {
+    /// Switch instantly.
+    None,
+    /// Fade the old screen out, then the new one in.
+    Fade {
+        /// Total duration in seconds.
+        duration: f32,
+    },
+    /// Slide the new screen in from the right (or the left when popping).
+    Slide {
+        /// Total duration in seconds.
+        duration: f32,
+    },
+}
+
+impl Transition {
+    fn duration(self) -> f32 {
+        match self {
+            Transition::None => 0.0,
+            Transition::Fade { duration } | Transition::Slide { duration } => duration,
+        }
+    }
+}
+
+/// A running transition. `outgoing` is `None` when the old screen is still on the stack
+/// (below the pushed one).
+struct ActiveTransition {
+    outgoing: Option<Box<dyn Screen>>,
+    backwards: bool,
+    elapsed: f32,
+}
+
+/// Stack of screens; the top one is active.
+pub struct ScreenStack {
+    screens: Vec<Box<dyn Screen>>,
+    transition: Transition,
+    active: Option<ActiveTransition>,
+    generation: u64,
+}
+
+impl Default for ScreenStack {
+    fn default() -> Self {
+        Self {
+            screens: Vec::new(),
+            transition: Transition::Fade { duration: 0.3 },
+            active: None,
+            generation: 0,
+        }
+    }
+}
+
+impl ScreenStack {
+    /// Creates an empty stack using a short fade transition.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_screen::ScreenStack;
+    /// let screens = ScreenStack::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the transition played on navigation.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_screen::{ScreenStack, Transition};
+    /// let screens = ScreenStack::new().transition(Transition::Slide { duration: 0.35 });
+    /// ```
+    pub fn transition(mut self, transition: Transition) -> Self {
+        self.transition = transition;
+        self
+    }
+
+    /// Pushes `screen` on top and makes it active.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Ui;
+    /// # use deckbuilder_eng::ui_screen::{Screen, ScreenAction, ScreenStack};
+    /// struct Map;
+    /// impl Screen for Map {
+    ///     fn update(&mut self, ui: &mut Ui) -> ScreenAction {
+    ///         ui.label("Map");
+    ///         ScreenAction::None
+    ///     }
+    /// }
+    /// let mut screens = ScreenStack::new();
+    /// screens.push(Box::new(Map));
+    /// assert_eq!(screens.len(), 1);
+    /// ```
+    pub fn push(&mut self, mut screen: Box<dyn Screen>) {
+        screen.on_enter();
+        self.generation += 1;
+        let had_screen = !self.screens.is_empty();
+        self.screens.push(screen);
+        if had_screen {
+            self.begin(None, false);
+        }
+    }
+
+    /// Pops the top screen and returns it. The screen below becomes active.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_screen::ScreenStack;
+    /// let mut screens = ScreenStack::new();
+    /// assert!(screens.pop().is_none());
+    /// ```
+    pub fn pop(&mut self) -> Option<Box<dyn Screen>> {
+        let mut screen = self.screens.pop()?;
+        screen.on_exit();
+        self.generation += 1;
+        // The caller owns the popped screen, so it can't be drawn while fading out.
+        self.begin(None, true);
+        Some(screen)
+    }
+
+    /// Replaces the top screen with `screen` (e.g. map → combat).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Ui;
+    /// # use deckbuilder_eng::ui_screen::{Screen, ScreenAction, ScreenStack};
+    /// struct Rest;
+    /// impl Screen for Rest {
+    ///     fn update(&mut self, ui: &mut Ui) -> ScreenAction {
+    ///         ScreenAction::None
+    ///     }
+    /// }
+    /// let mut screens = ScreenStack::new();
+    /// screens.replace(Box::new(Rest));
+    /// ```
+    pub fn replace(&mut self, mut screen: Box<dyn Screen>) {
+        let old = self.screens.pop().map(|mut old| {
+            old.on_exit();
+            old
+        });
+        screen.on_enter();
+        self.generation += 1;
+        self.screens.push(screen);
+        if old.is_some() {
+            self.begin(old, false);
+        }
+    }
+
+    /// Returns the number of screens on the stack.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_screen::ScreenStack;
+    /// assert_eq!(ScreenStack::new().len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.screens.len()
+    }
+
+    /// Returns `true` if there are no screens.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_screen::ScreenStack;
+    /// assert!(ScreenStack::new().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.screens.is_empty()
+    }
+
+    /// Returns `true` while a transition animation is playing.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_screen::ScreenStack;
+    /// assert!(!ScreenStack::new().is_transitioning());
+    /// ```
+    pub fn is_transitioning(&self) -> bool {
+        self.active.is_some()
+    }
+
+    /// Returns a counter increased by every push, pop and replace, to detect screen changes.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_screen::ScreenStack;
+    /// let mut screens = ScreenStack::new();
+    /// let before = screens.generation();
+    /// screens.pop();
+    /// assert_eq!(screens.generation(), before);
+    /// ```
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    fn begin(&mut self, outgoing: Option<Box<dyn Screen>>, backwards: bool) {
+        self.active = if self.transition == Transition::None {
+            None
+        } else {
+            Some(ActiveTransition { outgoing, backwards, elapsed: 0.0 })
+        };
+    }
+
+    /// Draws the active screen (and the transition, if running) into `ui`, then applies the
+    /// returned navigation action.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Ui;
+    /// # use deckbuilder_eng::ui_screen::ScreenStack;
+    /// # fn demo(ui: &mut Ui, screens: &mut ScreenStack) {
+    /// screens.ui(ui);
+    /// # }
+    /// ```
+    pub fn ui(&mut self, ui: &mut Ui) {
+        let rect = ui.available_rect_before_wrap();
+        let mut action = ScreenAction::None;
+
+        if let Some(mut active) = self.active.take() {
+            active.elapsed += ui.input(|i| i.stable_dt);
+            let duration = self.transition.duration().max(f32::EPSILON);
+            let t = Easing::EaseInOut.apply(active.elapsed / duration);
+            let top = self.screens.len().checked_sub(1);
+            let below = if active.outgoing.is_none() && !active.backwards {
+                top.and_then(|i| i.checked_sub(1))
+            } else {
+                None
+            };
+
+            // Opacity and horizontal offset of the old and new screens.
+            let width = rect.width();
+            let sign = if active.backwards { -1.0 } else { 1.0 };
+            let (old_look, new_look) = match self.transition {
+                Transition::Fade { .. } => ((1.0 - 2.0 * t).max(0.0), (2.0 * t - 1.0).max(0.0)),
+                _ => (1.0, 1.0),
+            };
+            let (old_dx, new_dx) = match self.transition {
+                Transition::Slide { .. } => (-sign * width * t, sign * width * (1.0 - t)),
+                _ => (0.0, 0.0),
+            };
+
+            if old_look > 0.0 {
+                let old = match (&mut active.outgoing, below) {
+                    (Some(screen), _) => Some((screen, "screen_outgoing".to_owned())),
+                    (None, Some(i)) => Some((&mut self.screens[i], format!("screen_{}", i))),
+                    _ => None,
+                };
+                if let Some((screen, id)) = old {
+                    draw_screen(ui, rect, old_dx, old_look, &id, screen.as_mut());
+                }
+            }
+            if let (true, Some(i)) = (new_look > 0.0, top) {
+                draw_screen(ui, rect, new_dx, new_look, &format!("screen_{}", i), self.screens[i].as_mut());
+            }
+
+            if active.elapsed < duration {
+                self.active = Some(active);
+                ui.ctx().request_repaint();
+            }
+        } else if let Some(top) = self.screens.len().checked_sub(1) {
+            let screen = &mut self.screens[top];
+            let id = format!("screen_{}", top);
+            let mut child = ui.child_ui_with_id_source(rect, *ui.layout(), id);
+            action = screen.update(&mut child);
+        }
+        ui.advance_cursor_after_rect(rect);
+
+        match action {
+            ScreenAction::None => {}
+            ScreenAction::Push(screen) => self.push(screen),
+            ScreenAction::Pop => {
+                // Keep the popped screen around so it can be drawn while transitioning out.
+                if let Some(mut screen) = self.screens.pop() {
+                    screen.on_exit();
+                    self.begin(Some(screen), true);
+                }
+            }
+            ScreenAction::Replace(screen) => self.replace(screen),
+        }
+    }
+
+    /// Draws the screens in a `CentralPanel` filling the window. Call once per frame.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Context;
+    /// # use deckbuilder_eng::ui_screen::ScreenStack;
+    /// # fn demo(ctx: &Context, screens: &mut ScreenStack) {
+    /// screens.show(ctx);
+    /// # }
+    /// ```
+    pub fn show(&mut self, ctx: &Context) {
+        CentralPanel::default().show(ctx, |ui| self.ui(ui));
+    }
+}
+
+/// Draws a screen offset horizontally with the given opacity, ignoring its action.
+fn draw_screen(ui: &mut Ui, rect: Rect, dx: f32, opacity: f32, id: &str, screen: &mut dyn Screen) {
+    let mut child = ui.child_ui_with_id_source(rect.translate(Vec2::new(dx, 0.0)), *ui.layout(), id);
+    child.set_clip_rect(rect.intersect(ui.clip_rect()));
+    child.set_opacity(opacity);
+    let _ = screen.update(&mut child);
+}
+
+/// Size of the dissolve noise mask in pixels.
+const NOISE_SIZE: [usize; 2] = [64, 36];
+
+/// Look of a [`TransitionOverlay`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransitionEffect {
+    /// Fade the overlay color in and out.
+    Fade,
+    /// Sweep the overlay across the window, entering from the given side.
+    Wipe(WipeDirection),
+    /// Cover the window in noisy blotches.
+    Dissolve,
+}
+
+/// Side a [`TransitionEffect::Wipe`] enters from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WipeDirection {
+    /// Sweep from the left edge to the right.
+    Left,
+    /// Sweep from the right edge to the left.
+    Right,
+    /// Sweep from the top edge down.
+    Top,
+    /// Sweep from the bottom edge up.
+    Bottom,
+}
+
+/// Milestone reported by [`TransitionOverlay::show`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransitionEvent {
+    /// The window has just become fully covered.
+    Covered,
+    /// The window has just become fully visible again.
+    Finished,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OverlayPhase {
+    Idle,
+    Covering { then_uncover: bool },
+    Uncovering,
+}
+
+/// Full-window overlay that fades, wipes or dissolves over a set duration and runs a callback
+/// when it completes.
+pub struct TransitionOverlay {
+    effect: TransitionEffect,
+    duration: Duration,
+    color: Color32,
+    phase: OverlayPhase,
+    elapsed: f32,
+    covered: bool,
+    on_covered: Option<Box<dyn FnOnce()>>,
+    on_complete: Option<Box<dyn FnOnce()>>,
+    noise: Vec<u8>,
+    mask: Option<TextureHandle>,
+}
+
+impl TransitionOverlay {
+    /// Creates an idle overlay playing `effect` in black, taking `duration` per direction.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use deckbuilder_eng::ui_screen::{TransitionEffect, TransitionOverlay, WipeDirection};
+    /// let overlay = TransitionOverlay::new(TransitionEffect::Wipe(WipeDirection::Left), Duration::from_millis(400));
+    /// assert!(!overlay.is_active());
+    /// ```
+    pub fn new(effect: TransitionEffect, duration: Duration) -> Self {
+        Self {
+            effect,
+            duration,
+            color: Color32::BLACK,
+            phase: OverlayPhase::Idle,
+            elapsed: 0.0,
+            covered: false,
+            on_covered: None,
+            on_complete: None,
+            noise: noise_mask(),
+            mask: None,
+        }
+    }
+
+    /// Sets the overlay color (black by default).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use egui::Color32;
+    /// # use deckbuilder_eng::ui_screen::{TransitionEffect, TransitionOverlay};
+    /// let overlay = TransitionOverlay::new(TransitionEffect::Fade, Duration::from_millis(300)).color(Color32::WHITE);
+    /// ```
+    pub fn color(mut self, color: Color32) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Changes the effect used by the next transition.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use deckbuilder_eng::ui_screen::{TransitionEffect, TransitionOverlay};
+    /// let mut overlay = TransitionOverlay::new(TransitionEffect::Fade, Duration::from_millis(300));
+    /// overlay.set_effect(TransitionEffect::Dissolve);
+    /// ```
+    pub fn set_effect(&mut self, effect: TransitionEffect) {
+        self.effect = effect;
+    }
+
+    /// Covers the window and calls `on_complete` once it is fully covered. The overlay stays
+    /// until [`uncover`](Self::uncover) is called.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use deckbuilder_eng::ui_screen::{TransitionEffect, TransitionOverlay};
+    /// let mut overlay = TransitionOverlay::new(TransitionEffect::Fade, Duration::from_millis(300));
+    /// overlay.cover(|| println!("loading next act"));
+    /// assert!(overlay.is_active());
+    /// ```
+    pub fn cover(&mut self, on_complete: impl FnOnce() + 'static) {
+        self.start(OverlayPhase::Covering { then_uncover: false });
+        self.on_complete = Some(Box::new(on_complete));
+    }
+
+    /// Reveals the window again and calls `on_complete` once the overlay is gone.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use deckbuilder_eng::ui_screen::{TransitionEffect, TransitionOverlay};
+    /// let mut overlay = TransitionOverlay::new(TransitionEffect::Fade, Duration::from_millis(300));
+    /// overlay.uncover(|| println!("act 2 begins"));
+    /// ```
+    pub fn uncover(&mut self, on_complete: impl FnOnce() + 'static) {
+        self.start(OverlayPhase::Uncovering);
+        self.on_complete = Some(Box::new(on_complete));
+    }
+
+    /// Covers the window, calls `on_covered` (swap scenes there), then uncovers it again.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use deckbuilder_eng::ui_screen::{TransitionEffect, TransitionOverlay};
+    /// let mut overlay = TransitionOverlay::new(TransitionEffect::Dissolve, Duration::from_millis(500));
+    /// overlay.play(|| println!("switch to the map"));
+    /// ```
+    pub fn play(&mut self, on_covered: impl FnOnce() + 'static) {
+        self.start(OverlayPhase::Covering { then_uncover: true });
+        self.on_covered = Some(Box::new(on_covered));
+    }
+
+    /// Returns `true` while covering or uncovering.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use deckbuilder_eng::ui_screen::{TransitionEffect, TransitionOverlay};
+    /// let overlay = TransitionOverlay::new(TransitionEffect::Fade, Duration::from_millis(300));
+    /// assert!(!overlay.is_active());
+    /// ```
+    pub fn is_active(&self) -> bool {
+        self.phase != OverlayPhase::Idle
+    }
+
+    /// Returns how much of the window is covered, from 0.0 (clear) to 1.0 (covered).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use deckbuilder_eng::ui_screen::{TransitionEffect, TransitionOverlay};
+    /// let overlay = TransitionOverlay::new(TransitionEffect::Fade, Duration::from_millis(300));
+    /// assert_eq!(overlay.coverage(), 0.0);
+    /// ```
+    pub fn coverage(&self) -> f32 {
+        let t = (self.elapsed / self.duration.as_secs_f32().max(f32::EPSILON)).clamp(0.0, 1.0);
+        match self.phase {
+            OverlayPhase::Idle => {
+                if self.covered {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            OverlayPhase::Covering { .. } => Easing::EaseInOut.apply(t),
+            OverlayPhase::Uncovering => 1.0 - Easing::EaseInOut.apply(t),
+        }
+    }
+
+    /// Advances the transition by `dt` seconds, runs callbacks and returns the milestone
+    /// reached, if any.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use deckbuilder_eng::ui_screen::{TransitionEffect, TransitionEvent, TransitionOverlay};
+    /// let mut overlay = TransitionOverlay::new(TransitionEffect::Fade, Duration::from_millis(200));
+    /// overlay.play(|| {});
+    /// assert_eq!(overlay.update(0.25), Some(TransitionEvent::Covered));
+    /// assert_eq!(overlay.update(0.25), Some(TransitionEvent::Finished));
+    /// assert!(!overlay.is_active());
+    /// ```
+    pub fn update(&mut self, dt: f32) -> Option<TransitionEvent> {
+        if self.phase == OverlayPhase::Idle {
+            return None;
+        }
+        self.elapsed += dt;
+        if self.elapsed < self.duration.as_secs_f32() {
+            return None;
+        }
+        match self.phase {
+            OverlayPhase::Covering { then_uncover } => {
+                self.covered = true;
+                if let Some(on_covered) = self.on_covered.take() {
+                    on_covered();
+                }
+                if then_uncover {
+                    self.start(OverlayPhase::Uncovering);
+                } else {
+                    self.finish();
+                }
+                Some(TransitionEvent::Covered)
+            }
+            OverlayPhase::Uncovering => {
+                self.covered = false;
+                self.finish();
+                Some(TransitionEvent::Finished)
+            }
+            OverlayPhase::Idle => None,
+        }
+    }
+
+    /// Paints the overlay over the whole window without advancing time.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Context;
+    /// # use deckbuilder_eng::ui_screen::TransitionOverlay;
+    /// # fn demo(ctx: &Context, overlay: &mut TransitionOverlay) {
+    /// overlay.paint(ctx);
+    /// # }
+    /// ```
+    pub fn paint(&mut self, ctx: &Context) {
+        let coverage = self.coverage();
+        if coverage <= 0.0 {
+            return;
+        }
+        let screen = ctx.screen_rect();
+        let painter = ctx.layer_painter(LayerId::new(Order::Foreground, Id::new("deckbuilder_transition")));
+        match self.effect {
+            TransitionEffect::Fade => {
+                painter.rect_filled(screen, 0.0, self.color.gamma_multiply(coverage));
+            }
+            TransitionEffect::Wipe(direction) => {
+                // Covering grows from the entry side; uncovering keeps sweeping the same way.
+                let (from, to) = if self.phase == OverlayPhase::Uncovering {
+                    (1.0 - coverage, 1.0)
+                } else {
+                    (0.0, coverage)
+                };
+                let (l, r, t, b) = (screen.left(), screen.right(), screen.top(), screen.bottom());
+                let (w, h) = (screen.width(), screen.height());
+                let rect = match direction {
+                    WipeDirection::Left => Rect::from_x_y_ranges(l + w * from..=l + w * to, screen.y_range()),
+                    WipeDirection::Right => Rect::from_x_y_ranges(r - w * to..=r - w * from, screen.y_range()),
+                    WipeDirection::Top => Rect::from_x_y_ranges(screen.x_range(), t + h * from..=t + h * to),
+                    WipeDirection::Bottom => Rect::from_x_y_ranges(screen.x_range(), b - h * to..=b - h * from),
+                };
+                painter.rect_filled(rect, 0.0, self.color);
+            }
+            TransitionEffect::Dissolve => {
+                let image = self.dissolve_mask(coverage);
+                let mask = match &mut self.mask {
+                    Some(mask) => {
+                        mask.set(image, TextureOptions::LINEAR);
+                        mask
+                    }
+                    None => self.mask.insert(ctx.load_texture("deckbuilder_dissolve", image, TextureOptions::LINEAR)),
+                };
+                let uv = Rect::from_min_max(Pos2::ZERO, Pos2::new(1.0, 1.0));
+                painter.image(mask.id(), screen, uv, self.color);
+            }
+        }
+    }
+
+    /// Advances time by the frame delta, paints the overlay and requests a repaint while
+    /// active. Call once per frame, after drawing the screens.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Context;
+    /// # use deckbuilder_eng::ui_screen::{TransitionEvent, TransitionOverlay};
+    /// # fn demo(ctx: &Context, overlay: &mut TransitionOverlay) {
+    /// if overlay.show(ctx) == Some(TransitionEvent::Finished) {
+    ///     println!("transition done");
+    /// }
+    /// # }
+    /// ```
+    pub fn show(&mut self, ctx: &Context) -> Option<TransitionEvent> {
+        let event = self.update(ctx.input(|i| i.stable_dt));
+        self.paint(ctx);
+        if self.is_active() {
+            ctx.request_repaint();
+        }
+        event
+    }
+
+    fn start(&mut self, phase: OverlayPhase) {
+        self.phase = phase;
+        self.elapsed = 0.0;
+        self.on_covered = None;
+        self.on_complete = None;
+    }
+
+    fn finish(&mut self) {
+        self.phase = OverlayPhase::Idle;
+        if let Some(on_complete) = self.on_complete.take() {
+            on_complete();
+        }
+    }
+
+    /// Builds the white mask whose alpha covers noise cells below the coverage threshold.
+    fn dissolve_mask(&self, coverage: f32) -> ColorImage {
+        // Width of the soft edge between covered and clear cells, in noise units.
+        const SOFT: f32 = 0.15;
+        let threshold = coverage * (1.0 + SOFT);
+        let pixels = self
+            .noise
+            .iter()
+            .map(|&n| {
+                let alpha = ((threshold - n as f32 / 255.0) / SOFT).clamp(0.0, 1.0);
+                Color32::from_white_alpha((alpha * 255.0) as u8)
+            })
+            .collect();
+        ColorImage { size: NOISE_SIZE, pixels }
+    }
+}
+
+/// Deterministic per-cell noise values for the dissolve mask.
+fn noise_mask() -> Vec<u8> {
+    (0..NOISE_SIZE[0] * NOISE_SIZE[1])
+        .map(|i| {
+            // Integer hash (lowbias32) of the cell index.
+            let mut x = i as u32;
+            x ^= x >> 16;
+            x = x.wrapping_mul(0x7feb_352d);
+            x ^= x >> 15;
+            x = x.wrapping_mul(0x846c_a68b);
+            x ^= x >> 16;
+            (x >> 24) as u8
+        })
+        .collect()
+}
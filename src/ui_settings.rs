@@ -0,0 +1,226 @@
+//! Settings menu prefab for egui-based deckbuilder UIs.
+//!
+//! Provides [`ui_settings_panel`], a ready-made settings screen for [`GameSettings`] covering
+//! audio volumes, window mode, UI scale, language and keybindings, with Apply/Revert buttons,
+//! and [`ui_keybinding_editor`] for rebinding an [`InputMap`] on its own.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::audio::AudioManager;
+//! use deckbuilder_eng::settings::GameSettings;
+//! use deckbuilder_eng::ui_settings::ui_settings_panel;
+//! use egui::{CentralPanel, Context};
+//!
+//! fn settings_screen(ctx: &Context, settings: &mut GameSettings, audio: &mut AudioManager) {
+//!     CentralPanel::default().show(ctx, |ui| {
+//!         if ui_settings_panel(ui, settings).applied {
+//!             settings.apply_to_context(ctx);
+//!             let _ = settings.apply_audio(audio);
+//!         }
+//!     });
+//! }
+//! ```
+//!
+//! # Details
+//!
+//! - Edits go to a draft kept in egui memory; `settings` only changes when Apply is pressed.
+//!   Revert discards the draft.
+//! - To rebind a key, click its button and press the new key (Escape cancels). Setting a key
+//!   that is already used moves it to the new action.
+//! - See each function's documentation for usage and customization options.
+
+use egui::{ComboBox, Event, Grid, Key, Response, Slider, Ui};
+
+use crate::input::{Action, Binding, InputMap};
+use crate::settings::{GameSettings, WindowMode};
+
+/// Result of a [`ui_settings_panel`] frame.
+pub struct SettingsResponse {
+    /// Apply was pressed; `settings` now holds the new values.
+    pub applied: bool,
+    /// Revert was pressed; the draft was reset to `settings`.
+    pub reverted: bool,
+    /// The draft differs from the applied settings.
+    pub dirty: bool,
+}
+
+fn window_mode_label(mode: WindowMode) -> &'static str {
+    match mode {
+        WindowMode::Windowed => "Windowed",
+        WindowMode::Fullscreen => "Fullscreen",
+        WindowMode::Borderless => "Borderless",
+    }
+}
+
+/// Settings screen for audio, window mode, UI scale, language and keybindings with
+/// Apply/Revert semantics. Apply the returned settings to the audio manager and context
+/// when `applied` is set.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::settings::GameSettings;
+/// # use deckbuilder_eng::ui_settings::ui_settings_panel;
+/// # fn demo(ui: &mut Ui, settings: &mut GameSettings) {
+/// let res = ui_settings_panel(ui, settings);
+/// if res.applied {
+///     settings.apply_to_context(ui.ctx());
+/// }
+/// # }
+/// ```
+pub fn ui_settings_panel(ui: &mut Ui, settings: &mut GameSettings) -> SettingsResponse {
+    let draft_id = ui.make_persistent_id("settings_draft");
+    let mut draft = ui
+        .data(|d| d.get_temp::<GameSettings>(draft_id))
+        .unwrap_or_else(|| settings.clone());
+
+    ui.heading("Audio");
+    Grid::new(draft_id.with("audio")).num_columns(2).show(ui, |ui| {
+        ui.label("Master");
+        ui.add(Slider::new(&mut draft.master_volume, 0.0..=1.0));
+        ui.end_row();
+        ui.label("Music");
+        ui.add(Slider::new(&mut draft.music_volume, 0.0..=1.0));
+        ui.end_row();
+        ui.label("Sound effects");
+        ui.add(Slider::new(&mut draft.sfx_volume, 0.0..=1.0));
+        ui.end_row();
+    });
+
+    ui.separator();
+    ui.heading("Display");
+    Grid::new(draft_id.with("display")).num_columns(2).show(ui, |ui| {
+        ui.label("Window mode");
+        ComboBox::from_id_source(draft_id.with("window_mode"))
+            .selected_text(window_mode_label(draft.window_mode))
+            .show_ui(ui, |ui| {
+                for mode in [WindowMode::Windowed, WindowMode::Fullscreen, WindowMode::Borderless] {
+                    ui.selectable_value(&mut draft.window_mode, mode, window_mode_label(mode));
+                }
+            });
+        ui.end_row();
+        ui.label("UI scale");
+        ui.add(Slider::new(&mut draft.ui_scale, 0.5..=2.0).step_by(0.05));
+        ui.end_row();
+        ui.label("Language");
+        ComboBox::from_id_source(draft_id.with("language"))
+            .selected_text(draft.language.as_str())
+            .show_ui(ui, |ui| {
+                for language in draft.available_languages.clone() {
+                    let label = language.clone();
+                    ui.selectable_value(&mut draft.language, language, label);
+                }
+            });
+        ui.end_row();
+    });
+
+    ui.separator();
+    ui.heading("Keybindings");
+    ui_keybinding_editor(ui, &mut draft.keybindings);
+
+    ui.separator();
+    let dirty = draft != *settings;
+    let mut applied = false;
+    let mut reverted = false;
+    ui.horizontal(|ui| {
+        if ui.add_enabled(dirty, egui::Button::new("Apply")).clicked() {
+            *settings = draft.clone();
+            applied = true;
+        }
+        if ui.add_enabled(dirty, egui::Button::new("Revert")).clicked() {
+            draft = settings.clone();
+            reverted = true;
+        }
+        if ui.button("Defaults").clicked() {
+            draft = GameSettings {
+                available_languages: draft.available_languages.clone(),
+                ..GameSettings::default()
+            };
+        }
+    });
+
+    ui.data_mut(|d| d.insert_temp(draft_id, draft));
+    SettingsResponse {
+        applied,
+        reverted,
+        dirty: dirty && !applied && !reverted,
+    }
+}
+
+fn binding_label(binding: Binding) -> String {
+    match binding {
+        Binding::Key(key) => key.name().to_owned(),
+        Binding::Gamepad(button) => format!("{:?}", button),
+    }
+}
+
+/// Table of all actions with their bindings. Clicking an action's key button waits for the
+/// next key press and rebinds it; Escape cancels. Gamepad bindings are listed read-only.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::input::InputMap;
+/// # use deckbuilder_eng::ui_settings::ui_keybinding_editor;
+/// # fn demo(ui: &mut Ui, input: &mut InputMap) {
+/// if ui_keybinding_editor(ui, input).changed() {
+///     println!("Bindings changed");
+/// }
+/// # }
+/// ```
+pub fn ui_keybinding_editor(ui: &mut Ui, input: &mut InputMap) -> Response {
+    let id = ui.make_persistent_id("keybinding_editor");
+    let mut waiting = ui.data(|d| d.get_temp::<Option<Action>>(id)).flatten();
+    let mut changed = false;
+
+    if let Some(action) = waiting {
+        let pressed = ui.input(|i| {
+            i.events.iter().find_map(|e| match e {
+                Event::Key { key, pressed: true, .. } => Some(*key),
+                _ => None,
+            })
+        });
+        match pressed {
+            Some(Key::Escape) => waiting = None,
+            Some(key) => {
+                input.rebind_key(action, key);
+                waiting = None;
+                changed = true;
+            }
+            None => {}
+        }
+    }
+
+    let mut response = Grid::new(id.with("grid"))
+        .num_columns(3)
+        .striped(true)
+        .show(ui, |ui| {
+            for action in Action::ALL {
+                ui.label(action.label());
+                let text = if waiting == Some(action) {
+                    "Press a key...".to_owned()
+                } else {
+                    input.key_for(action).map_or_else(|| "-".to_owned(), |k| k.name().to_owned())
+                };
+                if ui.button(text).clicked() {
+                    waiting = Some(action);
+                }
+                let pads: Vec<String> = input
+                    .bindings_for(action)
+                    .into_iter()
+                    .filter(|b| matches!(b, Binding::Gamepad(_)))
+                    .map(binding_label)
+                    .collect();
+                ui.weak(pads.join(", "));
+                ui.end_row();
+            }
+        })
+        .response;
+
+    ui.data_mut(|d| d.insert_temp(id, waiting));
+    if changed {
+        response.mark_changed();
+    }
+    response
+}
@@ -0,0 +1,198 @@
+//! Shop screen prefab for egui-based deckbuilder UIs.
+//!
+//! Provides [`ui_shop`], which renders a [`Shop`]'s cards, relics, card removal service and
+//! reroll button with prices, grays out what the player can't afford, asks for confirmation
+//! and returns [`ShopAction`]s for the game layer to apply.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::shop::{Shop, ShopAction};
+//! use deckbuilder_eng::ui_shop::{ui_shop, ShopState};
+//! use egui::{CentralPanel, Context};
+//!
+//! fn shop_screen(ctx: &Context, shop: &mut Shop, state: &mut ShopState) -> bool {
+//!     let actions = CentralPanel::default().show(ctx, |ui| ui_shop(ui, shop, state)).inner;
+//!     for action in actions {
+//!         match action {
+//!             ShopAction::Buy(index) => {
+//!                 if let Some(offer) = shop.buy(index) {
+//!                     println!("Bought {}", offer.name());
+//!                 }
+//!             }
+//!             ShopAction::RemoveCard => {
+//!                 shop.use_removal();
+//!             }
+//!             ShopAction::Reroll => println!("Reroll the offers"),
+//!             ShopAction::Leave => return true,
+//!         }
+//!     }
+//!     false
+//! }
+//! ```
+//!
+//! # Details
+//!
+//! - The shop itself is not modified; apply the returned actions, e.g. with `Shop::buy`.
+//! - Paid actions go through a Yes/No dialog unless `ShopState::confirm_purchases` is off.
+//! - See each function's documentation for usage and customization options.
+
+use egui::{Align, Button, Color32, Frame, Layout, RichText, Rounding, Sense, Ui, Vec2};
+
+use crate::shop::{Shop, ShopAction, ShopOffer};
+use crate::ui_card::{ui_card, CardVisuals};
+use crate::ui_overlay::ui_confirm_dialog;
+
+const GOLD: Color32 = Color32::from_rgb(240, 200, 80);
+
+/// UI state of a [`ui_shop`] screen.
+#[derive(Debug, Clone)]
+pub struct ShopState {
+    /// Ask before buying, removing a card or rerolling.
+    pub confirm_purchases: bool,
+    /// Size of the cards on sale.
+    pub card_size: Vec2,
+    pending: Option<ShopAction>,
+}
+
+impl Default for ShopState {
+    fn default() -> Self {
+        Self {
+            confirm_purchases: true,
+            card_size: Vec2::new(120.0, 170.0),
+            pending: None,
+        }
+    }
+}
+
+fn price_label(ui: &mut Ui, price: u32, affordable: bool) {
+    let color = if affordable { GOLD } else { ui.visuals().error_fg_color };
+    ui.label(RichText::new(format!("{} gold", price)).color(color).strong());
+}
+
+/// Shop screen. Returns the actions the player confirmed this frame.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::shop::Shop;
+/// # use deckbuilder_eng::ui_shop::{ui_shop, ShopState};
+/// # fn demo(ui: &mut Ui, shop: &Shop, state: &mut ShopState) {
+/// for action in ui_shop(ui, shop, state) {
+///     println!("{:?}", action);
+/// }
+/// # }
+/// ```
+pub fn ui_shop(ui: &mut Ui, shop: &Shop, state: &mut ShopState) -> Vec<ShopAction> {
+    let mut requested = None;
+
+    ui.horizontal(|ui| {
+        ui.heading("Shop");
+        ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+            ui.label(RichText::new(format!("{} gold", shop.gold)).color(GOLD).size(18.0));
+        });
+    });
+    ui.separator();
+
+    let visuals = CardVisuals { size: state.card_size, ..CardVisuals::default() };
+    ui.horizontal_wrapped(|ui| {
+        for (i, item) in shop.items.iter().enumerate() {
+            let ShopOffer::Card(card) = &item.offer else {
+                continue;
+            };
+            let affordable = shop.can_afford(item.price);
+            ui.vertical(|ui| {
+                ui.add_enabled_ui(affordable && !item.sold, |ui| {
+                    if ui_card(ui, card, &visuals).clicked && affordable && !item.sold {
+                        requested = Some(ShopAction::Buy(i));
+                    }
+                });
+                if item.sold {
+                    ui.weak("Sold");
+                } else {
+                    price_label(ui, item.price, affordable);
+                }
+            });
+        }
+    });
+
+    ui.add_space(8.0);
+    ui.horizontal_wrapped(|ui| {
+        for (i, item) in shop.items.iter().enumerate() {
+            let ShopOffer::Relic(relic) = &item.offer else {
+                continue;
+            };
+            let affordable = shop.can_afford(item.price);
+            ui.vertical(|ui| {
+                ui.add_enabled_ui(affordable && !item.sold, |ui| {
+                    let frame = Frame::group(ui.style()).rounding(Rounding::same(6.0)).show(ui, |ui| {
+                        ui.set_width(state.card_size.x);
+                        ui.strong(&relic.name);
+                        ui.small(&relic.description);
+                    });
+                    let response = ui.interact(frame.response.rect, ui.id().with(("relic", i)), Sense::click());
+                    if response.clicked() && affordable && !item.sold {
+                        requested = Some(ShopAction::Buy(i));
+                    }
+                });
+                if item.sold {
+                    ui.weak("Sold");
+                } else {
+                    price_label(ui, item.price, affordable);
+                }
+            });
+        }
+    });
+
+    ui.add_space(8.0);
+    ui.separator();
+    ui.horizontal(|ui| {
+        if let Some(price) = shop.removal_price {
+            let affordable = shop.can_afford(price);
+            let button = ui.add_enabled(affordable, Button::new(format!("Remove a card ({} gold)", price)));
+            if button.clicked() {
+                requested = Some(ShopAction::RemoveCard);
+            }
+        }
+        if let Some(price) = shop.reroll_price {
+            let affordable = shop.can_afford(price);
+            if ui.add_enabled(affordable, Button::new(format!("Reroll ({} gold)", price))).clicked() {
+                requested = Some(ShopAction::Reroll);
+            }
+        }
+        ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+            if ui.button("Leave").clicked() {
+                requested = Some(ShopAction::Leave);
+            }
+        });
+    });
+
+    let mut actions = Vec::new();
+    match requested {
+        Some(ShopAction::Leave) => actions.push(ShopAction::Leave),
+        Some(action) if state.confirm_purchases => state.pending = Some(action),
+        Some(action) => actions.push(action),
+        None => {}
+    }
+
+    if let Some(action) = state.pending {
+        let (title, price) = match action {
+            ShopAction::Buy(i) => match shop.items.get(i) {
+                Some(item) => (format!("Buy {}?", item.offer.name()), item.price),
+                None => ("Buy?".to_owned(), 0),
+            },
+            ShopAction::RemoveCard => ("Remove a card?".to_owned(), shop.removal_price.unwrap_or(0)),
+            ShopAction::Reroll => ("Reroll the shop?".to_owned(), shop.reroll_price.unwrap_or(0)),
+            ShopAction::Leave => ("Leave?".to_owned(), 0),
+        };
+        match ui_confirm_dialog(ui.ctx(), &title, &format!("This costs {} gold.", price)) {
+            Some(true) => {
+                actions.push(action);
+                state.pending = None;
+            }
+            Some(false) => state.pending = None,
+            None => {}
+        }
+    }
+    actions
+}
@@ -0,0 +1,480 @@
+//! Sprite sheet animation for egui-based deckbuilder UIs.
+//!
+//! Provides a [`SpriteSheet`] that splits a texture into a grid of frames, an [`AnimatedSprite`]
+//! that plays named clips (frame ranges with their own fps, looping or ping-pong) from it, and
+//! [`ui_animated_sprite`], which advances and draws a sprite in one call.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::ui_image::load_texture_from_path;
+//! use deckbuilder_eng::ui_sprite::{ui_animated_sprite, AnimatedSprite, ClipMode, SpriteClip, SpriteEvent, SpriteSheet};
+//! use egui::{CentralPanel, Context, Vec2};
+//!
+//! fn make_slime(ctx: &Context) -> deckbuilder_eng::Result<AnimatedSprite> {
+//!     let texture = load_texture_from_path(ctx, "assets/slime.png")?;
+//!     let mut sprite = AnimatedSprite::new(SpriteSheet::new(texture, 8, 2));
+//!     sprite.add_clip("idle", SpriteClip::new(0, 7, 8.0));
+//!     sprite.add_clip("attack", SpriteClip::new(8, 15, 16.0).mode(ClipMode::Once));
+//!     sprite.play("idle");
+//!     Ok(sprite)
+//! }
+//!
+//! fn enemy_ui(ctx: &Context, slime: &mut AnimatedSprite) {
+//!     CentralPanel::default().show(ctx, |ui| {
+//!         if ui.button("Attack").clicked() {
+//!             slime.play("attack");
+//!         }
+//!         let (_, event) = ui_animated_sprite(ui, slime, Vec2::new(96.0, 96.0));
+//!         if let Some(SpriteEvent::Finished(clip)) = event {
+//!             if clip == "attack" {
+//!                 slime.play("idle");
+//!             }
+//!         }
+//!     });
+//! }
+//! ```
+//!
+//! # Details
+//!
+//! - Frames are numbered row by row, starting at the top-left cell of the sheet.
+//! - `update` takes the elapsed seconds, so sprites can also be driven outside egui.
+//! - `ui_animated_sprite` uses the game time of [`game_dt`] and requests repaints while a clip plays.
+//! - See each function's documentation for usage and customization options.
+
+use std::collections::HashMap;
+
+use egui::{Color32, Image, Pos2, Rect, Response, Sense, TextureHandle, Ui, Vec2};
+
+use crate::game_time::game_dt;
+use crate::ui_image::ImageFlip;
+
+/// A texture split into a grid of equally sized frames.
+#[derive(Clone)]
+pub struct SpriteSheet {
+    /// The sheet texture.
+    pub texture: TextureHandle,
+    /// Number of frame columns.
+    pub columns: usize,
+    /// Number of frame rows.
+    pub rows: usize,
+}
+
+impl SpriteSheet {
+    /// Creates a sheet of `columns` x `rows` frames.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::{Color32, ColorImage, Context};
+    /// # use deckbuilder_eng::ui_sprite::SpriteSheet;
+    /// let ctx = Context::default();
+    /// let texture = ctx.load_texture("sheet", ColorImage::new([64, 32], Color32::WHITE), Default::default());
+    /// let sheet = SpriteSheet::new(texture, 4, 2);
+    /// assert_eq!(sheet.frame_count(), 8);
+    /// ```
+    pub fn new(texture: TextureHandle, columns: usize, rows: usize) -> Self {
+        Self {
+            texture,
+            columns: columns.max(1),
+            rows: rows.max(1),
+        }
+    }
+
+    /// Returns the number of frames in the sheet.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_sprite::SpriteSheet;
+    /// # fn demo(sheet: &SpriteSheet) {
+    /// println!("{} frames", sheet.frame_count());
+    /// # }
+    /// ```
+    pub fn frame_count(&self) -> usize {
+        self.columns * self.rows
+    }
+
+    /// Returns the size of one frame in pixels.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::{Color32, ColorImage, Context, Vec2};
+    /// # use deckbuilder_eng::ui_sprite::SpriteSheet;
+    /// let ctx = Context::default();
+    /// let texture = ctx.load_texture("sheet", ColorImage::new([64, 32], Color32::WHITE), Default::default());
+    /// assert_eq!(SpriteSheet::new(texture, 4, 2).frame_size(), Vec2::new(16.0, 16.0));
+    /// ```
+    pub fn frame_size(&self) -> Vec2 {
+        self.texture.size_vec2() / Vec2::new(self.columns as f32, self.rows as f32)
+    }
+
+    /// Returns the UV rect of `frame` (clamped to the last frame).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::{Color32, ColorImage, Context, Pos2};
+    /// # use deckbuilder_eng::ui_sprite::SpriteSheet;
+    /// let ctx = Context::default();
+    /// let texture = ctx.load_texture("sheet", ColorImage::new([64, 32], Color32::WHITE), Default::default());
+    /// let sheet = SpriteSheet::new(texture, 4, 2);
+    /// assert_eq!(sheet.frame_uv(5).min, Pos2::new(0.25, 0.5));
+    /// ```
+    pub fn frame_uv(&self, frame: usize) -> Rect {
+        let frame = frame.min(self.frame_count() - 1);
+        let cell = Vec2::new(1.0 / self.columns as f32, 1.0 / self.rows as f32);
+        let min = Pos2::new((frame % self.columns) as f32 * cell.x, (frame / self.columns) as f32 * cell.y);
+        Rect::from_min_size(min, cell)
+    }
+}
+
+/// How a clip continues after its last frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ClipMode {
+    /// Restarts from the first frame.
+    #[default]
+    Loop,
+    /// Plays backwards to the first frame, then forwards again.
+    PingPong,
+    /// Stops on the last frame.
+    Once,
+}
+
+/// A named animation: an inclusive range of sheet frames played at a fixed rate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpriteClip {
+    /// First frame of the clip.
+    pub first: usize,
+    /// Last frame of the clip (inclusive).
+    pub last: usize,
+    /// Frames per second.
+    pub fps: f32,
+    /// What happens after the last frame.
+    pub mode: ClipMode,
+}
+
+impl SpriteClip {
+    /// Creates a looping clip of frames `first..=last` at `fps`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_sprite::SpriteClip;
+    /// let idle = SpriteClip::new(0, 7, 8.0);
+    /// assert_eq!(idle.frame_count(), 8);
+    /// ```
+    pub fn new(first: usize, last: usize, fps: f32) -> Self {
+        Self {
+            first: first.min(last),
+            last: first.max(last),
+            fps,
+            mode: ClipMode::Loop,
+        }
+    }
+
+    /// Sets the clip mode.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_sprite::{ClipMode, SpriteClip};
+    /// let attack = SpriteClip::new(8, 15, 16.0).mode(ClipMode::Once);
+    /// ```
+    pub fn mode(mut self, mode: ClipMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Returns the number of frames in the clip.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_sprite::SpriteClip;
+    /// assert_eq!(SpriteClip::new(3, 3, 1.0).frame_count(), 1);
+    /// ```
+    pub fn frame_count(&self) -> usize {
+        self.last - self.first + 1
+    }
+}
+
+/// Playback events returned by [`AnimatedSprite::update`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpriteEvent {
+    /// A looping or ping-pong clip completed a cycle.
+    Looped(String),
+    /// A [`ClipMode::Once`] clip reached its last frame.
+    Finished(String),
+}
+
+/// A sprite playing named clips from a [`SpriteSheet`].
+#[derive(Clone)]
+pub struct AnimatedSprite {
+    sheet: SpriteSheet,
+    clips: HashMap<String, SpriteClip>,
+    current: Option<String>,
+    /// Offset into the current clip.
+    step: usize,
+    reverse: bool,
+    elapsed: f32,
+    playing: bool,
+    /// Playback speed multiplier (`1.0` = normal).
+    pub speed: f32,
+    /// Mirroring of the drawn frame, e.g. [`ImageFlip::Horizontal`] for a sprite facing left.
+    pub flip: ImageFlip,
+}
+
+impl AnimatedSprite {
+    /// Creates a sprite without clips; it shows the first frame of the sheet.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_sprite::{AnimatedSprite, SpriteSheet};
+    /// # fn demo(sheet: SpriteSheet) {
+    /// let sprite = AnimatedSprite::new(sheet);
+    /// assert_eq!(sprite.frame(), 0);
+    /// # }
+    /// ```
+    pub fn new(sheet: SpriteSheet) -> Self {
+        Self {
+            sheet,
+            clips: HashMap::new(),
+            current: None,
+            step: 0,
+            reverse: false,
+            elapsed: 0.0,
+            playing: false,
+            speed: 1.0,
+            flip: ImageFlip::None,
+        }
+    }
+
+    /// Adds (or replaces) the clip called `name`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_sprite::{AnimatedSprite, ClipMode, SpriteClip};
+    /// # fn demo(sprite: &mut AnimatedSprite) {
+    /// sprite
+    ///     .add_clip("idle", SpriteClip::new(0, 3, 6.0))
+    ///     .add_clip("hit", SpriteClip::new(4, 6, 12.0).mode(ClipMode::Once));
+    /// # }
+    /// ```
+    pub fn add_clip(&mut self, name: impl Into<String>, clip: SpriteClip) -> &mut Self {
+        self.clips.insert(name.into(), clip);
+        self
+    }
+
+    /// Starts the clip called `name` from its first frame. Unknown names are ignored; returns
+    /// `true` if the clip exists.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_sprite::AnimatedSprite;
+    /// # fn demo(sprite: &mut AnimatedSprite) {
+    /// if !sprite.play("attack") {
+    ///     sprite.play("idle");
+    /// }
+    /// # }
+    /// ```
+    pub fn play(&mut self, name: &str) -> bool {
+        if !self.clips.contains_key(name) {
+            return false;
+        }
+        self.current = Some(name.to_string());
+        self.step = 0;
+        self.reverse = false;
+        self.elapsed = 0.0;
+        self.playing = true;
+        true
+    }
+
+    /// Pauses playback on the current frame.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_sprite::AnimatedSprite;
+    /// # fn demo(sprite: &mut AnimatedSprite) {
+    /// sprite.pause();
+    /// assert!(!sprite.is_playing());
+    /// # }
+    /// ```
+    pub fn pause(&mut self) {
+        self.playing = false;
+    }
+
+    /// Resumes a paused clip.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_sprite::AnimatedSprite;
+    /// # fn demo(sprite: &mut AnimatedSprite) {
+    /// sprite.resume();
+    /// # }
+    /// ```
+    pub fn resume(&mut self) {
+        self.playing = self.current.is_some();
+    }
+
+    /// Returns `true` while a clip is advancing.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_sprite::AnimatedSprite;
+    /// # fn demo(sprite: &AnimatedSprite) {
+    /// let animating = sprite.is_playing();
+    /// # }
+    /// ```
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    /// Returns the name of the current clip.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_sprite::AnimatedSprite;
+    /// # fn demo(sprite: &AnimatedSprite) {
+    /// if sprite.current_clip() == Some("idle") {
+    ///     println!("Idling");
+    /// }
+    /// # }
+    /// ```
+    pub fn current_clip(&self) -> Option<&str> {
+        self.current.as_deref()
+    }
+
+    /// Returns the sheet frame currently shown.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_sprite::AnimatedSprite;
+    /// # fn demo(sprite: &AnimatedSprite) {
+    /// println!("Frame {}", sprite.frame());
+    /// # }
+    /// ```
+    pub fn frame(&self) -> usize {
+        self.clip().map_or(0, |clip| clip.first + self.step)
+    }
+
+    /// Returns the sheet the sprite plays from.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_sprite::AnimatedSprite;
+    /// # fn demo(sprite: &AnimatedSprite) {
+    /// let frame_size = sprite.sheet().frame_size();
+    /// # }
+    /// ```
+    pub fn sheet(&self) -> &SpriteSheet {
+        &self.sheet
+    }
+
+    fn clip(&self) -> Option<SpriteClip> {
+        self.current.as_ref().and_then(|name| self.clips.get(name)).copied()
+    }
+
+    /// Advances playback by `dt` seconds. Returns an event when the clip loops or finishes.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::{Color32, ColorImage, Context};
+    /// # use deckbuilder_eng::ui_sprite::{AnimatedSprite, ClipMode, SpriteClip, SpriteEvent, SpriteSheet};
+    /// let ctx = Context::default();
+    /// let texture = ctx.load_texture("sheet", ColorImage::new([64, 16], Color32::WHITE), Default::default());
+    /// let mut sprite = AnimatedSprite::new(SpriteSheet::new(texture, 4, 1));
+    /// sprite.add_clip("hit", SpriteClip::new(0, 3, 10.0).mode(ClipMode::Once));
+    /// sprite.play("hit");
+    /// assert_eq!(sprite.update(0.15), None);
+    /// assert_eq!(sprite.frame(), 1);
+    /// assert_eq!(sprite.update(0.3), Some(SpriteEvent::Finished("hit".into())));
+    /// assert_eq!(sprite.frame(), 3);
+    /// ```
+    pub fn update(&mut self, dt: f32) -> Option<SpriteEvent> {
+        if !self.playing {
+            return None;
+        }
+        let clip = self.clip()?;
+        let name = self.current.clone()?;
+        if clip.fps <= 0.0 {
+            return None;
+        }
+        self.elapsed += dt.max(0.0) * self.speed.max(0.0);
+        let frame_time = 1.0 / clip.fps;
+        let last = clip.frame_count() - 1;
+        let mut event = None;
+        while self.elapsed >= frame_time {
+            self.elapsed -= frame_time;
+            match clip.mode {
+                ClipMode::Loop => {
+                    if self.step >= last {
+                        self.step = 0;
+                        event = Some(SpriteEvent::Looped(name.clone()));
+                    } else {
+                        self.step += 1;
+                    }
+                }
+                ClipMode::PingPong => {
+                    if last == 0 {
+                        event = Some(SpriteEvent::Looped(name.clone()));
+                    } else if self.reverse {
+                        self.step -= 1;
+                        if self.step == 0 {
+                            self.reverse = false;
+                            event = Some(SpriteEvent::Looped(name.clone()));
+                        }
+                    } else {
+                        self.step += 1;
+                        if self.step >= last {
+                            self.reverse = true;
+                        }
+                    }
+                }
+                ClipMode::Once => {
+                    self.step = (self.step + 1).min(last);
+                    if self.step == last {
+                        self.playing = false;
+                        self.elapsed = 0.0;
+                        return Some(SpriteEvent::Finished(name));
+                    }
+                }
+            }
+        }
+        event
+    }
+
+    /// Paints the current frame into `rect`, multiplied by `tint`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::{Color32, Painter, Rect};
+    /// # use deckbuilder_eng::ui_sprite::AnimatedSprite;
+    /// # fn demo(painter: &Painter, sprite: &AnimatedSprite, rect: Rect) {
+    /// sprite.paint(painter, rect, Color32::WHITE);
+    /// # }
+    /// ```
+    pub fn paint(&self, painter: &egui::Painter, rect: Rect, tint: Color32) {
+        let uv = self.flip.apply(self.sheet.frame_uv(self.frame()));
+        painter.image(self.sheet.texture.id(), rect, uv, tint);
+    }
+}
+
+/// Advances `sprite` by the game time of this frame and shows its current frame at `size`. Returns the
+/// response and the playback event of this frame, if any.
+///
+/// # Example
+/// ```rust
+/// # use egui::{Ui, Vec2};
+/// # use deckbuilder_eng::ui_sprite::{ui_animated_sprite, AnimatedSprite, SpriteEvent};
+/// # fn demo(ui: &mut Ui, sprite: &mut AnimatedSprite) {
+/// let (response, event) = ui_animated_sprite(ui, sprite, Vec2::new(64.0, 64.0));
+/// if let Some(SpriteEvent::Finished(_)) = event {
+///     sprite.play("idle");
+/// }
+/// # }
+/// ```
+pub fn ui_animated_sprite(ui: &mut Ui, sprite: &mut AnimatedSprite, size: Vec2) -> (Response, Option<SpriteEvent>) {
+    let dt = game_dt(ui.ctx());
+    let event = sprite.update(dt);
+    if sprite.is_playing() {
+        ui.ctx().request_repaint();
+    }
+    let uv = sprite.flip.apply(sprite.sheet.frame_uv(sprite.frame()));
+    let response = ui.add(Image::new((sprite.sheet.texture.id(), size)).uv(uv).sense(Sense::hover()));
+    (response, event)
+}
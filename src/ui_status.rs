@@ -0,0 +1,175 @@
+//! Status effect widgets for egui-based deckbuilder UIs.
+//!
+//! Provides [`ui_status_icons`], a row of buff/debuff icons with stack numbers, turns-remaining
+//! pips and glossary tooltips. Icons are looked up by status name in a
+//! [`crate::ui_text::IconRegistry`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::status::StatusContainer;
+//! use deckbuilder_eng::ui_status::ui_status_icons;
+//! use deckbuilder_eng::ui_text::IconRegistry;
+//! use egui::{Context, TextureHandle};
+//!
+//! fn build_icons(strength: TextureHandle) -> IconRegistry {
+//!     let mut icons = IconRegistry::new();
+//!     icons.insert("Strength", strength);
+//!     icons
+//! }
+//!
+//! fn player_statuses(ctx: &Context, statuses: &StatusContainer, icons: &IconRegistry) {
+//!     egui::CentralPanel::default().show(ctx, |ui| {
+//!         ui_status_icons(ui, statuses, icons);
+//!     });
+//! }
+//! ```
+//!
+//! # Details
+//!
+//! - Statuses without a registered icon are drawn as a colored disc with their first letter.
+//! - Buffs get a green ring, debuffs a red one.
+//! - Tooltips come from [`crate::status::default_glossary`] unless a glossary is given in
+//!   [`StatusIconStyle`]; statuses missing from it show their stacks and turns.
+//! - See each function's documentation for usage and customization options.
+
+use egui::{Align2, Color32, FontId, Pos2, Rect, Response, Sense, Stroke, Ui, Vec2};
+
+use crate::card::Keyword;
+use crate::status::{default_glossary, Status, StatusContainer, StatusKind};
+use crate::ui_text::IconRegistry;
+use crate::ui_tooltip::ui_keyword_tooltips;
+
+/// Size, colors and glossary of [`ui_status_icons_ex`].
+#[derive(Debug, Clone, Copy)]
+pub struct StatusIconStyle {
+    /// Icon size in points.
+    pub size: f32,
+    /// Most turns-remaining pips drawn under an icon.
+    pub max_pips: u32,
+    /// Ring color of buffs.
+    pub buff_color: Color32,
+    /// Ring color of debuffs.
+    pub debuff_color: Color32,
+    /// Tooltip text of each status, matched by keyword name. A custom glossary is built once,
+    /// e.g. in a `static` or with [`Vec::leak`], and shared by every style.
+    pub glossary: &'static [Keyword],
+}
+
+impl Default for StatusIconStyle {
+    fn default() -> Self {
+        Self {
+            size: 28.0,
+            max_pips: 5,
+            buff_color: Color32::from_rgb(90, 200, 110),
+            debuff_color: Color32::from_rgb(220, 70, 70),
+            glossary: default_glossary(),
+        }
+    }
+}
+
+fn paint_status(ui: &Ui, rect: Rect, status: &Status, icons: &IconRegistry, style: &StatusIconStyle) {
+    let painter = ui.painter();
+    let icon_rect = Rect::from_min_size(rect.min, Vec2::splat(style.size));
+    let ring = match status.kind {
+        StatusKind::Buff => style.buff_color,
+        StatusKind::Debuff => style.debuff_color,
+    };
+    match icons.get(&status.name) {
+        Some(texture) => {
+            painter.image(
+                texture.id(),
+                icon_rect,
+                Rect::from_min_max(Pos2::ZERO, Pos2::new(1.0, 1.0)),
+                Color32::WHITE,
+            );
+        }
+        None => {
+            let letter: String = status.name.chars().take(1).collect();
+            painter.circle_filled(icon_rect.center(), style.size / 2.0 - 1.0, ring.gamma_multiply(0.6));
+            painter.text(
+                icon_rect.center(),
+                Align2::CENTER_CENTER,
+                letter,
+                FontId::proportional(style.size * 0.5),
+                Color32::WHITE,
+            );
+        }
+    }
+    painter.circle_stroke(icon_rect.center(), style.size / 2.0 - 1.0, Stroke::new(1.5, ring));
+
+    if status.stacks != 1 {
+        let pos = icon_rect.right_bottom() + Vec2::new(1.0, 1.0);
+        let font = FontId::proportional(style.size * 0.4);
+        painter.text(pos + Vec2::splat(1.0), Align2::RIGHT_BOTTOM, status.stacks.to_string(), font.clone(), Color32::BLACK);
+        painter.text(pos, Align2::RIGHT_BOTTOM, status.stacks.to_string(), font, Color32::WHITE);
+    }
+
+    if let Some(turns) = status.turns {
+        let pips = turns.min(style.max_pips);
+        let radius = 2.0;
+        let spacing = radius * 2.0 + 2.0;
+        let y = icon_rect.bottom() + 4.0;
+        let x0 = icon_rect.center().x - spacing * (pips as f32 - 1.0) / 2.0;
+        for i in 0..pips {
+            painter.circle_filled(Pos2::new(x0 + spacing * i as f32, y), radius, ring);
+        }
+    }
+}
+
+/// Row of status icons using the default style. Hovering an icon shows the status's glossary
+/// entry. Returns the row's response.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::status::{Status, StatusContainer};
+/// # use deckbuilder_eng::ui_status::ui_status_icons;
+/// # use deckbuilder_eng::ui_text::IconRegistry;
+/// # fn demo(ui: &mut Ui) {
+/// let mut statuses = StatusContainer::new();
+/// statuses.apply(Status::debuff("Weak", 1).turns(2));
+/// ui_status_icons(ui, &statuses, &IconRegistry::new());
+/// # }
+/// ```
+pub fn ui_status_icons(ui: &mut Ui, statuses: &StatusContainer, icons: &IconRegistry) -> Response {
+    ui_status_icons_ex(ui, statuses, icons, &StatusIconStyle::default())
+}
+
+/// Status icon row with custom size, pip count and colors.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::status::StatusContainer;
+/// # use deckbuilder_eng::ui_status::{ui_status_icons_ex, StatusIconStyle};
+/// # use deckbuilder_eng::ui_text::IconRegistry;
+/// # fn demo(ui: &mut Ui, statuses: &StatusContainer, icons: &IconRegistry) {
+/// let style = StatusIconStyle { size: 20.0, ..Default::default() };
+/// ui_status_icons_ex(ui, statuses, icons, &style);
+/// # }
+/// ```
+pub fn ui_status_icons_ex(
+    ui: &mut Ui,
+    statuses: &StatusContainer,
+    icons: &IconRegistry,
+    style: &StatusIconStyle,
+) -> Response {
+    ui.horizontal(|ui| {
+        ui.spacing_mut().item_spacing.x = 4.0;
+        for status in statuses.iter() {
+            let pip_height = if status.turns.is_some() { 8.0 } else { 0.0 };
+            let (rect, response) = ui.allocate_exact_size(Vec2::new(style.size, style.size + pip_height), Sense::hover());
+            if ui.is_rect_visible(rect) {
+                paint_status(ui, rect, status, icons, style);
+            }
+            let entry = style.glossary.iter().find(|k| k.name == status.name);
+            let keyword = entry.cloned().unwrap_or_else(|| {
+                let turns = status.turns.map(|t| format!(", {} turns left", t)).unwrap_or_default();
+                Keyword::new(status.name.clone(), format!("Stacks: {}{}", status.stacks, turns))
+            });
+            ui_keyword_tooltips(ui, &response, &[keyword]);
+        }
+    })
+    .response
+}
@@ -0,0 +1,381 @@
+//! Sortable data table for egui-based deckbuilder UIs.
+//!
+//! Provides [`ui_table`], a table with resizable columns, click-to-sort headers and row
+//! selection, for screens like run history, card statistics and collection views.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::ui_table::*;
+//! use egui::{CentralPanel, Context};
+//!
+//! fn run_history(ctx: &Context, rows: &[Vec<String>]) {
+//!     let spec = TableSpec::new("run_history")
+//!         .column(Column::new("Character").width(140.0))
+//!         .column(Column::new("Floor").numeric())
+//!         .column(Column::new("Score").numeric());
+//!     CentralPanel::default().show(ctx, |ui| {
+//!         let res = ui_table(ui, &spec, rows);
+//!         if let Some(row) = res.double_clicked {
+//!             println!("Open run {}", rows[row][0]);
+//!         }
+//!     });
+//! }
+//! ```
+//!
+//! # Details
+//!
+//! - Row indices in [`TableResponse`] always refer to the `rows` slice, not the sorted order.
+//! - Clicking a header sorts by that column; clicking again reverses the order. Numeric
+//!   columns sort by value and are right-aligned.
+//! - Drag the right edge of a header to resize a column. Widths, sort order and the selected
+//!   row are kept in egui memory per table id.
+//! - Only visible rows are laid out, so large tables stay fast.
+//! - See each struct and function's documentation for more.
+
+use std::cmp::Ordering;
+
+use egui::{
+    Align2, CursorIcon, Id, Pos2, Rect, ScrollArea, Sense, Stroke, TextStyle, Ui, Vec2,
+};
+
+/// A column of a [`TableSpec`].
+#[derive(Clone)]
+pub struct Column {
+    /// Header text.
+    pub title: String,
+    /// Initial width in points.
+    pub width: f32,
+    /// Smallest width the column can be resized to.
+    pub min_width: f32,
+    /// Whether clicking the header sorts by this column.
+    pub sortable: bool,
+    /// Sort by numeric value and right-align the cells.
+    pub numeric: bool,
+}
+
+impl Column {
+    /// Creates a sortable text column.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_table::Column;
+    /// let column = Column::new("Name");
+    /// ```
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            width: 100.0,
+            min_width: 30.0,
+            sortable: true,
+            numeric: false,
+        }
+    }
+
+    /// Sets the initial width.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_table::Column;
+    /// let column = Column::new("Description").width(240.0);
+    /// ```
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Makes the column sort numerically and right-aligns it.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_table::Column;
+    /// let column = Column::new("Gold").numeric();
+    /// ```
+    pub fn numeric(mut self) -> Self {
+        self.numeric = true;
+        self
+    }
+
+    /// Enables or disables sorting by this column.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_table::Column;
+    /// let column = Column::new("Notes").sortable(false);
+    /// ```
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+}
+
+/// Layout of a [`ui_table`].
+#[derive(Clone)]
+pub struct TableSpec {
+    /// Id source; tables with the same id share widths, sorting and selection.
+    pub id: String,
+    /// Columns from left to right.
+    pub columns: Vec<Column>,
+    /// Height of each row.
+    pub row_height: f32,
+    /// Alternate row background colors.
+    pub striped: bool,
+    /// Whether clicking a row selects it.
+    pub selectable: bool,
+    /// Maximum height of the table body; defaults to the available height.
+    pub max_height: Option<f32>,
+}
+
+impl TableSpec {
+    /// Creates a table without columns.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_table::TableSpec;
+    /// let spec = TableSpec::new("card_stats");
+    /// ```
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            columns: Vec::new(),
+            row_height: 22.0,
+            striped: true,
+            selectable: true,
+            max_height: None,
+        }
+    }
+
+    /// Adds a column.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_table::{Column, TableSpec};
+    /// let spec = TableSpec::new("card_stats").column(Column::new("Card")).column(Column::new("Picks").numeric());
+    /// assert_eq!(spec.columns.len(), 2);
+    /// ```
+    pub fn column(mut self, column: Column) -> Self {
+        self.columns.push(column);
+        self
+    }
+}
+
+/// Interaction state of a [`ui_table`]. Row indices refer to the `rows` slice.
+#[derive(Default)]
+pub struct TableResponse {
+    /// Selected row.
+    pub selected: Option<usize>,
+    /// Row under the pointer.
+    pub hovered: Option<usize>,
+    /// Row clicked this frame.
+    pub clicked: Option<usize>,
+    /// Row double-clicked this frame.
+    pub double_clicked: Option<usize>,
+    /// The sort column or direction changed this frame.
+    pub sort_changed: bool,
+}
+
+/// Per-table state kept in egui memory.
+#[derive(Clone, Default)]
+struct TableState {
+    widths: Vec<f32>,
+    sort: Option<(usize, bool)>,
+    selected: Option<usize>,
+}
+
+fn compare_cells(a: &str, b: &str, numeric: bool) -> Ordering {
+    if numeric {
+        let parse = |s: &str| s.trim().parse::<f64>().ok();
+        match (parse(a), parse(b)) {
+            (Some(x), Some(y)) => x.partial_cmp(&y).unwrap_or(Ordering::Equal),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => a.cmp(b),
+        }
+    } else {
+        a.to_lowercase().cmp(&b.to_lowercase())
+    }
+}
+
+/// Returns the display order of `rows` when sorted by `column`, as indices into `rows`.
+///
+/// # Example
+/// ```rust
+/// # use deckbuilder_eng::ui_table::sorted_order;
+/// let rows = vec![vec!["b".to_string(), "10".to_string()], vec!["a".to_string(), "9".to_string()]];
+/// assert_eq!(sorted_order(&rows, 1, true, true), vec![1, 0]);
+/// assert_eq!(sorted_order(&rows, 0, false, false), vec![0, 1]);
+/// ```
+pub fn sorted_order(rows: &[Vec<String>], column: usize, numeric: bool, ascending: bool) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..rows.len()).collect();
+    let cell = |i: usize| rows[i].get(column).map(String::as_str).unwrap_or("");
+    order.sort_by(|&a, &b| {
+        let ord = compare_cells(cell(a), cell(b), numeric);
+        if ascending { ord } else { ord.reverse() }
+    });
+    order
+}
+
+/// Table with resizable columns, click-to-sort headers and row selection. Each row holds
+/// one string per column.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui_table::{ui_table, Column, TableSpec};
+/// # fn demo(ui: &mut Ui) {
+/// let spec = TableSpec::new("collection").column(Column::new("Card")).column(Column::new("Copies").numeric());
+/// let rows = vec![
+///     vec!["Strike".to_string(), "5".to_string()],
+///     vec!["Bash".to_string(), "1".to_string()],
+/// ];
+/// let res = ui_table(ui, &spec, &rows);
+/// if let Some(row) = res.selected {
+///     ui.label(format!("Selected {}", rows[row][0]));
+/// }
+/// # }
+/// ```
+pub fn ui_table(ui: &mut Ui, spec: &TableSpec, rows: &[Vec<String>]) -> TableResponse {
+    let id = ui.make_persistent_id(Id::new(&spec.id));
+    let mut state = ui.data(|d| d.get_temp::<TableState>(id)).unwrap_or_default();
+    if state.widths.len() != spec.columns.len() {
+        state.widths = spec.columns.iter().map(|c| c.width).collect();
+    }
+    let mut result = TableResponse::default();
+    let visuals = ui.visuals().clone();
+    let padding = 4.0;
+    let total_width: f32 = state.widths.iter().sum();
+    let font = TextStyle::Body.resolve(ui.style());
+
+    // Header with sort buttons and resize handles
+    let header_height = spec.row_height + 2.0;
+    let (header_rect, _) = ui.allocate_exact_size(Vec2::new(total_width, header_height), Sense::hover());
+    let mut x = header_rect.left();
+    for (c, column) in spec.columns.iter().enumerate() {
+        let cell = Rect::from_min_size(Pos2::new(x, header_rect.top()), Vec2::new(state.widths[c], header_height));
+        let sense = if column.sortable { Sense::click() } else { Sense::hover() };
+        let response = ui.interact(cell, id.with(("header", c)), sense);
+        if response.clicked() {
+            state.sort = match state.sort {
+                Some((sorted, ascending)) if sorted == c => Some((c, !ascending)),
+                _ => Some((c, true)),
+            };
+            result.sort_changed = true;
+        }
+        let fill = if response.hovered() && column.sortable {
+            visuals.widgets.hovered.weak_bg_fill
+        } else {
+            visuals.faint_bg_color
+        };
+        ui.painter().rect_filled(cell, 0.0, fill);
+        let arrow = match state.sort {
+            Some((sorted, true)) if sorted == c => " ⏶",
+            Some((sorted, false)) if sorted == c => " ⏷",
+            _ => "",
+        };
+        let (anchor, pos) = if column.numeric {
+            (Align2::RIGHT_CENTER, Pos2::new(cell.right() - padding, cell.center().y))
+        } else {
+            (Align2::LEFT_CENTER, Pos2::new(cell.left() + padding, cell.center().y))
+        };
+        ui.painter().with_clip_rect(cell).text(
+            pos,
+            anchor,
+            format!("{}{}", column.title, arrow),
+            font.clone(),
+            visuals.strong_text_color(),
+        );
+
+        let handle = Rect::from_center_size(Pos2::new(cell.right(), cell.center().y), Vec2::new(6.0, header_height));
+        let handle_response = ui.interact(handle, id.with(("resize", c)), Sense::drag());
+        if handle_response.hovered() || handle_response.dragged() {
+            ui.ctx().set_cursor_icon(CursorIcon::ResizeHorizontal);
+        }
+        if handle_response.dragged() {
+            state.widths[c] = (state.widths[c] + handle_response.drag_delta().x).max(column.min_width);
+        }
+        let line_color = if handle_response.dragged() {
+            visuals.selection.stroke.color
+        } else {
+            visuals.widgets.noninteractive.bg_stroke.color
+        };
+        ui.painter().vline(cell.right(), cell.y_range(), Stroke::new(1.0, line_color));
+        x += state.widths[c];
+    }
+
+    let order = match state.sort {
+        Some((c, ascending)) if c < spec.columns.len() => sorted_order(rows, c, spec.columns[c].numeric, ascending),
+        _ => (0..rows.len()).collect(),
+    };
+
+    // Body; only visible rows are laid out.
+    let mut scroll = ScrollArea::vertical().id_source(id.with("body")).auto_shrink([false, true]);
+    if let Some(max_height) = spec.max_height {
+        scroll = scroll.max_height(max_height);
+    }
+    let row_height = spec.row_height;
+    scroll.show_rows(ui, row_height, order.len(), |ui, range| {
+        ui.spacing_mut().item_spacing.y = 0.0;
+        for visual_row in range {
+            let row = order[visual_row];
+            let (rect, response) = ui.allocate_exact_size(Vec2::new(total_width, row_height), Sense::click());
+            let selected = state.selected == Some(row);
+            if response.hovered() {
+                result.hovered = Some(row);
+            }
+            if response.clicked() {
+                result.clicked = Some(row);
+                if spec.selectable {
+                    state.selected = Some(row);
+                }
+            }
+            if response.double_clicked() {
+                result.double_clicked = Some(row);
+            }
+            if !ui.is_rect_visible(rect) {
+                continue;
+            }
+            let fill = if selected {
+                Some(visuals.selection.bg_fill)
+            } else if response.hovered() {
+                Some(visuals.widgets.hovered.weak_bg_fill)
+            } else if spec.striped && visual_row % 2 == 1 {
+                Some(visuals.faint_bg_color)
+            } else {
+                None
+            };
+            if let Some(fill) = fill {
+                ui.painter().rect_filled(rect, 0.0, fill);
+            }
+            let text_color = if selected {
+                visuals.selection.stroke.color
+            } else {
+                visuals.text_color()
+            };
+            let mut x = rect.left();
+            for (c, column) in spec.columns.iter().enumerate() {
+                let cell = Rect::from_min_size(Pos2::new(x, rect.top()), Vec2::new(state.widths[c], row_height));
+                let text = rows[row].get(c).map(String::as_str).unwrap_or("");
+                let (anchor, pos) = if column.numeric {
+                    (Align2::RIGHT_CENTER, Pos2::new(cell.right() - padding, cell.center().y))
+                } else {
+                    (Align2::LEFT_CENTER, Pos2::new(cell.left() + padding, cell.center().y))
+                };
+                ui.painter().with_clip_rect(cell.shrink2(Vec2::new(padding / 2.0, 0.0))).text(
+                    pos,
+                    anchor,
+                    text,
+                    font.clone(),
+                    text_color,
+                );
+                x += state.widths[c];
+            }
+        }
+    });
+
+    if state.selected.is_some_and(|row| row >= rows.len()) {
+        state.selected = None;
+    }
+    result.selected = state.selected;
+    ui.data_mut(|d| d.insert_temp(id, state));
+    result
+}
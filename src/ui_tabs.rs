@@ -0,0 +1,205 @@
+//! Tab widgets for egui-based deckbuilder UIs.
+//!
+//! Provides [`ui_tabs`], a tab bar with the active tab's content below it, and [`ui_tab_bar`]
+//! for just the bar. Tabs can show a count badge (e.g. the number of new cards).
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::ui_tabs::{ui_tabs, Tab};
+//! use egui::{CentralPanel, Context};
+//!
+//! fn run_panel(ctx: &Context, selected: &mut usize, new_cards: u32) {
+//!     CentralPanel::default().show(ctx, |ui| {
+//!         ui_tabs(
+//!             ui,
+//!             selected,
+//!             vec![
+//!                 Tab::new("Deck", |ui| {
+//!                     ui.label("Your deck");
+//!                 })
+//!                 .badge(new_cards),
+//!                 Tab::new("Relics", |ui| {
+//!                     ui.label("No relics yet");
+//!                 }),
+//!                 Tab::new("Settings", |ui| {
+//!                     ui.label("Settings");
+//!                 }),
+//!             ],
+//!         );
+//!     });
+//! }
+//! ```
+//!
+//! # Details
+//!
+//! - A badge of 0 is hidden, so the count can be passed unconditionally.
+//! - `selected` is clamped to the number of tabs; disabled tabs can't be selected.
+//! - See each function's documentation for usage and customization options.
+
+use egui::{Color32, FontId, Pos2, Rect, Response, Rounding, Sense, Stroke, TextStyle, Ui, Vec2, WidgetText};
+
+/// A tab of [`ui_tabs`]: its title, optional badge and content.
+pub struct Tab<'a> {
+    title: String,
+    badge: u32,
+    enabled: bool,
+    content: Box<dyn FnOnce(&mut Ui) + 'a>,
+}
+
+impl<'a> Tab<'a> {
+    /// Creates a tab showing `content` while it is selected.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_tabs::Tab;
+    /// let tab = Tab::new("Relics", |ui| {
+    ///     ui.label("Burning Blood");
+    /// });
+    /// ```
+    pub fn new(title: impl Into<String>, content: impl FnOnce(&mut Ui) + 'a) -> Self {
+        Self {
+            title: title.into(),
+            badge: 0,
+            enabled: true,
+            content: Box::new(content),
+        }
+    }
+
+    /// Shows `count` in a badge next to the title (hidden when 0).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_tabs::Tab;
+    /// let tab = Tab::new("Deck", |ui| {}).badge(2);
+    /// ```
+    pub fn badge(mut self, count: u32) -> Self {
+        self.badge = count;
+        self
+    }
+
+    /// Enables or disables the tab.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_tabs::Tab;
+    /// let tab = Tab::new("Map", |ui| {}).enabled(false);
+    /// ```
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+}
+
+/// Paints a round count badge centered at `center`.
+fn paint_badge(ui: &Ui, center: Pos2, count: u32) {
+    let text = if count > 99 { "99+".to_owned() } else { count.to_string() };
+    let font = FontId::proportional(10.0);
+    let galley = ui.painter().layout_no_wrap(text, font, Color32::WHITE);
+    let size = Vec2::new((galley.size().x + 8.0).max(16.0), 16.0);
+    let rect = Rect::from_center_size(center, size);
+    ui.painter().rect_filled(rect, Rounding::same(8.0), ui.visuals().error_fg_color);
+    ui.painter().galley(rect.center() - galley.size() / 2.0, galley, Color32::WHITE);
+}
+
+/// Tab bar without content. `tabs` holds each tab's title and badge count. Returns the bar's
+/// response, marked changed when the selection changes.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui_tabs::ui_tab_bar;
+/// # fn demo(ui: &mut Ui, selected: &mut usize) {
+/// if ui_tab_bar(ui, selected, &[("Deck", 0), ("Relics", 1)]).changed() {
+///     println!("Switched to tab {}", selected);
+/// }
+/// # }
+/// ```
+pub fn ui_tab_bar(ui: &mut Ui, selected: &mut usize, tabs: &[(&str, u32)]) -> Response {
+    let enabled: Vec<bool> = vec![true; tabs.len()];
+    tab_bar(ui, selected, tabs, &enabled)
+}
+
+fn tab_bar(ui: &mut Ui, selected: &mut usize, tabs: &[(&str, u32)], enabled: &[bool]) -> Response {
+    *selected = (*selected).min(tabs.len().saturating_sub(1));
+    let mut changed = false;
+    let inner = ui.horizontal(|ui| {
+        ui.spacing_mut().item_spacing.x = 2.0;
+        for (i, &(title, badge)) in tabs.iter().enumerate() {
+            let galley = WidgetText::from(title).into_galley(ui, Some(false), f32::INFINITY, TextStyle::Button);
+            let badge_width = if badge > 0 { 22.0 } else { 0.0 };
+            let padding = ui.spacing().button_padding + Vec2::new(4.0, 2.0);
+            let size = galley.size() + 2.0 * padding + Vec2::new(badge_width, 0.0);
+            let sense = if enabled[i] { Sense::click() } else { Sense::hover() };
+            let (rect, response) = ui.allocate_exact_size(size, sense);
+            let is_selected = *selected == i;
+            if response.clicked() && !is_selected {
+                *selected = i;
+                changed = true;
+            }
+            if ui.is_rect_visible(rect) {
+                let visuals = ui.style().interact_selectable(&response, is_selected);
+                let rounding = Rounding { nw: 4.0, ne: 4.0, sw: 0.0, se: 0.0 };
+                if is_selected || response.hovered() {
+                    ui.painter().rect_filled(rect, rounding, visuals.weak_bg_fill);
+                }
+                if is_selected {
+                    ui.painter().hline(
+                        rect.x_range(),
+                        rect.bottom() - 1.0,
+                        Stroke::new(2.0, ui.visuals().selection.stroke.color),
+                    );
+                }
+                let text_color = if enabled[i] {
+                    visuals.text_color()
+                } else {
+                    ui.visuals().weak_text_color()
+                };
+                let text_pos = Pos2::new(rect.left() + padding.x, rect.center().y - galley.size().y / 2.0);
+                ui.painter().galley(text_pos, galley, text_color);
+                if badge > 0 {
+                    paint_badge(ui, Pos2::new(rect.right() - padding.x - 8.0, rect.center().y), badge);
+                }
+            }
+        }
+    });
+    let full = ui.available_width().max(inner.response.rect.width());
+    let line_y = inner.response.rect.bottom();
+    ui.painter().hline(
+        inner.response.rect.left()..=inner.response.rect.left() + full,
+        line_y,
+        ui.visuals().widgets.noninteractive.bg_stroke,
+    );
+    let mut response = inner.response;
+    if changed {
+        response.mark_changed();
+    }
+    response
+}
+
+/// Tab bar with the selected tab's content below it. Returns the tab bar's response, marked
+/// changed when the selection changes.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui_tabs::{ui_tabs, Tab};
+/// # fn demo(ui: &mut Ui, selected: &mut usize) {
+/// ui_tabs(ui, selected, vec![
+///     Tab::new("Deck", |ui| { ui.label("20 cards"); }),
+///     Tab::new("Relics", |ui| { ui.label("3 relics"); }).badge(1),
+/// ]);
+/// # }
+/// ```
+pub fn ui_tabs(ui: &mut Ui, selected: &mut usize, tabs: Vec<Tab<'_>>) -> Response {
+    let titles: Vec<(&str, u32)> = tabs.iter().map(|t| (t.title.as_str(), t.badge)).collect();
+    let enabled: Vec<bool> = tabs.iter().map(|t| t.enabled).collect();
+    let response = tab_bar(ui, selected, &titles, &enabled);
+    ui.add_space(4.0);
+    let current = *selected;
+    if let Some(tab) = tabs.into_iter().nth(current) {
+        ui.push_id(("tab_content", current), |ui| (tab.content)(ui));
+    }
+    response
+}
+
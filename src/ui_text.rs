@@ -0,0 +1,422 @@
+//! Rich text helpers for egui-based deckbuilder UIs.
+//!
+//! Provides [`ui_rich_label`], which renders card descriptions written in a small markup
+//! language, so they can be formatted from data files, and an [`IconRegistry`] for drawing
+//! `{name}` tokens as inline images.
+//!
+//! # Markup
+//!
+//! - `[red]6[/red]` – color by name (`red`, `green`, `blue`, `yellow`, `gold`, `orange`,
+//!   `purple`, `gray`, `white`, `black`) or hex (`[#ff8800]...[/#ff8800]`).
+//! - `<b>Exhaust</b>` – bold; `<i>...</i>` – italic; `<u>...</u>` – underline.
+//! - `<kw>Vulnerable</kw>` – keyword: accent color and underline.
+//! - `{energy}` – icon by name. Built-in glyphs exist for `energy`, `attack`, `block`, `heal`,
+//!   `gold` and `card`; any name can be given a texture with an [`IconRegistry`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::ui_text::{ui_rich_label, ui_rich_label_with_icons, IconRegistry};
+//! use egui::{CentralPanel, Context};
+//!
+//! fn my_card_text(ctx: &Context) {
+//!     CentralPanel::default().show(ctx, |ui| {
+//!         ui_rich_label(ui, "Deal [red]6[/red] damage. Apply <kw>Vulnerable</kw>. <b>Exhaust</b>.");
+//!         ui_rich_label(ui, "Gain {energy}{energy}.");
+//!     });
+//! }
+//!
+//! fn my_card_text_with_icons(ctx: &Context, icons: &IconRegistry) {
+//!     CentralPanel::default().show(ctx, |ui| {
+//!         // `{energy}` is drawn with the registered texture at font height
+//!         ui_rich_label_with_icons(ui, "Gain {energy}{energy}.", icons);
+//!     });
+//! }
+//! ```
+//!
+//! # Details
+//!
+//! - Tags can be nested; unknown or unmatched tags are shown as plain text.
+//! - egui has no bold font by default: bold text uses the strong text color, or a font
+//!   installed into `FontFamily::Name("bold".into())` if there is one.
+//! - See each function's documentation for usage and customization options.
+
+use std::collections::HashMap;
+
+use egui::text::LayoutJob;
+use egui::{
+    Color32, Context, FontFamily, FontId, Pos2, Rect, Response, Sense, Stroke, TextFormat,
+    TextStyle, TextureHandle, TextureId, Ui, Vec2,
+};
+
+use crate::ui_image::load_texture_from_path;
+
+/// Style of a run of rich text.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct RichStyle {
+    /// Explicit color, or `None` for the default text color.
+    pub color: Option<Color32>,
+    /// Bold text.
+    pub bold: bool,
+    /// Italic text.
+    pub italic: bool,
+    /// Underlined text.
+    pub underline: bool,
+    /// Keyword (accent color and underline).
+    pub keyword: bool,
+}
+
+/// A piece of parsed markup.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RichSpan {
+    /// Styled text.
+    Text {
+        /// The text.
+        text: String,
+        /// Its style.
+        style: RichStyle,
+    },
+    /// An icon token like `{energy}`.
+    Icon {
+        /// Icon name inside the braces.
+        name: String,
+        /// Style active at the icon.
+        style: RichStyle,
+    },
+}
+
+/// Parses a color tag name (`red`, `#ff8800`, ...).
+fn parse_color(name: &str) -> Option<Color32> {
+    if let Some(hex) = name.strip_prefix('#') {
+        return Color32::from_hex(&format!("#{}", hex)).ok();
+    }
+    Some(match name {
+        "red" => Color32::from_rgb(230, 70, 60),
+        "green" => Color32::from_rgb(90, 200, 90),
+        "blue" => Color32::from_rgb(90, 140, 240),
+        "yellow" => Color32::from_rgb(240, 220, 80),
+        "gold" => Color32::GOLD,
+        "orange" => Color32::from_rgb(240, 150, 50),
+        "purple" => Color32::from_rgb(180, 110, 230),
+        "gray" | "grey" => Color32::GRAY,
+        "white" => Color32::WHITE,
+        "black" => Color32::BLACK,
+        _ => return None,
+    })
+}
+
+/// Applies an opening tag to `style`, or returns `None` for unknown tags.
+fn apply_tag(style: RichStyle, tag: &str, angle: bool) -> Option<RichStyle> {
+    let mut style = style;
+    if angle {
+        match tag {
+            "b" => style.bold = true,
+            "i" => style.italic = true,
+            "u" => style.underline = true,
+            "kw" => style.keyword = true,
+            _ => return None,
+        }
+    } else {
+        style.color = Some(parse_color(tag)?);
+    }
+    Some(style)
+}
+
+/// Parses markup into styled spans. Adjacent text with the same style is merged.
+///
+/// # Example
+/// ```rust
+/// # use deckbuilder_eng::ui_text::{parse_markup, RichSpan};
+/// let spans = parse_markup("Deal [red]6[/red] damage.");
+/// assert_eq!(spans.len(), 3);
+/// assert!(matches!(&spans[1], RichSpan::Text { text, style } if text == "6" && style.color.is_some()));
+/// ```
+pub fn parse_markup(markup: &str) -> Vec<RichSpan> {
+    let mut spans: Vec<RichSpan> = Vec::new();
+    // Open tags with the style that was active before them.
+    let mut stack: Vec<(String, bool, RichStyle)> = Vec::new();
+    let mut style = RichStyle::default();
+    let mut rest = markup;
+
+    let push_text = |spans: &mut Vec<RichSpan>, text: &str, style: RichStyle| {
+        if text.is_empty() {
+            return;
+        }
+        if let Some(RichSpan::Text { text: last, style: last_style }) = spans.last_mut()
+            && *last_style == style
+        {
+            last.push_str(text);
+            return;
+        }
+        spans.push(RichSpan::Text { text: text.to_owned(), style });
+    };
+
+    while let Some(start) = rest.find(['[', '<', '{']) {
+        push_text(&mut spans, &rest[..start], style);
+        let open = rest.as_bytes()[start] as char;
+        let close = match open {
+            '[' => ']',
+            '<' => '>',
+            _ => '}',
+        };
+        let after = &rest[start + 1..];
+        let Some(end) = after.find(close) else {
+            push_text(&mut spans, &rest[start..], style);
+            rest = "";
+            break;
+        };
+        let inner = &after[..end];
+        let token = &rest[start..start + end + 2];
+        rest = &after[end + 1..];
+
+        if open == '{' {
+            if !inner.is_empty() && inner.chars().all(|c| c.is_alphanumeric() || c == '_') {
+                spans.push(RichSpan::Icon { name: inner.to_owned(), style });
+            } else {
+                push_text(&mut spans, token, style);
+            }
+            continue;
+        }
+
+        let angle = open == '<';
+        if let Some(name) = inner.strip_prefix('/') {
+            // Close the innermost matching tag (and anything opened inside it).
+            if let Some(pos) = stack.iter().rposition(|(n, a, _)| n == name && *a == angle) {
+                style = stack[pos].2;
+                stack.truncate(pos);
+            } else {
+                push_text(&mut spans, token, style);
+            }
+        } else if let Some(new_style) = apply_tag(style, inner, angle) {
+            stack.push((inner.to_owned(), angle, style));
+            style = new_style;
+        } else {
+            push_text(&mut spans, token, style);
+        }
+    }
+    push_text(&mut spans, rest, style);
+    spans
+}
+
+/// Built-in icon names and the glyphs used to draw them.
+const ICON_GLYPHS: [(&str, &str); 6] = [
+    ("energy", "⚡"),
+    ("attack", "⚔"),
+    ("block", "🛡"),
+    ("heal", "❤"),
+    ("gold", "💰"),
+    ("card", "🃏"),
+];
+
+/// Returns the glyph of a built-in icon name.
+fn icon_glyph(name: &str) -> Option<&'static str> {
+    ICON_GLYPHS.iter().find(|(n, _)| *n == name).map(|(_, g)| *g)
+}
+
+/// Builds the egui text format of a span.
+fn span_format(ui: &Ui, style: RichStyle, font_id: &FontId, base_color: Color32) -> TextFormat {
+    let visuals = ui.visuals();
+    let mut format = TextFormat::simple(font_id.clone(), base_color);
+    if style.bold {
+        let bold = FontFamily::Name("bold".into());
+        if ui.fonts(|f| f.families().contains(&bold)) {
+            format.font_id.family = bold;
+        } else {
+            format.color = visuals.strong_text_color();
+        }
+    }
+    if style.keyword {
+        format.color = visuals.hyperlink_color;
+    }
+    if let Some(color) = style.color {
+        format.color = color;
+    }
+    format.italics = style.italic;
+    if style.underline || style.keyword {
+        format.underline = Stroke::new(1.0, format.color);
+    }
+    format
+}
+
+/// Lays out markup into an egui `LayoutJob` using the body font and the current text color,
+/// wrapped to the available width.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui_text::rich_layout_job;
+/// # fn demo(ui: &mut Ui) {
+/// let job = rich_layout_job(ui, "<b>Innate</b>. Draw [blue]2[/blue] cards.");
+/// ui.label(job);
+/// # }
+/// ```
+pub fn rich_layout_job(ui: &Ui, markup: &str) -> LayoutJob {
+    build_job(ui, markup, None).0
+}
+
+/// Builds the layout job; icons found in `icons` become blank placeholders whose section
+/// indices are returned with their textures.
+fn build_job(ui: &Ui, markup: &str, icons: Option<&IconRegistry>) -> (LayoutJob, Vec<(u32, TextureId)>) {
+    let font_id = TextStyle::Body.resolve(ui.style());
+    let base_color = ui.visuals().text_color();
+    let mut job = LayoutJob::default();
+    job.wrap.max_width = ui.available_width();
+    let mut placeholders = Vec::new();
+    for span in parse_markup(markup) {
+        match span {
+            RichSpan::Text { text, style } => {
+                job.append(&text, 0.0, span_format(ui, style, &font_id, base_color));
+            }
+            RichSpan::Icon { name, style } => {
+                if let Some(texture) = icons.and_then(|icons| icons.get(&name)) {
+                    // A space widened to one em reserves room for the image.
+                    let mut format = span_format(ui, style, &font_id, base_color);
+                    let space = ui.fonts(|f| f.glyph_width(&font_id, ' '));
+                    format.extra_letter_spacing = font_id.size - space + 1.0;
+                    format.underline = Stroke::NONE;
+                    placeholders.push((job.sections.len() as u32, texture.id()));
+                    job.append(" ", 0.0, format);
+                } else {
+                    let text = icon_glyph(&name).map(str::to_owned).unwrap_or_else(|| format!("{{{}}}", name));
+                    job.append(&text, 0.0, span_format(ui, style, &font_id, base_color));
+                }
+            }
+        }
+    }
+    (job, placeholders)
+}
+
+/// Label that renders markup (colors, bold, italics, keywords, icons).
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui_text::ui_rich_label;
+/// # fn demo(ui: &mut Ui) {
+/// ui_rich_label(ui, "Deal [red]6[/red] damage. <b>Exhaust</b>.");
+/// # }
+/// ```
+pub fn ui_rich_label(ui: &mut Ui, markup: &str) -> Response {
+    let job = rich_layout_job(ui, markup);
+    ui.label(job)
+}
+
+/// Named small textures used for `{name}` tokens in rich text.
+#[derive(Clone, Default)]
+pub struct IconRegistry {
+    icons: HashMap<String, TextureHandle>,
+}
+
+impl IconRegistry {
+    /// Creates an empty registry.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_text::IconRegistry;
+    /// let icons = IconRegistry::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `texture` under `name`, replacing any previous icon.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::TextureHandle;
+    /// # use deckbuilder_eng::ui_text::IconRegistry;
+    /// # fn demo(texture: TextureHandle) {
+    /// let mut icons = IconRegistry::new();
+    /// icons.insert("energy", texture);
+    /// # }
+    /// ```
+    pub fn insert(&mut self, name: impl Into<String>, texture: TextureHandle) {
+        self.icons.insert(name.into(), texture);
+    }
+
+    /// Loads an image file and registers it under `name`. Returns `false` if loading failed.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Context;
+    /// # use deckbuilder_eng::ui_text::IconRegistry;
+    /// # fn demo(ctx: &Context) {
+    /// let mut icons = IconRegistry::new();
+    /// if !icons.load(ctx, "energy", "assets/icons/energy.png") {
+    ///     println!("Energy icon missing, falling back to the glyph");
+    /// }
+    /// # }
+    /// ```
+    pub fn load(&mut self, ctx: &Context, name: impl Into<String>, path: &str) -> bool {
+        match load_texture_from_path(ctx, path) {
+            Ok(texture) => {
+                self.insert(name, texture);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Returns the icon registered under `name`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_text::IconRegistry;
+    /// let icons = IconRegistry::new();
+    /// assert!(icons.get("energy").is_none());
+    /// ```
+    pub fn get(&self, name: &str) -> Option<&TextureHandle> {
+        self.icons.get(name)
+    }
+
+    /// Removes the icon registered under `name`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_text::IconRegistry;
+    /// let mut icons = IconRegistry::new();
+    /// icons.remove("energy");
+    /// ```
+    pub fn remove(&mut self, name: &str) -> Option<TextureHandle> {
+        self.icons.remove(name)
+    }
+}
+
+/// Label that renders markup and substitutes `{name}` tokens with registered icon
+/// textures at font height. Unregistered names fall back to the built-in glyphs.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui_text::{ui_rich_label_with_icons, IconRegistry};
+/// # fn demo(ui: &mut Ui, icons: &IconRegistry) {
+/// ui_rich_label_with_icons(ui, "Gain {energy}{energy}. Draw [blue]1[/blue] card.", icons);
+/// # }
+/// ```
+pub fn ui_rich_label_with_icons(ui: &mut Ui, markup: &str, icons: &IconRegistry) -> Response {
+    let (job, placeholders) = build_job(ui, markup, Some(icons));
+    let icon_size = TextStyle::Body.resolve(ui.style()).size;
+    let galley = ui.fonts(|f| f.layout_job(job));
+    let (rect, response) = ui.allocate_exact_size(galley.size(), Sense::hover());
+    if !ui.is_rect_visible(rect) {
+        return response;
+    }
+
+    let painter = ui.painter();
+    painter.galley(rect.min, galley.clone(), ui.visuals().text_color());
+    for row in &galley.rows {
+        for glyph in &row.glyphs {
+            let Some((_, texture)) = placeholders.iter().find(|(i, _)| *i == glyph.section_index) else {
+                continue;
+            };
+            let center = Pos2::new(glyph.pos.x + icon_size / 2.0, row.rect.center().y);
+            painter.image(
+                *texture,
+                Rect::from_center_size(rect.min + center.to_vec2(), Vec2::splat(icon_size)),
+                Rect::from_min_max(Pos2::ZERO, Pos2::new(1.0, 1.0)),
+                Color32::WHITE,
+            );
+        }
+    }
+    response
+}
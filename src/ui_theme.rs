@@ -0,0 +1,434 @@
+//! Theming helpers for egui-based deckbuilder UIs.
+//!
+//! Provides a [`Theme`] (palette, corner radii, font sizes, paddings) that restyles egui in one
+//! call with [`apply_theme`], built-in presets, a selector widget for runtime switching, and a
+//! UI scale setting.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::ui_theme::{apply_theme, ui_theme_selector, Theme};
+//! use egui::{CentralPanel, Context};
+//!
+//! fn setup(ctx: &Context) {
+//!     apply_theme(ctx, &Theme::dark_fantasy());
+//! }
+//!
+//! fn settings(ctx: &Context, theme: &mut Theme) {
+//!     CentralPanel::default().show(ctx, |ui| {
+//!         // Applies the new theme immediately when the player picks one
+//!         ui_theme_selector(ui, theme);
+//!     });
+//! }
+//! ```
+//!
+//! # Details
+//!
+//! - `apply_theme` replaces the context's `Style`; call it again at any time to switch themes.
+//! - The last applied theme can be read back with [`current_theme`], so custom widgets can use
+//!   its palette.
+//! - [`set_ui_scale`] scales text and spacing on top of the theme for 4K or small screens;
+//!   [`ui_scale_selector`] is a ready-made settings widget for it.
+//! - See each function's documentation for usage and customization options.
+
+use egui::{
+    Color32, ComboBox, Context, FontFamily, FontId, Id, Margin, Response, Rounding, Slider, Stroke,
+    Style, TextStyle, Ui, Vec2, Visuals,
+};
+
+/// Colors used by a [`Theme`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Palette {
+    /// Panel and window background.
+    pub background: Color32,
+    /// Background of text edits, scroll areas and other recessed areas.
+    pub surface: Color32,
+    /// Regular text.
+    pub text: Color32,
+    /// Highlight color (selection, links, focused widgets).
+    pub accent: Color32,
+    /// Button/widget background.
+    pub widget: Color32,
+    /// Button/widget background while hovered.
+    pub widget_hover: Color32,
+    /// Button/widget background while pressed.
+    pub widget_active: Color32,
+    /// Outline of widgets and windows.
+    pub border: Color32,
+    /// Warning text.
+    pub warning: Color32,
+    /// Error text.
+    pub error: Color32,
+}
+
+/// Complete look of the UI: palette, corner radii, font sizes and paddings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    /// Display name of the theme.
+    pub name: String,
+    /// Whether egui's dark visuals are used as the base.
+    pub dark: bool,
+    /// Colors.
+    pub palette: Palette,
+    /// Corner radius of widgets.
+    pub widget_rounding: f32,
+    /// Corner radius of windows and menus.
+    pub window_rounding: f32,
+    /// Body text size in points.
+    pub body_size: f32,
+    /// Heading text size in points.
+    pub heading_size: f32,
+    /// Button text size in points.
+    pub button_size: f32,
+    /// Small text size in points.
+    pub small_size: f32,
+    /// Space between widgets.
+    pub item_spacing: Vec2,
+    /// Padding inside buttons.
+    pub button_padding: Vec2,
+    /// Inner margin of windows.
+    pub window_margin: f32,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark_fantasy()
+    }
+}
+
+impl Theme {
+    /// Dark preset with deep purple panels and gold accents.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_theme::Theme;
+    /// let theme = Theme::dark_fantasy();
+    /// ```
+    pub fn dark_fantasy() -> Self {
+        Self {
+            name: "Dark Fantasy".to_owned(),
+            dark: true,
+            palette: Palette {
+                background: Color32::from_rgb(28, 22, 36),
+                surface: Color32::from_rgb(16, 12, 22),
+                text: Color32::from_rgb(225, 215, 195),
+                accent: Color32::from_rgb(210, 170, 70),
+                widget: Color32::from_rgb(58, 44, 72),
+                widget_hover: Color32::from_rgb(80, 60, 98),
+                widget_active: Color32::from_rgb(110, 80, 60),
+                border: Color32::from_rgb(95, 75, 55),
+                warning: Color32::from_rgb(230, 170, 60),
+                error: Color32::from_rgb(220, 70, 60),
+            },
+            widget_rounding: 4.0,
+            window_rounding: 8.0,
+            body_size: 15.0,
+            heading_size: 24.0,
+            button_size: 15.0,
+            small_size: 11.0,
+            item_spacing: Vec2::new(8.0, 6.0),
+            button_padding: Vec2::new(10.0, 5.0),
+            window_margin: 10.0,
+        }
+    }
+
+    /// Light preset with warm paper colors and ink-brown text.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_theme::Theme;
+    /// let theme = Theme::parchment();
+    /// ```
+    pub fn parchment() -> Self {
+        Self {
+            name: "Parchment".to_owned(),
+            dark: false,
+            palette: Palette {
+                background: Color32::from_rgb(236, 222, 190),
+                surface: Color32::from_rgb(248, 238, 212),
+                text: Color32::from_rgb(60, 40, 25),
+                accent: Color32::from_rgb(150, 40, 30),
+                widget: Color32::from_rgb(214, 194, 150),
+                widget_hover: Color32::from_rgb(200, 176, 128),
+                widget_active: Color32::from_rgb(180, 150, 100),
+                border: Color32::from_rgb(130, 100, 60),
+                warning: Color32::from_rgb(170, 100, 10),
+                error: Color32::from_rgb(170, 30, 30),
+            },
+            widget_rounding: 2.0,
+            window_rounding: 4.0,
+            body_size: 15.0,
+            heading_size: 24.0,
+            button_size: 15.0,
+            small_size: 11.0,
+            item_spacing: Vec2::new(8.0, 6.0),
+            button_padding: Vec2::new(10.0, 5.0),
+            window_margin: 12.0,
+        }
+    }
+
+    /// All built-in presets.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_theme::Theme;
+    /// for theme in Theme::presets() {
+    ///     println!("{}", theme.name);
+    /// }
+    /// ```
+    pub fn presets() -> Vec<Theme> {
+        vec![Self::dark_fantasy(), Self::parchment()]
+    }
+
+    /// Builds the egui `Visuals` for this theme.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_theme::Theme;
+    /// let visuals = Theme::parchment().visuals();
+    /// assert!(!visuals.dark_mode);
+    /// ```
+    pub fn visuals(&self) -> Visuals {
+        let p = &self.palette;
+        let mut visuals = if self.dark { Visuals::dark() } else { Visuals::light() };
+        visuals.override_text_color = Some(p.text);
+        visuals.panel_fill = p.background;
+        visuals.window_fill = p.background;
+        visuals.extreme_bg_color = p.surface;
+        visuals.faint_bg_color = p.surface;
+        visuals.hyperlink_color = p.accent;
+        visuals.warn_fg_color = p.warning;
+        visuals.error_fg_color = p.error;
+        visuals.selection.bg_fill = p.accent.gamma_multiply(0.6);
+        visuals.selection.stroke = Stroke::new(1.0, p.text);
+        visuals.window_stroke = Stroke::new(1.0, p.border);
+        visuals.window_rounding = Rounding::same(self.window_rounding);
+        visuals.menu_rounding = Rounding::same(self.window_rounding);
+
+        let rounding = Rounding::same(self.widget_rounding);
+        let widgets = &mut visuals.widgets;
+        widgets.noninteractive.bg_fill = p.background;
+        widgets.noninteractive.weak_bg_fill = p.background;
+        widgets.noninteractive.bg_stroke = Stroke::new(1.0, p.border);
+        widgets.noninteractive.fg_stroke = Stroke::new(1.0, p.text);
+        for (state, fill) in [
+            (&mut widgets.inactive, p.widget),
+            (&mut widgets.hovered, p.widget_hover),
+            (&mut widgets.active, p.widget_active),
+            (&mut widgets.open, p.widget_hover),
+        ] {
+            state.bg_fill = fill;
+            state.weak_bg_fill = fill;
+            state.bg_stroke = Stroke::new(1.0, p.border);
+            state.fg_stroke = Stroke::new(1.0, p.text);
+        }
+        widgets.hovered.bg_stroke = Stroke::new(1.0, p.accent);
+        widgets.active.bg_stroke = Stroke::new(1.0, p.accent);
+        for state in [
+            &mut widgets.noninteractive,
+            &mut widgets.inactive,
+            &mut widgets.hovered,
+            &mut widgets.active,
+            &mut widgets.open,
+        ] {
+            state.rounding = rounding;
+        }
+        visuals
+    }
+}
+
+/// Applies `theme` to the whole context (colors, radii, font sizes, spacing).
+/// Can be called at any time to switch themes.
+///
+/// # Example
+/// ```rust
+/// # use egui::Context;
+/// # use deckbuilder_eng::ui_theme::{apply_theme, Theme};
+/// let ctx = Context::default();
+/// apply_theme(&ctx, &Theme::parchment());
+/// ```
+pub fn apply_theme(ctx: &Context, theme: &Theme) {
+    let mut style = (*ctx.style()).clone();
+    style.visuals = theme.visuals();
+    style.spacing.item_spacing = theme.item_spacing;
+    style.spacing.button_padding = theme.button_padding;
+    style.spacing.window_margin = Margin::same(theme.window_margin);
+    style.text_styles = [
+        (TextStyle::Small, FontId::new(theme.small_size, FontFamily::Proportional)),
+        (TextStyle::Body, FontId::new(theme.body_size, FontFamily::Proportional)),
+        (TextStyle::Button, FontId::new(theme.button_size, FontFamily::Proportional)),
+        (TextStyle::Heading, FontId::new(theme.heading_size, FontFamily::Proportional)),
+        (TextStyle::Monospace, FontId::new(theme.body_size * 0.9, FontFamily::Monospace)),
+    ]
+    .into();
+    ctx.set_style(scaled_style(&style, ui_scale(ctx)));
+    ctx.data_mut(|d| {
+        d.insert_temp(base_style_id(), style);
+        d.insert_temp(theme_id(), theme.clone());
+    });
+}
+
+/// Returns the theme last passed to [`apply_theme`], if any.
+///
+/// # Example
+/// ```rust
+/// # use egui::Context;
+/// # use deckbuilder_eng::ui_theme::{apply_theme, current_theme, Theme};
+/// let ctx = Context::default();
+/// apply_theme(&ctx, &Theme::dark_fantasy());
+/// assert_eq!(current_theme(&ctx).unwrap().name, "Dark Fantasy");
+/// ```
+pub fn current_theme(ctx: &Context) -> Option<Theme> {
+    ctx.data(|d| d.get_temp::<Theme>(theme_id()))
+}
+
+fn theme_id() -> Id {
+    Id::new("deckbuilder_theme")
+}
+
+fn base_style_id() -> Id {
+    Id::new("deckbuilder_base_style")
+}
+
+fn ui_scale_id() -> Id {
+    Id::new("deckbuilder_ui_scale")
+}
+
+fn scaled_style(base: &Style, factor: f32) -> Style {
+    let mut style = base.clone();
+    for font in style.text_styles.values_mut() {
+        font.size *= factor;
+    }
+    let spacing = &mut style.spacing;
+    spacing.item_spacing *= factor;
+    spacing.window_margin *= factor;
+    spacing.button_padding *= factor;
+    spacing.menu_margin *= factor;
+    spacing.indent *= factor;
+    spacing.interact_size *= factor;
+    spacing.slider_width *= factor;
+    spacing.combo_width *= factor;
+    spacing.text_edit_width *= factor;
+    spacing.icon_width *= factor;
+    spacing.icon_width_inner *= factor;
+    spacing.icon_spacing *= factor;
+    spacing.tooltip_width *= factor;
+    spacing.menu_width *= factor;
+    style
+}
+
+/// Scales all text styles and spacing of the context by `factor` (clamped to `0.5..=3.0`),
+/// relative to the unscaled style, so repeated calls don't compound. The factor is kept in
+/// egui's persisted memory and reapplied by [`apply_theme`].
+///
+/// Unlike the zoom factor this keeps lines and images crisp, and only grows what affects
+/// readability. Style changes made after the first call without [`apply_theme`] are lost on
+/// the next call.
+///
+/// # Example
+/// ```rust
+/// # use egui::{Context, TextStyle};
+/// # use deckbuilder_eng::ui_theme::{set_ui_scale, ui_scale};
+/// let ctx = Context::default();
+/// let body = ctx.style().text_styles[&TextStyle::Body].size;
+/// set_ui_scale(&ctx, 1.5);
+/// set_ui_scale(&ctx, 1.5);
+/// assert_eq!(ctx.style().text_styles[&TextStyle::Body].size, body * 1.5);
+/// assert_eq!(ui_scale(&ctx), 1.5);
+/// ```
+pub fn set_ui_scale(ctx: &Context, factor: f32) {
+    let factor = factor.clamp(0.5, 3.0);
+    let base = ctx
+        .data(|d| d.get_temp::<Style>(base_style_id()))
+        .unwrap_or_else(|| (*ctx.style()).clone());
+    ctx.set_style(scaled_style(&base, factor));
+    ctx.data_mut(|d| {
+        d.insert_temp(base_style_id(), base);
+        d.insert_persisted(ui_scale_id(), factor);
+    });
+}
+
+/// Returns the factor last passed to [`set_ui_scale`], or `1.0`. With eframe persistence
+/// enabled it survives restarts; call `set_ui_scale(ctx, ui_scale(ctx))` at startup to
+/// restore it.
+///
+/// # Example
+/// ```rust
+/// # use egui::Context;
+/// # use deckbuilder_eng::ui_theme::ui_scale;
+/// assert_eq!(ui_scale(&Context::default()), 1.0);
+/// ```
+pub fn ui_scale(ctx: &Context) -> f32 {
+    ctx.data_mut(|d| d.get_persisted::<f32>(ui_scale_id())).unwrap_or(1.0)
+}
+
+/// Slider for the UI scale (50%–200%) with a reset button. The scale is applied with
+/// [`set_ui_scale`] when the slider is released, so the layout doesn't move while dragging.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui_theme::ui_scale_selector;
+/// # fn demo(ui: &mut Ui, scale: &mut f32) {
+/// if ui_scale_selector(ui, scale).changed() {
+///     println!("UI scale is now {:.0}%", *scale * 100.0);
+/// }
+/// # }
+/// ```
+pub fn ui_scale_selector(ui: &mut Ui, scale: &mut f32) -> Response {
+    let mut apply = false;
+    let mut response = ui
+        .horizontal(|ui| {
+            let slider = ui.add(
+                Slider::new(scale, 0.5..=2.0)
+                    .step_by(0.05)
+                    .text("UI scale")
+                    .custom_formatter(|v, _| format!("{:.0}%", v * 100.0))
+                    .custom_parser(|s| s.trim_end_matches('%').trim().parse::<f64>().ok().map(|v| v / 100.0)),
+            );
+            apply = slider.drag_stopped() || (slider.changed() && !slider.dragged());
+            if ui.add_enabled(*scale != 1.0, egui::Button::new("Reset")).clicked() {
+                *scale = 1.0;
+                apply = true;
+            }
+        })
+        .response;
+    if apply {
+        set_ui_scale(ui.ctx(), *scale);
+        response.mark_changed();
+    }
+    response
+}
+
+/// Combo box listing the built-in presets. Applies the picked theme immediately and
+/// stores it in `theme`.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui_theme::{ui_theme_selector, Theme};
+/// # fn demo(ui: &mut Ui, theme: &mut Theme) {
+/// if ui_theme_selector(ui, theme).changed() {
+///     println!("Switched to {}", theme.name);
+/// }
+/// # }
+/// ```
+pub fn ui_theme_selector(ui: &mut Ui, theme: &mut Theme) -> Response {
+    let mut picked = None;
+    let mut response = ComboBox::from_label("Theme")
+        .selected_text(theme.name.as_str())
+        .show_ui(ui, |ui| {
+            for preset in Theme::presets() {
+                if ui.selectable_label(preset.name == theme.name, preset.name.as_str()).clicked() {
+                    picked = Some(preset);
+                }
+            }
+        })
+        .response;
+    if let Some(preset) = picked {
+        apply_theme(ui.ctx(), &preset);
+        *theme = preset;
+        response.mark_changed();
+    }
+    response
+}
@@ -0,0 +1,212 @@
+//! Tooltip helpers for egui-based deckbuilder UIs.
+//!
+//! Provides rich hover panels that can be attached to any widget response: a full card render
+//! ([`ui_card_tooltip`]) and keyword explanations ([`ui_keyword_tooltips`]), with configurable
+//! delay and placement.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::card::{Card, CardType, Keyword};
+//! use deckbuilder_eng::ui_tooltip::*;
+//! use egui::{CentralPanel, Context};
+//!
+//! fn my_ui(ctx: &Context, card: &Card) {
+//!     CentralPanel::default().show(ctx, |ui| {
+//!         let response = ui.label(&card.name);
+//!         ui_card_tooltip(ui, &response, card);
+//!
+//!         let response = ui.label("Exhaust");
+//!         let keywords = [Keyword::new("Exhaust", "Removed until end of combat.")];
+//!         ui_keyword_tooltips(ui, &response, &keywords);
+//!     });
+//! }
+//! ```
+//!
+//! # Details
+//!
+//! - Tooltips are shown on the tooltip layer after the pointer rests on the widget for
+//!   `TooltipConfig::delay` seconds.
+//! - Keyword descriptions support the rich text markup of [`crate::ui_text`]; keywords are
+//!   translated by the installed [`crate::i18n::I18n`].
+//! - See each function's documentation for usage and customization options.
+
+use egui::{Align2, Area, Frame, Id, Order, Rect, Response, Sense, Ui, Vec2};
+
+use crate::card::{Card, Keyword};
+use crate::i18n::localize_keyword;
+use crate::ui_card::{paint_card, CardVisuals};
+use crate::ui_text::ui_rich_label;
+
+/// Where a tooltip is placed relative to the widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TooltipPlacement {
+    /// To the right of the widget.
+    Right,
+    /// To the left of the widget.
+    Left,
+    /// Above the widget.
+    Above,
+    /// Below the widget.
+    Below,
+    /// Next to the mouse pointer.
+    Pointer,
+}
+
+/// Delay and placement of a tooltip.
+#[derive(Debug, Clone, Copy)]
+pub struct TooltipConfig {
+    /// Seconds the pointer must rest on the widget before the tooltip appears.
+    pub delay: f32,
+    /// Placement relative to the widget.
+    pub placement: TooltipPlacement,
+    /// Distance between the widget and the tooltip.
+    pub gap: f32,
+}
+
+impl Default for TooltipConfig {
+    fn default() -> Self {
+        Self {
+            delay: 0.3,
+            placement: TooltipPlacement::Right,
+            gap: 8.0,
+        }
+    }
+}
+
+/// Returns `true` once `response` has been hovered for at least `delay` seconds.
+fn hover_delay_passed(ui: &Ui, response: &Response, delay: f32) -> bool {
+    let id = response.id.with("tooltip_hover_start");
+    let now = ui.input(|i| i.time);
+    if !response.hovered() {
+        ui.data_mut(|d| d.remove::<f64>(id));
+        return false;
+    }
+    let start = ui.data_mut(|d| *d.get_temp_mut_or_insert_with(id, || now));
+    let passed = now - start >= delay as f64;
+    if !passed {
+        ui.ctx().request_repaint();
+    }
+    passed
+}
+
+/// Shows `add_contents` in a tooltip area next to `rect` according to `config`.
+fn show_tooltip_area(ui: &Ui, id: Id, rect: Rect, config: &TooltipConfig, add_contents: impl FnOnce(&mut Ui)) {
+    let gap = config.gap;
+    let (pos, pivot) = match config.placement {
+        TooltipPlacement::Right => (rect.right_top() + Vec2::new(gap, 0.0), Align2::LEFT_TOP),
+        TooltipPlacement::Left => (rect.left_top() - Vec2::new(gap, 0.0), Align2::RIGHT_TOP),
+        TooltipPlacement::Above => (rect.center_top() - Vec2::new(0.0, gap), Align2::CENTER_BOTTOM),
+        TooltipPlacement::Below => (rect.center_bottom() + Vec2::new(0.0, gap), Align2::CENTER_TOP),
+        TooltipPlacement::Pointer => {
+            let pointer = ui.input(|i| i.pointer.hover_pos()).unwrap_or(rect.center());
+            (pointer + Vec2::splat(gap * 2.0), Align2::LEFT_TOP)
+        }
+    };
+    Area::new(id)
+        .order(Order::Tooltip)
+        .fixed_pos(pos)
+        .pivot(pivot)
+        .interactable(false)
+        .show(ui.ctx(), add_contents);
+}
+
+/// Shows a full card render while `response` is hovered, using the default config.
+/// Returns `true` while the tooltip is visible.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::card::{Card, CardType};
+/// # use deckbuilder_eng::ui_tooltip::ui_card_tooltip;
+/// # fn demo(ui: &mut Ui) {
+/// let card = Card::new(1, "Strike", "Deal 6 damage", 1, CardType::Attack);
+/// let response = ui.link("Strike");
+/// ui_card_tooltip(ui, &response, &card);
+/// # }
+/// ```
+pub fn ui_card_tooltip(ui: &Ui, response: &Response, card: &Card) -> bool {
+    ui_card_tooltip_ex(ui, response, card, &CardVisuals::default(), &TooltipConfig::default())
+}
+
+/// Card tooltip with custom visuals, delay and placement.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::card::{Card, CardType};
+/// # use deckbuilder_eng::ui_card::CardVisuals;
+/// # use deckbuilder_eng::ui_tooltip::{ui_card_tooltip_ex, TooltipConfig, TooltipPlacement};
+/// # fn demo(ui: &mut Ui, card: &Card) {
+/// let response = ui.label(&card.name);
+/// let config = TooltipConfig { delay: 0.0, placement: TooltipPlacement::Above, ..Default::default() };
+/// ui_card_tooltip_ex(ui, &response, card, &CardVisuals::default(), &config);
+/// # }
+/// ```
+pub fn ui_card_tooltip_ex(
+    ui: &Ui,
+    response: &Response,
+    card: &Card,
+    visuals: &CardVisuals,
+    config: &TooltipConfig,
+) -> bool {
+    if !hover_delay_passed(ui, response, config.delay) {
+        return false;
+    }
+    show_tooltip_area(ui, response.id.with("card_tooltip"), response.rect, config, |ui| {
+        let (rect, _) = ui.allocate_exact_size(visuals.size, Sense::hover());
+        paint_card(ui.painter(), rect, card, visuals, false);
+    });
+    true
+}
+
+/// Shows one explanation panel per keyword while `response` is hovered, using the default
+/// config. Returns `true` while the tooltip is visible.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::card::Keyword;
+/// # use deckbuilder_eng::ui_tooltip::ui_keyword_tooltips;
+/// # fn demo(ui: &mut Ui) {
+/// let keywords = [
+///     Keyword::new("Vulnerable", "Takes [red]50%[/red] more damage from attacks."),
+///     Keyword::new("Exhaust", "Removed until end of combat."),
+/// ];
+/// let response = ui.label("Bash");
+/// ui_keyword_tooltips(ui, &response, &keywords);
+/// # }
+/// ```
+pub fn ui_keyword_tooltips(ui: &Ui, response: &Response, keywords: &[Keyword]) -> bool {
+    ui_keyword_tooltips_ex(ui, response, keywords, &TooltipConfig::default())
+}
+
+/// Keyword tooltips with custom delay and placement.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::card::Keyword;
+/// # use deckbuilder_eng::ui_tooltip::{ui_keyword_tooltips_ex, TooltipConfig, TooltipPlacement};
+/// # fn demo(ui: &mut Ui, keywords: &[Keyword]) {
+/// let response = ui.label("Bash");
+/// let config = TooltipConfig { placement: TooltipPlacement::Pointer, ..Default::default() };
+/// ui_keyword_tooltips_ex(ui, &response, keywords, &config);
+/// # }
+/// ```
+pub fn ui_keyword_tooltips_ex(ui: &Ui, response: &Response, keywords: &[Keyword], config: &TooltipConfig) -> bool {
+    if keywords.is_empty() || !hover_delay_passed(ui, response, config.delay) {
+        return false;
+    }
+    show_tooltip_area(ui, response.id.with("keyword_tooltips"), response.rect, config, |ui| {
+        ui.set_max_width(220.0);
+        for keyword in keywords {
+            let keyword = localize_keyword(ui.ctx(), keyword);
+            Frame::popup(ui.style()).show(ui, |ui| {
+                ui.strong(&keyword.name);
+                ui_rich_label(ui, &keyword.description);
+            });
+        }
+    });
+    true
+}
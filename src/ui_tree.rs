@@ -0,0 +1,244 @@
+//! Collapsible sections and tree views for egui-based deckbuilder UIs.
+//!
+//! Provides [`ui_collapsible`] for a titled section that can be folded away, and [`ui_tree`]
+//! for nested data such as a content-pack browser or a debug state inspector. Open/closed
+//! state is kept in egui memory, so it persists between frames (and across restarts when egui
+//! persistence is enabled).
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::ui_tree::*;
+//! use egui::{CentralPanel, Context};
+//!
+//! fn browser(ctx: &Context, roots: &[TreeNode]) {
+//!     CentralPanel::default().show(ctx, |ui| {
+//!         ui_collapsible(ui, "help", "Help", false, |ui| {
+//!             ui.label("Click an entry to inspect it.");
+//!         });
+//!         let res = ui_tree(ui, "content_packs", roots);
+//!         if let Some(node) = res.clicked.as_deref().and_then(|path| node_at(roots, path)) {
+//!             println!("Selected {}", node.label);
+//!         }
+//!     });
+//! }
+//!
+//! let roots = vec![
+//!     TreeNode::new("Base game")
+//!         .child(TreeNode::new("Cards").child(TreeNode::new("Strike").with_value("Attack")))
+//!         .child(TreeNode::new("Relics")),
+//! ];
+//! ```
+//!
+//! # Details
+//!
+//! - Tree nodes are addressed by their index path from the roots (e.g. `[0, 1, 2]`).
+//! - Leaves are selectable rows; branches have a fold arrow and a selectable header.
+//! - See each struct and function's documentation for more.
+
+use std::hash::Hash;
+
+use egui::{
+    collapsing_header::{CollapsingResponse, CollapsingState},
+    CollapsingHeader, Id, Response, Ui,
+};
+
+/// Titled section whose body can be folded away. The open state is remembered per `id`.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui_tree::ui_collapsible;
+/// # fn demo(ui: &mut Ui) {
+/// ui_collapsible(ui, "stats", "Statistics", true, |ui| {
+///     ui.label("Floors climbed: 12");
+/// });
+/// # }
+/// ```
+pub fn ui_collapsible<R>(
+    ui: &mut Ui,
+    id: impl Hash,
+    title: &str,
+    default_open: bool,
+    body: impl FnOnce(&mut Ui) -> R,
+) -> CollapsingResponse<R> {
+    CollapsingHeader::new(title).id_source(id).default_open(default_open).show(ui, body)
+}
+
+/// A node of a [`ui_tree`]: a label, an optional value shown next to it and child nodes.
+#[derive(Debug, Clone, Default)]
+pub struct TreeNode {
+    pub label: String,
+    pub value: Option<String>,
+    pub children: Vec<TreeNode>,
+    pub default_open: bool,
+}
+
+impl TreeNode {
+    /// Creates a node without value or children.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_tree::TreeNode;
+    /// let node = TreeNode::new("Relics");
+    /// ```
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the value shown next to the label.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_tree::TreeNode;
+    /// let node = TreeNode::new("player_health").with_value("72");
+    /// ```
+    pub fn with_value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Adds a child node.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_tree::TreeNode;
+    /// let node = TreeNode::new("Cards").child(TreeNode::new("Strike"));
+    /// assert_eq!(node.children.len(), 1);
+    /// ```
+    pub fn child(mut self, child: TreeNode) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Makes the node start expanded.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_tree::TreeNode;
+    /// let node = TreeNode::new("Base game").open(true);
+    /// ```
+    pub fn open(mut self, open: bool) -> Self {
+        self.default_open = open;
+        self
+    }
+}
+
+/// Returns the node at `path` (child indices starting from `roots`).
+///
+/// # Example
+/// ```rust
+/// # use deckbuilder_eng::ui_tree::{node_at, TreeNode};
+/// let roots = vec![TreeNode::new("Cards").child(TreeNode::new("Strike"))];
+/// assert_eq!(node_at(&roots, &[0, 0]).map(|n| n.label.as_str()), Some("Strike"));
+/// assert!(node_at(&roots, &[1]).is_none());
+/// ```
+pub fn node_at<'a>(roots: &'a [TreeNode], path: &[usize]) -> Option<&'a TreeNode> {
+    let (first, rest) = path.split_first()?;
+    let mut node = roots.get(*first)?;
+    for &i in rest {
+        node = node.children.get(i)?;
+    }
+    Some(node)
+}
+
+/// Result of a [`ui_tree`] frame. Nodes are given as index paths.
+#[derive(Default)]
+pub struct TreeResponse {
+    /// Node under the pointer.
+    pub hovered: Option<Vec<usize>>,
+    /// Node clicked this frame.
+    pub clicked: Option<Vec<usize>>,
+}
+
+fn node_text(node: &TreeNode) -> String {
+    match &node.value {
+        Some(value) => format!("{}: {}", node.label, value),
+        None => node.label.clone(),
+    }
+}
+
+fn tree_node(
+    ui: &mut Ui,
+    id: Id,
+    node: &TreeNode,
+    path: &mut Vec<usize>,
+    selected: Option<&[usize]>,
+    out: &mut TreeResponse,
+) {
+    let is_selected = selected == Some(path.as_slice());
+    let record = |response: Response, path: &Vec<usize>, out: &mut TreeResponse| {
+        if response.hovered() {
+            out.hovered = Some(path.clone());
+        }
+        if response.clicked() {
+            out.clicked = Some(path.clone());
+        }
+    };
+
+    if node.children.is_empty() {
+        let response = ui.selectable_label(is_selected, node_text(node));
+        record(response, path, out);
+        return;
+    }
+
+    let state = CollapsingState::load_with_default_open(ui.ctx(), id.with(path.as_slice()), node.default_open);
+    let header = state.show_header(ui, |ui| ui.selectable_label(is_selected, node_text(node)));
+    let (_, label, _) = header.body(|ui| {
+        for (i, child) in node.children.iter().enumerate() {
+            path.push(i);
+            tree_node(ui, id, child, path, selected, out);
+            path.pop();
+        }
+    });
+    record(label.inner, path, out);
+}
+
+/// Tree view of nested [`TreeNode`]s with foldable branches. The open state of each branch
+/// is remembered per `id`.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui_tree::{ui_tree, TreeNode};
+/// # fn demo(ui: &mut Ui) {
+/// let roots = vec![TreeNode::new("Deck").child(TreeNode::new("Strike")).child(TreeNode::new("Defend"))];
+/// let res = ui_tree(ui, "deck_tree", &roots);
+/// if let Some(path) = res.clicked {
+///     println!("Clicked {:?}", path);
+/// }
+/// # }
+/// ```
+pub fn ui_tree(ui: &mut Ui, id: impl Hash, roots: &[TreeNode]) -> TreeResponse {
+    ui_tree_selectable(ui, id, roots, None)
+}
+
+/// Same as [`ui_tree`], highlighting the node at `selected`.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui_tree::{ui_tree_selectable, TreeNode};
+/// # fn demo(ui: &mut Ui, roots: &[TreeNode], selected: &mut Option<Vec<usize>>) {
+/// let res = ui_tree_selectable(ui, "inspector", roots, selected.as_deref());
+/// if res.clicked.is_some() {
+///     *selected = res.clicked;
+/// }
+/// # }
+/// ```
+pub fn ui_tree_selectable(ui: &mut Ui, id: impl Hash, roots: &[TreeNode], selected: Option<&[usize]>) -> TreeResponse {
+    let id = ui.make_persistent_id(id);
+    let mut out = TreeResponse::default();
+    let mut path = Vec::new();
+    ui.push_id(id, |ui| {
+        for (i, node) in roots.iter().enumerate() {
+            path.push(i);
+            tree_node(ui, id, node, &mut path, selected, &mut out);
+            path.pop();
+        }
+    });
+    out
+}
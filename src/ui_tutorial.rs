@@ -0,0 +1,383 @@
+//! Tutorial highlight overlay for egui-based deckbuilder UIs.
+//!
+//! Widgets register named anchor rects with [`tutorial_anchor`]; a [`Tutorial`] then walks
+//! through its steps with [`ui_tutorial`], dimming everything except the highlighted anchor
+//! and showing a callout with Next/Skip buttons.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::ui_tutorial::*;
+//! use egui::{CentralPanel, Context};
+//!
+//! fn first_battle(ctx: &Context, tutorial: &mut Tutorial) {
+//!     CentralPanel::default().show(ctx, |ui| {
+//!         tutorial_anchor(&ui.label("Energy: 3/3"), "energy");
+//!         tutorial_anchor(&ui.button("End Turn"), "end_turn");
+//!     });
+//!     // Draw the overlay after the widgets so this frame's anchors are known
+//!     if let Some(TutorialEvent::Finished | TutorialEvent::Skipped) = ui_tutorial(ctx, tutorial) {
+//!         println!("Tutorial done");
+//!     }
+//! }
+//!
+//! let tutorial = Tutorial::new()
+//!     .step(TutorialStep::new("energy", "Energy", "Playing cards costs energy."))
+//!     .step(TutorialStep::new("end_turn", "End your turn", "Click here when you are done.").advance_on_click());
+//! ```
+//!
+//! # Details
+//!
+//! - Clicks outside the highlighted rect are blocked while a step is shown, so the player can
+//!   only interact with the highlighted widget.
+//! - Steps whose anchor wasn't registered in this or the previous frame are shown as a
+//!   centered callout over a fully dimmed screen.
+//! - See each struct and function's documentation for more.
+
+use std::collections::HashMap;
+
+use egui::{
+    Align2, Area, Color32, Context, Frame, Id, LayerId, Order, Pos2, Rect, Response, RichText,
+    Rounding, Sense, Stroke, Vec2,
+};
+
+/// One callout of a [`Tutorial`].
+#[derive(Debug, Clone)]
+pub struct TutorialStep {
+    /// Name of the anchor to highlight.
+    pub anchor: String,
+    pub title: String,
+    pub text: String,
+    /// Advance when the highlighted widget is clicked instead of with a Next button.
+    pub advance_on_click: bool,
+}
+
+impl TutorialStep {
+    /// Creates a step highlighting `anchor`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_tutorial::TutorialStep;
+    /// let step = TutorialStep::new("hand", "Your hand", "These are the cards you can play.");
+    /// ```
+    pub fn new(anchor: impl Into<String>, title: impl Into<String>, text: impl Into<String>) -> Self {
+        Self {
+            anchor: anchor.into(),
+            title: title.into(),
+            text: text.into(),
+            advance_on_click: false,
+        }
+    }
+
+    /// Waits for the player to click the highlighted widget.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_tutorial::TutorialStep;
+    /// let step = TutorialStep::new("end_turn", "End your turn", "Click here.").advance_on_click();
+    /// ```
+    pub fn advance_on_click(mut self) -> Self {
+        self.advance_on_click = true;
+        self
+    }
+}
+
+/// What happened to a [`Tutorial`] this frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TutorialEvent {
+    /// Moved on to the step with this index.
+    Advanced(usize),
+    /// The last step was completed.
+    Finished,
+    /// The player skipped the rest of the tutorial.
+    Skipped,
+}
+
+/// Scripted sequence of tutorial steps.
+#[derive(Debug, Clone, Default)]
+pub struct Tutorial {
+    steps: Vec<TutorialStep>,
+    current: usize,
+    done: bool,
+}
+
+impl Tutorial {
+    /// Creates an empty tutorial.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_tutorial::Tutorial;
+    /// assert!(Tutorial::new().is_finished());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a step.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_tutorial::{Tutorial, TutorialStep};
+    /// let tutorial = Tutorial::new().step(TutorialStep::new("deck", "Deck", "Your draw pile."));
+    /// assert_eq!(tutorial.len(), 1);
+    /// ```
+    pub fn step(mut self, step: TutorialStep) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// Returns the number of steps.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_tutorial::Tutorial;
+    /// assert_eq!(Tutorial::new().len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Returns `true` if the tutorial has no steps.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_tutorial::Tutorial;
+    /// assert!(Tutorial::new().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Returns the index and step being shown, or `None` once finished.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_tutorial::{Tutorial, TutorialStep};
+    /// let tutorial = Tutorial::new().step(TutorialStep::new("deck", "Deck", "Your draw pile."));
+    /// assert_eq!(tutorial.current().unwrap().0, 0);
+    /// ```
+    pub fn current(&self) -> Option<(usize, &TutorialStep)> {
+        if self.done {
+            return None;
+        }
+        self.steps.get(self.current).map(|step| (self.current, step))
+    }
+
+    /// Moves to the next step.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_tutorial::{Tutorial, TutorialEvent, TutorialStep};
+    /// let mut tutorial = Tutorial::new()
+    ///     .step(TutorialStep::new("hand", "Hand", "Your cards."))
+    ///     .step(TutorialStep::new("deck", "Deck", "Your draw pile."));
+    /// assert_eq!(tutorial.advance(), TutorialEvent::Advanced(1));
+    /// assert_eq!(tutorial.advance(), TutorialEvent::Finished);
+    /// assert!(tutorial.is_finished());
+    /// ```
+    pub fn advance(&mut self) -> TutorialEvent {
+        self.current += 1;
+        if self.current >= self.steps.len() {
+            self.done = true;
+            TutorialEvent::Finished
+        } else {
+            TutorialEvent::Advanced(self.current)
+        }
+    }
+
+    /// Ends the tutorial early.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_tutorial::{Tutorial, TutorialStep};
+    /// let mut tutorial = Tutorial::new().step(TutorialStep::new("hand", "Hand", "Your cards."));
+    /// tutorial.skip();
+    /// assert!(tutorial.is_finished());
+    /// ```
+    pub fn skip(&mut self) -> TutorialEvent {
+        self.done = true;
+        TutorialEvent::Skipped
+    }
+
+    /// Starts over from the first step.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_tutorial::{Tutorial, TutorialStep};
+    /// let mut tutorial = Tutorial::new().step(TutorialStep::new("hand", "Hand", "Your cards."));
+    /// tutorial.skip();
+    /// tutorial.restart();
+    /// assert!(!tutorial.is_finished());
+    /// ```
+    pub fn restart(&mut self) {
+        self.current = 0;
+        self.done = false;
+    }
+
+    /// Returns `true` once all steps are done or the tutorial was skipped.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_tutorial::Tutorial;
+    /// assert!(Tutorial::new().is_finished());
+    /// ```
+    pub fn is_finished(&self) -> bool {
+        self.current().is_none()
+    }
+}
+
+#[derive(Clone, Default)]
+struct Anchors {
+    rects: HashMap<String, (Rect, u64)>,
+    clicked: Option<(String, u64)>,
+}
+
+fn anchors_id() -> Id {
+    Id::new("deckbuilder_tutorial_anchors")
+}
+
+/// Registers the rect of `response` as the tutorial anchor `name`, and remembers clicks for
+/// steps that advance on click. Call every frame the widget is shown.
+///
+/// # Example
+/// ```rust
+/// # use egui::Ui;
+/// # use deckbuilder_eng::ui_tutorial::tutorial_anchor;
+/// # fn demo(ui: &mut Ui) {
+/// if tutorial_anchor(&ui.button("Map"), "map_button").clicked() {
+///     println!("Open map");
+/// }
+/// # }
+/// ```
+pub fn tutorial_anchor<'a>(response: &'a Response, name: &str) -> &'a Response {
+    register_anchor(&response.ctx, name, response.rect);
+    if response.clicked() {
+        let frame = response.ctx.frame_nr();
+        response.ctx.data_mut(|d| {
+            d.get_temp_mut_or_default::<Anchors>(anchors_id()).clicked = Some((name.to_owned(), frame));
+        });
+    }
+    response
+}
+
+/// Registers `rect` as the tutorial anchor `name`, for areas that aren't a single widget.
+///
+/// # Example
+/// ```rust
+/// # use egui::{Context, Rect, Pos2, Vec2};
+/// # use deckbuilder_eng::ui_tutorial::register_anchor;
+/// let ctx = Context::default();
+/// register_anchor(&ctx, "hand", Rect::from_min_size(Pos2::new(100.0, 500.0), Vec2::new(600.0, 200.0)));
+/// ```
+pub fn register_anchor(ctx: &Context, name: &str, rect: Rect) {
+    let frame = ctx.frame_nr();
+    ctx.data_mut(|d| {
+        d.get_temp_mut_or_default::<Anchors>(anchors_id())
+            .rects
+            .insert(name.to_owned(), (rect, frame));
+    });
+}
+
+/// Shows the current step of `tutorial`: dims the screen except the anchor, draws the callout
+/// and handles Next/Skip. Call once per frame after the anchored widgets. Returns what
+/// happened this frame.
+///
+/// # Example
+/// ```rust
+/// # use egui::Context;
+/// # use deckbuilder_eng::ui_tutorial::{ui_tutorial, Tutorial, TutorialEvent};
+/// # fn demo(ctx: &Context, tutorial: &mut Tutorial) {
+/// if let Some(TutorialEvent::Advanced(step)) = ui_tutorial(ctx, tutorial) {
+///     println!("Step {}", step + 1);
+/// }
+/// # }
+/// ```
+pub fn ui_tutorial(ctx: &Context, tutorial: &mut Tutorial) -> Option<TutorialEvent> {
+    let (index, step) = tutorial.current()?;
+    let step = step.clone();
+    let count = tutorial.len();
+    let frame = ctx.frame_nr();
+    let anchors = ctx.data(|d| d.get_temp::<Anchors>(anchors_id())).unwrap_or_default();
+    let highlight = anchors
+        .rects
+        .get(&step.anchor)
+        .filter(|(_, seen)| frame.saturating_sub(*seen) <= 1)
+        .map(|(rect, _)| rect.expand(6.0));
+    let anchor_clicked = anchors
+        .clicked
+        .as_ref()
+        .is_some_and(|(name, seen)| *name == step.anchor && frame.saturating_sub(*seen) <= 1);
+
+    let mut event = None;
+    if step.advance_on_click && anchor_clicked {
+        ctx.data_mut(|d| d.get_temp_mut_or_default::<Anchors>(anchors_id()).clicked = None);
+        event = Some(tutorial.advance());
+    }
+
+    let screen = ctx.screen_rect();
+    let dim_id = Id::new("deckbuilder_tutorial_dim");
+    Area::new(dim_id)
+        .order(Order::Foreground)
+        .fixed_pos(screen.min)
+        .interactable(true)
+        .show(ctx, |ui| {
+            let dim = Color32::from_black_alpha(170);
+            let blockers = match highlight {
+                Some(hole) => vec![
+                    Rect::from_min_max(screen.min, Pos2::new(screen.max.x, hole.min.y)),
+                    Rect::from_min_max(Pos2::new(screen.min.x, hole.max.y), screen.max),
+                    Rect::from_min_max(Pos2::new(screen.min.x, hole.min.y), Pos2::new(hole.min.x, hole.max.y)),
+                    Rect::from_min_max(Pos2::new(hole.max.x, hole.min.y), Pos2::new(screen.max.x, hole.max.y)),
+                ],
+                None => vec![screen],
+            };
+            for (i, blocker) in blockers.into_iter().enumerate() {
+                ui.painter().rect_filled(blocker, 0.0, dim);
+                // Swallow clicks so only the highlighted widget can be used.
+                ui.interact(blocker, dim_id.with(i), Sense::click_and_drag());
+            }
+            if let Some(hole) = highlight {
+                let wave = ((ui.input(|i| i.time) * 3.0).sin() * 0.5 + 0.5) as f32;
+                ui.painter().rect_stroke(
+                    hole,
+                    Rounding::same(6.0),
+                    Stroke::new(2.0 + wave, Color32::from_rgb(250, 210, 120)),
+                );
+                ctx.request_repaint();
+            }
+        });
+
+    let callout_id = Id::new("deckbuilder_tutorial_callout");
+    let area = Area::new(callout_id).order(Order::Foreground);
+    let area = match highlight {
+        Some(hole) if hole.bottom() + 160.0 < screen.bottom() => {
+            area.pivot(Align2::CENTER_TOP).fixed_pos(Pos2::new(hole.center().x, hole.bottom() + 12.0))
+        }
+        Some(hole) => area.pivot(Align2::CENTER_BOTTOM).fixed_pos(Pos2::new(hole.center().x, hole.top() - 12.0)),
+        None => area.anchor(Align2::CENTER_CENTER, Vec2::ZERO),
+    };
+    area.show(ctx, |ui| {
+        Frame::popup(ui.style()).show(ui, |ui| {
+            ui.set_max_width(280.0);
+            ui.label(RichText::new(&step.title).strong().size(16.0));
+            ui.label(&step.text);
+            ui.add_space(6.0);
+            ui.horizontal(|ui| {
+                ui.weak(format!("{}/{}", index + 1, count));
+                if ui.button("Skip").clicked() {
+                    event = Some(tutorial.skip());
+                }
+                if !step.advance_on_click {
+                    let label = if index + 1 == count { "Done" } else { "Next" };
+                    if ui.button(label).clicked() {
+                        event = Some(tutorial.advance());
+                    }
+                }
+            });
+        });
+    });
+
+    ctx.move_to_top(LayerId::new(Order::Foreground, dim_id));
+    ctx.move_to_top(LayerId::new(Order::Foreground, callout_id));
+    event
+}
@@ -0,0 +1,391 @@
+//! Window manager for egui-based deckbuilder UIs.
+//!
+//! Provides a [`WindowManager`] on top of egui [`Window`]s: game windows (deck view, combat
+//! log, options, ...) are registered once, then opened, closed, raised and pinned by id. Window
+//! positions are remembered while the windows are closed.
+//!
+//! # Example
+//!
+//! ```rust
+//! use deckbuilder_eng::ui_screen::ScreenStack;
+//! use deckbuilder_eng::ui_window::{GameWindow, WindowManager};
+//! use egui::Context;
+//!
+//! let mut windows = WindowManager::new();
+//! windows.register(GameWindow::new("deck", "Deck").default_size([320.0, 400.0]));
+//! windows.register(GameWindow::new("log", "Combat log").pinned(true));
+//! windows.open("deck");
+//!
+//! fn frame(ctx: &Context, screens: &mut ScreenStack, windows: &mut WindowManager) {
+//!     screens.show(ctx);
+//!     // Closes unpinned windows whenever the screen changes.
+//!     windows.follow_screens(screens);
+//!     windows.show(ctx, |id, ui| match id {
+//!         "deck" => {
+//!             ui.label("20 cards");
+//!         }
+//!         "log" => {
+//!             ui.label("Turn 1");
+//!         }
+//!         _ => {}
+//!     });
+//! }
+//! ```
+//!
+//! # Details
+//!
+//! - Pinned windows are kept above the other windows and survive [`WindowManager::close_all`].
+//! - Windows are drawn in registration order; [`WindowManager::bring_to_front`] raises one.
+//! - See each struct and function's documentation for more.
+
+use egui::{Context, Id, Pos2, Rect, Ui, Vec2, Window};
+
+use crate::ui_screen::ScreenStack;
+
+/// A window registered with a [`WindowManager`].
+#[derive(Debug, Clone)]
+pub struct GameWindow {
+    /// Id used by the manager's API and passed to the content callback.
+    pub id: String,
+    /// Title bar text.
+    pub title: String,
+    /// Position of the top-left corner the first time the window opens.
+    pub default_pos: Option<Pos2>,
+    /// Size the first time the window opens.
+    pub default_size: Option<Vec2>,
+    /// Whether the window can be resized.
+    pub resizable: bool,
+    /// Pinned windows stay on top and survive [`WindowManager::close_all`].
+    pub pinned: bool,
+    open: bool,
+    raise: bool,
+    rect: Option<Rect>,
+}
+
+impl GameWindow {
+    /// Creates a closed, unpinned, resizable window.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_window::GameWindow;
+    /// let window = GameWindow::new("options", "Options");
+    /// ```
+    pub fn new(id: impl Into<String>, title: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            default_pos: None,
+            default_size: None,
+            resizable: true,
+            pinned: false,
+            open: false,
+            raise: false,
+            rect: None,
+        }
+    }
+
+    /// Sets where the window first appears.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_window::GameWindow;
+    /// let window = GameWindow::new("log", "Combat log").default_pos([20.0, 300.0]);
+    /// ```
+    pub fn default_pos(mut self, pos: impl Into<Pos2>) -> Self {
+        self.default_pos = Some(pos.into());
+        self
+    }
+
+    /// Sets the window's initial size.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_window::GameWindow;
+    /// let window = GameWindow::new("deck", "Deck").default_size([320.0, 400.0]);
+    /// ```
+    pub fn default_size(mut self, size: impl Into<Vec2>) -> Self {
+        self.default_size = Some(size.into());
+        self
+    }
+
+    /// Sets whether the window can be resized.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_window::GameWindow;
+    /// let window = GameWindow::new("options", "Options").resizable(false);
+    /// ```
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+
+    /// Pins the window: it stays on top and survives [`WindowManager::close_all`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_window::GameWindow;
+    /// let window = GameWindow::new("log", "Combat log").pinned(true);
+    /// ```
+    pub fn pinned(mut self, pinned: bool) -> Self {
+        self.pinned = pinned;
+        self
+    }
+
+    /// Makes the window start open.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_window::GameWindow;
+    /// let window = GameWindow::new("log", "Combat log").open(true);
+    /// ```
+    pub fn open(mut self, open: bool) -> Self {
+        self.open = open;
+        self
+    }
+}
+
+/// Registered game windows with open/close, z-order and pinning control.
+#[derive(Default)]
+pub struct WindowManager {
+    windows: Vec<GameWindow>,
+    screen_generation: Option<u64>,
+}
+
+impl WindowManager {
+    /// Creates a manager without windows.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_window::WindowManager;
+    /// let windows = WindowManager::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `window`, replacing any window with the same id.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_window::{GameWindow, WindowManager};
+    /// let mut windows = WindowManager::new();
+    /// windows.register(GameWindow::new("deck", "Deck"));
+    /// assert!(!windows.is_open("deck"));
+    /// ```
+    pub fn register(&mut self, window: GameWindow) {
+        match self.windows.iter_mut().find(|w| w.id == window.id) {
+            Some(existing) => *existing = window,
+            None => self.windows.push(window),
+        }
+    }
+
+    /// Returns the registered window with `id`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_window::{GameWindow, WindowManager};
+    /// let mut windows = WindowManager::new();
+    /// windows.register(GameWindow::new("deck", "Deck"));
+    /// assert_eq!(windows.get("deck").map(|w| w.title.as_str()), Some("Deck"));
+    /// ```
+    pub fn get(&self, id: &str) -> Option<&GameWindow> {
+        self.windows.iter().find(|w| w.id == id)
+    }
+
+    fn get_mut(&mut self, id: &str) -> Option<&mut GameWindow> {
+        self.windows.iter_mut().find(|w| w.id == id)
+    }
+
+    /// Opens the window with `id` and brings it to the front.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_window::{GameWindow, WindowManager};
+    /// let mut windows = WindowManager::new();
+    /// windows.register(GameWindow::new("deck", "Deck"));
+    /// windows.open("deck");
+    /// assert!(windows.is_open("deck"));
+    /// ```
+    pub fn open(&mut self, id: &str) {
+        if let Some(window) = self.get_mut(id) {
+            window.open = true;
+            window.raise = true;
+        }
+    }
+
+    /// Closes the window with `id`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_window::{GameWindow, WindowManager};
+    /// let mut windows = WindowManager::new();
+    /// windows.register(GameWindow::new("deck", "Deck").open(true));
+    /// windows.close("deck");
+    /// assert!(!windows.is_open("deck"));
+    /// ```
+    pub fn close(&mut self, id: &str) {
+        if let Some(window) = self.get_mut(id) {
+            window.open = false;
+        }
+    }
+
+    /// Opens the window with `id` if it is closed, closes it otherwise.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_window::{GameWindow, WindowManager};
+    /// let mut windows = WindowManager::new();
+    /// windows.register(GameWindow::new("deck", "Deck"));
+    /// windows.toggle("deck");
+    /// assert!(windows.is_open("deck"));
+    /// ```
+    pub fn toggle(&mut self, id: &str) {
+        if self.is_open(id) {
+            self.close(id);
+        } else {
+            self.open(id);
+        }
+    }
+
+    /// Returns `true` if the window with `id` is open.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_window::WindowManager;
+    /// assert!(!WindowManager::new().is_open("deck"));
+    /// ```
+    pub fn is_open(&self, id: &str) -> bool {
+        self.get(id).is_some_and(|w| w.open)
+    }
+
+    /// Raises the window with `id` above the other windows on the next frame.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_window::{GameWindow, WindowManager};
+    /// let mut windows = WindowManager::new();
+    /// windows.register(GameWindow::new("log", "Combat log").open(true));
+    /// windows.bring_to_front("log");
+    /// ```
+    pub fn bring_to_front(&mut self, id: &str) {
+        if let Some(window) = self.get_mut(id) {
+            window.raise = true;
+        }
+    }
+
+    /// Pins or unpins the window with `id`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_window::{GameWindow, WindowManager};
+    /// let mut windows = WindowManager::new();
+    /// windows.register(GameWindow::new("log", "Combat log"));
+    /// windows.set_pinned("log", true);
+    /// assert!(windows.get("log").unwrap().pinned);
+    /// ```
+    pub fn set_pinned(&mut self, id: &str, pinned: bool) {
+        if let Some(window) = self.get_mut(id) {
+            window.pinned = pinned;
+        }
+    }
+
+    /// Closes every window that isn't pinned.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_window::{GameWindow, WindowManager};
+    /// let mut windows = WindowManager::new();
+    /// windows.register(GameWindow::new("deck", "Deck").open(true));
+    /// windows.register(GameWindow::new("log", "Combat log").open(true).pinned(true));
+    /// windows.close_all();
+    /// assert!(!windows.is_open("deck"));
+    /// assert!(windows.is_open("log"));
+    /// ```
+    pub fn close_all(&mut self) {
+        for window in self.windows.iter_mut().filter(|w| !w.pinned) {
+            window.open = false;
+        }
+    }
+
+    /// Calls [`close_all`](Self::close_all) whenever `screens` navigated since the last call.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_screen::ScreenStack;
+    /// # use deckbuilder_eng::ui_window::{GameWindow, WindowManager};
+    /// let screens = ScreenStack::new();
+    /// let mut windows = WindowManager::new();
+    /// windows.register(GameWindow::new("deck", "Deck").open(true));
+    /// windows.follow_screens(&screens);
+    /// assert!(windows.is_open("deck"));
+    /// ```
+    pub fn follow_screens(&mut self, screens: &ScreenStack) {
+        let generation = screens.generation();
+        if self.screen_generation.is_some_and(|g| g != generation) {
+            self.close_all();
+        }
+        self.screen_generation = Some(generation);
+    }
+
+    /// Returns the window's rectangle from the last frame it was shown.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::ui_window::{GameWindow, WindowManager};
+    /// let mut windows = WindowManager::new();
+    /// windows.register(GameWindow::new("deck", "Deck"));
+    /// assert!(windows.rect("deck").is_none());
+    /// ```
+    pub fn rect(&self, id: &str) -> Option<Rect> {
+        self.get(id).and_then(|w| w.rect)
+    }
+
+    /// Shows all open windows, calling `content` with each window's id and inner Ui.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui::Context;
+    /// # use deckbuilder_eng::ui_window::WindowManager;
+    /// # fn demo(ctx: &Context, windows: &mut WindowManager) {
+    /// windows.show(ctx, |id, ui| {
+    ///     if id == "options" {
+    ///         ui.label("Volume");
+    ///     }
+    /// });
+    /// # }
+    /// ```
+    pub fn show(&mut self, ctx: &Context, mut content: impl FnMut(&str, &mut Ui)) {
+        let mut pinned_layers = Vec::new();
+        for window in self.windows.iter_mut().filter(|w| w.open) {
+            let mut open = true;
+            let mut egui_window = Window::new(window.title.as_str())
+                .id(Id::new(("deckbuilder_window", window.id.as_str())))
+                .open(&mut open)
+                .resizable(window.resizable);
+            // egui keeps the position of closed windows; this restores it if egui memory was reset.
+            if let Some(pos) = window.rect.map(|r| r.min).or(window.default_pos) {
+                egui_window = egui_window.default_pos(pos);
+            }
+            if let Some(size) = window.default_size {
+                egui_window = egui_window.default_size(size);
+            }
+            let id = window.id.as_str();
+            if let Some(response) = egui_window.show(ctx, |ui| content(id, ui)) {
+                let layer = response.response.layer_id;
+                window.rect = Some(response.response.rect);
+                if window.raise {
+                    ctx.move_to_top(layer);
+                }
+                if window.pinned {
+                    pinned_layers.push(layer);
+                }
+            }
+            window.raise = false;
+            window.open = open;
+        }
+        for layer in pinned_layers {
+            ctx.move_to_top(layer);
+        }
+    }
+}
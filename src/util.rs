@@ -0,0 +1,32 @@
+//! Small helpers shared by modules that don't otherwise depend on each other.
+
+use std::io;
+use std::path::Path;
+
+/// Seconds since the Unix epoch; `0` on `wasm32`, where the system clock isn't available.
+pub(crate) fn unix_now() -> u64 {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs())
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        0
+    }
+}
+
+/// Flushes the directory entries of `dir`, so renames inside it survive a power loss. Only
+/// Unix can open a directory for syncing; elsewhere this does nothing.
+pub(crate) fn sync_dir(dir: &Path) -> io::Result<()> {
+    #[cfg(unix)]
+    {
+        std::fs::File::open(dir)?.sync_all()
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = dir;
+        Ok(())
+    }
+}
@@ -0,0 +1,433 @@
+//! Virtual filesystem for game assets.
+//!
+//! Provides the [`AssetSource`] trait with a [`DirSource`] for plain directories and a
+//! [`PakSource`] for `.pak` archives built with [`PakBuilder`], and a layered [`Vfs`] that looks
+//! files up in mod override directories before the shipped pack.
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::sync::Arc;
+//! use deckbuilder_eng::texture::TextureManager;
+//! use deckbuilder_eng::vfs::{AssetSource, DirSource, PakBuilder, PakSource, Vfs};
+//!
+//! // Build step: pack the assets directory into one file.
+//! let pak = std::env::temp_dir().join("deckbuilder_vfs_example.pak");
+//! PakBuilder::new().add_dir("assets").unwrap().write(&pak).unwrap();
+//!
+//! // Game: read from the pack, with a mods directory taking priority.
+//! let mut vfs = Vfs::new();
+//! vfs.mount(PakSource::open(&pak).unwrap());
+//! vfs.mount(DirSource::new("mods/my_mod"));
+//! assert!(vfs.exists("sfx/click.wav".as_ref()));
+//!
+//! let mut textures = TextureManager::new(&egui::Context::default());
+//! textures.set_source(Arc::new(vfs));
+//! let frames = textures.load_gif("example.gif", 1.0);
+//! assert!(frames.is_ok());
+//! ```
+//!
+//! # Details
+//!
+//! - Paths are relative to the source root and use `/` inside archives; `.` and `..` components
+//!   are resolved before lookup.
+//! - A pack is a header (`DBPAK1`, entry count), an index of path/offset/size records and the
+//!   uncompressed file contents. Files are read on demand, so large packs aren't held in memory.
+//! - Sources mounted later in a [`Vfs`] override earlier ones.
+//! - See each struct and function's documentation for more.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, Cursor, Read, Seek, SeekFrom};
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
+
+const PAK_MAGIC: &[u8; 8] = b"DBPAK1\0\0";
+
+/// A place assets are read from.
+pub trait AssetSource: Send + Sync {
+    /// Reads the whole file at `path`.
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>>;
+
+    /// Returns `true` if the source has a file at `path`.
+    fn exists(&self, path: &Path) -> bool;
+
+    /// Returns the paths of all files in the source.
+    fn files(&self) -> Vec<PathBuf>;
+
+    /// Reads the file at `path` as UTF-8 text, e.g. a manifest or card database.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::vfs::{AssetSource, DirSource};
+    /// assert!(DirSource::new("assets").read_to_string("missing.json".as_ref()).is_err());
+    /// ```
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        String::from_utf8(self.read(path)?).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+}
+
+/// Resolves `.` and `..` and joins the remaining components with `/`.
+fn normalize(path: &Path) -> String {
+    let mut parts: Vec<String> = Vec::new();
+    for component in path.components() {
+        match component {
+            Component::Normal(part) => parts.push(part.to_string_lossy().into_owned()),
+            Component::ParentDir => {
+                parts.pop();
+            }
+            _ => {}
+        }
+    }
+    parts.join("/")
+}
+
+/// Files of a directory on disk.
+#[derive(Debug, Clone)]
+pub struct DirSource {
+    root: PathBuf,
+}
+
+impl DirSource {
+    /// Creates a source reading files below `root`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::vfs::{AssetSource, DirSource};
+    /// let assets = DirSource::new("assets");
+    /// assert!(assets.exists("music/background.ogg".as_ref()));
+    /// ```
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Returns the root directory.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::vfs::DirSource;
+    /// assert_eq!(DirSource::new("mods").root(), std::path::Path::new("mods"));
+    /// ```
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn resolve(&self, path: &Path) -> PathBuf {
+        self.root.join(normalize(path))
+    }
+}
+
+impl AssetSource for DirSource {
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        std::fs::read(self.resolve(path))
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        self.resolve(path).is_file()
+    }
+
+    fn files(&self) -> Vec<PathBuf> {
+        let mut files = Vec::new();
+        walk_dir(&self.root, Path::new(""), &mut files);
+        files.sort();
+        files
+    }
+}
+
+/// Collects the files below `dir` as paths relative to the walk's root.
+fn walk_dir(dir: &Path, relative: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let relative = relative.join(entry.file_name());
+        if path.is_dir() {
+            walk_dir(&path, &relative, files);
+        } else {
+            files.push(relative);
+        }
+    }
+}
+
+trait PakReader: Read + Seek + Send {}
+
+impl<T: Read + Seek + Send> PakReader for T {}
+
+/// Files of a `.pak` archive written by [`PakBuilder`].
+pub struct PakSource {
+    reader: Mutex<Box<dyn PakReader>>,
+    index: HashMap<String, (u64, u64)>,
+}
+
+impl PakSource {
+    /// Opens the archive at `path`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::vfs::PakSource;
+    /// assert!(PakSource::open("missing.pak").is_err());
+    /// ```
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::from_reader(File::open(path)?)
+    }
+
+    /// Reads an archive held in memory, e.g. one embedded with `include_bytes!`. An archive
+    /// whose index points past its end is refused with [`io::ErrorKind::InvalidData`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::vfs::{AssetSource, PakBuilder, PakSource};
+    /// let bytes = PakBuilder::new().add("cards.json", b"[]".to_vec()).to_bytes();
+    /// let pak = PakSource::from_bytes(bytes.clone()).unwrap();
+    /// assert_eq!(pak.read_to_string("cards.json".as_ref()).unwrap(), "[]");
+    ///
+    /// let truncated = bytes[..bytes.len() - 1].to_vec();
+    /// assert_eq!(PakSource::from_bytes(truncated).err().unwrap().kind(), std::io::ErrorKind::InvalidData);
+    /// ```
+    pub fn from_bytes(bytes: impl AsRef<[u8]> + Send + 'static) -> io::Result<Self> {
+        Self::from_reader(Cursor::new(bytes))
+    }
+
+    fn from_reader(mut reader: impl Read + Seek + Send + 'static) -> io::Result<Self> {
+        let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidData, message.to_owned());
+        let mut magic = [0; 8];
+        reader.read_exact(&mut magic)?;
+        if &magic != PAK_MAGIC {
+            return Err(invalid("not an asset pack"));
+        }
+        let count = read_u32(&mut reader)?;
+        // Sizes come from the file, so they are checked against its length before allocating
+        let position = reader.stream_position()?;
+        let length = reader.seek(SeekFrom::End(0))?;
+        reader.seek(SeekFrom::Start(position))?;
+        let mut index = HashMap::new();
+        for _ in 0..count {
+            let len = u64::from(read_u32(&mut reader)?);
+            if len > length - reader.stream_position()? {
+                return Err(invalid("asset pack path runs past the end of the file"));
+            }
+            let mut path = vec![0; len as usize];
+            reader.read_exact(&mut path)?;
+            let path = String::from_utf8(path).map_err(|_| invalid("asset pack path is not UTF-8"))?;
+            let offset = read_u64(&mut reader)?;
+            let size = read_u64(&mut reader)?;
+            if offset.checked_add(size).is_none_or(|end| end > length) {
+                return Err(invalid("asset pack file runs past the end of the file"));
+            }
+            index.insert(path, (offset, size));
+        }
+        Ok(Self { reader: Mutex::new(Box::new(reader)), index })
+    }
+
+    /// Returns the number of files in the archive.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::vfs::{PakBuilder, PakSource};
+    /// let pak = PakSource::from_bytes(PakBuilder::new().to_bytes()).unwrap();
+    /// assert_eq!(pak.file_count(), 0);
+    /// ```
+    pub fn file_count(&self) -> usize {
+        self.index.len()
+    }
+}
+
+impl AssetSource for PakSource {
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        let Some(&(offset, size)) = self.index.get(&normalize(path)) else {
+            return Err(io::Error::new(io::ErrorKind::NotFound, format!("{} is not in the asset pack", path.display())));
+        };
+        // The reader is seeked before every read, so a panic while it was locked leaves no state behind
+        let mut reader = self.reader.lock().unwrap_or_else(PoisonError::into_inner);
+        reader.seek(SeekFrom::Start(offset))?;
+        let mut bytes = vec![0; size as usize];
+        reader.read_exact(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        self.index.contains_key(&normalize(path))
+    }
+
+    fn files(&self) -> Vec<PathBuf> {
+        let mut files: Vec<PathBuf> = self.index.keys().map(PathBuf::from).collect();
+        files.sort();
+        files
+    }
+}
+
+fn read_u32(reader: &mut impl Read) -> io::Result<u32> {
+    let mut bytes = [0; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_u64(reader: &mut impl Read) -> io::Result<u64> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+/// Builds a `.pak` archive for [`PakSource`].
+#[derive(Debug, Clone, Default)]
+pub struct PakBuilder {
+    files: Vec<(String, Vec<u8>)>,
+}
+
+impl PakBuilder {
+    /// Creates an empty archive.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::vfs::PakBuilder;
+    /// let bytes = PakBuilder::new().to_bytes();
+    /// assert!(bytes.starts_with(b"DBPAK1"));
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a file, replacing an earlier one with the same path.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::vfs::PakBuilder;
+    /// let pak = PakBuilder::new()
+    ///     .add("data/cards.json", b"[]".to_vec())
+    ///     .add("data/relics.json", b"[]".to_vec());
+    /// ```
+    pub fn add(mut self, path: impl AsRef<Path>, bytes: Vec<u8>) -> Self {
+        let path = normalize(path.as_ref());
+        self.files.retain(|(existing, _)| *existing != path);
+        self.files.push((path, bytes));
+        self
+    }
+
+    /// Adds every file below `dir`, with paths relative to `dir`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::vfs::{AssetSource, PakBuilder, PakSource};
+    /// let bytes = PakBuilder::new().add_dir("assets").unwrap().to_bytes();
+    /// let pak = PakSource::from_bytes(bytes).unwrap();
+    /// assert!(pak.exists("music/background.ogg".as_ref()));
+    /// ```
+    pub fn add_dir(mut self, dir: impl AsRef<Path>) -> io::Result<Self> {
+        let dir = dir.as_ref();
+        if !dir.is_dir() {
+            return Err(io::Error::new(io::ErrorKind::NotFound, format!("{} is not a directory", dir.display())));
+        }
+        for path in DirSource::new(dir).files() {
+            let bytes = std::fs::read(dir.join(&path))?;
+            self = self.add(path, bytes);
+        }
+        Ok(self)
+    }
+
+    /// Returns the archive's bytes.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::vfs::{PakBuilder, PakSource};
+    /// let bytes = PakBuilder::new().add("a.txt", b"a".to_vec()).to_bytes();
+    /// assert_eq!(PakSource::from_bytes(bytes).unwrap().file_count(), 1);
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let index_len: usize = self.files.iter().map(|(path, _)| 4 + path.len() + 16).sum();
+        let mut offset = (PAK_MAGIC.len() + 4 + index_len) as u64;
+        let mut bytes = Vec::with_capacity(offset as usize + self.files.iter().map(|(_, data)| data.len()).sum::<usize>());
+        bytes.extend_from_slice(PAK_MAGIC);
+        bytes.extend_from_slice(&(self.files.len() as u32).to_le_bytes());
+        for (path, data) in &self.files {
+            bytes.extend_from_slice(&(path.len() as u32).to_le_bytes());
+            bytes.extend_from_slice(path.as_bytes());
+            bytes.extend_from_slice(&offset.to_le_bytes());
+            bytes.extend_from_slice(&(data.len() as u64).to_le_bytes());
+            offset += data.len() as u64;
+        }
+        for (_, data) in &self.files {
+            bytes.extend_from_slice(data);
+        }
+        bytes
+    }
+
+    /// Writes the archive to `path`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::vfs::PakBuilder;
+    /// let path = std::env::temp_dir().join("deckbuilder_vfs_write.pak");
+    /// PakBuilder::new().add("a.txt", b"a".to_vec()).write(&path).unwrap();
+    /// ```
+    pub fn write(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        std::fs::write(path, self.to_bytes())
+    }
+}
+
+/// Layered sources: a file is read from the last mounted source that has it, so mod directories
+/// mounted after the shipped pack override its files.
+#[derive(Default)]
+pub struct Vfs {
+    sources: Vec<Box<dyn AssetSource>>,
+}
+
+impl Vfs {
+    /// Creates a filesystem without sources.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::vfs::{AssetSource, Vfs};
+    /// assert!(Vfs::new().files().is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a source that takes priority over all sources mounted before it.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::vfs::{AssetSource, DirSource, PakBuilder, PakSource, Vfs};
+    /// let mut vfs = Vfs::new();
+    /// vfs.mount(PakSource::from_bytes(PakBuilder::new().add("a.txt", b"base".to_vec()).to_bytes()).unwrap());
+    /// vfs.mount(PakSource::from_bytes(PakBuilder::new().add("a.txt", b"mod".to_vec()).to_bytes()).unwrap());
+    /// assert_eq!(vfs.read_to_string("a.txt".as_ref()).unwrap(), "mod");
+    /// ```
+    pub fn mount(&mut self, source: impl AssetSource + 'static) {
+        self.sources.push(Box::new(source));
+    }
+
+    /// Returns the number of mounted sources.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::vfs::{DirSource, Vfs};
+    /// let mut vfs = Vfs::new();
+    /// vfs.mount(DirSource::new("assets"));
+    /// assert_eq!(vfs.source_count(), 1);
+    /// ```
+    pub fn source_count(&self) -> usize {
+        self.sources.len()
+    }
+}
+
+impl AssetSource for Vfs {
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        match self.sources.iter().rev().find(|source| source.exists(path)) {
+            Some(source) => source.read(path),
+            None => Err(io::Error::new(io::ErrorKind::NotFound, format!("{} is in no mounted source", path.display()))),
+        }
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        self.sources.iter().any(|source| source.exists(path))
+    }
+
+    fn files(&self) -> Vec<PathBuf> {
+        let mut files: Vec<PathBuf> = self.sources.iter().flat_map(|source| source.files()).collect();
+        files.sort();
+        files.dedup();
+        files
+    }
+}
@@ -0,0 +1,233 @@
+//! Browser support for `wasm32-unknown-unknown` builds.
+//!
+//! Provides [`fetch_bytes`] and [`fetch_text`] to download files with the browser's `fetch` API,
+//! [`fetch_pak`] to download an asset pack as a [`PakSource`] that texture, GIF and audio
+//! loaders can read from, and [`WebSocketTransport`] to join networked sessions.
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::sync::Arc;
+//! use deckbuilder_eng::texture::TextureManager;
+//! use deckbuilder_eng::web::fetch_pak;
+//!
+//! async fn load(mut textures: TextureManager) {
+//!     let pak = fetch_pak("assets.pak").await.unwrap();
+//!     textures.set_source(Arc::new(pak));
+//!     let card_back = textures.load("cards/back.png");
+//! }
+//! ```
+//!
+//! # Details
+//!
+//! - Browsers have no filesystem: paths passed to loaders must come from an
+//!   [`AssetSource`](crate::vfs::AssetSource) set with `set_source`, typically a fetched pack.
+//!   Sounds are decoded from bytes with `AudioManager::load_sound_from_bytes`.
+//! - URLs are relative to the page, like any `fetch` call.
+//! - Browsers can't accept connections, so a browser is always a [`Client`](crate::net::Client).
+//!   [`WebSocketTransport`] carries the same newline-separated stream as a
+//!   [`TcpTransport`](crate::net::TcpTransport), so a native [`Host`](crate::net::Host)
+//!   listening on TCP serves browser clients through a WebSocket-to-TCP proxy such as
+//!   websockify.
+//! - This module only exists on `wasm32` targets.
+//! - See each function's documentation for more.
+
+use std::cell::RefCell;
+use std::io::{self, ErrorKind};
+use std::rc::Rc;
+
+use js_sys::Uint8Array;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{BinaryType, MessageEvent, Response, WebSocket};
+
+use crate::error::{Error, Result};
+use crate::net::{LineBuffer, Transport};
+use crate::vfs::PakSource;
+
+/// Downloads `url` and returns the response body.
+///
+/// Fails with [`Error::Io`] (with `url` as the path) if the request fails or the server doesn't
+/// answer with a success status.
+///
+/// # Example
+/// ```rust
+/// # use deckbuilder_eng::web::fetch_bytes;
+/// # async fn demo() {
+/// let bytes = fetch_bytes("assets/sfx/click.wav").await.unwrap();
+/// # }
+/// ```
+pub async fn fetch_bytes(url: &str) -> Result<Vec<u8>> {
+    let window = web_sys::window().ok_or_else(|| fetch_error(url, "no browser window"))?;
+    let response: Response = JsFuture::from(window.fetch_with_str(url))
+        .await
+        .and_then(|response| response.dyn_into())
+        .map_err(|err| js_error(url, err))?;
+    if !response.ok() {
+        return Err(fetch_error(url, &format!("HTTP {} {}", response.status(), response.status_text())));
+    }
+    let buffer = response.array_buffer().map_err(|err| js_error(url, err))?;
+    let buffer = JsFuture::from(buffer).await.map_err(|err| js_error(url, err))?;
+    Ok(Uint8Array::new(&buffer).to_vec())
+}
+
+/// Downloads `url` as UTF-8 text, e.g. an asset manifest or a string table.
+///
+/// # Example
+/// ```rust
+/// # use deckbuilder_eng::web::fetch_text;
+/// # async fn demo() {
+/// let manifest = fetch_text("assets/manifest.txt").await.unwrap();
+/// # }
+/// ```
+pub async fn fetch_text(url: &str) -> Result<String> {
+    let bytes = fetch_bytes(url).await?;
+    String::from_utf8(bytes).map_err(|err| Error::io(url, io::Error::new(io::ErrorKind::InvalidData, err)))
+}
+
+/// Downloads the `.pak` asset pack at `url` into memory.
+///
+/// # Example
+/// ```rust
+/// # use deckbuilder_eng::vfs::{AssetSource, Vfs};
+/// # use deckbuilder_eng::web::fetch_pak;
+/// # async fn demo() {
+/// let mut vfs = Vfs::new();
+/// vfs.mount(fetch_pak("assets.pak").await.unwrap());
+/// assert!(vfs.exists("cards/back.png".as_ref()));
+/// # }
+/// ```
+pub async fn fetch_pak(url: &str) -> Result<PakSource> {
+    let bytes = fetch_bytes(url).await?;
+    PakSource::from_bytes(bytes).map_err(|err| Error::io(url, err))
+}
+
+/// [`Transport`] over a browser WebSocket, for joining a session from a web build.
+///
+/// Each line is sent as a text message ending in a newline; received text and binary messages
+/// are joined and split at newlines, so lines may span messages.
+pub struct WebSocketTransport {
+    socket: WebSocket,
+    state: Rc<RefCell<SocketState>>,
+    _on_open: Closure<dyn FnMut()>,
+    _on_message: Closure<dyn FnMut(MessageEvent)>,
+    _on_close: Closure<dyn FnMut()>,
+}
+
+#[derive(Default)]
+struct SocketState {
+    open: bool,
+    closed: bool,
+    incoming: LineBuffer,
+    /// Lines sent while the connection was still opening.
+    pending: Vec<String>,
+}
+
+impl WebSocketTransport {
+    /// Starts connecting to `url` (`ws://` or `wss://`). Lines sent before the connection is open
+    /// are queued; a connection that fails to open shows up as an error from `receive`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use deckbuilder_eng::net::Client;
+    /// # use deckbuilder_eng::web::WebSocketTransport;
+    /// # fn demo() -> std::io::Result<()> {
+    /// let transport = WebSocketTransport::connect("wss://play.example.com/session")?;
+    /// let mut client = Client::with_transport(transport, "Bob");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn connect(url: &str) -> io::Result<Self> {
+        let socket = WebSocket::new(url).map_err(socket_error)?;
+        socket.set_binary_type(BinaryType::Arraybuffer);
+        let state = Rc::new(RefCell::new(SocketState::default()));
+
+        let on_open = {
+            let (socket, state) = (socket.clone(), Rc::clone(&state));
+            Closure::<dyn FnMut()>::new(move || {
+                let mut state = state.borrow_mut();
+                state.open = true;
+                for line in std::mem::take(&mut state.pending) {
+                    if socket.send_with_str(&line).is_err() {
+                        state.closed = true;
+                    }
+                }
+            })
+        };
+        let on_message = {
+            let state = Rc::clone(&state);
+            Closure::<dyn FnMut(MessageEvent)>::new(move |event: MessageEvent| {
+                let data = event.data();
+                let mut state = state.borrow_mut();
+                match data.as_string() {
+                    Some(text) => state.incoming.extend(text.as_bytes()),
+                    None => state.incoming.extend(&Uint8Array::new(&data).to_vec()),
+                }
+            })
+        };
+        // Errors are always followed by a close event
+        let on_close = {
+            let state = Rc::clone(&state);
+            Closure::<dyn FnMut()>::new(move || state.borrow_mut().closed = true)
+        };
+        socket.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+        socket.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+        socket.set_onclose(Some(on_close.as_ref().unchecked_ref()));
+
+        Ok(Self {
+            socket,
+            state,
+            _on_open: on_open,
+            _on_message: on_message,
+            _on_close: on_close,
+        })
+    }
+}
+
+impl Transport for WebSocketTransport {
+    fn send(&mut self, line: &str) -> io::Result<()> {
+        let mut state = self.state.borrow_mut();
+        if state.closed {
+            return Err(ErrorKind::BrokenPipe.into());
+        }
+        let line = format!("{line}\n");
+        if !state.open {
+            state.pending.push(line);
+            return Ok(());
+        }
+        self.socket.send_with_str(&line).map_err(socket_error)
+    }
+
+    fn receive(&mut self) -> io::Result<Option<String>> {
+        let mut state = self.state.borrow_mut();
+        match state.incoming.next_line()? {
+            Some(line) => Ok(Some(line)),
+            None if state.closed => Err(ErrorKind::UnexpectedEof.into()),
+            None => Ok(None),
+        }
+    }
+}
+
+impl Drop for WebSocketTransport {
+    fn drop(&mut self) {
+        // The handlers are freed with the transport, so the socket must not call them anymore
+        self.socket.set_onopen(None);
+        self.socket.set_onmessage(None);
+        self.socket.set_onclose(None);
+        let _ = self.socket.close();
+    }
+}
+
+fn socket_error(err: JsValue) -> io::Error {
+    io::Error::other(err.as_string().unwrap_or_else(|| format!("{err:?}")))
+}
+
+fn fetch_error(url: &str, message: &str) -> Error {
+    Error::io(url, io::Error::other(message.to_owned()))
+}
+
+fn js_error(url: &str, err: JsValue) -> Error {
+    let message = err.as_string().unwrap_or_else(|| format!("{err:?}"));
+    fetch_error(url, &message)
+}